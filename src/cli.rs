@@ -0,0 +1,100 @@
+//! Command-line modes that run without opening the editor window
+//!
+//! `nodle diff <old> <new>` prints the differences between two project files
+//! and `nodle merge <base> <ours> <theirs> [-o <output>]` three-way merges
+//! them, so project files kept in version control can be reviewed and merged.
+//! `nodle report <project> [-o <output>]` cooks a project and writes its graph
+//! statistics and memory report as JSON, for tracking them in CI.
+
+use std::path::{Path, PathBuf};
+use crate::editor::file_manager::{read_save_data, write_save_data};
+use crate::nodes::NodeGraphEngine;
+use crate::nodes::graph_diff::{diff_graphs, merge_three_way};
+use crate::nodes::graph_stats::GraphReport;
+
+const USAGE: &str = "usage:\n  nodle diff <old> <new>\n  nodle merge <base> <ours> <theirs> [-o <output>]\n  nodle report <project> [-o <output>]";
+
+/// Run a command-line mode if the arguments name one.
+/// Returns the process exit code, or None to start the editor.
+pub fn run(args: &[String]) -> Option<i32> {
+    let command = args.get(1)?;
+    let result = match command.as_str() {
+        "diff" => diff(&args[2..]),
+        "merge" => merge(&args[2..]),
+        "report" => report(&args[2..]),
+        _ => return None,
+    };
+    Some(match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}", e);
+            2
+        }
+    })
+}
+
+/// Print the differences; exit code 0 when the graphs match, 1 when they differ
+fn diff(args: &[String]) -> Result<i32, String> {
+    let [old_path, new_path] = args else {
+        return Err(USAGE.to_string());
+    };
+    let (old, _, _) = read_save_data(Path::new(old_path))?;
+    let (new, _, _) = read_save_data(Path::new(new_path))?;
+
+    let diff = diff_graphs(&old, &new);
+    for line in diff.to_lines() {
+        println!("{}", line);
+    }
+    Ok(if diff.is_empty() { 0 } else { 1 })
+}
+
+/// Write the merged graph (over `ours` unless `-o` is given); exit code 1 when there were conflicts
+fn merge(args: &[String]) -> Result<i32, String> {
+    let (paths, output) = match args {
+        [base, ours, theirs] => ([base, ours, theirs], PathBuf::from(ours)),
+        [base, ours, theirs, flag, output] if flag == "-o" => ([base, ours, theirs], PathBuf::from(output)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let (base, _, _) = read_save_data(Path::new(paths[0]))?;
+    // The merged file keeps our canvas transform and panel layout
+    let (ours, canvas, panel_layout) = read_save_data(Path::new(paths[1]))?;
+    let (theirs, _, _) = read_save_data(Path::new(paths[2]))?;
+
+    let result = merge_three_way(&base, &ours, &theirs);
+    write_save_data(&output, &result.graph, &canvas, &panel_layout, "Merged project")?;
+
+    for conflict in &result.conflicts {
+        eprintln!("conflict: {}", conflict);
+    }
+    println!("Merged into {} ({} conflicts)", output.display(), result.conflicts.len());
+    Ok(if result.conflicts.is_empty() { 0 } else { 1 })
+}
+
+/// Cook the project and print its graph report as JSON (or write it with `-o`); exit code 1 when a cook failed
+fn report(args: &[String]) -> Result<i32, String> {
+    let (path, output) = match args {
+        [path] => (path, None),
+        [path, flag, output] if flag == "-o" => (path, Some(PathBuf::from(output))),
+        _ => return Err(USAGE.to_string()),
+    };
+    let (graph, _, _) = read_save_data(Path::new(path))?;
+
+    let mut engine = NodeGraphEngine::new();
+    engine.set_seed(graph.seed, &graph);
+    engine.mark_all_dirty(&graph);
+    let cooked = engine.execute_dirty_nodes(&graph);
+    if let Err(e) = &cooked {
+        eprintln!("cook failed: {}", e);
+    }
+
+    // No viewport draws here, so there is no GPU memory to report
+    let report = GraphReport::build(&graph, &engine, None);
+    match output {
+        Some(output) => {
+            report.save_json(&output)?;
+            println!("Wrote graph report to {}", output.display());
+        }
+        None => println!("{}", report.to_json()?),
+    }
+    Ok(if cooked.is_ok() { 0 } else { 1 })
+}
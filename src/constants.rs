@@ -33,6 +33,8 @@ pub mod panel {
 pub mod menu {
     /// Timer delay before closing submenus (milliseconds)
     pub const SUBMENU_CLOSE_DELAY_MS: u128 = 800;
+    /// Most nodes listed for a search in the create menu
+    pub const MAX_SEARCH_RESULTS: usize = 12;
 }
 
 /// Node system constants  
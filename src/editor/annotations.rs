@@ -0,0 +1,255 @@
+//! Annotation tools
+//!
+//! Drawing tools for leaving review notes on the canvas: arrows, freehand
+//! strokes and text callouts, plus an eraser. While a tool is active, left
+//! clicks and drags on the canvas draw instead of selecting or moving nodes.
+//! The annotations themselves are stored on the graph (see
+//! `nodes::annotation`), so they are saved with the file.
+
+use eframe::egui;
+use egui::{Color32, PointerButton, Pos2, Rect, Response, Stroke, Vec2};
+use crate::nodes::annotation::{annotation_at, Annotation, AnnotationShape};
+
+/// Line width of arrows and strokes at zoom 1
+const STROKE_WIDTH: f32 = 2.5;
+
+/// Length of the arrow head at zoom 1
+const ARROW_HEAD_LENGTH: f32 = 14.0;
+
+/// Callout font size at zoom 1
+const CALLOUT_FONT_SIZE: f32 = 14.0;
+
+/// Screen distance within which the eraser picks an annotation
+const ERASER_TOLERANCE: f32 = 6.0;
+
+/// Drawing tool in use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationTool {
+    Arrow,
+    Freehand,
+    Text,
+    Eraser,
+}
+
+/// Change to apply to the viewed graph's annotations
+#[derive(Debug, Clone)]
+pub enum AnnotationEdit {
+    Add(Annotation),
+    Remove(usize),
+    Clear,
+}
+
+/// Annotation tool state and rendering
+pub struct AnnotationLayer {
+    /// Whether annotations are drawn on the canvas
+    pub visible: bool,
+    tool: Option<AnnotationTool>,
+    color: Color32,
+    /// Arrow or stroke being drawn
+    in_progress: Option<AnnotationShape>,
+    /// Position and text of a callout being typed
+    callout_prompt: Option<(Pos2, String)>,
+}
+
+impl Default for AnnotationLayer {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            tool: None,
+            color: Color32::from_rgb(255, 196, 0),
+            in_progress: None,
+            callout_prompt: None,
+        }
+    }
+}
+
+impl AnnotationLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether canvas input currently goes to an annotation tool
+    pub fn is_tool_active(&self) -> bool {
+        self.tool.is_some()
+    }
+
+    fn color_rgba(&self) -> [u8; 4] {
+        self.color.to_srgba_unmultiplied()
+    }
+
+    /// Handle canvas input for the active tool. `to_world` converts screen to graph positions.
+    pub fn handle_input(&mut self, response: &Response, to_world: &dyn Fn(Pos2) -> Pos2, annotations: &[Annotation], zoom: f32) -> Option<AnnotationEdit> {
+        let tool = self.tool?;
+        let pointer = response.interact_pointer_pos().map(to_world)?;
+
+        match tool {
+            AnnotationTool::Arrow | AnnotationTool::Freehand => {
+                if response.drag_started_by(PointerButton::Primary) {
+                    self.in_progress = Some(match tool {
+                        AnnotationTool::Arrow => AnnotationShape::Arrow { from: pointer, to: pointer },
+                        _ => AnnotationShape::Freehand { points: vec![pointer] },
+                    });
+                } else if response.dragged_by(PointerButton::Primary) {
+                    match &mut self.in_progress {
+                        Some(AnnotationShape::Arrow { to, .. }) => *to = pointer,
+                        // Skip points closer than two screen pixels to keep saved strokes small
+                        Some(AnnotationShape::Freehand { points }) => {
+                            if points.last().map_or(true, |last| last.distance(pointer) * zoom >= 2.0) {
+                                points.push(pointer);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                if response.drag_stopped_by(PointerButton::Primary) {
+                    let shape = self.in_progress.take()?;
+                    let long_enough = match &shape {
+                        AnnotationShape::Arrow { from, to } => from.distance(*to) * zoom >= 8.0,
+                        AnnotationShape::Freehand { points } => points.len() >= 2,
+                        AnnotationShape::Callout { .. } => true,
+                    };
+                    if long_enough {
+                        return Some(AnnotationEdit::Add(Annotation { shape, color: self.color_rgba() }));
+                    }
+                }
+                None
+            }
+            AnnotationTool::Text => {
+                if response.clicked_by(PointerButton::Primary) {
+                    self.callout_prompt = Some((pointer, String::new()));
+                }
+                None
+            }
+            AnnotationTool::Eraser => {
+                let erasing = response.clicked_by(PointerButton::Primary) || response.dragged_by(PointerButton::Primary);
+                if erasing {
+                    annotation_at(annotations, pointer, ERASER_TOLERANCE / zoom).map(AnnotationEdit::Remove)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Draw `annotations` (when visible) and anything being drawn
+    pub fn paint(&self, painter: &egui::Painter, annotations: &[Annotation], transform_pos: &dyn Fn(Pos2) -> Pos2, zoom: f32) {
+        if self.visible {
+            for annotation in annotations {
+                let [r, g, b, a] = annotation.color;
+                paint_shape(painter, &annotation.shape, Color32::from_rgba_unmultiplied(r, g, b, a), transform_pos, zoom);
+            }
+        }
+        if let Some(shape) = &self.in_progress {
+            paint_shape(painter, shape, self.color, transform_pos, zoom);
+        }
+        if let Some((position, _)) = &self.callout_prompt {
+            painter.circle_filled(transform_pos(*position), 4.0, self.color);
+        }
+    }
+
+    /// Render the annotation toolbar and the callout text prompt
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) -> Option<AnnotationEdit> {
+        let mut edit = None;
+        if *open {
+            egui::Window::new("Annotations")
+                .open(open)
+                .resizable(false)
+                .default_pos([80.0, 80.0])
+                .show(ctx, |ui| {
+                    ui.checkbox(&mut self.visible, "Show annotations");
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        for (tool, label, hint) in [
+                            (None, "🖱", "Edit nodes"),
+                            (Some(AnnotationTool::Arrow), "➡", "Arrow: drag from tail to tip"),
+                            (Some(AnnotationTool::Freehand), "✏", "Freehand: drag to draw"),
+                            (Some(AnnotationTool::Text), "💬", "Callout: click to place text"),
+                            (Some(AnnotationTool::Eraser), "⌫", "Eraser: click or drag over annotations"),
+                        ] {
+                            ui.selectable_value(&mut self.tool, tool, label).on_hover_text(hint);
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Color");
+                        ui.color_edit_button_srgba(&mut self.color);
+                    });
+                    ui.separator();
+                    if ui.button("Clear All").on_hover_text("Remove every annotation from this graph").clicked() {
+                        edit = Some(AnnotationEdit::Clear);
+                    }
+                });
+        }
+        if !*open {
+            // Closing the toolbar returns the canvas to node editing
+            self.tool = None;
+            self.in_progress = None;
+        }
+
+        if let Some((position, mut text)) = self.callout_prompt.take() {
+            let mut keep_open = true;
+            let mut confirmed = false;
+            egui::Window::new("Callout")
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ctx, |ui| {
+                    let field = ui.add(egui::TextEdit::multiline(&mut text)
+                        .desired_rows(3)
+                        .hint_text("Note"));
+                    field.request_focus();
+                    ui.horizontal(|ui| {
+                        confirmed = ui.button("Add").clicked();
+                        if ui.button("Cancel").clicked() || ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+                            keep_open = false;
+                        }
+                    });
+                });
+            if confirmed && !text.trim().is_empty() {
+                edit = Some(AnnotationEdit::Add(Annotation {
+                    shape: AnnotationShape::Callout { position, text: text.trim_end().to_string() },
+                    color: self.color_rgba(),
+                }));
+            } else if keep_open && !confirmed {
+                self.callout_prompt = Some((position, text));
+            }
+        }
+        edit
+    }
+}
+
+/// Draw one annotation shape in screen space
+fn paint_shape(painter: &egui::Painter, shape: &AnnotationShape, color: Color32, transform_pos: &dyn Fn(Pos2) -> Pos2, zoom: f32) {
+    let stroke = Stroke::new(STROKE_WIDTH * zoom, color);
+    match shape {
+        AnnotationShape::Arrow { from, to } => {
+            let (from, to) = (transform_pos(*from), transform_pos(*to));
+            painter.line_segment([from, to], stroke);
+            let direction = (to - from).normalized();
+            if direction != Vec2::ZERO {
+                let head = ARROW_HEAD_LENGTH * zoom;
+                let back = to - direction * head;
+                let side = direction.rot90() * head * 0.5;
+                painter.line_segment([to, back + side], stroke);
+                painter.line_segment([to, back - side], stroke);
+            }
+        }
+        AnnotationShape::Freehand { points } => {
+            let points: Vec<Pos2> = points.iter().map(|point| transform_pos(*point)).collect();
+            painter.add(egui::Shape::line(points, stroke));
+        }
+        AnnotationShape::Callout { position, text } => {
+            let galley = painter.layout(
+                text.clone(),
+                egui::FontId::proportional(CALLOUT_FONT_SIZE * zoom),
+                Color32::WHITE,
+                f32::INFINITY,
+            );
+            let top_left = transform_pos(*position);
+            let padding = Vec2::splat(6.0 * zoom);
+            let rect = Rect::from_min_size(top_left, galley.size() + padding * 2.0);
+            painter.rect_filled(rect, 4.0 * zoom, Color32::from_rgba_unmultiplied(20, 20, 20, 220));
+            painter.rect_stroke(rect, 4.0 * zoom, Stroke::new(1.5 * zoom, color), egui::StrokeKind::Inside);
+            painter.galley(top_left + padding, galley, Color32::WHITE);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Canvas management for pan/zoom operations in the node editor
 
-use egui::{Pos2, Vec2};
+use egui::{Pos2, Rect, Vec2};
 
 /// Manages canvas state including pan and zoom for the node editor
 #[derive(Debug, Clone)]
@@ -50,6 +50,16 @@ impl Canvas {
         )
     }
 
+    /// Pan and zoom so `world_rect` fills `view` (screen space) with a margin
+    pub fn frame_rect(&mut self, world_rect: Rect, view: Rect) {
+        let margin = 60.0;
+        let available = (view.size() - Vec2::splat(margin * 2.0)).max(Vec2::splat(1.0));
+        let size = world_rect.size().max(Vec2::splat(1.0));
+        // Never zoom in past 1:1 when framing a few small nodes
+        self.zoom = (available.x / size.x).min(available.y / size.y).clamp(0.1, 1.0);
+        self.pan_offset = view.center().to_vec2() - world_rect.center().to_vec2() * self.zoom;
+    }
+
     /// Get GPU pan offset (no menu bar adjustment needed)
     pub fn get_gpu_pan_offset(&self, _menu_bar_height: f32) -> Vec2 {
         Vec2::new(
@@ -32,8 +32,8 @@ impl MeshRenderer {
         // NODE BODY COMPONENTS
         let radius = theme::dimensions().corner_radius * zoom;
         
-        // BACKGROUND: Inner gradient mesh - same for all nodes
-        let (background_top_color, background_bottom_color) = (theme::colors().node_bg_top, theme::colors().node_bg_bottom);
+        // BACKGROUND: Inner gradient mesh - theme colors, the node's category color if the theme sets one, or its tag color
+        let (background_top_color, background_bottom_color) = node.body_gradient();
         
         // BORDER: Outermost layer (1px larger than node rect, scaled by zoom)
         let border_expand = theme::dimensions().border_width * zoom;
@@ -44,7 +44,7 @@ impl MeshRenderer {
         let border_color = if selected {
             theme::colors().selection_blue // Blue selection
         } else {
-            Color32::from_rgb(64, 64, 64)    // Dark grey unselected
+            theme::colors().node_border    // Dark grey unselected
         };
         
         let border_mesh = Self::create_rounded_gradient_mesh_optimized(
@@ -58,7 +58,7 @@ impl MeshRenderer {
         
         // BEVEL: Middle layer (same size as original node rect)
         let bevel_rect = transformed_rect;
-        let (bevel_top_color, bevel_bottom_color) = (Color32::from_rgb(166, 166, 166), Color32::from_rgb(38, 38, 38));
+        let (bevel_top_color, bevel_bottom_color) = (theme::colors().node_bevel_light, theme::colors().node_bevel_dark);
         
         let bevel_mesh = Self::create_rounded_gradient_mesh_optimized(
             bevel_rect,
@@ -96,12 +96,64 @@ impl MeshRenderer {
         );
     }
 
+    /// Render the secondary label under a node's title, shortened to fit the node
+    pub fn render_node_subtitle(
+        painter: &Painter,
+        node: &Node,
+        subtitle: &str,
+        zoom: f32,
+        transform_pos: impl Fn(Pos2) -> Pos2,
+    ) {
+        let font_id = egui::FontId::proportional(10.0 * zoom);
+        let max_width = (node.size.x - 20.0) * zoom;
+        let width_of = |text: &str| painter.fonts(|fonts| {
+            fonts.layout_no_wrap(text.to_string(), font_id.clone(), Color32::WHITE).size().x
+        });
+        
+        let mut text = subtitle.to_string();
+        if width_of(&text) > max_width {
+            let mut chars: Vec<char> = subtitle.chars().collect();
+            while !chars.is_empty() {
+                chars.pop();
+                text = format!("{}…", chars.iter().collect::<String>());
+                if width_of(&text) <= max_width {
+                    break;
+                }
+            }
+        }
+        
+        painter.text(
+            transform_pos(node.position + Vec2::new(node.size.x / 2.0, 15.0 + crate::nodes::factory::SUBTITLE_LINE_HEIGHT)),
+            egui::Align2::CENTER_CENTER,
+            text,
+            font_id,
+            Color32::from_gray(170),
+        );
+    }
+
+    /// Render an anchor label (a Null node's name) in large type above the node
+    pub fn render_anchor_label(
+        painter: &Painter,
+        node: &Node,
+        label: &str,
+        zoom: f32,
+        transform_pos: impl Fn(Pos2) -> Pos2,
+    ) {
+        painter.text(
+            transform_pos(node.position + Vec2::new(node.size.x / 2.0, -6.0)),
+            egui::Align2::CENTER_BOTTOM,
+            label,
+            egui::FontId::proportional(18.0 * zoom),
+            Color32::from_rgb(235, 215, 140),
+        );
+    }
+
     /// Render a port with all layers using CPU mesh generation
     /// This matches the exact port rendering logic from the original editor
     pub fn render_port_complete_cpu(
         painter: &Painter,
         port_pos: Pos2,
-        is_input: bool,
+        port_color: Color32,
         is_connecting: bool,
         zoom: f32,
         transform_pos: impl Fn(Pos2) -> Pos2,
@@ -113,7 +165,7 @@ impl MeshRenderer {
         let port_border_color = if is_connecting {
             theme::colors().selection_blue // Blue selection color
         } else {
-            Color32::from_rgb(64, 64, 64) // Unselected node border color
+            theme::colors().node_border // Unselected node border color
         };
         
         painter.circle_filled(
@@ -126,20 +178,14 @@ impl MeshRenderer {
         painter.circle_filled(
             transformed_pos,
             port_radius + 1.0 * zoom,
-            Color32::from_rgb(38, 38, 38), // Node bevel bottom color (0.15)
+            theme::colors().node_bevel_dark, // Node bevel bottom color (0.15)
         );
         
-        // Draw port background (main port)
-        let port_bg_color = if is_input {
-            theme::colors().port_input // Darker green for input ports
-        } else {
-            theme::colors().port_output // Darker red for output ports
-        };
-        
+        // Draw port background (main port) in the port's data type color
         painter.circle_filled(
             transformed_pos,
             port_radius,
-            port_bg_color,
+            port_color,
         );
     }
 
@@ -157,7 +203,7 @@ impl MeshRenderer {
         let border_color = if is_visible {
             theme::colors().selection_blue // Blue selection color when enabled
         } else {
-            Color32::from_rgb(64, 64, 64) // Grey when disabled
+            theme::colors().node_border // Grey when disabled
         };
         
         let border_radius = theme::dimensions().corner_radius * zoom + 2.0 * zoom;
@@ -172,7 +218,7 @@ impl MeshRenderer {
         painter.circle_stroke(
             transformed_pos,
             bevel_radius,
-            Stroke::new(1.0 * zoom, Color32::from_rgb(38, 38, 38)), // Bevel outline
+            Stroke::new(1.0 * zoom, theme::colors().node_bevel_dark), // Bevel outline
         );
         
         // Add bigger dot for visible nodes only
@@ -0,0 +1,73 @@
+//! Data-flow overlay
+//!
+//! When enabled, a pulse runs along the wires leaving a node each time it
+//! recooks, and hovering a wire shows the last scalar or string value that
+//! went through it.
+
+use egui::{Color32, Painter, Pos2};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use crate::nodes::{NodeGraphEngine, NodeId};
+use crate::nodes::wire_style::WirePath;
+
+/// How long a pulse takes to travel a wire
+const PULSE_DURATION: Duration = Duration::from_millis(600);
+
+/// Screen distance from a wire within which its value tooltip shows
+pub const HOVER_DISTANCE: f32 = 6.0;
+
+/// Cook tracking for the pulse animation
+#[derive(Default)]
+pub struct DataFlowOverlay {
+    pub enabled: bool,
+    /// Cook count of each node when last seen
+    cook_counts: HashMap<NodeId, u64>,
+    /// When each node last recooked
+    pulses: HashMap<NodeId, Instant>,
+}
+
+impl DataFlowOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start pulses for nodes that cooked since the last frame
+    pub fn observe(&mut self, engine: &NodeGraphEngine, nodes: impl Iterator<Item = NodeId>) {
+        let now = Instant::now();
+        for node_id in nodes {
+            let count = engine.get_cook_count(node_id);
+            // The first sighting only records the count, so opening a graph does not flash every wire
+            if let Some(previous) = self.cook_counts.insert(node_id, count) {
+                if previous != count {
+                    self.pulses.insert(node_id, now);
+                }
+            }
+        }
+        self.pulses.retain(|_, started| started.elapsed() < PULSE_DURATION);
+    }
+
+    /// Whether any pulse is still running (the canvas needs repainting)
+    pub fn is_animating(&self) -> bool {
+        !self.pulses.is_empty()
+    }
+
+    /// How far along the wires leaving `node_id` its pulse is, from 0 to 1
+    pub fn pulse_progress(&self, node_id: NodeId) -> Option<f32> {
+        let elapsed = self.pulses.get(&node_id)?.elapsed();
+        (elapsed < PULSE_DURATION).then(|| elapsed.as_secs_f32() / PULSE_DURATION.as_secs_f32())
+    }
+}
+
+/// Draw a pulse at `progress` along `wire`
+pub fn paint_pulse(painter: &Painter, wire: &WirePath, progress: f32, color: Color32, zoom: f32) {
+    let position = wire.point_at(progress);
+    // Fade out over the last part of the trip
+    let alpha = (1.0 - progress).min(0.3) / 0.3;
+    painter.circle_filled(position, 6.0 * zoom, color.gamma_multiply(0.25 * alpha));
+    painter.circle_filled(position, 3.0 * zoom, color.gamma_multiply(alpha));
+}
+
+/// Whether `pointer` is over `wire`
+pub fn is_hovered(pointer: Pos2, wire: &WirePath) -> bool {
+    wire.distance_to(pointer) <= HOVER_DISTANCE
+}
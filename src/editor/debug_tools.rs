@@ -73,11 +73,11 @@ impl DebugToolsManager {
                     ui.label(format!("Nodes: {}", node_count));
                     ui.separator();
                     ui.label("F1: Toggle performance info");
-                    ui.label("F2: Add 10 nodes");
                     ui.label("F3: Add 25 nodes");
                     ui.label("F4: Stress test (5000 nodes + connections)");
                     ui.label("F5: Clear all nodes");
                     ui.label("F6: Toggle GPU/CPU rendering");
+                    ui.label("F7: Add 10 nodes");
                 });
         }
     }
@@ -0,0 +1,167 @@
+//! Dependency view
+//!
+//! When enabled, everything upstream of the selected nodes (and optionally
+//! everything downstream) stays lit while the rest of the graph is dimmed.
+//! Related nodes are outlined in a tint that fades with their distance from
+//! the selection, which shows how far a change would propagate dirtiness.
+
+use egui::{Align2, Color32, FontId, Painter, Pos2, Rect, Stroke};
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::nodes::{Connection, Node, NodeId};
+
+/// Outline of nodes feeding the selection
+const UPSTREAM_COLOR: Color32 = Color32::from_rgb(255, 170, 60);
+/// Outline of nodes fed by the selection
+const DOWNSTREAM_COLOR: Color32 = Color32::from_rgb(80, 200, 255);
+/// Depth at which the tint stops fading
+const MAX_TINT_DEPTH: usize = 6;
+
+/// How a node relates to the selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Selected,
+    /// Feeds the selection through this many connections
+    Upstream(usize),
+    /// Is fed by the selection through this many connections
+    Downstream(usize),
+}
+
+impl Relation {
+    /// Outline color, fading with depth
+    pub fn color(&self) -> Color32 {
+        let (base, depth) = match *self {
+            Relation::Selected => return Color32::WHITE,
+            Relation::Upstream(depth) => (UPSTREAM_COLOR, depth),
+            Relation::Downstream(depth) => (DOWNSTREAM_COLOR, depth),
+        };
+        let fade = (depth.min(MAX_TINT_DEPTH) - 1) as f32 / MAX_TINT_DEPTH as f32;
+        base.gamma_multiply(1.0 - 0.7 * fade)
+    }
+}
+
+/// Toggle state of the dependency view
+#[derive(Debug, Default)]
+pub struct DependencyView {
+    pub enabled: bool,
+    /// Also light up the nodes downstream of the selection
+    pub include_downstream: bool,
+}
+
+impl DependencyView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Relations of the nodes around `selected`; empty while disabled or nothing is selected
+    pub fn relations(&self, connections: &[Connection], selected: &HashSet<NodeId>) -> HashMap<NodeId, Relation> {
+        if !self.enabled || selected.is_empty() {
+            return HashMap::new();
+        }
+        related_nodes(connections, selected, self.include_downstream)
+    }
+}
+
+/// Breadth-first distances from `roots` against (upstream) or along (downstream) the connections
+fn depths(connections: &[Connection], roots: &HashSet<NodeId>, upstream: bool) -> HashMap<NodeId, usize> {
+    let mut depths = HashMap::new();
+    let mut queue: VecDeque<(NodeId, usize)> = roots.iter().map(|&id| (id, 0)).collect();
+    let mut seen = roots.clone();
+    while let Some((node_id, depth)) = queue.pop_front() {
+        for connection in connections {
+            let (here, next) = if upstream {
+                (connection.to_node, connection.from_node)
+            } else {
+                (connection.from_node, connection.to_node)
+            };
+            if here == node_id && seen.insert(next) {
+                depths.insert(next, depth + 1);
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    depths
+}
+
+/// Relations of every node connected to `roots`; upstream wins for nodes that are both
+pub fn related_nodes(connections: &[Connection], roots: &HashSet<NodeId>, include_downstream: bool) -> HashMap<NodeId, Relation> {
+    let mut relations: HashMap<NodeId, Relation> = roots.iter().map(|&id| (id, Relation::Selected)).collect();
+    if include_downstream {
+        for (node_id, depth) in depths(connections, roots, false) {
+            relations.insert(node_id, Relation::Downstream(depth));
+        }
+    }
+    for (node_id, depth) in depths(connections, roots, true) {
+        relations.insert(node_id, Relation::Upstream(depth));
+    }
+    relations
+}
+
+/// Wire color under the view: kept when it links two related nodes, faded otherwise
+pub fn wire_color(relations: &HashMap<NodeId, Relation>, connection: &Connection, color: Color32) -> Color32 {
+    if relations.is_empty() || (relations.contains_key(&connection.from_node) && relations.contains_key(&connection.to_node)) {
+        color
+    } else {
+        color.gamma_multiply(0.15)
+    }
+}
+
+/// Dim unrelated nodes and outline related ones with their depth
+pub fn paint(painter: &Painter, nodes: &HashMap<NodeId, Node>, relations: &HashMap<NodeId, Relation>, transform_pos: &dyn Fn(Pos2) -> Pos2, zoom: f32) {
+    if relations.is_empty() {
+        return;
+    }
+    for node in nodes.values() {
+        let rect = node.get_rect();
+        let screen_rect = Rect::from_two_pos(transform_pos(rect.min), transform_pos(rect.max));
+        let Some(relation) = relations.get(&node.id) else {
+            painter.rect_filled(screen_rect.expand(2.0 * zoom), 5.0 * zoom, Color32::from_black_alpha(170));
+            continue;
+        };
+        let color = relation.color();
+        painter.rect_stroke(screen_rect.expand(4.0 * zoom), 5.0 * zoom, Stroke::new(2.0 * zoom, color), egui::StrokeKind::Outside);
+        let depth = match relation {
+            Relation::Selected => continue,
+            Relation::Upstream(depth) => format!("↑{}", depth),
+            Relation::Downstream(depth) => format!("↓{}", depth),
+        };
+        painter.text(
+            screen_rect.left_top() + egui::vec2(-6.0 * zoom, -6.0 * zoom),
+            Align2::RIGHT_BOTTOM,
+            depth,
+            FontId::proportional(11.0 * zoom),
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection(from_node: NodeId, to_node: NodeId) -> Connection {
+        Connection::new(from_node, 0, to_node, 0)
+    }
+
+    #[test]
+    fn test_relations_follow_connections_by_depth() {
+        // 1 -> 2 -> 3 -> 4, 5 -> 3, 6 unconnected
+        let connections = vec![connection(1, 2), connection(2, 3), connection(3, 4), connection(5, 3)];
+        let roots = HashSet::from([3]);
+
+        let upstream = related_nodes(&connections, &roots, false);
+        assert_eq!(upstream.get(&3), Some(&Relation::Selected));
+        assert_eq!(upstream.get(&2), Some(&Relation::Upstream(1)));
+        assert_eq!(upstream.get(&5), Some(&Relation::Upstream(1)));
+        assert_eq!(upstream.get(&1), Some(&Relation::Upstream(2)));
+        assert!(!upstream.contains_key(&4));
+        assert!(!upstream.contains_key(&6));
+
+        let both = related_nodes(&connections, &roots, true);
+        assert_eq!(both.get(&4), Some(&Relation::Downstream(1)));
+        assert_eq!(both.len(), 5);
+
+        assert_eq!(wire_color(&both, &connection(3, 4), Color32::RED), Color32::RED);
+        assert_ne!(wire_color(&upstream, &connection(3, 4), Color32::RED), Color32::RED);
+        assert!(Relation::Upstream(1).color().r() > Relation::Upstream(5).color().r());
+    }
+}
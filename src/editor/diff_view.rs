@@ -0,0 +1,126 @@
+//! Visual graph diff overlay
+//!
+//! Highlights the nodes that differ from another revision of the project:
+//! added nodes in green, changed nodes in amber and removed nodes as red ghosts
+//! at their old position. A window lists the differences and any merge conflicts.
+
+use eframe::egui;
+use egui::{Color32, Painter, Pos2, Rect, RichText, Stroke};
+use std::collections::HashMap;
+use crate::nodes::{Node, NodeId};
+use crate::nodes::graph_diff::GraphDiff;
+
+const ADDED_COLOR: Color32 = Color32::from_rgb(90, 200, 110);
+const CHANGED_COLOR: Color32 = Color32::from_rgb(230, 170, 60);
+const REMOVED_COLOR: Color32 = Color32::from_rgb(220, 80, 80);
+
+/// The diff currently shown on the canvas, if any
+#[derive(Default)]
+pub struct DiffView {
+    diff: Option<GraphDiff>,
+    /// What the canvas is compared against ("main.nodle", "merge of a and b")
+    label: String,
+    conflicts: Vec<String>,
+}
+
+impl DiffView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show `diff` on the canvas along with any merge conflicts
+    pub fn show(&mut self, label: impl Into<String>, diff: GraphDiff, conflicts: Vec<String>) {
+        self.label = label.into();
+        self.diff = Some(diff);
+        self.conflicts = conflicts;
+    }
+
+    /// Stop showing the diff
+    pub fn clear(&mut self) {
+        self.diff = None;
+        self.conflicts.clear();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.diff.is_some()
+    }
+
+    /// Outline nodes of the current graph and draw removed nodes as ghosts
+    pub fn paint_overlay(&self, painter: &Painter, nodes: &HashMap<NodeId, Node>, transform_pos: &dyn Fn(Pos2) -> Pos2, zoom: f32) {
+        let Some(diff) = &self.diff else {
+            return;
+        };
+        let to_screen = |rect: Rect| Rect::from_min_max(transform_pos(rect.min), transform_pos(rect.max));
+        let outline = |rect: Rect, color: Color32| {
+            painter.rect_stroke(to_screen(rect).expand(4.0 * zoom), 6.0 * zoom, Stroke::new(3.0 * zoom, color), egui::StrokeKind::Outside);
+        };
+
+        for node in &diff.removed_nodes {
+            let rect = to_screen(node.rect);
+            painter.rect_filled(rect, 5.0 * zoom, REMOVED_COLOR.gamma_multiply(0.15));
+            painter.rect_stroke(rect, 5.0 * zoom, Stroke::new(2.0 * zoom, REMOVED_COLOR), egui::StrokeKind::Middle);
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("− {}", node.title),
+                egui::FontId::proportional(12.0 * zoom),
+                REMOVED_COLOR,
+            );
+        }
+        for node in &diff.added_nodes {
+            if let Some(current) = nodes.get(&node.id) {
+                outline(current.get_rect(), ADDED_COLOR);
+            }
+        }
+        for change in &diff.changed_nodes {
+            if let Some(current) = nodes.get(&change.id) {
+                outline(current.get_rect(), CHANGED_COLOR);
+            }
+        }
+    }
+
+    /// Render the diff summary window
+    pub fn render(&mut self, ctx: &egui::Context) {
+        let Some(diff) = &self.diff else {
+            return;
+        };
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new("Graph Diff")
+            .open(&mut open)
+            .default_size([380.0, 300.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Compared with {}", self.label));
+                    if ui.button("Clear").clicked() {
+                        close = true;
+                    }
+                });
+                if !self.conflicts.is_empty() {
+                    ui.label(RichText::new(format!("{} conflicts (kept current version)", self.conflicts.len())).color(REMOVED_COLOR).strong());
+                    for conflict in &self.conflicts {
+                        ui.label(RichText::new(conflict.as_str()).color(REMOVED_COLOR));
+                    }
+                }
+                ui.separator();
+
+                if diff.is_empty() {
+                    ui.label(RichText::new("No differences").weak());
+                    return;
+                }
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for line in diff.to_lines() {
+                        let color = match line.chars().next() {
+                            Some('+') => ADDED_COLOR,
+                            Some('-') => REMOVED_COLOR,
+                            _ => CHANGED_COLOR,
+                        };
+                        ui.label(RichText::new(line).color(color).monospace());
+                    }
+                });
+            });
+        if !open || close {
+            self.clear();
+        }
+    }
+}
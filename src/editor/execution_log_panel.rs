@@ -0,0 +1,115 @@
+//! Execution log window
+//!
+//! Lists the engine's [`ExecutionLog`] newest last, filtered by text, by the
+//! selected nodes or to problems only. The filtered entries can be exported
+//! as text or JSON.
+
+use eframe::egui;
+use egui::{Color32, RichText};
+use std::collections::HashSet;
+use crate::nodes::NodeId;
+use crate::nodes::execution_log::{ExecutionLog, LogFilter, LogKind};
+
+/// Filter state of the execution log window
+#[derive(Debug, Default)]
+pub struct ExecutionLogPanel {
+    text: String,
+    /// Show only the entries of the selected nodes
+    selected_only: bool,
+    problems_only: bool,
+}
+
+impl ExecutionLogPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn filter(&self, selected: &HashSet<NodeId>) -> LogFilter {
+        LogFilter {
+            text: self.text.clone(),
+            nodes: if self.selected_only { selected.iter().copied().collect() } else { Vec::new() },
+            problems_only: self.problems_only,
+        }
+    }
+
+    /// Render the log window; `open` is cleared when the window is closed
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool, execution_log: &mut ExecutionLog, selected: &HashSet<NodeId>) {
+        let mut export = false;
+        let mut clear = false;
+        let filter = self.filter(selected);
+        egui::Window::new("Execution Log")
+            .open(open)
+            .default_size([560.0, 320.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.text).hint_text("Filter nodes or messages").desired_width(180.0));
+                    ui.checkbox(&mut self.selected_only, "Selected nodes")
+                        .on_hover_text("Only show entries of the nodes selected in the graph");
+                    ui.checkbox(&mut self.problems_only, "Problems only")
+                        .on_hover_text("Only show failed cooks and entries with warnings");
+                    if ui.button("Export...").clicked() {
+                        export = true;
+                    }
+                    if ui.button("Clear").clicked() {
+                        clear = true;
+                    }
+                });
+                ui.separator();
+
+                let entries: Vec<_> = execution_log.filtered(&filter).collect();
+                if entries.is_empty() {
+                    ui.label(RichText::new(if execution_log.is_empty() { "Nothing cooked yet" } else { "No entries match the filter" }).weak());
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show_rows(ui, ui.text_style_height(&egui::TextStyle::Body), entries.len(), |ui, rows| {
+                        for entry in &entries[rows] {
+                            let color = match entry.kind {
+                                LogKind::Error => Color32::from_rgb(230, 90, 90),
+                                _ if entry.has_warnings() => Color32::from_rgb(230, 180, 80),
+                                LogKind::Event => Color32::GRAY,
+                                LogKind::Cook => ui.visuals().text_color(),
+                            };
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(entry.time.format("%H:%M:%S%.3f").to_string()).color(Color32::GRAY).monospace());
+                                ui.label(RichText::new(&entry.node_title).strong())
+                                    .on_hover_text(format!("{} (node {})", entry.node_type, entry.node_id));
+                                if let Some(duration_ms) = entry.duration_ms {
+                                    ui.label(RichText::new(format!("{:.2} ms", duration_ms)).monospace());
+                                }
+                                let response = ui.label(RichText::new(&entry.message).color(color));
+                                if !entry.inputs.is_empty() || !entry.warnings.is_empty() {
+                                    let mut details = Vec::new();
+                                    if !entry.inputs.is_empty() {
+                                        details.push(format!("Inputs: {}", entry.inputs));
+                                    }
+                                    details.extend(entry.warnings.iter().map(|warning| format!("Warning: {}", warning)));
+                                    response.on_hover_text(details.join("\n"));
+                                }
+                                if !entry.warnings.is_empty() {
+                                    ui.label(RichText::new(format!("⚠ {}", entry.warnings.len())).color(color));
+                                }
+                            });
+                        }
+                    });
+            });
+
+        if export {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("JSON", &["json"])
+                .add_filter("Text files", &["txt", "log"])
+                .set_file_name("execution_log.json")
+                .save_file()
+            {
+                if let Err(e) = execution_log.export_to_file(&path, &filter) {
+                    log::error!("{}", e);
+                }
+            }
+        }
+        if clear {
+            execution_log.clear();
+        }
+    }
+}
@@ -4,8 +4,10 @@
 
 use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
-use crate::nodes::NodeGraph;
+use crate::nodes::{Node, NodeGraph};
+use crate::nodes::interface::PanelLayout;
 use crate::editor::canvas::Canvas;
+use crate::editor::templates::{self, ProjectTemplate, TemplateSource};
 
 /// Save file data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +16,9 @@ pub struct SaveData {
     pub metadata: SaveMetadata,
     pub viewport: CanvasData,
     pub root_graph: NodeGraph,
+    /// Open panels, their placement and stacking (absent in older files)
+    #[serde(default)]
+    pub panel_layout: PanelLayout,
 }
 
 /// Metadata for save files
@@ -32,20 +37,290 @@ pub struct CanvasData {  // Renamed from ViewportData to avoid conflict with 3D
     pub zoom: f32,
 }
 
+/// Maximum number of entries kept in the recent files list
+pub const MAX_RECENT_FILES: usize = 10;
+
+/// Recent files list and startup preferences, persisted in `~/.nodle/recent_files.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentFiles {
+    /// Most recently used first
+    pub files: Vec<PathBuf>,
+    /// Whether the startup screen is shown when the editor opens
+    #[serde(default = "default_show_startup_screen")]
+    pub show_startup_screen: bool,
+}
+
+fn default_show_startup_screen() -> bool {
+    true
+}
+
+impl Default for RecentFiles {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            show_startup_screen: true,
+        }
+    }
+}
+
+/// Default per-user storage directory (`~/.nodle`) for recent files and templates
+fn default_storage_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".nodle"))
+}
+
+/// Header identifying compressed save files, followed by zstd-compressed JSON
+const COMPRESSED_MAGIC: &[u8] = b"NODLEZ\x00\x01";
+
+/// zstd level used for compressed saves (favours size; saving is not latency critical)
+const COMPRESSION_LEVEL: i32 = 9;
+
+/// On-disk encoding of a save file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// Pretty-printed JSON (`.json`)
+    Json,
+    /// zstd-compressed JSON with a binary header (`.nodlez`), much smaller for USD-heavy graphs
+    Compressed,
+}
+
+impl SaveFormat {
+    /// File extension for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Compressed => "nodlez",
+        }
+    }
+
+    /// Format to write for a path, chosen by its extension
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case(SaveFormat::Compressed.extension()) => SaveFormat::Compressed,
+            _ => SaveFormat::Json,
+        }
+    }
+
+    /// Detect the format of file contents (independent of the file name)
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(COMPRESSED_MAGIC) {
+            SaveFormat::Compressed
+        } else {
+            SaveFormat::Json
+        }
+    }
+}
+
+/// Assemble the save file contents for a graph, its canvas transform and panel layout
+pub(crate) fn build_save_data(graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout, description: &str) -> SaveData {
+    SaveData {
+        version: "1.0".to_string(),
+        metadata: SaveMetadata {
+            created: chrono::Utc::now().to_rfc3339(),
+            modified: chrono::Utc::now().to_rfc3339(),
+            creator: "Nōdle 1.0".to_string(),
+            description: description.to_string(),
+        },
+        viewport: CanvasData {
+            pan_offset: [canvas.pan_offset.x, canvas.pan_offset.y],
+            zoom: canvas.zoom,
+        },
+        root_graph: graph.clone(),
+        panel_layout: panel_layout.clone(),
+    }
+}
+
+/// Write a graph, its canvas transform and panel layout as a save file
+/// (compressed when the path has the `.nodlez` extension)
+pub(crate) fn write_save_data(file_path: &Path, graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout, description: &str) -> Result<(), String> {
+    let save_data = build_save_data(graph, canvas, panel_layout, description);
+
+    let content = match SaveFormat::from_path(file_path) {
+        SaveFormat::Json => serde_json::to_vec_pretty(&save_data)
+            .map_err(|e| format!("Failed to serialize save data: {}", e))?,
+        SaveFormat::Compressed => {
+            let json_content = serde_json::to_vec(&save_data)
+                .map_err(|e| format!("Failed to serialize save data: {}", e))?;
+            let compressed = zstd::encode_all(json_content.as_slice(), COMPRESSION_LEVEL)
+                .map_err(|e| format!("Failed to compress save data: {}", e))?;
+            [COMPRESSED_MAGIC, compressed.as_slice()].concat()
+        }
+    };
+
+    std::fs::write(file_path, content)
+        .map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Read a save file back into a graph, canvas and panel layout
+pub(crate) fn read_save_data(file_path: &Path) -> Result<(NodeGraph, Canvas, PanelLayout), String> {
+    let file_content = std::fs::read(file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    // Detect the format from the contents so renamed files still load
+    let json_content = match SaveFormat::detect(&file_content) {
+        SaveFormat::Json => file_content,
+        SaveFormat::Compressed => zstd::decode_all(&file_content[COMPRESSED_MAGIC.len()..])
+            .map_err(|e| format!("Failed to decompress save file: {}", e))?,
+    };
+
+    let save_data: SaveData = serde_json::from_slice(&json_content)
+        .map_err(|e| format!("Failed to parse save file: {}", e))?;
+
+    // Create canvas from saved data
+    let mut canvas = Canvas::new();
+    canvas.pan_offset = egui::Vec2::new(
+        save_data.viewport.pan_offset[0], 
+        save_data.viewport.pan_offset[1]
+    );
+    canvas.zoom = save_data.viewport.zoom;
+
+    let mut graph = save_data.root_graph;
+    crate::nodes::three_d::lighting::usd_light::upgrade_legacy_lights(&mut graph);
+
+    Ok((graph, canvas, save_data.panel_layout))
+}
+
 /// Manages file operations for the node editor
 pub struct FileManager {
     /// Current file path (None if unsaved/new file)
     current_file_path: Option<PathBuf>,
     /// Whether the file has been modified since last save
     is_modified: bool,
+    /// Most recently used project files
+    recent_files: RecentFiles,
+    /// Where the recent files list is persisted (None keeps it in memory only)
+    recent_files_path: Option<PathBuf>,
+    /// Directory scanned for user templates (None disables user templates)
+    templates_dir: Option<PathBuf>,
 }
 
 impl FileManager {
-    /// Create a new file manager
+    /// Create a new file manager, loading the recent files list from the user's home directory
     pub fn new() -> Self {
+        Self::with_storage_dir(default_storage_dir())
+    }
+    
+    /// Create a file manager keeping its recent files list and templates under `dir`
+    /// (None keeps everything in memory and offers only the built-in templates)
+    pub fn with_storage_dir(dir: Option<PathBuf>) -> Self {
+        let path = dir.as_ref().map(|dir| dir.join("recent_files.json"));
+        let recent_files = path.as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
         Self {
             current_file_path: None,
             is_modified: false,
+            recent_files,
+            recent_files_path: path,
+            templates_dir: dir.map(|dir| dir.join("templates")),
+        }
+    }
+    
+    /// Recently opened or saved project files, most recent first
+    pub fn recent_files(&self) -> &[PathBuf] {
+        &self.recent_files.files
+    }
+    
+    /// Move a file to the front of the recent files list
+    pub fn add_recent_file(&mut self, path: &Path) {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.recent_files.files.retain(|existing| *existing != path);
+        self.recent_files.files.insert(0, path);
+        self.recent_files.files.truncate(MAX_RECENT_FILES);
+        self.persist_recent_files();
+    }
+    
+    /// Drop a file from the recent files list (e.g. it no longer exists)
+    pub fn remove_recent_file(&mut self, path: &Path) {
+        self.recent_files.files.retain(|existing| existing != path);
+        self.persist_recent_files();
+    }
+    
+    /// Forget all recent files
+    pub fn clear_recent_files(&mut self) {
+        self.recent_files.files.clear();
+        self.persist_recent_files();
+    }
+    
+    /// Whether the startup screen should be shown when the editor opens
+    pub fn show_startup_screen(&self) -> bool {
+        self.recent_files.show_startup_screen
+    }
+    
+    /// Enable or disable the startup screen
+    pub fn set_show_startup_screen(&mut self, show: bool) {
+        if self.recent_files.show_startup_screen != show {
+            self.recent_files.show_startup_screen = show;
+            self.persist_recent_files();
+        }
+    }
+    
+    /// Built-in templates followed by the templates found in the templates directory
+    pub fn templates(&self) -> Vec<ProjectTemplate> {
+        let mut found: Vec<ProjectTemplate> = self.templates_dir.as_ref()
+            .and_then(|dir| std::fs::read_dir(dir).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_string_lossy().to_string();
+                Some(ProjectTemplate { name, source: TemplateSource::File(path) })
+            })
+            .collect();
+        found.sort_by(|a, b| a.name.cmp(&b.name));
+
+        templates::BUILTIN_TEMPLATES.iter()
+            .map(|name| ProjectTemplate::builtin(name))
+            .chain(found)
+            .collect()
+    }
+    
+    /// Save a graph into the templates directory, returning the template file path
+    pub fn save_as_template(&self, name: &str, graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout) -> Result<PathBuf, String> {
+        let dir = self.templates_dir.as_ref()
+            .ok_or_else(|| "No templates directory available".to_string())?;
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create templates directory: {}", e))?;
+        let path = dir.join(format!("{}.json", templates::template_file_stem(name)));
+        write_save_data(&path, graph, canvas, panel_layout, &format!("Nōdle template: {}", name.trim()))?;
+        Ok(path)
+    }
+    
+    /// Start a new, unsaved project from a template
+    pub fn new_from_template(&mut self, template: &ProjectTemplate) -> Result<(NodeGraph, Canvas, PanelLayout), String> {
+        let loaded = match &template.source {
+            TemplateSource::BuiltIn => templates::build_builtin_template(&template.name)
+                .map(|graph| (graph, Canvas::new(), PanelLayout::default()))
+                .ok_or_else(|| format!("Unknown built-in template: {}", template.name))?,
+            TemplateSource::File(path) => read_save_data(path)?,
+        };
+
+        // The template itself is never overwritten by Save
+        self.current_file_path = None;
+        self.is_modified = true;
+
+        Ok(loaded)
+    }
+    
+    /// Write the recent files list to disk (failures are logged, never fatal)
+    fn persist_recent_files(&self) {
+        let Some(path) = &self.recent_files_path else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                log::warn!("Failed to create {}: {}", parent.display(), e);
+                return;
+            }
+        }
+        match serde_json::to_string_pretty(&self.recent_files) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(path, json) {
+                    log::warn!("Failed to write recent files list: {}", e);
+                }
+            }
+            Err(e) => log::warn!("Failed to serialize recent files list: {}", e),
         }
     }
 
@@ -100,77 +375,50 @@ impl FileManager {
     }
 
     /// Save the current graph to a file
-    pub fn save_to_file(&mut self, file_path: &Path, graph: &NodeGraph, canvas: &Canvas) -> Result<(), String> {
-        let save_data = SaveData {
-            version: "1.0".to_string(),
-            metadata: SaveMetadata {
-                created: chrono::Utc::now().to_rfc3339(),
-                modified: chrono::Utc::now().to_rfc3339(),
-                creator: "Nōdle 1.0".to_string(),
-                description: "Node graph created with Nōdle".to_string(),
-            },
-            viewport: CanvasData {
-                pan_offset: [canvas.pan_offset.x, canvas.pan_offset.y],
-                zoom: canvas.zoom,
-            },
-            root_graph: graph.clone(),
-        };
-
-        let json_content = serde_json::to_string_pretty(&save_data)
-            .map_err(|e| format!("Failed to serialize save data: {}", e))?;
-
-        std::fs::write(file_path, json_content)
-            .map_err(|e| format!("Failed to write file: {}", e))?;
+    pub fn save_to_file(&mut self, file_path: &Path, graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout) -> Result<(), String> {
+        write_save_data(file_path, graph, canvas, panel_layout, "Node graph created with Nōdle")?;
 
         // Update file manager state
         self.current_file_path = Some(file_path.to_path_buf());
         self.is_modified = false;
+        self.add_recent_file(file_path);
 
         Ok(())
     }
 
-    /// Load a graph from a file
-    pub fn load_from_file(&mut self, file_path: &Path) -> Result<(NodeGraph, Canvas), String> {
-        let file_content = std::fs::read_to_string(file_path)
-            .map_err(|e| format!("Failed to read file: {}", e))?;
-
-        let save_data: SaveData = serde_json::from_str(&file_content)
-            .map_err(|e| format!("Failed to parse save file: {}", e))?;
-
-        // Create canvas from saved data
-        let mut canvas = Canvas::new();
-        canvas.pan_offset = egui::Vec2::new(
-            save_data.viewport.pan_offset[0], 
-            save_data.viewport.pan_offset[1]
-        );
-        canvas.zoom = save_data.viewport.zoom;
+    /// Load a graph, its canvas transform and panel layout from a file
+    pub fn load_from_file(&mut self, file_path: &Path) -> Result<(NodeGraph, Canvas, PanelLayout), String> {
+        let loaded = read_save_data(file_path)?;
 
         // Update file manager state
         self.current_file_path = Some(file_path.to_path_buf());
         self.is_modified = false;
+        self.add_recent_file(file_path);
 
-        Ok((save_data.root_graph, canvas))
+        Ok(loaded)
     }
 
     /// Save the current file (use existing path or prompt for new path)
-    pub fn save_file(&mut self, graph: &NodeGraph, canvas: &Canvas) -> Result<(), String> {
+    pub fn save_file(&mut self, graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout) -> Result<(), String> {
         if let Some(path) = &self.current_file_path.clone() {
-            self.save_to_file(path, graph, canvas)
+            self.save_to_file(path, graph, canvas, panel_layout)
         } else {
             Err("No file path set. Use save_as instead.".to_string())
         }
     }
 
     /// Open file dialog and load selected file
-    pub fn open_file_dialog(&mut self) -> Result<Option<(NodeGraph, Canvas)>, String> {
+    pub fn open_file_dialog(&mut self) -> Result<Option<(NodeGraph, Canvas, PanelLayout)>, String> {
         use rfd::FileDialog;
         
         if let Some(path) = FileDialog::new()
+            .add_filter("Nōdle files", &["json", "nodlez"])
             .add_filter("JSON files", &["json"])
+            .add_filter("Compressed files", &["nodlez"])
             .pick_file()
         {
             match self.load_from_file(&path) {
-                Ok((graph, canvas)) => Ok(Some((graph, canvas))),
+                Ok(loaded) => Ok(Some(loaded)),
                 Err(error) => Err(error),
             }
         } else {
@@ -179,14 +427,16 @@ impl FileManager {
     }
 
     /// Save as file dialog
-    pub fn save_as_file_dialog(&mut self, graph: &NodeGraph, canvas: &Canvas) -> Result<bool, String> {
+    pub fn save_as_file_dialog(&mut self, graph: &NodeGraph, canvas: &Canvas, panel_layout: &PanelLayout) -> Result<bool, String> {
         use rfd::FileDialog;
         
+        // The format follows the chosen extension (see `SaveFormat::from_path`)
         if let Some(path) = FileDialog::new()
             .add_filter("JSON files", &["json"])
+            .add_filter("Compressed files", &["nodlez"])
             .save_file()
         {
-            match self.save_to_file(&path, graph, canvas) {
+            match self.save_to_file(&path, graph, canvas, panel_layout) {
                 Ok(()) => Ok(true),
                 Err(error) => Err(error),
             }
@@ -196,8 +446,237 @@ impl FileManager {
     }
 }
 
+/// Extension of standalone subgraph files referenced by workspace nodes
+pub const SUBGRAPH_EXTENSION: &str = "nodlesub";
+
+/// A workspace node saved on its own so other graphs can reference it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubgraphAsset {
+    pub version: String,
+    /// Workspace node with its internal graph, external ports and port mappings
+    pub node: Node,
+}
+
+/// Save a workspace node's subgraph to its own file
+pub fn save_subgraph(node: &Node, file_path: &Path) -> Result<(), String> {
+    if !node.is_workspace() {
+        return Err(format!("'{}' is not a workspace node", node.title));
+    }
+    let mut node = node.clone();
+    // The asset itself is always embedded; references are recorded by the graphs using it
+    let _ = node.set_reference(None);
+    let asset = SubgraphAsset {
+        version: "1.0".to_string(),
+        node,
+    };
+    let json_content = serde_json::to_string_pretty(&asset)
+        .map_err(|e| format!("Failed to serialize subgraph: {}", e))?;
+    std::fs::write(file_path, json_content)
+        .map_err(|e| format!("Failed to write subgraph: {}", e))
+}
+
+/// Resolve a reference path; relative references are relative to the referencing project's folder
+pub fn resolve_reference_path(reference: &str, base_dir: Option<&Path>) -> PathBuf {
+    let path = PathBuf::from(reference);
+    match base_dir {
+        Some(base_dir) if path.is_relative() => base_dir.join(path),
+        _ => path,
+    }
+}
+
+/// Path to store in a reference: relative to `base_dir` when the file lives beneath it
+pub fn reference_path_for(file_path: &Path, base_dir: Option<&Path>) -> String {
+    base_dir
+        .and_then(|base_dir| file_path.strip_prefix(base_dir).ok())
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Create a workspace node referencing the subgraph stored at `file_path`
+pub fn create_reference_node(file_path: &Path, base_dir: Option<&Path>, position: egui::Pos2) -> Result<Node, String> {
+    let mut node = load_subgraph(file_path)?;
+    node.position = position;
+    node.set_reference(Some(reference_path_for(file_path, base_dir)))
+        .map_err(|e| e.to_string())?;
+    node.update_port_positions();
+    Ok(node)
+}
+
+/// Read the workspace node stored in a subgraph file
+fn load_subgraph(file_path: &Path) -> Result<Node, String> {
+    let file_content = std::fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read subgraph {}: {}", file_path.display(), e))?;
+    let asset: SubgraphAsset = serde_json::from_str(&file_content)
+        .map_err(|e| format!("Failed to parse subgraph {}: {}", file_path.display(), e))?;
+    if !asset.node.is_workspace() {
+        return Err(format!("{} does not contain a workspace node", file_path.display()));
+    }
+    Ok(asset.node)
+}
+
+/// Refresh every referencing workspace node in `graph` from its subgraph file.
+/// Nested embedded workspaces are searched too. Connections to ports that no longer
+/// exist are dropped. Returns one message per reference that failed to load.
+pub fn reload_references(graph: &mut NodeGraph, base_dir: Option<&Path>) -> Vec<String> {
+    let mut errors = Vec::new();
+    for node in graph.nodes.values_mut() {
+        match node.get_reference().map(str::to_string) {
+            Some(reference) => {
+                let path = resolve_reference_path(&reference, base_dir);
+                match load_subgraph(&path) {
+                    Ok(mut asset_node) => {
+                        let _ = asset_node.set_reference(Some(reference));
+                        node.node_type = asset_node.node_type;
+                        node.inputs = asset_node.inputs;
+                        node.outputs = asset_node.outputs;
+                        node.update_port_positions();
+                    }
+                    Err(error) => errors.push(error),
+                }
+            }
+            None => {
+                if let Some(internal_graph) = node.get_internal_graph_mut() {
+                    errors.extend(reload_references(internal_graph, base_dir));
+                }
+            }
+        }
+    }
+
+    // Ports may have been added or removed in the referenced file
+    let nodes = &graph.nodes;
+    graph.connections.retain(|connection| {
+        nodes.get(&connection.from_node).is_some_and(|node| connection.from_port < node.outputs.len())
+            && nodes.get(&connection.to_node).is_some_and(|node| connection.to_port < node.inputs.len())
+    });
+    errors
+}
+
 impl Default for FileManager {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::interface::{InterfacePanelManager, PanelType};
+
+    #[test]
+    fn test_panel_layout_round_trip() {
+        let mut panels = InterfacePanelManager::new();
+        panels.set_panel_type(7, PanelType::Tree);
+        panels.set_panel_visibility(7, true);
+        panels.set_panel_stacked(7, false);
+        panels.set_panel_rect(7, egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(300.0, 400.0)));
+
+        let path = std::env::temp_dir().join(format!("nodle_layout_test_{}.json", std::process::id()));
+        let mut file_manager = FileManager::with_storage_dir(None);
+        file_manager.save_to_file(&path, &NodeGraph::new(), &Canvas::new(), &panels.export_layout()).unwrap();
+        let (_, _, layout) = file_manager.load_from_file(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let mut restored = InterfacePanelManager::new();
+        restored.import_layout(&layout);
+        assert!(restored.is_panel_visible(7));
+        assert!(!restored.is_panel_stacked(7));
+        let rect = restored.take_pending_rect(7).unwrap();
+        assert_eq!(rect.min, egui::pos2(10.0, 20.0));
+        // Saved rects are applied only once
+        assert!(restored.take_pending_rect(7).is_none());
+    }
+
+    #[test]
+    fn test_compressed_save_round_trips_like_json() {
+        let mut graph = NodeGraph::new();
+        let a = graph.add_node(crate::nodes::Node::new(0, "A", egui::Pos2::new(1.0, 2.0)));
+        let b = graph.add_node(crate::nodes::Node::new(0, "B", egui::Pos2::new(3.0, 4.0)));
+        graph.add_connection_by_ids(a, 0, b, 0).unwrap();
+
+        let base = std::env::temp_dir().join(format!("nodle_format_test_{}", std::process::id()));
+        let json_path = base.with_extension("json");
+        let compressed_path = base.with_extension("nodlez");
+        let mut file_manager = FileManager::with_storage_dir(None);
+        file_manager.save_to_file(&json_path, &graph, &Canvas::new(), &PanelLayout::default()).unwrap();
+        file_manager.save_to_file(&compressed_path, &graph, &Canvas::new(), &PanelLayout::default()).unwrap();
+
+        let compressed_bytes = std::fs::read(&compressed_path).unwrap();
+        let (from_json, _, _) = file_manager.load_from_file(&json_path).unwrap();
+        let (from_compressed, _, _) = file_manager.load_from_file(&compressed_path).unwrap();
+        let _ = std::fs::remove_file(&json_path);
+        let _ = std::fs::remove_file(&compressed_path);
+
+        assert_eq!(SaveFormat::detect(&compressed_bytes), SaveFormat::Compressed);
+        assert_eq!(
+            serde_json::to_value(&from_json).unwrap(),
+            serde_json::to_value(&from_compressed).unwrap()
+        );
+        assert_eq!(from_compressed.connections.len(), 1);
+    }
+
+    #[test]
+    fn test_reload_reference_picks_up_subgraph_changes() {
+        let dir = std::env::temp_dir().join(format!("nodle_subgraph_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let asset_path = dir.join("shader.nodlesub");
+
+        let mut workspace = crate::nodes::Node::new_workspace(0, "MaterialX", egui::Pos2::ZERO);
+        workspace.add_output("Surface");
+        save_subgraph(&workspace, &asset_path).unwrap();
+
+        let mut graph = NodeGraph::new();
+        let reference = graph.add_node(create_reference_node(&asset_path, Some(&dir), egui::Pos2::new(50.0, 50.0)).unwrap());
+        let consumer = graph.add_node(crate::nodes::Node::new(0, "Consumer", egui::Pos2::ZERO));
+        graph.nodes.get_mut(&consumer).unwrap().add_input("In");
+        graph.add_connection_by_ids(reference, 0, consumer, 0).unwrap();
+        assert_eq!(graph.nodes[&reference].get_reference(), Some("shader.nodlesub"));
+
+        // The shared asset changes: an internal node is added and the output removed
+        let mut updated = crate::nodes::Node::new_workspace(0, "MaterialX", egui::Pos2::ZERO);
+        updated.get_internal_graph_mut().unwrap().add_node(crate::nodes::Node::new(0, "Inner", egui::Pos2::ZERO));
+        save_subgraph(&updated, &asset_path).unwrap();
+
+        let errors = reload_references(&mut graph, Some(&dir));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(errors.is_empty());
+        let reloaded = &graph.nodes[&reference];
+        assert_eq!(reloaded.get_internal_graph().unwrap().nodes.len(), 1);
+        assert_eq!(reloaded.position, egui::Pos2::new(50.0, 50.0));
+        assert_eq!(reloaded.get_reference(), Some("shader.nodlesub"));
+        assert!(graph.connections.is_empty());
+    }
+
+    #[test]
+    fn test_recent_files_most_recent_first_without_duplicates() {
+        let mut file_manager = FileManager::with_storage_dir(None);
+        for index in 0..(MAX_RECENT_FILES + 2) {
+            file_manager.add_recent_file(Path::new(&format!("/tmp/nodle_missing_{}.json", index)));
+        }
+        file_manager.add_recent_file(Path::new("/tmp/nodle_missing_5.json"));
+        let recent = file_manager.recent_files();
+        assert_eq!(recent.len(), MAX_RECENT_FILES);
+        assert_eq!(recent[0], PathBuf::from("/tmp/nodle_missing_5.json"));
+        assert_eq!(recent.iter().filter(|p| p.ends_with("nodle_missing_5.json")).count(), 1);
+    }
+
+    #[test]
+    fn test_saved_template_is_discovered_and_starts_unsaved_project() {
+        let storage_dir = std::env::temp_dir().join(format!("nodle_templates_test_{}", std::process::id()));
+        let mut file_manager = FileManager::with_storage_dir(Some(storage_dir.clone()));
+
+        let mut graph = NodeGraph::new();
+        graph.add_node(crate::nodes::Node::new(0, "Saved", egui::Pos2::new(10.0, 20.0)));
+        file_manager.save_as_template("Team Setup", &graph, &Canvas::new(), &PanelLayout::default())
+            .expect("save template");
+
+        let templates = file_manager.templates();
+        let template = templates.iter().find(|t| t.name == "Team Setup").expect("template listed");
+        let (loaded, _, _) = file_manager.new_from_template(template).expect("instantiate template");
+        let _ = std::fs::remove_dir_all(&storage_dir);
+
+        assert_eq!(loaded.nodes.len(), 1);
+        assert!(file_manager.current_file_path().is_none());
+        assert!(templates.iter().any(|t| t.source == TemplateSource::BuiltIn));
+    }
+}
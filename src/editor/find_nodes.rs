@@ -0,0 +1,92 @@
+//! Find Nodes dialog
+//!
+//! Runs a node query (see `nodes::node_query`) against the viewed graph as it
+//! is typed, lists the matches and selects them, optionally framing the view
+//! on them.
+
+use eframe::egui;
+use crate::nodes::node_query::{find_nodes, NodeQuery};
+use crate::nodes::{NodeGraph, NodeId};
+
+/// Most matches listed by title; all of them are still selected
+const MAX_LISTED: usize = 200;
+
+/// What to do with the matches
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindSelection {
+    pub nodes: Vec<NodeId>,
+    /// Pan and zoom the canvas onto the nodes
+    pub frame: bool,
+}
+
+/// Query text and options of the dialog
+#[derive(Debug)]
+pub struct FindNodesDialog {
+    query: String,
+    frame_matches: bool,
+    add_to_selection: bool,
+}
+
+impl Default for FindNodesDialog {
+    fn default() -> Self {
+        Self { query: String::new(), frame_matches: true, add_to_selection: false }
+    }
+}
+
+impl FindNodesDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether matches are added to the selection instead of replacing it
+    pub fn adds_to_selection(&self) -> bool {
+        self.add_to_selection
+    }
+
+    /// Render the dialog; `failed` tells whether a node's last cook failed
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool, graph: &NodeGraph, failed: &dyn Fn(NodeId) -> bool) -> Option<FindSelection> {
+        let mut selection = None;
+        egui::Window::new("Find Nodes")
+            .open(open)
+            .default_pos([80.0, 120.0])
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                let field = ui.add(egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("type:sphere radius > 2 tag:hero is:error")
+                    .desired_width(f32::INFINITY));
+                ui.weak("Terms: type:, title: or plain text, tag:, is:error, <parameter> <op> <value>");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.frame_matches, "Frame matches");
+                    ui.checkbox(&mut self.add_to_selection, "Add to selection");
+                });
+                ui.separator();
+
+                let matches = match NodeQuery::parse(&self.query) {
+                    Ok(query) => find_nodes(graph, &query, failed),
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(230, 120, 80), e);
+                        return;
+                    }
+                };
+                let select_all = field.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} of {} nodes", matches.len(), graph.nodes.len()));
+                    if (ui.add_enabled(!matches.is_empty(), egui::Button::new("Select All")).clicked() || select_all) && !matches.is_empty() {
+                        selection = Some(FindSelection { nodes: matches.clone(), frame: self.frame_matches });
+                    }
+                });
+                egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                    for node_id in matches.iter().take(MAX_LISTED) {
+                        let node = &graph.nodes[node_id];
+                        if ui.selectable_label(false, format!("{}  ({})", node.title, node.type_id)).clicked() {
+                            selection = Some(FindSelection { nodes: vec![*node_id], frame: self.frame_matches });
+                        }
+                    }
+                    if matches.len() > MAX_LISTED {
+                        ui.weak(format!("… and {} more", matches.len() - MAX_LISTED));
+                    }
+                });
+            });
+        selection
+    }
+}
@@ -0,0 +1,116 @@
+//! Graph report window
+//!
+//! Shows a [`GraphReport`] of the active graph: node counts per type,
+//! connections, the memory held by cached outputs and the viewport's GPU
+//! memory. The report is a snapshot taken when the window opens or is
+//! refreshed, and can be saved as JSON.
+
+use eframe::egui;
+use egui::RichText;
+use crate::nodes::{NodeGraph, NodeGraphEngine};
+use crate::nodes::graph_stats::{format_bytes, GraphReport};
+
+/// Heaviest nodes listed in the window
+const MAX_LISTED_NODES: usize = 50;
+
+/// Report window state
+#[derive(Debug, Default)]
+pub struct GraphReportPanel {
+    report: Option<GraphReport>,
+}
+
+impl GraphReportPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the report window; `open` is cleared when the window is closed
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool, graph: &NodeGraph, engine: &NodeGraphEngine) {
+        if !*open {
+            // Take a fresh snapshot next time the window opens
+            self.report = None;
+            return;
+        }
+        let report = self.report.get_or_insert_with(|| {
+            GraphReport::build(graph, engine, crate::gpu::viewport_3d_callback::estimated_gpu_memory())
+        });
+        let mut refresh = false;
+        let mut export = false;
+        egui::Window::new("Graph Report")
+            .open(open)
+            .default_size([440.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("⟳ Refresh").clicked() {
+                        refresh = true;
+                    }
+                    if ui.button("Export JSON...").clicked() {
+                        export = true;
+                    }
+                    ui.label(RichText::new(format!("Taken {}", report.generated_at.format("%H:%M:%S"))).weak());
+                });
+                ui.separator();
+
+                egui::Grid::new("graph_report_summary").num_columns(2).show(ui, |ui| {
+                    ui.label("Nodes");
+                    ui.label(report.node_count.to_string());
+                    ui.end_row();
+                    ui.label("Connections");
+                    ui.label(report.connection_count.to_string());
+                    ui.end_row();
+                    ui.label("Cached data");
+                    ui.label(format_bytes(report.total_cached_bytes));
+                    ui.end_row();
+                    ui.label("Viewport GPU memory");
+                    ui.label(report.gpu_bytes.map_or_else(|| "no viewport drawn".to_string(), |bytes| format_bytes(bytes as usize)));
+                    ui.end_row();
+                });
+
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    egui::CollapsingHeader::new(format!("Node types ({})", report.node_types.len()))
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            egui::Grid::new("graph_report_types").num_columns(3).striped(true).show(ui, |ui| {
+                                for node_type in &report.node_types {
+                                    ui.label(&node_type.type_id);
+                                    ui.label(format!("× {}", node_type.count));
+                                    ui.label(format_bytes(node_type.cached_bytes));
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                    egui::CollapsingHeader::new("Cached data per node")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            egui::Grid::new("graph_report_nodes").num_columns(4).striped(true).show(ui, |ui| {
+                                for node in report.nodes.iter().take(MAX_LISTED_NODES) {
+                                    ui.label(&node.title).on_hover_text(format!("{} (node {})", node.type_id, node.node_id));
+                                    ui.label(format_bytes(node.cached_bytes));
+                                    ui.label(RichText::new(format!("{} outputs", node.cached_outputs)).weak());
+                                    ui.label(RichText::new(format!("{} in / {} out", node.incoming_connections, node.outgoing_connections)).weak());
+                                    ui.end_row();
+                                }
+                            });
+                            if report.nodes.len() > MAX_LISTED_NODES {
+                                ui.label(RichText::new(format!("… {} more in the JSON export", report.nodes.len() - MAX_LISTED_NODES)).weak());
+                            }
+                        });
+                });
+            });
+
+        if export {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("JSON", &["json"])
+                .set_file_name("graph_report.json")
+                .save_file()
+            {
+                if let Err(e) = report.save_json(&path) {
+                    log::error!("{}", e);
+                }
+            }
+        }
+        if refresh {
+            self.report = None;
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Spacebar hotbox for quick node creation
+//!
+//! Holding space opens a radial menu around the cursor with the current
+//! workspace's top-level menu entries. Moving the cursor towards an entry
+//! selects it and lists its nodes, most used first; clicking a node, or
+//! releasing space while hovering it, creates it at the hotbox centre.
+
+use eframe::egui;
+use egui::{Color32, Id, Key, LayerId, Order, Pos2, Rect, Stroke, Vec2};
+use crate::workspace::WorkspaceMenuItem;
+
+/// Distance of the entry labels from the hotbox centre
+const RING_RADIUS: f32 = 110.0;
+
+/// Pointer movement inside this radius selects nothing
+const DEAD_ZONE_RADIUS: f32 = 30.0;
+
+/// Entries beyond this many are left to the right-click menu
+const MAX_SECTORS: usize = 12;
+
+/// Nodes listed per entry
+const MAX_NODES_PER_SECTOR: usize = 20;
+
+/// What the user picked in the hotbox
+#[derive(Debug, Clone, PartialEq)]
+pub enum HotboxAction {
+    None,
+    /// Create a node of this type at the given screen position
+    CreateNode(String, Pos2),
+    /// Enter the named sub-workspace
+    EnterWorkspace(String),
+}
+
+/// One entry around the ring
+#[derive(Debug, Clone)]
+struct Sector {
+    label: String,
+    /// Picked directly when the entry itself is chosen (single nodes, workspaces)
+    direct: Option<HotboxAction>,
+    /// (display name, node type) listed when the entry is a category
+    nodes: Vec<(String, String)>,
+}
+
+/// Radial quick-create menu
+#[derive(Default)]
+pub struct Hotbox {
+    center: Option<Pos2>,
+    sectors: Vec<Sector>,
+    active: Option<usize>,
+    /// Node list shown last frame; keeps its sector active while the pointer is over it
+    list_rect: Option<Rect>,
+}
+
+impl Hotbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.center.is_some()
+    }
+
+    /// Whether space was just pressed over the canvas (not while typing)
+    pub fn open_requested(ctx: &egui::Context) -> bool {
+        !ctx.wants_keyboard_input() && ctx.input(|input| input.key_pressed(Key::Space) && !input.modifiers.any())
+    }
+
+    /// Open around `center` with the workspace's menu; `usage` ranks nodes within an entry
+    pub fn open(&mut self, center: Pos2, menu: Vec<WorkspaceMenuItem>, usage: impl Fn(&str) -> u64) {
+        self.sectors = menu.into_iter()
+            .take(MAX_SECTORS)
+            .map(|item| match item {
+                WorkspaceMenuItem::Category { name, items } => {
+                    let mut nodes = Vec::new();
+                    collect_nodes(&items, &mut nodes);
+                    // Stable sort keeps the menu order among equally used nodes
+                    nodes.sort_by_key(|(_, node_type)| std::cmp::Reverse(usage(node_type)));
+                    nodes.truncate(MAX_NODES_PER_SECTOR);
+                    Sector { label: name, direct: None, nodes }
+                }
+                WorkspaceMenuItem::Node { name, node_type } => Sector {
+                    label: name,
+                    direct: Some(HotboxAction::CreateNode(node_type, center)),
+                    nodes: Vec::new(),
+                },
+                WorkspaceMenuItem::Workspace { name, .. } => Sector {
+                    label: name.clone(),
+                    direct: Some(HotboxAction::EnterWorkspace(name)),
+                    nodes: Vec::new(),
+                },
+            })
+            .collect();
+        self.center = Some(center);
+        self.active = None;
+        self.list_rect = None;
+    }
+
+    pub fn close(&mut self) {
+        self.center = None;
+        self.sectors.clear();
+        self.active = None;
+        self.list_rect = None;
+    }
+
+    /// Screen position of sector `index`'s label; the first entry sits at the top
+    fn sector_position(&self, center: Pos2, index: usize) -> Pos2 {
+        let angle = index as f32 / self.sectors.len() as f32 * std::f32::consts::TAU - std::f32::consts::FRAC_PI_2;
+        center + Vec2::angled(angle) * RING_RADIUS
+    }
+
+    /// Sector the pointer is heading towards, None inside the dead zone
+    fn sector_at(&self, center: Pos2, pointer: Pos2) -> Option<usize> {
+        let offset = pointer - center;
+        if offset.length() < DEAD_ZONE_RADIUS || self.sectors.is_empty() {
+            return None;
+        }
+        let step = std::f32::consts::TAU / self.sectors.len() as f32;
+        let angle = (offset.angle() + std::f32::consts::FRAC_PI_2).rem_euclid(std::f32::consts::TAU);
+        Some(((angle + step / 2.0) / step) as usize % self.sectors.len())
+    }
+
+    /// Draw the hotbox and handle input; call every frame while open
+    pub fn render(&mut self, ctx: &egui::Context) -> HotboxAction {
+        let Some(center) = self.center else {
+            return HotboxAction::None;
+        };
+        let (pointer, space_down, escape) = ctx.input(|input| {
+            (input.pointer.hover_pos(), input.key_down(Key::Space), input.key_pressed(Key::Escape))
+        });
+        if escape {
+            self.close();
+            return HotboxAction::None;
+        }
+
+        if let Some(pointer) = pointer {
+            let over_list = self.list_rect.is_some_and(|rect| rect.expand(8.0).contains(pointer));
+            if !over_list {
+                self.active = self.sector_at(center, pointer);
+            }
+        }
+
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("hotbox_ring")));
+        painter.circle_stroke(center, DEAD_ZONE_RADIUS, Stroke::new(1.5, Color32::from_gray(140)));
+        for (index, sector) in self.sectors.iter().enumerate() {
+            let position = self.sector_position(center, index);
+            let is_active = self.active == Some(index);
+            let galley = painter.layout_no_wrap(sector.label.clone(), egui::FontId::proportional(14.0), Color32::WHITE);
+            let rect = Rect::from_center_size(position, galley.size() + Vec2::new(16.0, 8.0));
+            let fill = if is_active { Color32::from_rgb(88, 166, 255) } else { Color32::from_rgba_unmultiplied(40, 40, 40, 235) };
+            painter.rect_filled(rect, 10.0, fill);
+            painter.rect_stroke(rect, 10.0, Stroke::new(1.0, Color32::from_gray(90)), egui::StrokeKind::Inside);
+            painter.galley(rect.center() - galley.size() / 2.0, galley, Color32::WHITE);
+        }
+
+        // Node list of the active category, placed on the side away from the centre
+        let mut clicked = None;
+        let mut hovered = None;
+        self.list_rect = None;
+        if let Some(index) = self.active {
+            let sector = &self.sectors[index];
+            if !sector.nodes.is_empty() {
+                let anchor = self.sector_position(center, index);
+                let to_right = anchor.x >= center.x;
+                let pivot = if to_right { egui::Align2::LEFT_CENTER } else { egui::Align2::RIGHT_CENTER };
+                let offset = Vec2::new(if to_right { 60.0 } else { -60.0 }, 0.0);
+                let response = egui::Area::new(Id::new("hotbox_nodes"))
+                    .order(Order::Foreground)
+                    .fixed_pos(anchor + offset)
+                    .pivot(pivot)
+                    .show(ctx, |ui| {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            for (name, node_type) in &sector.nodes {
+                                let item = ui.add(egui::Button::new(name.as_str()).frame(false));
+                                if item.clicked() {
+                                    clicked = Some(node_type.clone());
+                                }
+                                if item.hovered() {
+                                    hovered = Some(node_type.clone());
+                                }
+                            }
+                        });
+                    });
+                self.list_rect = Some(response.response.rect);
+            }
+        }
+        ctx.request_repaint();
+
+        let action = if let Some(node_type) = clicked {
+            HotboxAction::CreateNode(node_type, center)
+        } else if !space_down {
+            // Releasing space picks what is under the pointer
+            match (hovered, self.active) {
+                (Some(node_type), _) => HotboxAction::CreateNode(node_type, center),
+                (None, Some(index)) => self.sectors[index].direct.clone().unwrap_or(HotboxAction::None),
+                (None, None) => HotboxAction::None,
+            }
+        } else {
+            return HotboxAction::None;
+        };
+        self.close();
+        action
+    }
+}
+
+/// Flatten nested categories into (display name, node type) pairs
+fn collect_nodes(items: &[WorkspaceMenuItem], nodes: &mut Vec<(String, String)>) {
+    for item in items {
+        match item {
+            WorkspaceMenuItem::Category { items, .. } => collect_nodes(items, nodes),
+            WorkspaceMenuItem::Node { name, node_type } => nodes.push((name.clone(), node_type.clone())),
+            WorkspaceMenuItem::Workspace { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str) -> WorkspaceMenuItem {
+        WorkspaceMenuItem::Node { name: name.to_string(), node_type: name.to_string() }
+    }
+
+    #[test]
+    fn test_sectors_follow_pointer_direction_and_rank_by_usage() {
+        let menu = vec![
+            WorkspaceMenuItem::Category { name: "Math".to_string(), items: vec![node("Add"), node("Multiply")] },
+            node("Print"),
+            node("Viewport"),
+            node("Null"),
+        ];
+        let mut hotbox = Hotbox::new();
+        let center = Pos2::new(500.0, 500.0);
+        hotbox.open(center, menu, |node_type| if node_type == "Multiply" { 5 } else { 0 });
+
+        assert_eq!(hotbox.sectors[0].nodes[0].1, "Multiply");
+        // First entry is above the centre, then clockwise
+        assert_eq!(hotbox.sector_at(center, center + Vec2::new(0.0, -80.0)), Some(0));
+        assert_eq!(hotbox.sector_at(center, center + Vec2::new(80.0, 0.0)), Some(1));
+        assert_eq!(hotbox.sector_at(center, center + Vec2::new(-80.0, 5.0)), Some(3));
+        assert_eq!(hotbox.sector_at(center, center + Vec2::new(5.0, 5.0)), None);
+    }
+}
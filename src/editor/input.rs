@@ -2,6 +2,8 @@
 
 use egui::{Pos2, Vec2, Modifiers, Key, PointerButton};
 use crate::nodes::{NodeId, PortId, NodeGraph, Connection};
+use crate::nodes::wire_style::{WirePath, WireStyle};
+use super::keymap::{EditorAction, Keymap};
 
 /// Manages input state and event handling for the node editor
 #[derive(Debug, Clone)]
@@ -46,6 +48,9 @@ pub struct InputState {
     pub is_connecting_mode: bool,
     pub connect_paths: Vec<Vec<Pos2>>, // Multiple connection paths while C is held
     pub current_connect_path: Vec<Pos2>, // Current connection path being drawn
+    
+    // Remappable keyboard shortcuts
+    pub keymap: Keymap,
 }
 
 impl InputState {
@@ -76,6 +81,7 @@ impl InputState {
             is_connecting_mode: false,
             connect_paths: Vec::new(),
             current_connect_path: Vec::new(),
+            keymap: Keymap::load(),
         }
     }
 
@@ -134,8 +140,8 @@ impl InputState {
             self.context_menu_pos = None;
         }
         
-        // Handle cutting mode (X key)
-        let x_key_down = ui.input(|i| i.key_down(egui::Key::X));
+        // Handle cutting mode (X key by default)
+        let x_key_down = self.keymap.held(ui.ctx(), EditorAction::CutConnections);
         
         if x_key_down && !self.is_cutting_mode {
             // Start cutting mode
@@ -167,8 +173,8 @@ impl InputState {
             }
         }
         
-        // Handle connecting mode (C key)
-        let c_key_down = ui.input(|i| i.key_down(egui::Key::C));
+        // Handle connecting mode (C key by default)
+        let c_key_down = self.keymap.held(ui.ctx(), EditorAction::DrawConnections);
         
         if c_key_down && !self.is_connecting_mode {
             // Start connecting mode
@@ -372,7 +378,7 @@ impl InputState {
     }
 
     /// Find connection curve that was clicked, returns connection index
-    pub fn find_clicked_connection(&self, graph: &NodeGraph, click_radius: f32, zoom: f32) -> Option<usize> {
+    pub fn find_clicked_connection(&self, graph: &NodeGraph, click_radius: f32, wire_style: WireStyle) -> Option<usize> {
         if let Some(click_pos) = self.mouse_world_pos {
             for (idx, connection) in graph.connections.iter().enumerate() {
                 if let (Some(from_node), Some(to_node)) = (
@@ -386,21 +392,16 @@ impl InputState {
                         let from_pos = from_port.position;
                         let to_pos = to_port.position;
 
-                        // Calculate bezier curve control points (same logic as in rendering)
-                        let total_distance = (to_pos - from_pos).length();
-                        let control_offset = total_distance.sqrt() * 4.0;
-
-                        let control_point1 = egui::Pos2::new(from_pos.x, from_pos.y + control_offset);
-                        let control_point2 = egui::Pos2::new(to_pos.x, to_pos.y - control_offset);
+                        // Named wires are only drawn as stubs at their ends
+                        if connection.named {
+                            if super::named_wires::stubs_hit(click_pos, from_pos, to_pos, click_radius) {
+                                return Some(idx);
+                            }
+                            continue;
+                        }
 
-                        // Check if click is near the bezier curve
-                        let distance = crate::nodes::math_utils::distance_to_bezier_curve(
-                            click_pos,
-                            from_pos,
-                            control_point1,
-                            control_point2,
-                            to_pos,
-                        );
+                        // Check if click is near the wire as drawn
+                        let distance = WirePath::new(wire_style, from_pos, to_pos, 1.0).distance_to(click_pos);
 
                         if distance <= click_radius {
                             return Some(idx);
@@ -429,44 +430,14 @@ impl InputState {
     
     // === KEYBOARD SHORTCUTS ===
     
-    /// Check for delete key press
-    pub fn delete_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::Delete)
-    }
-    
-    /// Check for escape key press
-    pub fn escape_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::Escape)
-    }
-    
-    /// Check for F1 key press (performance info toggle)
-    pub fn f1_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F1)
-    }
-    
-    /// Check for F2 key press (add 10 nodes)
-    pub fn f2_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F2)
-    }
-    
-    /// Check for F3 key press (add 25 nodes)
-    pub fn f3_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F3)
+    /// Check whether the shortcut of `action` was pressed this frame
+    pub fn action_pressed(&self, ui: &egui::Ui, action: EditorAction) -> bool {
+        self.keymap.pressed(ui.ctx(), action)
     }
-    
-    /// Check for F4 key press (stress test)
-    pub fn f4_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F4)
-    }
-    
-    /// Check for F5 key press (clear all)
-    pub fn f5_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F5)
-    }
-    
-    /// Check for F6 key press (toggle GPU/CPU rendering)
-    pub fn f6_pressed(&self, ui: &egui::Ui) -> bool {
-        self.key_pressed(ui, Key::F6)
+
+    /// Check whether `action` was pressed this frame, alone or with Shift; returns whether Shift was held
+    pub fn action_pressed_or_shifted(&self, ui: &egui::Ui, action: EditorAction) -> Option<bool> {
+        self.keymap.pressed_or_shifted(ui.ctx(), action)
     }
     
     // === CONTEXT MENU ===
@@ -520,7 +491,7 @@ impl InputState {
     }
     
     /// Find connections that intersect with any cut path and return their indices
-    pub fn find_cut_connections(&self, graph: &NodeGraph, zoom: f32) -> Vec<usize> {
+    pub fn find_cut_connections(&self, graph: &NodeGraph, wire_style: WireStyle) -> Vec<usize> {
         let mut cut_connections = Vec::new();
         
         // Check all completed cut paths plus current path
@@ -529,7 +500,8 @@ impl InputState {
             all_paths.push(self.current_cut_path.clone());
         }
         
-        for (idx, connection) in graph.connections.iter().enumerate() {
+        // Named wires aren't drawn across the canvas, so there is nothing to cut
+        for (idx, connection) in graph.connections.iter().enumerate().filter(|(_, connection)| !connection.named) {
             if let (Some(from_node), Some(to_node)) = (
                 graph.nodes.get(&connection.from_node),
                 graph.nodes.get(&connection.to_node),
@@ -538,12 +510,11 @@ impl InputState {
                     from_node.outputs.get(connection.from_port),
                     to_node.inputs.get(connection.to_port),
                 ) {
-                    let from_pos = from_port.position;
-                    let to_pos = to_port.position;
+                    let wire = WirePath::new(wire_style, from_port.position, to_port.position, 1.0);
                     
                     // Check if any cut path intersects this connection
                     for cut_path in &all_paths {
-                        if self.path_intersects_connection(cut_path, from_pos, to_pos, zoom) {
+                        if self.path_intersects_connection(cut_path, &wire) {
                             cut_connections.push(idx);
                             break; // Only add once per connection
                         }
@@ -555,26 +526,14 @@ impl InputState {
         cut_connections
     }
     
-    /// Check if a cut path intersects with a connection bezier curve
-    fn path_intersects_connection(&self, cut_path: &[Pos2], from_pos: Pos2, to_pos: Pos2, zoom: f32) -> bool {
+    /// Check if a cut path intersects with a connection's wire
+    fn path_intersects_connection(&self, cut_path: &[Pos2], wire: &WirePath) -> bool {
         if cut_path.len() < 2 {
             return false;
         }
         
-        // Calculate bezier curve control points (same logic as rendering)
-        let total_distance = (to_pos - from_pos).length();
-        let control_offset = total_distance.sqrt() * 4.0;
-        
-        let control_point1 = egui::Pos2::new(from_pos.x, from_pos.y + control_offset);
-        let control_point2 = egui::Pos2::new(to_pos.x, to_pos.y - control_offset);
-        
-        // Sample points along the bezier curve
-        for i in 0..=20 {
-            let t = i as f32 / 20.0;
-            let curve_point = crate::nodes::math_utils::cubic_bezier_point(
-                t, from_pos, control_point1, control_point2, to_pos
-            );
-            
+        // Sample points along the wire
+        for curve_point in wire.sample(20) {
             // Check if this curve point is close to any segment of the cut path
             for window in cut_path.windows(2) {
                 let seg_start = window[0];
@@ -3,6 +3,7 @@
 use egui::{Pos2, Vec2};
 use std::collections::{HashMap, HashSet};
 use crate::nodes::{NodeId, NodeGraph};
+use crate::nodes::wire_style::{WirePath, WireStyle};
 
 /// Manages node interactions and selections
 #[derive(Debug, Clone)]
@@ -170,7 +171,7 @@ impl InteractionManager {
     }
     
     /// Complete box selection and return selected nodes
-    pub fn complete_box_selection(&mut self, graph: &NodeGraph, multi_select: bool) -> Vec<NodeId> {
+    pub fn complete_box_selection(&mut self, graph: &NodeGraph, multi_select: bool, wire_style: WireStyle) -> Vec<NodeId> {
         let mut selected_nodes = Vec::new();
         let mut selected_connections = Vec::new();
         
@@ -191,7 +192,7 @@ impl InteractionManager {
             }
             
             // Select connections that pass through the box
-            for (idx, connection) in graph.connections.iter().enumerate() {
+            for (idx, connection) in graph.connections.iter().enumerate().filter(|(_, connection)| !connection.named) {
                 if let (Some(from_node), Some(to_node)) = (
                     graph.nodes.get(&connection.from_node),
                     graph.nodes.get(&connection.to_node),
@@ -200,11 +201,10 @@ impl InteractionManager {
                         from_node.outputs.get(connection.from_port),
                         to_node.inputs.get(connection.to_port),
                     ) {
-                        let from_pos = from_port.position;
-                        let to_pos = to_port.position;
+                        let wire = WirePath::new(wire_style, from_port.position, to_port.position, 1.0);
                         
                         // Check if connection curve intersects with selection box
-                        if self.connection_intersects_box(from_pos, to_pos, min_x, max_x, min_y, max_y) {
+                        if self.connection_intersects_box(&wire, min_x, max_x, min_y, max_y) {
                             selected_connections.push(idx);
                         }
                     }
@@ -239,21 +239,9 @@ impl InteractionManager {
     }
     
     /// Check if a connection curve intersects with a selection box
-    fn connection_intersects_box(&self, from_pos: Pos2, to_pos: Pos2, min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> bool {
-        // Sample points along the bezier curve to check intersection
-        let total_distance = (to_pos - from_pos).length();
-        let control_offset = total_distance.sqrt() * 4.0;
-        
-        let control_point1 = egui::Pos2::new(from_pos.x, from_pos.y + control_offset);
-        let control_point2 = egui::Pos2::new(to_pos.x, to_pos.y - control_offset);
-        
-        // Sample multiple points along the bezier curve
-        for i in 0..=20 {
-            let t = i as f32 / 20.0;
-            let point = crate::nodes::math_utils::cubic_bezier_point(
-                t, from_pos, control_point1, control_point2, to_pos
-            );
-            
+    fn connection_intersects_box(&self, wire: &WirePath, min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> bool {
+        // Sample multiple points along the wire
+        for point in wire.sample(20) {
             // Check if this point is inside the selection box
             if point.x >= min_x && point.x <= max_x && point.y >= min_y && point.y <= max_y {
                 return true;
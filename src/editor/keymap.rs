@@ -0,0 +1,361 @@
+//! Remappable keyboard shortcuts
+//!
+//! Editor actions are looked up in a [`Keymap`] instead of being tied to fixed
+//! keys, so users coming from other applications or keyboard layouts can move
+//! them. Changes are made in the "Keyboard Shortcuts" window and stored in
+//! `~/.nodle/keymap.json` as action → shortcut text (`"clear_graph": "Ctrl+F5"`);
+//! actions missing from the file keep their default keys.
+
+use egui::{Key, KeyboardShortcut, Modifiers, RichText};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Something the editor does when a shortcut is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditorAction {
+    DeleteSelection,
+    Cancel,
+    /// Held while dragging across connections to cut them
+    CutConnections,
+    /// Held while dragging between ports to connect them
+    DrawConnections,
+    TogglePerformanceInfo,
+    AddTenNodes,
+    AddTwentyFiveNodes,
+    StressTest,
+    ClearGraph,
+    ToggleGpuRendering,
+    /// Breakpoint on the selected nodes
+    ToggleBreakpoint,
+    /// Run the node a cook is paused before, then pause again
+    StepExecution,
+    /// Run a paused cook on to the next breakpoint
+    ContinueExecution,
+    /// Edit the title of the selected node on the canvas
+    RenameNode,
+    /// Open the Find Nodes dialog
+    FindNodes,
+    /// Select the node feeding the selected one (with Shift, add it to the selection)
+    SelectUpstream,
+    /// Select the node the selected one feeds (with Shift, add it to the selection)
+    SelectDownstream,
+}
+
+impl EditorAction {
+    pub const ALL: [EditorAction; 17] = [
+        EditorAction::DeleteSelection,
+        EditorAction::Cancel,
+        EditorAction::CutConnections,
+        EditorAction::DrawConnections,
+        EditorAction::TogglePerformanceInfo,
+        EditorAction::AddTenNodes,
+        EditorAction::AddTwentyFiveNodes,
+        EditorAction::StressTest,
+        EditorAction::ClearGraph,
+        EditorAction::ToggleGpuRendering,
+        EditorAction::ToggleBreakpoint,
+        EditorAction::StepExecution,
+        EditorAction::ContinueExecution,
+        EditorAction::RenameNode,
+        EditorAction::FindNodes,
+        EditorAction::SelectUpstream,
+        EditorAction::SelectDownstream,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EditorAction::DeleteSelection => "Delete selection",
+            EditorAction::Cancel => "Cancel / close menu",
+            EditorAction::CutConnections => "Cut connections (hold)",
+            EditorAction::DrawConnections => "Draw connections (hold)",
+            EditorAction::TogglePerformanceInfo => "Toggle performance info",
+            EditorAction::AddTenNodes => "Add 10 benchmark nodes",
+            EditorAction::AddTwentyFiveNodes => "Add 25 benchmark nodes",
+            EditorAction::StressTest => "Stress test (5000 nodes)",
+            EditorAction::ClearGraph => "Clear graph",
+            EditorAction::ToggleGpuRendering => "Toggle GPU/CPU rendering",
+            EditorAction::ToggleBreakpoint => "Toggle breakpoint",
+            EditorAction::StepExecution => "Step paused cook",
+            EditorAction::ContinueExecution => "Continue paused cook",
+            EditorAction::RenameNode => "Rename node",
+            EditorAction::FindNodes => "Find nodes",
+            EditorAction::SelectUpstream => "Select upstream node",
+            EditorAction::SelectDownstream => "Select downstream node",
+        }
+    }
+
+    /// Key the action is bound to out of the box
+    pub fn default_shortcut(&self) -> KeyboardShortcut {
+        let key = match self {
+            EditorAction::DeleteSelection => Key::Delete,
+            EditorAction::Cancel => Key::Escape,
+            EditorAction::CutConnections => Key::X,
+            EditorAction::DrawConnections => Key::C,
+            EditorAction::TogglePerformanceInfo => Key::F1,
+            EditorAction::AddTenNodes => Key::F7,
+            EditorAction::AddTwentyFiveNodes => Key::F3,
+            EditorAction::StressTest => Key::F4,
+            EditorAction::ClearGraph => Key::F5,
+            EditorAction::ToggleGpuRendering => Key::F6,
+            EditorAction::ToggleBreakpoint => Key::F9,
+            EditorAction::StepExecution => Key::F10,
+            EditorAction::ContinueExecution => Key::F8,
+            EditorAction::RenameNode => Key::F2,
+            EditorAction::FindNodes => Key::F,
+            EditorAction::SelectUpstream => Key::ArrowUp,
+            EditorAction::SelectDownstream => Key::ArrowDown,
+        };
+        let modifiers = match self {
+            EditorAction::FindNodes => Modifiers::COMMAND,
+            _ => Modifiers::NONE,
+        };
+        KeyboardShortcut::new(modifiers, key)
+    }
+}
+
+/// Shortcut as stored in the keymap file, e.g. `Ctrl+Shift+F5`
+pub fn shortcut_text(shortcut: &KeyboardShortcut) -> String {
+    let mut parts = Vec::new();
+    if shortcut.modifiers.ctrl || shortcut.modifiers.command || shortcut.modifiers.mac_cmd {
+        parts.push("Ctrl");
+    }
+    if shortcut.modifiers.alt {
+        parts.push("Alt");
+    }
+    if shortcut.modifiers.shift {
+        parts.push("Shift");
+    }
+    parts.push(shortcut.logical_key.name());
+    parts.join("+")
+}
+
+/// Parse the text written by [`shortcut_text`]
+pub fn parse_shortcut(text: &str) -> Result<KeyboardShortcut, String> {
+    let mut modifiers = Modifiers::NONE;
+    let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+    let key_name = parts.pop().filter(|name| !name.is_empty())
+        .ok_or_else(|| format!("Shortcut \"{}\" has no key", text))?;
+    for part in parts {
+        match part.to_lowercase().as_str() {
+            "ctrl" | "cmd" => modifiers = modifiers | Modifiers::COMMAND,
+            "alt" => modifiers = modifiers | Modifiers::ALT,
+            "shift" => modifiers = modifiers | Modifiers::SHIFT,
+            _ => return Err(format!("Unknown modifier \"{}\" in \"{}\"", part, text)),
+        }
+    }
+    let key = Key::from_name(key_name).ok_or_else(|| format!("Unknown key \"{}\" in \"{}\"", key_name, text))?;
+    Ok(KeyboardShortcut::new(modifiers, key))
+}
+
+/// Shortcuts of every editor action
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: BTreeMap<EditorAction, KeyboardShortcut>,
+    path: Option<PathBuf>,
+    /// Action waiting for a key press in the shortcuts window
+    capturing: Option<EditorAction>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::with_path(None)
+    }
+}
+
+impl Keymap {
+    /// Load the keymap from `~/.nodle/keymap.json`
+    pub fn load() -> Self {
+        Self::with_path(dirs::home_dir().map(|home| home.join(".nodle").join("keymap.json")))
+    }
+
+    /// Load the keymap from `path` (None keeps it in memory only)
+    pub fn with_path(path: Option<PathBuf>) -> Self {
+        let mut keymap = Self {
+            bindings: EditorAction::ALL.iter().map(|action| (*action, action.default_shortcut())).collect(),
+            path,
+            capturing: None,
+        };
+        let stored: BTreeMap<EditorAction, String> = keymap.path.as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| log::warn!("Ignoring invalid keymap: {}", e)).ok())
+            .unwrap_or_default();
+        for (action, text) in stored {
+            match parse_shortcut(&text) {
+                Ok(shortcut) => {
+                    keymap.bindings.insert(action, shortcut);
+                }
+                Err(e) => log::warn!("Keeping the default shortcut of {}: {}", action.label(), e),
+            }
+        }
+        keymap
+    }
+
+    /// Write the keymap file
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let stored: BTreeMap<EditorAction, String> = self.bindings.iter()
+            .map(|(action, shortcut)| (*action, shortcut_text(shortcut)))
+            .collect();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(&stored).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    pub fn shortcut(&self, action: EditorAction) -> KeyboardShortcut {
+        self.bindings.get(&action).copied().unwrap_or_else(|| action.default_shortcut())
+    }
+
+    pub fn set_shortcut(&mut self, action: EditorAction, shortcut: KeyboardShortcut) {
+        // Store the platform-neutral form (Ctrl and Cmd are both "command"), as saved on disk
+        let shortcut = parse_shortcut(&shortcut_text(&shortcut)).unwrap_or(shortcut);
+        self.bindings.insert(action, shortcut);
+    }
+
+    /// Other actions bound to the same shortcut as `action`
+    pub fn conflicts_with(&self, action: EditorAction) -> Vec<EditorAction> {
+        let shortcut = self.shortcut(action);
+        EditorAction::ALL.iter()
+            .copied()
+            .filter(|other| *other != action && self.shortcut(*other) == shortcut)
+            .collect()
+    }
+
+    /// Whether `action` was triggered this frame (ignored while a text field has focus, except Cancel)
+    pub fn pressed(&self, ctx: &egui::Context, action: EditorAction) -> bool {
+        if self.capturing.is_some() || (action != EditorAction::Cancel && ctx.wants_keyboard_input()) {
+            return false;
+        }
+        let shortcut = self.shortcut(action);
+        ctx.input(|input| input.key_pressed(shortcut.logical_key) && input.modifiers.matches_exact(shortcut.modifiers))
+    }
+
+    /// Whether `action` was triggered this frame, either as bound or with Shift
+    /// added; returns whether Shift was added
+    pub fn pressed_or_shifted(&self, ctx: &egui::Context, action: EditorAction) -> Option<bool> {
+        if self.pressed(ctx, action) {
+            return Some(false);
+        }
+        if self.capturing.is_some() || ctx.wants_keyboard_input() {
+            return None;
+        }
+        let shortcut = self.shortcut(action);
+        let shifted = shortcut.modifiers | Modifiers::SHIFT;
+        ctx.input(|input| input.key_pressed(shortcut.logical_key) && input.modifiers.matches_exact(shifted)).then_some(true)
+    }
+
+    /// Whether the key of a held action is down (ignored while a text field has focus)
+    pub fn held(&self, ctx: &egui::Context, action: EditorAction) -> bool {
+        if self.capturing.is_some() || ctx.wants_keyboard_input() {
+            return false;
+        }
+        let shortcut = self.shortcut(action);
+        ctx.input(|input| input.key_down(shortcut.logical_key) && input.modifiers.contains(shortcut.modifiers))
+    }
+
+    /// Render the shortcuts window
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) {
+        if let Some(action) = self.capturing {
+            let pressed = ctx.input(|input| input.events.iter().find_map(|event| match event {
+                egui::Event::Key { key, pressed: true, modifiers, .. } => Some(KeyboardShortcut::new(*modifiers, *key)),
+                _ => None,
+            }));
+            if let Some(shortcut) = pressed {
+                self.capturing = None;
+                self.set_shortcut(action, shortcut);
+                if let Err(e) = self.save() {
+                    log::error!("Failed to save keymap: {}", e);
+                }
+            }
+        }
+
+        let mut changed = false;
+        egui::Window::new("Keyboard Shortcuts")
+            .open(open)
+            .default_size([380.0, 360.0])
+            .show(ctx, |ui| {
+                ui.label(RichText::new("Click a shortcut, then press the new key combination").weak());
+                ui.separator();
+                egui::Grid::new("keymap_bindings").striped(true).num_columns(3).show(ui, |ui| {
+                    for action in EditorAction::ALL {
+                        ui.label(action.label());
+                        let text = if self.capturing == Some(action) {
+                            "Press a key…".to_string()
+                        } else {
+                            ctx.format_shortcut(&self.shortcut(action))
+                        };
+                        let conflicts = self.conflicts_with(action);
+                        let mut button = egui::Button::new(text).min_size(egui::vec2(90.0, 0.0));
+                        if !conflicts.is_empty() {
+                            button = button.stroke(egui::Stroke::new(1.0, ui.visuals().error_fg_color));
+                        }
+                        let response = ui.add(button);
+                        let response = if conflicts.is_empty() {
+                            response
+                        } else {
+                            let names: Vec<&str> = conflicts.iter().map(|other| other.label()).collect();
+                            response.on_hover_text(format!("Also bound to: {}", names.join(", ")))
+                        };
+                        if response.clicked() {
+                            self.capturing = Some(action);
+                        }
+                        let is_default = self.shortcut(action) == action.default_shortcut();
+                        if ui.add_enabled(!is_default, egui::Button::new("↺").small()).on_hover_text("Restore the default key").clicked() {
+                            self.set_shortcut(action, action.default_shortcut());
+                            changed = true;
+                        }
+                        ui.end_row();
+                    }
+                });
+                ui.separator();
+                if ui.button("Reset All to Defaults").clicked() {
+                    for action in EditorAction::ALL {
+                        self.set_shortcut(action, action.default_shortcut());
+                    }
+                    changed = true;
+                }
+            });
+
+        if changed {
+            if let Err(e) = self.save() {
+                log::error!("Failed to save keymap: {}", e);
+            }
+        }
+        if !*open {
+            self.capturing = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcuts_round_trip_and_conflicts_are_reported() {
+        let shortcut = parse_shortcut("Ctrl+Shift+F5").unwrap();
+        assert_eq!(shortcut.logical_key, Key::F5);
+        assert!(shortcut.modifiers.shift && shortcut.modifiers.command);
+        assert_eq!(parse_shortcut(&shortcut_text(&shortcut)).unwrap(), shortcut);
+        assert!(parse_shortcut("Hyper+F5").is_err());
+        assert!(parse_shortcut("Ctrl+").is_err());
+
+        let path = std::env::temp_dir().join(format!("nodle_keymap_test_{}.json", std::process::id()));
+        let mut keymap = Keymap::with_path(Some(path.clone()));
+        assert!(keymap.conflicts_with(EditorAction::ClearGraph).is_empty());
+        keymap.set_shortcut(EditorAction::ClearGraph, KeyboardShortcut::new(Modifiers::NONE, Key::Delete));
+        assert_eq!(keymap.conflicts_with(EditorAction::ClearGraph), vec![EditorAction::DeleteSelection]);
+
+        keymap.set_shortcut(EditorAction::ClearGraph, shortcut);
+        keymap.save().unwrap();
+        let reloaded = Keymap::with_path(Some(path.clone()));
+        assert_eq!(reloaded.shortcut(EditorAction::ClearGraph), shortcut);
+        assert_eq!(reloaded.shortcut(EditorAction::Cancel), EditorAction::Cancel.default_shortcut());
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -130,6 +130,9 @@ pub struct MenuManager {
     submenu_path: Vec<String>, // Track the current path in the menu hierarchy
     // Support for multiple nested submenus
     nested_submenus: Vec<(String, Pos2)>, // Track multiple open submenus with their positions
+    // Node search at the top of the menu
+    search_query: String,
+    search_focused: bool,
 }
 
 impl MenuManager {
@@ -141,6 +144,8 @@ impl MenuManager {
             submenu_close_timer: None,
             submenu_path: Vec::new(),
             nested_submenus: Vec::new(),
+            search_query: String::new(),
+            search_focused: false,
         }
     }
 
@@ -151,6 +156,8 @@ impl MenuManager {
         self.submenu_close_timer = None;
         self.submenu_path.clear();
         self.nested_submenus.clear();
+        self.search_query.clear();
+        self.search_focused = false;
     }
 
     /// Render the workspace menu and return selected node type
@@ -198,11 +205,42 @@ impl MenuManager {
                         ui.set_max_width(menu_width);
 
                         ui.label("Create Node:");
+                        let search_response = ui.add(
+                            egui::TextEdit::singleline(&mut self.search_query)
+                                .hint_text("Search...")
+                                .desired_width(menu_width)
+                        );
+                        if !self.search_focused {
+                            search_response.request_focus();
+                            self.search_focused = true;
+                        }
                         ui.separator();
 
                         // Track if any item is currently being hovered
                         let mut any_item_hovered = false;
                         
+                        if !self.search_query.trim().is_empty() {
+                            // Search results replace the categories; Enter picks the best match
+                            self.open_submenu = None;
+                            self.submenu_pos = None;
+                            self.nested_submenus.clear();
+                            let results = workspace_manager.search_nodes_for_path(&navigation.current_path, &self.search_query);
+                            if results.is_empty() {
+                                ui.label("No matching nodes");
+                            }
+                            for node in results.iter().take(crate::constants::menu::MAX_SEARCH_RESULTS) {
+                                if render_menu_item(ui, &node.display_name, menu_width) {
+                                    selected_node_type = Some(node.node_type.clone());
+                                }
+                            }
+                            if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                                if let Some(best) = results.first() {
+                                    selected_node_type = Some(best.node_type.clone());
+                                }
+                            }
+                            return;
+                        }
+                        
                         // Render the workspace-aware menu structure
                         for menu_item in menu_structure {
                             match menu_item {
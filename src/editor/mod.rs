@@ -11,6 +11,32 @@ pub mod file_manager;
 pub mod panels;
 pub mod debug_tools;
 pub mod workspace_builder;
+pub mod startup_screen;
+pub mod templates;
+pub mod session_history;
+pub mod execution_log_panel;
+pub mod graph_report_panel;
+pub mod screen_recorder;
+pub mod plugin_browser;
+pub mod diff_view;
+pub mod usage_stats;
+pub mod project_archive;
+pub mod hotbox;
+pub mod annotations;
+pub mod plugin_reload;
+pub mod wire_bundling;
+pub mod plugin_crashes;
+pub mod presets;
+pub mod keymap;
+pub mod preferences;
+pub mod type_legend;
+pub mod data_flow;
+pub mod dependency_view;
+pub mod probe_display;
+pub mod render_notifications;
+pub mod selection_sets;
+pub mod find_nodes;
+pub mod named_wires;
 
 // Re-exports
 pub use canvas::Canvas;
@@ -23,6 +49,27 @@ pub use file_manager::FileManager;
 pub use panels::PanelManager;
 pub use debug_tools::DebugToolsManager;
 pub use workspace_builder::WorkspaceBuilder;
+pub use startup_screen::{StartupScreen, StartupAction};
+pub use templates::ProjectTemplate;
+pub use session_history::SessionHistory;
+pub use execution_log_panel::ExecutionLogPanel;
+pub use graph_report_panel::GraphReportPanel;
+pub use screen_recorder::ScreenRecorder;
+pub use plugin_browser::PluginBrowser;
+pub use diff_view::DiffView;
+pub use usage_stats::UsageStats;
+pub use hotbox::{Hotbox, HotboxAction};
+pub use annotations::{AnnotationLayer, AnnotationEdit};
+pub use plugin_reload::PluginHotReload;
+pub use plugin_crashes::{PluginCrashDialog, CrashAction};
+pub use keymap::{Keymap, EditorAction};
+pub use data_flow::DataFlowOverlay;
+pub use dependency_view::DependencyView;
+pub use preferences::PreferencesWindow;
+pub use render_notifications::RenderNotifications;
+pub use selection_sets::{SelectionSets, SelectionSetAction, Isolation};
+pub use find_nodes::{FindNodesDialog, FindSelection};
+pub use named_wires::{NamedWires, WireAction};
 
 use eframe::egui;
 use egui::{Color32, Pos2, Rect, Stroke, Vec2};
@@ -30,14 +77,24 @@ use egui_wgpu;
 use crate::nodes::{
     NodeGraph, Node, NodeId, Connection, NodeGraphEngine,
 };
-use std::collections::HashMap;
+use crate::nodes::{adapters, graph_diff, parameter_io, project_paths, value_preview};
+use crate::nodes::wire_style::{WirePath, WireStyle};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use log::{info, error, debug};
+use log::{info, warn, error, debug};
 use crate::workspace::WorkspaceManager;
 use crate::workspaces::WorkspaceRegistry;
+use crate::plugins::host::PluginCommand;
+use crate::plugins::events::GraphEventTracker;
 use crate::gpu::NodeRenderCallback;
 use crate::gpu::GpuInstanceManager;
 
+/// Marker drawn on nodes with a breakpoint
+const BREAKPOINT_COLOR: Color32 = Color32::from_rgb(220, 60, 60);
+
+/// Outline of the node a paused cook runs next
+const PAUSED_NODE_COLOR: Color32 = Color32::from_rgb(255, 200, 80);
+
 /// Execution mode for the node graph
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ExecutionMode {
@@ -47,6 +104,15 @@ pub enum ExecutionMode {
     Manual,
 }
 
+impl From<crate::settings::StartupExecutionMode> for ExecutionMode {
+    fn from(mode: crate::settings::StartupExecutionMode) -> Self {
+        match mode {
+            crate::settings::StartupExecutionMode::Auto => ExecutionMode::Auto,
+            crate::settings::StartupExecutionMode::Manual => ExecutionMode::Manual,
+        }
+    }
+}
+
 /// Main application state for the node editor
 pub struct NodeEditor {
     graph: NodeGraph,
@@ -69,10 +135,75 @@ pub struct NodeEditor {
     file_manager: FileManager,
     // Menu state
     show_file_menu: bool,
+    // Open File submenu ("Open Recent" / "New from Template") and its screen position
+    file_submenu: Option<(String, Pos2)>,
+    // Name being entered for File → Save as Template
+    template_name_prompt: Option<String>,
+    // Node being renamed on the canvas and the title typed so far
+    title_edit: Option<(NodeId, String)>,
+    // Last node reached with the upstream/downstream keys, where Shift extends the walk from
+    walk_end: Option<NodeId>,
+    // Startup screen listing recent projects
+    startup_screen: StartupScreen,
     // Layout constraints
     current_menu_bar_height: f32,
     // Execution mode
     execution_mode: ExecutionMode,
+    // Wire the selected node into nodes created from the context menu
+    auto_wire_new_nodes: bool,
+    // Draw parallel connections between the same regions as one bundled wire
+    bundle_wires: bool,
+    // Legend of port data type colors over the canvas
+    show_type_legend: bool,
+    // Cook pulses along wires and value tooltips on hover
+    data_flow: DataFlowOverlay,
+    dependency_view: DependencyView,
+    // Hovering a node shows the values on its outputs
+    inspect_on_hover: bool,
+    // Human-readable timeline of this session's edits
+    session_history: SessionHistory,
+    show_session_history: bool,
+    execution_log_panel: ExecutionLogPanel,
+    show_execution_log: bool,
+    // Node counts and memory use of the active graph
+    graph_report_panel: GraphReportPanel,
+    show_graph_report: bool,
+    // GIF capture of the editor window or a single panel
+    screen_recorder: ScreenRecorder,
+    // Remote plugin repository browser
+    plugin_browser: PluginBrowser,
+    show_plugin_browser: bool,
+    // Differences against another revision of the project, highlighted on the canvas
+    diff_view: DiffView,
+    // Local-only statistics on node usage and cook times
+    usage_stats: UsageStats,
+    show_usage_stats: bool,
+    show_keymap: bool,
+    // Application settings editor and the time of the last autosave
+    preferences: PreferencesWindow,
+    show_preferences: bool,
+    last_autosave: std::time::Instant,
+    // Spacebar radial menu for quick node creation
+    hotbox: Hotbox,
+    // Review notes drawn over the graph
+    annotation_layer: AnnotationLayer,
+    show_annotation_tools: bool,
+    // Named selection sets window and the isolated nodes, if any
+    selection_sets: SelectionSets,
+    show_selection_sets: bool,
+    // Node query dialog
+    find_nodes: FindNodesDialog,
+    show_find_nodes: bool,
+    // Connection labels and named wires window
+    named_wires: NamedWires,
+    show_named_wires: bool,
+    // Reloads plugin libraries rebuilt while the editor runs
+    plugin_hot_reload: PluginHotReload,
+    // Plugin panics caught by the sandbox, waiting for the user to retry or disable the plugin
+    plugin_crash_dialog: PluginCrashDialog,
+    render_notifications: RenderNotifications,
+    // Viewed graph and selection as of the last frame, diffed into events for plugins
+    graph_event_tracker: GraphEventTracker,
 }
 
 
@@ -91,6 +222,10 @@ impl NodeEditor {
         // Use the workspace registry to create a manager with all available workspaces
         let workspace_manager = WorkspaceRegistry::create_workspace_manager();
         
+        let file_manager = FileManager::new();
+        let startup_screen = StartupScreen::new(file_manager.show_startup_screen());
+        let settings = crate::settings::current();
+        
         let mut editor = Self {
             graph: NodeGraph::new(),
             execution_engine: NodeGraphEngine::new(),
@@ -105,17 +240,57 @@ impl NodeEditor {
             // Debug and performance monitoring
             debug_tools: DebugToolsManager::new(),
             // GPU rendering
-            use_gpu_rendering: true, // Start with GPU rendering enabled
+            use_gpu_rendering: settings.gpu_rendering,
             // Persistent GPU instance manager
             gpu_instance_manager: GpuInstanceManager::new(),
             // File management
-            file_manager: FileManager::new(),
+            file_manager,
             // Menu state
             show_file_menu: false,
+            file_submenu: None,
+            template_name_prompt: None,
+            title_edit: None,
+            walk_end: None,
+            startup_screen,
             // Layout constraints
             current_menu_bar_height: 0.0,
-            // Execution mode - start in Auto mode
-            execution_mode: ExecutionMode::Auto,
+            // Execution mode from the preferences
+            execution_mode: ExecutionMode::from(settings.execution_mode),
+            auto_wire_new_nodes: settings.auto_wire_new_nodes,
+            bundle_wires: false,
+            show_type_legend: true,
+            data_flow: DataFlowOverlay::new(),
+            dependency_view: DependencyView::new(),
+            inspect_on_hover: false,
+            session_history: SessionHistory::new(),
+            show_session_history: false,
+            execution_log_panel: ExecutionLogPanel::new(),
+            graph_report_panel: GraphReportPanel::new(),
+            show_graph_report: false,
+            show_execution_log: false,
+            screen_recorder: ScreenRecorder::new(),
+            plugin_browser: PluginBrowser::new(),
+            show_plugin_browser: false,
+            diff_view: DiffView::new(),
+            usage_stats: UsageStats::new(),
+            show_usage_stats: false,
+            show_keymap: false,
+            preferences: PreferencesWindow::new(),
+            show_preferences: false,
+            last_autosave: std::time::Instant::now(),
+            hotbox: Hotbox::new(),
+            annotation_layer: AnnotationLayer::new(),
+            show_annotation_tools: false,
+            selection_sets: SelectionSets::new(),
+            show_selection_sets: false,
+            find_nodes: FindNodesDialog::new(),
+            named_wires: NamedWires::new(),
+            show_named_wires: false,
+            show_find_nodes: false,
+            plugin_hot_reload: PluginHotReload::new(),
+            plugin_crash_dialog: PluginCrashDialog::new(),
+            render_notifications: RenderNotifications::new(),
+            graph_event_tracker: GraphEventTracker::new(),
         };
 
         // Start with empty node graph - nodes created at 150.0px x 30.0px
@@ -133,7 +308,12 @@ impl NodeEditor {
     
     /// Get the nodes to render based on current view
     fn get_viewed_nodes(&self) -> HashMap<NodeId, Node> {
-        let nodes = self.navigation.get_viewed_nodes(&self.graph);
+        let mut nodes = self.navigation.get_viewed_nodes(&self.graph);
+        // Isolate mode hides the nodes outside the isolated set
+        if let Some(isolation) = &self.selection_sets.isolation {
+            let graph = self.navigation.get_workspace_node_id();
+            nodes.retain(|node_id, _| isolation.shows(graph, *node_id));
+        }
         // Debug prints removed for performance
         
         // Also check workspace internal graphs
@@ -172,6 +352,31 @@ impl NodeEditor {
         }
     }
     
+    /// Add or remove breakpoints on the selected nodes
+    fn toggle_breakpoints_on_selection(&mut self) {
+        let selected: Vec<NodeId> = self.interaction.selected_nodes.iter().copied().collect();
+        for node_id in selected {
+            self.execution_engine.debugger_mut().toggle_breakpoint(node_id);
+        }
+    }
+
+    /// Resume a cook paused at a breakpoint, one node at a time or up to the next breakpoint
+    fn resume_execution(&mut self, step: bool) {
+        let debugger = self.execution_engine.debugger_mut();
+        if debugger.paused_at().is_none() {
+            return;
+        }
+        if step {
+            debugger.step();
+        } else {
+            debugger.resume();
+        }
+        let current_graph = self.navigation.get_active_graph(&self.graph);
+        if let Err(e) = self.execution_engine.execute_dirty_nodes(current_graph) {
+            eprintln!("Resumed execution failed: {}", e);
+        }
+    }
+
     /// Sync execution mode with the execution engine
     fn sync_execution_mode(&mut self) {
         use crate::nodes::execution_engine::EngineExecutionMode;
@@ -201,10 +406,32 @@ impl NodeEditor {
         self.navigation.get_active_graph(&self.graph)
     }
     
+    /// Mutable access to the graph being viewed (root or the entered workspace)
+    fn get_active_graph_mut(&mut self) -> &mut NodeGraph {
+        if let GraphView::WorkspaceNode(workspace_node_id) = self.navigation.current_view() {
+            let workspace_node_id = *workspace_node_id;
+            if self.graph.nodes.get(&workspace_node_id).is_some_and(|node| node.get_internal_graph().is_some()) {
+                return self.graph.nodes.get_mut(&workspace_node_id).and_then(|node| node.get_internal_graph_mut()).unwrap();
+            }
+        }
+        &mut self.graph
+    }
+    
     /// Add a connection to the appropriate graph based on current view
-    fn add_connection_to_active_graph(&mut self, connection: Connection) -> Result<(), &'static str> {
+    fn add_connection_to_active_graph(&mut self, mut connection: Connection) -> Result<(), &'static str> {
         // Debug prints removed for performance
         
+        // Mismatched but convertible port types get a conversion adapter
+        if connection.adapter.is_none() {
+            let graph = self.get_active_graph();
+            let type_of = |node_id: NodeId, is_input: bool, port: usize| graph.nodes.get(&node_id)
+                .and_then(|node| crate::theme::port_data_type(&node.type_id, is_input, port));
+            connection.adapter = adapters::auto_adapter(
+                type_of(connection.from_node, false, connection.from_port),
+                type_of(connection.to_node, true, connection.to_port),
+            );
+        }
+        
         // Check if we need to auto-open a panel BEFORE making the connection
         let should_auto_open_panel = self.should_auto_open_panel_for_connection(&connection);
         debug!("🔍 should_auto_open_panel: {}", should_auto_open_panel);
@@ -246,6 +473,11 @@ impl NodeEditor {
             }
         };
         
+        if result.is_ok() {
+            let description = self.describe_connection(&connection);
+            self.session_history.record(format!("connected {}", description));
+        }
+        
         // Auto-open panel after connection is made if needed
         if result.is_ok() && should_auto_open_panel {
             debug!("🌳 Connection successful, calling auto_open_panel_after_connection");
@@ -278,11 +510,11 @@ impl NodeEditor {
             }
         };
 
-        // Check if the target node (to_node) is a Scenegraph node (Tree panel type)
+        // Any node that declares a Tree panel (Scenegraph, Tree View, ...) opens on connection
         if let Some(target_node) = graph.nodes.get(&connection.to_node) {
-            let is_scenegraph = target_node.type_id == "Scenegraph";
-            debug!("🔍 Target node {} type_id: '{}', is_scenegraph: {}", connection.to_node, target_node.type_id, is_scenegraph);
-            is_scenegraph
+            let has_tree_panel = target_node.get_panel_type() == Some(crate::nodes::interface::PanelType::Tree);
+            debug!("🔍 Target node {} type_id: '{}', has_tree_panel: {}", connection.to_node, target_node.type_id, has_tree_panel);
+            has_tree_panel
         } else {
             debug!("🔍 Target node {} not found in graph", connection.to_node);
             false
@@ -291,7 +523,7 @@ impl NodeEditor {
 
     /// Auto-open tree panel after connection is made
     fn auto_open_panel_after_connection(&mut self, connection: &Connection) {
-        debug!("🌳 Auto-opening tree panel for Scenegraph node {} after USD connection", connection.to_node);
+        debug!("🌳 Auto-opening tree panel for node {} after connection", connection.to_node);
         
         // Auto-open the tree panel
         let panel_manager = self.panel_manager.interface_panel_manager_mut();
@@ -304,8 +536,33 @@ impl NodeEditor {
         debug!("🌳 Tree panel auto-open completed for node {}", connection.to_node);
     }
     
+    /// "Sphere.Scene → Viewport.USD Scene" for history entries
+    fn describe_connection(&self, connection: &Connection) -> String {
+        let graph = self.get_active_graph();
+        let endpoint = |node_id: NodeId, port: usize, output: bool| {
+            graph.nodes.get(&node_id)
+                .map(|node| {
+                    let ports = if output { &node.outputs } else { &node.inputs };
+                    match ports.get(port) {
+                        Some(port) => format!("{}.{}", node.title, port.name),
+                        None => node.title.clone(),
+                    }
+                })
+                .unwrap_or_else(|| format!("node {}", node_id))
+        };
+        format!(
+            "{} → {}",
+            endpoint(connection.from_node, connection.from_port, true),
+            endpoint(connection.to_node, connection.to_port, false)
+        )
+    }
+    
     /// Remove a connection from the appropriate graph based on current view
     fn remove_connection_from_active_graph(&mut self, idx: usize) {
+        if let Some(connection) = self.get_active_graph().connections.get(idx) {
+            let description = self.describe_connection(connection);
+            self.session_history.record(format!("disconnected {}", description));
+        }
         match self.navigation.current_view() {
             GraphView::Root => {
                 if let Some(connection) = self.graph.connections.get(idx) {
@@ -403,15 +660,219 @@ impl NodeEditor {
             }
 
             // Close on Escape key
-            if self.input_state.escape_pressed(ui) {
+            if self.input_state.action_pressed(ui, EditorAction::Cancel) {
                 self.input_state.close_context_menu();
                 self.menus.reset();
             }
         }
     }
 
+    /// Apply an annotation change to the graph being viewed
+    fn apply_annotation_edit(&mut self, edit: AnnotationEdit) {
+        let annotations = &mut self.get_active_graph_mut().annotations;
+        let description = match edit {
+            AnnotationEdit::Add(annotation) => {
+                annotations.push(annotation);
+                "added an annotation"
+            }
+            AnnotationEdit::Remove(index) if index < annotations.len() => {
+                annotations.remove(index);
+                "erased an annotation"
+            }
+            AnnotationEdit::Clear if !annotations.is_empty() => {
+                annotations.clear();
+                "cleared annotations"
+            }
+            _ => return,
+        };
+        self.session_history.record(description);
+        self.mark_modified();
+    }
+
+    /// Apply a change from the selection sets window to the graph being viewed
+    fn apply_selection_set_action(&mut self, action: SelectionSetAction) {
+        let selected: Vec<NodeId> = self.interaction.selected_nodes.iter().copied().collect();
+        match action {
+            SelectionSetAction::Save(name) => {
+                let mut nodes = selected;
+                nodes.sort_unstable();
+                self.session_history.record(format!("saved selection set {}", name));
+                self.get_active_graph_mut().selection_sets.insert(name, nodes);
+                self.mark_modified();
+            }
+            SelectionSetAction::Recall(name) => {
+                let graph = self.get_active_graph();
+                let Some(nodes) = graph.selection_sets.get(&name) else {
+                    return;
+                };
+                let nodes: Vec<NodeId> = nodes.iter().copied().filter(|id| graph.nodes.contains_key(id)).collect();
+                self.interaction.clear_selection();
+                for node_id in nodes {
+                    self.interaction.select_node(node_id, true);
+                }
+            }
+            SelectionSetAction::Delete(name) => {
+                if self.get_active_graph_mut().selection_sets.remove(&name).is_some() {
+                    self.session_history.record(format!("deleted selection set {}", name));
+                    self.mark_modified();
+                }
+            }
+            SelectionSetAction::Isolate(name) => {
+                let nodes: HashSet<NodeId> = match name {
+                    Some(name) => self.get_active_graph().selection_sets.get(&name).cloned().unwrap_or_default(),
+                    None => selected,
+                }.into_iter().collect();
+                // Hidden nodes can't stay selected
+                self.interaction.selected_nodes.retain(|node_id| nodes.contains(node_id));
+                self.selection_sets.isolation = Some(Isolation {
+                    graph: self.navigation.get_workspace_node_id(),
+                    nodes,
+                });
+            }
+            SelectionSetAction::EndIsolation => {
+                self.selection_sets.isolation = None;
+            }
+        }
+    }
+
+    /// Apply a change from the Wires window to the graph being viewed
+    fn apply_wire_action(&mut self, action: WireAction) {
+        match action {
+            WireAction::Label { connection: index, label } => {
+                let Some(connection) = self.get_active_graph().connections.get(index) else {
+                    return;
+                };
+                let description = self.describe_connection(connection);
+                self.session_history.record(if label.is_empty() {
+                    format!("removed the label of {}", description)
+                } else {
+                    format!("labelled {} as {}", description, label)
+                });
+                if let Some(connection) = self.get_active_graph_mut().connections.get_mut(index) {
+                    // A named wire without a name would be drawn as nothing
+                    connection.named &= !label.is_empty();
+                    connection.label = label;
+                }
+                self.mark_modified();
+            }
+            WireAction::SetNamed { connection: index, named } => {
+                if let Some(connection) = self.get_active_graph_mut().connections.get_mut(index) {
+                    connection.named = named && !connection.label.is_empty();
+                    self.mark_modified();
+                }
+            }
+            WireAction::PickUp { label, from_node, from_port, to_node, to_port } => {
+                // An input takes one connection, so the named wire replaces the current one
+                let existing = self.get_active_graph().connections.iter()
+                    .position(|connection| connection.to_node == to_node && connection.to_port == to_port);
+                if let Some(index) = existing {
+                    self.remove_connection_from_active_graph(index);
+                    self.interaction.clear_connection_selection();
+                }
+                let connection = Connection { label, named: true, ..Connection::new(from_node, from_port, to_node, to_port) };
+                let description = self.describe_connection(&connection);
+                match self.add_connection_to_active_graph(connection) {
+                    Ok(()) => {
+                        self.session_history.record(format!("picked up named wire {}", description));
+                        self.mark_modified();
+                    }
+                    Err(e) => error!("Failed to pick up named wire: {}", e),
+                }
+            }
+            WireAction::SetAdapter { connection: index, adapter } => {
+                let Some(connection) = self.get_active_graph().connections.get(index) else {
+                    return;
+                };
+                let description = self.describe_connection(connection);
+                self.session_history.record(match adapter {
+                    Some(adapter) => format!("set the adapter of {} to {}", description, adapter.label()),
+                    None => format!("removed the adapter of {}", description),
+                });
+                let Some(connection) = self.get_active_graph_mut().connections.get_mut(index) else {
+                    return;
+                };
+                connection.adapter = adapter;
+                let connection = connection.clone();
+                self.execution_engine.on_connection_adapter_changed(&connection, self.navigation.get_active_graph(&self.graph));
+                self.mark_modified();
+            }
+        }
+    }
+
+    /// Select the nodes picked in the Find Nodes dialog and frame them if asked
+    fn select_found_nodes(&mut self, ctx: &egui::Context, found: FindSelection) {
+        if !self.find_nodes.adds_to_selection() {
+            self.interaction.clear_selection();
+        }
+        for node_id in &found.nodes {
+            self.interaction.select_node(*node_id, true);
+        }
+        if found.frame {
+            let graph = self.get_active_graph();
+            let bounds = found.nodes.iter()
+                .filter_map(|node_id| graph.nodes.get(node_id))
+                .map(|node| node.get_rect())
+                .reduce(|a, b| a.union(b));
+            if let Some(bounds) = bounds {
+                let view = Rect::from_min_max(Pos2::new(0.0, self.current_menu_bar_height), ctx.screen_rect().max);
+                self.canvas.frame_rect(bounds, view);
+            }
+        }
+    }
+
+    /// Open the hotbox when space is pressed over the canvas and apply what gets picked
+    /// Report plugin panics caught since the last frame and act on the user's choice
+    fn update_plugin_crashes(&mut self, ctx: &egui::Context) {
+        for node_id in self.plugin_crash_dialog.collect() {
+            self.execution_engine.mark_error(node_id);
+        }
+        match self.plugin_crash_dialog.render(ctx) {
+            CrashAction::DisablePlugin(name) => {
+                if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+                    plugin_host.submit(PluginCommand::Disable(name));
+                }
+            }
+            CrashAction::RetryNode(node_id) => {
+                crate::plugins::sandbox::reset_node(node_id);
+                self.execution_engine.mark_dirty(node_id, &self.graph);
+            }
+            CrashAction::None => {}
+        }
+    }
+
+    fn update_hotbox(&mut self, ctx: &egui::Context) {
+        if !self.hotbox.is_open() && Hotbox::open_requested(ctx) {
+            // Only over the canvas, not over windows or panels drawn on top of it
+            let pointer = ctx.input(|input| input.pointer.hover_pos())
+                .filter(|pos| ctx.layer_id_at(*pos).map_or(true, |layer| layer.order == egui::Order::Background));
+            if let Some(pointer) = pointer {
+                let menu = self.workspace_manager.get_menu_for_path(&self.navigation.current_path);
+                let usage_stats = &self.usage_stats;
+                self.hotbox.open(pointer, menu, |node_type| usage_stats.usage_count(node_type));
+            }
+        }
+
+        match self.hotbox.render(ctx) {
+            HotboxAction::CreateNode(node_type, screen_pos) => {
+                let world_pos = self.canvas.screen_to_world(screen_pos);
+                self.create_node(&node_type, world_pos);
+            }
+            HotboxAction::EnterWorkspace(workspace_name) => {
+                self.navigation.enter_workspace(&workspace_name);
+                self.workspace_manager.set_active_workspace_by_id(Some(&workspace_name));
+            }
+            HotboxAction::None => {}
+        }
+    }
+
     fn create_node(&mut self, node_type: &str, position: Pos2) {
-        // Debug print removed
+        // Remember a single selected node so the new node can be wired to it
+        let wire_from = if self.auto_wire_new_nodes && self.interaction.selected_nodes.len() == 1 {
+            self.interaction.selected_nodes.iter().next().copied()
+        } else {
+            None
+        };
+        
         // Delegate to WorkspaceBuilder for all node creation logic
         if let Some(node_id) = WorkspaceBuilder::create_node(
             node_type,
@@ -420,7 +881,11 @@ impl NodeEditor {
             &self.workspace_manager,
             &mut self.graph,
         ) {
-            // Debug prints removed
+            // Nodes created while isolating join the isolated set so they stay visible
+            let viewed_graph = self.navigation.get_workspace_node_id();
+            if let Some(isolation) = self.selection_sets.isolation.as_mut().filter(|isolation| isolation.graph == viewed_graph) {
+                isolation.nodes.insert(node_id);
+            }
             // Use the actual NodeId returned from create_node instead of unreliable HashMap iteration
             let viewed_nodes = self.get_viewed_nodes();
             if let Some(node) = viewed_nodes.get(&node_id) {
@@ -455,7 +920,8 @@ impl NodeEditor {
                         crate::nodes::interface::PanelType::Parameter |
                         crate::nodes::interface::PanelType::Viewport |
                         crate::nodes::interface::PanelType::Tree |
-                        crate::nodes::interface::PanelType::Spreadsheet => {
+                        crate::nodes::interface::PanelType::Spreadsheet |
+                        crate::nodes::interface::PanelType::Inspector => {
                             let panel_manager = self.panel_manager.interface_panel_manager_mut();
                             panel_manager.set_panel_visibility(node_id, true);
                             panel_manager.set_panel_open(node_id, true);
@@ -470,17 +936,58 @@ impl NodeEditor {
                             }
                         },
                         crate::nodes::interface::PanelType::Viewer |
-                        crate::nodes::interface::PanelType::Editor |
-                        crate::nodes::interface::PanelType::Inspector => {
+                        crate::nodes::interface::PanelType::Editor => {
                             // Other panel types could auto-open in the future
                         }
                     }
                 }
             }
             
+            if let Some((title, type_id)) = self.get_active_graph().nodes.get(&node_id).map(|node| (node.title.clone(), node.type_id.clone())) {
+                self.session_history.record(format!("added {}", title));
+                self.usage_stats.record_node_added(&type_id);
+            }
+            
+            if let Some(source_id) = wire_from {
+                self.auto_wire_new_node(source_id, node_id);
+            }
+            
             self.mark_modified();
         }
     }
+    
+    /// Connect the first compatible output of `source_id` to the first free compatible input of `new_node_id`
+    fn auto_wire_new_node(&mut self, source_id: NodeId, new_node_id: NodeId) {
+        let (source_type, target_type, occupied_inputs) = {
+            let graph = self.get_active_graph();
+            let (Some(source), Some(target)) = (graph.nodes.get(&source_id), graph.nodes.get(&new_node_id)) else {
+                return;
+            };
+            let occupied: Vec<usize> = graph.connections.iter()
+                .filter(|c| c.to_node == new_node_id)
+                .map(|c| c.to_port)
+                .collect();
+            (source.type_id.clone(), target.type_id.clone(), occupied)
+        };
+        
+        let catalog = crate::nodes::factory::NodeCatalog::current();
+        let (Some(source_meta), Some(target_meta)) = (catalog.metadata(&source_type), catalog.metadata(&target_type)) else {
+            debug!("Auto-wire skipped: no metadata for '{}' or '{}'", source_type, target_type);
+            return;
+        };
+        
+        if let Some((from_port, to_port)) = source_meta.first_compatible_connection(target_meta, &occupied_inputs) {
+            let connection = Connection::new(source_id, from_port, new_node_id, to_port);
+            match self.add_connection_to_active_graph(connection) {
+                Ok(()) => {
+                    debug!("Auto-wired {}:{} -> {}:{}", source_id, from_port, new_node_id, to_port);
+                    // Select the new node so the next creation continues the chain
+                    self.interaction.select_node(new_node_id, false);
+                }
+                Err(e) => debug!("Auto-wire failed: {}", e),
+            }
+        }
+    }
 
     /// Add benchmark nodes in a grid pattern for performance testing
     fn add_benchmark_nodes(&mut self, count: usize) {
@@ -541,6 +1048,7 @@ impl NodeEditor {
     /// Create a new file (reset graph state)
     pub fn new_file(&mut self) {
         self.graph = NodeGraph::new();
+        self.selection_sets.isolation = None;
         self.execution_engine = NodeGraphEngine::new();
         self.navigation.set_root_view();
         self.navigation = NavigationManager::new();
@@ -548,22 +1056,27 @@ impl NodeEditor {
         self.file_manager.new_file();
         // Reset context manager to root (no active context)
         self.workspace_manager.set_active_workspace_by_id(None);
+        self.session_history.record("started a new project");
     }
     
     /// Save the current graph to a specific file path
     pub fn save_to_file(&mut self, file_path: &Path) -> Result<(), String> {
-        self.file_manager.save_to_file(file_path, &self.graph, &self.canvas)
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        self.file_manager.save_to_file(file_path, &self.graph, &self.canvas, &panel_layout)
     }
     
     /// Load a graph from a specific file path
     pub fn load_from_file(&mut self, file_path: &Path) -> Result<(), String> {
         match self.file_manager.load_from_file(file_path) {
-            Ok((graph, canvas)) => {
+            Ok((graph, canvas, panel_layout)) => {
                 self.graph = graph;
                 self.canvas = canvas;
+                self.selection_sets.isolation = None;
+                self.panel_manager.interface_panel_manager_mut().import_layout(&panel_layout);
                 
                 // Reset execution engine and mark all nodes dirty
                 self.execution_engine = NodeGraphEngine::new();
+                self.execution_engine.set_seed(self.graph.seed, &self.graph);
                 self.execution_engine.mark_all_dirty(&self.graph);
                 
                 // Reset view state
@@ -573,62 +1086,659 @@ impl NodeEditor {
                 // Reset context manager to root (no active context)
                 self.workspace_manager.set_active_workspace_by_id(None);
                 
+                // Pull in the latest version of referenced subgraphs
+                let project_dir = self.project_dir();
+                for error in file_manager::reload_references(&mut self.graph, project_dir.as_deref()) {
+                    error!("Failed to reload reference: {}", error);
+                }
+                
                 // Update port positions and rebuild GPU instances
                 self.graph.update_all_port_positions();
                 
+                self.session_history.record(format!("opened {}", self.get_file_display_name()));
                 Ok(())
             }
-            Err(error) => Err(error)
+            Err(error) => Err(error)
+        }
+    }
+    
+    /// Get display name for the current file
+    pub fn get_file_display_name(&self) -> String {
+        self.file_manager.get_file_display_name()
+    }
+    
+    /// Check if there are unsaved changes
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.file_manager.has_unsaved_changes()
+    }
+    
+    /// Mark the file as modified
+    pub fn mark_modified(&mut self) {
+        self.file_manager.mark_modified();
+    }
+    
+    /// Open file dialog and load selected file
+    pub fn open_file_dialog(&mut self) {
+        match self.file_manager.open_file_dialog() {
+            Ok(Some((graph, canvas, panel_layout))) => {
+                self.graph = graph;
+                self.canvas = canvas;
+                self.selection_sets.isolation = None;
+                self.panel_manager.interface_panel_manager_mut().import_layout(&panel_layout);
+                
+                // Reset view state
+                self.navigation.set_root_view();
+                self.navigation = NavigationManager::new();
+                self.interaction.clear_selection();
+                // Reset context manager to root (no active context)
+                self.workspace_manager.set_active_workspace_by_id(None);
+                
+                // Pull in the latest version of referenced subgraphs
+                let project_dir = self.project_dir();
+                for error in file_manager::reload_references(&mut self.graph, project_dir.as_deref()) {
+                    error!("Failed to reload reference: {}", error);
+                }
+                
+                // Update port positions and rebuild GPU instances
+                self.graph.update_all_port_positions();
+                
+                self.session_history.record(format!("opened {}", self.get_file_display_name()));
+            }
+            Ok(None) => {
+                // User cancelled - do nothing
+            }
+            Err(error) => {
+                error!("Failed to load file: {}", error);
+                // TODO: Show error dialog to user
+            }
+        }
+    }
+    
+    /// Start a new unsaved project from a template
+    pub fn new_from_template(&mut self, template: &ProjectTemplate) {
+        match self.file_manager.new_from_template(template) {
+            Ok((graph, canvas, panel_layout)) => {
+                self.graph = graph;
+                self.canvas = canvas;
+                self.selection_sets.isolation = None;
+                self.panel_manager.interface_panel_manager_mut().import_layout(&panel_layout);
+                
+                // Reset execution engine and mark all nodes dirty
+                self.execution_engine = NodeGraphEngine::new();
+                self.execution_engine.set_seed(self.graph.seed, &self.graph);
+                self.execution_engine.mark_all_dirty(&self.graph);
+                
+                // Reset view state
+                self.navigation.set_root_view();
+                self.navigation = NavigationManager::new();
+                self.interaction.clear_selection();
+                self.workspace_manager.set_active_workspace_by_id(None);
+                
+                self.graph.update_all_port_positions();
+                
+                self.session_history.record(format!("started a new project from template {}", template.name));
+            }
+            Err(error) => error!("Failed to create project from template '{}': {}", template.name, error),
+        }
+    }
+    
+    /// Save the current graph as a user template
+    pub fn save_as_template(&mut self, name: &str) {
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        match self.file_manager.save_as_template(name, &self.graph, &self.canvas, &panel_layout) {
+            Ok(path) => {
+                info!("Saved template '{}' to {}", name, path.display());
+                self.session_history.record(format!("saved template {}", name.trim()));
+            }
+            Err(error) => error!("Failed to save template '{}': {}", name, error),
+        }
+    }
+    
+    /// Prompt for a template name after File → Save as Template
+    fn render_template_name_prompt(&mut self, ctx: &egui::Context) {
+        let Some(mut name) = self.template_name_prompt.take() else {
+            return;
+        };
+        let mut keep_open = true;
+        let mut save = false;
+        egui::Window::new("Save as Template")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    let response = ui.text_edit_singleline(&mut name);
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        save = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!name.trim().is_empty(), egui::Button::new("Save")).clicked() {
+                        save = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        keep_open = false;
+                    }
+                });
+            });
+        if save && !name.trim().is_empty() {
+            self.save_as_template(&name);
+        } else if keep_open {
+            self.template_name_prompt = Some(name);
+        }
+    }
+    
+    /// Move the selection to the first upstream or downstream neighbor of the
+    /// selected node, or add that neighbor when extending; extending continues
+    /// from the end of the walk so far. Pans to the node when it's off screen.
+    fn walk_selection(&mut self, ctx: &egui::Context, upstream: bool, extend: bool) {
+        let selected = &self.interaction.selected_nodes;
+        let from = match self.walk_end {
+            Some(end) if extend && selected.contains(&end) => end,
+            _ if selected.len() == 1 => *selected.iter().next().unwrap(),
+            _ => return,
+        };
+        let graph = self.get_active_graph();
+        let next = if upstream { graph.first_upstream(from) } else { graph.first_downstream(from) };
+        let graph_id = self.navigation.get_workspace_node_id();
+        let hidden = |node_id: &NodeId| self.selection_sets.isolation.as_ref().is_some_and(|isolation| !isolation.shows(graph_id, *node_id));
+        let Some(next) = next.filter(|node_id| !hidden(node_id)) else {
+            return;
+        };
+        if !extend {
+            self.interaction.clear_selection();
+        }
+        self.interaction.selected_nodes.insert(next);
+        self.walk_end = Some(next);
+
+        let Some(rect) = self.get_active_graph().nodes.get(&next).map(|node| node.get_rect()) else {
+            return;
+        };
+        let screen_rect = Rect::from_min_max(
+            (rect.min.to_vec2() * self.canvas.zoom + self.canvas.pan_offset).to_pos2(),
+            (rect.max.to_vec2() * self.canvas.zoom + self.canvas.pan_offset).to_pos2(),
+        );
+        let view = Rect::from_min_max(Pos2::new(0.0, self.current_menu_bar_height), ctx.screen_rect().max);
+        if !view.contains_rect(screen_rect) {
+            self.canvas.pan_offset += view.center() - screen_rect.center();
+        }
+    }
+
+    /// Start editing a node's title on the canvas
+    fn start_title_edit(&mut self, node_id: NodeId) {
+        if let Some(node) = self.get_active_graph().nodes.get(&node_id) {
+            self.title_edit = Some((node_id, node.title.clone()));
+        }
+    }
+    
+    /// Text field over the title of the node being renamed; Enter or clicking
+    /// away renames it, Escape keeps the old title
+    fn render_title_edit(&mut self, ctx: &egui::Context) {
+        let Some((node_id, mut text)) = self.title_edit.take() else {
+            return;
+        };
+        let Some(node) = self.get_active_graph().nodes.get(&node_id) else {
+            return;
+        };
+        let zoom = self.canvas.zoom;
+        let center = (node.position + Vec2::new(node.size.x / 2.0, 15.0)).to_vec2() * zoom + self.canvas.pan_offset;
+        let size = Vec2::new(node.size.x * zoom, 20.0 * zoom);
+        let response = egui::Area::new(egui::Id::new("node_title_edit"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(center.to_pos2() - size / 2.0)
+            .show(ctx, |ui| {
+                ui.add_sized(size, egui::TextEdit::singleline(&mut text)
+                    .horizontal_align(egui::Align::Center)
+                    .font(egui::FontId::proportional(12.0 * zoom)))
+            })
+            .inner;
+        if response.lost_focus() {
+            if !ctx.input(|input| input.key_pressed(egui::Key::Escape)) {
+                self.rename_node(ctx, node_id, &text);
+            }
+        } else {
+            if !response.has_focus() {
+                response.request_focus();
+            }
+            self.title_edit = Some((node_id, text));
+        }
+    }
+    
+    /// Rename a node of the viewed graph, keeping titles unique within it
+    fn rename_node(&mut self, ctx: &egui::Context, node_id: NodeId, name: &str) {
+        let fit_name = self.panel_manager.interface_panel_manager().get_fit_name(node_id);
+        let graph = self.get_active_graph_mut();
+        let Some(old_title) = graph.nodes.get(&node_id).map(|node| node.title.clone()) else {
+            return;
+        };
+        let Some(title) = graph.rename_node(node_id, name).filter(|title| *title != old_title) else {
+            return;
+        };
+        if fit_name {
+            if let Some(node) = graph.nodes.get_mut(&node_id) {
+                panels::fit_node_to_title(ctx, node);
+            }
+        }
+        self.session_history.record(format!("renamed {} to {}", old_title, title));
+        self.mark_modified();
+    }
+    
+    /// Folder of the current project file, used to resolve relative subgraph references
+    fn project_dir(&self) -> Option<std::path::PathBuf> {
+        self.file_manager.current_file_path()
+            .and_then(|path| path.parent())
+            .map(|dir| dir.to_path_buf())
+    }
+    
+    /// Save the selected workspace node's subgraph to its own file and link the node to it
+    pub fn save_selected_subgraph_dialog(&mut self) {
+        let selected = if self.interaction.selected_nodes.len() == 1 {
+            self.interaction.selected_nodes.iter().next().copied()
+        } else {
+            None
+        };
+        let Some(node_id) = selected.filter(|id| self.graph.nodes.get(id).is_some_and(|node| node.is_workspace())) else {
+            error!("Select a single workspace node in the root graph to save it as a subgraph");
+            return;
+        };
+        
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Nōdle subgraph", &[file_manager::SUBGRAPH_EXTENSION])
+            .save_file()
+        else {
+            return;
+        };
+        
+        match file_manager::save_subgraph(&self.graph.nodes[&node_id], &path) {
+            Ok(()) => {
+                let reference = file_manager::reference_path_for(&path, self.project_dir().as_deref());
+                if let Some(node) = self.graph.nodes.get_mut(&node_id) {
+                    let _ = node.set_reference(Some(reference));
+                    self.session_history.record(format!("saved {} as subgraph {}", node.title, path.display()));
+                }
+                self.mark_modified();
+            }
+            Err(error) => error!("Failed to save subgraph: {}", error),
+        }
+    }
+    
+    /// Add a workspace node referencing a subgraph file to the root graph
+    pub fn reference_subgraph_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Nōdle subgraph", &[file_manager::SUBGRAPH_EXTENSION])
+            .pick_file()
+        else {
+            return;
+        };
+        
+        let position = self.canvas.screen_to_world(Pos2::new(200.0, 200.0));
+        match file_manager::create_reference_node(&path, self.project_dir().as_deref(), position) {
+            Ok(mut node) => {
+                node.title = self.graph.unique_title(&node.title, None);
+                let title = node.title.clone();
+                let node_id = self.graph.add_node(node);
+                self.execution_engine.mark_dirty(node_id, &self.graph);
+                self.session_history.record(format!("referenced subgraph {} as {}", path.display(), title));
+                self.mark_modified();
+            }
+            Err(error) => error!("Failed to reference subgraph: {}", error),
+        }
+    }
+    
+    /// Refresh all referencing workspace nodes from their subgraph files
+    pub fn reload_references(&mut self) {
+        let project_dir = self.project_dir();
+        let errors = file_manager::reload_references(&mut self.graph, project_dir.as_deref());
+        for error in &errors {
+            error!("Failed to reload reference: {}", error);
+        }
+        self.execution_engine.mark_all_dirty(&self.graph);
+        self.graph.update_all_port_positions();
+        self.session_history.record("reloaded subgraph references");
+    }
+    
+    /// Highlight the differences between another project file and the current graph
+    pub fn compare_with_file_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Nōdle files", &["json", "nodlez"])
+            .pick_file()
+        else {
+            return;
+        };
+        
+        match file_manager::read_save_data(&path) {
+            Ok((other, _, _)) => {
+                let label = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+                self.diff_view.show(label, graph_diff::diff_graphs(&other, &self.graph), Vec::new());
+            }
+            Err(error) => error!("Failed to load {} for comparison: {}", path.display(), error),
+        }
+    }
+    
+    /// Three-way merge: pick the common ancestor and the other revision, merge into the current graph
+    pub fn merge_with_files_dialog(&mut self) {
+        let Some(base_path) = rfd::FileDialog::new()
+            .set_title("Select the common ancestor (base) version")
+            .add_filter("Nōdle files", &["json", "nodlez"])
+            .pick_file()
+        else {
+            return;
+        };
+        let Some(their_path) = rfd::FileDialog::new()
+            .set_title("Select the version to merge in")
+            .add_filter("Nōdle files", &["json", "nodlez"])
+            .pick_file()
+        else {
+            return;
+        };
+        
+        let loaded = file_manager::read_save_data(&base_path)
+            .and_then(|(base, _, _)| file_manager::read_save_data(&their_path).map(|(theirs, _, _)| (base, theirs)));
+        let (base, theirs) = match loaded {
+            Ok(graphs) => graphs,
+            Err(error) => {
+                error!("Failed to load files for merge: {}", error);
+                return;
+            }
+        };
+        
+        let result = graph_diff::merge_three_way(&base, &self.graph, &theirs);
+        let label = format!("pre-merge version (merged {})", their_path.display());
+        self.diff_view.show(label, graph_diff::diff_graphs(&self.graph, &result.graph), result.conflicts);
+        self.graph = result.graph;
+        self.selection_sets.isolation = None;
+        self.navigation = NavigationManager::new();
+        self.interaction.clear_selection();
+        self.workspace_manager.set_active_workspace_by_id(None);
+        self.graph.update_all_port_positions();
+        self.execution_engine.mark_all_dirty(&self.graph);
+        self.session_history.record(format!("merged {}", their_path.display()));
+        self.mark_modified();
+    }
+    
+    /// Save a self-contained archive of the project and the assets it uses
+    pub fn save_packed_archive_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Nōdle packed project", &[project_archive::ARCHIVE_EXTENSION])
+            .save_file()
+        else {
+            return;
+        };
+        
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        let project_dir = self.project_dir();
+        match project_archive::pack_project(&path, &self.graph, &self.canvas, &panel_layout, project_dir.as_deref()) {
+            Ok(report) => {
+                for warning in &report.warnings {
+                    warn!("{}", warning);
+                }
+                info!("Packed project to {}", report.path.display());
+                self.session_history.record(format!("packed project to {}", report.path.display()));
+            }
+            Err(error) => error!("Failed to pack project: {}", error),
+        }
+    }
+    
+    /// Extract a packed archive into a folder and open the extracted project
+    pub fn extract_packed_archive_dialog(&mut self) {
+        let Some(archive_path) = rfd::FileDialog::new()
+            .add_filter("Nōdle packed project", &[project_archive::ARCHIVE_EXTENSION])
+            .pick_file()
+        else {
+            return;
+        };
+        let Some(dest_dir) = rfd::FileDialog::new()
+            .set_title("Extract project to folder")
+            .pick_folder()
+        else {
+            return;
+        };
+        
+        match project_archive::extract_project(&archive_path, &dest_dir) {
+            Ok(report) => {
+                for warning in &report.warnings {
+                    warn!("{}", warning);
+                }
+                if let Err(error) = self.load_from_file(&report.path) {
+                    error!("Failed to open extracted project {}: {}", report.path.display(), error);
+                }
+            }
+            Err(error) => error!("Failed to extract {}: {}", archive_path.display(), error),
+        }
+    }
+    
+    /// The single selected node, if exactly one node is selected
+    fn single_selected_node(&self) -> Option<NodeId> {
+        if self.interaction.selected_nodes.len() == 1 {
+            self.interaction.selected_nodes.iter().next().copied()
+        } else {
+            None
+        }
+    }
+    
+    /// Write the selected node's parameters to a parameter set file
+    pub fn export_node_parameters_dialog(&mut self) {
+        let Some(node) = self.single_selected_node().and_then(|node_id| self.get_active_graph().nodes.get(&node_id)) else {
+            error!("Select a single node to export its parameters");
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Parameter set", &["json"])
+            .set_file_name(format!("{}.params.json", node.title))
+            .save_file()
+        else {
+            return;
+        };
+        
+        let result = serde_json::to_string_pretty(&parameter_io::export_parameters(node))
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+        match result {
+            Ok(()) => info!("Exported {} parameters to {}", node.title, path.display()),
+            Err(error) => error!("Failed to export parameters: {}", error),
+        }
+    }
+    
+    /// Validate a parameter set file and apply it to the selected node
+    pub fn import_node_parameters_dialog(&mut self) {
+        let Some(node_id) = self.single_selected_node().filter(|node_id| self.get_active_graph().nodes.contains_key(node_id)) else {
+            error!("Select a single node to import parameters into");
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Parameter set", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        let json = match std::fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("Failed to read {}: {}", path.display(), e);
+                return;
+            }
+        };
+        
+        let node = &self.get_active_graph().nodes[&node_id];
+        let schema = crate::nodes::factory::NodeCatalog::current()
+            .metadata(&node.type_id)
+            .map(|metadata| metadata.parameters.clone())
+            .unwrap_or_default();
+        let values = match parameter_io::import_parameters(node, &json, &schema) {
+            Ok(values) => values,
+            Err(errors) => {
+                error!("Parameters in {} were not applied:", path.display());
+                for error in errors {
+                    error!("  {}", error);
+                }
+                return;
+            }
+        };
+        
+        let mut changes = Vec::new();
+        if let Some(node) = self.get_active_graph_mut().nodes.get_mut(&node_id) {
+            for (name, value) in values {
+                if let Some(old_value) = node.parameters.insert(name.clone(), value.clone()) {
+                    changes.push((node.title.clone(), name, old_value, value));
+                }
+            }
         }
+        for (title, name, old_value, new_value) in &changes {
+            self.session_history.record_parameter_change(node_id, title, name, old_value, new_value);
+        }
+        self.execution_engine.on_node_parameter_changed(node_id, self.navigation.get_active_graph(&self.graph));
+        self.mark_modified();
     }
     
-    /// Get display name for the current file
-    pub fn get_file_display_name(&self) -> String {
-        self.file_manager.get_file_display_name()
+    /// Shader network to export: the MaterialX workspace being viewed, or a selected MaterialX workspace node
+    fn materialx_network(&self) -> Option<&NodeGraph> {
+        let is_materialx = |node: &Node| node.get_workspace_type().is_some_and(|workspace_type| workspace_type.starts_with("MaterialX"));
+        let viewed = self.navigation.get_workspace_node_id().and_then(|node_id| self.graph.nodes.get(&node_id));
+        viewed.filter(|node| is_materialx(node))
+            .or_else(|| self.single_selected_node().and_then(|node_id| self.get_active_graph().nodes.get(&node_id)).filter(|node| is_materialx(node)))
+            .and_then(|node| node.get_internal_graph())
     }
     
-    /// Check if there are unsaved changes
-    pub fn has_unsaved_changes(&self) -> bool {
-        self.file_manager.has_unsaved_changes()
+    /// Write the current MaterialX shader network to a .mtlx document
+    pub fn export_materialx_dialog(&mut self) {
+        let Some(network) = self.materialx_network() else {
+            error!("Enter or select a MaterialX workspace to export its shader network");
+            return;
+        };
+        let export = crate::nodes::materialx::mtlx::export_document(network);
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("MaterialX", &["mtlx"])
+            .set_file_name("material.mtlx")
+            .save_file()
+        else {
+            return;
+        };
+        
+        match std::fs::write(&path, &export.document) {
+            Ok(()) => info!("Exported MaterialX network to {}", path.display()),
+            Err(e) => error!("Failed to write {}: {}", path.display(), e),
+        }
+        for warning in &export.warnings {
+            warn!("MaterialX export: {}", warning);
+        }
     }
     
-    /// Mark the file as modified
-    pub fn mark_modified(&mut self) {
-        self.file_manager.mark_modified();
+    /// Read a .mtlx document into the MaterialX workspace being viewed, or into a new MaterialX workspace node
+    pub fn import_materialx_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("MaterialX", &["mtlx"])
+            .pick_file()
+        else {
+            return;
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => {
+                error!("Failed to read {}: {}", path.display(), e);
+                return;
+            }
+        };
+        
+        let origin = self.canvas.screen_to_world(Pos2::new(200.0, 200.0));
+        let in_materialx = self.navigation.get_workspace_type(&self.graph)
+            .is_some_and(|workspace_type| workspace_type.starts_with("MaterialX"));
+        let result = if in_materialx {
+            crate::nodes::materialx::mtlx::import_document(&text, self.get_active_graph_mut(), origin)
+        } else if self.navigation.is_root_view() {
+            let mut workspace_node = Node::new_workspace(0, "MaterialX", origin);
+            workspace_node.set_panel_type(crate::nodes::interface::PanelType::Parameter);
+            let imported = workspace_node.get_internal_graph_mut()
+                .ok_or_else(|| "MaterialX workspace has no graph".to_string())
+                .and_then(|graph| crate::nodes::materialx::mtlx::import_document(&text, graph, Pos2::new(100.0, 100.0)));
+            if imported.is_ok() {
+                self.graph.add_node(workspace_node);
+            }
+            imported
+        } else {
+            Err("MaterialX documents import into a MaterialX workspace or the root graph".to_string())
+        };
+        
+        match result {
+            Ok(import) => {
+                for warning in &import.warnings {
+                    warn!("MaterialX import: {}", warning);
+                }
+                info!("Imported {} MaterialX nodes from {}", import.nodes.len(), path.display());
+                self.execution_engine.mark_all_dirty(self.navigation.get_active_graph(&self.graph));
+                self.session_history.record(format!("imported MaterialX document {}", path.display()));
+                self.mark_modified();
+            }
+            Err(error) => error!("Failed to import {}: {}", path.display(), error),
+        }
     }
     
-    /// Open file dialog and load selected file
-    pub fn open_file_dialog(&mut self) {
-        match self.file_manager.open_file_dialog() {
-            Ok(Some((graph, canvas))) => {
-                self.graph = graph;
-                self.canvas = canvas;
-                
-                // Reset view state
-                self.navigation.set_root_view();
-                self.navigation = NavigationManager::new();
-                self.interaction.clear_selection();
-                // Reset context manager to root (no active context)
-                self.workspace_manager.set_active_workspace_by_id(None);
-                
-                // Update port positions and rebuild GPU instances
-                self.graph.update_all_port_positions();
+    /// Record button: capture the window or the selected node's panel to a GIF
+    fn render_record_controls(&mut self, ui: &mut egui::Ui) {
+        if self.screen_recorder.is_recording() {
+            let label = format!("⏹ Stop ({} frames)", self.screen_recorder.frames_captured());
+            if ui.add(egui::Button::new(label).fill(Color32::DARK_RED)).clicked() {
+                if let Err(error) = self.screen_recorder.stop() {
+                    error!("Failed to stop recording: {}", error);
+                }
             }
-            Ok(None) => {
-                // User cancelled - do nothing
+            return;
+        }
+        
+        // Panel rect of a single selected node, if its panel has been shown
+        let selected_panel_rect = if self.interaction.selected_nodes.len() == 1 {
+            self.interaction.selected_nodes.iter().next()
+                .and_then(|node_id| self.panel_manager.interface_panel_manager().get_panel_rect(*node_id))
+        } else {
+            None
+        };
+        
+        if self.screen_recorder.is_saving() {
+            ui.label("Saving recording...");
+        }
+        
+        let mut start_region = None;
+        ui.menu_button("⏺ Record", |ui| {
+            if ui.button("Record Window (GIF)...").clicked() {
+                start_region = Some(None);
+                ui.close_menu();
             }
-            Err(error) => {
-                error!("Failed to load file: {}", error);
-                // TODO: Show error dialog to user
+            if ui.add_enabled(selected_panel_rect.is_some(), egui::Button::new("Record Selected Panel (GIF)..."))
+                .on_disabled_hover_text("Select a node whose panel is open")
+                .clicked()
+            {
+                start_region = Some(selected_panel_rect);
+                ui.close_menu();
+            }
+        });
+        
+        if let Some(region) = start_region {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("GIF animation", &["gif"])
+                .set_file_name("nodle_recording.gif")
+                .save_file()
+            {
+                if let Err(error) = self.screen_recorder.start(&path, region, screen_recorder::DEFAULT_CAPTURE_FPS) {
+                    error!("Failed to start recording: {}", error);
+                }
             }
         }
     }
     
+    /// Open a file from the recent files list, dropping it from the list if it can't be loaded
+    pub fn open_recent_file(&mut self, file_path: &Path) {
+        if let Err(error) = self.load_from_file(file_path) {
+            error!("Failed to open recent file {}: {}", file_path.display(), error);
+            self.file_manager.remove_recent_file(file_path);
+        }
+    }
+    
     /// Save to current file path, or prompt for new path if none exists
     pub fn save_file(&mut self) {
-        match self.file_manager.save_file(&self.graph, &self.canvas) {
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        match self.file_manager.save_file(&self.graph, &self.canvas, &panel_layout) {
             Ok(()) => {
-                // File saved successfully
+                self.session_history.record(format!("saved {}", self.get_file_display_name()));
             }
             Err(_) => {
                 // No current path, use save as dialog
@@ -637,11 +1747,38 @@ impl NodeEditor {
         }
     }
     
+    /// Apply saved preferences that take effect immediately
+    fn apply_settings(&mut self, ctx: &egui::Context, settings: &crate::settings::Settings) {
+        crate::theme::apply(ctx, &settings.theme);
+        self.use_gpu_rendering = settings.gpu_rendering;
+        self.auto_wire_new_nodes = settings.auto_wire_new_nodes;
+        self.execution_mode = ExecutionMode::from(settings.execution_mode);
+        self.sync_execution_mode();
+    }
+
+    /// Save a project that already has a file once the autosave interval has passed
+    fn autosave_if_due(&mut self) {
+        let minutes = crate::settings::current().autosave_minutes;
+        if minutes == 0 || self.last_autosave.elapsed() < std::time::Duration::from_secs(u64::from(minutes) * 60) {
+            return;
+        }
+        self.last_autosave = std::time::Instant::now();
+        if self.file_manager.current_file_path().is_none() || !self.file_manager.has_unsaved_changes() {
+            return;
+        }
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        match self.file_manager.save_file(&self.graph, &self.canvas, &panel_layout) {
+            Ok(()) => self.session_history.record(format!("autosaved {}", self.get_file_display_name())),
+            Err(e) => error!("Autosave failed: {}", e),
+        }
+    }
+
     /// Save as dialog
     pub fn save_as_file_dialog(&mut self) {
-        match self.file_manager.save_as_file_dialog(&self.graph, &self.canvas) {
+        let panel_layout = self.panel_manager.interface_panel_manager().export_layout();
+        match self.file_manager.save_as_file_dialog(&self.graph, &self.canvas, &panel_layout) {
             Ok(true) => {
-                // File saved successfully
+                self.session_history.record(format!("saved {}", self.get_file_display_name()));
             }
             Ok(false) => {
                 // User cancelled - do nothing
@@ -717,6 +1854,17 @@ impl NodeEditor {
                 }
             }
         }
+        
+        // Record parameter edits made through the panels in the session history
+        for change in self.panel_manager.take_applied_parameter_changes() {
+            self.session_history.record_parameter_change(
+                change.node_id,
+                &change.node_title,
+                &change.parameter,
+                &change.old_value,
+                &change.new_value,
+            );
+        }
     }
 
     /// Check for node connections and execute automatic data flow
@@ -754,16 +1902,13 @@ impl NodeEditor {
         }
     }
 
-    /// Initialize frame setup (repaint, timing, theme)
+    /// Initialize frame setup (repaint, timing)
     fn initialize_frame(&mut self, ctx: &egui::Context) {
         // Request repaint
         ctx.request_repaint();
 
         // Track frame time for performance monitoring
         self.debug_tools.update_frame_time();
-        
-        // Set dark theme for window decorations
-        ctx.send_viewport_cmd(egui::ViewportCommand::SetTheme(egui::SystemTheme::Dark));
     }
 }
 
@@ -773,10 +1918,13 @@ impl eframe::App for NodeEditor {
         // Initialize frame
         self.initialize_frame(ctx);
         // Frame initialized
+        
+        // Relative file-path parameters resolve against the open project's folder
+        project_paths::set_project_root(self.project_dir());
 
         // Render top menu bar as TopBottomPanel to ensure it's always on top with solid background
         let menu_bar_height = egui::TopBottomPanel::top("top_menu_bar")
-            .frame(egui::Frame::default().fill(Color32::from_rgb(28, 28, 28)).inner_margin(8.0))
+            .frame(egui::Frame::default().fill(crate::theme::colors().main_background).inner_margin(8.0))
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
                     ui.add_space(4.0); // Left padding
@@ -790,22 +1938,82 @@ impl eframe::App for NodeEditor {
                 // Render file menu using EXACT same shared function
                 if self.show_file_menu {
                     let menu_pos = file_button_response.rect.left_bottom();
-                    let menu_items = vec![("New", false), ("Open...", false), ("Save", false), ("Save As...", false)];
+                    let menu_items = vec![
+                        ("New", false), ("New from Template", true), ("Open...", false), ("Open Recent", true),
+                        ("Save", false), ("Save As...", false), ("Save as Template...", false),
+                        ("Save Subgraph As...", false), ("Reference Subgraph...", false), ("Reload References", false),
+                        ("Compare With...", false), ("Merge With...", false),
+                        ("Save Packed Archive...", false), ("Extract Packed Archive...", false),
+                        ("Export Node Parameters...", false), ("Import Node Parameters...", false),
+                        ("Import MaterialX...", false), ("Export MaterialX...", false),
+                    ];
+                    let mut file_submenu = self.file_submenu.clone();
                     
-                    let (selected_item, menu_response) = menus::render_shared_menu(
+                    let (mut selected_item, menu_response) = menus::render_shared_menu(
                         ui.ctx(),
                         "file_menu",
                         menu_pos,
                         menu_items,
                         |ui, items, menu_width| {
-                            for (text, _) in items {
-                                if menus::render_menu_item(ui, text, menu_width) {
+                            for (text, show_arrow) in items {
+                                let item_top = ui.cursor().top();
+                                let (clicked, hovered) = menus::render_menu_item_with_arrow(ui, text, menu_width, *show_arrow);
+                                if hovered {
+                                    // Hovering another item closes any open submenu
+                                    file_submenu = show_arrow.then(|| (text.to_string(), Pos2::new(ui.max_rect().right() + 8.0, item_top - 6.0)));
+                                }
+                                if clicked && !show_arrow {
                                     return Some(text.to_string());
                                 }
                             }
                             None
                         }
                     );
+                    self.file_submenu = file_submenu;
+                    
+                    // File → Open Recent / New from Template submenus: (label, action) pairs
+                    let mut submenu_response = None;
+                    if let Some((submenu, submenu_pos)) = self.file_submenu.clone() {
+                        let entries: Vec<(String, String)> = if submenu == "Open Recent" {
+                            if self.file_manager.recent_files().is_empty() {
+                                vec![("(No recent files)".to_string(), String::new())]
+                            } else {
+                                self.file_manager.recent_files().iter().enumerate()
+                                    .map(|(index, path)| {
+                                        let name = path.file_name()
+                                            .map(|name| name.to_string_lossy().to_string())
+                                            .unwrap_or_else(|| path.display().to_string());
+                                        (name, format!("RECENT:{}", index))
+                                    })
+                                    .chain(std::iter::once(("Clear Recent".to_string(), "Clear Recent".to_string())))
+                                    .collect()
+                            }
+                        } else {
+                            self.file_manager.templates().into_iter().enumerate()
+                                .map(|(index, template)| (template.name, format!("TEMPLATE:{}", index)))
+                                .collect()
+                        };
+                        let submenu_items: Vec<(&str, bool)> = entries.iter().map(|(label, _)| (label.as_str(), false)).collect();
+                        
+                        let (submenu_selected, response) = menus::render_shared_menu(
+                            ui.ctx(),
+                            "file_submenu",
+                            submenu_pos,
+                            submenu_items,
+                            |ui, items, menu_width| {
+                                for ((text, _), (_, action)) in items.iter().zip(&entries) {
+                                    if menus::render_menu_item(ui, text, menu_width) && !action.is_empty() {
+                                        return Some(action.clone());
+                                    }
+                                }
+                                None
+                            }
+                        );
+                        if submenu_selected.is_some() {
+                            selected_item = submenu_selected;
+                        }
+                        submenu_response = Some(response);
+                    }
                     
                     // Handle selected item
                     if let Some(item) = selected_item {
@@ -814,21 +2022,47 @@ impl eframe::App for NodeEditor {
                             "Open..." => self.open_file_dialog(),
                             "Save" => self.save_file(),
                             "Save As..." => self.save_as_file_dialog(),
-                            _ => {}
+                            "Save as Template..." => self.template_name_prompt = Some(self.get_file_display_name().trim_end_matches('*').trim_end_matches(".json").to_string()),
+                            "Save Subgraph As..." => self.save_selected_subgraph_dialog(),
+                            "Reference Subgraph..." => self.reference_subgraph_dialog(),
+                            "Reload References" => self.reload_references(),
+                            "Compare With..." => self.compare_with_file_dialog(),
+                            "Merge With..." => self.merge_with_files_dialog(),
+                            "Save Packed Archive..." => self.save_packed_archive_dialog(),
+                            "Extract Packed Archive..." => self.extract_packed_archive_dialog(),
+                            "Export Node Parameters..." => self.export_node_parameters_dialog(),
+                            "Import Node Parameters..." => self.import_node_parameters_dialog(),
+                            "Import MaterialX..." => self.import_materialx_dialog(),
+                            "Export MaterialX..." => self.export_materialx_dialog(),
+                            "Clear Recent" => self.file_manager.clear_recent_files(),
+                            action => {
+                                if let Some(index) = action.strip_prefix("RECENT:").and_then(|index| index.parse::<usize>().ok()) {
+                                    if let Some(path) = self.file_manager.recent_files().get(index).cloned() {
+                                        self.open_recent_file(&path);
+                                    }
+                                } else if let Some(index) = action.strip_prefix("TEMPLATE:").and_then(|index| index.parse::<usize>().ok()) {
+                                    if let Some(template) = self.file_manager.templates().get(index).cloned() {
+                                        self.new_from_template(&template);
+                                    }
+                                }
+                            }
                         }
                         self.show_file_menu = false;
+                        self.file_submenu = None;
                     }
                     
                     // Close menu if clicked outside
-                    if ui.input(|i| i.pointer.any_click()) && !menu_response.clicked() && !file_button_response.clicked() {
+                    let submenu_clicked = submenu_response.as_ref().is_some_and(|response| response.clicked());
+                    if ui.input(|i| i.pointer.any_click()) && !menu_response.clicked() && !submenu_clicked && !file_button_response.clicked() {
                         self.show_file_menu = false;
+                        self.file_submenu = None;
                     }
                 }
                 
                 ui.separator();
                 
                 // Navigation breadcrumb bar
-                let nav_action = self.navigation.render_breadcrumb(ui);
+                let nav_action = self.navigation.render_breadcrumb(ui, &self.graph);
                 
                 // Handle navigation actions
                 match nav_action {
@@ -904,8 +2138,175 @@ impl eframe::App for NodeEditor {
                             eprintln!("Cook execution failed: {}", e);
                         }
                     }
+                    
+                    // Draft/final switch: viewers show proxies of heavy geometry in draft
+                    use crate::nodes::execution_engine::QualityMode;
+                    let draft = self.execution_engine.get_quality_mode() == QualityMode::Draft;
+                    let (quality_label, quality_color) = if draft {
+                        ("Draft", Color32::from_rgb(90, 140, 200))
+                    } else {
+                        ("Final", Color32::from_rgb(180, 120, 200))
+                    };
+                    if ui.add(egui::Button::new(quality_label).fill(quality_color))
+                        .on_hover_text("Draft: viewports show decimated proxies of heavy geometry. Final: full resolution everywhere. Render and export nodes always use full resolution.")
+                        .clicked()
+                    {
+                        let mode = if draft { QualityMode::Final } else { QualityMode::Draft };
+                        let current_graph = self.navigation.get_active_graph(&self.graph);
+                        self.execution_engine.set_quality_mode(mode, current_graph);
+                        if self.execution_mode == ExecutionMode::Auto {
+                            if let Err(e) = self.execution_engine.execute_dirty_nodes(current_graph) {
+                                eprintln!("Quality switch execution failed: {}", e);
+                            }
+                        }
+                    }
+                    
+                    // Push cooks every dirty node; pull only what display and output nodes need
+                    use crate::nodes::execution_engine::EvaluationStrategy;
+                    let pull = self.execution_engine.get_evaluation_strategy() == EvaluationStrategy::Pull;
+                    let (strategy_label, strategy_color) = if pull {
+                        ("Pull", Color32::from_rgb(200, 140, 80))
+                    } else {
+                        ("Push", Color32::from_rgb(110, 110, 130))
+                    };
+                    if ui.add(egui::Button::new(strategy_label).fill(strategy_color))
+                        .on_hover_text("Push: cook every dirty node. Pull: cook only nodes that viewers, probes, spreadsheets and other outputs depend on; disconnected branches wait until something displays them.")
+                        .clicked()
+                    {
+                        let strategy = if pull { EvaluationStrategy::Push } else { EvaluationStrategy::Pull };
+                        self.execution_engine.set_evaluation_strategy(strategy);
+                        if self.execution_mode == ExecutionMode::Auto {
+                            let current_graph = self.navigation.get_active_graph(&self.graph);
+                            if let Err(e) = self.execution_engine.execute_dirty_nodes(current_graph) {
+                                eprintln!("Evaluation strategy switch execution failed: {}", e);
+                            }
+                        }
+                    }
+                    
+                    // Timeline frame: scrubbing only re-cooks nodes downstream of a Time node
+                    let mut frame = self.execution_engine.get_current_frame();
+                    if ui.add(egui::DragValue::new(&mut frame).speed(0.25).prefix("Frame "))
+                        .on_hover_text("Timeline frame read by Time nodes. Frames already cooked are reused.")
+                        .changed()
+                    {
+                        let current_graph = self.navigation.get_active_graph(&self.graph);
+                        self.execution_engine.set_frame(frame, current_graph);
+                        if self.execution_mode == ExecutionMode::Auto {
+                            if let Err(e) = self.execution_engine.execute_dirty_nodes(current_graph) {
+                                eprintln!("Frame change execution failed: {}", e);
+                            }
+                        }
+                    }
+
+                    // Project seed: random nodes draw reproducible values from it
+                    let mut seed = self.graph.seed;
+                    if ui.add(egui::DragValue::new(&mut seed).prefix("Seed "))
+                        .on_hover_text("Project-wide random seed. The same seed gives the same random values on every run and machine.")
+                        .changed()
+                    {
+                        self.graph.seed = seed;
+                        let current_graph = self.navigation.get_active_graph(&self.graph);
+                        self.execution_engine.set_seed(seed, current_graph);
+                        if self.execution_mode == ExecutionMode::Auto {
+                            if let Err(e) = self.execution_engine.execute_dirty_nodes(current_graph) {
+                                eprintln!("Seed change execution failed: {}", e);
+                            }
+                        }
+                        self.mark_modified();
+                    }
+
+                    // Breakpoints: cooks pause before nodes that have one
+                    if ui.add_enabled(!self.interaction.selected_nodes.is_empty(), egui::Button::new("● Break"))
+                        .on_hover_text("Toggle a breakpoint on the selected nodes; cooks pause before running them")
+                        .clicked()
+                    {
+                        self.toggle_breakpoints_on_selection();
+                    }
+                    if let Some(paused) = self.execution_engine.debugger().paused_at() {
+                        let title = self.navigation.get_active_graph(&self.graph).nodes.get(&paused)
+                            .map_or_else(|| format!("node {}", paused), |node| node.title.clone());
+                        ui.label(egui::RichText::new(format!("⏸ {}", title)).color(PAUSED_NODE_COLOR));
+                        if ui.button("⏭ Step").on_hover_text("Run the paused node and pause before the next one").clicked() {
+                            self.resume_execution(true);
+                        }
+                        if ui.button("▶ Continue").on_hover_text("Run on to the next breakpoint").clicked() {
+                            self.resume_execution(false);
+                        }
+                    }
                 });
                 
+                ui.separator();
+                if ui.toggle_value(&mut self.auto_wire_new_nodes, "🔗 Auto-wire")
+                    .on_hover_text("Connect the selected node to nodes created from the context menu")
+                    .changed()
+                {
+                    let mut settings = crate::settings::current();
+                    settings.auto_wire_new_nodes = self.auto_wire_new_nodes;
+                    if let Err(e) = crate::settings::update(settings) {
+                        error!("Failed to save preferences: {}", e);
+                    }
+                }
+                ui.toggle_value(&mut self.bundle_wires, "〰 Bundle")
+                    .on_hover_text("Draw parallel connections between the same areas as one wire; hover to expand");
+                let mut wire_style = self.graph.wire_style;
+                egui::ComboBox::from_id_salt("wire_style")
+                    .selected_text(wire_style.label())
+                    .show_ui(ui, |ui| {
+                        for style in WireStyle::ALL {
+                            ui.selectable_value(&mut wire_style, style, style.label());
+                        }
+                    })
+                    .response
+                    .on_hover_text("How wires are routed in this file");
+                if wire_style != self.graph.wire_style {
+                    self.session_history.record(format!("set wire style to {}", wire_style.label()));
+                    self.graph.wire_style = wire_style;
+                    self.mark_modified();
+                }
+                ui.toggle_value(&mut self.show_type_legend, "🎨 Types")
+                    .on_hover_text("Show which port colors stand for which data types");
+                ui.toggle_value(&mut self.data_flow.enabled, "⚡ Flow")
+                    .on_hover_text("Pulse wires when data is recooked and show wire values on hover");
+                ui.toggle_value(&mut self.dependency_view.enabled, "🌳 Deps")
+                    .on_hover_text("Dim the graph except what feeds the selected nodes, tinted by distance");
+                if self.dependency_view.enabled {
+                    ui.toggle_value(&mut self.dependency_view.include_downstream, "↓ Downstream")
+                        .on_hover_text("Also highlight the nodes the selection feeds");
+                }
+                ui.toggle_value(&mut self.inspect_on_hover, "🔍 Inspect")
+                    .on_hover_text("Hover a node to see the values on its outputs");
+                ui.toggle_value(&mut self.show_session_history, "🕘 History")
+                    .on_hover_text("Timeline of edits made this session");
+                ui.toggle_value(&mut self.show_execution_log, "📜 Log")
+                    .on_hover_text("Cooks with their durations, inputs and warnings");
+                ui.toggle_value(&mut self.show_graph_report, "📈 Report")
+                    .on_hover_text("Node counts, cached data sizes and GPU memory of this graph");
+                self.render_record_controls(ui);
+                ui.toggle_value(&mut self.show_plugin_browser, "🧩 Plugins")
+                    .on_hover_text("Browse and install plugins from a repository");
+                ui.toggle_value(&mut self.show_usage_stats, "📊 Stats")
+                    .on_hover_text("Node usage and cook times, stored only on this computer");
+                ui.toggle_value(&mut self.show_annotation_tools, "✏ Annotate")
+                    .on_hover_text("Draw arrows, strokes and notes over the graph");
+                ui.toggle_value(&mut self.show_find_nodes, "🔎 Find")
+                    .on_hover_text("Find nodes by type, title, tag, parameter value or error (Ctrl+F)");
+                ui.toggle_value(&mut self.show_named_wires, "🏷 Wires")
+                    .on_hover_text("Label the selected wire, or feed the selected node from a named output");
+                ui.toggle_value(&mut self.show_selection_sets, "🗂 Sets")
+                    .on_hover_text("Save and recall named selections, or isolate them");
+                if self.selection_sets.isolation.is_some()
+                    && ui.button("👁 Show All").on_hover_text("End isolate mode and show every node").clicked()
+                {
+                    self.apply_selection_set_action(SelectionSetAction::EndIsolation);
+                }
+                ui.toggle_value(&mut self.show_keymap, "⌨ Keys")
+                    .on_hover_text("Change keyboard shortcuts");
+                if ui.toggle_value(&mut self.show_preferences, "⚙ Preferences")
+                    .on_hover_text("Theme, autosave, execution mode and viewport defaults")
+                    .clicked() && self.show_preferences {
+                    self.preferences.reload();
+                }
+                
                 ui.separator();
                 ui.label(format!("Zoom: {:.1}x", self.canvas.zoom));
                 ui.label(format!(
@@ -921,7 +2322,7 @@ impl eframe::App for NodeEditor {
             .height();
 
         egui::CentralPanel::default()
-            .frame(egui::Frame::default().fill(Color32::from_rgb(22, 27, 34)))
+            .frame(egui::Frame::default().fill(crate::theme::colors().panel_background))
             .show(ctx, |ui| {
             let response = ui.allocate_response(ui.available_size(), egui::Sense::click_and_drag());
             
@@ -930,6 +2331,8 @@ impl eframe::App for NodeEditor {
                 ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair); // Use crosshair for cutting mode
             } else if self.input_state.is_connecting_mode() {
                 ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair); // Use crosshair for connecting mode
+            } else if self.annotation_layer.is_tool_active() && response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Crosshair);
             }
             
             // Handle context menu before creating the painter (to avoid borrow conflicts)
@@ -941,7 +2344,7 @@ impl eframe::App for NodeEditor {
             painter.rect_filled(
                 response.rect,
                 0.0,
-                Color32::from_rgb(28, 28, 28), // Standard background color
+                crate::theme::colors().main_background,
             );
 
             // Apply zoom and pan transforms using canvas
@@ -987,6 +2390,12 @@ impl eframe::App for NodeEditor {
             } else if self.input_state.is_connecting_mode() {
                 // In connecting mode - skip normal interactions
                 // Connecting is handled in the input state update
+            } else if self.annotation_layer.is_tool_active() {
+                // Annotation tools take left clicks and drags instead of node interaction
+                let annotations = &self.navigation.get_active_graph(&self.graph).annotations;
+                if let Some(edit) = self.annotation_layer.handle_input(&response, &inverse_transform_pos, annotations, zoom) {
+                    self.apply_annotation_edit(edit);
+                }
             } else if let Some(pos) = self.input_state.mouse_world_pos {
                 // Skip node interaction if we're panning
                 if !self.input_state.is_panning {
@@ -998,7 +2407,12 @@ impl eframe::App for NodeEditor {
                         // Active graph checked
                         // Use smaller radius for precise clicks when not in connecting mode
                         let click_radius = if self.input_state.is_connecting_mode() { 80.0 } else { 8.0 };
-                        if let Some((node_id, port_idx, is_input)) = self.input_state.find_clicked_port(active_graph, click_radius) {
+                        let clicked_port = self.input_state.find_clicked_port(active_graph, click_radius);
+                        let inspect_click = response.ctx.input(|i| i.modifiers.alt) && !self.input_state.is_connecting_active();
+                        if let Some((node_id, port_idx, is_input)) = clicked_port.filter(|_| inspect_click) {
+                            // Alt-click opens the inspector on the port instead of wiring it
+                            self.panel_manager.inspect_port(node_id, port_idx, is_input);
+                        } else if let Some((node_id, port_idx, is_input)) = clicked_port {
                             // Port click found
                             // Handle connection logic
                             if self.input_state.is_connecting_active() {
@@ -1178,11 +2592,14 @@ impl eframe::App for NodeEditor {
                                                 };
                                                 self.workspace_manager.set_active_workspace_by_id(workspace_id);
                                         }
+                                    } else {
+                                        // Double-clicking any other node renames it
+                                        self.start_title_edit(node_id);
                                     }
                                 }
                                 
                             }
-                        } else if let Some(connection_idx) = self.input_state.find_clicked_connection(&self.build_temp_graph(&viewed_nodes), 8.0, self.canvas.zoom) {
+                        } else if let Some(connection_idx) = self.input_state.find_clicked_connection(&self.build_temp_graph(&viewed_nodes), 8.0, self.graph.wire_style) {
                             // Handle connection selection with multi-select support
                             self.interaction.select_connection_multi(connection_idx, self.input_state.is_multi_select());
                         } else {
@@ -1310,12 +2727,12 @@ impl eframe::App for NodeEditor {
                     if self.interaction.box_selection_start.is_some() {
                         match self.navigation.current_view() {
                             GraphView::Root => {
-                                self.interaction.complete_box_selection(&self.graph, self.input_state.is_multi_select());
+                                self.interaction.complete_box_selection(&self.graph, self.input_state.is_multi_select(), self.graph.wire_style);
                             }
                             GraphView::WorkspaceNode(node_id) => {
                                 if let Some(node) = self.graph.nodes.get(&node_id) {
                                     if let Some(internal_graph) = node.get_internal_graph() {
-                                        self.interaction.complete_box_selection(internal_graph, self.input_state.is_multi_select());
+                                        self.interaction.complete_box_selection(internal_graph, self.input_state.is_multi_select(), self.graph.wire_style);
                                     }
                                 }
                             }
@@ -1328,13 +2745,21 @@ impl eframe::App for NodeEditor {
             }
 
             // Handle keyboard input using input state
-            if self.input_state.delete_pressed(ui) {
+            if self.input_state.action_pressed(ui, EditorAction::DeleteSelection) {
                 if !self.interaction.selected_nodes.is_empty() {
                     // Clean up panel caches for deleted nodes
                     for node_id in &self.interaction.selected_nodes {
                         self.panel_manager.cleanup_deleted_node(*node_id);
                     }
                     
+                    let deleted_titles: Vec<String> = self.interaction.selected_nodes.iter()
+                        .filter_map(|node_id| self.get_active_graph().nodes.get(node_id))
+                        .map(|node| node.title.clone())
+                        .collect();
+                    for title in deleted_titles {
+                        self.session_history.record(format!("deleted {}", title));
+                    }
+                    
                     // Delete all selected nodes from the correct graph
                     match self.navigation.current_view() {
                         GraphView::Root => {
@@ -1385,8 +2810,8 @@ impl eframe::App for NodeEditor {
                 }
             }
 
-            // Handle ESC key to cancel connections
-            if self.input_state.escape_pressed(ui) {
+            // Cancel connections (Escape by default)
+            if self.input_state.action_pressed(ui, EditorAction::Cancel) {
                 self.input_state.cancel_connection();
             }
 
@@ -1398,7 +2823,7 @@ impl eframe::App for NodeEditor {
                 // X key was just released - apply cuts
                 let cut_connections = {
                     let active_graph = self.navigation.get_active_graph(&self.graph);
-                    self.input_state.find_cut_connections(active_graph, self.canvas.zoom)
+                    self.input_state.find_cut_connections(active_graph, self.graph.wire_style)
                 };
                 
                 if !cut_connections.is_empty() {
@@ -1454,35 +2879,66 @@ impl eframe::App for NodeEditor {
                 self.input_state.clear_connect_paths();
             }
 
-            // Handle F1 to toggle performance info
-            if self.input_state.f1_pressed(ui) {
+            // Toggle performance info (F1 by default)
+            if self.input_state.action_pressed(ui, EditorAction::TogglePerformanceInfo) {
                 self.debug_tools.toggle_performance_info();
             }
 
-            // Handle F2-F4 to add different numbers of nodes
-            if self.input_state.f2_pressed(ui) {
+            // Find Nodes dialog (Ctrl+F by default)
+            if self.input_state.action_pressed(ui, EditorAction::FindNodes) {
+                self.show_find_nodes = true;
+            }
+
+            // Rename the selected node in place (F2 by default)
+            if self.input_state.action_pressed(ui, EditorAction::RenameNode) && self.interaction.selected_nodes.len() == 1 {
+                if let Some(node_id) = self.interaction.selected_nodes.iter().next().copied() {
+                    self.start_title_edit(node_id);
+                }
+            }
+
+            // Walk the selection along the pipeline (Up and Down by default, Shift extends)
+            if let Some(extend) = self.input_state.action_pressed_or_shifted(ui, EditorAction::SelectUpstream) {
+                self.walk_selection(ui.ctx(), true, extend);
+            }
+            if let Some(extend) = self.input_state.action_pressed_or_shifted(ui, EditorAction::SelectDownstream) {
+                self.walk_selection(ui.ctx(), false, extend);
+            }
+
+            // Add different numbers of nodes (F7, F3 and F4 by default)
+            if self.input_state.action_pressed(ui, EditorAction::AddTenNodes) {
                 self.add_benchmark_nodes(10);
             }
-            if self.input_state.f3_pressed(ui) {
+            if self.input_state.action_pressed(ui, EditorAction::AddTwentyFiveNodes) {
                 self.add_benchmark_nodes(25);
             }
-            if self.input_state.f4_pressed(ui) {
+            if self.input_state.action_pressed(ui, EditorAction::StressTest) {
                 self.add_performance_stress_test(5000);
             }
 
-            // Handle F5 to clear all nodes
-            if self.input_state.f5_pressed(ui) {
+            // Clear all nodes (F5 by default)
+            if self.input_state.action_pressed(ui, EditorAction::ClearGraph) {
                 self.graph.nodes.clear();
                 self.graph.connections.clear();
                 self.interaction.clear_selection();
                 self.input_state.cancel_connection();
             }
 
-            // Handle F6 to toggle GPU/CPU rendering
-            if self.input_state.f6_pressed(ui) {
+            // Toggle GPU/CPU rendering (F6 by default)
+            if self.input_state.action_pressed(ui, EditorAction::ToggleGpuRendering) {
                 self.use_gpu_rendering = !self.use_gpu_rendering;
             }
 
+            // Breakpoints and stepping (F9, F10 and F8 by default)
+            if self.input_state.action_pressed(ui, EditorAction::ToggleBreakpoint) {
+                self.toggle_breakpoints_on_selection();
+            }
+            if self.input_state.action_pressed(ui, EditorAction::StepExecution) {
+                self.resume_execution(true);
+            }
+            if self.input_state.action_pressed(ui, EditorAction::ContinueExecution) {
+                self.resume_execution(false);
+            }
+
             // Handle right-click for context menu first (before other input handling)
             if self.input_state.right_clicked_this_frame {
                 if let Some(node_id) = self.input_state.find_node_under_mouse(&self.build_temp_graph(&viewed_nodes)) {
@@ -1496,6 +2952,20 @@ impl eframe::App for NodeEditor {
 
 
 
+            // Secondary title lines, built from each node's parameters
+            let catalog = crate::nodes::factory::NodeCatalog::current();
+            let node_subtitles: HashMap<NodeId, String> = viewed_nodes.iter()
+                .filter_map(|(node_id, node)| {
+                    let subtitle = catalog.metadata(&node.type_id)?.subtitle?;
+                    subtitle(node).map(|subtitle| (*node_id, subtitle))
+                })
+                .collect();
+            // Names of Null anchor points, drawn large above them
+            let anchor_labels: HashMap<NodeId, String> = viewed_nodes.iter()
+                .filter(|(_, node)| node.type_id == "Null")
+                .filter_map(|(node_id, node)| crate::nodes::utility::NullNode::anchor_label(node).map(|label| (*node_id, label)))
+                .collect();
+
             // Draw nodes - GPU vs CPU rendering
             if self.use_gpu_rendering && !viewed_nodes.is_empty() {
                     // Calculate viewport bounds for GPU callback
@@ -1581,14 +3051,16 @@ impl eframe::App for NodeEditor {
                                 
                                 // Binary search to find the maximum characters that fit with ellipsis
                                 let mut low = 0;
-                                let mut high = node.title.len();
+                                // Search over characters, as renamed titles may hold multi-byte ones
+                                let char_count = node.title.chars().count();
+                                let mut high = char_count;
                                 let mut best_fit = 0;
                                 
-                                while low <= high && low < node.title.len() {
+                                while low <= high && low < char_count {
                                     let mid = (low + high) / 2;
-                                    let test_text = &node.title[..mid];
+                                    let test_text: String = node.title.chars().take(mid).collect();
                                     let test_width = painter.fonts(|fonts| {
-                                        fonts.layout_no_wrap(test_text.to_string(), font_id.clone(), egui::Color32::WHITE).size().x
+                                        fonts.layout_no_wrap(test_text, font_id.clone(), egui::Color32::WHITE).size().x
                                     });
                                     
                                     if test_width <= available_for_text {
@@ -1601,7 +3073,7 @@ impl eframe::App for NodeEditor {
                                 }
                                 
                                 if best_fit > 0 {
-                                    let truncated = &node.title[..best_fit];
+                                    let truncated: String = node.title.chars().take(best_fit).collect();
                                     format!("{}{}", truncated, ellipsis)
                                 } else {
                                     ellipsis.to_string()
@@ -1609,6 +3081,13 @@ impl eframe::App for NodeEditor {
                             }
                         };
                         
+                        // Link badge on workspace nodes that reference a subgraph file
+                        let display_text = if node.get_reference().is_some() {
+                            format!("🔗 {}", display_text)
+                        } else {
+                            display_text
+                        };
+                        
                         // Node titles (CPU-rendered text)
                         painter.text(
                             transform_pos(node.position + Vec2::new(node.size.x / 2.0, 15.0)),
@@ -1617,6 +3096,12 @@ impl eframe::App for NodeEditor {
                             font_id,
                             Color32::WHITE,
                         );
+                        if let Some(subtitle) = node_subtitles.get(node_id) {
+                            MeshRenderer::render_node_subtitle(&painter, node, subtitle, self.canvas.zoom, &transform_pos);
+                        }
+                        if let Some(label) = anchor_labels.get(node_id) {
+                            MeshRenderer::render_anchor_label(&painter, node, label, self.canvas.zoom, &transform_pos);
+                        }
                     
                     // Port names on hover (CPU-rendered text)
                     if let Some(mouse_world_pos) = self.input_state.mouse_world_pos {
@@ -1626,7 +3111,7 @@ impl eframe::App for NodeEditor {
                                 painter.text(
                                     transform_pos(input.position - Vec2::new(0.0, 15.0)),
                                     egui::Align2::CENTER_BOTTOM,
-                                    &input.name,
+                                    input.display_name(),
                                     egui::FontId::proportional(10.0 * self.canvas.zoom),
                                     Color32::WHITE,
                                 );
@@ -1639,7 +3124,7 @@ impl eframe::App for NodeEditor {
                                 painter.text(
                                     transform_pos(output.position + Vec2::new(0.0, 15.0)),
                                     egui::Align2::CENTER_TOP,
-                                    &output.name,
+                                    output.display_name(),
                                     egui::FontId::proportional(10.0 * self.canvas.zoom),
                                     Color32::WHITE,
                                 );
@@ -1671,6 +3156,12 @@ impl eframe::App for NodeEditor {
                         zoom,
                         &transform_pos,
                     );
+                    if let Some(subtitle) = node_subtitles.get(node_id) {
+                        MeshRenderer::render_node_subtitle(&painter, node, subtitle, zoom, &transform_pos);
+                    }
+                    if let Some(label) = anchor_labels.get(node_id) {
+                        MeshRenderer::render_anchor_label(&painter, node, label, zoom, &transform_pos);
+                    }
 
 
                     // Draw ports using MeshRenderer
@@ -1715,7 +3206,7 @@ impl eframe::App for NodeEditor {
                         MeshRenderer::render_port_complete_cpu(
                             &painter,
                             input.position,
-                            true, // is_input
+                            crate::theme::theme().port_color(&node.type_id, true, port_idx),
                             is_connecting_port,
                             zoom,
                             &transform_pos,
@@ -1725,7 +3216,7 @@ impl eframe::App for NodeEditor {
                         MeshRenderer::render_port_name_on_hover(
                             &painter,
                             input.position,
-                            &input.name,
+                            &input.display_name(),
                             true, // is_input
                             self.input_state.mouse_world_pos,
                             zoom,
@@ -1774,7 +3265,7 @@ impl eframe::App for NodeEditor {
                         MeshRenderer::render_port_complete_cpu(
                             &painter,
                             output.position,
-                            false, // is_input
+                            crate::theme::theme().port_color(&node.type_id, false, port_idx),
                             is_connecting_port,
                             zoom,
                             &transform_pos,
@@ -1784,7 +3275,7 @@ impl eframe::App for NodeEditor {
                         MeshRenderer::render_port_name_on_hover(
                             &painter,
                             output.position,
-                            &output.name,
+                            &output.display_name(),
                             false, // is_input
                             self.input_state.mouse_world_pos,
                             zoom,
@@ -1832,7 +3323,42 @@ impl eframe::App for NodeEditor {
 
             // Draw connections
             let viewed_connections = self.get_viewed_connections();
+            let wire_style = self.graph.wire_style;
+            let mut bundled_connections = std::collections::HashSet::new();
+            if self.bundle_wires {
+                for bundle in wire_bundling::bundle_connections(&viewed_connections, &viewed_nodes) {
+                    // Bundles with a selected wire stay expanded so the selection is visible
+                    if bundle.iter().any(|idx| self.interaction.selected_connections.contains(idx)) {
+                        continue;
+                    }
+                    let ends = bundle.iter()
+                        .filter_map(|&idx| {
+                            let connection = &viewed_connections[idx];
+                            let from = viewed_nodes.get(&connection.from_node)?.outputs.get(connection.from_port)?;
+                            let to = viewed_nodes.get(&connection.to_node)?.inputs.get(connection.to_port)?;
+                            Some((transform_pos(from.position), transform_pos(to.position)))
+                        })
+                        .collect();
+                    let wires = wire_bundling::Bundle::new(ends, zoom, wire_style);
+                    if wires.is_hovered(self.input_state.mouse_pos) {
+                        continue;
+                    }
+                    wires.paint(&painter, zoom);
+                    bundled_connections.extend(bundle);
+                }
+            }
+            if self.data_flow.enabled {
+                self.data_flow.observe(&self.execution_engine, viewed_nodes.keys().copied());
+                if self.data_flow.is_animating() {
+                    ui.ctx().request_repaint();
+                }
+            }
+            let relations = self.dependency_view.relations(&viewed_connections, &self.interaction.selected_nodes);
+            let mut hovered_wire_value = None;
             for (idx, connection) in viewed_connections.iter().enumerate() {
+                if bundled_connections.contains(&idx) {
+                    continue;
+                }
                 if let (Some(from_node), Some(to_node)) = (
                     viewed_nodes.get(&connection.from_node),
                     viewed_nodes.get(&connection.to_node),
@@ -1848,34 +3374,55 @@ impl eframe::App for NodeEditor {
                         let transformed_from = transform_pos(from_pos);
                         let transformed_to = transform_pos(to_pos);
 
-                        // Draw bezier curve with handle length proportional to total distance
-                        let total_distance = (transformed_to - transformed_from).length();
-                        let control_offset = total_distance.sqrt() * 4.0;
-
-                        let points = [
-                            transformed_from,
-                            transformed_from + Vec2::new(0.0, control_offset),
-                            transformed_to - Vec2::new(0.0, control_offset),
-                            transformed_to,
-                        ];
+                        // Routed in the file's wire style
+                        let wire = WirePath::new(wire_style, transformed_from, transformed_to, zoom);
 
-                        // Highlight selected connections
+                        // Highlight selected connections; others follow the theme's connection scheme
                         let (stroke_width, stroke_color) = if self.interaction.selected_connections.contains(&idx)
                         {
-                            (4.0 * zoom, Color32::from_rgb(88, 166, 255)) // Blue accent for selected
+                            (4.0 * zoom, crate::theme::colors().connection_selected)
                         } else {
-                            (2.0 * zoom, Color32::from_rgb(100, 110, 120)) // Darker gray for normal
+                            (2.0 * zoom, crate::theme::theme().connection_color(&from_node.type_id, connection.from_port))
                         };
+                        let stroke_color = dependency_view::wire_color(&relations, connection, stroke_color);
+
+                        if connection.named {
+                            named_wires::paint_named_wire(&painter, transformed_from, transformed_to, &connection.label, Stroke::new(stroke_width, stroke_color), zoom);
+                        } else {
+                            painter.add(wire.shape(Stroke::new(stroke_width, stroke_color)));
+                            if !connection.label.is_empty() {
+                                named_wires::paint_label(&painter, wire.point_at(0.5), &connection.label, stroke_color, zoom);
+                            }
+                        }
+                        if let Some(adapter) = connection.adapter {
+                            let badge = named_wires::adapter_badge_position(&wire, transformed_to, connection.named, zoom);
+                            named_wires::paint_adapter_badge(&painter, badge, adapter, zoom);
+                        }
+                        if connection.named {
+                            continue;
+                        }
 
-                        painter.add(egui::Shape::CubicBezier(egui::epaint::CubicBezierShape {
-                            points,
-                            closed: false,
-                            fill: Color32::TRANSPARENT,
-                            stroke: Stroke::new(stroke_width, stroke_color).into(),
-                        }));
+                        if self.data_flow.enabled {
+                            if let Some(progress) = self.data_flow.pulse_progress(connection.from_node) {
+                                data_flow::paint_pulse(&painter, &wire, progress, stroke_color, zoom);
+                            }
+                            if hovered_wire_value.is_none()
+                                && self.input_state.mouse_pos.is_some_and(|pointer| data_flow::is_hovered(pointer, &wire))
+                            {
+                                hovered_wire_value = Some(
+                                    self.execution_engine.get_cached_output(connection.from_node, connection.from_port)
+                                        .map(|value| crate::nodes::value_preview::scalar_text(value)
+                                            .unwrap_or_else(|| "(value not shown for this type)".to_string()))
+                                        .unwrap_or_else(|| "(not cooked yet)".to_string()),
+                                );
+                            }
+                        }
                     }
                 }
             }
+            if let Some(value) = hovered_wire_value {
+                response.clone().on_hover_text_at_pointer(value);
+            }
 
             // Draw current connection being made
             if let Some((from_node, from_port, from_is_input)) = self.input_state.get_connecting_from() {
@@ -1888,39 +3435,14 @@ impl eframe::App for NodeEditor {
                         };
 
                         let transformed_from = transform_pos(from_pos);
-                        let transformed_to = mouse_pos;
-
-                        // Draw bezier curve for connection preview with proportional handle length
-                        let total_distance = (transformed_to - transformed_from).length();
-                        let control_offset = total_distance.sqrt() * 4.0;
 
-                        // Control points should aim in the correct direction based on port type
-                        let from_control = if from_is_input {
-                            transformed_from - Vec2::new(0.0, control_offset) // Input ports: aim up
+                        // Wires run from output to input, so a wire dragged from an input starts at the mouse
+                        let wire = if from_is_input {
+                            WirePath::new(wire_style, mouse_pos, transformed_from, zoom)
                         } else {
-                            transformed_from + Vec2::new(0.0, control_offset) // Output ports: aim down
+                            WirePath::new(wire_style, transformed_from, mouse_pos, zoom)
                         };
-                        
-                        let to_control = if from_is_input {
-                            transformed_to + Vec2::new(0.0, control_offset) // When connecting from input: aim up from mouse
-                        } else {
-                            transformed_to - Vec2::new(0.0, control_offset) // When connecting from output: aim down to mouse
-                        };
-
-                        let points = [
-                            transformed_from,
-                            from_control,
-                            to_control,
-                            transformed_to,
-                        ];
-
-                        painter.add(egui::Shape::CubicBezier(egui::epaint::CubicBezierShape {
-                            points,
-                            closed: false,
-                            fill: Color32::TRANSPARENT,
-                            stroke: Stroke::new(2.0 * zoom, Color32::from_rgb(100, 180, 255))
-                                .into(),
-                        }));
+                        painter.add(wire.shape(Stroke::new(2.0 * zoom, Color32::from_rgb(100, 180, 255))));
                     }
                 }
             }
@@ -1951,6 +3473,95 @@ impl eframe::App for NodeEditor {
                 }
             }
 
+            // Breakpoint markers, and an outline around the node a paused cook runs next
+            let debugger = self.execution_engine.debugger();
+            for node in viewed_nodes.values() {
+                let rect = node.get_rect();
+                let node_rect = Rect::from_two_pos(transform_pos(rect.min), transform_pos(rect.max));
+                if debugger.has_breakpoint(node.id) {
+                    painter.circle_filled(node_rect.left_top() + Vec2::splat(6.0 * zoom), 4.0 * zoom, BREAKPOINT_COLOR);
+                }
+                if debugger.paused_at() == Some(node.id) {
+                    painter.rect_stroke(node_rect.expand(4.0 * zoom), 6.0 * zoom, Stroke::new(2.5 * zoom, PAUSED_NODE_COLOR), egui::StrokeKind::Outside);
+                }
+            }
+
+            // Probe values on their nodes, and the inspect card of the hovered node
+            for node in viewed_nodes.values().filter(|node| node.type_id == "Probe") {
+                let preview = self.execution_engine.get_cached_output(node.id, 0)
+                    .map(|value| value_preview::preview(value, crate::nodes::output::probe::PROBE_LINES))
+                    .unwrap_or_else(|| value_preview::ValuePreview::message("(not cooked yet)"));
+                let rect = node.get_rect();
+                let node_rect = Rect::from_two_pos(transform_pos(rect.min), transform_pos(rect.max));
+                probe_display::paint_preview(ui, probe_display::probe_body_rect(node_rect, zoom), &preview, zoom);
+            }
+            if self.inspect_on_hover {
+                let hovered = self.input_state.mouse_world_pos.and_then(|pointer| {
+                    viewed_nodes.values().find(|node| node.get_rect().contains(pointer) && !node.outputs.is_empty())
+                });
+                if let Some(node) = hovered {
+                    let outputs: Vec<(String, value_preview::ValuePreview)> = node.outputs.iter().enumerate()
+                        .map(|(port_idx, port)| {
+                            let preview = self.execution_engine.get_cached_output(node.id, port_idx)
+                                .map(|value| value_preview::preview(value, 3))
+                                .unwrap_or_else(|| value_preview::ValuePreview::message("(not cooked yet)"));
+                            (port.display_name().into_owned(), preview)
+                        })
+                        .collect();
+                    let rect = node.get_rect();
+                    probe_display::paint_inspect_card(ui, Rect::from_two_pos(transform_pos(rect.min), transform_pos(rect.max)), &outputs);
+                }
+            }
+
+            // Tags and notes of the hovered node
+            let annotated = self.input_state.mouse_world_pos.and_then(|pointer| {
+                viewed_nodes.values().find(|node| node.get_rect().contains(pointer) && (!node.notes.is_empty() || !node.tags.is_empty()))
+            });
+            if let Some(node) = annotated {
+                response.clone().on_hover_ui_at_pointer(|ui| {
+                    ui.strong(&node.title);
+                    panels::render_node_notes(ui, node);
+                });
+            }
+
+            dependency_view::paint(&painter, &viewed_nodes, &relations, &transform_pos, zoom);
+
+            self.annotation_layer.paint(&painter, &self.navigation.get_active_graph(&self.graph).annotations, &transform_pos, zoom);
+
+            if self.show_type_legend && crate::theme::theme().connection_scheme == crate::theme::ConnectionScheme::DataType {
+                type_legend::paint(&painter, response.rect, &viewed_nodes);
+            }
+
+            // Outline plugin nodes stopped after a panic
+            let crashed_nodes = crate::plugins::sandbox::crashed_nodes();
+            for node in viewed_nodes.values().filter(|node| crashed_nodes.contains(&node.id)) {
+                let rect = node.get_rect();
+                painter.rect_stroke(
+                    Rect::from_two_pos(transform_pos(rect.min), transform_pos(rect.max)).expand(3.0 * zoom),
+                    5.0 * zoom,
+                    Stroke::new(2.0 * zoom, Color32::from_rgb(255, 60, 60)),
+                    egui::StrokeKind::Outside,
+                );
+            }
+
+            // Mark nodes whose output is pinned to a viewer
+            let pinned_sources = self.panel_manager.interface_panel_manager().pinned_viewer_sources();
+            for node in viewed_nodes.values().filter(|node| pinned_sources.contains(&node.id)) {
+                let rect = node.get_rect();
+                painter.text(
+                    transform_pos(Pos2::new(rect.max.x, rect.min.y)),
+                    egui::Align2::CENTER_CENTER,
+                    "📌",
+                    egui::FontId::proportional(14.0 * zoom),
+                    Color32::from_rgb(255, 150, 100),
+                );
+            }
+
+            // Diff highlights refer to root graph node ids
+            if self.navigation.is_root_view() {
+                self.diff_view.paint_overlay(&painter, &viewed_nodes, &transform_pos, zoom);
+            }
+
             // Draw box selection
             if let (Some(start), Some(end)) = (self.interaction.box_selection_start, self.interaction.box_selection_end) {
                 let selection_rect = egui::Rect::from_two_pos(start, end);
@@ -1987,6 +3598,121 @@ impl eframe::App for NodeEditor {
             self.debug_tools.render_performance_info(ui, self.use_gpu_rendering, self.graph.nodes.len(), self.current_menu_bar_height);
             // Performance info rendered
         });
+        
+        // Startup screen listing recent projects
+        match self.startup_screen.render(ctx, &mut self.file_manager) {
+            StartupAction::NewProject => self.new_file(),
+            StartupAction::NewFromTemplate(template) => self.new_from_template(&template),
+            StartupAction::Open => self.open_file_dialog(),
+            StartupAction::OpenRecent(path) => self.open_recent_file(&path),
+            StartupAction::None => {}
+        }
+        self.render_template_name_prompt(ctx);
+        self.render_title_edit(ctx);
+        
+        if self.show_session_history {
+            self.session_history.render(ctx, &mut self.show_session_history);
+        }
+        if self.show_execution_log {
+            self.execution_log_panel.render(ctx, &mut self.show_execution_log, self.execution_engine.log_mut(), &self.interaction.selected_nodes);
+        }
+        self.graph_report_panel.render(ctx, &mut self.show_graph_report, self.navigation.get_active_graph(&self.graph), &self.execution_engine);
+        
+        self.diff_view.render(ctx);
+        self.update_hotbox(ctx);
+        if let Some(edit) = self.annotation_layer.render(ctx, &mut self.show_annotation_tools) {
+            self.apply_annotation_edit(edit);
+        }
+        if self.show_find_nodes {
+            let graph = self.navigation.get_active_graph(&self.graph);
+            let engine = &self.execution_engine;
+            let failed = |node_id| engine.get_node_state(node_id) == crate::nodes::execution_engine::NodeState::Error;
+            if let Some(found) = self.find_nodes.render(ctx, &mut self.show_find_nodes, graph, &failed) {
+                self.select_found_nodes(ctx, found);
+            }
+        }
+        if self.show_named_wires {
+            let graph = self.navigation.get_active_graph(&self.graph);
+            let connections = &self.interaction.selected_connections;
+            let selected_connection = if connections.len() == 1 { connections.iter().next().copied() } else { None };
+            let nodes = &self.interaction.selected_nodes;
+            let selected_node = if nodes.len() == 1 { nodes.iter().next().copied() } else { None };
+            if let Some(action) = self.named_wires.render(ctx, &mut self.show_named_wires, graph, selected_connection, selected_node) {
+                self.apply_wire_action(action);
+            }
+        }
+        if self.show_selection_sets {
+            let sets = self.get_active_graph().selection_sets.clone();
+            let selection_count = self.interaction.selected_nodes.len();
+            if let Some(action) = self.selection_sets.render(ctx, &mut self.show_selection_sets, &sets, selection_count) {
+                self.apply_selection_set_action(action);
+            }
+        }
+        
+        // Fold this frame's cooks into the usage statistics
+        for (type_id, duration) in self.execution_engine.take_cook_timings() {
+            self.usage_stats.record_cook(&type_id, duration);
+        }
+        if self.show_usage_stats {
+            self.usage_stats.render(ctx, &mut self.show_usage_stats);
+        }
+        if self.show_keymap {
+            self.input_state.keymap.render(ctx, &mut self.show_keymap);
+        }
+        if self.show_preferences {
+            if let Some(settings) = self.preferences.render(ctx, &mut self.show_preferences) {
+                self.apply_settings(ctx, &settings);
+            }
+        }
+        self.autosave_if_due();
+        if ctx.input(|input| input.viewport().close_requested()) {
+            if let Err(e) = self.usage_stats.save() {
+                error!("Failed to save usage statistics: {}", e);
+            }
+        } else {
+            self.usage_stats.save_periodically();
+        }
+        
+        if self.show_plugin_browser && self.plugin_browser.render(ctx, &mut self.show_plugin_browser) {
+            // Workspaces register plugin nodes when built; rebuild them to pick up the new plugin
+            let active_workspace = self.workspace_manager.get_active_workspace().map(|workspace| workspace.id());
+            self.workspace_manager = WorkspaceRegistry::create_workspace_manager();
+            self.workspace_manager.set_active_workspace_by_id(active_workspace);
+            self.session_history.record("installed a plugin from the repository");
+        }
+        
+        self.plugin_hot_reload.poll();
+        self.update_plugin_crashes(ctx);
+        
+        // Finished background renders re-cook their Render node so its Status output updates
+        for node_id in self.render_notifications.collect() {
+            self.execution_engine.mark_dirty(node_id, &self.graph);
+        }
+        self.render_notifications.render(ctx);
+        
+        // Report this frame's graph changes to plugins subscribed to events
+        if crate::plugins::events::is_listening() {
+            let active_graph = self.navigation.get_active_graph(&self.graph);
+            self.graph_event_tracker.update(self.navigation.get_workspace_node_id(), active_graph, &self.interaction.selected_nodes);
+        } else {
+            self.graph_event_tracker.reset();
+        }
+        if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+            plugin_host.deliver_events();
+        }
+        
+        // Plugin changes are applied here, after this frame's plugin calls are done
+        let plugin_messages = plugin_reload::apply_plugin_commands(&mut self.graph, &mut self.workspace_manager);
+        if !plugin_messages.is_empty() {
+            for message in plugin_messages {
+                info!("{}", message);
+                self.session_history.record(message);
+            }
+            self.execution_engine.mark_all_dirty(&self.graph);
+        }
+        
+        // Capture after everything is drawn so recordings include this frame's windows
+        self.screen_recorder.update(ctx);
         // Frame update completed
     }
 
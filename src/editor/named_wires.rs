@@ -0,0 +1,218 @@
+//! Connection labels and named wires
+//!
+//! Any connection can carry a label, drawn at the middle of its wire. A
+//! labelled connection also names its output, which other inputs can then be
+//! fed from by picking the name in the Wires window. Such named wires are
+//! drawn as short labelled stubs at both ends instead of a wire across the
+//! canvas. The window also swaps or removes the type adapter of the selected
+//! wire (see `nodes::adapters`), drawn as a badge near the wire's input.
+
+use eframe::egui;
+use egui::{Color32, Painter, Pos2, Stroke, Vec2};
+use crate::nodes::adapters::{adapters_for, Adapter};
+use crate::nodes::math_utils::distance_to_line_segment;
+use crate::nodes::wire_style::WirePath;
+use crate::nodes::{NodeGraph, NodeId, PortId};
+
+/// Length of a named wire's stubs at zoom 1
+pub const STUB_LENGTH: f32 = 24.0;
+
+/// Label font size at zoom 1
+const LABEL_FONT_SIZE: f32 = 11.0;
+
+const LABEL_BACKGROUND: Color32 = Color32::from_rgba_premultiplied(25, 25, 25, 220);
+
+/// Text color of adapter badges
+const ADAPTER_COLOR: Color32 = Color32::from_rgb(240, 190, 90);
+
+/// Change requested from the Wires window
+#[derive(Debug, Clone, PartialEq)]
+pub enum WireAction {
+    /// Set the label of the connection at this index of the viewed graph; empty removes it
+    Label { connection: usize, label: String },
+    /// Draw the connection as a named wire, or as a regular wire again
+    SetNamed { connection: usize, named: bool },
+    /// Feed an input from a named output
+    PickUp { label: String, from_node: NodeId, from_port: PortId, to_node: NodeId, to_port: PortId },
+    /// Convert the connection's values with another adapter, or not at all
+    SetAdapter { connection: usize, adapter: Option<Adapter> },
+}
+
+/// The stubs of a named wire: one leaving the output, one entering the input
+pub fn stub_segments(from: Pos2, to: Pos2, length: f32) -> [[Pos2; 2]; 2] {
+    [[from, from + Vec2::new(0.0, length)], [to - Vec2::new(0.0, length), to]]
+}
+
+/// Whether `point` lies within `radius` of either stub; positions in world units
+pub fn stubs_hit(point: Pos2, from: Pos2, to: Pos2, radius: f32) -> bool {
+    stub_segments(from, to, STUB_LENGTH).iter().any(|[a, b]| distance_to_line_segment(point, *a, *b) <= radius)
+}
+
+/// Draw `text` in a small dark tag centered on `at`
+pub fn paint_label(painter: &Painter, at: Pos2, text: &str, color: Color32, zoom: f32) {
+    let galley = painter.layout_no_wrap(text.to_string(), egui::FontId::proportional(LABEL_FONT_SIZE * zoom), color);
+    let rect = egui::Rect::from_center_size(at, galley.size() + Vec2::new(8.0, 4.0) * zoom);
+    painter.rect_filled(rect, 3.0 * zoom, LABEL_BACKGROUND);
+    painter.galley(rect.center() - galley.size() / 2.0, galley, color);
+}
+
+/// Draw a named wire as a stub below its output and one above its input, each ending in its label
+pub fn paint_named_wire(painter: &Painter, from: Pos2, to: Pos2, label: &str, stroke: Stroke, zoom: f32) {
+    let [leaving, entering] = stub_segments(from, to, STUB_LENGTH * zoom);
+    painter.line_segment(leaving, stroke);
+    painter.line_segment(entering, stroke);
+    let offset = Vec2::new(0.0, 7.0 * zoom);
+    paint_label(painter, leaving[1] + offset, label, stroke.color, zoom);
+    paint_label(painter, entering[0] - offset, label, stroke.color, zoom);
+}
+
+/// Draw the badge of a wire's adapter centered on `at`
+pub fn paint_adapter_badge(painter: &Painter, at: Pos2, adapter: Adapter, zoom: f32) {
+    paint_label(painter, at, &format!("⇄ {}", adapter.label()), ADAPTER_COLOR, zoom);
+}
+
+/// Where a wire's adapter badge goes: above the input stub of a named wire,
+/// otherwise three quarters of the way along the wire
+pub fn adapter_badge_position(wire: &WirePath, to: Pos2, named: bool, zoom: f32) -> Pos2 {
+    if named {
+        to - Vec2::new(0.0, (STUB_LENGTH + 23.0) * zoom)
+    } else {
+        wire.point_at(0.75)
+    }
+}
+
+/// Wires window state
+#[derive(Debug, Default)]
+pub struct NamedWires {
+    /// Connection whose label is being typed, and the text so far
+    label_edit: Option<(usize, String)>,
+    /// Input of the selected node that a picked-up output feeds
+    input_port: PortId,
+}
+
+impl NamedWires {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the window for the viewed graph, its selected connection and its selected node
+    pub fn render(
+        &mut self,
+        ctx: &egui::Context,
+        open: &mut bool,
+        graph: &NodeGraph,
+        selected_connection: Option<usize>,
+        selected_node: Option<NodeId>,
+    ) -> Option<WireAction> {
+        let mut action = None;
+        egui::Window::new("Wires")
+            .open(open)
+            .resizable(false)
+            .default_pos([80.0, 200.0])
+            .show(ctx, |ui| {
+                ui.strong("Selected wire");
+                match selected_connection.and_then(|index| graph.connections.get(index).map(|connection| (index, connection))) {
+                    Some((index, connection)) => {
+                        if !matches!(self.label_edit, Some((edited, _)) if edited == index) {
+                            self.label_edit = Some((index, connection.label.clone()));
+                        }
+                        if let Some((_, text)) = &mut self.label_edit {
+                            let field = ui.add(egui::TextEdit::singleline(text).hint_text("Label"));
+                            if field.lost_focus() && text.trim() != connection.label {
+                                action = Some(WireAction::Label { connection: index, label: text.trim().to_string() });
+                            }
+                        }
+                        let mut named = connection.named;
+                        if ui.add_enabled(!connection.label.is_empty(), egui::Checkbox::new(&mut named, "Named wire"))
+                            .on_hover_text("Draw labelled stubs at both ends instead of the whole wire")
+                            .changed()
+                        {
+                            action = Some(WireAction::SetNamed { connection: index, named });
+                        }
+                        if let Some(adapter) = adapter_choice(ui, graph, index) {
+                            action = Some(WireAction::SetAdapter { connection: index, adapter });
+                        }
+                    }
+                    None => {
+                        self.label_edit = None;
+                        ui.weak("Select a wire to label it");
+                    }
+                }
+                ui.separator();
+
+                ui.strong("Pick up a named output");
+                let outputs = graph.named_outputs();
+                let Some(node) = selected_node.and_then(|node_id| graph.nodes.get(&node_id)) else {
+                    ui.weak("Select a node to feed it from a named output");
+                    return;
+                };
+                if node.inputs.is_empty() {
+                    ui.weak(format!("{} has no inputs", node.title));
+                    return;
+                }
+                self.input_port = self.input_port.min(node.inputs.len() - 1);
+                egui::ComboBox::from_label("Input")
+                    .selected_text(node.inputs[self.input_port].display_name())
+                    .show_ui(ui, |ui| {
+                        for (port, input) in node.inputs.iter().enumerate() {
+                            ui.selectable_value(&mut self.input_port, port, input.display_name());
+                        }
+                    });
+                if outputs.is_empty() {
+                    ui.weak("Label a wire to name its output");
+                }
+                for (label, from_node, from_port) in outputs {
+                    let source = graph.nodes.get(&from_node).map_or("?", |source| source.title.as_str());
+                    let enabled = from_node != node.id;
+                    if ui.add_enabled(enabled, egui::Button::new(format!("{}  ({})", label, source)))
+                        .on_hover_text(format!("Feed {} from this output", node.title))
+                        .clicked()
+                    {
+                        action = Some(WireAction::PickUp { label, from_node, from_port, to_node: node.id, to_port: self.input_port });
+                    }
+                }
+            });
+        action
+    }
+}
+
+/// Adapter picker for the connection at `index`, shown when its ports'
+/// types have adapters or it carries one; returns the newly picked adapter
+fn adapter_choice(ui: &mut egui::Ui, graph: &NodeGraph, index: usize) -> Option<Option<Adapter>> {
+    let connection = &graph.connections[index];
+    let type_of = |node_id: NodeId, is_input: bool, port: PortId| graph.nodes.get(&node_id)
+        .and_then(|node| crate::theme::port_data_type(&node.type_id, is_input, port));
+    let choices = match (type_of(connection.from_node, false, connection.from_port), type_of(connection.to_node, true, connection.to_port)) {
+        (Some(from), Some(to)) if from != to => adapters_for(&from, &to),
+        _ => Vec::new(),
+    };
+    if choices.is_empty() && connection.adapter.is_none() {
+        return None;
+    }
+    let mut adapter = connection.adapter;
+    egui::ComboBox::from_label("Adapter")
+        .selected_text(adapter.map_or("None", |adapter| adapter.label()))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut adapter, None, "None")
+                .on_hover_text("Pass values on unconverted");
+            for choice in choices {
+                ui.selectable_value(&mut adapter, Some(choice), choice.label());
+            }
+        });
+    (adapter != connection.adapter).then_some(adapter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stubs_hang_off_both_ports() {
+        let from = Pos2::new(0.0, 0.0);
+        let to = Pos2::new(500.0, 800.0);
+        assert!(stubs_hit(Pos2::new(2.0, 10.0), from, to, 4.0));
+        assert!(stubs_hit(Pos2::new(500.0, 790.0), from, to, 4.0));
+        // Nothing is drawn between the stubs
+        assert!(!stubs_hit(Pos2::new(250.0, 400.0), from, to, 4.0));
+    }
+}
@@ -160,9 +160,24 @@ impl NavigationManager {
         }
     }
     
-    /// Render the navigation breadcrumb bar
-    pub fn render_breadcrumb(&mut self, ui: &mut egui::Ui) -> NavigationAction {
+    /// Titles of the entered workspace nodes, outermost first
+    pub fn workspace_titles(&self, graph: &NodeGraph) -> Vec<String> {
+        let mut titles = Vec::new();
+        let mut current = Some(graph);
+        for node_id in &self.workspace_stack {
+            let Some(node) = current.and_then(|graph| graph.nodes.get(node_id)) else {
+                break;
+            };
+            titles.push(node.title.clone());
+            current = node.get_internal_graph();
+        }
+        titles
+    }
+    
+    /// Render the navigation breadcrumb bar; entered workspace nodes show their titles
+    pub fn render_breadcrumb(&mut self, ui: &mut egui::Ui, graph: &NodeGraph) -> NavigationAction {
         let mut action = NavigationAction::None;
+        let titles = self.workspace_titles(graph);
         
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 2.0;
@@ -170,7 +185,8 @@ impl NavigationManager {
             // Always show unified breadcrumb navigation
             let segments = self.current_path.breadcrumb_segments();
             
-            for (i, (name, path)) in segments.iter().enumerate() {
+            for (i, (segment, path)) in segments.iter().enumerate() {
+                let name = i.checked_sub(1).and_then(|index| titles.get(index)).unwrap_or(segment);
                 // Add separator between segments (except before first)
                 if i > 0 {
                     ui.label("/");
@@ -0,0 +1,505 @@
+//! Inspector panel implementation
+//!
+//! Shows the full structure of the `NodeData` flowing through a port: nested
+//! USD scene contents, array lengths, an estimate of the memory the value
+//! holds and the conversions applied across the port's connections.
+//! Alt-clicking a port opens the inspector on it; nodes whose panel type is
+//! Inspector show every one of their outputs this way.
+
+use egui::{Context, RichText, Ui};
+use crate::nodes::{Node, NodeId, NodeGraph, InterfacePanelManager};
+use crate::nodes::graph_stats::{estimated_size, format_bytes};
+use crate::nodes::interface::{GeometryData, NodeData, TableValue, TreeItem};
+use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDMeshGeometry};
+use crate::editor::panels::PanelAction;
+
+/// Children listed under one item before the rest are summarized
+const MAX_CHILDREN: usize = 64;
+
+/// The port the inspector window shows
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PortTarget {
+    node_id: NodeId,
+    port_idx: usize,
+    is_input: bool,
+}
+
+/// Inspector panel renderer
+pub struct InspectorPanel {
+    /// Port picked by alt-clicking it, shown in the inspector window
+    target: Option<PortTarget>,
+    /// Default inspector panel size
+    default_size: [f32; 2],
+}
+
+impl InspectorPanel {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            default_size: [360.0, 420.0],
+        }
+    }
+
+    /// Show the data flowing through a port in the inspector window
+    pub fn inspect(&mut self, node_id: NodeId, port_idx: usize, is_input: bool) {
+        self.target = Some(PortTarget { node_id, port_idx, is_input });
+    }
+
+    /// Stop inspecting a deleted node's port
+    pub fn cleanup_deleted_node(&mut self, node_id: NodeId) {
+        if self.target.is_some_and(|target| target.node_id == node_id) {
+            self.target = None;
+        }
+    }
+
+    /// Render the window of the alt-clicked port, if any
+    pub fn render_port_window(
+        &mut self,
+        ctx: &Context,
+        menu_bar_height: f32,
+        graph: &NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        let Some(target) = self.target else {
+            return;
+        };
+        let Some(node) = graph.nodes.get(&target.node_id) else {
+            self.target = None;
+            return;
+        };
+        let ports = if target.is_input { &node.inputs } else { &node.outputs };
+        let Some(port) = ports.get(target.port_idx) else {
+            self.target = None;
+            return;
+        };
+
+        let value = port_value(graph, execution_engine, target);
+        let conversions = port_conversions(graph, target.node_id, target.port_idx, target.is_input);
+        let direction = if target.is_input { "input" } else { "output" };
+
+        let mut is_open = true;
+        egui::Window::new(format!("🔎 {}.{}", node.title, port.name))
+            .id(egui::Id::new("inspector_port_window"))
+            .open(&mut is_open)
+            .default_size(self.default_size)
+            .resizable(true)
+            .collapsible(true)
+            .constrain_to(egui::Rect::from_min_size(
+                egui::Pos2::new(0.0, menu_bar_height),
+                egui::Vec2::new(ctx.screen_rect().width(), ctx.screen_rect().height() - menu_bar_height)
+            ))
+            .show(ctx, |ui| {
+                ui.label(RichText::new(format!("{} {} of {}", direction, target.port_idx, node.title)).weak());
+                ui.separator();
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    render_value(ui, &port.name, value.as_ref());
+                    render_conversions(ui, &conversions);
+                });
+            });
+
+        if !is_open {
+            self.target = None;
+        }
+    }
+
+    /// Render the panel of a node whose panel type is Inspector: the structure of each of its outputs
+    pub fn render(
+        &mut self,
+        ctx: &Context,
+        node_id: NodeId,
+        node: &Node,
+        panel_manager: &mut InterfacePanelManager,
+        menu_bar_height: f32,
+        _viewed_nodes: &std::collections::HashMap<NodeId, Node>,
+        graph: &mut NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) -> PanelAction {
+        if !panel_manager.is_panel_visible(node_id) {
+            return PanelAction::None;
+        }
+
+        let mut is_open = panel_manager.is_panel_open(node_id);
+        let mut window = egui::Window::new(format!("🔎 {} - Inspector", node.title))
+            .id(egui::Id::new(format!("inspector_panel_{}", node_id)))
+            .open(&mut is_open)
+            .default_size(self.default_size)
+            .default_pos(node.position + egui::Vec2::new(200.0, 0.0))
+            .resizable(true)
+            .collapsible(true)
+            .constrain_to(egui::Rect::from_min_size(
+                egui::Pos2::new(0.0, menu_bar_height),
+                egui::Vec2::new(ctx.screen_rect().width(), ctx.screen_rect().height() - menu_bar_height)
+            ));
+
+        // Restore the saved placement once after loading a file
+        if let Some(rect) = panel_manager.take_pending_rect(node_id) {
+            window = window.current_pos(rect.min).default_size(rect.size());
+        }
+
+        let window_response = window.show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                if node.outputs.is_empty() {
+                    ui.label(RichText::new("This node has no outputs").weak());
+                }
+                for (port_idx, port) in node.outputs.iter().enumerate() {
+                    let target = PortTarget { node_id, port_idx, is_input: false };
+                    let value = port_value(graph, execution_engine, target);
+                    render_value(ui, &port.name, value.as_ref());
+                    render_conversions(ui, &port_conversions(graph, node_id, port_idx, false));
+                    ui.separator();
+                }
+            });
+        });
+
+        // Remember the placement so it can be saved with the project
+        if let Some(response) = &window_response {
+            panel_manager.set_panel_rect(node_id, response.response.rect);
+        }
+
+        if is_open {
+            PanelAction::None
+        } else {
+            PanelAction::Close
+        }
+    }
+}
+
+impl Default for InspectorPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The value on a port as its node sees it: an output's cached result, or
+/// the connected output converted to the input's unit
+fn port_value(
+    graph: &NodeGraph,
+    execution_engine: &mut crate::nodes::NodeGraphEngine,
+    target: PortTarget,
+) -> Option<NodeData> {
+    if !target.is_input {
+        return execution_engine.get_cached_output(target.node_id, target.port_idx).cloned();
+    }
+    let connection = graph.connections.iter()
+        .find(|connection| connection.to_node == target.node_id && connection.to_port == target.port_idx)?;
+    let value = execution_engine.get_cached_output(connection.from_node, connection.from_port)?.clone();
+    let from_unit = graph.nodes.get(&connection.from_node)
+        .and_then(|source| source.outputs.get(connection.from_port))
+        .map(|port| port.unit)
+        .unwrap_or_default();
+    let to_unit = graph.nodes.get(&target.node_id)
+        .and_then(|node| node.inputs.get(target.port_idx))
+        .map(|port| port.unit)
+        .unwrap_or_default();
+    Some(from_unit.convert_data(value, to_unit))
+}
+
+/// Conversions applied on the connections through a port, one line each
+pub fn port_conversions(graph: &NodeGraph, node_id: NodeId, port_idx: usize, is_input: bool) -> Vec<String> {
+    let mut conversions = Vec::new();
+    for connection in &graph.connections {
+        let attached = if is_input {
+            connection.to_node == node_id && connection.to_port == port_idx
+        } else {
+            connection.from_node == node_id && connection.from_port == port_idx
+        };
+        if !attached {
+            continue;
+        }
+        let (Some(source), Some(target)) = (graph.nodes.get(&connection.from_node), graph.nodes.get(&connection.to_node)) else {
+            continue;
+        };
+        let (Some(from_port), Some(to_port)) = (source.outputs.get(connection.from_port), target.inputs.get(connection.to_port)) else {
+            continue;
+        };
+        let route = format!("{}.{} → {}.{}", source.title, from_port.name, target.title, to_port.name);
+
+        if from_port.unit.converts_to(to_port.unit) {
+            conversions.push(format!(
+                "{}: {} to {} (×{})",
+                route,
+                from_port.unit.name(),
+                to_port.unit.name(),
+                from_port.unit.convert(1.0, to_port.unit),
+            ));
+        }
+
+        if let Some(adapter) = connection.adapter {
+            conversions.push(format!("{}: {} adapter", route, adapter.label()));
+            continue;
+        }
+        let from_type = crate::theme::port_data_type(&source.type_id, false, connection.from_port);
+        let to_type = crate::theme::port_data_type(&target.type_id, true, connection.to_port);
+        if let (Some(from_type), Some(to_type)) = (from_type, to_type) {
+            let generic = from_type == crate::nodes::DataType::Any || to_type == crate::nodes::DataType::Any;
+            if from_type != to_type && !generic {
+                conversions.push(format!("{}: {} widened to {}", route, from_type.name(), to_type.name()));
+            }
+        }
+    }
+    conversions
+}
+
+/// Type name and structure of a value as a tree: label, detail and nested parts
+pub fn describe(label: &str, value: &NodeData) -> TreeItem {
+    let item = |detail: String| TreeItem::new(label, label).with_detail(detail);
+    match value {
+        NodeData::Float(v) => item(format!("Float = {}", v)),
+        NodeData::Integer(v) => item(format!("Integer = {}", v)),
+        NodeData::Boolean(v) => item(format!("Boolean = {}", v)),
+        NodeData::Vector3(v) => item(format!("Vector3 = {:?}", v)),
+        NodeData::Color(c) => item(format!("Color = {:?}", c)),
+        NodeData::String(text) => item(format!("String ({} chars)", text.chars().count())),
+        NodeData::Any(text) => item(format!("Any ({})", text)),
+        NodeData::Custom(data) => item(format!("{} ({})", crate::plugins::data_types::type_name(&data.type_id), data.type_id)),
+        NodeData::None => item("None".to_string()),
+        NodeData::Buffer(values) => item(format!("Buffer [{}]", values.len())),
+        NodeData::Table(table) => {
+            let mut table_item = item(format!("Table {} rows × {} columns", table.row_count(), table.column_count()));
+            table_item.children = table.columns.iter().enumerate()
+                .map(|(index, column)| {
+                    let kind = table.rows.iter()
+                        .filter_map(|row| row.get(index))
+                        .find(|cell| !matches!(cell, TableValue::Null))
+                        .map_or("empty", |cell| match cell {
+                            TableValue::Number(_) => "number",
+                            TableValue::Text(_) => "text",
+                            TableValue::Boolean(_) => "boolean",
+                            TableValue::Null => "empty",
+                        });
+                    TreeItem::new(format!("{}/{}", label, column), column.as_str()).with_detail(kind)
+                })
+                .collect();
+            table_item
+        }
+        NodeData::Tree(tree) => {
+            let mut tree_item = item(format!("Tree ({} items)", tree.item_count()));
+            tree_item.children = tree.roots.clone();
+            tree_item
+        }
+        NodeData::PointCloud(points) => item(format!(
+            "Point cloud [{}]{}",
+            points.point_count(),
+            if points.has_colors() { " with colors" } else { "" }
+        )),
+        NodeData::Volume(volume) => {
+            let mut volume_item = item(format!("Volume ({} grids)", volume.grids.len()));
+            volume_item.children = volume.grids.iter().map(|grid| item(format!(
+                "{} ({}, {}, {} active voxels)",
+                grid.name, grid.value_type, grid.grid_class, grid.active_voxels
+            ))).collect();
+            volume_item
+        }
+        NodeData::List(items) => {
+            let mut list_item = item(format!("List [{}]", items.len()));
+            list_item.children = limited(
+                items.iter().enumerate().map(|(index, value)| describe(&format!("{}[{}]", label, index), value)),
+                label,
+            );
+            list_item
+        }
+        NodeData::Map(entries) => {
+            let mut map_item = item(format!("Map ({} keys)", entries.len()));
+            map_item.children = limited(
+                entries.iter().map(|(key, value)| describe(&format!("{}.{}", label, key), value)),
+                label,
+            );
+            map_item
+        }
+        NodeData::Audio(audio) => item(format!(
+            "Audio {:.2}s ({} Hz, {} channels)",
+            audio.duration(), audio.sample_rate, audio.channels
+        )),
+        NodeData::Geometry(geometry) => describe_geometry(label, geometry),
+        NodeData::Material(material) => item(format!("Material '{}' (metallic {}, roughness {})", material.id, material.metallic, material.roughness)),
+        NodeData::Light(light) => item(format!("Light '{}' ({:?}, intensity {})", light.id, light.light_type, light.intensity)),
+        NodeData::Image(image) => item(format!("Image {} × {} {:?}", image.width, image.height, image.format)),
+        NodeData::Stage(stage) => {
+            let mut stage_item = item(format!("Stage '{}' ({} prims)", stage.identifier, stage.prims.len()));
+            stage_item.children = limited(stage.prims.iter().map(|prim| TreeItem::new(prim.as_str(), prim.as_str())), label);
+            stage_item
+        }
+        NodeData::Scene(scene) => {
+            let mut scene_item = item(format!(
+                "Scene ({} geometry, {} materials, {} lights)",
+                scene.geometry.len(), scene.materials.len(), scene.lights.len()
+            ));
+            scene_item.children = limited(
+                scene.geometry.iter().map(|geometry| describe_geometry(&geometry.id, geometry)),
+                label,
+            );
+            scene_item
+        }
+        NodeData::USDSceneData(scene) => {
+            let mut scene_item = item(format!(
+                "USD scene '{}' (up axis {})",
+                scene.stage_path, scene.up_axis
+            ));
+            let meshes = TreeItem::new(format!("{}/meshes", label), "meshes")
+                .with_detail(format!("[{}]", scene.meshes.len()));
+            let lights = TreeItem::new(format!("{}/lights", label), "lights")
+                .with_detail(format!("[{}]", scene.lights.len()));
+            let materials = TreeItem::new(format!("{}/materials", label), "materials")
+                .with_detail(format!("[{}]", scene.materials.len()));
+            scene_item.children = vec![
+                TreeItem { children: limited(scene.meshes.iter().map(describe_usd_mesh), label), ..meshes },
+                TreeItem {
+                    children: limited(scene.lights.iter().map(|light| {
+                        TreeItem::new(light.prim_path.as_str(), light.prim_path.as_str())
+                            .with_detail(format!("{} light, intensity {}", light.light_type, light.intensity))
+                    }), label),
+                    ..lights
+                },
+                TreeItem {
+                    children: limited(scene.materials.iter().map(|material| {
+                        TreeItem::new(material.prim_path.as_str(), material.prim_path.as_str())
+                            .with_detail(format!("metallic {}, roughness {}", material.metallic, material.roughness))
+                    }), label),
+                    ..materials
+                },
+            ];
+            scene_item
+        }
+        NodeData::USDScenegraphMetadata(metadata) => {
+            let mut metadata_item = item(format!(
+                "USD scenegraph '{}' ({} vertices, {} triangles)",
+                metadata.stage_path, metadata.total_vertices, metadata.total_triangles
+            ));
+            metadata_item.children = limited(metadata.meshes.iter().map(|mesh| {
+                TreeItem::new(mesh.prim_path.as_str(), mesh.prim_path.as_str())
+                    .with_detail(format!("{} vertices, {} triangles", mesh.vertex_count, mesh.triangle_count))
+            }), label);
+            metadata_item
+        }
+    }
+}
+
+fn describe_geometry(label: &str, geometry: &GeometryData) -> TreeItem {
+    let mut item = TreeItem::new(label, label).with_detail(format!("Geometry '{}'", geometry.id));
+    item.children = vec![
+        array_item(label, "vertices", geometry.vertices.len()),
+        array_item(label, "indices", geometry.indices.len()),
+        array_item(label, "normals", geometry.normals.len()),
+        array_item(label, "uvs", geometry.uvs.len()),
+    ];
+    item
+}
+
+fn describe_usd_mesh(mesh: &USDMeshGeometry) -> TreeItem {
+    let path = mesh.prim_path.as_str();
+    let mut item = TreeItem::new(path, path)
+        .with_detail(format!("{} vertices, {} triangles", mesh.vertices.len(), mesh.indices.len() / 3));
+    item.children = vec![
+        array_item(path, "points", mesh.vertices.len()),
+        array_item(path, "indices", mesh.indices.len()),
+        array_item(path, "normals", mesh.normals.len()),
+        array_item(path, "uvs", mesh.uvs.len()),
+        array_item(path, "displayColor", mesh.vertex_colors.as_ref().map_or(0, Vec::len)),
+    ];
+    item.children.extend(mesh.primvars.iter().map(|primvar| {
+        TreeItem::new(format!("{}/primvars:{}", path, primvar.name), format!("primvars:{}", primvar.name))
+            .with_detail(format!("{} {} [{}]", primvar.interpolation, primvar.data_type, primvar_len(&primvar.values)))
+    }));
+    if !mesh.attributes.is_empty() {
+        let mut attributes = TreeItem::new(format!("{}/attributes", path), "attributes")
+            .with_detail(format!("[{}]", mesh.attributes.len()));
+        attributes.children = limited(mesh.attributes.iter().map(|attribute| {
+            TreeItem::new(format!("{}.{}", path, attribute.name), attribute.name.as_str())
+                .with_detail(attribute.value_type.as_str())
+        }), path);
+        item.children.push(attributes);
+    }
+    item
+}
+
+fn array_item(parent: &str, name: &str, len: usize) -> TreeItem {
+    TreeItem::new(format!("{}/{}", parent, name), name).with_detail(format!("[{}]", len))
+}
+
+/// At most `MAX_CHILDREN` items, with a final note counting the rest
+fn limited(items: impl ExactSizeIterator<Item = TreeItem>, parent: &str) -> Vec<TreeItem> {
+    let total = items.len();
+    let mut children: Vec<TreeItem> = items.take(MAX_CHILDREN).collect();
+    if total > MAX_CHILDREN {
+        children.push(TreeItem::new(format!("{}/…", parent), format!("… {} more", total - MAX_CHILDREN)));
+    }
+    children
+}
+
+fn primvar_len(values: &PrimvarValues) -> usize {
+    match values {
+        PrimvarValues::Float(values) => values.len(),
+        PrimvarValues::Float2(values) => values.len(),
+        PrimvarValues::Float3(values) => values.len(),
+        PrimvarValues::Int(values) => values.len(),
+        PrimvarValues::String(values) => values.len(),
+    }
+}
+
+/// Structure and size of one port's value
+fn render_value(ui: &mut Ui, name: &str, value: Option<&NodeData>) {
+    let Some(value) = value else {
+        ui.label(RichText::new(format!("{}: not cooked yet", name)).weak());
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.strong(name);
+        ui.label(RichText::new(format!("≈ {}", format_bytes(estimated_size(value)))).weak())
+            .on_hover_text("Estimated memory held by the value");
+    });
+    render_item(ui, &describe(name, value), 0);
+}
+
+fn render_item(ui: &mut Ui, item: &TreeItem, depth: usize) {
+    let text = match &item.detail {
+        Some(detail) => format!("{}: {}", item.label, detail),
+        None => item.label.clone(),
+    };
+    if item.children.is_empty() {
+        ui.label(text);
+    } else {
+        egui::CollapsingHeader::new(text)
+            .id_salt(("inspector_item", item.path.as_str(), depth))
+            .default_open(depth == 0)
+            .show(ui, |ui| {
+                for child in &item.children {
+                    render_item(ui, child, depth + 1);
+                }
+            });
+    }
+}
+
+fn render_conversions(ui: &mut Ui, conversions: &[String]) {
+    if conversions.is_empty() {
+        return;
+    }
+    ui.add_space(4.0);
+    ui.label(RichText::new("Conversions").strong());
+    for conversion in conversions {
+        ui.label(format!("• {}", conversion));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::interface::TableData;
+
+    #[test]
+    fn test_describe_lists_structure() {
+        let table = NodeData::Table(TableData {
+            columns: vec!["name".to_string(), "height".to_string()],
+            rows: vec![
+                vec![TableValue::Text("fir".to_string()), TableValue::Number(12.0)],
+                vec![TableValue::Null, TableValue::Number(3.5)],
+            ],
+        });
+        let item = describe("rows", &table);
+        assert_eq!(item.detail.as_deref(), Some("Table 2 rows × 2 columns"));
+        let kinds: Vec<_> = item.children.iter().map(|child| child.detail.clone().unwrap()).collect();
+        assert_eq!(kinds, vec!["text", "number"]);
+
+        let buffer = NodeData::Buffer(vec![0.0; 1000]);
+        assert_eq!(describe("values", &buffer).detail.as_deref(), Some("Buffer [1000]"));
+    }
+}
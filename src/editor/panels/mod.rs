@@ -7,11 +7,13 @@ mod parameter;
 mod viewport;
 mod tree;
 mod spreadsheet;
+mod inspector;
 
-pub use parameter::ParameterPanel;
+pub use parameter::{ParameterPanel, AppliedParameterChange, fit_node_to_title, render_node_notes};
 pub use viewport::ViewportPanel;
 pub use tree::TreePanel;
 pub use spreadsheet::SpreadsheetPanel;
+pub use inspector::InspectorPanel;
 
 use egui::Ui;
 use crate::nodes::{
@@ -49,6 +51,8 @@ pub struct PanelManager {
     tree_panel: TreePanel,
     /// Spreadsheet panel renderer
     spreadsheet_panel: SpreadsheetPanel,
+    /// Inspector panel renderer, also showing alt-clicked ports
+    inspector_panel: InspectorPanel,
 }
 
 impl PanelManager {
@@ -61,6 +65,7 @@ impl PanelManager {
             viewport_panel: ViewportPanel::new(),
             tree_panel: TreePanel::new(),
             spreadsheet_panel: SpreadsheetPanel::new(),
+            inspector_panel: InspectorPanel::new(),
         }
     }
 
@@ -74,11 +79,21 @@ impl PanelManager {
         &mut self.interface_panel_manager
     }
     
+    /// Take the parameter changes applied through panels since the last call
+    pub fn take_applied_parameter_changes(&mut self) -> Vec<AppliedParameterChange> {
+        self.parameter_panel.take_applied_changes()
+    }
+    
     /// Get a mutable reference to the tree panel
     pub fn tree_panel_mut(&mut self) -> &mut TreePanel {
         &mut self.tree_panel
     }
 
+    /// Open the inspector on the data flowing through a port
+    pub fn inspect_port(&mut self, node_id: NodeId, port_idx: usize, is_input: bool) {
+        self.inspector_panel.inspect(node_id, port_idx, is_input);
+    }
+
     /// Set the current menu bar height for window constraints
     pub fn set_menu_bar_height(&mut self, height: f32) {
         self.current_menu_bar_height = height;
@@ -190,6 +205,18 @@ impl PanelManager {
                         debug!("PanelManager: Spreadsheet panel render completed for node {}, result: {:?}", node_id, result);
                         result
                     },
+                    PanelType::Inspector => {
+                        self.inspector_panel.render(
+                            ctx,
+                            node_id,
+                            node,
+                            &mut self.interface_panel_manager,
+                            menu_bar_height,
+                            viewed_nodes,
+                            graph,
+                            execution_engine,
+                        )
+                    },
                     _ => {
                         // All other types use parameter panel for now
                         self.parameter_panel.render(
@@ -235,6 +262,9 @@ impl PanelManager {
         }
         
         
+        // The alt-clicked port's inspector window
+        self.inspector_panel.render_port_window(ctx, menu_bar_height, graph, execution_engine);
+
         // Apply panel actions (after iteration to avoid borrowing conflicts)
         for node_id in nodes_to_close {
             debug!("PanelManager: Applying close action for node {}", node_id);
@@ -314,6 +344,10 @@ impl PanelManager {
         // Clean up tree panel caches
         self.tree_panel.cleanup_deleted_node(node_id);
         
+        // Clean up spreadsheet view state
+        self.spreadsheet_panel.cleanup_deleted_node(node_id);
+        self.inspector_panel.cleanup_deleted_node(node_id);
+        
         // Clean up interface panel manager state
         self.interface_panel_manager.set_panel_visibility(node_id, false);
         self.interface_panel_manager.set_panel_open(node_id, false);
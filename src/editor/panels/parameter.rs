@@ -5,23 +5,102 @@
 use egui::{Context, Color32, Pos2};
 use crate::nodes::{Node, NodeId, InterfacePanelManager};
 use crate::nodes::interface::NodeData;
+use crate::nodes::expressions;
 use crate::editor::panels::PanelAction;
-use std::collections::HashMap;
-use log::info;
+use crate::editor::presets::PresetStore;
+use crate::plugins::sandbox;
+use std::collections::{HashMap, HashSet};
+use log::{error, info};
 
 /// Parameter panel renderer
 pub struct ParameterPanel {
     /// Tracks which parameter panels are in stacked mode
     stacked_panels: HashMap<NodeId, bool>,
+    /// Parameter changes applied since the editor last collected them
+    applied_changes: Vec<AppliedParameterChange>,
+    /// Saved parameter presets
+    presets: PresetStore,
+    /// Name typed for the next preset, per panel
+    preset_names: HashMap<NodeId, String>,
+}
+
+/// A parameter change applied through a panel, with the value it replaced
+#[derive(Debug, Clone)]
+pub struct AppliedParameterChange {
+    pub node_id: NodeId,
+    pub node_title: String,
+    pub parameter: String,
+    pub old_value: crate::nodes::interface::NodeData,
+    pub new_value: crate::nodes::interface::NodeData,
+}
+
+/// Tag colors offered in the parameter panel; any other can be picked too
+const TAG_COLORS: [Color32; 6] = [
+    Color32::from_rgb(150, 60, 60),
+    Color32::from_rgb(160, 110, 40),
+    Color32::from_rgb(140, 140, 50),
+    Color32::from_rgb(60, 120, 70),
+    Color32::from_rgb(50, 100, 150),
+    Color32::from_rgb(110, 70, 140),
+];
+
+/// Tag color, tags and notes of a node, shown atop its parameter panel and
+/// when hovering it on the canvas
+pub fn render_node_notes(ui: &mut egui::Ui, node: &Node) {
+    if node.tag_color.is_some() || !node.tags.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            if let Some(color) = node.tag_color {
+                let (rect, _) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                ui.painter().rect_filled(rect, 2.0, color);
+            }
+            for tag in &node.tags {
+                ui.label(egui::RichText::new(format!("#{}", tag)).small().color(Color32::from_rgb(150, 190, 230)));
+            }
+        });
+    }
+    if !node.notes.is_empty() {
+        ui.label(egui::RichText::new(&node.notes).italics());
+    }
+}
+
+/// Tags typed as comma-separated text, trimmed and without repeats
+fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in text.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Widen or narrow a node so its whole title shows (the "Fit name" option)
+pub fn fit_node_to_title(ctx: &Context, node: &mut Node) {
+    let font_id = egui::FontId::proportional(12.0);
+    let text_width = ctx.fonts(|fonts| {
+        fonts.layout_no_wrap(node.title.clone(), font_id, egui::Color32::WHITE).size().x
+    });
+    let padding = 60.0; // 30px padding on each side (extra 30px to avoid visibility flag)
+    let min_width = 120.0; // Minimum node width
+    node.size.x = (text_width + padding).max(min_width);
+    node.update_port_positions();
 }
 
 impl ParameterPanel {
     pub fn new() -> Self {
         Self {
             stacked_panels: HashMap::new(),
+            applied_changes: Vec::new(),
+            presets: PresetStore::new(),
+            preset_names: HashMap::new(),
         }
     }
 
+    /// Take the parameter changes applied since the last call
+    pub fn take_applied_changes(&mut self) -> Vec<AppliedParameterChange> {
+        std::mem::take(&mut self.applied_changes)
+    }
+
     /// Render parameter panels (handles both stacked and individual)
     pub fn render(
         &mut self,
@@ -100,7 +179,7 @@ impl ParameterPanel {
         
         // Create parameter panel window
         let window_title = format!("{} Parameters", node.title);
-        egui::Window::new(&window_title)
+        let mut window = egui::Window::new(&window_title)
             .id(panel_id)
             .default_pos(position)
             .default_size(crate::constants::panel::DEFAULT_PARAMETER_SIZE)
@@ -112,28 +191,41 @@ impl ParameterPanel {
             .constrain_to(egui::Rect::from_min_size(
                 egui::Pos2::new(0.0, menu_bar_height), 
                 egui::Vec2::new(screen_rect.width(), screen_rect.height() - menu_bar_height)
-            ))
-            .show(ctx, |ui| {
-                // Panel controls at the top
-                let (control_action, close_requested) = self.render_panel_controls(ui, node_id, panel_manager);
-                if control_action != PanelAction::None {
-                    panel_action = control_action;
-                }
-                if close_requested {
-                    panel_action = PanelAction::Close;
-                }
-                
-                ui.separator();
-                
-                // Node-specific content
-                egui::Frame::default()
-                    .inner_margin(egui::Margin::same(8))
-                    .fill(Color32::from_gray(40))
-                    .corner_radius(4.0)
-                    .show(ui, |ui| {
-                        self.render_parameter_content(ui, node_id, panel_manager, graph, execution_engine);
-                    });
-            });
+            ));
+        
+        // Restore the saved placement once after loading a file
+        if let Some(rect) = panel_manager.take_pending_rect(node_id) {
+            window = window.current_pos(rect.min).default_size(rect.size());
+        }
+        
+        let window_response = window.show(ctx, |ui| {
+            // Panel controls at the top
+            let (control_action, close_requested) = self.render_panel_controls(ui, node_id, panel_manager);
+            if control_action != PanelAction::None {
+                panel_action = control_action;
+            }
+            if close_requested {
+                panel_action = PanelAction::Close;
+            }
+            if let Some(node) = graph.nodes.get(&node_id) {
+                render_node_notes(ui, node);
+            }
+            
+            ui.separator();
+            
+            // Node-specific content
+            egui::Frame::default()
+                .inner_margin(egui::Margin::same(8))
+                .fill(Color32::from_gray(40))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    self.render_parameter_content(ui, node_id, panel_manager, graph, execution_engine);
+                });
+        });
+        
+        if let Some(response) = &window_response {
+            panel_manager.set_panel_rect(node_id, response.response.rect);
+        }
         
         // Update the panel manager with the new state
         panel_manager.set_panel_open(node_id, window_open);
@@ -218,6 +310,9 @@ impl ParameterPanel {
                                         if close_requested {
                                             panel_action = PanelAction::Close;
                                         }
+                                        if let Some(node) = graph.nodes.get(&node_id) {
+                                            render_node_notes(ui, node);
+                                        }
                                         
                                         // Separator with negative margin to extend to window edge
                                         egui::Frame::default()
@@ -345,36 +440,30 @@ impl ParameterPanel {
         ui.horizontal(|ui| {
             ui.label("Name:");
             
-            // Rendering name text field
-            
-            // Name text field - directly edit the node's title
+            // The typed name is kept in egui memory while the field has focus and
+            // applied when it loses focus, so uniqueness suffixes don't fight the typing
+            let edit_id = ui.make_persistent_id(("node_name_edit", node_id));
+            if let Some(editing) = ui.data(|data| data.get_temp::<String>(edit_id)) {
+                name_buffer = editing;
+            }
             let name_response = ui.text_edit_singleline(&mut name_buffer);
+            if name_response.has_focus() {
+                ui.data_mut(|data| data.insert_temp(edit_id, name_buffer.clone()));
+            }
             
-            // Name field rendered
-            
-            if name_response.changed() {
-                // Name changed by user
-                
-                // Update the actual node's title in the graph
-                if let Some(node_mut) = graph.nodes.get_mut(&node_id) {
-                    // Updating graph node title
-                    node_mut.title = name_buffer.clone();
-                    
-                    // If fit name is enabled, resize the node to fit the new title
+            let cancelled = ui.input(|input| input.key_pressed(egui::Key::Escape));
+            if name_response.lost_focus() {
+                ui.data_mut(|data| data.remove::<String>(edit_id));
+            }
+            if name_response.lost_focus() && !cancelled {
+                // Renaming keeps titles unique and updates expressions reading this node
+                if let Some(title) = graph.rename_node(node_id, &name_buffer) {
+                    name_buffer = title;
                     if fit_name {
-                        // Calculate new size based on actual text width with 15px padding on each side
-                        let font_id = egui::FontId::proportional(12.0);
-                        let text_width = ui.fonts(|fonts| {
-                            fonts.layout_no_wrap(name_buffer.clone(), font_id, egui::Color32::WHITE).size().x
-                        });
-                        let padding = 60.0; // 30px padding on each side (extra 30px to avoid visibility flag)
-                        let min_width = 120.0; // Minimum node width
-                        let new_width = (text_width + padding).max(min_width);
-                        node_mut.size.x = new_width;
-                        node_mut.update_port_positions(); // Update port positions after resize
+                        if let Some(node_mut) = graph.nodes.get_mut(&node_id) {
+                            fit_node_to_title(ui.ctx(), node_mut);
+                        }
                     }
-                    
-                } else {
                 }
             }
             
@@ -387,16 +476,7 @@ impl ParameterPanel {
                 if fit_name {
                     // Fit name was just enabled - resize to fit text
                     if let Some(node_mut) = graph.nodes.get_mut(&node_id) {
-                        // Calculate new size based on actual text width with 15px padding on each side
-                        let font_id = egui::FontId::proportional(12.0);
-                        let text_width = ui.fonts(|fonts| {
-                            fonts.layout_no_wrap(node_mut.title.clone(), font_id, egui::Color32::WHITE).size().x
-                        });
-                        let padding = 60.0; // 30px padding on each side (extra 30px to avoid visibility flag)
-                        let min_width = 120.0; // Minimum node width
-                        let new_width = (text_width + padding).max(min_width);
-                        node_mut.size.x = new_width;
-                        node_mut.update_port_positions();
+                        fit_node_to_title(ui.ctx(), node_mut);
                     }
                 } else {
                     // Fit name was just disabled - restore default width
@@ -409,6 +489,10 @@ impl ParameterPanel {
             }
         });
         
+        Self::render_note_controls(ui, node_id, graph);
+        self.render_preset_controls(ui, node_id, graph, execution_engine);
+        Self::render_expression_controls(ui, node_id, graph, execution_engine);
+        
         ui.separator();
         
         // Show node info - using fresh graph data
@@ -481,6 +565,11 @@ impl ParameterPanel {
         
         ui.separator();
         
+        // MaterialX nodes show their shader on a preview ball
+        if graph.nodes.get(&node_id).and_then(crate::nodes::materialx::mtlx::node_def_of).is_some() {
+            crate::nodes::materialx::preview::show(ui, graph, node_id);
+        }
+        
         // Use proper parameter interface for all nodes that have build_interface methods
         let handled = if graph.nodes.contains_key(&node_id) {
             self.render_node_interface_safe(ui, node_id, execution_engine, graph)
@@ -547,13 +636,19 @@ impl ParameterPanel {
         // This avoids the borrowing conflict while still enabling parameter change notifications
         let mut changes_applied = false;
         let mut handled = false;
+        let connected_ports: HashSet<usize> = graph.connections.iter()
+            .filter(|connection| connection.to_node == node_id)
+            .map(|connection| connection.to_port)
+            .collect();
         
         if let Some(node) = graph.nodes.get_mut(&node_id) {
             let title = node.title.clone();
+            let schema = self.parameter_schema(&node.type_id);
+            let inputs = self.input_schema(&node.type_id);
             // Rendering node interface
             
             // Try to handle all known node types with build_interface methods
-            let changes = match node.type_id.as_str() {
+            let mut changes = match node.type_id.as_str() {
                 // Data nodes
                 "Data_UsdFileReader" => {
                     // Using USD File Reader interface
@@ -605,19 +700,14 @@ impl ParameterPanel {
                     // Using Capsule interface
                     crate::nodes::three_d::geometry::capsule::parameters::CapsuleParameters::build_interface(node, ui)
                 },
-                
-                // 3D Lighting nodes
-                "Point Light" => {
-                    // Using Point Light interface
-                    crate::nodes::three_d::lighting::point_light::parameters::PointLightNode::build_interface(node, ui)
+                "Torus" | "3D_Torus" => {
+                    crate::nodes::three_d::geometry::torus::parameters::TorusParameters::build_interface(node, ui)
                 },
-                "Directional Light" => {
-                    // Using Directional Light interface
-                    crate::nodes::three_d::lighting::directional_light::parameters::DirectionalLightNode::build_interface(node, ui)
+                "Disk" | "3D_Disk" => {
+                    crate::nodes::three_d::geometry::disk::parameters::DiskParameters::build_interface(node, ui)
                 },
-                "Spot Light" => {
-                    // Using Spot Light interface
-                    crate::nodes::three_d::lighting::spot_light::parameters::SpotLightNode::build_interface(node, ui)
+                "Tube" | "3D_Tube" => {
+                    crate::nodes::three_d::geometry::tube::parameters::TubeParameters::build_interface(node, ui)
                 },
                 
                 // 3D Modify nodes
@@ -625,6 +715,8 @@ impl ParameterPanel {
                     // Using Reverse interface
                     crate::nodes::three_d::modify::reverse::parameters::ReverseNode::build_interface(node, ui)
                 },
+                "3D_VariantSelect" => crate::nodes::three_d::modify::variant_select::VariantSelectNode::build_interface(node, ui),
+                "3D_LayerStack" => crate::nodes::three_d::modify::layer_stack::LayerStackNode::build_interface(node, ui),
                 
                 // 3D Output nodes
                 "3D_Render" => {
@@ -632,12 +724,55 @@ impl ParameterPanel {
                     crate::nodes::three_d::output::render::parameters::RenderParameters::build_interface(node, ui)
                 },
                 
+                // Integer math nodes
+                "Math_IntegerConstant" => crate::nodes::math::integer_constant::parameters::IntegerConstantNode::build_interface(node, ui),
+                "Math_IntegerArithmetic" => crate::nodes::math::integer_arithmetic::parameters::IntegerArithmeticNode::build_interface(node, ui),
+                "Math_Modulo" => crate::nodes::math::modulo::parameters::ModuloNode::build_interface(node, ui),
+                "Math_Bitwise" => crate::nodes::math::bitwise::parameters::BitwiseNode::build_interface(node, ui),
+                "Math_ConvertUnits" => crate::nodes::math::convert_units::parameters::ConvertUnitsNode::build_interface(node, ui),
+                "Math_Clamp" => crate::nodes::math::clamp::parameters::ClampNode::build_interface(node, ui),
+                "Math_Remap" => crate::nodes::math::remap::parameters::RemapNode::build_interface(node, ui),
+                "Math_Lerp" => crate::nodes::math::lerp::parameters::LerpNode::build_interface(node, ui),
+                "Math_Smoothstep" => crate::nodes::math::smoothstep::parameters::SmoothstepNode::build_interface(node, ui),
+                "Math_Fit" => crate::nodes::math::fit::parameters::FitNode::build_interface(node, ui),
+                "Math_Function" => crate::nodes::math::function::parameters::FunctionNode::build_interface(node, ui),
+                
+                // Logic nodes
+                "Logic_Xor" => crate::nodes::logic::xor::parameters::XorNode::build_interface(node, ui),
+                "Logic_Nand" => crate::nodes::logic::nand::parameters::NandNode::build_interface(node, ui),
+                "Logic_Compare" => crate::nodes::logic::compare::parameters::CompareNode::build_interface(node, ui),
+                "Logic_InRange" => crate::nodes::logic::in_range::parameters::InRangeNode::build_interface(node, ui),
+                "Logic_Branch" => crate::nodes::logic::branch::parameters::BranchNode::build_interface(node, ui),
+                "Logic_BoolToFloat" => crate::nodes::logic::bool_to_float::parameters::BoolToFloatNode::build_interface(node, ui),
+                "Logic_Counter" => crate::nodes::logic::counter::parameters::CounterNode::build_interface(node, ui),
+                "Logic_Accumulate" => crate::nodes::logic::accumulate::parameters::AccumulateNode::build_interface(node, ui),
+                "Logic_Hold" => crate::nodes::logic::hold::parameters::HoldNode::build_interface(node, ui),
+                "Logic_Feedback" => crate::nodes::logic::feedback::parameters::FeedbackNode::build_interface(node, ui),
+                "Logic_Timer" => crate::nodes::logic::timer::parameters::TimerNode::build_interface(node, ui),
+                "Logic_Trigger" => crate::nodes::logic::trigger::parameters::TriggerNode::build_interface(node, ui),
+                
+                // Utility and data nodes
+                "Utility_ComputeShader" => crate::nodes::utility::compute_shader::ComputeShaderNode::build_interface(node, ui),
+                "Data_ReadCsv" => crate::nodes::data::read_csv::ReadCsvNode::build_interface(node, ui),
+                "3D_ReadGLTF" => crate::nodes::three_d::input::read_gltf::ReadGltfNode::build_interface(node, ui),
+                "3D_ReadMesh" => crate::nodes::three_d::input::read_mesh::ReadMeshNode::build_interface(node, ui),
+                "3D_ReadPoints" => crate::nodes::three_d::input::read_points::ReadPointsNode::build_interface(node, ui),
+                "3D_ReadVDB" => crate::nodes::three_d::input::read_vdb::ReadVdbNode::build_interface(node, ui),
+                "Data_ReadJson" => crate::nodes::data::read_json::ReadJsonNode::build_interface(node, ui),
+                "Data_ReadAudio" => crate::nodes::data::read_audio::ReadAudioNode::build_interface(node, ui),
+                "Data_AudioAnalysis" => crate::nodes::data::audio_analysis::AudioAnalysisNode::build_interface(node, ui),
+                "MaterialX_Image" => crate::nodes::materialx::textures::build_image_interface(node, ui),
+                "MaterialX_StandardSurface" => crate::nodes::materialx::shading::build_standard_surface_interface(node, ui),
+                
                 // Other node types - check if it's a plugin node first, otherwise use generic interface
                 _ => {
                     // Check if this is a plugin node that should be handled by plugin system
                     if node.plugin_node.is_some() {
                         // This is a plugin node - don't handle it here, let plugin handling take over
                         Vec::new() // Return empty changes - plugin handler will take over
+                    } else if !schema.is_empty() {
+                        // Interface generated from the parameters declared in the node's metadata
+                        crate::nodes::parameter_schema::build_schema_interface(node, ui, &schema)
                     } else {
                         // Using generic parameter interface
                         // Fall back to basic parameter editing for unknown types
@@ -647,11 +782,26 @@ impl ParameterPanel {
                 }
             };
             
-            // Apply changes if any were detected
+            // Defaults of unconnected inputs, generated from the declared ports
+            changes.extend(crate::nodes::parameter_schema::build_port_default_interface(
+                node, ui, &inputs, &|port| connected_ports.contains(&port),
+            ));
+            
+            // Apply changes if any were detected, keeping declared parameters within their schema
+            let changes = crate::nodes::parameter_schema::validate_changes(&schema, changes);
             if !changes.is_empty() {
                 info!("Applied {} parameter changes for {} node {}", changes.len(), title, node_id);
                 // Applying parameter changes
                 for change in changes {
+                    if let Some(old_value) = node.parameters.get(&change.parameter) {
+                        self.applied_changes.push(AppliedParameterChange {
+                            node_id,
+                            node_title: node.title.clone(),
+                            parameter: change.parameter.clone(),
+                            old_value: old_value.clone(),
+                            new_value: change.value.clone(),
+                        });
+                    }
                     node.parameters.insert(change.parameter.clone(), change.value.clone());
                     
                     // Special handling for render node trigger_render parameter
@@ -714,18 +864,18 @@ impl ParameterPanel {
     ) -> bool {
         if let Some(node) = graph.nodes.get_mut(&node_id) {
             let title = node.title.clone();
+            let type_id = node.type_id.clone();
             
             // Check for plugin nodes that weren't handled by the main match statement
             
             if let Some(plugin_node) = &mut node.plugin_node {
                 
                 // Get UI description from plugin using normal Rust types
-                let ui_description = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                    let result = plugin_node.get_parameter_ui();
-                        result
-                })) {
-                    Ok(ui_desc) => ui_desc,
-                    Err(e) => {
+                let ui_description = match sandbox::guard(node_id, &type_id, &title, "draw parameters", || {
+                    plugin_node.get_parameter_ui()
+                }) {
+                    Some(ui_desc) => ui_desc,
+                    None => {
                         ui.colored_label(egui::Color32::RED, format!("Plugin '{}' parameter UI crashed", title));
                         return true;
                     }
@@ -742,26 +892,7 @@ impl ParameterPanel {
                             crate::plugins::UIAction::ParameterChanged { parameter, value } => {
                                 
                                 // Convert plugin NodeData to core NodeData
-                                let core_value = match value {
-                                    crate::plugins::NodeData::Float(f) => crate::nodes::interface::NodeData::Float(f),
-                                    crate::plugins::NodeData::Vector3(v) => crate::nodes::interface::NodeData::Vector3(v),
-                                    crate::plugins::NodeData::Color(c) => crate::nodes::interface::NodeData::Color([c[0], c[1], c[2], 1.0]),
-                                    crate::plugins::NodeData::String(s) => crate::nodes::interface::NodeData::String(s),
-                                    crate::plugins::NodeData::Boolean(b) => crate::nodes::interface::NodeData::Boolean(b),
-                                    crate::plugins::NodeData::USDScene(s) => crate::nodes::interface::NodeData::String(s), // Convert USD to string
-                                    // Rich data types - convert to JSON strings for now
-                                    crate::plugins::NodeData::Scene(_) => crate::nodes::interface::NodeData::String("[Scene Data]".to_string()),
-                                    crate::plugins::NodeData::Geometry(_) => crate::nodes::interface::NodeData::String("[Geometry Data]".to_string()),
-                                    crate::plugins::NodeData::Material(_) => crate::nodes::interface::NodeData::String("[Material Data]".to_string()),
-                                    crate::plugins::NodeData::Stage(_) => crate::nodes::interface::NodeData::String("[Stage Data]".to_string()),
-                                    crate::plugins::NodeData::USDSceneData(_) => crate::nodes::interface::NodeData::String("[USD Scene Data]".to_string()),
-                                    crate::plugins::NodeData::USDScenegraphMetadata(_) => crate::nodes::interface::NodeData::String("[USD Metadata]".to_string()),
-                                    crate::plugins::NodeData::Light(_) => crate::nodes::interface::NodeData::String("[Light Data]".to_string()),
-                                    crate::plugins::NodeData::Image(_) => crate::nodes::interface::NodeData::String("[Image Data]".to_string()),
-                                    crate::plugins::NodeData::Integer(i) => crate::nodes::interface::NodeData::Integer(i),
-                                    crate::plugins::NodeData::Any(s) => crate::nodes::interface::NodeData::String(s),
-                                    crate::plugins::NodeData::None => crate::nodes::interface::NodeData::String("None".to_string()),
-                                };
+                                let core_value = crate::plugins::from_plugin_data(value);
                                 node.parameters.insert(parameter, core_value);
                             }
                             crate::plugins::UIAction::ButtonClicked { action } => {
@@ -786,6 +917,226 @@ impl ParameterPanel {
         false
     }
     
+    /// Preset dropdown and save field for the node's type
+    fn render_preset_controls(
+        &mut self,
+        ui: &mut egui::Ui,
+        node_id: NodeId,
+        graph: &mut crate::nodes::NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        let Some(type_id) = graph.nodes.get(&node_id).map(|node| node.type_id.clone()) else {
+            return;
+        };
+        let mut load = None;
+        let mut delete = None;
+        let mut save = None;
+        
+        ui.horizontal(|ui| {
+            ui.label("Preset:");
+            egui::ComboBox::from_id_salt(("parameter_presets", node_id))
+                .selected_text("Load…")
+                .show_ui(ui, |ui| {
+                    // Only listed while the dropdown is open
+                    let names = self.presets.list(&type_id);
+                    if names.is_empty() {
+                        ui.weak("No saved presets");
+                    }
+                    for name in names {
+                        ui.horizontal(|ui| {
+                            if ui.selectable_label(false, &name).clicked() {
+                                load = Some(name.clone());
+                            }
+                            if ui.small_button("🗑").on_hover_text("Delete preset").clicked() {
+                                delete = Some(name.clone());
+                            }
+                        });
+                    }
+                });
+            
+            let preset_name = self.preset_names.entry(node_id).or_default();
+            ui.add(egui::TextEdit::singleline(preset_name).hint_text("Preset name").desired_width(110.0));
+            let name = preset_name.trim().to_string();
+            if ui.add_enabled(!name.is_empty(), egui::Button::new("Save Preset")).clicked() {
+                save = Some(name);
+            }
+        });
+        
+        if let Some(name) = save {
+            if let Some(node) = graph.nodes.get(&node_id) {
+                match self.presets.save(node, &name) {
+                    Ok(path) => info!("Saved preset \"{}\" to {}", name, path.display()),
+                    Err(e) => error!("Failed to save preset \"{}\": {}", name, e),
+                }
+            }
+            self.preset_names.remove(&node_id);
+        }
+        
+        if let Some(name) = delete {
+            if let Err(e) = self.presets.delete(&type_id, &name) {
+                error!("Failed to delete preset \"{}\": {}", name, e);
+            }
+        }
+        
+        if let Some(name) = load {
+            let schema = self.parameter_schema(&type_id);
+            let Some(node) = graph.nodes.get_mut(&node_id) else {
+                return;
+            };
+            match self.presets.load(node, &name, &schema) {
+                Ok(values) => {
+                    for (parameter, value) in values {
+                        if let Some(old_value) = node.parameters.insert(parameter.clone(), value.clone()) {
+                            self.applied_changes.push(AppliedParameterChange {
+                                node_id,
+                                node_title: node.title.clone(),
+                                parameter,
+                                old_value,
+                                new_value: value,
+                            });
+                        }
+                    }
+                    info!("Applied preset \"{}\" to {}", name, node.title);
+                    execution_engine.on_node_parameter_changed(node_id, graph);
+                }
+                Err(errors) => {
+                    error!("Preset \"{}\" was not applied:", name);
+                    for error in errors {
+                        error!("  {}", error);
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Expressions driving the node's numeric parameters
+    fn render_expression_controls(
+        ui: &mut egui::Ui,
+        node_id: NodeId,
+        graph: &mut crate::nodes::NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        let Some(node) = graph.nodes.get(&node_id) else {
+            return;
+        };
+        let mut names: Vec<String> = node.parameters.iter()
+            .filter(|(_, value)| expressions::is_numeric(value))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        names.sort();
+        let values = expressions::evaluate_parameters(node, graph);
+        let mut edited = None;
+        
+        egui::CollapsingHeader::new(format!("Expressions ({})", node.expressions.len()))
+            .id_salt(("parameter_expressions", node_id))
+            .show(ui, |ui| {
+                ui.weak("Drive a parameter from others, e.g. ch(\"Sphere1/radius\") * 2");
+                egui::Grid::new(("parameter_expression_grid", node_id)).num_columns(3).show(ui, |ui| {
+                    for name in &names {
+                        ui.label(name);
+                        let mut text = node.expressions.get(name).cloned().unwrap_or_default();
+                        if ui.add(egui::TextEdit::singleline(&mut text).hint_text("stored value").desired_width(160.0)).changed() {
+                            edited = Some((name.clone(), text));
+                        }
+                        match values.get(name) {
+                            Some(Ok(NodeData::Float(value))) => ui.label(format!("= {:.3}", value)),
+                            Some(Ok(NodeData::Integer(value))) => ui.label(format!("= {}", value)),
+                            Some(Ok(NodeData::Boolean(value))) => ui.label(format!("= {}", value)),
+                            Some(Err(e)) => ui.colored_label(Color32::from_rgb(230, 120, 80), "⚠").on_hover_text(e),
+                            _ => ui.label(""),
+                        };
+                        ui.end_row();
+                    }
+                });
+            });
+        
+        if let Some((name, text)) = edited {
+            if let Some(node) = graph.nodes.get_mut(&node_id) {
+                if text.trim().is_empty() {
+                    node.expressions.remove(&name);
+                } else {
+                    node.expressions.insert(name, text);
+                }
+            }
+            execution_engine.on_node_parameter_changed(node_id, graph);
+        }
+    }
+    
+    /// Editors for the node's tag color, tags and notes
+    fn render_note_controls(ui: &mut egui::Ui, node_id: NodeId, graph: &mut crate::nodes::NodeGraph) {
+        let Some(node) = graph.nodes.get_mut(&node_id) else {
+            return;
+        };
+        egui::CollapsingHeader::new("Tags & Notes")
+            .id_salt(("parameter_notes", node_id))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Color:");
+                    for color in TAG_COLORS {
+                        let (rect, response) = ui.allocate_exact_size(egui::vec2(16.0, 16.0), egui::Sense::click());
+                        ui.painter().rect_filled(rect, 3.0, color);
+                        if node.tag_color == Some(color) {
+                            ui.painter().rect_stroke(rect, 3.0, egui::Stroke::new(2.0, Color32::WHITE), egui::StrokeKind::Outside);
+                        }
+                        if response.clicked() {
+                            node.tag_color = Some(color);
+                        }
+                    }
+                    let mut custom = node.tag_color.unwrap_or(Color32::from_gray(90));
+                    if ui.color_edit_button_srgba(&mut custom).changed() {
+                        node.tag_color = Some(custom);
+                    }
+                    if ui.add_enabled(node.tag_color.is_some(), egui::Button::new("✖").small())
+                        .on_hover_text("Back to the category color")
+                        .clicked()
+                    {
+                        node.tag_color = None;
+                    }
+                });
+
+                // Applied when the field loses focus, so separators can be typed
+                ui.horizontal(|ui| {
+                    ui.label("Tags:");
+                    let edit_id = ui.make_persistent_id(("node_tags_edit", node_id));
+                    let mut text = ui.data(|data| data.get_temp::<String>(edit_id))
+                        .unwrap_or_else(|| node.tags.join(", "));
+                    let response = ui.add(egui::TextEdit::singleline(&mut text).hint_text("review, lighting"));
+                    if response.has_focus() {
+                        ui.data_mut(|data| data.insert_temp(edit_id, text.clone()));
+                    }
+                    if response.lost_focus() {
+                        ui.data_mut(|data| data.remove::<String>(edit_id));
+                        node.tags = parse_tags(&text);
+                    }
+                });
+
+                ui.label("Notes:");
+                ui.add(egui::TextEdit::multiline(&mut node.notes)
+                    .desired_rows(3)
+                    .desired_width(f32::INFINITY)
+                    .hint_text("Shown when hovering the node"));
+            });
+    }
+    
+    /// Parameters declared in the metadata of `node_type`
+    fn parameter_schema(&self, node_type: &str) -> Vec<crate::nodes::ParameterDefinition> {
+        crate::nodes::factory::NodeCatalog::current()
+            .metadata(node_type)
+            .map(|metadata| metadata.parameters.clone())
+            .unwrap_or_default()
+    }
+    
+    /// Inputs declared in the metadata of `node_type`, core or plugin, for their defaults
+    fn input_schema(&self, node_type: &str) -> Vec<crate::nodes::PortDefinition> {
+        crate::nodes::factory::NodeCatalog::current()
+            .metadata(node_type)
+            .map(|metadata| metadata.inputs.clone())
+            .unwrap_or_default()
+    }
+    
     /// Build a generic parameter interface for nodes without specialized interfaces
     fn build_generic_parameter_interface(
         &mut self, 
@@ -794,7 +1145,17 @@ impl ParameterPanel {
     ) -> Vec<crate::nodes::interface::ParameterChange> {
         let mut changes = Vec::new();
         
-        if node.parameters.is_empty() {
+        // Clone the parameters to avoid borrowing issues during iteration;
+        // input defaults have their own section
+        let port_defaults: HashSet<String> = node.inputs.iter()
+            .map(|input| crate::nodes::parameter_schema::port_default_key(&input.name))
+            .collect();
+        let parameters: Vec<(String, crate::nodes::interface::NodeData)> = node.parameters.iter()
+            .filter(|(k, _)| !port_defaults.contains(*k))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        
+        if parameters.is_empty() {
             ui.label("No parameters available");
             return changes;
         }
@@ -802,11 +1163,6 @@ impl ParameterPanel {
         ui.label("Parameters:");
         ui.separator();
         
-        // Clone the parameters to avoid borrowing issues during iteration
-        let parameters: Vec<(String, crate::nodes::interface::NodeData)> = node.parameters.iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-        
         for (param_name, param_value) in parameters {
             ui.horizontal(|ui| {
                 ui.label(format!("{}:", param_name));
@@ -870,27 +1226,14 @@ impl ParameterPanel {
         // Handle plugin nodes using FFI-SAFE methods
         // Check core node storage first (non-viewport nodes)
         if let Some(plugin_node) = &mut node.plugin_node {
-            
-            // Try calling a simpler method first
-            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                let id = plugin_node.id();
-            })) {
-                Ok(_) => {},
-                Err(e) => {
-                    ui.colored_label(egui::Color32::RED, format!("Plugin '{}' vtable corrupted", title));
-                    return true;
-                }
-            }
+            let type_id = node.type_id.clone();
             
             // Get UI description from plugin using normal Rust types
-            let ui_description = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                let result = plugin_node.get_parameter_ui();
-                result
-            })) {
-                Ok(ui_desc) => {
-                    ui_desc
-                },
-                Err(e) => {
+            let ui_description = match sandbox::guard(node_id, &type_id, &title, "draw parameters", || {
+                plugin_node.get_parameter_ui()
+            }) {
+                Some(ui_desc) => ui_desc,
+                None => {
                     ui.colored_label(egui::Color32::RED, format!("Plugin '{}' crashed getting UI description", title));
                     return true;
                 }
@@ -902,60 +1245,51 @@ impl ParameterPanel {
             
             // Send actions back to plugin using normal Rust types and get parameter changes
             for action in ui_actions {
-                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                    plugin_node.handle_ui_action(action)
-                })) {
-                    Ok(changes) => {
-                        for change in changes {
-                            // Apply parameter changes
-                            plugin_node.set_parameter(&change.parameter, change.value);
-                        }
-                    }
-                    Err(e) => {
+                sandbox::guard(node_id, &type_id, &title, "apply parameter change", || {
+                    for change in plugin_node.handle_ui_action(action) {
+                        plugin_node.set_parameter(&change.parameter, change.value);
                     }
-                }
+                });
             }
             
             return true;
         }
         
-        // Check global plugin manager for viewport nodes (stored separately)
-        if let Some(plugin_manager) = crate::workspace::get_global_plugin_manager() {
-            if let Ok(mut manager) = plugin_manager.lock() {
-                if let Some(plugin_node) = manager.get_plugin_node_for_rendering(node_id, &title) {
-                    
-                    // Get UI description from plugin using normal Rust types
-                    let ui_description = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                        plugin_node.get_parameter_ui()
-                    })) {
-                        Ok(ui_desc) => ui_desc,
-                        Err(e) => {
-                                    ui.colored_label(egui::Color32::RED, format!("Plugin '{}' crashed getting UI description", title));
-                            return true;
-                        }
-                    };
-                    
-                    // CORE renders the UI based on normal Rust description
-                    let ui_actions = self.render_ui_elements(ui, &ui_description.elements);
-                    
-                    // Send actions back to plugin using normal Rust types and get parameter changes
-                    for action in ui_actions {
-                        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            plugin_node.handle_ui_action(action)
-                        })) {
-                            Ok(changes) => {
-                                for change in changes {
-                                    // Apply parameter changes
-                                    plugin_node.set_parameter(&change.parameter, change.value);
-                                }
-                            }
-                            Err(e) => {
-                                    }
-                        }
+        // Check the plugin host for viewport nodes (stored separately)
+        if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+            let type_id = node.type_id.clone();
+            let drawn = plugin_host.with_instance(node_id, |plugin_node| {
+                if !plugin_node.supports_viewport() {
+                    return false;
+                }
+                
+                // Get UI description from plugin using normal Rust types
+                let ui_description = match sandbox::guard(node_id, &type_id, &title, "draw parameters", || {
+                    plugin_node.get_parameter_ui()
+                }) {
+                    Some(ui_desc) => ui_desc,
+                    None => {
+                        ui.colored_label(egui::Color32::RED, format!("Plugin '{}' crashed getting UI description", title));
+                        return true;
                     }
-                    
-                    return true;
+                };
+                
+                // CORE renders the UI based on normal Rust description
+                let ui_actions = self.render_ui_elements(ui, &ui_description.elements);
+                
+                // Send actions back to plugin using normal Rust types and get parameter changes
+                for action in ui_actions {
+                    sandbox::guard(node_id, &type_id, &title, "apply parameter change", || {
+                        for change in plugin_node.handle_ui_action(action) {
+                            plugin_node.set_parameter(&change.parameter, change.value);
+                        }
+                    });
                 }
+                
+                true
+            });
+            if drawn == Some(true) {
+                return true;
             }
         }
         
@@ -1001,97 +1335,78 @@ impl ParameterPanel {
         }
         
         // Data nodes using Pattern A
-        if title.contains("Constant") {
+        if node.type_id.contains("Constant") {
             let changes = crate::nodes::data::constant::parameters::ConstantNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Variable") {
+        if node.type_id.contains("Variable") {
             let changes = crate::nodes::data::variable::parameters::VariableNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
         // Output nodes using Pattern A
-        if title.contains("Debug") {
+        if node.type_id.contains("Debug") {
             let changes = crate::nodes::output::debug::parameters::DebugNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Print") {
+        if node.type_id.contains("Print") {
             let changes = crate::nodes::output::print::parameters::PrintNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
         // Logic nodes using Pattern A
-        if title.contains("And") && !title.contains("USD") {
+        if matches!(node.type_id.as_str(), "Logic_And" | "And") {
             let changes = crate::nodes::logic::and::parameters::AndNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Or") && !title.contains("USD") {
+        if matches!(node.type_id.as_str(), "Logic_Or" | "Or") {
             let changes = crate::nodes::logic::or::parameters::OrNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Not") && !title.contains("USD") {
+        if matches!(node.type_id.as_str(), "Logic_Not" | "Not") {
             let changes = crate::nodes::logic::not::parameters::NotNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
         // Transform nodes using Pattern A
-        if title.contains("Translate") && !title.contains("USD") {
+        if node.type_id.contains("Translate") && !node.type_id.contains("USD") {
             let changes = crate::nodes::three_d::transform::translate::parameters::TranslateNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Scale") && !title.contains("USD") {
+        if node.type_id.contains("Scale") && !node.type_id.contains("USD") {
             let changes = crate::nodes::three_d::transform::scale::parameters::ScaleNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        if title.contains("Rotate") && !title.contains("USD") {
+        if node.type_id.contains("Rotate") && !node.type_id.contains("USD") {
             let changes = crate::nodes::three_d::transform::rotate::parameters::RotateNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
-        // Lighting nodes using Pattern A
-        if title.contains("Spot Light") || (title.contains("Spot") && title.contains("Light")) {
-            let changes = crate::nodes::three_d::lighting::spot_light::parameters::SpotLightNode::build_interface(node, ui);
-            self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
-            return true;
-        }
-        
-        if title.contains("Directional Light") || (title.contains("Directional") && title.contains("Light")) {
-            let changes = crate::nodes::three_d::lighting::directional_light::parameters::DirectionalLightNode::build_interface(node, ui);
-            self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
-            return true;
-        }
-        
-        if title.contains("Point Light") || (title.contains("Point") && title.contains("Light")) {
-            let changes = crate::nodes::three_d::lighting::point_light::parameters::PointLightNode::build_interface(node, ui);
-            self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
-            return true;
-        }
-        
         // Geometry nodes using Pattern A
-        if title.contains("Plane") && !title.contains("USD") {
+        if node.type_id.contains("Plane") && !node.type_id.contains("USD") {
             let changes = crate::nodes::three_d::geometry::plane::parameters::PlaneParameters::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
         }
         
         // Utility nodes using Pattern A
-        if title.contains("Null") {
+        if node.type_id == "Null" {
             let changes = crate::nodes::utility::null::parameters::NullNode::build_interface(node, ui);
             self.apply_parameter_changes(node, changes, &title, node_id, execution_engine, graph);
             return true;
@@ -1129,6 +1444,15 @@ impl ParameterPanel {
             let changes_count = changes.len();
             info!("Applied {} parameter changes for {} node {}", changes_count, title, node_id);
             for change in changes {
+                if let Some(old_value) = node.parameters.get(&change.parameter) {
+                    self.applied_changes.push(AppliedParameterChange {
+                        node_id,
+                        node_title: node.title.clone(),
+                        parameter: change.parameter.clone(),
+                        old_value: old_value.clone(),
+                        new_value: change.value.clone(),
+                    });
+                }
                 node.parameters.insert(change.parameter, change.value);
             }
             
@@ -1143,7 +1467,8 @@ impl ParameterPanel {
                     // USD File Reader
                     "Data_UsdFileReader" => true,
                     // All 3D geometry nodes that output USD scene data
-                    "3D_Cube" | "3D_Sphere" | "3D_Cylinder" | "3D_Cone" | "3D_Plane" | "3D_Capsule" => true,
+                    "3D_Cube" | "3D_Sphere" | "3D_Cylinder" | "3D_Cone" | "3D_Plane" | "3D_Capsule"
+                    | "3D_Torus" | "3D_Disk" | "3D_Tube" => true,
                     // Any other node types that output USD data
                     _ => current_node.type_id.contains("USD") || current_node.type_id.contains("3D_"),
                 };
@@ -1,22 +1,51 @@
 //! Spreadsheet panel implementation
-//! 
-//! Handles spreadsheet-type interface panels for tabular data display
+//!
+//! Handles spreadsheet-type interface panels for tabular data display.
+//! Any `NodeData` flowing into or out of the node is converted to a table
+//! (tables, USD point arrays and prim attributes, buffers, scalars) and shown
+//! with column sorting, row/column filtering and copy-to-clipboard.
 
 use egui::Context;
+use egui_extras::{TableBuilder, Column};
 use crate::nodes::{Node, NodeId, InterfacePanelManager};
+use crate::nodes::interface::{NodeData, TableData, TableValue};
 use crate::editor::panels::PanelAction;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Row height used by spreadsheet tables
+const ROW_HEIGHT: f32 = 18.0;
+
+/// Per-node view state for the generic spreadsheet
+#[derive(Default)]
+struct SpreadsheetViewState {
+    /// Index into the available tables
+    selected_table: usize,
+    /// Column used for sorting, with ascending flag
+    sort: Option<(usize, bool)>,
+    /// Case-insensitive substring that at least one visible cell must contain
+    row_filter: String,
+    /// Names of columns hidden by the user
+    hidden_columns: HashSet<String>,
+    /// Converted tables, rebuilt when any source node re-cooks
+    tables: Vec<(String, TableData)>,
+    /// (label, node, port, cook count) of each source the tables were built from
+    signature: Vec<(String, NodeId, usize, u64)>,
+}
 
 /// Spreadsheet panel renderer
 pub struct SpreadsheetPanel {
     /// Default spreadsheet panel size
     default_size: [f32; 2],
+    /// View state for generic spreadsheet nodes
+    view_states: HashMap<NodeId, SpreadsheetViewState>,
 }
 
 impl SpreadsheetPanel {
     pub fn new() -> Self {
         Self {
             default_size: [400.0, 300.0], // Default size for spreadsheet panels
+            view_states: HashMap::new(),
         }
     }
 
@@ -39,13 +68,13 @@ impl SpreadsheetPanel {
 
         let panel_id = egui::Id::new(format!("spreadsheet_panel_{}", node_id));
         let mut panel_action = PanelAction::None;
-        
+
         // Get panel open state reference
         let mut is_open = panel_manager.is_panel_open(node_id);
-        
+
         // Create window title
         let title = format!("📊 {} - Spreadsheet", node.title);
-        
+
         // Create window with size constraints like other panels
         let mut window = egui::Window::new(title)
             .id(panel_id)
@@ -58,18 +87,25 @@ impl SpreadsheetPanel {
                 egui::Pos2::new(0.0, menu_bar_height),
                 egui::Vec2::new(ctx.screen_rect().width(), ctx.screen_rect().height() - menu_bar_height)
             ));
-        
+
         // Position spreadsheet panel to the right of the node (same as tree panel)
         let node_pos = node.position;
         window = window.default_pos(node_pos + egui::Vec2::new(200.0, 0.0));
-        
-        let _window_response = window.show(ctx, |ui| {
+
+        // Restore the saved placement once after loading a file
+        if let Some(rect) = panel_manager.take_pending_rect(node_id) {
+            window = window.current_pos(rect.min).default_size(rect.size());
+        }
+
+        let view_state = self.view_states.entry(node_id).or_default();
+
+        let window_response = window.show(ctx, |ui| {
             // Render spreadsheet content based on node type
             match node.type_id.as_str() {
                 "Attributes" => {
                     // Get the actual inputs from connections
                     let mut inputs = HashMap::new();
-                    
+
                     // Find connections to this node's inputs
                     for (port_idx, input_port) in node.inputs.iter().enumerate() {
                         for connection in &graph.connections {
@@ -81,7 +117,7 @@ impl SpreadsheetPanel {
                             }
                         }
                     }
-                    
+
                     egui::ScrollArea::vertical()
                         .show(ui, |ui| {
                             crate::nodes::three_d::ui::attributes::parameters::render_attributes_parameters(
@@ -93,19 +129,367 @@ impl SpreadsheetPanel {
                         });
                 }
                 _ => {
-                    ui.label("Spreadsheet view not implemented for this node type");
+                    refresh_tables(view_state, node_id, node, graph, execution_engine);
+                    render_table_view(ui, node_id, view_state);
                 }
             }
         });
-        
+
+        // Remember the placement so it can be saved with the project
+        if let Some(response) = &window_response {
+            panel_manager.set_panel_rect(node_id, response.response.rect);
+        }
+
         // Update panel open state
         panel_manager.set_panel_open(node_id, is_open);
-        
+
         // Check if window was closed via X button
         if !is_open {
             panel_action = PanelAction::Close;
         }
-        
+
         panel_action
     }
-}
\ No newline at end of file
+
+    /// Drop view state for a deleted node
+    pub fn cleanup_deleted_node(&mut self, node_id: NodeId) {
+        self.view_states.remove(&node_id);
+    }
+}
+
+/// Rebuild the node's tables if any of its data sources cooked since the last build
+fn refresh_tables(
+    view_state: &mut SpreadsheetViewState,
+    node_id: NodeId,
+    node: &Node,
+    graph: &crate::nodes::NodeGraph,
+    execution_engine: &mut crate::nodes::NodeGraphEngine,
+) {
+    // Sources: upstream outputs feeding our inputs, then our own outputs
+    // (the Spreadsheet viewer's output is a pass-through, so it is skipped)
+    let mut sources: Vec<(String, NodeId, usize)> = Vec::new();
+    for (port_idx, input_port) in node.inputs.iter().enumerate() {
+        for connection in &graph.connections {
+            if connection.to_node == node_id && connection.to_port == port_idx {
+                // Name the upstream node, so renamed nodes are recognizable
+                let label = match graph.nodes.get(&connection.from_node) {
+                    Some(source) => format!("{} ({})", input_port.name, source.title),
+                    None => input_port.name.clone(),
+                };
+                sources.push((label, connection.from_node, connection.from_port));
+            }
+        }
+    }
+    if node.type_id != "Spreadsheet" {
+        for (port_idx, output_port) in node.outputs.iter().enumerate() {
+            sources.push((output_port.name.clone(), node_id, port_idx));
+        }
+    }
+
+    let signature: Vec<(String, NodeId, usize, u64)> = sources.iter()
+        .map(|(label, source_node, port)| (label.clone(), *source_node, *port, execution_engine.get_cook_count(*source_node)))
+        .collect();
+    if signature == view_state.signature && !view_state.tables.is_empty() {
+        return;
+    }
+
+    let mut tables = Vec::new();
+    let mut seen = HashSet::new();
+    for (label, source_node, port) in &sources {
+        // Several inputs may share one upstream output; show it once
+        if !seen.insert((*source_node, *port)) {
+            continue;
+        }
+        if let Some(data) = execution_engine.get_cached_output(*source_node, *port) {
+            tables.extend(tables_from_node_data(label, data));
+        }
+    }
+
+    view_state.tables = tables;
+    view_state.signature = signature;
+    if view_state.selected_table >= view_state.tables.len() {
+        view_state.selected_table = 0;
+    }
+}
+
+/// Render the table selector, toolbar and table body
+fn render_table_view(ui: &mut egui::Ui, node_id: NodeId, view_state: &mut SpreadsheetViewState) {
+    if view_state.tables.is_empty() {
+        ui.centered_and_justified(|ui| {
+            ui.label("No tabular data - connect a table, buffer, USD scene or value");
+        });
+        return;
+    }
+
+    // Toolbar: table selection, filter, column visibility, copy
+    ui.horizontal(|ui| {
+        let selected_name = view_state.tables[view_state.selected_table].0.clone();
+        egui::ComboBox::from_id_salt(("spreadsheet_table", node_id))
+            .selected_text(selected_name)
+            .show_ui(ui, |ui| {
+                for (index, (name, table)) in view_state.tables.iter().enumerate() {
+                    let label = format!("{} ({} rows)", name, table.row_count());
+                    if ui.selectable_label(view_state.selected_table == index, label).clicked() {
+                        view_state.selected_table = index;
+                        view_state.sort = None;
+                    }
+                }
+            });
+
+        ui.label("Filter:");
+        ui.add(egui::TextEdit::singleline(&mut view_state.row_filter).desired_width(120.0));
+
+        let table = &view_state.tables[view_state.selected_table].1;
+        ui.menu_button("Columns", |ui| {
+            for column in &table.columns {
+                let mut visible = !view_state.hidden_columns.contains(column);
+                if ui.checkbox(&mut visible, column).changed() {
+                    if visible {
+                        view_state.hidden_columns.remove(column);
+                    } else {
+                        view_state.hidden_columns.insert(column.clone());
+                    }
+                }
+            }
+        });
+    });
+
+    let table = &view_state.tables[view_state.selected_table].1;
+    let visible_columns: Vec<usize> = (0..table.column_count())
+        .filter(|&i| !view_state.hidden_columns.contains(&table.columns[i]))
+        .collect();
+    let rows = visible_rows(table, &visible_columns, &view_state.row_filter, view_state.sort);
+
+    ui.horizontal(|ui| {
+        ui.label(format!("{} of {} rows, {} columns", rows.len(), table.row_count(), visible_columns.len()));
+        if ui.button("📋 Copy").on_hover_text("Copy visible rows as tab-separated text").clicked() {
+            ui.ctx().copy_text(to_tsv(table, &visible_columns, &rows));
+        }
+    });
+    ui.separator();
+
+    let mut clicked_column = None;
+    let mut builder = TableBuilder::new(ui)
+        .id_salt(("spreadsheet_body", node_id))
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .auto_shrink([false, false]);
+    for _ in &visible_columns {
+        builder = builder.column(Column::auto().at_least(60.0).resizable(true));
+    }
+
+    builder
+        .header(ROW_HEIGHT + 4.0, |mut header| {
+            for &column in &visible_columns {
+                header.col(|ui| {
+                    let arrow = match view_state.sort {
+                        Some((c, true)) if c == column => " ▲",
+                        Some((c, false)) if c == column => " ▼",
+                        _ => "",
+                    };
+                    if ui.button(format!("{}{}", table.columns[column], arrow)).clicked() {
+                        clicked_column = Some(column);
+                    }
+                });
+            }
+        })
+        .body(|body| {
+            body.rows(ROW_HEIGHT, rows.len(), |mut row| {
+                let row_values = &table.rows[rows[row.index()]];
+                for &column in &visible_columns {
+                    row.col(|ui| {
+                        if let Some(value) = row_values.get(column) {
+                            ui.label(value.to_string());
+                        }
+                    });
+                }
+            });
+        });
+
+    // Cycle ascending -> descending -> unsorted
+    if let Some(column) = clicked_column {
+        view_state.sort = match view_state.sort {
+            Some((c, true)) if c == column => Some((column, false)),
+            Some((c, false)) if c == column => None,
+            _ => Some((column, true)),
+        };
+    }
+}
+
+/// Indices of rows passing the filter, in display order
+fn visible_rows(table: &TableData, columns: &[usize], filter: &str, sort: Option<(usize, bool)>) -> Vec<usize> {
+    let needle = filter.trim().to_lowercase();
+    let mut rows: Vec<usize> = (0..table.row_count())
+        .filter(|&r| needle.is_empty() || columns.iter().any(|&c| {
+            table.rows[r].get(c).map(|v| v.to_string().to_lowercase().contains(&needle)).unwrap_or(false)
+        }))
+        .collect();
+
+    if let Some((column, ascending)) = sort {
+        rows.sort_by(|&a, &b| {
+            let ordering = compare_cells(table.rows[a].get(column), table.rows[b].get(column));
+            if ascending { ordering } else { ordering.reverse() }
+        });
+    }
+    rows
+}
+
+/// Numeric-aware cell ordering; empty cells sort last
+fn compare_cells(a: Option<&TableValue>, b: Option<&TableValue>) -> Ordering {
+    let a = a.filter(|v| **v != TableValue::Null);
+    let b = b.filter(|v| **v != TableValue::Null);
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => match (a.as_f64(), b.as_f64()) {
+            (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+            _ => a.to_string().cmp(&b.to_string()),
+        },
+    }
+}
+
+/// Serialize rows as tab-separated text with a header line
+fn to_tsv(table: &TableData, columns: &[usize], rows: &[usize]) -> String {
+    let mut text = columns.iter().map(|&c| table.columns[c].as_str()).collect::<Vec<_>>().join("\t");
+    for &r in rows {
+        text.push('\n');
+        let cells: Vec<String> = columns.iter()
+            .map(|&c| table.rows[r].get(c).map(|v| v.to_string()).unwrap_or_default())
+            .collect();
+        text.push_str(&cells.join("\t"));
+    }
+    text
+}
+
+/// A list item or map value as one cell; values without a cell form show their preview
+fn cell(value: &NodeData) -> TableValue {
+    match value {
+        NodeData::Float(v) => TableValue::Number(*v as f64),
+        NodeData::Integer(v) => TableValue::Number(*v as f64),
+        NodeData::Boolean(v) => TableValue::Boolean(*v),
+        NodeData::String(text) => TableValue::Text(text.clone()),
+        NodeData::None => TableValue::Null,
+        other => TableValue::Text(crate::nodes::value_preview::preview(other, 1).lines.concat()),
+    }
+}
+
+/// Convert node data into one or more named tables
+fn tables_from_node_data(label: &str, data: &NodeData) -> Vec<(String, TableData)> {
+    let number = |v: f32| TableValue::Number(v as f64);
+    match data {
+        NodeData::Table(table) => vec![(label.to_string(), table.clone())],
+        NodeData::Buffer(values) => vec![(label.to_string(), TableData {
+            columns: vec!["index".to_string(), "value".to_string()],
+            rows: values.iter().enumerate()
+                .map(|(i, v)| vec![TableValue::Number(i as f64), number(*v)])
+                .collect(),
+        })],
+        NodeData::List(items) => vec![(label.to_string(), TableData {
+            columns: vec!["index".to_string(), "value".to_string()],
+            rows: items.iter().enumerate()
+                .map(|(i, item)| vec![TableValue::Number(i as f64), cell(item)])
+                .collect(),
+        })],
+        NodeData::Map(entries) => vec![(label.to_string(), TableData {
+            columns: vec!["key".to_string(), "value".to_string()],
+            rows: entries.iter()
+                .map(|(key, value)| vec![TableValue::Text(key.clone()), cell(value)])
+                .collect(),
+        })],
+        NodeData::USDSceneData(scene) => {
+            let mut tables = Vec::new();
+            for mesh in &scene.meshes {
+                let has_normals = mesh.normals.len() == mesh.vertices.len();
+                let mut columns: Vec<String> = ["index", "P.x", "P.y", "P.z"].iter().map(|s| s.to_string()).collect();
+                if has_normals {
+                    columns.extend(["N.x", "N.y", "N.z"].iter().map(|s| s.to_string()));
+                }
+                let rows = mesh.vertices.iter().enumerate().map(|(i, p)| {
+                    let mut row = vec![TableValue::Number(i as f64), number(p.x), number(p.y), number(p.z)];
+                    if has_normals {
+                        let n = mesh.normals[i];
+                        row.extend([number(n.x), number(n.y), number(n.z)]);
+                    }
+                    row
+                }).collect();
+                tables.push((format!("{}: {} points", label, mesh.prim_path), TableData { columns, rows }));
+            }
+
+            let attribute_rows: Vec<Vec<TableValue>> = scene.meshes.iter()
+                .flat_map(|mesh| mesh.attributes.iter().map(move |attr| vec![
+                    TableValue::Text(mesh.prim_path.clone()),
+                    TableValue::Text(attr.name.clone()),
+                    TableValue::Text(attr.value_type.clone()),
+                    TableValue::Boolean(attr.is_custom),
+                    TableValue::Text(format!("{:?}", attr.value)),
+                ]))
+                .collect();
+            if !attribute_rows.is_empty() {
+                tables.push((format!("{}: prim attributes", label), TableData {
+                    columns: ["prim", "attribute", "type", "custom", "value"].iter().map(|s| s.to_string()).collect(),
+                    rows: attribute_rows,
+                }));
+            }
+            tables
+        }
+        NodeData::PointCloud(points) => {
+            let mut columns: Vec<String> = ["index", "P.x", "P.y", "P.z"].iter().map(|s| s.to_string()).collect();
+            if points.has_colors() {
+                columns.extend(["Cd.r", "Cd.g", "Cd.b"].iter().map(|s| s.to_string()));
+            }
+            let rows = points.positions.iter().enumerate().map(|(i, p)| {
+                let mut row = vec![TableValue::Number(i as f64), number(p.x), number(p.y), number(p.z)];
+                if points.has_colors() {
+                    let c = points.colors[i];
+                    row.extend([number(c.x), number(c.y), number(c.z)]);
+                }
+                row
+            }).collect();
+            vec![(format!("{}: {} points", label, points.point_count()), TableData { columns, rows })]
+        }
+        NodeData::Volume(volume) => vec![(format!("{}: grids", label), TableData {
+            columns: ["grid", "type", "class", "active voxels", "voxel size", "min", "max"].iter().map(|s| s.to_string()).collect(),
+            rows: volume.grids.iter().map(|grid| vec![
+                TableValue::Text(grid.name.clone()),
+                TableValue::Text(grid.value_type.clone()),
+                TableValue::Text(grid.grid_class.clone()),
+                TableValue::Number(grid.active_voxels as f64),
+                number(grid.voxel_size().x),
+                TableValue::Text(format!("{:?}", grid.index_min)),
+                TableValue::Text(format!("{:?}", grid.index_max)),
+            ]).collect(),
+        })],
+        NodeData::USDScenegraphMetadata(metadata) => vec![(format!("{}: meshes", label), TableData {
+            columns: ["prim", "vertices", "triangles", "normals"].iter().map(|s| s.to_string()).collect(),
+            rows: metadata.meshes.iter().map(|mesh| vec![
+                TableValue::Text(mesh.prim_path.clone()),
+                TableValue::Number(mesh.vertex_count as f64),
+                TableValue::Number(mesh.triangle_count as f64),
+                TableValue::Boolean(mesh.has_normals),
+            ]).collect(),
+        })],
+        NodeData::Vector3(v) => vec![(label.to_string(), TableData {
+            columns: vec!["x".to_string(), "y".to_string(), "z".to_string()],
+            rows: vec![v.iter().map(|c| number(*c)).collect()],
+        })],
+        NodeData::Color(c) => vec![(label.to_string(), TableData {
+            columns: vec!["r".to_string(), "g".to_string(), "b".to_string(), "a".to_string()],
+            rows: vec![c.iter().map(|c| number(*c)).collect()],
+        })],
+        NodeData::Float(f) => vec![(label.to_string(), single_value(number(*f)))],
+        NodeData::Integer(i) => vec![(label.to_string(), single_value(TableValue::Number(*i as f64)))],
+        NodeData::Boolean(b) => vec![(label.to_string(), single_value(TableValue::Boolean(*b)))],
+        NodeData::String(s) => vec![(label.to_string(), single_value(TableValue::Text(s.clone())))],
+        _ => Vec::new(),
+    }
+}
+
+/// A one-cell table for scalar values
+fn single_value(value: TableValue) -> TableData {
+    TableData {
+        columns: vec!["value".to_string()],
+        rows: vec![vec![value]],
+    }
+}
@@ -1,11 +1,14 @@
 //! Tree panel implementation
 //! 
-//! Handles tree-type interface panels for hierarchical visualization (e.g., USD scene graphs)
+//! Handles tree-type interface panels for hierarchical visualization (e.g., USD scene graphs).
+//! Scenegraph nodes use the optimized USD renderer; any other node with a Tree panel
+//! shows the `NodeData::Tree` values on its inputs and outputs.
 
 use egui::{Context, Pos2, ScrollArea};
 use crate::nodes::{Node, NodeId, InterfacePanelManager};
-use crate::nodes::interface::{PanelType, NodeData};
+use crate::nodes::interface::{PanelType, NodeData, TreeData, TreeItem};
 use crate::editor::panels::PanelAction;
+use crate::viewport::selection::{self, SelectMode};
 use crate::workspaces::three_d::usd::usd_engine::{USDMeshGeometry, USDMeshMetadata};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -22,6 +25,9 @@ const ADAPTIVE_CHECK_MIN: u64 = 5;        // Minimum interval for high-frequency
 const ADAPTIVE_CHECK_MAX: u64 = 120;      // Maximum interval for stable data
 const ADAPTIVE_THRESHOLD: u64 = 10;       // Threshold for adaptive adjustment
 
+/// Children shown under one expanded item before the rest are summarized
+const MAX_CHILDREN_SHOWN: usize = 500;
+
 /// Simple string interner for UI labels to reduce memory allocations
 #[derive(Default)]
 struct StringInterner {
@@ -160,6 +166,10 @@ pub struct TreePanel {
     metrics: ScenegraphMetrics,
     /// Tracks changes to enable incremental cache updates
     change_trackers: HashMap<NodeId, CacheChangeTracker>,
+    /// Generic trees per node with the (source node, port, cook count) signature they were built from
+    generic_trees: HashMap<NodeId, (Vec<(NodeId, usize, u64)>, Vec<(String, TreeData)>)>,
+    /// Search text per node
+    search_queries: HashMap<NodeId, String>,
 }
 
 impl TreePanel {
@@ -178,6 +188,8 @@ impl TreePanel {
             change_frequency: HashMap::new(),
             metrics: ScenegraphMetrics::default(),
             change_trackers: HashMap::new(),
+            generic_trees: HashMap::new(),
+            search_queries: HashMap::new(),
         }
     }
 
@@ -275,7 +287,7 @@ impl TreePanel {
         
         // Determine window title
         let title = if is_stacked {
-            format!("🌳 Scene Graph Tree ({})", node_ids.len())
+            format!("🌳 Trees ({})", node_ids.len())
         } else {
             let custom_name = panel_manager.get_node_name(primary_node_id);
             format!("🌳 {}", custom_name.unwrap_or(&primary_node.title))
@@ -304,6 +316,16 @@ impl TreePanel {
             window = window.default_pos(node_pos + egui::vec2(200.0, 0.0));
         }
         
+        // Restore the saved placement once after loading a file
+        let restored_rect = if is_stacked {
+            panel_manager.take_pending_stack_rect(PanelType::Tree)
+        } else {
+            panel_manager.take_pending_rect(primary_node_id)
+        };
+        if let Some(rect) = restored_rect {
+            window = window.current_pos(rect.min).default_size(rect.size());
+        }
+        
         let window_response = window.show(ctx, |ui| {
             if is_stacked && node_ids.len() > 1 {
                 // Render tabs for multiple tree panels
@@ -354,6 +376,15 @@ impl TreePanel {
             });
         });
         
+        // Remember the placement so it can be saved with the project
+        if let Some(response) = &window_response {
+            if is_stacked {
+                panel_manager.set_stack_rect(PanelType::Tree, response.response.rect);
+            } else {
+                panel_manager.set_panel_rect(primary_node_id, response.response.rect);
+            }
+        }
+        
         // Update open state in panel manager
         panel_manager.set_panel_open(primary_node_id, is_open);
         
@@ -372,9 +403,15 @@ impl TreePanel {
         ui: &mut egui::Ui,
         node_id: NodeId,
         node: &Node,
-        graph: &crate::nodes::NodeGraph,
+        graph: &mut crate::nodes::NodeGraph,
         execution_engine: &mut crate::nodes::NodeGraphEngine,
     ) {
+        // Only Scenegraph nodes feed the USD cache; everything else declares NodeData::Tree outputs
+        if node.type_id != "Scenegraph" {
+            self.render_generic_tree(ui, node_id, node, graph, execution_engine);
+            return;
+        }
+        
         // For responsive disconnection detection, always check cache when we have data
         // For performance, throttle checks when we have no data  
         let has_local_cache = self.cached_data.contains_key(&node_id);
@@ -430,6 +467,165 @@ impl TreePanel {
         }
     }
     
+    /// Render NodeData::Tree values from the node's inputs and outputs with search and selection
+    fn render_generic_tree(
+        &mut self,
+        ui: &mut egui::Ui,
+        node_id: NodeId,
+        node: &Node,
+        graph: &mut crate::nodes::NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        self.refresh_generic_trees(node_id, node, graph, execution_engine);
+        
+        let trees = match self.generic_trees.get(&node_id) {
+            Some((_, trees)) if !trees.is_empty() => trees.clone(),
+            _ => {
+                ui.centered_and_justified(|ui| {
+                    ui.label("No hierarchical data - connect a node with a Tree output");
+                });
+                return;
+            }
+        };
+        
+        let query = self.search_queries.entry(node_id).or_default();
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            ui.add(egui::TextEdit::singleline(query).hint_text("Search items"));
+            if !query.is_empty() && ui.small_button("✖").clicked() {
+                query.clear();
+            }
+        });
+        let query_lower = query.trim().to_lowercase();
+        ui.separator();
+        
+        let selected_path = graph.nodes.get(&node_id)
+            .and_then(|n| n.parameters.get(TreeData::SELECTED_PATH_PARAMETER))
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        let mut clicked_path: Option<String> = None;
+        
+        ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+            for (source_index, (source_name, tree)) in trees.iter().enumerate() {
+                if trees.len() > 1 {
+                    ui.strong(format!("{} ({} items)", source_name, tree.item_count()));
+                }
+                for root in &tree.roots {
+                    if query_lower.is_empty() {
+                        Self::render_tree_item_lazy(ui, (node_id, source_index), root, &selected_path, &mut clicked_path);
+                    } else if root.matches(&query_lower) {
+                        Self::render_tree_item_filtered(ui, root, &query_lower, &selected_path, &mut clicked_path);
+                    }
+                }
+            }
+        });
+        
+        // Selection callback: write the picked path into the node's parameters
+        if let Some(path) = clicked_path {
+            if let Some(graph_node) = graph.nodes.get_mut(&node_id) {
+                graph_node.parameters.insert(TreeData::SELECTED_PATH_PARAMETER.to_string(), NodeData::String(path));
+                execution_engine.on_node_parameter_changed(node_id, graph);
+            }
+        }
+    }
+    
+    /// Collect tree outputs from upstream connections and the node's own outputs
+    fn refresh_generic_trees(
+        &mut self,
+        node_id: NodeId,
+        node: &Node,
+        graph: &crate::nodes::NodeGraph,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        let mut sources: Vec<(String, NodeId, usize)> = Vec::new();
+        for (port_idx, input_port) in node.inputs.iter().enumerate() {
+            for connection in &graph.connections {
+                if connection.to_node == node_id && connection.to_port == port_idx {
+                    sources.push((input_port.name.clone(), connection.from_node, connection.from_port));
+                }
+            }
+        }
+        for (port_idx, output_port) in node.outputs.iter().enumerate() {
+            sources.push((output_port.name.clone(), node_id, port_idx));
+        }
+        
+        let signature: Vec<(NodeId, usize, u64)> = sources.iter()
+            .map(|(_, source_node, port)| (*source_node, *port, execution_engine.get_cook_count(*source_node)))
+            .collect();
+        if matches!(self.generic_trees.get(&node_id), Some((cached, _)) if *cached == signature) {
+            return;
+        }
+        
+        let mut trees: Vec<(String, TreeData)> = Vec::new();
+        for (label, source_node, port) in &sources {
+            if let Some(NodeData::Tree(tree)) = execution_engine.get_cached_output(*source_node, *port) {
+                // A pass-through output repeats its input; show each tree once
+                if !trees.iter().any(|(_, existing)| existing == tree) {
+                    trees.push((label.clone(), tree.clone()));
+                }
+            }
+        }
+        self.generic_trees.insert(node_id, (signature, trees));
+    }
+    
+    /// Render an item whose children are only built while it is expanded
+    fn render_tree_item_lazy(
+        ui: &mut egui::Ui,
+        id_salt: (NodeId, usize),
+        item: &TreeItem,
+        selected_path: &str,
+        clicked_path: &mut Option<String>,
+    ) {
+        if item.children.is_empty() {
+            Self::render_tree_item_label(ui, item, selected_path, clicked_path);
+            return;
+        }
+        
+        let id = ui.make_persistent_id(("tree_item", id_salt, &item.path));
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+            .show_header(ui, |ui| {
+                Self::render_tree_item_label(ui, item, selected_path, clicked_path);
+            })
+            .body(|ui| {
+                for child in item.children.iter().take(MAX_CHILDREN_SHOWN) {
+                    Self::render_tree_item_lazy(ui, id_salt, child, selected_path, clicked_path);
+                }
+                if item.children.len() > MAX_CHILDREN_SHOWN {
+                    ui.weak(format!("… {} more", item.children.len() - MAX_CHILDREN_SHOWN));
+                }
+            });
+    }
+    
+    /// Render only the branches that contain search matches, fully expanded
+    fn render_tree_item_filtered(
+        ui: &mut egui::Ui,
+        item: &TreeItem,
+        query_lower: &str,
+        selected_path: &str,
+        clicked_path: &mut Option<String>,
+    ) {
+        Self::render_tree_item_label(ui, item, selected_path, clicked_path);
+        ui.indent(("tree_search", &item.path), |ui| {
+            for child in item.children.iter().filter(|child| child.matches(query_lower)) {
+                Self::render_tree_item_filtered(ui, child, query_lower, selected_path, clicked_path);
+            }
+        });
+    }
+    
+    /// Selectable label for a single item
+    fn render_tree_item_label(ui: &mut egui::Ui, item: &TreeItem, selected_path: &str, clicked_path: &mut Option<String>) {
+        ui.horizontal(|ui| {
+            let response = ui.selectable_label(item.path == selected_path, item.label.as_str())
+                .on_hover_text(if item.path.is_empty() { "/" } else { item.path.as_str() });
+            if response.clicked() {
+                *clicked_path = Some(item.path.clone());
+            }
+            if let Some(detail) = &item.detail {
+                ui.weak(detail.as_str());
+            }
+        });
+    }
+    
     /// Super-optimized render method using lightweight metadata (no geometry data)
     fn render_usd_metadata_optimized(&mut self, ui: &mut egui::Ui, node_id: NodeId) {
         // Get the lightweight metadata and clone it to avoid borrowing issues
@@ -539,36 +735,47 @@ impl TreePanel {
         });
     }
     
-    /// Render a single mesh metadata item using cached name
+    /// Render a single mesh metadata item using cached name; clicking the name selects the prim in the viewports
     fn render_mesh_metadata_cached(ui: &mut egui::Ui, mesh: &USDMeshMetadata, cached_name: &Arc<str>) {
-        ui.collapsing(format!("🔹 {}", cached_name), |ui| {
-            ui.horizontal(|ui| {
-                ui.label("  📍");
-                ui.label(format!("Path: {}", mesh.prim_path));
-            });
-            ui.horizontal(|ui| {
-                ui.label("  🔸");
-                ui.label(format!("Vertices: {}", mesh.vertex_count));
-            });
-            ui.horizontal(|ui| {
-                ui.label("  🔺");
-                ui.label(format!("Triangles: {}", mesh.triangle_count));
-            });
-            ui.horizontal(|ui| {
-                ui.label("  📊");
-                ui.label(format!("Attributes: {}{}{}",
-                    if mesh.has_normals { "N " } else { "" },
-                    if mesh.has_uvs { "UV " } else { "" },
-                    if mesh.has_colors { "C " } else { "" }
-                ));
-            });
-            if let Some(material) = &mesh.material_binding {
+        let id = ui.make_persistent_id(("scenegraph_mesh", &mesh.prim_path));
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+            .show_header(ui, |ui| {
+                let selected = selection::current().contains(&mesh.prim_path);
+                let response = ui.selectable_label(selected, format!("🔹 {}", cached_name))
+                    .on_hover_text("Click to select, Shift+click to add or remove");
+                if response.clicked() {
+                    let mode = if ui.input(|i| i.modifiers.shift) { SelectMode::Toggle } else { SelectMode::Replace };
+                    selection::pick(Some(&mesh.prim_path), mode);
+                }
+            })
+            .body(|ui| {
                 ui.horizontal(|ui| {
-                    ui.label("  🎨");
-                    ui.label(format!("Material: {}", material));
+                    ui.label("  📍");
+                    ui.label(format!("Path: {}", mesh.prim_path));
                 });
-            }
-        });
+                ui.horizontal(|ui| {
+                    ui.label("  🔸");
+                    ui.label(format!("Vertices: {}", mesh.vertex_count));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("  🔺");
+                    ui.label(format!("Triangles: {}", mesh.triangle_count));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("  📊");
+                    ui.label(format!("Attributes: {}{}{}",
+                        if mesh.has_normals { "N " } else { "" },
+                        if mesh.has_uvs { "UV " } else { "" },
+                        if mesh.has_colors { "C " } else { "" }
+                    ));
+                });
+                if let Some(material) = &mesh.material_binding {
+                    ui.horizontal(|ui| {
+                        ui.label("  🎨");
+                        ui.label(format!("Material: {}", material));
+                    });
+                }
+            });
     }
 
     /// Render a single mesh metadata item
@@ -1406,6 +1613,8 @@ impl TreePanel {
         self.cached_render_data.remove(&node_id);
         self.adaptive_intervals.remove(&node_id);
         self.change_frequency.remove(&node_id);
+        self.generic_trees.remove(&node_id);
+        self.search_queries.remove(&node_id);
         debug!("🧹 Tree panel cleanup completed for deleted node: {}", node_id);
     }
     
@@ -5,7 +5,10 @@
 use egui::{Context, Color32, Pos2};
 use crate::nodes::{Node, NodeId, InterfacePanelManager};
 use crate::nodes::interface::PanelType;
+use crate::nodes::execution_engine::QualityMode;
 use crate::editor::panels::PanelAction;
+use crate::plugins::sandbox;
+use crate::nodes::three_d::ui::viewport::hydra_preview::{self, PreviewRequest, PreviewState};
 use std::collections::HashMap;
 use log::info;
 
@@ -22,6 +25,8 @@ pub struct ViewportPanel {
     viewport_instances: HashMap<NodeId, crate::nodes::three_d::ui::viewport::ViewportNode>,
     /// 3D rendering callbacks for each viewport (to avoid renderer conflicts)
     viewport_callbacks: HashMap<NodeId, crate::gpu::viewport_3d_callback::ViewportRenderCallback>,
+    /// Converted scenes of pinned node outputs per viewer, with the (node, port, cook count) they came from
+    pinned_scenes: HashMap<NodeId, ((NodeId, usize, u64, QualityMode), ViewportData)>,
 }
 
 impl ViewportPanel {
@@ -31,6 +36,7 @@ impl ViewportPanel {
             selected_tabs: HashMap::new(),
             viewport_instances: HashMap::new(),
             viewport_callbacks: HashMap::new(),
+            pinned_scenes: HashMap::new(),
         }
     }
 
@@ -46,6 +52,8 @@ impl ViewportPanel {
         graph: &mut crate::nodes::NodeGraph,
         execution_engine: &mut crate::nodes::NodeGraphEngine,
     ) -> PanelAction {
+        Self::sync_selection_output(node_id, graph, execution_engine);
+        
         // Check if this panel should be stacked
         if panel_manager.is_panel_stacked(node_id) {
             // For stacked panels, only render the shared window from the first stacked node
@@ -71,6 +79,20 @@ impl ViewportPanel {
         }
     }
 
+    /// Mirror the shared prim selection into a core viewport node, re-cooking its Selection output when it changed
+    fn sync_selection_output(node_id: NodeId, graph: &mut crate::nodes::NodeGraph, execution_engine: &mut crate::nodes::NodeGraphEngine) {
+        use crate::nodes::three_d::ui::viewport::{ViewportNode, SELECTION_PARAMETER};
+        let Some(node) = graph.nodes.get_mut(&node_id) else { return };
+        if node.type_id != "Viewport" && node.type_id != "3D_Viewport" {
+            return;
+        }
+        let selection = crate::viewport::selection::current().to_text();
+        if ViewportNode::selection_text(node) != selection {
+            node.parameters.insert(SELECTION_PARAMETER.to_string(), crate::nodes::interface::NodeData::String(selection));
+            execution_engine.on_node_parameter_changed(node_id, graph);
+        }
+    }
+    
     /// Unified viewport window renderer (handles both individual and stacked modes)
     fn render_viewport_window(
         &mut self,
@@ -130,6 +152,17 @@ impl ViewportPanel {
                 egui::Vec2::new(ctx.screen_rect().width(), ctx.screen_rect().height() - menu_bar_height)
             ));
         
+        // Restore the saved placement once after loading a file
+        let restored_rect = if is_stacked {
+            panel_manager.take_pending_stack_rect(PanelType::Viewport)
+        } else {
+            panel_manager.take_pending_rect(primary_node_id)
+        };
+        let window = match restored_rect {
+            Some(rect) => window.current_pos(rect.min).default_size(rect.size()),
+            None => window,
+        };
+        
         let window_response = window.show(ctx, |ui| {
             if is_stacked {
                 // Render stacked content with tabs
@@ -145,7 +178,12 @@ impl ViewportPanel {
             None => return PanelAction::None,
         };
         
-        // No position tracking needed - keep it simple
+        // Remember the placement so it can be saved with the project
+        if is_stacked {
+            panel_manager.set_stack_rect(PanelType::Viewport, window_response.response.rect);
+        } else {
+            panel_manager.set_panel_rect(primary_node_id, window_response.response.rect);
+        }
         
         // Update the panel manager with the new state
         if is_stacked {
@@ -191,63 +229,13 @@ impl ViewportPanel {
         
         // Viewport-specific content - check if this is a plugin viewport node
         if let Some(crate::nodes::interface::PanelType::Viewport) = node.get_panel_type() {
-            // Check if this might be a plugin node by looking for plugin-specific indicators
-            if let Some(plugin_manager) = crate::workspace::get_global_plugin_manager() {
-                if let Ok(mut manager) = plugin_manager.lock() {
-                    // Try to get plugin node instance for viewport data
-                    if let Some(plugin_node) = manager.get_plugin_node_for_rendering(node_id, &node.title) {
-                        // This is a plugin viewport node - use data-driven rendering
-                        // Get viewport data from plugin (safe, no egui rendering)
-                        if let Some(plugin_viewport_data) = plugin_node.get_viewport_data() {
-                            // Convert plugin viewport data to core viewport data using conversion layer
-                            let viewport_data: crate::viewport::ViewportData = plugin_viewport_data.into();
-                            // Render viewport using core's 3D rendering system
-                            self.render_plugin_viewport_data(ui, viewport_data, plugin_node.as_mut(), node_id);
-                        } else {
-                            ui.label("🎬 Plugin Viewport");
-                            ui.label("No viewport data available from plugin");
-                        }
-                    } else {
-                        // This is a core viewport node
-                        let viewport_node = self.viewport_instances.entry(node_id)
-                            .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                        
-                        // Try to get viewport data from the core node
-                        if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                            // Render the 3D viewport
-                            self.render_core_viewport_data(ui, viewport_data, node_id);
-                        } else {
-                            // No viewport data - show parameter interface
-                            let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                        }
-                    }
-                } else {
-                    // Plugin manager lock failed - fall back to core viewport
-                    let viewport_node = self.viewport_instances.entry(node_id)
-                        .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                    
-                    // Try to get viewport data from the core node
-                    if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                        // Render the 3D viewport
-                        self.render_core_viewport_data(ui, viewport_data, node_id);
-                    } else {
-                        // No viewport data - show parameter interface
-                        let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                    }
-                }
-            } else {
-                // No plugin manager - fall back to core viewport
-                let viewport_node = self.viewport_instances.entry(node_id)
-                    .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                
-                // Try to get viewport data from the core node
-                if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                    // Render the 3D viewport
-                    self.render_core_viewport_data(ui, viewport_data, node_id);
-                } else {
-                    // No viewport data - show parameter interface
-                    let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                }
+            // Plugin viewport nodes keep their instance in the plugin host
+            let plugin_drawn = crate::workspace::get_plugin_host()
+                .and_then(|plugin_host| plugin_host.with_instance(node_id, |plugin_node| self.render_plugin_viewport(ui, plugin_node, node)))
+                .unwrap_or(false);
+            if !plugin_drawn {
+                // This is a core viewport node
+                self.render_core_viewport(ui, node, panel_manager, execution_engine);
             }
         } else {
             ui.label("Error: Node does not have viewport panel type");
@@ -341,63 +329,13 @@ impl ViewportPanel {
                 
                 // Viewport content area - check if this is a plugin viewport node
                 if let Some(crate::nodes::interface::PanelType::Viewport) = node.get_panel_type() {
-                    // Check if this might be a plugin node by looking for plugin-specific indicators
-                    if let Some(plugin_manager) = crate::workspace::get_global_plugin_manager() {
-                        if let Ok(mut manager) = plugin_manager.lock() {
-                            // Try to get plugin node instance for viewport data
-                            if let Some(plugin_node) = manager.get_plugin_node_for_rendering(selected_node_id, &node.title) {
-                                // This is a plugin viewport node - use data-driven rendering
-                                // Get viewport data from plugin (safe, no egui rendering)
-                                if let Some(plugin_viewport_data) = plugin_node.get_viewport_data() {
-                                    // Convert plugin viewport data to core viewport data using conversion layer
-                                    let viewport_data: crate::viewport::ViewportData = plugin_viewport_data.into();
-                                    // Render viewport using core's 3D rendering system
-                                    self.render_plugin_viewport_data(ui, viewport_data, plugin_node.as_mut(), selected_node_id);
-                                } else {
-                                    ui.label("🎬 Plugin Viewport");
-                                    ui.label("No viewport data available from plugin");
-                                }
-                            } else {
-                                // This is a core viewport node
-                                let viewport_node = self.viewport_instances.entry(selected_node_id)
-                                    .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                                
-                                // Try to get viewport data from the core node
-                                if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                                    // Render the 3D viewport
-                                    self.render_core_viewport_data(ui, viewport_data, selected_node_id);
-                                } else {
-                                    // No viewport data - show parameter interface
-                                    let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                                }
-                            }
-                        } else {
-                            // Plugin manager lock failed - fall back to core viewport
-                            let viewport_node = self.viewport_instances.entry(selected_node_id)
-                                .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                            
-                            // Try to get viewport data from the core node
-                            if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                                // Render the 3D viewport
-                                self.render_core_viewport_data(ui, viewport_data, selected_node_id);
-                            } else {
-                                // No viewport data - show parameter interface
-                                let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                            }
-                        }
-                    } else {
-                        // No plugin manager - fall back to core viewport
-                        let viewport_node = self.viewport_instances.entry(selected_node_id)
-                            .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
-                        
-                        // Try to get viewport data from the core node
-                        if let Some(viewport_data) = crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(&node) {
-                            // Render the 3D viewport
-                            self.render_core_viewport_data(ui, viewport_data, selected_node_id);
-                        } else {
-                            // No viewport data - show parameter interface
-                            let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
-                        }
+                    // Plugin viewport nodes keep their instance in the plugin host
+                    let plugin_drawn = crate::workspace::get_plugin_host()
+                        .and_then(|plugin_host| plugin_host.with_instance(selected_node_id, |plugin_node| self.render_plugin_viewport(ui, plugin_node, node)))
+                        .unwrap_or(false);
+                    if !plugin_drawn {
+                        // This is a core viewport node
+                        self.render_core_viewport(ui, node, panel_manager, execution_engine);
                     }
                 } else {
                     ui.label("Error: Node does not have viewport panel type");
@@ -494,6 +432,40 @@ impl ViewportPanel {
             ui.separator();
         }
         
+        // Pin another node's output so this viewer keeps showing it, whatever is wired in
+        let pinned_source = panel_manager.get_viewer_source(node_id);
+        let mut new_source = pinned_source;
+        ui.horizontal(|ui| {
+            ui.label("Show:");
+            let selected_text = match pinned_source {
+                Some((source_id, port)) => viewed_nodes.get(&source_id)
+                    .map(|source| format!("📌 {}", output_label(source, port)))
+                    .unwrap_or_else(|| "📌 Missing node".to_string()),
+                None => "Connected input".to_string(),
+            };
+            egui::ComboBox::from_id_salt(("viewer_source", node_id))
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut new_source, None, "Connected input");
+                    let mut sources: Vec<&Node> = viewed_nodes.values()
+                        .filter(|source| source.id != node_id && !source.outputs.is_empty())
+                        .collect();
+                    sources.sort_by(|a, b| a.title.cmp(&b.title).then(a.id.cmp(&b.id)));
+                    for source in sources {
+                        for port in 0..source.outputs.len() {
+                            ui.selectable_value(&mut new_source, Some((source.id, port)), format!("📌 {}", output_label(source, port)));
+                        }
+                    }
+                });
+            if pinned_source.is_some() && ui.small_button("Unpin").clicked() {
+                new_source = None;
+            }
+        });
+        if new_source != pinned_source {
+            panel_manager.set_viewer_source(node_id, new_source);
+            self.pinned_scenes.remove(&node_id);
+        }
+        
         ui.horizontal(|ui| {
             ui.label("Viewport controls:");
             
@@ -534,8 +506,33 @@ impl ViewportPanel {
         (panel_action, close_requested)
     }
     
+    /// Render the viewport of a plugin node. Returns false if the plugin has no
+    /// viewport, so the node is drawn as a core viewport instead.
+    fn render_plugin_viewport(&mut self, ui: &mut egui::Ui, plugin_node: &mut dyn nodle_plugin_sdk::PluginNode, node: &Node) -> bool {
+        if !plugin_node.supports_viewport() {
+            return false;
+        }
+        // Data-driven rendering: the plugin only describes the scene (no egui rendering)
+        let plugin_viewport_data = sandbox::guard(node.id, &node.type_id, &node.title, "render viewport", || {
+            plugin_node.get_viewport_data()
+        }).flatten();
+        if let Some(plugin_viewport_data) = plugin_viewport_data {
+            // Convert plugin viewport data to core viewport data using conversion layer
+            let viewport_data: crate::viewport::ViewportData = plugin_viewport_data.into();
+            // Render viewport using core's 3D rendering system
+            self.render_plugin_viewport_data(ui, viewport_data, plugin_node, node);
+        } else if sandbox::is_crashed(node.id) {
+            ui.colored_label(egui::Color32::RED, format!("Plugin '{}' crashed rendering its viewport", node.title));
+        } else {
+            ui.label("🎬 Plugin Viewport");
+            ui.label("No viewport data available from plugin");
+        }
+        true
+    }
+    
     /// Render plugin viewport data using the core's 3D rendering system
-    fn render_plugin_viewport_data(&mut self, ui: &mut egui::Ui, viewport_data: ViewportData, plugin_node: &mut dyn nodle_plugin_sdk::PluginNode, node_id: NodeId) {
+    fn render_plugin_viewport_data(&mut self, ui: &mut egui::Ui, viewport_data: ViewportData, plugin_node: &mut dyn nodle_plugin_sdk::PluginNode, node: &Node) {
+        let node_id = node.id;
         // 3D Viewport area with actual wgpu rendering - no extra UI elements
         // Create viewport area - use all available space
         let available_size = ui.available_size();
@@ -556,7 +553,9 @@ impl ViewportPanel {
             .or_insert_with(|| crate::nodes::three_d::ui::viewport::ViewportNode::default());
         
         // Delegate plugin input handling to the viewport node
-        viewport_node.handle_plugin_viewport_input(ui, &response, callback, plugin_node);
+        sandbox::guard(node_id, &node.type_id, &node.title, "handle viewport input", || {
+            viewport_node.handle_plugin_viewport_input(ui, &response, callback, plugin_node);
+        });
         
         // Add the 3D rendering callback to egui (clone it since egui takes ownership)
         ui.painter().add(egui_wgpu::Callback::new_paint_callback(
@@ -565,6 +564,58 @@ impl ViewportPanel {
         ));
     }
     
+    /// Render a core viewport node, showing the node output pinned to it if there is one
+    fn render_core_viewport(
+        &mut self,
+        ui: &mut egui::Ui,
+        node: &Node,
+        panel_manager: &InterfacePanelManager,
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) {
+        let node_id = node.id;
+        let viewport_data = match panel_manager.get_viewer_source(node_id) {
+            Some(source) => match self.pinned_viewport_data(node, source, execution_engine) {
+                Some(viewport_data) => Some(viewport_data),
+                None => {
+                    ui.label("📌 The pinned node has no scene output yet");
+                    return;
+                }
+            },
+            None => crate::nodes::three_d::ui::viewport::ViewportNode::get_viewport_data(node),
+        };
+        
+        if let Some(viewport_data) = viewport_data {
+            // Render the 3D viewport
+            self.render_core_viewport_data(ui, viewport_data, node_id);
+        } else {
+            // No viewport data - show parameter interface
+            let _changes = crate::nodes::three_d::ui::viewport::ViewportNode::build_interface(&mut node.clone(), ui);
+        }
+    }
+    
+    /// Scene of the node output pinned to the viewer `node`, converted again only
+    /// when the source node re-cooks
+    fn pinned_viewport_data(
+        &mut self,
+        node: &Node,
+        (source_id, port): (NodeId, usize),
+        execution_engine: &mut crate::nodes::NodeGraphEngine,
+    ) -> Option<ViewportData> {
+        let signature = (source_id, port, execution_engine.get_cook_count(source_id), execution_engine.get_quality_mode());
+        let is_current = self.pinned_scenes.get(&node.id).is_some_and(|(cached, _)| *cached == signature);
+        if !is_current {
+            let output = execution_engine.get_viewer_output(source_id, port)?;
+            let viewport_data = crate::nodes::three_d::ui::viewport::ViewportNode::viewport_data_for_output(node, &output)?;
+            self.pinned_scenes.insert(node.id, (signature, viewport_data));
+        }
+        
+        // Display settings follow the viewer's parameters, not the cached conversion
+        let (_, viewport_data) = self.pinned_scenes.get(&node.id)?;
+        let mut viewport_data = viewport_data.clone();
+        crate::nodes::three_d::ui::viewport::ViewportNode::apply_viewport_settings(&mut viewport_data, node);
+        Some(viewport_data)
+    }
+    
     /// Render viewport data from a core node (similar to plugin viewport rendering)
     fn render_core_viewport_data(&mut self, ui: &mut egui::Ui, viewport_data: crate::viewport::ViewportData, node_id: NodeId) {
         // Rendering viewport data
@@ -574,7 +625,7 @@ impl ViewportPanel {
             available_size.x.max(100.0),
             available_size.y.max(100.0)
         );
-        let (rect, response) = ui.allocate_exact_size(viewport_size, egui::Sense::drag());
+        let (rect, response) = ui.allocate_exact_size(viewport_size, egui::Sense::click_and_drag());
             
         // Get or create 3D rendering callback for this specific viewport node
         let callback = self.viewport_callbacks.entry(node_id)
@@ -594,6 +645,59 @@ impl ViewportPanel {
             rect,
             callback.clone(),
         ));
+        
+        // A Hydra delegate draws over the built-in renderer, which keeps handling the camera
+        if let Some(delegate) = viewport_data.settings.hydra_delegate.clone() {
+            let pixels_per_point = ui.ctx().pixels_per_point();
+            let request = PreviewRequest {
+                delegate,
+                settings: viewport_data.settings.delegate_settings.clone(),
+                aov: viewport_data.settings.active_aov().map(str::to_string),
+                camera: callback.get_camera_data(),
+                width: (rect.width() * pixels_per_point) as u32,
+                height: (rect.height() * pixels_per_point) as u32,
+                region: viewport_data.settings.hydra_region,
+            };
+            let state = hydra_preview::update(node_id, request);
+            Self::paint_hydra_preview(ui, rect, &state);
+        }
+    }
+    
+    /// Draw the latest Hydra image over the viewport, with the render status in a corner
+    fn paint_hydra_preview(ui: &egui::Ui, rect: egui::Rect, state: &PreviewState) {
+        if let Some(replaced) = &state.replaced {
+            ui.ctx().forget_image(&format!("file://{}", replaced));
+        }
+        if let Some(image) = &state.image {
+            // A cropped render covers only its region of the viewport
+            let [left, top, right, bottom] = state.image_crop;
+            let image_rect = egui::Rect::from_min_max(
+                rect.lerp_inside(egui::vec2(left, top)),
+                rect.lerp_inside(egui::vec2(right, bottom)),
+            );
+            egui::Image::new(format!("file://{}", image)).paint_at(ui, image_rect);
+            if image_rect != rect {
+                ui.painter().rect_stroke(image_rect, 0.0, egui::Stroke::new(1.0, Color32::from_rgb(255, 170, 60)), egui::StrokeKind::Outside);
+            }
+        }
+        let status = match (&state.error, state.rendering) {
+            (Some(error), _) => Some((error.as_str(), Color32::from_rgb(255, 110, 90))),
+            (None, true) => Some(("Rendering…", Color32::from_gray(220))),
+            (None, false) => None,
+        };
+        if let Some((text, color)) = status {
+            ui.painter().text(
+                rect.left_bottom() + egui::vec2(8.0, -8.0),
+                egui::Align2::LEFT_BOTTOM,
+                text,
+                egui::FontId::proportional(12.0),
+                color,
+            );
+        }
+        if state.rendering {
+            // Poll the background render
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+        }
     }
 
     /// Auto-load USD stage into a viewport node
@@ -621,6 +725,9 @@ impl ViewportPanel {
             info!("🧹 Cleaned up viewport callback for deleted node: {}", node_id);
         }
         
+        self.pinned_scenes.remove(&node_id);
+        hydra_preview::forget(node_id);
+        
         // Clean up any tab tracking for this node
         let node_id_str = node_id.to_string();
         self.selected_tabs.retain(|window_id, _| {
@@ -630,4 +737,12 @@ impl ViewportPanel {
         info!("🧹 Viewport panel cleanup completed for deleted node: {}", node_id);
     }
 
-}
\ No newline at end of file
+}
+
+/// Menu label of a node output: the node title, plus the port name when it has several
+fn output_label(node: &Node, port: usize) -> String {
+    match node.outputs.get(port) {
+        Some(output) if node.outputs.len() > 1 => format!("{} › {}", node.title, output.name),
+        _ => node.title.clone(),
+    }
+}
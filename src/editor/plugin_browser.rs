@@ -0,0 +1,189 @@
+//! Plugin repository browser window
+//!
+//! Lists the plugins offered by the configured repository index and installs
+//! them into `~/.nodle/plugins`. Network work runs on background threads; the
+//! installed library is loaded into the global plugin manager on the UI thread.
+
+use eframe::egui;
+use egui::{Color32, RichText};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use crate::plugins::host::PluginCommand;
+use crate::plugins::repository::{self, PluginIndex, PluginIndexEntry, RepositoryConfig};
+
+/// Result of a background repository task
+enum TaskResult {
+    Index(Result<PluginIndex, String>),
+    Installed(String, Result<PathBuf, String>),
+}
+
+/// Browser for the remote plugin repository
+pub struct PluginBrowser {
+    config: RepositoryConfig,
+    index: Option<PluginIndex>,
+    status: Option<(String, bool)>,
+    pending: Option<Receiver<TaskResult>>,
+}
+
+impl PluginBrowser {
+    pub fn new() -> Self {
+        Self {
+            config: RepositoryConfig::load(),
+            index: None,
+            status: None,
+            pending: None,
+        }
+    }
+
+    /// Run `task` on a background thread; its result is picked up by `render`
+    fn spawn(&mut self, task: impl FnOnce() -> TaskResult + Send + 'static) {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(task());
+        });
+        self.pending = Some(receiver);
+    }
+
+    /// Render the browser window. Returns true when a plugin was loaded this frame,
+    /// so callers can refresh anything built from the plugin registry.
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) -> bool {
+        let loaded = self.poll_pending();
+        if self.pending.is_some() {
+            ctx.request_repaint();
+        }
+
+        let busy = self.pending.is_some();
+        let mut refresh = false;
+        let mut install: Option<PluginIndexEntry> = None;
+
+        egui::Window::new("Plugin Repository")
+            .open(open)
+            .default_size([460.0, 360.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Index URL:");
+                    ui.add(egui::TextEdit::singleline(&mut self.config.index_url)
+                        .hint_text("https://example.com/nodle/plugins.json")
+                        .desired_width(260.0));
+                    if ui.add_enabled(!busy, egui::Button::new("Refresh")).clicked() {
+                        refresh = true;
+                    }
+                });
+                if let Some((message, is_error)) = &self.status {
+                    let color = if *is_error { Color32::LIGHT_RED } else { Color32::LIGHT_GREEN };
+                    ui.label(RichText::new(message.as_str()).color(color));
+                }
+                if busy {
+                    ui.spinner();
+                }
+                ui.separator();
+
+                let Some(index) = &self.index else {
+                    ui.label(RichText::new("Enter an index URL and press Refresh").weak());
+                    return;
+                };
+                let loaded_names = loaded_plugin_names();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for entry in &index.plugins {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(format!("{} {}", entry.name, entry.version)).strong());
+                            if loaded_names.contains(&entry.name) {
+                                ui.label(RichText::new("loaded").color(Color32::GRAY));
+                            } else if !entry.is_compatible() {
+                                ui.label(RichText::new(format!("{} only", entry.platform.as_deref().unwrap_or("?"))).color(Color32::GRAY));
+                            } else if ui.add_enabled(!busy, egui::Button::new("Install")).clicked() {
+                                install = Some(entry.clone());
+                            }
+                        });
+                        if !entry.description.is_empty() {
+                            ui.label(RichText::new(entry.description.as_str()).weak());
+                        }
+                        ui.separator();
+                    }
+                });
+            });
+
+        if refresh {
+            if let Err(e) = self.config.save() {
+                log::warn!("Failed to save plugin repository settings: {}", e);
+            }
+            let url = self.config.index_url.trim().to_string();
+            self.status = None;
+            self.spawn(move || TaskResult::Index(repository::fetch_index(&url)));
+        }
+        if let Some(entry) = install {
+            match repository::default_install_dir() {
+                Some(install_dir) => {
+                    let name = entry.name.clone();
+                    self.status = Some((format!("Downloading {}...", name), false));
+                    self.spawn(move || TaskResult::Installed(name, repository::download_plugin(&entry, &install_dir)));
+                }
+                None => self.status = Some(("No home directory to install plugins into".to_string(), true)),
+            }
+        }
+
+        loaded
+    }
+
+    /// Collect a finished background task; returns true if a plugin was loaded
+    fn poll_pending(&mut self) -> bool {
+        let Some(receiver) = &self.pending else {
+            return false;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => return false,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.pending = None;
+                self.status = Some(("Background task failed".to_string(), true));
+                return false;
+            }
+        };
+        self.pending = None;
+
+        match result {
+            TaskResult::Index(Ok(index)) => {
+                self.status = Some((format!("{} plugins available", index.plugins.len()), false));
+                self.index = Some(index);
+                false
+            }
+            TaskResult::Index(Err(e)) | TaskResult::Installed(_, Err(e)) => {
+                self.status = Some((e, true));
+                false
+            }
+            TaskResult::Installed(name, Ok(path)) => {
+                // Load into the running editor so the plugin's nodes are available immediately
+                let load_result = crate::workspace::get_plugin_host()
+                    .ok_or_else(|| "Plugin manager is not initialized".to_string())
+                    .and_then(|plugin_host| {
+                        plugin_host.apply(&PluginCommand::Load(path.clone())).map_err(|e| e.to_string())
+                    });
+                match load_result {
+                    Ok(Some(info)) => {
+                        self.status = Some((format!("Installed and loaded {} {}", info.name, info.version), false));
+                        true
+                    }
+                    Ok(None) => false,
+                    Err(e) => {
+                        self.status = Some((format!("Installed {} to {} but loading failed: {}", name, path.display(), e), true));
+                        false
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Names of the plugins currently loaded into the global plugin manager
+fn loaded_plugin_names() -> Vec<String> {
+    crate::workspace::get_plugin_host()
+        .map(|plugin_host| plugin_host.snapshot().plugins.iter().map(|plugin| plugin.info.name.clone()).collect())
+        .unwrap_or_default()
+}
+
+impl Default for PluginBrowser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,124 @@
+//! Plugin crash reporting
+//!
+//! Shows the plugin panics caught by the sandbox and lets the user retry the
+//! crashed node or disable the plugin that provided it.
+
+use egui::Color32;
+use crate::nodes::NodeId;
+use crate::plugins::sandbox::{self, PluginCrash};
+
+/// What the user chose in the crash window
+pub enum CrashAction {
+    None,
+    /// Unload the named plugin and keep it from loading again
+    DisablePlugin(String),
+    /// Call the crashed node again
+    RetryNode(NodeId),
+}
+
+/// A reported crash and the plugin it came from
+struct CrashReport {
+    crash: PluginCrash,
+    plugin_name: Option<String>,
+}
+
+/// Window listing plugin crashes not yet handled
+pub struct PluginCrashDialog {
+    reports: Vec<CrashReport>,
+}
+
+impl PluginCrashDialog {
+    pub fn new() -> Self {
+        Self { reports: Vec::new() }
+    }
+
+    /// Take the crashes caught since the last frame. Returns the newly crashed nodes.
+    pub fn collect(&mut self) -> Vec<NodeId> {
+        let crashes = sandbox::take_crashes();
+        if crashes.is_empty() {
+            return Vec::new();
+        }
+        let snapshot = crate::workspace::get_plugin_host().map(|plugin_host| plugin_host.snapshot());
+        crashes.into_iter()
+            .map(|crash| {
+                let node_id = crash.node_id;
+                let plugin_name = snapshot.as_ref()
+                    .and_then(|snapshot| snapshot.plugin_for_node_type(&crash.node_type))
+                    .map(|plugin| plugin.info.name.clone());
+                self.reports.push(CrashReport { crash, plugin_name });
+                node_id
+            })
+            .collect()
+    }
+
+    /// Forget the reports of `node_id`
+    fn dismiss_node(&mut self, node_id: NodeId) {
+        self.reports.retain(|report| report.crash.node_id != node_id);
+    }
+
+    /// Draw the window while there are unhandled crashes
+    pub fn render(&mut self, ctx: &egui::Context) -> CrashAction {
+        if self.reports.is_empty() {
+            return CrashAction::None;
+        }
+
+        let mut action = CrashAction::None;
+        let mut dismissed = Vec::new();
+        let mut dismiss_all = false;
+        egui::Window::new("Plugin Crashed")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 60.0))
+            .show(ctx, |ui| {
+                ui.label("A plugin node panicked. It was stopped and will not be called again until retried.");
+                ui.separator();
+                for (index, report) in self.reports.iter().enumerate() {
+                    let crash = &report.crash;
+                    ui.push_id(index, |ui| {
+                        ui.label(egui::RichText::new(format!("{} ({})", crash.node_title, crash.node_type)).strong());
+                        ui.label(format!("While trying to {}:", crash.operation));
+                        ui.colored_label(Color32::from_rgb(255, 120, 120), &crash.message);
+                        ui.horizontal(|ui| {
+                            if let Some(plugin_name) = &report.plugin_name {
+                                if ui.button(format!("Disable {}", plugin_name))
+                                    .on_hover_text("Unload this plugin and skip it on future launches")
+                                    .clicked()
+                                {
+                                    action = CrashAction::DisablePlugin(plugin_name.clone());
+                                }
+                            }
+                            if ui.button("Retry Node").clicked() {
+                                action = CrashAction::RetryNode(crash.node_id);
+                            }
+                            if ui.button("Dismiss").clicked() {
+                                dismissed.push(index);
+                            }
+                        });
+                    });
+                    ui.separator();
+                }
+                if self.reports.len() > 1 && ui.button("Dismiss All").clicked() {
+                    dismiss_all = true;
+                }
+            });
+
+        if dismiss_all {
+            self.reports.clear();
+        }
+        for index in dismissed.into_iter().rev() {
+            self.reports.remove(index);
+        }
+        match &action {
+            CrashAction::DisablePlugin(name) => self.reports.retain(|report| report.plugin_name.as_ref() != Some(name)),
+            CrashAction::RetryNode(node_id) => self.dismiss_node(*node_id),
+            CrashAction::None => {}
+        }
+        action
+    }
+}
+
+impl Default for PluginCrashDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,260 @@
+//! Plugin hot reloading
+//!
+//! Polls the plugin directories for rebuilt libraries. When one changes, every
+//! node instance and factory from the old library is dropped, the library is
+//! swapped for the new build and the affected nodes are recreated in place.
+//! Node parameters are stored on the core node, so they are handed back to the
+//! new instances and the graph keeps its state.
+//!
+//! Disabling a crashed plugin goes through the same teardown, except that the
+//! nodes are left without an instance instead of being recreated.
+//!
+//! Both are requested as [`PluginCommand`]s on the plugin host's queue and
+//! carried out by [`apply_plugin_commands`] once per frame, when no plugin code
+//! is running.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use crate::nodes::{Node, NodeGraph, NodeId};
+use crate::plugins::host::{PluginCommand, PluginHost};
+use crate::workspace::WorkspaceManager;
+use crate::workspaces::WorkspaceRegistry;
+
+/// How often the plugin directories are checked for changed libraries
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches plugin libraries for changes
+pub struct PluginHotReload {
+    last_check: Instant,
+}
+
+impl PluginHotReload {
+    pub fn new() -> Self {
+        Self {
+            last_check: Instant::now(),
+        }
+    }
+
+    /// Queue a reload for every plugin library that changed since it was loaded,
+    /// checking at most once per interval
+    pub fn poll(&mut self) {
+        if self.last_check.elapsed() < CHECK_INTERVAL {
+            return;
+        }
+        self.last_check = Instant::now();
+        let Some(plugin_host) = crate::workspace::get_plugin_host() else {
+            return;
+        };
+        // Skipped this time if the manager is busy; the files are still changed next time
+        for path in plugin_host.query(|manager| manager.changed_plugin_files()).unwrap_or_default() {
+            plugin_host.submit(PluginCommand::Reload(path));
+        }
+    }
+}
+
+impl Default for PluginHotReload {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Call `f` on every node of `graph` and of the workspace graphs nested in it
+fn for_each_node(graph: &mut NodeGraph, f: &mut dyn FnMut(&mut Node)) {
+    for node in graph.nodes.values_mut() {
+        f(node);
+        if let Some(internal_graph) = node.get_internal_graph_mut() {
+            for_each_node(internal_graph, f);
+        }
+    }
+}
+
+/// Drop connections to ports that no longer exist, in `graph` and nested graphs
+fn prune_connections(graph: &mut NodeGraph) {
+    let nodes = &graph.nodes;
+    graph.connections.retain(|connection| {
+        let from_ok = nodes.get(&connection.from_node).is_some_and(|node| connection.from_port < node.outputs.len());
+        let to_ok = nodes.get(&connection.to_node).is_some_and(|node| connection.to_port < node.inputs.len());
+        from_ok && to_ok
+    });
+    for node in graph.nodes.values_mut() {
+        if let Some(internal_graph) = node.get_internal_graph_mut() {
+            prune_connections(internal_graph);
+        }
+    }
+}
+
+/// Hand the node's stored parameters to a new plugin instance
+fn restore_parameters(instance: &mut dyn nodle_plugin_sdk::PluginNode, node: &Node) {
+    for (name, value) in &node.parameters {
+        if let Some(value) = crate::plugins::to_plugin_data(value) {
+            let restored = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                instance.set_parameter(name, value);
+            }));
+            if restored.is_err() {
+                log::warn!("Plugin node {} panicked restoring parameter {}", node.title, name);
+            }
+        }
+    }
+}
+
+/// Recreate the plugin instance of `node` from the (reloaded) workspaces. The new
+/// ports replace the old ones when the plugin changed them.
+fn recreate_instance(node: &mut Node, workspace_manager: &WorkspaceManager) -> bool {
+    let Some(mut fresh) = workspace_manager.get_workspaces().iter()
+        .find_map(|workspace| workspace.create_workspace_node(&node.type_id, node.position))
+    else {
+        return false;
+    };
+
+    let port_names = |ports: &[crate::nodes::port::Port]| ports.iter().map(|port| port.name.clone()).collect::<Vec<_>>();
+    if port_names(&fresh.inputs) != port_names(&node.inputs) || port_names(&fresh.outputs) != port_names(&node.outputs) {
+        node.inputs = std::mem::take(&mut fresh.inputs);
+        node.outputs = std::mem::take(&mut fresh.outputs);
+        node.update_port_positions();
+    }
+
+    if let Some(mut instance) = fresh.plugin_node.take() {
+        restore_parameters(instance.as_mut(), node);
+        node.plugin_node = Some(instance);
+        return true;
+    }
+
+    // Viewport plugin nodes keep their instance in the plugin host, stored under the fresh node's temporary id
+    let Some(plugin_host) = crate::workspace::get_plugin_host() else {
+        return false;
+    };
+    if !plugin_host.move_instance(fresh.id, node.id) {
+        return false;
+    }
+    plugin_host.with_instance(node.id, |instance| restore_parameters(instance, node));
+    true
+}
+
+/// Drop every node instance of `affected_types` in `graph` and in the plugin host,
+/// and every node factory in `workspace_manager`, so the libraries providing them
+/// can be unloaded.
+fn release_plugin_code(plugin_host: &PluginHost, affected_types: &[String], graph: &mut NodeGraph, workspace_manager: &mut WorkspaceManager) {
+    let mut affected_ids: Vec<NodeId> = Vec::new();
+    for_each_node(graph, &mut |node| {
+        if affected_types.contains(&node.type_id) {
+            node.plugin_node = None;
+            affected_ids.push(node.id);
+        }
+    });
+    for id in affected_ids {
+        plugin_host.remove_instance(id);
+    }
+    *workspace_manager = WorkspaceManager::new();
+}
+
+/// Rebuild `workspace_manager` from the currently loaded plugins, keeping the active workspace
+fn rebuild_workspaces(workspace_manager: &mut WorkspaceManager, active_workspace: Option<&str>) {
+    // Workspaces register plugin nodes when built, which picks up the current factories
+    *workspace_manager = WorkspaceRegistry::create_workspace_manager();
+    workspace_manager.set_active_workspace_by_id(active_workspace);
+}
+
+/// Reload the plugin libraries at `paths` and rebuild the affected nodes of `graph`.
+/// `workspace_manager` is rebuilt so its node factories come from the new libraries.
+/// Returns one status line per library.
+fn reload_plugins(plugin_host: &PluginHost, paths: &[PathBuf], graph: &mut NodeGraph, workspace_manager: &mut WorkspaceManager) -> Vec<String> {
+    // Node types whose code is about to be unloaded
+    let snapshot = plugin_host.snapshot();
+    let affected_types: Vec<String> = paths.iter()
+        .filter_map(|path| snapshot.plugin_for_path(path))
+        .flat_map(|plugin| plugin.node_types.iter().cloned())
+        .collect();
+
+    // Drop every instance and factory from the old libraries before unloading them
+    let active_workspace = workspace_manager.get_active_workspace().map(|workspace| workspace.id());
+    release_plugin_code(plugin_host, &affected_types, graph, workspace_manager);
+
+    let mut messages = Vec::new();
+    for path in paths {
+        match plugin_host.apply(&PluginCommand::Reload(path.clone())) {
+            Ok(Some(info)) => messages.push(format!("Reloaded {} {}", info.name, info.version)),
+            Ok(None) => {}
+            Err(e) => messages.push(format!("Failed to reload {}: {}", path.display(), e)),
+        }
+    }
+
+    rebuild_workspaces(workspace_manager, active_workspace);
+
+    let mut rebuilt = 0;
+    for_each_node(graph, &mut |node| {
+        if affected_types.contains(&node.type_id) {
+            if recreate_instance(node, workspace_manager) {
+                // New code gets a fresh chance even if the old build crashed
+                crate::plugins::sandbox::reset_node(node.id);
+                rebuilt += 1;
+            } else {
+                log::warn!("Could not recreate plugin node {} ({}) after reload", node.title, node.type_id);
+            }
+        }
+    });
+    prune_connections(graph);
+    if rebuilt > 0 {
+        messages.push(format!("Rebuilt {} plugin node(s)", rebuilt));
+    }
+    messages
+}
+
+/// Unload the plugin `name` for good after it crashed. Its nodes stay in `graph`
+/// with their parameters but without an instance, so they pass their inputs
+/// through until the plugin is enabled and loaded again.
+fn disable_plugin(plugin_host: &PluginHost, name: &str, graph: &mut NodeGraph, workspace_manager: &mut WorkspaceManager) -> String {
+    let affected_types = plugin_host.snapshot().plugin(name)
+        .map(|plugin| plugin.node_types.clone())
+        .unwrap_or_default();
+
+    let active_workspace = workspace_manager.get_active_workspace().map(|workspace| workspace.id());
+    release_plugin_code(plugin_host, &affected_types, graph, workspace_manager);
+    let result = plugin_host.apply(&PluginCommand::Disable(name.to_string()));
+    rebuild_workspaces(workspace_manager, active_workspace);
+
+    match result {
+        Ok(_) => format!("Disabled crashed plugin {}", name),
+        Err(e) => format!("Failed to disable plugin {}: {}", name, e),
+    }
+}
+
+/// Carry out the plugin changes queued on the plugin host, tearing down and
+/// rebuilding the nodes of `graph` they affect. Returns one status line per change.
+pub fn apply_plugin_commands(graph: &mut NodeGraph, workspace_manager: &mut WorkspaceManager) -> Vec<String> {
+    let Some(plugin_host) = crate::workspace::get_plugin_host() else {
+        return Vec::new();
+    };
+    let commands = plugin_host.take_commands();
+    if commands.is_empty() {
+        return Vec::new();
+    }
+
+    let mut messages = Vec::new();
+    let mut loaded_any = false;
+    let mut reloads = Vec::new();
+    for command in commands {
+        match command {
+            PluginCommand::Load(path) => match plugin_host.apply(&PluginCommand::Load(path.clone())) {
+                Ok(info) => {
+                    loaded_any = true;
+                    if let Some(info) = info {
+                        messages.push(format!("Loaded {} {}", info.name, info.version));
+                    }
+                }
+                Err(e) => messages.push(format!("Failed to load {}: {}", path.display(), e)),
+            },
+            PluginCommand::Reload(path) => reloads.push(path),
+            PluginCommand::Disable(name) => messages.push(disable_plugin(plugin_host, &name, graph, workspace_manager)),
+        }
+    }
+
+    // Reloads are batched so the workspaces are only rebuilt once
+    if !reloads.is_empty() {
+        messages.extend(reload_plugins(plugin_host, &reloads, graph, workspace_manager));
+    } else if loaded_any {
+        // Workspaces register plugin nodes when built; rebuild them to pick up the new plugins
+        let active_workspace = workspace_manager.get_active_workspace().map(|workspace| workspace.id());
+        rebuild_workspaces(workspace_manager, active_workspace);
+    }
+    messages
+}
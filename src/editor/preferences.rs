@@ -0,0 +1,155 @@
+//! Preferences window
+//!
+//! Edits a copy of the application [`Settings`]; nothing changes until the
+//! user saves, at which point the settings are written to the config file and
+//! handed back to the editor to apply.
+
+use eframe::egui;
+use egui::RichText;
+use crate::settings::{self, Settings, StartupExecutionMode};
+
+/// Preferences window state
+pub struct PreferencesWindow {
+    /// Settings being edited
+    draft: Settings,
+    /// Text of the "add plugin directory" field
+    new_plugin_directory: String,
+    /// Built-in and custom theme names, read when the window is (re)loaded
+    themes: Vec<String>,
+}
+
+impl PreferencesWindow {
+    pub fn new() -> Self {
+        Self {
+            draft: settings::current(),
+            new_plugin_directory: String::new(),
+            themes: crate::theme::available_themes(),
+        }
+    }
+
+    /// Start editing from the current settings
+    pub fn reload(&mut self) {
+        self.draft = settings::current();
+        self.new_plugin_directory.clear();
+        self.themes = crate::theme::available_themes();
+    }
+
+    /// Render the window. Returns the saved settings when the user saved them.
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) -> Option<Settings> {
+        let mut saved = None;
+        let mut close = false;
+        egui::Window::new("Preferences")
+            .open(open)
+            .default_size([420.0, 460.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.strong("Appearance");
+                    egui::Grid::new("preferences_appearance").num_columns(2).show(ui, |ui| {
+                        ui.label("Theme:");
+                        egui::ComboBox::from_id_salt("preferences_theme")
+                            .selected_text(self.draft.theme.clone())
+                            .show_ui(ui, |ui| {
+                                for theme in &self.themes {
+                                    ui.selectable_value(&mut self.draft.theme, theme.clone(), theme);
+                                }
+                            })
+                            .response
+                            .on_hover_text("Add custom themes as TOML files in ~/.nodle/themes");
+                        ui.end_row();
+                        ui.label("GPU node rendering:");
+                        ui.checkbox(&mut self.draft.gpu_rendering, "");
+                        ui.end_row();
+                    });
+
+                    ui.add_space(8.0);
+                    ui.strong("Projects");
+                    egui::Grid::new("preferences_projects").num_columns(2).show(ui, |ui| {
+                        ui.label("Autosave every:");
+                        ui.add(egui::DragValue::new(&mut self.draft.autosave_minutes).range(0..=120).suffix(" min"))
+                            .on_hover_text("Saves projects that already have a file; 0 turns autosave off");
+                        ui.end_row();
+                        ui.label("Execution mode:");
+                        ui.horizontal(|ui| {
+                            ui.selectable_value(&mut self.draft.execution_mode, StartupExecutionMode::Auto, "Auto");
+                            ui.selectable_value(&mut self.draft.execution_mode, StartupExecutionMode::Manual, "Manual");
+                        });
+                        ui.end_row();
+                        ui.label("Auto-wire new nodes:");
+                        ui.checkbox(&mut self.draft.auto_wire_new_nodes, "")
+                            .on_hover_text("Connect the selected node to nodes created from the context menu");
+                        ui.end_row();
+                    });
+
+                    ui.add_space(8.0);
+                    ui.strong("Viewport defaults");
+                    egui::Grid::new("preferences_viewport").num_columns(2).show(ui, |ui| {
+                        ui.label("Background:");
+                        ui.color_edit_button_rgba_unmultiplied(&mut self.draft.viewport.background_color);
+                        ui.end_row();
+                        ui.label("Show grid:");
+                        ui.checkbox(&mut self.draft.viewport.show_grid, "");
+                        ui.end_row();
+                        ui.label("Field of view:");
+                        ui.add(egui::DragValue::new(&mut self.draft.viewport.field_of_view).range(10.0..=120.0).suffix("°"));
+                        ui.end_row();
+                    });
+
+                    ui.add_space(8.0);
+                    ui.strong("Plugin directories");
+                    ui.label(RichText::new("Searched in addition to ~/.nodle/plugins and ./plugins; takes effect after a restart").weak());
+                    let mut remove = None;
+                    for (index, directory) in self.draft.plugin_directories.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                                remove = Some(index);
+                            }
+                            ui.label(directory.display().to_string());
+                        });
+                    }
+                    if let Some(index) = remove {
+                        self.draft.plugin_directories.remove(index);
+                    }
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.new_plugin_directory).hint_text("Directory").desired_width(220.0));
+                        if ui.button("Browse...").clicked() {
+                            if let Some(folder) = rfd::FileDialog::new().set_title("Select Plugin Directory").pick_folder() {
+                                self.new_plugin_directory = folder.display().to_string();
+                            }
+                        }
+                        let directory = self.new_plugin_directory.trim();
+                        if ui.add_enabled(!directory.is_empty(), egui::Button::new("Add")).clicked() {
+                            self.draft.plugin_directories.push(directory.into());
+                            self.new_plugin_directory.clear();
+                        }
+                    });
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let changed = self.draft != settings::current();
+                    if ui.add_enabled(changed, egui::Button::new("Save")).clicked() {
+                        saved = Some(self.draft.clone());
+                    }
+                    if ui.add_enabled(changed, egui::Button::new("Revert")).clicked() {
+                        self.reload();
+                    }
+                    if ui.button("Restore Defaults").clicked() {
+                        self.draft = Settings::default();
+                    }
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if close {
+            *open = false;
+        }
+        if let Some(settings) = &saved {
+            if let Err(e) = settings::update(settings.clone()) {
+                log::error!("Failed to save preferences: {}", e);
+            }
+        }
+        saved
+    }
+}
@@ -0,0 +1,119 @@
+//! Named parameter presets per node type
+//!
+//! A preset is a parameter set file (see [`crate::nodes::parameter_io`]) saved
+//! under a name in `~/.nodle/presets/<type_id>/`, so one node's settings can be
+//! reused on any other node of the same type ("HD 1080 render" for the Render
+//! node, say). Loading goes through the same validation as importing a
+//! parameter set.
+
+use std::path::PathBuf;
+use crate::nodes::{Node, ParameterDefinition};
+use crate::nodes::interface::NodeData;
+use crate::nodes::parameter_io;
+use super::templates::template_file_stem;
+
+/// Preset files on disk, one directory per node type
+#[derive(Debug, Clone)]
+pub struct PresetStore {
+    root: Option<PathBuf>,
+}
+
+impl Default for PresetStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PresetStore {
+    /// Store in `~/.nodle/presets`
+    pub fn new() -> Self {
+        Self::with_root(dirs::home_dir().map(|home| home.join(".nodle").join("presets")))
+    }
+
+    /// Store in `root` (None disables presets)
+    pub fn with_root(root: Option<PathBuf>) -> Self {
+        Self { root }
+    }
+
+    /// Directory holding the presets of `type_id`
+    pub fn type_dir(&self, type_id: &str) -> Option<PathBuf> {
+        self.root.as_ref().map(|root| root.join(template_file_stem(type_id)))
+    }
+
+    fn preset_path(&self, type_id: &str, name: &str) -> Option<PathBuf> {
+        self.type_dir(type_id).map(|dir| dir.join(format!("{}.json", template_file_stem(name))))
+    }
+
+    /// Names of the saved presets of `type_id`, sorted
+    pub fn list(&self, type_id: &str) -> Vec<String> {
+        let Some(entries) = self.type_dir(type_id).and_then(|dir| std::fs::read_dir(dir).ok()) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+            .collect();
+        names.sort_by_key(|name| name.to_lowercase());
+        names
+    }
+
+    /// Save `node`'s parameters as the preset `name` of its type, replacing any preset of that name
+    pub fn save(&self, node: &Node, name: &str) -> Result<PathBuf, String> {
+        let path = self.preset_path(&node.type_id, name)
+            .ok_or_else(|| "No home directory to store presets in".to_string())?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(&parameter_io::export_parameters(node))
+            .map_err(|e| e.to_string())?;
+        std::fs::write(&path, json).map_err(|e| e.to_string())?;
+        Ok(path)
+    }
+
+    /// Read the preset `name` for `node`, validated against its declared `schema`
+    pub fn load(&self, node: &Node, name: &str, schema: &[ParameterDefinition]) -> Result<Vec<(String, NodeData)>, Vec<String>> {
+        let path = self.preset_path(&node.type_id, name)
+            .ok_or_else(|| vec!["No home directory to load presets from".to_string()])?;
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| vec![format!("Failed to read preset {}: {}", path.display(), e)])?;
+        parameter_io::import_parameters(node, &json, schema)
+    }
+
+    /// Delete the preset `name` of `type_id`
+    pub fn delete(&self, type_id: &str, name: &str) -> Result<(), String> {
+        let path = self.preset_path(type_id, name)
+            .ok_or_else(|| "No home directory to delete presets from".to_string())?;
+        std::fs::remove_file(path).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_presets_round_trip_per_node_type() {
+        let root = std::env::temp_dir().join(format!("nodle_presets_test_{}", std::process::id()));
+        let store = PresetStore::with_root(Some(root.clone()));
+
+        let mut render = Node::new(1, "Render", Pos2::ZERO);
+        render.type_id = "3D_Render".to_string();
+        render.parameters.insert("width".to_string(), NodeData::Integer(1920));
+        render.parameters.insert("height".to_string(), NodeData::Integer(1080));
+        store.save(&render, "HD 1080 render").unwrap();
+        assert_eq!(store.list("3D_Render"), vec!["HD 1080 render".to_string()]);
+        assert!(store.list("3D_Cube").is_empty());
+
+        let mut other = render.clone();
+        other.parameters.insert("width".to_string(), NodeData::Integer(640));
+        let values = store.load(&other, "HD 1080 render", &[]).unwrap();
+        assert!(values.iter().any(|(name, value)| name == "width" && matches!(value, NodeData::Integer(1920))));
+
+        store.delete("3D_Render", "HD 1080 render").unwrap();
+        assert!(store.list("3D_Render").is_empty());
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
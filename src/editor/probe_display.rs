@@ -0,0 +1,82 @@
+//! Values drawn on the canvas
+//!
+//! Probe nodes show the value flowing through them on their body, and in
+//! inspect mode hovering any node shows a card with the values on its
+//! outputs. Both read the engine's output cache, so they follow every cook.
+
+use egui::{Align2, Color32, FontId, Pos2, Rect, Stroke, Vec2};
+use crate::nodes::value_preview::ValuePreview;
+use crate::theme;
+
+/// Height of a value line at zoom 1
+const LINE_HEIGHT: f32 = 12.0;
+
+/// Width of an image thumbnail at zoom 1
+const THUMBNAIL_WIDTH: f32 = 48.0;
+
+fn text_color() -> Color32 {
+    if theme::theme().dark { Color32::from_gray(220) } else { Color32::from_gray(30) }
+}
+
+/// Paint `preview` inside `rect` (screen space), thumbnail first
+pub fn paint_preview(ui: &egui::Ui, rect: Rect, preview: &ValuePreview, zoom: f32) {
+    let painter = ui.painter();
+    painter.rect_filled(rect, 3.0 * zoom, Color32::from_black_alpha(90));
+
+    let mut text_left = rect.left() + 4.0 * zoom;
+    if let Some(path) = &preview.image_path {
+        let size = (THUMBNAIL_WIDTH * zoom).min(rect.height() - 4.0 * zoom);
+        let thumbnail = Rect::from_min_size(rect.left_top() + Vec2::splat(2.0 * zoom), Vec2::splat(size));
+        egui::Image::new(format!("file://{}", path))
+            .maintain_aspect_ratio(true)
+            .paint_at(ui, thumbnail);
+        text_left = thumbnail.right() + 4.0 * zoom;
+    }
+
+    let font = FontId::monospace(9.0 * zoom);
+    let painter = painter.with_clip_rect(rect);
+    for (row, line) in preview.lines.iter().enumerate() {
+        let y = rect.top() + 2.0 * zoom + row as f32 * LINE_HEIGHT * zoom;
+        if y > rect.bottom() {
+            break;
+        }
+        painter.text(Pos2::new(text_left, y), Align2::LEFT_TOP, line, font.clone(), text_color());
+    }
+}
+
+/// Body area of a probe node (screen space) left free by the title and ports
+pub fn probe_body_rect(node_rect: Rect, zoom: f32) -> Rect {
+    Rect::from_min_max(
+        node_rect.min + Vec2::new(8.0, 26.0) * zoom,
+        node_rect.max - Vec2::new(8.0, 10.0) * zoom,
+    )
+}
+
+/// Height at zoom 1 of `preview` drawn in a card
+fn block_height(preview: &ValuePreview) -> f32 {
+    let text = preview.lines.len().max(1) as f32 * LINE_HEIGHT;
+    let image = if preview.image_path.is_some() { THUMBNAIL_WIDTH + 4.0 } else { 0.0 };
+    text.max(image) + 4.0
+}
+
+/// Paint an inspect card beside `node_rect` (screen space) listing each output's value
+pub fn paint_inspect_card(ui: &egui::Ui, node_rect: Rect, outputs: &[(String, ValuePreview)]) {
+    let title_font = FontId::proportional(11.0);
+    let has_image = outputs.iter().any(|(_, preview)| preview.image_path.is_some());
+    let width = if has_image { 260.0 } else { 210.0 };
+    let height = outputs.iter().map(|(_, preview)| LINE_HEIGHT + block_height(preview) + 6.0).sum::<f32>() + 4.0;
+    let card = Rect::from_min_size(node_rect.right_top() + Vec2::new(12.0, 0.0), Vec2::new(width, height));
+
+    let painter = ui.painter();
+    painter.rect_filled(card, 4.0, theme::colors().panel_background);
+    painter.rect_stroke(card, 4.0, Stroke::new(1.0, theme::colors().selection_blue), egui::StrokeKind::Inside);
+
+    let mut y = card.top() + 4.0;
+    for (name, preview) in outputs {
+        painter.text(Pos2::new(card.left() + 6.0, y), Align2::LEFT_TOP, name, title_font.clone(), theme::colors().selection_blue);
+        y += LINE_HEIGHT;
+        let value_rect = Rect::from_min_size(Pos2::new(card.left() + 6.0, y), Vec2::new(width - 12.0, block_height(preview)));
+        paint_preview(ui, value_rect, preview, 1.0);
+        y += block_height(preview) + 6.0;
+    }
+}
@@ -0,0 +1,315 @@
+//! Self-contained project archives (`.nodlepack`)
+//!
+//! A packed archive is a zip file holding the project, copies of the USD files
+//! and textures its parameters point at, and the versions of the plugins that
+//! were loaded when it was packed. Inside the archive asset paths are relative
+//! (`assets/<file>`); on extraction they are rewritten to the extracted files so
+//! the project opens on another machine without manual relinking.
+//!
+//! Referenced subgraphs are embedded rather than packed as separate files, and
+//! layers or textures referenced from inside USD files are not followed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use crate::nodes::NodeGraph;
+use crate::nodes::interface::{NodeData, PanelLayout};
+use crate::editor::canvas::Canvas;
+use crate::editor::file_manager::{build_save_data, SaveData};
+
+/// File extension of packed project archives
+pub const ARCHIVE_EXTENSION: &str = "nodlepack";
+
+const MANIFEST_FILE: &str = "manifest.json";
+const PROJECT_FILE: &str = "project.json";
+const ASSET_DIR: &str = "assets";
+
+/// Parameter values with these extensions are treated as asset files to pack
+const ASSET_EXTENSIONS: &[&str] = &[
+    "usd", "usda", "usdc", "usdz", "mtlx",
+    "png", "jpg", "jpeg", "exr", "hdr", "tif", "tiff", "tx", "tga", "bmp",
+];
+
+/// Plugin loaded when the archive was packed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginVersion {
+    pub name: String,
+    pub version: String,
+}
+
+/// A file copied into the archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackedAsset {
+    /// Path inside the archive, e.g. `assets/chair.usda`
+    pub archive_path: String,
+    /// Path the project referenced when it was packed
+    pub original_path: String,
+}
+
+/// Archive contents description, stored as `manifest.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub version: u32,
+    pub created: String,
+    #[serde(default)]
+    pub plugins: Vec<PluginVersion>,
+    #[serde(default)]
+    pub assets: Vec<PackedAsset>,
+}
+
+/// Outcome of packing or extracting, with anything the user should know about
+#[derive(Debug)]
+pub struct ArchiveReport {
+    /// Packed archive, or the extracted project file
+    pub path: PathBuf,
+    pub warnings: Vec<String>,
+}
+
+/// Whether a string parameter looks like a path to an asset file
+fn is_asset_path(value: &str) -> bool {
+    Path::new(value)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ASSET_EXTENSIONS.iter().any(|known| ext.eq_ignore_ascii_case(known)))
+}
+
+/// Call `visit` on every string parameter in the graph, including nested workspaces
+fn for_each_string_parameter(graph: &mut NodeGraph, visit: &mut dyn FnMut(&mut String)) {
+    for node in graph.nodes.values_mut() {
+        for value in node.parameters.values_mut() {
+            if let NodeData::String(text) = value {
+                visit(text);
+            }
+        }
+        if let Some(internal_graph) = node.get_internal_graph_mut() {
+            for_each_string_parameter(internal_graph, visit);
+        }
+    }
+}
+
+/// Turn subgraph references into embedded workspaces so the archive needs no external files
+fn embed_references(graph: &mut NodeGraph) {
+    for node in graph.nodes.values_mut() {
+        if node.get_reference().is_some() {
+            let _ = node.set_reference(None);
+        }
+        if let Some(internal_graph) = node.get_internal_graph_mut() {
+            embed_references(internal_graph);
+        }
+    }
+}
+
+/// Name and version of every plugin in the global plugin manager
+fn loaded_plugin_versions() -> Vec<PluginVersion> {
+    crate::workspace::get_plugin_host()
+        .map(|plugin_host| plugin_host.snapshot().plugins.iter()
+            .map(|plugin| PluginVersion { name: plugin.info.name.clone(), version: plugin.info.version.clone() })
+            .collect())
+        .unwrap_or_default()
+}
+
+/// Pick an archive path for `file_name` that no other asset uses
+fn unique_archive_path(file_name: &str, taken: &HashMap<PathBuf, String>) -> String {
+    let path = Path::new(file_name);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("asset");
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let mut candidate = format!("{}/{}", ASSET_DIR, file_name);
+    let mut counter = 1;
+    while taken.values().any(|used| *used == candidate) {
+        candidate = format!("{}/{}_{}.{}", ASSET_DIR, stem, counter, extension);
+        counter += 1;
+    }
+    candidate
+}
+
+/// Pack the project and the assets it references into `archive_path`.
+/// Relative asset paths are resolved against `project_dir`.
+pub fn pack_project(
+    archive_path: &Path,
+    graph: &NodeGraph,
+    canvas: &Canvas,
+    panel_layout: &PanelLayout,
+    project_dir: Option<&Path>,
+) -> Result<ArchiveReport, String> {
+    let mut packed_graph = graph.clone();
+    embed_references(&mut packed_graph);
+
+    // Rewrite asset parameters to archive paths, remembering which files to copy
+    let mut files: HashMap<PathBuf, String> = HashMap::new();
+    let mut warnings = Vec::new();
+    for_each_string_parameter(&mut packed_graph, &mut |value| {
+        if !is_asset_path(value) {
+            return;
+        }
+        let source = match project_dir {
+            Some(dir) if Path::new(value.as_str()).is_relative() => dir.join(value.as_str()),
+            _ => PathBuf::from(value.as_str()),
+        };
+        if !source.is_file() {
+            warnings.push(format!("Asset not found, left as is: {}", value));
+            return;
+        }
+        let source = source.canonicalize().unwrap_or(source);
+        let archive_entry = match files.get(&source).cloned() {
+            Some(entry) => entry,
+            None => {
+                let file_name = source.file_name().and_then(|n| n.to_str()).unwrap_or("asset").to_string();
+                let entry = unique_archive_path(&file_name, &files);
+                files.insert(source, entry.clone());
+                entry
+            }
+        };
+        *value = archive_entry;
+    });
+
+    let manifest = ArchiveManifest {
+        version: 1,
+        created: chrono::Utc::now().to_rfc3339(),
+        plugins: loaded_plugin_versions(),
+        assets: files.iter()
+            .map(|(source, entry)| PackedAsset { archive_path: entry.clone(), original_path: source.display().to_string() })
+            .collect(),
+    };
+    let save_data = build_save_data(&packed_graph, canvas, panel_layout, "Packed project");
+
+    let file = std::fs::File::create(archive_path)
+        .map_err(|e| format!("Failed to create {}: {}", archive_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    let zip_error = |e: zip::result::ZipError| format!("Failed to write archive: {}", e);
+    let io_error = |e: std::io::Error| format!("Failed to write archive: {}", e);
+
+    zip.start_file(MANIFEST_FILE, options).map_err(zip_error)?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?).map_err(io_error)?;
+    zip.start_file(PROJECT_FILE, options).map_err(zip_error)?;
+    zip.write_all(&serde_json::to_vec_pretty(&save_data).map_err(|e| e.to_string())?).map_err(io_error)?;
+    for (source, entry) in &files {
+        let mut input = std::fs::File::open(source)
+            .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+        zip.start_file(entry.as_str(), options).map_err(zip_error)?;
+        std::io::copy(&mut input, &mut zip).map_err(io_error)?;
+    }
+    zip.finish().map_err(zip_error)?;
+
+    Ok(ArchiveReport { path: archive_path.to_path_buf(), warnings })
+}
+
+/// Extract an archive into `dest_dir` and write its project file there with asset
+/// paths pointing at the extracted files. Plugin version differences are reported
+/// as warnings.
+pub fn extract_project(archive_path: &Path, dest_dir: &Path) -> Result<ArchiveReport, String> {
+    let file = std::fs::File::open(archive_path)
+        .map_err(|e| format!("Failed to open {}: {}", archive_path.display(), e))?;
+    let mut zip = zip::ZipArchive::new(file)
+        .map_err(|e| format!("Not a valid project archive: {}", e))?;
+
+    let read_entry = |zip: &mut zip::ZipArchive<std::fs::File>, name: &str| -> Result<Vec<u8>, String> {
+        let mut entry = zip.by_name(name)
+            .map_err(|_| format!("Archive is missing {}", name))?;
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        Ok(data)
+    };
+    let manifest: ArchiveManifest = serde_json::from_slice(&read_entry(&mut zip, MANIFEST_FILE)?)
+        .map_err(|e| format!("Invalid archive manifest: {}", e))?;
+    let mut save_data: SaveData = serde_json::from_slice(&read_entry(&mut zip, PROJECT_FILE)?)
+        .map_err(|e| format!("Invalid archived project: {}", e))?;
+
+    // Extract the packed assets; enclosed_name rejects entries escaping dest_dir
+    for asset in &manifest.assets {
+        let mut entry = zip.by_name(&asset.archive_path)
+            .map_err(|_| format!("Archive is missing {}", asset.archive_path))?;
+        let relative = entry.enclosed_name()
+            .ok_or_else(|| format!("Unsafe path in archive: {}", asset.archive_path))?;
+        let target = dest_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let mut output = std::fs::File::create(&target)
+            .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+        std::io::copy(&mut entry, &mut output)
+            .map_err(|e| format!("Failed to extract {}: {}", asset.archive_path, e))?;
+    }
+
+    // Point asset parameters at the extracted copies
+    let extracted: HashMap<&str, PathBuf> = manifest.assets.iter()
+        .map(|asset| (asset.archive_path.as_str(), dest_dir.join(&asset.archive_path)))
+        .collect();
+    for_each_string_parameter(&mut save_data.root_graph, &mut |value| {
+        if let Some(path) = extracted.get(value.as_str()) {
+            *value = path.display().to_string();
+        }
+    });
+
+    let stem = archive_path.file_stem().and_then(|s| s.to_str()).unwrap_or("project");
+    let project_path = dest_dir.join(format!("{}.json", stem));
+    let json = serde_json::to_vec_pretty(&save_data).map_err(|e| e.to_string())?;
+    std::fs::write(&project_path, json)
+        .map_err(|e| format!("Failed to write {}: {}", project_path.display(), e))?;
+
+    Ok(ArchiveReport { path: project_path, warnings: plugin_warnings(&manifest.plugins, &loaded_plugin_versions()) })
+}
+
+/// Warnings for plugins the archive was packed with that are missing or differ here
+fn plugin_warnings(packed: &[PluginVersion], installed: &[PluginVersion]) -> Vec<String> {
+    packed.iter()
+        .filter_map(|plugin| match installed.iter().find(|p| p.name == plugin.name) {
+            None => Some(format!("Plugin {} {} is not installed", plugin.name, plugin.version)),
+            Some(local) if local.version != plugin.version => Some(format!(
+                "Plugin {} was packed with version {} but {} is installed",
+                plugin.name, plugin.version, local.version
+            )),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::Node;
+    use egui::Pos2;
+
+    #[test]
+    fn test_pack_and_extract_rewrites_asset_paths() {
+        let root = std::env::temp_dir().join(format!("nodle_archive_test_{}", std::process::id()));
+        let source_dir = root.join("source");
+        let dest_dir = root.join("extracted");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let usd_path = source_dir.join("chair.usda");
+        std::fs::write(&usd_path, "#usda 1.0\n").unwrap();
+
+        let mut graph = NodeGraph::new();
+        let mut node = Node::new(0, "Reader", Pos2::ZERO);
+        node.parameters.insert("file_path".to_string(), NodeData::String(usd_path.display().to_string()));
+        node.parameters.insert("label".to_string(), NodeData::String("chair".to_string()));
+        let node_id = graph.add_node(node);
+
+        let archive_path = root.join("chair.nodlepack");
+        let report = pack_project(&archive_path, &graph, &Canvas::new(), &PanelLayout::default(), None).unwrap();
+        assert!(report.warnings.is_empty());
+
+        let report = extract_project(&archive_path, &dest_dir).unwrap();
+        let (extracted, _, _) = crate::editor::file_manager::read_save_data(&report.path).unwrap();
+        let _ = std::fs::remove_dir_all(&root);
+
+        let parameters = &extracted.nodes[&node_id].parameters;
+        let expected = dest_dir.join("assets").join("chair.usda").display().to_string();
+        assert!(matches!(parameters.get("file_path"), Some(NodeData::String(path)) if *path == expected));
+        assert!(matches!(parameters.get("label"), Some(NodeData::String(label)) if label == "chair"));
+    }
+
+    #[test]
+    fn test_plugin_version_mismatch_is_reported() {
+        let packed = vec![
+            PluginVersion { name: "USD".to_string(), version: "1.0".to_string() },
+            PluginVersion { name: "Extra".to_string(), version: "0.1".to_string() },
+        ];
+        let installed = vec![PluginVersion { name: "USD".to_string(), version: "1.1".to_string() }];
+        assert_eq!(plugin_warnings(&packed, &installed).len(), 2);
+        assert!(plugin_warnings(&packed[..1], &packed[..1]).is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+//! Render completion notifications
+//!
+//! Renders run in the background queue, so the editor announces each job as
+//! it finishes with a short-lived message in the bottom-right corner, and
+//! re-cooks the Render node so its Status output shows the result.
+
+use egui::Color32;
+use std::time::{Duration, Instant};
+use crate::nodes::NodeId;
+use crate::nodes::three_d::output::render::queue::{self, JobState, RenderJob};
+
+/// How long a message stays up
+const MESSAGE_LIFETIME: Duration = Duration::from_secs(6);
+
+/// Messages about finished render jobs
+#[derive(Default)]
+pub struct RenderNotifications {
+    messages: Vec<(RenderJob, Instant)>,
+}
+
+impl RenderNotifications {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the jobs finished since the last frame. Returns the Render nodes whose status changed.
+    pub fn collect(&mut self) -> Vec<NodeId> {
+        let finished = queue::take_finished();
+        let node_ids = finished.iter().map(|job| job.node_id).collect();
+        let now = Instant::now();
+        self.messages.extend(finished.into_iter().map(|job| (job, now)));
+        node_ids
+    }
+
+    /// Draw the messages still up, newest at the bottom
+    pub fn render(&mut self, ctx: &egui::Context) {
+        self.messages.retain(|(_, shown)| shown.elapsed() < MESSAGE_LIFETIME);
+        if queue::is_busy() {
+            // Poll the queue for jobs finishing while the app is idle
+            ctx.request_repaint_after(Duration::from_millis(500));
+        }
+        if self.messages.is_empty() {
+            return;
+        }
+
+        let mut dismissed = None;
+        egui::Area::new(egui::Id::new("render_notifications"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-16.0, -16.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                for (index, (job, _)) in self.messages.iter().enumerate() {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            let (icon, color) = match job.state {
+                                JobState::Done => ("✅ Render finished", Color32::from_rgb(120, 220, 120)),
+                                JobState::Cancelled => ("⏹ Render cancelled", Color32::from_gray(200)),
+                                _ => ("❌ Render failed", Color32::from_rgb(255, 120, 120)),
+                            };
+                            ui.colored_label(color, icon);
+                            ui.label(&job.label);
+                            if job.state == JobState::Done && ui.small_button("Open").clicked() {
+                                queue::open_file(&job.output_path);
+                            }
+                            if ui.small_button("✖").clicked() {
+                                dismissed = Some(index);
+                            }
+                        });
+                        if let JobState::Failed(error) = &job.state {
+                            ui.label(error.lines().last().unwrap_or_default());
+                        }
+                    });
+                }
+            });
+        if let Some(index) = dismissed {
+            self.messages.remove(index);
+        }
+        // Fade out on time even without input
+        ctx.request_repaint_after(Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,251 @@
+//! Built-in screen recording of the editor window
+//!
+//! Frames are grabbed from the wgpu surface with egui's screenshot command and
+//! handed over a bounded channel to a background thread that encodes an
+//! animated GIF, so the UI thread never blocks on encoding: frames arriving
+//! while the queue is full are dropped, and stopping only closes the channel,
+//! leaving the encoder to finish the file on its own. A recording can cover the
+//! whole window or be cropped to a single panel's rectangle.
+//!
+//! Only GIF is encoded in-process; MP4 would require an external video encoder.
+
+use eframe::egui;
+use egui::{ColorImage, Rect};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Default capture rate; GIF delays are in centiseconds, so 10/20/25/50 fps are exact
+pub const DEFAULT_CAPTURE_FPS: u32 = 20;
+
+/// Frames waiting for the encoder; frames captured while it is full are dropped
+const FRAME_QUEUE_LEN: usize = 4;
+
+/// An in-progress recording
+struct Recording {
+    path: PathBuf,
+    sender: SyncSender<Arc<ColorImage>>,
+    encoder: JoinHandle<Result<usize, String>>,
+    /// Screen region to crop to (points), None = whole window
+    region: Option<Rect>,
+    frame_interval: Duration,
+    last_request: Option<Instant>,
+    frames_sent: usize,
+    frames_dropped: usize,
+}
+
+/// A stopped recording whose encoder is still writing the file
+struct FinishingRecording {
+    path: PathBuf,
+    encoder: JoinHandle<Result<usize, String>>,
+    frames_dropped: usize,
+}
+
+/// Records the editor window (or one panel) to an animated GIF
+#[derive(Default)]
+pub struct ScreenRecorder {
+    recording: Option<Recording>,
+    finishing: Vec<FinishingRecording>,
+}
+
+impl ScreenRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a recording is in progress
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Whether a stopped recording is still being written
+    pub fn is_saving(&self) -> bool {
+        !self.finishing.is_empty()
+    }
+
+    /// Frames captured so far in the current recording
+    pub fn frames_captured(&self) -> usize {
+        self.recording.as_ref().map_or(0, |recording| recording.frames_sent)
+    }
+
+    /// Start recording to `path`, optionally cropped to `region` (in points)
+    pub fn start(&mut self, path: &Path, region: Option<Rect>, fps: u32) -> Result<(), String> {
+        if self.recording.is_some() {
+            return Err("A recording is already in progress".to_string());
+        }
+        let fps = fps.clamp(1, 50);
+        let file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let (sender, receiver) = mpsc::sync_channel(FRAME_QUEUE_LEN);
+        let delay_centiseconds = (100 / fps) as u16;
+
+        let encoder = std::thread::Builder::new()
+            .name("nodle-gif-encoder".to_string())
+            .spawn(move || encode_gif(file, receiver, delay_centiseconds))
+            .map_err(|e| format!("Failed to start encoder thread: {}", e))?;
+
+        self.recording = Some(Recording {
+            path: path.to_path_buf(),
+            sender,
+            encoder,
+            region,
+            frame_interval: Duration::from_secs_f32(1.0 / fps as f32),
+            last_request: None,
+            frames_sent: 0,
+            frames_dropped: 0,
+        });
+        Ok(())
+    }
+
+    /// Stop recording; the encoder finishes the file in the background and
+    /// [`update`](Self::update) reports the result once it is written
+    pub fn stop(&mut self) -> Result<(), String> {
+        let recording = self.recording.take()
+            .ok_or_else(|| "No recording in progress".to_string())?;
+        // Closing the channel tells the encoder to finish after the queued frames
+        drop(recording.sender);
+        self.finishing.push(FinishingRecording {
+            path: recording.path,
+            encoder: recording.encoder,
+            frames_dropped: recording.frames_dropped,
+        });
+        Ok(())
+    }
+
+    /// Report stopped recordings whose encoder has finished, without waiting on the others
+    fn collect_finished(&mut self) {
+        let (finished, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.finishing)
+            .into_iter()
+            .partition(|finishing| finishing.encoder.is_finished());
+        self.finishing = pending;
+        for finishing in finished {
+            let result = finishing.encoder.join()
+                .map_err(|_| "Encoder thread panicked".to_string())
+                .and_then(|result| result);
+            match result {
+                Ok(frames) => log::info!(
+                    "Saved recording of {} frames to {} ({} dropped while the encoder was busy)",
+                    frames, finishing.path.display(), finishing.frames_dropped
+                ),
+                Err(error) => log::error!("Failed to finish recording {}: {}", finishing.path.display(), error),
+            }
+        }
+    }
+
+    /// Request and collect frames; call once per frame while the editor updates
+    pub fn update(&mut self, ctx: &egui::Context) {
+        if !self.finishing.is_empty() {
+            self.collect_finished();
+            ctx.request_repaint_after(Duration::from_millis(100));
+        }
+        let Some(recording) = &mut self.recording else {
+            return;
+        };
+
+        // Collect screenshots delivered by the renderer for the previous request
+        let pixels_per_point = ctx.pixels_per_point();
+        let screenshots: Vec<Arc<ColorImage>> = ctx.input(|input| {
+            input.raw.events.iter()
+                .filter_map(|event| match event {
+                    egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                    _ => None,
+                })
+                .collect()
+        });
+        for image in screenshots {
+            let frame = match recording.region {
+                Some(region) => Arc::new(image.region(&region, Some(pixels_per_point))),
+                None => image,
+            };
+            match recording.sender.try_send(frame) {
+                Ok(()) => recording.frames_sent += 1,
+                Err(TrySendError::Full(_)) => recording.frames_dropped += 1,
+                // The encoder stopped on an error, reported once the recording is stopped
+                Err(TrySendError::Disconnected(_)) => {}
+            }
+        }
+
+        // Ask for the next frame at the capture rate
+        let due = match recording.last_request {
+            Some(last) => last.elapsed() >= recording.frame_interval,
+            None => true,
+        };
+        if due {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::default()));
+            recording.last_request = Some(Instant::now());
+        }
+        ctx.request_repaint_after(recording.frame_interval);
+    }
+}
+
+/// Encoder thread body: write every received frame into a looping GIF until the channel closes
+fn encode_gif(file: std::fs::File, receiver: mpsc::Receiver<Arc<ColorImage>>, delay_centiseconds: u16) -> Result<usize, String> {
+    let mut encoder: Option<gif::Encoder<std::io::BufWriter<std::fs::File>>> = None;
+    let mut file = Some(std::io::BufWriter::new(file));
+    let mut canvas_size = [0usize; 2];
+    let mut frames = 0;
+
+    while let Ok(image) = receiver.recv() {
+        // The GIF canvas is fixed by the first frame; frames from a resized window are skipped
+        if let Some(file) = file.take() {
+            canvas_size = image.size;
+            let mut new_encoder = gif::Encoder::new(file, image.size[0] as u16, image.size[1] as u16, &[])
+                .map_err(|e| format!("Failed to start GIF: {}", e))?;
+            new_encoder.set_repeat(gif::Repeat::Infinite)
+                .map_err(|e| format!("Failed to start GIF: {}", e))?;
+            encoder = Some(new_encoder);
+        }
+        if image.size != canvas_size {
+            continue;
+        }
+
+        let mut rgba = image.as_raw().to_vec();
+        let mut frame = gif::Frame::from_rgba_speed(image.size[0] as u16, image.size[1] as u16, &mut rgba, 10);
+        frame.delay = delay_centiseconds;
+        if let Some(encoder) = &mut encoder {
+            encoder.write_frame(&frame)
+                .map_err(|e| format!("Failed to write GIF frame: {}", e))?;
+            frames += 1;
+        }
+    }
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_are_encoded_to_a_looping_gif() {
+        let path = std::env::temp_dir().join(format!("nodle_recording_test_{}.gif", std::process::id()));
+        let (sender, receiver) = mpsc::sync_channel(FRAME_QUEUE_LEN);
+        for color in [egui::Color32::RED, egui::Color32::GREEN, egui::Color32::BLUE] {
+            sender.send(Arc::new(ColorImage::new([8, 6], color))).unwrap();
+        }
+        // A frame from a resized window doesn't fit the canvas and is skipped
+        sender.send(Arc::new(ColorImage::new([4, 4], egui::Color32::WHITE))).unwrap();
+        drop(sender);
+
+        let file = std::fs::File::create(&path).unwrap();
+        assert_eq!(encode_gif(file, receiver, 5), Ok(3));
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (8, 6));
+        let mut first_pixels = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 5);
+            first_pixels.push([frame.buffer[0], frame.buffer[1], frame.buffer[2]]);
+        }
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(first_pixels.len(), 3);
+        // GIF frames are quantized, so compare the dominant channel
+        let dominant = |pixel: &[u8; 3]| (0..3).max_by_key(|&channel| pixel[channel]).unwrap();
+        assert_eq!(first_pixels.iter().map(dominant).collect::<Vec<_>>(), [0, 1, 2]);
+    }
+}
@@ -0,0 +1,121 @@
+//! Selection sets and isolate mode
+//!
+//! A selection set is a named group of nodes that can be selected again
+//! later. Sets are stored on the graph they belong to (see
+//! `NodeGraph::selection_sets`), so they are saved with the project and every
+//! workspace keeps its own. Isolating hides every node of the viewed graph
+//! outside the selection or a set until isolation ends; the nodes stay in the
+//! graph and keep cooking.
+
+use eframe::egui;
+use std::collections::{BTreeMap, HashSet};
+use crate::nodes::NodeId;
+
+/// Change requested from the selection sets window
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectionSetAction {
+    /// Store the current selection under a name, replacing a set of that name
+    Save(String),
+    /// Select the nodes of a set
+    Recall(String),
+    Delete(String),
+    /// Hide every node outside the named set, or the selection when None
+    Isolate(Option<String>),
+    EndIsolation,
+}
+
+/// Nodes shown while isolating, and the graph they belong to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isolation {
+    /// Workspace node whose graph is isolated; None for the root graph
+    pub graph: Option<NodeId>,
+    pub nodes: HashSet<NodeId>,
+}
+
+impl Isolation {
+    /// Whether `node_id` is drawn while viewing the graph of `graph`
+    pub fn shows(&self, graph: Option<NodeId>, node_id: NodeId) -> bool {
+        self.graph != graph || self.nodes.contains(&node_id)
+    }
+}
+
+/// Selection sets window state and the active isolation
+#[derive(Debug, Default)]
+pub struct SelectionSets {
+    /// Name typed for the next saved set
+    new_set_name: String,
+    pub isolation: Option<Isolation>,
+}
+
+impl SelectionSets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the window listing the viewed graph's sets
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool, sets: &BTreeMap<String, Vec<NodeId>>, selection_count: usize) -> Option<SelectionSetAction> {
+        let mut action = None;
+        egui::Window::new("Selection Sets")
+            .open(open)
+            .resizable(false)
+            .default_pos([80.0, 160.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.new_set_name).hint_text("Set name").desired_width(140.0));
+                    let can_save = selection_count > 0 && !self.new_set_name.trim().is_empty();
+                    if ui.add_enabled(can_save, egui::Button::new("Save Selection"))
+                        .on_hover_text("Store the selected nodes under this name")
+                        .clicked()
+                    {
+                        action = Some(SelectionSetAction::Save(self.new_set_name.trim().to_string()));
+                        self.new_set_name.clear();
+                    }
+                });
+                ui.separator();
+
+                if sets.is_empty() {
+                    ui.weak("No selection sets in this graph");
+                }
+                egui::Grid::new("selection_set_grid").num_columns(4).show(ui, |ui| {
+                    for (name, nodes) in sets {
+                        if ui.button(name).on_hover_text("Select these nodes").clicked() {
+                            action = Some(SelectionSetAction::Recall(name.clone()));
+                        }
+                        ui.weak(format!("{} nodes", nodes.len()));
+                        if ui.small_button("Isolate").on_hover_text("Hide every other node").clicked() {
+                            action = Some(SelectionSetAction::Isolate(Some(name.clone())));
+                        }
+                        if ui.small_button("🗑").on_hover_text("Delete this set").clicked() {
+                            action = Some(SelectionSetAction::Delete(name.clone()));
+                        }
+                        ui.end_row();
+                    }
+                });
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(selection_count > 0, egui::Button::new("Isolate Selection")).clicked() {
+                        action = Some(SelectionSetAction::Isolate(None));
+                    }
+                    if ui.add_enabled(self.isolation.is_some(), egui::Button::new("Show All")).clicked() {
+                        action = Some(SelectionSetAction::EndIsolation);
+                    }
+                });
+            });
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolation_hides_only_nodes_of_its_graph() {
+        let isolation = Isolation { graph: Some(4), nodes: HashSet::from([1, 2]) };
+        assert!(isolation.shows(Some(4), 2));
+        assert!(!isolation.shows(Some(4), 3));
+        // Other graphs are unaffected
+        assert!(isolation.shows(None, 3));
+    }
+}
@@ -0,0 +1,213 @@
+//! Session timeline of edits
+//!
+//! Keeps a human-readable log of what happened during the session
+//! ("14:02 added Sphere", "14:05 changed Sphere.radius 1.0 → 2.0") that can be
+//! browsed in a window and exported as plain text for handoffs.
+
+use eframe::egui;
+use egui::{Color32, RichText};
+use std::path::Path;
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+
+/// Oldest entries are dropped beyond this many
+const MAX_HISTORY_ENTRIES: usize = 5000;
+
+/// Repeated changes to the same parameter within this window (e.g. a drag) merge into one entry
+const MERGE_WINDOW_SECONDS: i64 = 3;
+
+/// One recorded edit
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub time: chrono::DateTime<chrono::Local>,
+    pub description: String,
+    /// Parameter change this entry describes: (node, parameter, value before the first change)
+    parameter_change: Option<(NodeId, String, String)>,
+}
+
+impl HistoryEntry {
+    /// "14:02 added Sphere"
+    pub fn to_line(&self) -> String {
+        format!("{} {}", self.time.format("%H:%M"), self.description)
+    }
+}
+
+/// Human-readable record of the edits made this session
+#[derive(Debug, Default)]
+pub struct SessionHistory {
+    entries: Vec<HistoryEntry>,
+}
+
+impl SessionHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an edit happening now
+    pub fn record(&mut self, description: impl Into<String>) {
+        self.push(description.into(), None);
+    }
+
+    /// Record a parameter change, merging consecutive changes of the same parameter
+    pub fn record_parameter_change(&mut self, node_id: NodeId, node_title: &str, parameter: &str, old_value: &NodeData, new_value: &NodeData) {
+        let new_text = describe_value(new_value);
+        let now = chrono::Local::now();
+        if let Some(last) = self.entries.last_mut() {
+            if let Some((last_node, last_parameter, first_old)) = &last.parameter_change {
+                if *last_node == node_id && last_parameter == parameter
+                    && (now - last.time).num_seconds() < MERGE_WINDOW_SECONDS
+                {
+                    last.description = format!("changed {}.{} {} → {}", node_title, parameter, first_old, new_text);
+                    last.time = now;
+                    return;
+                }
+            }
+        }
+
+        let old_text = describe_value(old_value);
+        if old_text == new_text {
+            return;
+        }
+        self.push(
+            format!("changed {}.{} {} → {}", node_title, parameter, old_text, new_text),
+            Some((node_id, parameter.to_string(), old_text)),
+        );
+    }
+
+    fn push(&mut self, description: String, parameter_change: Option<(NodeId, String, String)>) {
+        self.entries.push(HistoryEntry {
+            time: chrono::Local::now(),
+            description,
+            parameter_change,
+        });
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let overflow = self.entries.len() - MAX_HISTORY_ENTRIES;
+            self.entries.drain(..overflow);
+        }
+    }
+
+    /// Recorded edits, oldest first
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Forget all recorded edits
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The whole timeline as plain text, one edit per line
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Nōdle session history ({})\n", chrono::Local::now().format("%Y-%m-%d"));
+        for entry in &self.entries {
+            text.push_str(&entry.to_line());
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Write the timeline to a text file
+    pub fn export_to_file(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_text())
+            .map_err(|e| format!("Failed to write session history: {}", e))
+    }
+
+    /// Render the history window; `open` is cleared when the window is closed
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) {
+        let mut export = false;
+        let mut clear = false;
+        egui::Window::new("Session History")
+            .open(open)
+            .default_size([360.0, 320.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} edits", self.entries.len()));
+                    if ui.button("Export...").clicked() {
+                        export = true;
+                    }
+                    if ui.button("Clear").clicked() {
+                        clear = true;
+                    }
+                });
+                ui.separator();
+
+                if self.entries.is_empty() {
+                    ui.label(RichText::new("No edits yet").weak());
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show_rows(ui, ui.text_style_height(&egui::TextStyle::Body), self.entries.len(), |ui, rows| {
+                        for entry in &self.entries[rows] {
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(entry.time.format("%H:%M:%S").to_string()).color(Color32::GRAY).monospace());
+                                ui.label(entry.description.as_str());
+                            });
+                        }
+                    });
+            });
+
+        if export {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("Text files", &["txt"])
+                .set_file_name("session_history.txt")
+                .save_file()
+            {
+                if let Err(e) = self.export_to_file(&path) {
+                    log::error!("{}", e);
+                }
+            }
+        }
+        if clear {
+            self.clear();
+        }
+    }
+}
+
+/// Short display form of a value for history lines ("1.0", "(0, 1, 0)", "\"text\"")
+pub fn describe_value(value: &NodeData) -> String {
+    match value {
+        NodeData::Float(v) => format!("{:?}", v),
+        NodeData::Integer(v) => v.to_string(),
+        NodeData::Boolean(v) => v.to_string(),
+        NodeData::Vector3(v) => format!("({}, {}, {})", v[0], v[1], v[2]),
+        NodeData::Color(c) => format!("rgba({:.2}, {:.2}, {:.2}, {:.2})", c[0], c[1], c[2], c[3]),
+        NodeData::String(s) | NodeData::Any(s) => {
+            const MAX_CHARS: usize = 40;
+            if s.chars().count() > MAX_CHARS {
+                format!("\"{}…\"", s.chars().take(MAX_CHARS).collect::<String>())
+            } else {
+                format!("\"{}\"", s)
+            }
+        }
+        NodeData::Buffer(values) => format!("[{} values]", values.len()),
+        NodeData::Table(table) => format!("[{} rows]", table.row_count()),
+        NodeData::Tree(tree) => format!("[{} items]", tree.item_count()),
+        NodeData::PointCloud(points) => format!("[{} points]", points.point_count()),
+        NodeData::Volume(volume) => format!("[{} grids]", volume.grids.len()),
+        NodeData::Audio(audio) => format!("[{:.2}s audio]", audio.duration()),
+        NodeData::List(items) => format!("[{} items]", items.len()),
+        NodeData::Map(entries) => format!("[{} keys]", entries.len()),
+        _ => "…".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_text_lists_edits_in_order() {
+        let mut history = SessionHistory::new();
+        history.record("added Sphere");
+        // A drag produces many changes; they collapse into one entry keeping the original value
+        history.record_parameter_change(3, "Sphere", "radius", &NodeData::Float(1.0), &NodeData::Float(1.5));
+        history.record_parameter_change(3, "Sphere", "radius", &NodeData::Float(1.5), &NodeData::Float(2.0));
+        let text = history.to_text();
+        let lines: Vec<&str> = text.lines().skip(1).collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("added Sphere"));
+        assert!(lines[1].ends_with("changed Sphere.radius 1.0 → 2.0"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Startup screen for the node editor
+//!
+//! Shown when the editor opens, listing recently used projects so they can be
+//! reopened with a single click, and the available project templates.
+//! Can be disabled with its "Show on startup" checkbox.
+
+use eframe::egui;
+use egui::{Color32, RichText};
+use std::path::PathBuf;
+use crate::editor::FileManager;
+use crate::editor::templates::{ProjectTemplate, TemplateSource};
+
+/// What the user picked on the startup screen
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartupAction {
+    None,
+    NewProject,
+    NewFromTemplate(ProjectTemplate),
+    Open,
+    OpenRecent(PathBuf),
+}
+
+/// Startup screen state
+pub struct StartupScreen {
+    /// Whether the screen is currently shown
+    open: bool,
+}
+
+impl StartupScreen {
+    /// Create the startup screen, shown immediately if `show_on_startup` is set
+    pub fn new(show_on_startup: bool) -> Self {
+        Self { open: show_on_startup }
+    }
+
+    /// Render the startup screen; the screen closes itself once an action is picked
+    pub fn render(&mut self, ctx: &egui::Context, file_manager: &mut FileManager) -> StartupAction {
+        if !self.open {
+            return StartupAction::None;
+        }
+
+        let mut action = StartupAction::None;
+        let mut open = self.open;
+        egui::Window::new("Welcome to Nodle")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("📄 New Project").clicked() {
+                        action = StartupAction::NewProject;
+                    }
+                    if ui.button("📂 Open...").clicked() {
+                        action = StartupAction::Open;
+                    }
+                });
+
+                ui.separator();
+                ui.label(RichText::new("Recent Projects").strong());
+
+                if file_manager.recent_files().is_empty() {
+                    ui.label(RichText::new("No recent projects").weak());
+                } else {
+                    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                        for path in file_manager.recent_files() {
+                            let name = path.file_name()
+                                .map(|name| name.to_string_lossy().to_string())
+                                .unwrap_or_else(|| path.display().to_string());
+                            let exists = path.exists();
+                            let text = if exists {
+                                RichText::new(name)
+                            } else {
+                                RichText::new(format!("{} (missing)", name)).color(Color32::GRAY)
+                            };
+                            let response = ui.add_enabled(exists, egui::Button::new(text).frame(false))
+                                .on_hover_text(path.display().to_string());
+                            if response.clicked() {
+                                action = StartupAction::OpenRecent(path.clone());
+                            }
+                        }
+                    });
+                }
+
+                ui.separator();
+                ui.label(RichText::new("Templates").strong());
+                ui.horizontal_wrapped(|ui| {
+                    for template in file_manager.templates() {
+                        let hover = match &template.source {
+                            TemplateSource::BuiltIn => "Built-in template".to_string(),
+                            TemplateSource::File(path) => path.display().to_string(),
+                        };
+                        if ui.button(template.name.as_str()).on_hover_text(hover).clicked() {
+                            action = StartupAction::NewFromTemplate(template);
+                        }
+                    }
+                });
+
+                ui.separator();
+                let mut show_on_startup = file_manager.show_startup_screen();
+                if ui.checkbox(&mut show_on_startup, "Show on startup").changed() {
+                    file_manager.set_show_startup_screen(show_on_startup);
+                }
+            });
+
+        self.open = open && action == StartupAction::None;
+        action
+    }
+}
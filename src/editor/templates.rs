@@ -0,0 +1,99 @@
+//! Project templates for the node editor
+//!
+//! A template is a pre-wired graph used as the starting point for a new project.
+//! Built-in templates are assembled in code; user templates are ordinary save
+//! files stored in `~/.nodle/templates/` and are picked up automatically.
+
+use egui::Pos2;
+use std::path::PathBuf;
+use crate::nodes::{NodeGraph, NodeId};
+use crate::nodes::factory::NodeRegistry;
+
+/// Names of the templates that ship with the editor
+pub const BUILTIN_TEMPLATES: [&str; 2] = ["USD Turntable", "MaterialX Preview"];
+
+/// Where a template's graph comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateSource {
+    /// Assembled in code by [`build_builtin_template`]
+    BuiltIn,
+    /// Save file in the templates directory
+    File(PathBuf),
+}
+
+/// A template offered by "New from Template"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectTemplate {
+    pub name: String,
+    pub source: TemplateSource,
+}
+
+impl ProjectTemplate {
+    /// Built-in template by name
+    pub fn builtin(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            source: TemplateSource::BuiltIn,
+        }
+    }
+}
+
+/// Turn a template name into a safe file name (without extension)
+pub fn template_file_stem(name: &str) -> String {
+    let stem: String = name.trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' { c } else { '_' })
+        .collect();
+    if stem.is_empty() {
+        "Untitled Template".to_string()
+    } else {
+        stem
+    }
+}
+
+/// Build the graph of a built-in template
+pub fn build_builtin_template(name: &str) -> Option<NodeGraph> {
+    let registry = NodeRegistry::default();
+    let mut graph = NodeGraph::new();
+    let add = |graph: &mut NodeGraph, node_type: &str, x: f32, y: f32| -> Option<NodeId> {
+        registry.create_node(node_type, Pos2::new(x, y)).map(|node| graph.add_node(node))
+    };
+
+    match name {
+        "USD Turntable" => {
+            // Stage → key light → viewport
+            let reader = add(&mut graph, "Data_UsdFileReader", 100.0, 150.0)?;
+            let light = add(&mut graph, "3D_DirectionalLight", 250.0, 150.0)?;
+            let viewport = add(&mut graph, "Viewport", 400.0, 150.0)?;
+            graph.add_connection_by_ids(reader, 0, light, 0).ok()?;
+            graph.add_connection_by_ids(light, 0, viewport, 0).ok()?;
+        }
+        "MaterialX Preview" => {
+            // Shader workspace next to a preview sphere shown in a viewport
+            graph.add_node(crate::nodes::materialx::create_shader_workspace_node(Pos2::new(100.0, 300.0)));
+            let sphere = add(&mut graph, "3D_Sphere", 100.0, 150.0)?;
+            let viewport = add(&mut graph, "Viewport", 400.0, 150.0)?;
+            graph.add_connection_by_ids(sphere, 0, viewport, 0).ok()?;
+        }
+        _ => return None,
+    }
+
+    graph.update_all_port_positions();
+    Some(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_file_stem_strips_path_separators() {
+        assert_eq!(template_file_stem("My/Template"), "My_Template");
+        assert_eq!(template_file_stem("  "), "Untitled Template");
+    }
+
+    #[test]
+    fn test_unknown_builtin_template() {
+        assert!(build_builtin_template("Nope").is_none());
+    }
+}
@@ -0,0 +1,85 @@
+//! Data type legend
+//!
+//! Lists the colors of the data types on the ports of the nodes in view, in
+//! the bottom-left corner of the canvas.
+
+use egui::{Align2, Color32, FontId, Painter, Pos2, Rect, Vec2};
+use std::collections::HashMap;
+use crate::nodes::{Node, NodeId};
+use crate::nodes::factory::DataType;
+use crate::theme;
+
+const ROW_HEIGHT: f32 = 16.0;
+const MARGIN: f32 = 10.0;
+
+/// Data types found on the ports of `nodes`, in legend order
+pub fn types_in_view(nodes: &HashMap<NodeId, Node>) -> Vec<DataType> {
+    let mut found = Vec::new();
+    for node in nodes.values() {
+        let inputs = (0..node.inputs.len()).map(|index| (true, index));
+        let outputs = (0..node.outputs.len()).map(|index| (false, index));
+        for (is_input, index) in inputs.chain(outputs) {
+            if let Some(data_type) = theme::port_data_type(&node.type_id, is_input, index) {
+                if !found.contains(&data_type) {
+                    found.push(data_type);
+                }
+            }
+        }
+    }
+    DataType::ALL.into_iter().filter(|data_type| found.contains(data_type)).collect()
+}
+
+/// Paint the legend for `nodes` into the bottom-left corner of `canvas`
+pub fn paint(painter: &Painter, canvas: Rect, nodes: &HashMap<NodeId, Node>) {
+    let types = types_in_view(nodes);
+    if types.is_empty() {
+        return;
+    }
+    let colors = theme::colors();
+    let text_color = if theme::theme().dark { Color32::from_gray(210) } else { Color32::from_gray(40) };
+    let font = FontId::proportional(12.0);
+    let width = types.iter()
+        .map(|data_type| painter.layout_no_wrap(data_type.name().to_string(), font.clone(), text_color).size().x)
+        .fold(0.0, f32::max) + 34.0;
+    let height = types.len() as f32 * ROW_HEIGHT + 12.0;
+    let frame = Rect::from_min_size(
+        Pos2::new(canvas.left() + MARGIN, canvas.bottom() - MARGIN - height),
+        Vec2::new(width, height),
+    );
+    painter.rect_filled(frame, 4.0, colors.panel_background.gamma_multiply(0.9));
+
+    for (row, data_type) in types.iter().enumerate() {
+        let center_y = frame.top() + 6.0 + ROW_HEIGHT * (row as f32 + 0.5);
+        painter.circle_filled(Pos2::new(frame.left() + 14.0, center_y), 5.0, data_type.color());
+        painter.text(
+            Pos2::new(frame.left() + 26.0, center_y),
+            Align2::LEFT_CENTER,
+            data_type.name(),
+            font.clone(),
+            text_color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legend_lists_types_in_view_once() {
+        let registry = crate::nodes::factory::NodeRegistry::default();
+        let mut nodes = HashMap::new();
+        for (id, node_type) in [(1, "Add"), (2, "Add")] {
+            let mut node = registry.create_node(node_type, Pos2::ZERO).unwrap();
+            node.id = id;
+            nodes.insert(id, node);
+        }
+        assert_eq!(types_in_view(&nodes), vec![DataType::Float]);
+
+        let mut unknown = Node::new(3, "Unknown", Pos2::ZERO);
+        unknown.add_input("in");
+        nodes.insert(3, unknown);
+        assert_eq!(types_in_view(&nodes), vec![DataType::Float]);
+        assert!(types_in_view(&HashMap::new()).is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+//! Local usage statistics
+//!
+//! Counts which node types get added, how long each type takes to cook and how
+//! long sessions last. Everything stays on this machine in
+//! `~/.nodle/usage_stats.json`; nothing is ever sent anywhere.
+
+use eframe::egui;
+use egui::RichText;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// How often statistics are written back to disk while the editor runs
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Rows shown per table in the statistics window
+const MAX_ROWS: usize = 15;
+
+/// Accumulated cook time of one node type
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CookTime {
+    pub cooks: u64,
+    pub total_seconds: f64,
+}
+
+impl CookTime {
+    pub fn average(&self) -> Duration {
+        if self.cooks == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(self.total_seconds / self.cooks as f64)
+        }
+    }
+}
+
+/// Persisted statistics across all sessions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StatsData {
+    /// Times each node type was added to a graph
+    #[serde(default)]
+    nodes_added: HashMap<String, u64>,
+    #[serde(default)]
+    cook_times: HashMap<String, CookTime>,
+    /// Editor sessions started, including the current one
+    #[serde(default)]
+    sessions: u64,
+    /// Length of all finished sessions
+    #[serde(default)]
+    previous_sessions_seconds: f64,
+}
+
+/// Usage statistics for this machine
+pub struct UsageStats {
+    data: StatsData,
+    path: Option<PathBuf>,
+    session_start: Instant,
+    last_saved: Instant,
+}
+
+impl UsageStats {
+    /// Load statistics from `~/.nodle/usage_stats.json` and start a new session
+    pub fn new() -> Self {
+        Self::with_path(dirs::home_dir().map(|home| home.join(".nodle").join("usage_stats.json")))
+    }
+
+    /// Load statistics from `path` (None keeps them in memory only)
+    pub fn with_path(path: Option<PathBuf>) -> Self {
+        let mut data: StatsData = path.as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        data.sessions += 1;
+        Self {
+            data,
+            path,
+            session_start: Instant::now(),
+            last_saved: Instant::now(),
+        }
+    }
+
+    /// Count a node of `type_id` being added to a graph
+    pub fn record_node_added(&mut self, type_id: &str) {
+        *self.data.nodes_added.entry(type_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Add one cook of `type_id` taking `duration`
+    pub fn record_cook(&mut self, type_id: &str, duration: Duration) {
+        let cook_time = self.data.cook_times.entry(type_id.to_string()).or_default();
+        cook_time.cooks += 1;
+        cook_time.total_seconds += duration.as_secs_f64();
+    }
+
+    /// Times a node of `type_id` was added to a graph
+    pub fn usage_count(&self, type_id: &str) -> u64 {
+        self.data.nodes_added.get(type_id).copied().unwrap_or(0)
+    }
+
+    /// Length of the current session
+    pub fn session_length(&self) -> Duration {
+        self.session_start.elapsed()
+    }
+
+    /// Node types by how often they were added, most used first
+    pub fn most_used_nodes(&self) -> Vec<(&str, u64)> {
+        let mut nodes: Vec<(&str, u64)> = self.data.nodes_added.iter()
+            .map(|(type_id, count)| (type_id.as_str(), *count))
+            .collect();
+        nodes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        nodes
+    }
+
+    /// Node types by average cook time, slowest first
+    pub fn cook_times(&self) -> Vec<(&str, CookTime)> {
+        let mut times: Vec<(&str, CookTime)> = self.data.cook_times.iter()
+            .map(|(type_id, time)| (type_id.as_str(), *time))
+            .collect();
+        times.sort_by(|a, b| b.1.average().cmp(&a.1.average()).then(a.0.cmp(b.0)));
+        times
+    }
+
+    /// Write the statistics to disk, counting the current session so far
+    pub fn save(&mut self) -> Result<(), String> {
+        self.last_saved = Instant::now();
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut snapshot = self.data.clone();
+        snapshot.previous_sessions_seconds += self.session_length().as_secs_f64();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Save if the last save is older than the save interval
+    pub fn save_periodically(&mut self) {
+        if self.last_saved.elapsed() >= SAVE_INTERVAL {
+            if let Err(e) = self.save() {
+                log::warn!("Failed to save usage statistics: {}", e);
+            }
+        }
+    }
+
+    /// Forget all statistics, starting over with the current session
+    pub fn reset(&mut self) {
+        self.data = StatsData { sessions: 1, ..StatsData::default() };
+        self.session_start = Instant::now();
+        if let Err(e) = self.save() {
+            log::warn!("Failed to save usage statistics: {}", e);
+        }
+    }
+
+    /// Render the statistics window
+    pub fn render(&mut self, ctx: &egui::Context, open: &mut bool) {
+        let mut reset = false;
+        egui::Window::new("Usage Statistics")
+            .open(open)
+            .default_size([360.0, 420.0])
+            .show(ctx, |ui| {
+                let total = Duration::from_secs_f64(self.data.previous_sessions_seconds) + self.session_length();
+                ui.label(format!("Current session: {}", format_duration(self.session_length())));
+                ui.label(format!("{} sessions, {} in total", self.data.sessions, format_duration(total)));
+                ui.label(RichText::new("Stored only on this computer").weak());
+                ui.separator();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.strong("Most used nodes");
+                    let nodes = self.most_used_nodes();
+                    if nodes.is_empty() {
+                        ui.label(RichText::new("No nodes added yet").weak());
+                    }
+                    egui::Grid::new("usage_stats_nodes").striped(true).show(ui, |ui| {
+                        for (type_id, count) in nodes.into_iter().take(MAX_ROWS) {
+                            ui.label(type_id);
+                            ui.label(count.to_string());
+                            ui.end_row();
+                        }
+                    });
+
+                    ui.add_space(8.0);
+                    ui.strong("Average cook time");
+                    let times = self.cook_times();
+                    if times.is_empty() {
+                        ui.label(RichText::new("Nothing cooked yet").weak());
+                    }
+                    egui::Grid::new("usage_stats_cooks").striped(true).show(ui, |ui| {
+                        for (type_id, time) in times.into_iter().take(MAX_ROWS) {
+                            ui.label(type_id);
+                            ui.label(format!("{:.2} ms", time.average().as_secs_f64() * 1000.0));
+                            ui.label(RichText::new(format!("× {}", time.cooks)).weak());
+                            ui.end_row();
+                        }
+                    });
+                });
+
+                ui.separator();
+                if ui.button("Reset Statistics").clicked() {
+                    reset = true;
+                }
+            });
+        if reset {
+            self.reset();
+        }
+    }
+}
+
+impl Default for UsageStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// "1h 05m", "12m 30s", "42s"
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!("{}h {:02}m", seconds / 3600, (seconds % 3600) / 60)
+    } else if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_persist_and_rank() {
+        let path = std::env::temp_dir().join(format!("nodle_usage_stats_test_{}.json", std::process::id()));
+        let mut stats = UsageStats::with_path(Some(path.clone()));
+        stats.record_node_added("3D_Sphere");
+        stats.record_node_added("3D_Sphere");
+        stats.record_node_added("3D_Cube");
+        stats.record_cook("3D_Cube", Duration::from_millis(2));
+        stats.record_cook("Data_UsdFileReader", Duration::from_millis(40));
+        stats.save().unwrap();
+
+        let reloaded = UsageStats::with_path(Some(path.clone()));
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(reloaded.data.sessions, 2);
+        assert_eq!(reloaded.most_used_nodes()[0], ("3D_Sphere", 2));
+        assert_eq!(reloaded.cook_times()[0].0, "Data_UsdFileReader");
+        assert_eq!(format_duration(Duration::from_secs(3725)), "1h 02m");
+    }
+}
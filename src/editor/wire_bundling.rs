@@ -0,0 +1,139 @@
+//! Wire bundling
+//!
+//! Connections running between the same two regions of the canvas are drawn
+//! as one thick wire with short fans to the individual ports. Hovering a
+//! bundle (or selecting one of its connections) draws its wires individually
+//! again. Named wires are never bundled, and the trunk is routed in the
+//! file's wire style like any other wire.
+
+use egui::{Color32, Painter, Pos2, Stroke, Vec2};
+use std::collections::HashMap;
+use crate::nodes::{Connection, Node, NodeId};
+use crate::nodes::wire_style::{WirePath, WireStyle};
+
+/// Size of the grid cells, in world units, that connections are grouped by
+const REGION_SIZE: f32 = 300.0;
+
+/// Fewest parallel connections drawn as a bundle
+pub const MIN_BUNDLE_SIZE: usize = 3;
+
+/// Length of the fans joining ports to the bundle, at zoom 1
+const FAN_LENGTH: f32 = 30.0;
+
+/// Screen distance from the bundle within which it expands
+const HOVER_DISTANCE: f32 = 10.0;
+
+const BUNDLE_COLOR: Color32 = Color32::from_rgb(120, 130, 140);
+
+/// Grid cell containing `position`
+fn region(position: Pos2) -> (i32, i32) {
+    ((position.x / REGION_SIZE).floor() as i32, (position.y / REGION_SIZE).floor() as i32)
+}
+
+/// Group connections whose source and target nodes lie in the same two regions.
+/// Returns the connection indices of each group with at least `MIN_BUNDLE_SIZE` members.
+pub fn bundle_connections(connections: &[Connection], nodes: &HashMap<NodeId, Node>) -> Vec<Vec<usize>> {
+    let mut groups: HashMap<((i32, i32), (i32, i32)), Vec<usize>> = HashMap::new();
+    // Named wires aren't drawn across the canvas, so there is nothing to bundle
+    for (index, connection) in connections.iter().enumerate().filter(|(_, connection)| !connection.named) {
+        if let (Some(from), Some(to)) = (nodes.get(&connection.from_node), nodes.get(&connection.to_node)) {
+            let key = (region(from.get_rect().center()), region(to.get_rect().center()));
+            groups.entry(key).or_default().push(index);
+        }
+    }
+    let mut bundles: Vec<Vec<usize>> = groups.into_values()
+        .filter(|group| group.len() >= MIN_BUNDLE_SIZE)
+        .collect();
+    // Stable order so drawing does not flicker between frames
+    bundles.sort_by_key(|bundle| bundle[0]);
+    bundles
+}
+
+/// A bundle ready to draw: screen positions of each wire's ends and of the trunk
+pub struct Bundle {
+    ends: Vec<(Pos2, Pos2)>,
+    trunk_start: Pos2,
+    trunk_end: Pos2,
+    trunk: WirePath,
+}
+
+impl Bundle {
+    /// Lay out a bundle from the screen positions of its wires' ends
+    pub fn new(ends: Vec<(Pos2, Pos2)>, zoom: f32, style: WireStyle) -> Self {
+        let count = ends.len().max(1) as f32;
+        let mean = |points: &mut dyn Iterator<Item = Pos2>| {
+            let sum = points.fold(Vec2::ZERO, |sum, point| sum + point.to_vec2());
+            (sum / count).to_pos2()
+        };
+        let trunk_start = mean(&mut ends.iter().map(|end| end.0)) + Vec2::new(0.0, FAN_LENGTH * zoom);
+        let trunk_end = mean(&mut ends.iter().map(|end| end.1)) - Vec2::new(0.0, FAN_LENGTH * zoom);
+        let trunk = WirePath::new(style, trunk_start, trunk_end, zoom);
+        Self { ends, trunk_start, trunk_end, trunk }
+    }
+
+    /// Whether the pointer is over the trunk, so the bundle should expand
+    pub fn is_hovered(&self, pointer: Option<Pos2>) -> bool {
+        pointer.is_some_and(|pointer| self.trunk.distance_to(pointer) <= HOVER_DISTANCE)
+    }
+
+    /// Draw the trunk, its fans and the number of wires it carries
+    pub fn paint(&self, painter: &Painter, zoom: f32) {
+        let fan_stroke = Stroke::new(1.5 * zoom, BUNDLE_COLOR);
+        for (from, to) in &self.ends {
+            painter.line_segment([*from, self.trunk_start], fan_stroke);
+            painter.line_segment([self.trunk_end, *to], fan_stroke);
+        }
+        let width = 2.0 * zoom * (self.ends.len() as f32).sqrt();
+        painter.add(self.trunk.shape(Stroke::new(width, BUNDLE_COLOR)));
+
+        let middle = self.trunk.point_at(0.5);
+        painter.circle_filled(middle, 9.0 * zoom, Color32::from_rgb(40, 40, 40));
+        painter.text(
+            middle,
+            egui::Align2::CENTER_CENTER,
+            self.ends.len().to_string(),
+            egui::FontId::proportional(10.0 * zoom),
+            Color32::WHITE,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_connections_between_regions_are_bundled() {
+        let mut nodes = HashMap::new();
+        let mut source = Node::new(0, "Source", Pos2::new(0.0, 0.0));
+        let mut target = Node::new(1, "Target", Pos2::new(0.0, 900.0));
+        let mut other = Node::new(2, "Other", Pos2::new(900.0, 900.0));
+        for index in 0..4 {
+            source.add_output(&format!("out{}", index));
+            target.add_input(&format!("in{}", index));
+        }
+        other.add_input("in");
+        nodes.insert(0, source);
+        nodes.insert(1, target);
+        nodes.insert(2, other);
+
+        let mut connections: Vec<Connection> = (0..4)
+            .map(|port| Connection::new(0, port, 1, port))
+            .collect();
+        connections.push(Connection::new(0, 0, 2, 0));
+
+        let bundles = bundle_connections(&connections, &nodes);
+        assert_eq!(bundles, vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_bundle_trunks_follow_the_wire_style() {
+        let ends = vec![(Pos2::new(0.0, 0.0), Pos2::new(400.0, 600.0)), (Pos2::new(20.0, 0.0), Pos2::new(420.0, 600.0))];
+        let curved = Bundle::new(ends.clone(), 1.0, WireStyle::Bezier);
+        let stepped = Bundle::new(ends, 1.0, WireStyle::Stepped);
+        // The stepped trunk crosses over halfway down; the curve doesn't pass that corner
+        let corner = Some(Pos2::new(20.0, 300.0));
+        assert!(stepped.is_hovered(corner));
+        assert!(!curved.is_hovered(corner));
+    }
+}
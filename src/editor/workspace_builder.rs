@@ -22,16 +22,18 @@ impl WorkspaceBuilder {
         graph: &mut NodeGraph,
     ) -> Option<NodeId> {
         // Check if this is a workspace node creation
-        if Self::is_workspace_type(node_type) {
+        if Self::is_workspace_type(node_type, workspace_manager) {
             Self::create_workspace_node(node_type, position, navigation, graph)
         } else {
             Self::create_regular_node(node_type, position, navigation, workspace_manager, graph)
         }
     }
 
-    /// Check if a node type represents a workspace
-    fn is_workspace_type(node_type: &str) -> bool {
+    /// Check if a node type represents a workspace (built-in or registered by a plugin)
+    fn is_workspace_type(node_type: &str, workspace_manager: &WorkspaceManager) -> bool {
         matches!(node_type, "WORKSPACE:2D" | "WORKSPACE:3D" | "WORKSPACE:MaterialX")
+            || node_type.strip_prefix("WORKSPACE:")
+                .is_some_and(|workspace_id| workspace_manager.get_workspace_by_id(workspace_id).is_some())
     }
 
     /// Create a workspace node with proper population
@@ -45,7 +47,8 @@ impl WorkspaceBuilder {
             "WORKSPACE:2D" => "2D",
             "WORKSPACE:3D" => "3D", 
             "WORKSPACE:MaterialX" => "MaterialX",
-            _ => return None,
+            // Plugin workspaces are entered by their id
+            _ => node_type.strip_prefix("WORKSPACE:")?,
         };
 
         let mut workspace_node = Node::new_workspace(0, workspace_type, position);
@@ -131,7 +134,7 @@ impl WorkspaceBuilder {
 
     /// Place a node in the appropriate graph based on the current view
     fn place_node_in_graph(
-        node: Node,
+        mut node: Node,
         navigation: &NavigationManager,
         graph: &mut NodeGraph,
     ) -> Option<NodeId> {
@@ -142,6 +145,8 @@ impl WorkspaceBuilder {
         let temp_node_id = node.id; // This should be 0 for plugin nodes
         
         let final_node_id = if navigation.is_root_view() {
+            // Titles are unique within a graph so expressions can refer to them
+            node.title = graph.unique_title(&node.title, None);
             // add_node returns the actual assigned ID
             let node_id = graph.add_node(node);
             debug!("WorkspaceBuilder: Added node {} to root graph", node_id);
@@ -150,6 +155,7 @@ impl WorkspaceBuilder {
             // Try to add to workspace internal graph
             if let Some(workspace_node) = graph.nodes.get_mut(&workspace_node_id) {
                 if let Some(internal_graph) = workspace_node.get_internal_graph_mut() {
+                    node.title = internal_graph.unique_title(&node.title, None);
                     // add_node returns the actual assigned ID
                     let node_id = internal_graph.add_node(node);
                     debug!("WorkspaceBuilder: Added node {} to workspace {} internal graph", node_id, workspace_node_id);
@@ -181,17 +187,12 @@ impl WorkspaceBuilder {
     fn update_plugin_instance_id(temp_id: NodeId, real_id: NodeId) {
         debug!("WorkspaceBuilder: Updating plugin instance ID from {} to {}", temp_id, real_id);
         
-        if let Some(plugin_manager) = crate::workspace::get_global_plugin_manager() {
-            if let Ok(mut manager) = plugin_manager.lock() {
-                // Move the plugin instance from temp ID to real ID
-                if let Some(plugin_instance) = manager.plugin_node_instances.remove(&temp_id) {
-                    manager.plugin_node_instances.insert(real_id, plugin_instance);
-                    info!("WorkspaceBuilder: Successfully moved plugin instance from {} to {}", temp_id, real_id);
-                } else {
-                    warn!("WorkspaceBuilder: No plugin instance found for temp ID {}", temp_id);
-                }
+        if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+            // Move the plugin instance from temp ID to real ID
+            if plugin_host.move_instance(temp_id, real_id) {
+                info!("WorkspaceBuilder: Successfully moved plugin instance from {} to {}", temp_id, real_id);
             } else {
-                error!("WorkspaceBuilder: Failed to lock plugin manager for ID update");
+                warn!("WorkspaceBuilder: No plugin instance found for temp ID {}", temp_id);
             }
         } else {
             error!("WorkspaceBuilder: No global plugin manager available for ID update");
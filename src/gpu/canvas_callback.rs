@@ -54,7 +54,7 @@ impl NodeRenderCallback {
                     false
                 };
                 
-                let port_instance = PortInstanceData::from_port(port_pos, 5.0, is_connecting, true);
+                let port_instance = PortInstanceData::from_port(port_pos, 5.0, is_connecting, true, crate::theme::theme().port_color(&node.type_id, true, port_idx));
                 port_instances.push(port_instance);
             }
             
@@ -68,7 +68,7 @@ impl NodeRenderCallback {
                     false
                 };
                 
-                let port_instance = PortInstanceData::from_port(port_pos, 5.0, is_connecting, false);
+                let port_instance = PortInstanceData::from_port(port_pos, 5.0, is_connecting, false, crate::theme::theme().port_color(&node.type_id, false, port_idx));
                 port_instances.push(port_instance);
             }
             
@@ -118,6 +118,9 @@ impl egui_wgpu::CallbackTrait for NodeRenderCallback {
     ) -> Vec<eframe::wgpu::CommandBuffer> {
         // Update GPU resources
         
+        // Share the device with compute shader nodes
+        super::compute::register_device(device, queue);
+        
         // Get or create the global renderer
         let mut renderer_lock = match GLOBAL_GPU_RENDERER.lock() {
             Ok(lock) => lock,
@@ -130,10 +133,10 @@ impl egui_wgpu::CallbackTrait for NodeRenderCallback {
             *renderer_lock = Some(super::GpuNodeRenderer::new(device, format));
         }
         
-        if let Some(renderer) = renderer_lock.as_ref() {
+        if let Some(renderer) = renderer_lock.as_mut() {
             renderer.update_uniforms(queue, &self.uniforms);
             renderer.update_node_instances(queue, &self.nodes);
-            renderer.update_port_instances(queue, &self.ports);
+            renderer.update_port_instances(device, queue, &self.ports);
             renderer.update_button_instances(queue, &self.buttons);
             renderer.update_flag_instances(queue, &self.flags);
         }
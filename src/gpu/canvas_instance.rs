@@ -5,6 +5,7 @@
 
 use egui::{Color32, Pos2, Vec2};
 use crate::nodes::{Node, NodeId};
+use crate::theme;
 use std::collections::{HashMap, HashSet};
 
 /// Button color variants for gradient colorization
@@ -91,15 +92,16 @@ impl NodeInstanceData {
     pub fn from_node(node: &Node, selected: bool, _zoom: f32) -> Self {
         let rect = node.get_rect();
         
-        // All nodes use same colors - no special cases
-        let (bevel_top, bevel_bottom) = (Color32::from_rgb(166, 166, 166), Color32::from_rgb(38, 38, 38));
-        let (background_top, background_bottom) = (Color32::from_rgb(127, 127, 127), Color32::from_rgb(64, 64, 64));
+        // Theme colors, with the node's tag or category color for the body
+        let colors = theme::colors();
+        let (bevel_top, bevel_bottom) = (colors.node_bevel_light, colors.node_bevel_dark);
+        let (background_top, background_bottom) = node.body_gradient();
         
-        // BORDER color - blue if selected, dark gray otherwise
+        // BORDER color - selection color if selected, node border otherwise
         let border_color = if selected {
-            Color32::from_rgb(100, 150, 255) // Blue selection
+            colors.selection_blue
         } else {
-            Color32::from_rgb(64, 64, 64)    // 0.25 grey unselected
+            colors.node_border
         };
         
         // Use the original node size - bevel layer matches node size exactly
@@ -128,20 +130,15 @@ impl NodeInstanceData {
 }
 
 impl PortInstanceData {
-    pub fn from_port(position: Pos2, radius: f32, is_connecting: bool, is_input: bool) -> Self {
+    pub fn from_port(position: Pos2, radius: f32, is_connecting: bool, is_input: bool, background_color: Color32) -> Self {
+        let colors = theme::colors();
         let border_color = if is_connecting {
-            Color32::from_rgb(100, 150, 255) // Blue when connecting
+            colors.selection_blue // Blue when connecting
         } else {
-            Color32::from_rgb(64, 64, 64)    // Dark grey normally
+            colors.node_border    // Dark grey normally
         };
         
-        let bevel_color = Color32::from_rgb(38, 38, 38); // Dark grey bevel
-        
-        let background_color = if is_input {
-            Color32::from_rgb(90, 160, 120)  // Brighter green for input ports
-        } else {
-            Color32::from_rgb(160, 90, 90)   // Brighter red for output ports
-        };
+        let bevel_color = colors.node_bevel_dark; // Dark grey bevel
         
         Self {
             position: [position.x, position.y],
@@ -470,7 +467,7 @@ impl GpuInstanceManager {
                     }
                 }
                 
-                let port_instance = PortInstanceData::from_port(port.position, 5.0, is_connecting, true);
+                let port_instance = PortInstanceData::from_port(port.position, 5.0, is_connecting, true, theme::theme().port_color(&node.type_id, true, port_idx));
                 self.port_instances.push(port_instance);
             }
             
@@ -510,7 +507,7 @@ impl GpuInstanceManager {
                     }
                 }
                 
-                let port_instance = PortInstanceData::from_port(port.position, 5.0, is_connecting, false);
+                let port_instance = PortInstanceData::from_port(port.position, 5.0, is_connecting, false, theme::theme().port_color(&node.type_id, false, port_idx));
                 self.port_instances.push(port_instance);
             }
             
@@ -594,14 +594,23 @@ impl GpuNodeRenderer {
         }
     }
     
-    pub fn update_port_instances(&self, queue: &eframe::wgpu::Queue, instances: &[PortInstanceData]) {
-        if instances.len() <= self.max_port_instances {
-            queue.write_buffer(
-                &self.port_instance_buffer,
-                0,
-                bytemuck::cast_slice(instances),
-            );
+    /// Upload the port instances, reallocating the buffer when variadic inputs
+    /// have grown the port count past its capacity
+    pub fn update_port_instances(&mut self, device: &eframe::wgpu::Device, queue: &eframe::wgpu::Queue, instances: &[PortInstanceData]) {
+        if instances.len() > self.max_port_instances {
+            self.max_port_instances = instances.len().next_power_of_two();
+            self.port_instance_buffer = device.create_buffer(&eframe::wgpu::BufferDescriptor {
+                label: Some("Port Instance Buffer"),
+                size: (self.max_port_instances * std::mem::size_of::<PortInstanceData>()) as u64,
+                usage: eframe::wgpu::BufferUsages::VERTEX | eframe::wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
         }
+        queue.write_buffer(
+            &self.port_instance_buffer,
+            0,
+            bytemuck::cast_slice(instances),
+        );
     }
     
     pub fn update_button_instances(&self, queue: &eframe::wgpu::Queue, instances: &[ButtonInstanceData]) {
@@ -640,7 +649,7 @@ impl GpuNodeRenderer {
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
         render_pass.set_vertex_buffer(1, self.port_instance_buffer.slice(..));
         render_pass.set_index_buffer(self.index_buffer.slice(..), eframe::wgpu::IndexFormat::Uint16);
-        render_pass.draw_indexed(0..6, 0, 0..instance_count);
+        render_pass.draw_indexed(0..6, 0, 0..instance_count.min(self.max_port_instances as u32));
     }
     
     pub fn render_buttons(&self, render_pass: &mut eframe::wgpu::RenderPass, instance_count: u32) {
@@ -0,0 +1,213 @@
+//! GPU compute dispatch for user-authored WGSL
+//!
+//! The editor's wgpu device only exists inside egui paint callbacks, so the canvas
+//! callback hands a clone of it to [`register_device`] the first time it prepares.
+//! Node execution can then run compute work on that same device via [`dispatch`].
+//!
+//! ## Binding convention
+//!
+//! Every input buffer is bound as `var<storage, read>` at `@group(0) @binding(i)` in
+//! input order, and the output is bound as `var<storage, read_write>` at the binding
+//! directly after the last input. All buffers are arrays of `f32`.
+
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use eframe::wgpu::util::DeviceExt;
+use once_cell::sync::Lazy;
+
+/// Device and queue shared with the egui renderer
+#[derive(Clone)]
+pub struct GpuComputeContext {
+    pub device: eframe::wgpu::Device,
+    pub queue: eframe::wgpu::Queue,
+}
+
+/// Global compute context, populated by the first canvas paint callback
+pub static GLOBAL_COMPUTE_CONTEXT: Lazy<Arc<Mutex<Option<GpuComputeContext>>>> = Lazy::new(|| {
+    Arc::new(Mutex::new(None))
+});
+
+/// Store the renderer's device for compute use (no-op once registered)
+pub fn register_device(device: &eframe::wgpu::Device, queue: &eframe::wgpu::Queue) {
+    if let Ok(mut context) = GLOBAL_COMPUTE_CONTEXT.lock() {
+        if context.is_none() {
+            *context = Some(GpuComputeContext {
+                device: device.clone(),
+                queue: queue.clone(),
+            });
+        }
+    }
+}
+
+/// Check whether a device has been registered yet
+pub fn is_available() -> bool {
+    GLOBAL_COMPUTE_CONTEXT.lock().map(|c| c.is_some()).unwrap_or(false)
+}
+
+/// A single compute dispatch request
+#[derive(Debug, Clone)]
+pub struct ComputeRequest<'a> {
+    /// WGSL source
+    pub source: &'a str,
+    /// Compute entry point name
+    pub entry_point: &'a str,
+    /// Read-only input buffers, bound in order starting at binding 0
+    pub inputs: &'a [Vec<f32>],
+    /// Number of f32 elements in the output buffer
+    pub output_len: usize,
+    /// Workgroup counts (x, y, z)
+    pub workgroups: [u32; 3],
+}
+
+/// Compile and run a compute shader, returning the contents of the output buffer
+pub fn dispatch(request: &ComputeRequest) -> Result<Vec<f32>, String> {
+    let context = GLOBAL_COMPUTE_CONTEXT.lock()
+        .map_err(|_| "GPU compute context lock poisoned".to_string())?
+        .clone()
+        .ok_or_else(|| "GPU device not initialized yet".to_string())?;
+    let device = &context.device;
+
+    if request.output_len == 0 {
+        return Err("Output buffer length must be greater than zero".to_string());
+    }
+
+    device.push_error_scope(eframe::wgpu::ErrorFilter::Validation);
+
+    let module = device.create_shader_module(eframe::wgpu::ShaderModuleDescriptor {
+        label: Some("Compute Shader Node"),
+        source: eframe::wgpu::ShaderSource::Wgsl(request.source.into()),
+    });
+
+    // Bind group layout: inputs read-only, output read-write
+    let storage_entry = |binding: u32, read_only: bool| eframe::wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: eframe::wgpu::ShaderStages::COMPUTE,
+        ty: eframe::wgpu::BindingType::Buffer {
+            ty: eframe::wgpu::BufferBindingType::Storage { read_only },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    };
+    let output_binding = request.inputs.len() as u32;
+    let mut layout_entries: Vec<_> = (0..output_binding).map(|i| storage_entry(i, true)).collect();
+    layout_entries.push(storage_entry(output_binding, false));
+
+    let bind_group_layout = device.create_bind_group_layout(&eframe::wgpu::BindGroupLayoutDescriptor {
+        label: Some("Compute Shader Node Bind Group Layout"),
+        entries: &layout_entries,
+    });
+    let pipeline_layout = device.create_pipeline_layout(&eframe::wgpu::PipelineLayoutDescriptor {
+        label: Some("Compute Shader Node Pipeline Layout"),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    });
+    let pipeline = device.create_compute_pipeline(&eframe::wgpu::ComputePipelineDescriptor {
+        label: Some("Compute Shader Node Pipeline"),
+        layout: Some(&pipeline_layout),
+        module: &module,
+        entry_point: Some(request.entry_point),
+        compilation_options: Default::default(),
+        cache: None,
+    });
+
+    // Storage buffers must be non-empty, so pad empty inputs with a single zero
+    let input_buffers: Vec<eframe::wgpu::Buffer> = request.inputs.iter().enumerate().map(|(i, data)| {
+        let padded;
+        let contents: &[f32] = if data.is_empty() {
+            padded = [0.0f32];
+            &padded
+        } else {
+            data
+        };
+        device.create_buffer_init(&eframe::wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("Compute Input {}", i)),
+            contents: bytemuck::cast_slice(contents),
+            usage: eframe::wgpu::BufferUsages::STORAGE,
+        })
+    }).collect();
+
+    let output_size = (request.output_len * std::mem::size_of::<f32>()) as u64;
+    let output_buffer = device.create_buffer(&eframe::wgpu::BufferDescriptor {
+        label: Some("Compute Output"),
+        size: output_size,
+        usage: eframe::wgpu::BufferUsages::STORAGE | eframe::wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let staging_buffer = device.create_buffer(&eframe::wgpu::BufferDescriptor {
+        label: Some("Compute Staging"),
+        size: output_size,
+        usage: eframe::wgpu::BufferUsages::MAP_READ | eframe::wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let mut bind_entries: Vec<eframe::wgpu::BindGroupEntry> = input_buffers.iter().enumerate()
+        .map(|(i, buffer)| eframe::wgpu::BindGroupEntry {
+            binding: i as u32,
+            resource: buffer.as_entire_binding(),
+        })
+        .collect();
+    bind_entries.push(eframe::wgpu::BindGroupEntry {
+        binding: output_binding,
+        resource: output_buffer.as_entire_binding(),
+    });
+    let bind_group = device.create_bind_group(&eframe::wgpu::BindGroupDescriptor {
+        label: Some("Compute Shader Node Bind Group"),
+        layout: &bind_group_layout,
+        entries: &bind_entries,
+    });
+
+    // Surface shader compilation and binding mismatches before submitting
+    if let Some(error) = block_on_ready(device.pop_error_scope()).flatten() {
+        return Err(format!("Shader validation failed: {}", error));
+    }
+
+    let mut encoder = device.create_command_encoder(&eframe::wgpu::CommandEncoderDescriptor {
+        label: Some("Compute Shader Node Encoder"),
+    });
+    {
+        let mut pass = encoder.begin_compute_pass(&eframe::wgpu::ComputePassDescriptor {
+            label: Some("Compute Shader Node Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        let [x, y, z] = request.workgroups;
+        pass.dispatch_workgroups(x.max(1), y.max(1), z.max(1));
+    }
+    encoder.copy_buffer_to_buffer(&output_buffer, 0, &staging_buffer, 0, output_size);
+    context.queue.submit(Some(encoder.finish()));
+
+    // Read the output back synchronously
+    let slice = staging_buffer.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(eframe::wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device.poll(eframe::wgpu::Maintain::Wait);
+    receiver.recv()
+        .map_err(|_| "GPU readback was cancelled".to_string())?
+        .map_err(|e| format!("Failed to map output buffer: {}", e))?;
+
+    let result = bytemuck::cast_slice::<u8, f32>(&slice.get_mapped_range()).to_vec();
+    staging_buffer.unmap();
+    Ok(result)
+}
+
+/// Poll a future that native wgpu resolves immediately (e.g. `pop_error_scope`)
+fn block_on_ready<F: std::future::Future>(future: F) -> Option<F::Output> {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker { noop_raw_waker() }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    match future.as_mut().poll(&mut context) {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
+}
@@ -0,0 +1,140 @@
+//! MaterialX preview ball rendering callback
+//!
+//! Draws a sphere or plane shaded with a material into an egui rect. The whole
+//! image comes from one fragment shader, so the preview needs no meshes: only
+//! a small uniform block per preview, kept in egui's callback resources.
+
+use bytemuck::{Pod, Zeroable};
+use egui_wgpu::CallbackTrait;
+use std::collections::HashMap;
+use super::config::GraphicsConfig;
+
+/// Material parameters as the preview shader reads them
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct MaterialPreviewUniforms {
+    /// First pattern color; w is the pattern kind (0 solid, 1 checker, 2 noise)
+    pub base_a: [f32; 4],
+    /// Second pattern color; w is the pattern scale
+    pub base_b: [f32; 4],
+    /// Emission color; w is the opacity
+    pub emission: [f32; 4],
+    /// Metallic, roughness, shape (0 sphere, 1 plane), unused
+    pub params: [f32; 4],
+}
+
+/// Pipeline and per-preview uniforms, stored in egui's callback resources
+struct PreviewResources {
+    pipeline: eframe::wgpu::RenderPipeline,
+    bind_group_layout: eframe::wgpu::BindGroupLayout,
+    previews: HashMap<u64, (eframe::wgpu::Buffer, eframe::wgpu::BindGroup)>,
+}
+
+impl PreviewResources {
+    fn new(device: &eframe::wgpu::Device) -> Self {
+        let shader = device.create_shader_module(eframe::wgpu::ShaderModuleDescriptor {
+            label: Some("Material Preview Shader"),
+            source: eframe::wgpu::ShaderSource::Wgsl(include_str!("shaders/material_preview.wgsl").into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&eframe::wgpu::BindGroupLayoutDescriptor {
+            label: Some("Material Preview Bind Group Layout"),
+            entries: &[eframe::wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: eframe::wgpu::ShaderStages::FRAGMENT,
+                ty: eframe::wgpu::BindingType::Buffer {
+                    ty: eframe::wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&eframe::wgpu::PipelineLayoutDescriptor {
+            label: Some("Material Preview Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let config = GraphicsConfig::global();
+        let pipeline = device.create_render_pipeline(&eframe::wgpu::RenderPipelineDescriptor {
+            label: Some("Material Preview Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: eframe::wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(eframe::wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(config.color_target_state())],
+                compilation_options: Default::default(),
+            }),
+            primitive: eframe::wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: config.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+        Self { pipeline, bind_group_layout, previews: HashMap::new() }
+    }
+}
+
+/// Paint callback drawing one preview ball
+pub struct MaterialPreviewCallback {
+    /// Identifies the preview's uniform buffer across frames, e.g. the node id
+    pub key: u64,
+    pub uniforms: MaterialPreviewUniforms,
+}
+
+impl CallbackTrait for MaterialPreviewCallback {
+    fn prepare(
+        &self,
+        device: &eframe::wgpu::Device,
+        queue: &eframe::wgpu::Queue,
+        _screen_descriptor: &egui_wgpu::ScreenDescriptor,
+        _egui_encoder: &mut eframe::wgpu::CommandEncoder,
+        callback_resources: &mut egui_wgpu::CallbackResources,
+    ) -> Vec<eframe::wgpu::CommandBuffer> {
+        if !callback_resources.contains::<PreviewResources>() {
+            callback_resources.insert(PreviewResources::new(device));
+        }
+        let Some(resources) = callback_resources.get_mut::<PreviewResources>() else {
+            return Vec::new();
+        };
+        let layout = &resources.bind_group_layout;
+        let (buffer, _) = resources.previews.entry(self.key).or_insert_with(|| {
+            let buffer = device.create_buffer(&eframe::wgpu::BufferDescriptor {
+                label: Some("Material Preview Uniforms"),
+                size: std::mem::size_of::<MaterialPreviewUniforms>() as u64,
+                usage: eframe::wgpu::BufferUsages::UNIFORM | eframe::wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = device.create_bind_group(&eframe::wgpu::BindGroupDescriptor {
+                label: Some("Material Preview Bind Group"),
+                layout,
+                entries: &[eframe::wgpu::BindGroupEntry { binding: 0, resource: buffer.as_entire_binding() }],
+            });
+            (buffer, bind_group)
+        });
+        queue.write_buffer(buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        Vec::new()
+    }
+
+    fn paint(
+        &self,
+        _info: egui::PaintCallbackInfo,
+        render_pass: &mut eframe::wgpu::RenderPass<'static>,
+        callback_resources: &egui_wgpu::CallbackResources,
+    ) {
+        let Some(resources) = callback_resources.get::<PreviewResources>() else {
+            return;
+        };
+        let Some((_, bind_group)) = resources.previews.get(&self.key) else {
+            return;
+        };
+        render_pass.set_pipeline(&resources.pipeline);
+        render_pass.set_bind_group(0, bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
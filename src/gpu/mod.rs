@@ -14,6 +14,8 @@
 //! - [`canvas_callback`] - egui paint callback integration for canvas
 //! - [`viewport_3d_rendering`] - 3D viewport renderer and pipeline management
 //! - [`viewport_3d_callback`] - egui paint callback integration for 3D viewport
+//! - [`compute`] - Compute shader dispatch on the shared editor device
+//! - [`material_preview_callback`] - MaterialX preview ball drawn in parameter panels
 //! - `shaders/` - WGSL shader files for nodes and ports
 
 pub mod config;
@@ -22,6 +24,8 @@ pub mod canvas_rendering;
 pub mod viewport_3d_rendering;
 pub mod canvas_callback;
 pub mod viewport_3d_callback;
+pub mod compute;
+pub mod material_preview_callback;
 
 // Config re-exports removed - only used internally
 pub use canvas_instance::{NodeInstanceData, PortInstanceData, ButtonInstanceData, FlagInstanceData, Uniforms, GpuInstanceManager};
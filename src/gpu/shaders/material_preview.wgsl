@@ -0,0 +1,127 @@
+// MaterialX preview ball: a sphere or plane shaded with the evaluated material
+
+struct Uniforms {
+    // rgb, w = pattern kind (0 solid, 1 checker, 2 noise)
+    base_a: vec4<f32>,
+    // rgb, w = pattern scale
+    base_b: vec4<f32>,
+    // rgb, w = opacity
+    emission: vec4<f32>,
+    // metallic, roughness, shape (0 sphere, 1 plane), unused
+    params: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+const PI: f32 = 3.14159265;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    // One triangle covering the whole callback rect
+    let corner = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(corner * 2.0 - 1.0, 0.0, 1.0);
+    out.uv = corner * 2.0 - 1.0;
+    return out;
+}
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
+}
+
+fn value_noise(p: vec2<f32>) -> f32 {
+    let cell = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let a = hash(cell);
+    let b = hash(cell + vec2<f32>(1.0, 0.0));
+    let c = hash(cell + vec2<f32>(0.0, 1.0));
+    let d = hash(cell + vec2<f32>(1.0, 1.0));
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+fn base_color(uv: vec2<f32>) -> vec3<f32> {
+    let kind = uniforms.base_a.w;
+    let scale = uniforms.base_b.w;
+    if (kind > 1.5) {
+        var value = 0.0;
+        var amplitude = 0.5;
+        var p = uv * scale;
+        for (var octave = 0; octave < 4; octave++) {
+            value += value_noise(p) * amplitude;
+            p *= 2.0;
+            amplitude *= 0.5;
+        }
+        return mix(uniforms.base_a.rgb, uniforms.base_b.rgb, value / 0.9375);
+    }
+    if (kind > 0.5) {
+        let cell = floor(uv * scale);
+        let odd = (cell.x + cell.y) - 2.0 * floor((cell.x + cell.y) * 0.5);
+        return select(uniforms.base_a.rgb, uniforms.base_b.rgb, odd > 0.5);
+    }
+    return uniforms.base_a.rgb;
+}
+
+fn background(uv: vec2<f32>) -> vec3<f32> {
+    let cell = floor(uv * 6.0);
+    let odd = (cell.x + cell.y) - 2.0 * floor((cell.x + cell.y) * 0.5);
+    return select(vec3<f32>(0.16), vec3<f32>(0.22), odd > 0.5);
+}
+
+fn shade(normal: vec3<f32>, surface_uv: vec2<f32>) -> vec3<f32> {
+    let metallic = clamp(uniforms.params.x, 0.0, 1.0);
+    let roughness = clamp(uniforms.params.y, 0.04, 1.0);
+    let albedo = base_color(surface_uv);
+    let view = vec3<f32>(0.0, 0.0, 1.0);
+    let light = normalize(vec3<f32>(-0.5, 0.7, 0.8));
+    let half_vector = normalize(light + view);
+
+    let n_dot_l = max(dot(normal, light), 0.0);
+    let n_dot_v = max(dot(normal, view), 0.001);
+    let n_dot_h = max(dot(normal, half_vector), 0.0);
+
+    // GGX specular with Schlick Fresnel
+    let alpha = roughness * roughness;
+    let denominator = n_dot_h * n_dot_h * (alpha * alpha - 1.0) + 1.0;
+    let distribution = alpha * alpha / (PI * denominator * denominator);
+    let k = alpha * 0.5;
+    let geometry = n_dot_l / (n_dot_l * (1.0 - k) + k) * n_dot_v / (n_dot_v * (1.0 - k) + k);
+    let f0 = mix(vec3<f32>(0.04), albedo, metallic);
+    let fresnel = f0 + (1.0 - f0) * pow(1.0 - max(dot(half_vector, view), 0.0), 5.0);
+    let specular = distribution * geometry * fresnel / (4.0 * n_dot_l * n_dot_v + 0.001);
+
+    let diffuse = albedo * (1.0 - metallic) / PI;
+    let direct = (diffuse * (1.0 - fresnel) + specular) * n_dot_l * 3.0;
+    // Sky above, ground below
+    let ambient = mix(vec3<f32>(0.08, 0.07, 0.06), vec3<f32>(0.25, 0.28, 0.32), normal.y * 0.5 + 0.5);
+    return direct + ambient * mix(albedo * (1.0 - metallic), f0, 0.5);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = vec2<f32>(in.uv.x, -in.uv.y);
+    let back = background(uv);
+    var color: vec3<f32>;
+    if (uniforms.params.z > 0.5) {
+        // Plane tilted slightly towards the light
+        color = shade(normalize(vec3<f32>(0.0, 0.3, 1.0)), uv * 0.5 + 0.5);
+    } else {
+        let radius = 0.9;
+        let r2 = dot(uv, uv);
+        if (r2 > radius * radius) {
+            return vec4<f32>(pow(back, vec3<f32>(1.0 / 2.2)), 1.0);
+        }
+        let normal = normalize(vec3<f32>(uv, sqrt(radius * radius - r2)));
+        let surface_uv = vec2<f32>(atan2(normal.x, normal.z) / (2.0 * PI) + 0.5, acos(clamp(normal.y, -1.0, 1.0)) / PI);
+        color = shade(normal, surface_uv);
+    }
+    color = mix(back, color, clamp(uniforms.emission.w, 0.0, 1.0)) + uniforms.emission.rgb;
+    // The target is not sRGB, so encode here
+    return vec4<f32>(pow(clamp(color, vec3<f32>(0.0), vec3<f32>(1.0)), vec3<f32>(1.0 / 2.2)), 1.0);
+}
@@ -4,9 +4,14 @@ struct Uniforms {
     view_proj: mat4x4<f32>,
     model: mat4x4<f32>,
     camera_pos: vec3<f32>,
-    _padding: f32,
+    display_mode: u32,
 }
 
+// Display modes, matching DisplayMode in src/viewport/types.rs
+const DISPLAY_NORMALS: u32 = 3u;
+const DISPLAY_PRIM_ID: u32 = 4u;
+const DISPLAY_UV_CHECKER: u32 = 5u;
+
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) normal: vec3<f32>,
@@ -20,6 +25,7 @@ struct VertexOutput {
     @location(1) world_normal: vec3<f32>,
     @location(2) uv: vec2<f32>,
     @location(3) vertex_color: vec3<f32>,
+    @location(4) @interpolate(flat) prim_id: u32,
 }
 
 struct FragmentOutput {
@@ -30,7 +36,7 @@ struct FragmentOutput {
 var<uniform> uniforms: Uniforms;
 
 @vertex
-fn vs_main(model: VertexInput) -> VertexOutput {
+fn vs_main(model: VertexInput, @builtin(instance_index) prim_id: u32) -> VertexOutput {
     var out: VertexOutput;
     
     // Transform position to world space
@@ -50,6 +56,8 @@ fn vs_main(model: VertexInput) -> VertexOutput {
     
     out.uv = model.uv;
     out.vertex_color = model.color;
+    // Each prim is drawn as its own instance, so the instance index identifies it
+    out.prim_id = prim_id;
     
     return out;
 }
@@ -58,8 +66,20 @@ fn vs_main(model: VertexInput) -> VertexOutput {
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
     
+    if (uniforms.display_mode == DISPLAY_NORMALS) {
+        out.color = vec4<f32>(normalize(in.world_normal) * 0.5 + 0.5, 1.0);
+        return out;
+    }
+    if (uniforms.display_mode == DISPLAY_PRIM_ID) {
+        out.color = vec4<f32>(prim_color(in.prim_id), 1.0);
+        return out;
+    }
+    
     // Use vertex color as base color with minimal lighting
-    let base_color = in.vertex_color;
+    var base_color = in.vertex_color;
+    if (uniforms.display_mode == DISPLAY_UV_CHECKER) {
+        base_color = uv_checker(in.uv);
+    }
     let light_dir = normalize(vec3<f32>(1.0, 1.0, 1.0));
     let view_dir = normalize(uniforms.camera_pos - in.world_position);
     
@@ -74,4 +94,22 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     out.color = vec4<f32>(final_color, 1.0);
     
     return out;
-}
\ No newline at end of file
+}
+
+// Distinct, stable color per prim index (integer hash to hue-like RGB)
+fn prim_color(prim_id: u32) -> vec3<f32> {
+    var h = prim_id * 747796405u + 2891336453u;
+    h = ((h >> ((h >> 28u) + 4u)) ^ h) * 277803737u;
+    h = (h >> 22u) ^ h;
+    let rgb = vec3<f32>(f32(h & 255u), f32((h >> 8u) & 255u), f32((h >> 16u) & 255u)) / 255.0;
+    // Keep colors away from black so prims stay readable
+    return rgb * 0.75 + 0.25;
+}
+
+// 8x8 checkerboard over the unit UV square, with a red/green tint showing the U and V directions
+fn uv_checker(uv: vec2<f32>) -> vec3<f32> {
+    let cell = vec2<i32>(floor(uv * 8.0));
+    let checker = f32((cell.x + cell.y) & 1);
+    let tint = vec3<f32>(fract(uv.x), fract(uv.y), 0.5);
+    return mix(vec3<f32>(0.15), vec3<f32>(0.9), checker) * mix(vec3<f32>(1.0), tint, 0.35);
+}
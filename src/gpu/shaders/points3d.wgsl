@@ -0,0 +1,75 @@
+// 3D Point Cloud Shader for Nodle 3D Viewport
+// Each point is an instance drawn as a round screen-space dot of a fixed pixel size
+
+struct PointUniforms {
+    view_proj: mat4x4<f32>,
+    // rgb = color in Solid mode, w = PointColorMode (0 source, 1 solid, 2 height)
+    solid_color: vec4<f32>,
+    viewport_size: vec2<f32>,
+    point_size: f32,
+    _padding: f32,
+    // Lowest and highest point, for the Height ramp
+    height_range: vec2<f32>,
+    _padding2: vec2<f32>,
+}
+
+// Matches PointColorMode in src/viewport/types.rs
+const COLOR_SOLID: f32 = 1.0;
+const COLOR_HEIGHT: f32 = 2.0;
+
+struct PointInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    // Position within the dot, -1..1 across
+    @location(1) offset: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: PointUniforms;
+
+// Blue through green and yellow to red
+fn height_ramp(t: f32) -> vec3<f32> {
+    let x = clamp(t, 0.0, 1.0) * 4.0;
+    return clamp(vec3<f32>(x - 1.5, 1.5 - abs(x - 2.0), 2.5 - x), vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+@vertex
+fn vs_main(point: PointInput, @builtin(vertex_index) index: u32) -> VertexOutput {
+    // Two triangles around the point
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, -1.0), vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, 1.0), vec2<f32>(-1.0, 1.0),
+    );
+    let corner = corners[index];
+
+    var out: VertexOutput;
+    let clip = uniforms.view_proj * vec4<f32>(point.position, 1.0);
+    // One pixel is 2 / viewport_size in normalized device coordinates; the dot's radius is half its size
+    let offset = corner * uniforms.point_size / max(uniforms.viewport_size, vec2<f32>(1.0));
+    out.clip_position = clip + vec4<f32>(offset * clip.w, 0.0, 0.0);
+    out.offset = corner;
+
+    let mode = uniforms.solid_color.w;
+    if (mode == COLOR_SOLID) {
+        out.color = uniforms.solid_color.rgb;
+    } else if (mode == COLOR_HEIGHT) {
+        let span = max(uniforms.height_range.y - uniforms.height_range.x, 0.0001);
+        out.color = height_ramp((point.position.y - uniforms.height_range.x) / span);
+    } else {
+        out.color = point.color;
+    }
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (dot(in.offset, in.offset) > 1.0) {
+        discard;
+    }
+    return vec4<f32>(in.color, 1.0);
+}
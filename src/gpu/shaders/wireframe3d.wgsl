@@ -4,9 +4,12 @@ struct Uniforms {
     view_proj: mat4x4<f32>,
     model: mat4x4<f32>,
     camera_pos: vec3<f32>,
-    _padding: f32,
+    display_mode: u32,
 }
 
+// Matches DisplayMode::WireframeOnShaded in src/viewport/types.rs
+const DISPLAY_WIREFRAME_ON_SHADED: u32 = 1u;
+
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) normal: vec3<f32>,
@@ -16,6 +19,7 @@ struct VertexInput {
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
+    @location(0) @interpolate(flat) highlighted: u32,
 }
 
 struct FragmentOutput {
@@ -26,12 +30,13 @@ struct FragmentOutput {
 var<uniform> uniforms: Uniforms;
 
 @vertex
-fn vs_main(model: VertexInput) -> VertexOutput {
+fn vs_main(model: VertexInput, @builtin(instance_index) highlighted: u32) -> VertexOutput {
     var out: VertexOutput;
     
     // Transform position to world space then to clip space
     let world_position = uniforms.model * vec4<f32>(model.position, 1.0);
     out.clip_position = uniforms.view_proj * world_position;
+    out.highlighted = highlighted;
     
     return out;
 }
@@ -40,8 +45,14 @@ fn vs_main(model: VertexInput) -> VertexOutput {
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
     
-    // Simple white wireframe
-    out.color = vec4<f32>(0.8, 0.8, 0.8, 1.0);
+    // Selected prims in orange, otherwise light gray, dark when drawn over shaded surfaces
+    if (in.highlighted != 0u) {
+        out.color = vec4<f32>(1.0, 0.6, 0.1, 1.0);
+    } else if (uniforms.display_mode == DISPLAY_WIREFRAME_ON_SHADED) {
+        out.color = vec4<f32>(0.1, 0.1, 0.1, 1.0);
+    } else {
+        out.color = vec4<f32>(0.8, 0.8, 0.8, 1.0);
+    }
     
     return out;
 }
\ No newline at end of file
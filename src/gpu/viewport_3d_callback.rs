@@ -127,6 +127,43 @@ impl ViewportRenderCallback {
         self.camera.frame_bounds(scene_bounds, selected_bounds);
     }
     
+    /// Prim path of the nearest mesh under a point given in 0..1 viewport coordinates
+    pub fn pick_prim(&self, screen_x: f32, screen_y: f32) -> Option<String> {
+        let viewport_data = self.viewport_data.as_ref()?;
+        let (ray_origin, ray_direction) = self.camera.screen_to_ray(screen_x, screen_y);
+        let vertex = |vertices: &[f32], index: u32| {
+            let i = index as usize * 3;
+            vertices.get(i..i + 3).map(glam::Vec3::from_slice)
+        };
+        
+        let mut closest: Option<(f32, &str)> = None;
+        for mesh in viewport_data.scene.meshes.iter().filter(|mesh| !mesh.prim_path.is_empty()) {
+            for triangle in mesh.indices.chunks_exact(3) {
+                let (Some(v0), Some(v1), Some(v2)) = (vertex(&mesh.vertices, triangle[0]), vertex(&mesh.vertices, triangle[1]), vertex(&mesh.vertices, triangle[2])) else {
+                    continue;
+                };
+                if let Some(distance) = self.camera.ray_triangle_intersect(ray_origin, ray_direction, v0, v1, v2) {
+                    if closest.map_or(true, |(nearest, _)| distance < nearest) {
+                        closest = Some((distance, &mesh.prim_path));
+                    }
+                }
+            }
+        }
+        closest.map(|(_, prim_path)| prim_path.to_string())
+    }
+    
+    /// Bounds of the meshes of the given prims; None when none of them are in the scene
+    pub fn selected_bounds(&self, prim_paths: &[String]) -> Option<(glam::Vec3, glam::Vec3)> {
+        let viewport_data = self.viewport_data.as_ref()?;
+        viewport_data.scene.meshes.iter()
+            .filter(|mesh| prim_paths.contains(&mesh.prim_path))
+            .flat_map(|mesh| mesh.vertices.chunks_exact(3).map(glam::Vec3::from_slice))
+            .fold(None, |bounds, point| match bounds {
+                None => Some((point, point)),
+                Some((min, max)) => Some((min.min(point), max.max(point))),
+            })
+    }
+    
     /// Get current camera data for plugins
     pub fn get_camera_data(&self) -> crate::viewport::CameraData {
         crate::viewport::CameraData {
@@ -156,6 +193,11 @@ pub fn clear_all_gpu_mesh_caches() {
     }
 }
 
+/// Estimated GPU memory of the shared viewport renderer; None until a viewport has drawn
+pub fn estimated_gpu_memory() -> Option<u64> {
+    SHARED_RENDERER.lock().ok()?.estimated_gpu_memory()
+}
+
 pub enum CameraManipulationType {
     Orbit,
     Pan,
@@ -192,9 +234,15 @@ impl CallbackTrait for ViewportRenderCallback {
                 
                 // Update camera in renderer
                 renderer.set_camera(&self.camera);
+                if let Some(ref viewport_data) = self.viewport_data {
+                    renderer.set_display_mode(viewport_data.settings.display_mode);
+                }
                 
                 // Update camera uniforms
                 renderer.update_camera_uniforms(queue);
+                if let Some(ref viewport_data) = self.viewport_data {
+                    renderer.update_point_uniforms(queue, viewport_data, self.viewport_size);
+                }
                 
                 // Preparation complete
             }
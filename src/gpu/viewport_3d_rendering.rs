@@ -69,7 +69,55 @@ pub struct Uniforms3D {
     pub view_proj: [[f32; 4]; 4],
     pub model: [[f32; 4]; 4],
     pub camera_pos: [f32; 3],
+    /// `DisplayMode` as its discriminant, read by the mesh and wireframe shaders
+    pub display_mode: u32,
+}
+
+/// One point of a point cloud, drawn as an instance
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub struct PointVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 3],
+}
+
+impl PointVertex {
+    const ATTRIBUTES: [VertexAttribute; 2] = [
+        VertexAttribute {
+            offset: 0,
+            shader_location: 0,
+            format: VertexFormat::Float32x3,
+        },
+        VertexAttribute {
+            offset: mem::size_of::<[f32; 3]>() as eframe::wgpu::BufferAddress,
+            shader_location: 1,
+            format: VertexFormat::Float32x3,
+        },
+    ];
+
+    pub fn desc<'a>() -> VertexBufferLayout<'a> {
+        VertexBufferLayout {
+            array_stride: mem::size_of::<PointVertex>() as eframe::wgpu::BufferAddress,
+            step_mode: VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// Uniforms of the point cloud shader
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+pub struct PointUniforms {
+    pub view_proj: [[f32; 4]; 4],
+    /// Solid color; w is the `PointColorMode` discriminant
+    pub solid_color: [f32; 4],
+    pub viewport_size: [f32; 2],
+    /// Dot diameter in pixels
+    pub point_size: f32,
     pub _padding: f32,
+    /// Lowest and highest point, for height coloring
+    pub height_range: [f32; 2],
+    pub _padding2: [f32; 2],
 }
 
 /// 3D Camera with Maya-style navigation
@@ -450,9 +498,24 @@ pub struct GpuMesh {
     pub vertex_buffer: Buffer,
     pub index_buffer: Buffer,
     pub index_count: u32,
+    /// Each triangle edge as a line, for wireframe display modes
+    pub edge_index_buffer: Buffer,
+    pub edge_index_count: u32,
     pub material_id: Option<String>,
 }
 
+/// Point cloud with its points uploaded as an instance buffer
+pub struct GpuPoints {
+    pub instance_buffer: Buffer,
+    pub point_count: u32,
+}
+
+/// Overlay line segments, drawn with the edge pipeline
+pub struct GpuLines {
+    pub vertex_buffer: Buffer,
+    pub vertex_count: u32,
+}
+
 impl Mesh3D {
     /// Create a cube mesh
     pub fn cube() -> Self {
@@ -527,12 +590,17 @@ pub struct Renderer3D {
     pub queue: Option<Queue>,
     pub mesh_pipeline: Option<RenderPipeline>,
     pub wireframe_pipeline: Option<RenderPipeline>,
+    /// Draws mesh edges as lines
+    pub edge_pipeline: Option<RenderPipeline>,
     pub grid_pipeline: Option<RenderPipeline>,
     pub axis_pipeline: Option<RenderPipeline>,
     pub uniform_buffer: Option<Buffer>,
     pub uniform_bind_group: Option<BindGroup>,
     pub depth_texture: Option<TextureView>,
+    /// Size of the depth texture behind `depth_texture`
+    pub depth_texture_bytes: u64,
     pub camera: Camera3D,
+    pub display_mode: crate::viewport::DisplayMode,
     pub cube_mesh: Option<Mesh3D>,
     pub grid_mesh: Option<Mesh3D>,
     pub grid_vertex_buffer: Option<Buffer>,
@@ -543,6 +611,12 @@ pub struct Renderer3D {
     pub axis_index_count: u32,
     // USD mesh storage
     pub gpu_meshes: HashMap<String, GpuMesh>,
+    /// Draws point clouds as instanced dots, with its own uniforms
+    pub point_pipeline: Option<RenderPipeline>,
+    pub point_uniform_buffer: Option<Buffer>,
+    pub point_bind_group: Option<BindGroup>,
+    pub gpu_points: HashMap<String, GpuPoints>,
+    pub gpu_lines: HashMap<String, GpuLines>,
 }
 
 impl std::fmt::Debug for Renderer3D {
@@ -565,12 +639,15 @@ impl Default for Renderer3D {
             queue: None,
             mesh_pipeline: None,
             wireframe_pipeline: None,
+            edge_pipeline: None,
             grid_pipeline: None,
             axis_pipeline: None,
             uniform_buffer: None,
             uniform_bind_group: None,
             depth_texture: None,
+            depth_texture_bytes: 0,
             camera: Camera3D::default(),
+            display_mode: crate::viewport::DisplayMode::default(),
             cube_mesh: Some(Mesh3D::cube()),
             grid_mesh: Some(Mesh3D::grid_plane(10.0)),
             grid_vertex_buffer: None,
@@ -580,6 +657,11 @@ impl Default for Renderer3D {
             axis_index_buffer: None,
             axis_index_count: 0,
             gpu_meshes: HashMap::new(),
+            point_pipeline: None,
+            point_uniform_buffer: None,
+            point_bind_group: None,
+            gpu_points: HashMap::new(),
+            gpu_lines: HashMap::new(),
         }
     }
 }
@@ -630,6 +712,7 @@ impl Renderer3D {
         
         // Load shaders and create pipelines
         self.create_pipelines_with_device(&device, &bind_group_layout);
+        self.create_point_pipeline(&device);
         
         // Store the created resources
         self.uniform_buffer = Some(uniform_buffer);
@@ -742,6 +825,41 @@ impl Renderer3D {
             multiview: None,
         }));
         
+        // Create edge pipeline: the wireframe shader over line lists, so edges stay thin without PolygonMode::Line
+        self.edge_pipeline = Some(device.create_render_pipeline(&eframe::wgpu::RenderPipelineDescriptor {
+            label: Some("3D Edge Pipeline"),
+            layout: Some(&pipeline_layout),
+            cache: None,
+            vertex: eframe::wgpu::VertexState {
+                module: &wireframe_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[Vertex3D::desc()],
+                compilation_options: eframe::wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(eframe::wgpu::FragmentState {
+                module: &wireframe_shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(eframe::wgpu::ColorTargetState {
+                    format: TextureFormat::Bgra8Unorm,
+                    blend: Some(eframe::wgpu::BlendState::REPLACE),
+                    write_mask: eframe::wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: eframe::wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: eframe::wgpu::PrimitiveState {
+                topology: PrimitiveTopology::LineList,
+                strip_index_format: None,
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None, // Depth testing requires render target setup
+            multisample: GraphicsConfig::global().multisample_state(),
+            multiview: None,
+        }));
+        
         // Create grid pipeline
         self.grid_pipeline = Some(device.create_render_pipeline(&eframe::wgpu::RenderPipelineDescriptor {
             label: Some("3D Grid Pipeline"),
@@ -842,6 +960,87 @@ impl Renderer3D {
         }));
     }
     
+    /// Create the point cloud pipeline with its uniform buffer and bind group
+    fn create_point_pipeline(&mut self, device: &Device) {
+        let point_shader = device.create_shader_module(eframe::wgpu::ShaderModuleDescriptor {
+            label: Some("3D Point Shader"),
+            source: eframe::wgpu::ShaderSource::Wgsl(include_str!("shaders/points3d.wgsl").into()),
+        });
+        
+        let uniform_buffer = device.create_buffer(&eframe::wgpu::BufferDescriptor {
+            label: Some("3D Point Uniform Buffer"),
+            size: mem::size_of::<PointUniforms>() as u64,
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group_layout = device.create_bind_group_layout(&eframe::wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                eframe::wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+                    ty: eframe::wgpu::BindingType::Buffer {
+                        ty: eframe::wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+            label: Some("3D Point Bind Group Layout"),
+        });
+        let bind_group = device.create_bind_group(&eframe::wgpu::BindGroupDescriptor {
+            layout: &bind_group_layout,
+            entries: &[
+                eframe::wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+            label: Some("3D Point Bind Group"),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&eframe::wgpu::PipelineLayoutDescriptor {
+            label: Some("3D Point Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        
+        self.point_pipeline = Some(device.create_render_pipeline(&eframe::wgpu::RenderPipelineDescriptor {
+            label: Some("3D Point Pipeline"),
+            layout: Some(&pipeline_layout),
+            cache: None,
+            vertex: eframe::wgpu::VertexState {
+                module: &point_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[PointVertex::desc()],
+                compilation_options: eframe::wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(eframe::wgpu::FragmentState {
+                module: &point_shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(eframe::wgpu::ColorTargetState {
+                    format: TextureFormat::Bgra8Unorm,
+                    blend: Some(eframe::wgpu::BlendState::REPLACE),
+                    write_mask: eframe::wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: eframe::wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: eframe::wgpu::PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None, // Depth testing requires render target setup
+            multisample: GraphicsConfig::global().multisample_state(),
+            multiview: None,
+        }));
+        self.point_uniform_buffer = Some(uniform_buffer);
+        self.point_bind_group = Some(bind_group);
+    }
+    
     /// Initialize renderer using references (for callback system)
     /// Initialize with device and queue references and store them for later use
     pub fn initialize_from_refs(&mut self, device: &Device, queue: &Queue) {
@@ -890,6 +1089,7 @@ impl Renderer3D {
         
         // Create pipelines
         self.create_pipelines_with_device(device, &bind_group_layout);
+        self.create_point_pipeline(device);
         
         // Store created resources
         self.uniform_buffer = Some(uniform_buffer);
@@ -923,6 +1123,8 @@ impl Renderer3D {
             });
             
             self.depth_texture = Some(depth_texture.create_view(&TextureViewDescriptor::default()));
+            // Depth32Float: 4 bytes per texel
+            self.depth_texture_bytes = width as u64 * height as u64 * 4;
         }
     }
     
@@ -939,7 +1141,7 @@ impl Renderer3D {
                 view_proj: view_proj_matrix.to_cols_array_2d(),
                 model: Mat4::IDENTITY.to_cols_array_2d(),
                 camera_pos: [self.camera.position.x, self.camera.position.y, self.camera.position.z],
-                _padding: 0.0,
+                display_mode: self.display_mode as u32,
             };
             
             queue.write_buffer(uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
@@ -947,6 +1149,34 @@ impl Renderer3D {
         }
     }
     
+    /// Set the display mode written to the uniforms with the next camera update
+    pub fn set_display_mode(&mut self, display_mode: crate::viewport::DisplayMode) {
+        if self.display_mode != display_mode {
+            self.display_mode = display_mode;
+            self.camera.mark_dirty();
+        }
+    }
+    
+    /// Write the point shader's uniforms for the current camera and `viewport_data`'s point display
+    pub fn update_point_uniforms(&self, queue: &Queue, viewport_data: &crate::viewport::ViewportData, viewport_size: (u32, u32)) {
+        let Some(uniform_buffer) = &self.point_uniform_buffer else {
+            return;
+        };
+        let display = &viewport_data.settings.point_display;
+        let (low, high) = viewport_data.scene.bounding_box.map_or((0.0, 1.0), |(min, max)| (min[1], max[1]));
+        let [r, g, b] = display.solid_color;
+        let uniforms = PointUniforms {
+            view_proj: self.camera.build_view_projection_matrix().to_cols_array_2d(),
+            solid_color: [r, g, b, display.color_mode as u32 as f32],
+            viewport_size: [viewport_size.0 as f32, viewport_size.1 as f32],
+            point_size: display.size,
+            _padding: 0.0,
+            height_range: [low, high],
+            _padding2: [0.0; 2],
+        };
+        queue.write_buffer(uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+    }
+    
     /// Set camera for rendering
     pub fn set_camera(&mut self, camera: &Camera3D) {
         self.camera = camera.clone();
@@ -964,6 +1194,30 @@ impl Renderer3D {
         }
     }
     
+    /// Render mesh geometry as one prim; the shaders color Prim ID display by `prim_index`
+    pub fn render_prim(&self, render_pass: &mut eframe::wgpu::RenderPass, gpu_mesh: &GpuMesh, prim_index: u32) {
+        if let (Some(pipeline), Some(bind_group)) = (&self.mesh_pipeline, &self.uniform_bind_group) {
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(gpu_mesh.index_buffer.slice(..), eframe::wgpu::IndexFormat::Uint32);
+            render_pass.draw_indexed(0..gpu_mesh.index_count, 0, prim_index..prim_index + 1);
+        }
+    }
+    
+    /// Render the triangle edges of a mesh as lines, in the selection color when `highlighted`
+    pub fn render_edges(&self, render_pass: &mut eframe::wgpu::RenderPass, gpu_mesh: &GpuMesh, highlighted: bool) {
+        if let (Some(pipeline), Some(bind_group)) = (&self.edge_pipeline, &self.uniform_bind_group) {
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(gpu_mesh.edge_index_buffer.slice(..), eframe::wgpu::IndexFormat::Uint32);
+            // The wireframe shader reads instance 1 as highlighted
+            let instance = highlighted as u32;
+            render_pass.draw_indexed(0..gpu_mesh.edge_index_count, 0, instance..instance + 1);
+        }
+    }
+    
     /// Render wireframe geometry
     pub fn render_wireframe(&self, render_pass: &mut eframe::wgpu::RenderPass, vertex_buffer: &Buffer, index_buffer: &Buffer, index_count: u32) {
         if let (Some(pipeline), Some(bind_group)) = (&self.wireframe_pipeline, &self.uniform_bind_group) {
@@ -1289,10 +1543,20 @@ impl Renderer3D {
             usage: BufferUsages::INDEX,
         });
         
+        // Create edge index buffer
+        let edge_indices = edge_indices(&mesh_data.indices);
+        let edge_index_buffer = device.create_buffer_init(&eframe::wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("USD Mesh Edge Index Buffer - {}", mesh_id)),
+            contents: bytemuck::cast_slice(&edge_indices),
+            usage: BufferUsages::INDEX,
+        });
+        
         let gpu_mesh = GpuMesh {
             vertex_buffer,
             index_buffer,
             index_count: mesh_data.indices.len() as u32,
+            edge_index_buffer,
+            edge_index_count: edge_indices.len() as u32,
             material_id: mesh_data.material_id.clone(),
         };
         
@@ -1301,9 +1565,112 @@ impl Renderer3D {
         Ok(())
     }
 
+    /// Upload a point cloud as an instance buffer; points without colors are white
+    pub fn upload_points_to_gpu(&mut self, points: &crate::viewport::PointsData) -> Result<(), String> {
+        let device = self.device.as_ref().ok_or("Device not initialized")?;
+        if self.gpu_points.contains_key(&points.id) {
+            return Ok(());
+        }
+        
+        let point_count = points.positions.len() / 3;
+        if point_count == 0 {
+            return Err(format!("Point cloud {} has no points", points.id));
+        }
+        let has_colors = points.colors.len() == points.positions.len();
+        let instances: Vec<PointVertex> = (0..point_count)
+            .map(|i| PointVertex {
+                position: [points.positions[i * 3], points.positions[i * 3 + 1], points.positions[i * 3 + 2]],
+                color: if has_colors {
+                    [points.colors[i * 3], points.colors[i * 3 + 1], points.colors[i * 3 + 2]]
+                } else {
+                    [1.0, 1.0, 1.0]
+                },
+            })
+            .collect();
+        
+        let instance_buffer = device.create_buffer_init(&eframe::wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("Point Instance Buffer - {}", points.id)),
+            contents: bytemuck::cast_slice(&instances),
+            usage: BufferUsages::VERTEX,
+        });
+        self.gpu_points.insert(points.id.clone(), GpuPoints { instance_buffer, point_count: point_count as u32 });
+        Ok(())
+    }
+    
+    /// Render an uploaded point cloud as dots
+    pub fn render_points(&self, render_pass: &mut eframe::wgpu::RenderPass, gpu_points: &GpuPoints) {
+        if let (Some(pipeline), Some(bind_group)) = (&self.point_pipeline, &self.point_bind_group) {
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, gpu_points.instance_buffer.slice(..));
+            render_pass.draw(0..6, 0..gpu_points.point_count);
+        }
+    }
+
+    /// Upload overlay lines as Vertex3D pairs
+    pub fn upload_lines_to_gpu(&mut self, lines: &crate::viewport::LinesData) -> Result<(), String> {
+        let device = self.device.as_ref().ok_or("Device not initialized")?;
+        if self.gpu_lines.contains_key(&lines.id) {
+            return Ok(());
+        }
+        
+        let vertices: Vec<Vertex3D> = lines.positions.chunks_exact(3)
+            .map(|p| Vertex3D {
+                position: [p[0], p[1], p[2]],
+                normal: [0.0, 1.0, 0.0],
+                uv: [0.0, 0.0],
+                color: [1.0, 1.0, 1.0],
+            })
+            .collect();
+        if vertices.len() < 2 {
+            return Err(format!("Lines {} have no segments", lines.id));
+        }
+        
+        let vertex_buffer = device.create_buffer_init(&eframe::wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("Line Vertex Buffer - {}", lines.id)),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: BufferUsages::VERTEX,
+        });
+        self.gpu_lines.insert(lines.id.clone(), GpuLines { vertex_buffer, vertex_count: vertices.len() as u32 & !1 });
+        Ok(())
+    }
+    
+    /// Render overlay lines in the highlight color
+    pub fn render_lines(&self, render_pass: &mut eframe::wgpu::RenderPass, gpu_lines: &GpuLines) {
+        if let (Some(pipeline), Some(bind_group)) = (&self.edge_pipeline, &self.uniform_bind_group) {
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_vertex_buffer(0, gpu_lines.vertex_buffer.slice(..));
+            // The wireframe shader reads instance 1 as highlighted
+            render_pass.draw(0..gpu_lines.vertex_count, 1..2);
+        }
+    }
+
     /// Clear all GPU mesh cache (call when USD parameters change)
+    /// Bytes of the buffers and textures this renderer holds on the GPU; None before initialization
+    pub fn estimated_gpu_memory(&self) -> Option<u64> {
+        self.device.as_ref()?;
+        let buffers = [
+            &self.uniform_buffer,
+            &self.grid_vertex_buffer,
+            &self.grid_index_buffer,
+            &self.axis_vertex_buffer,
+            &self.axis_index_buffer,
+            &self.point_uniform_buffer,
+        ];
+        let fixed: u64 = buffers.into_iter().filter_map(|buffer| buffer.as_ref()).map(Buffer::size).sum();
+        let meshes: u64 = self.gpu_meshes.values()
+            .map(|mesh| mesh.vertex_buffer.size() + mesh.index_buffer.size() + mesh.edge_index_buffer.size())
+            .sum();
+        let points: u64 = self.gpu_points.values().map(|points| points.instance_buffer.size()).sum();
+        let lines: u64 = self.gpu_lines.values().map(|lines| lines.vertex_buffer.size()).sum();
+        Some(fixed + meshes + points + lines + self.depth_texture_bytes)
+    }
+    
     pub fn clear_gpu_mesh_cache(&mut self) {
         self.gpu_meshes.clear();
+        self.gpu_points.clear();
+        self.gpu_lines.clear();
         println!("🧹 Cleared GPU mesh cache");
     }
     
@@ -1326,7 +1693,8 @@ impl Renderer3D {
         if !viewport_data.scene.meshes.is_empty() {
             // Rendering USD meshes
             
-            for mesh in &viewport_data.scene.meshes {
+            let display_mode = viewport_data.settings.display_mode;
+            for (prim_index, mesh) in viewport_data.scene.meshes.iter().enumerate() {
                 // Upload mesh to GPU if not already uploaded (uses content-based caching)
                 if let Err(e) = self.upload_mesh_to_gpu(mesh.id.clone(), mesh) {
                     // Failed to upload mesh - continuing
@@ -1338,17 +1706,46 @@ impl Renderer3D {
                     // Apply transform if needed
                     // For now, using identity transform from the uniform buffer
                     
-                    // Render based on viewport settings
-                    if viewport_data.settings.wireframe {
-                        self.render_wireframe(render_pass, &gpu_mesh.vertex_buffer, &gpu_mesh.index_buffer, gpu_mesh.index_count);
-                    } else {
-                        self.render_mesh(render_pass, &gpu_mesh.vertex_buffer, &gpu_mesh.index_buffer, gpu_mesh.index_count);
+                    // Render based on the display mode; edges go last so they overlay the surfaces
+                    if display_mode.draws_surfaces() {
+                        self.render_prim(render_pass, gpu_mesh, prim_index as u32);
+                    }
+                    let selected = !mesh.prim_path.is_empty() && viewport_data.settings.selected_prims.contains(&mesh.prim_path);
+                    if display_mode.draws_edges() || selected {
+                        self.render_edges(render_pass, gpu_mesh, selected);
                     }
                 }
             }
             
             // GPU meshes rendered
         }
+        
+        // A new cloud replaces the ones uploaded for earlier versions of the scene
+        let scene_points = &viewport_data.scene.points;
+        if scene_points.iter().any(|points| !self.gpu_points.contains_key(&points.id)) {
+            self.gpu_points.retain(|id, _| scene_points.iter().any(|points| &points.id == id));
+        }
+        for points in scene_points {
+            if self.upload_points_to_gpu(points).is_err() {
+                continue;
+            }
+            if let Some(gpu_points) = self.gpu_points.get(&points.id) {
+                self.render_points(render_pass, gpu_points);
+            }
+        }
+        
+        let scene_lines = &viewport_data.scene.lines;
+        if scene_lines.iter().any(|lines| !self.gpu_lines.contains_key(&lines.id)) {
+            self.gpu_lines.retain(|id, _| scene_lines.iter().any(|lines| &lines.id == id));
+        }
+        for lines in scene_lines {
+            if self.upload_lines_to_gpu(lines).is_err() {
+                continue;
+            }
+            if let Some(gpu_lines) = self.gpu_lines.get(&lines.id) {
+                self.render_lines(render_pass, gpu_lines);
+            }
+        }
     }
     
     /// Render basic scene (grid, axes) when no plugin data is available
@@ -1370,4 +1767,17 @@ impl Renderer3D {
         // Render axis labels
         self.render_axis_labels(render_pass);
     }
-}
\ No newline at end of file
+}
+/// Line-list indices of the unique edges of a triangle list
+fn edge_indices(indices: &[u32]) -> Vec<u32> {
+    let mut seen = std::collections::HashSet::new();
+    let mut edges = Vec::with_capacity(indices.len() * 2);
+    for triangle in indices.chunks_exact(3) {
+        for (a, b) in [(triangle[0], triangle[1]), (triangle[1], triangle[2]), (triangle[2], triangle[0])] {
+            if seen.insert((a.min(b), a.max(b))) {
+                edges.extend([a, b]);
+            }
+        }
+    }
+    edges
+}
@@ -1,8 +1,9 @@
 //! Nōdle Application - Node-based visual programming editor
 
 use eframe::egui;
-use log::{info, error};
+use log::info;
 
+mod cli;
 mod constants;
 mod editor;
 mod menu_hierarchy;
@@ -13,6 +14,7 @@ mod workspace;
 mod gpu;
 mod startup_checks;
 mod theme;
+mod settings;
 mod plugins;
 mod viewport;
 mod plugin_interface;
@@ -46,6 +48,12 @@ fn main() -> Result<(), eframe::Error> {
     env_logger::Builder::from_default_env()
         .filter_level(log::LevelFilter::Info)
         .init();
+
+    // Command-line modes (diff/merge) run without the editor window
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(exit_code) = cli::run(&args) {
+        std::process::exit(exit_code);
+    }
     
     info!("Starting Nōdle Application");
     
@@ -66,25 +74,18 @@ fn main() -> Result<(), eframe::Error> {
     println!("🔌 Initializing global plugin system...");
     match workspace::initialize_global_plugin_manager() {
         Ok(()) => {
-            if let Some(plugin_manager) = workspace::get_global_plugin_manager() {
-                match plugin_manager.lock() {
-                    Ok(manager) => {
-                        let loaded_plugins = manager.get_loaded_plugins();
-                        
-                        if loaded_plugins.is_empty() {
-                            println!("📦 No plugins found in standard directories");
-                            println!("   Looking in: ~/.nodle/plugins/ and ./plugins/");
-                        } else {
-                            println!("✅ Loaded {} plugin(s):", loaded_plugins.len());
-                            for plugin in loaded_plugins {
-                                println!("   • {} v{} by {}", plugin.name, plugin.version, plugin.author);
-                            }
-                            println!("🔗 Plugin system initialized successfully");
-                        }
-                    }
-                    Err(e) => {
-                        error!("Failed to lock plugin manager: {}", e);
+            if let Some(plugin_host) = workspace::get_plugin_host() {
+                let snapshot = plugin_host.snapshot();
+                
+                if snapshot.plugins.is_empty() {
+                    println!("📦 No plugins found in standard directories");
+                    println!("   Looking in: ~/.nodle/plugins/ and ./plugins/");
+                } else {
+                    println!("✅ Loaded {} plugin(s):", snapshot.plugins.len());
+                    for plugin in &snapshot.plugins {
+                        println!("   • {} v{} by {}", plugin.info.name, plugin.info.version, plugin.info.author);
                     }
+                    println!("🔗 Plugin system initialized successfully");
                 }
             }
         }
@@ -110,9 +111,11 @@ fn main() -> Result<(), eframe::Error> {
         "Nōdle - Node Editor",
         options,
         Box::new(|cc| {
-            // Set dark theme
-            cc.egui_ctx.set_visuals(egui::Visuals::dark());
-            cc.egui_ctx.set_theme(egui::Theme::Dark);
+            // Image thumbnails on the canvas load through egui's image loaders
+            egui_extras::install_image_loaders(&cc.egui_ctx);
+
+            // Theme from the preferences
+            theme::apply(&cc.egui_ctx, &settings::current().theme);
             
             Ok(Box::new(NodeEditor::new()))
         }),
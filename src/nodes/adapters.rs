@@ -0,0 +1,240 @@
+//! Type conversion adapters on connections
+//!
+//! When an output feeds an input of a different but convertible type (an
+//! Integer into a Float, a number into a String, a scene into a bounds input),
+//! the connection carries an adapter that converts values on the way; the
+//! editor shows it as a badge on the wire. An adapter is picked when the wire
+//! is connected, and can be swapped for another one valid for the same types
+//! or removed from the Wires window. Wires from generic (Any) outputs, such as
+//! the scene modifiers', are converted by the type of the value that arrives.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use glam::Vec3;
+use super::factory::DataType;
+use super::interface::NodeData;
+
+/// Data types with geometry that has bounds
+const BOUNDED: [DataType; 3] = [DataType::USDScene, DataType::PointCloud, DataType::Volume];
+
+/// Data types written as text by [`Adapter::Text`]
+const SCALARS: [DataType; 5] = [DataType::Float, DataType::Integer, DataType::Boolean, DataType::Vector3, DataType::Color];
+
+/// Conversion applied to the values crossing a connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Adapter {
+    IntegerToFloat,
+    /// Float to the nearest integer
+    Round,
+    /// Float to the integer below it
+    Floor,
+    /// True as 1 and false as 0
+    BooleanToFloat,
+    /// Non-zero numbers are true
+    NonZero,
+    /// Numbers, booleans, vectors and colors as text
+    Text,
+    /// Map of the min, max, center and size of the geometry's bounds
+    Bounds,
+    /// Size of the geometry's bounds
+    BoundsSize,
+    /// Center of the geometry's bounds
+    BoundsCenter,
+}
+
+impl Adapter {
+    /// Every adapter; for each pair of types the first one that applies is picked automatically
+    pub const ALL: [Adapter; 9] = [
+        Adapter::IntegerToFloat,
+        Adapter::Round,
+        Adapter::Floor,
+        Adapter::BooleanToFloat,
+        Adapter::NonZero,
+        Adapter::Text,
+        Adapter::Bounds,
+        Adapter::BoundsSize,
+        Adapter::BoundsCenter,
+    ];
+
+    /// Short name shown on the wire badge
+    pub fn label(&self) -> &'static str {
+        match self {
+            Adapter::IntegerToFloat => "Int→Float",
+            Adapter::Round => "Round",
+            Adapter::Floor => "Floor",
+            Adapter::BooleanToFloat => "Bool→Float",
+            Adapter::NonZero => "≠ 0",
+            Adapter::Text => "Text",
+            Adapter::Bounds => "Bounds",
+            Adapter::BoundsSize => "Bounds Size",
+            Adapter::BoundsCenter => "Bounds Center",
+        }
+    }
+
+    /// Whether the adapter turns values of `from` into values of `to`
+    pub fn converts(&self, from: &DataType, to: &DataType) -> bool {
+        match self {
+            Adapter::IntegerToFloat => *from == DataType::Integer && *to == DataType::Float,
+            Adapter::Round | Adapter::Floor => *from == DataType::Float && *to == DataType::Integer,
+            Adapter::BooleanToFloat => *from == DataType::Boolean && *to == DataType::Float,
+            Adapter::NonZero => matches!(from, DataType::Float | DataType::Integer) && *to == DataType::Boolean,
+            Adapter::Text => SCALARS.contains(from) && *to == DataType::String,
+            Adapter::Bounds => BOUNDED.contains(from) && *to == DataType::Map,
+            Adapter::BoundsSize | Adapter::BoundsCenter => BOUNDED.contains(from) && *to == DataType::Vector3,
+        }
+    }
+
+    /// Convert one value; values the adapter does not apply to pass through unchanged
+    pub fn convert(&self, data: NodeData) -> NodeData {
+        match (self, data) {
+            (Adapter::IntegerToFloat, NodeData::Integer(i)) => NodeData::Float(i as f32),
+            (Adapter::Round, NodeData::Float(f)) => NodeData::Integer(f.round() as i32),
+            (Adapter::Floor, NodeData::Float(f)) => NodeData::Integer(f.floor() as i32),
+            (Adapter::BooleanToFloat, NodeData::Boolean(b)) => NodeData::Float(if b { 1.0 } else { 0.0 }),
+            (Adapter::NonZero, NodeData::Float(f)) => NodeData::Boolean(f != 0.0),
+            (Adapter::NonZero, NodeData::Integer(i)) => NodeData::Boolean(i != 0),
+            (Adapter::Text, data) => text(&data).map_or(data, NodeData::String),
+            (Adapter::Bounds | Adapter::BoundsSize | Adapter::BoundsCenter, data) => match bounds(&data) {
+                Some((min, max)) => match self {
+                    Adapter::BoundsSize => NodeData::Vector3((max - min).to_array()),
+                    Adapter::BoundsCenter => NodeData::Vector3(((min + max) / 2.0).to_array()),
+                    _ => NodeData::Map(BTreeMap::from([
+                        ("min".to_string(), NodeData::Vector3(min.to_array())),
+                        ("max".to_string(), NodeData::Vector3(max.to_array())),
+                        ("center".to_string(), NodeData::Vector3(((min + max) / 2.0).to_array())),
+                        ("size".to_string(), NodeData::Vector3((max - min).to_array())),
+                    ])),
+                },
+                // Empty geometry has no bounds
+                None if bounds_source(&data) => NodeData::None,
+                None => data,
+            },
+            (_, data) => data,
+        }
+    }
+}
+
+/// Adapters converting `from` into `to`, the automatic pick first
+pub fn adapters_for(from: &DataType, to: &DataType) -> Vec<Adapter> {
+    Adapter::ALL.into_iter().filter(|adapter| adapter.converts(from, to)).collect()
+}
+
+/// Adapter to put on a new connection between ports of these declared types;
+/// None when the types match, either is unknown or generic, or nothing converts them
+pub fn auto_adapter(from: Option<DataType>, to: Option<DataType>) -> Option<Adapter> {
+    let (from, to) = (from?, to?);
+    if from == to {
+        return None;
+    }
+    adapters_for(&from, &to).first().copied()
+}
+
+/// Adapter for a value crossing an adapter-less connection from a generic
+/// output, such as a modifier passing its input scene on: the type of the
+/// value produced stands in for the port's declared type
+pub fn value_adapter(data: &NodeData, to: Option<DataType>) -> Option<Adapter> {
+    let from = match data {
+        NodeData::Float(_) => DataType::Float,
+        NodeData::Integer(_) => DataType::Integer,
+        NodeData::Boolean(_) => DataType::Boolean,
+        NodeData::Vector3(_) => DataType::Vector3,
+        NodeData::Color(_) => DataType::Color,
+        NodeData::USDSceneData(_) => DataType::USDScene,
+        NodeData::PointCloud(_) => DataType::PointCloud,
+        NodeData::Volume(_) => DataType::Volume,
+        _ => return None,
+    };
+    auto_adapter(Some(from), to)
+}
+
+fn text(data: &NodeData) -> Option<String> {
+    Some(match data {
+        NodeData::Float(v) => v.to_string(),
+        NodeData::Integer(v) => v.to_string(),
+        NodeData::Boolean(v) => v.to_string(),
+        NodeData::Vector3([x, y, z]) => format!("{}, {}, {}", x, y, z),
+        NodeData::Color([r, g, b, a]) => format!("{}, {}, {}, {}", r, g, b, a),
+        _ => return None,
+    })
+}
+
+fn bounds_source(data: &NodeData) -> bool {
+    matches!(data, NodeData::USDSceneData(_) | NodeData::PointCloud(_) | NodeData::Volume(_))
+}
+
+/// World-space bounds of a scene's meshes, a point cloud or a volume
+fn bounds(data: &NodeData) -> Option<(Vec3, Vec3)> {
+    match data {
+        NodeData::USDSceneData(scene) => scene.meshes.iter()
+            .flat_map(|mesh| mesh.vertices.iter().map(|vertex| mesh.transform.transform_point3(*vertex)))
+            .fold(None, |bounds, p| Some(match bounds {
+                Some((min, max)) => (Vec3::min(min, p), Vec3::max(max, p)),
+                None => (p, p),
+            })),
+        NodeData::PointCloud(points) => points.bounds(),
+        NodeData::Volume(volume) => volume.bounds(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::interface::PointCloudData;
+
+    #[test]
+    fn test_adapters_are_picked_for_convertible_types_only() {
+        assert_eq!(auto_adapter(Some(DataType::Integer), Some(DataType::Float)), Some(Adapter::IntegerToFloat));
+        assert_eq!(auto_adapter(Some(DataType::Float), Some(DataType::String)), Some(Adapter::Text));
+        assert_eq!(auto_adapter(Some(DataType::USDScene), Some(DataType::Map)), Some(Adapter::Bounds));
+        assert_eq!(adapters_for(&DataType::Float, &DataType::Integer), vec![Adapter::Round, Adapter::Floor]);
+        assert_eq!(auto_adapter(Some(DataType::Float), Some(DataType::Float)), None);
+        assert_eq!(auto_adapter(Some(DataType::Float), Some(DataType::Any)), None);
+        assert_eq!(auto_adapter(Some(DataType::Audio), Some(DataType::Float)), None);
+        assert_eq!(auto_adapter(None, Some(DataType::Float)), None);
+    }
+
+    #[test]
+    fn test_values_are_converted_and_others_pass_through() {
+        assert!(matches!(Adapter::IntegerToFloat.convert(NodeData::Integer(3)), NodeData::Float(f) if f == 3.0));
+        assert!(matches!(Adapter::Floor.convert(NodeData::Float(2.7)), NodeData::Integer(2)));
+        assert!(matches!(Adapter::Text.convert(NodeData::Float(0.5)), NodeData::String(s) if s == "0.5"));
+        assert!(matches!(Adapter::Round.convert(NodeData::None), NodeData::None));
+
+        let points = NodeData::PointCloud(PointCloudData {
+            positions: vec![Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 4.0, 2.0)],
+            ..Default::default()
+        });
+        assert!(matches!(Adapter::BoundsSize.convert(points.clone()), NodeData::Vector3(size) if size == [4.0, 4.0, 0.0]));
+        assert!(matches!(Adapter::Bounds.convert(points), NodeData::Map(entries)
+            if matches!(entries["center"], NodeData::Vector3(center) if center == [1.0, 2.0, 2.0])));
+        assert!(matches!(Adapter::BoundsCenter.convert(NodeData::PointCloud(PointCloudData::default())), NodeData::None));
+    }
+
+    #[test]
+    fn test_scene_outputs_of_registered_nodes_get_bounds_adapters() {
+        use crate::theme::port_data_type;
+        // A cube's scene wired into the map input of Get Key
+        assert_eq!(auto_adapter(port_data_type("3D_Cube", false, 0), port_data_type("Map_GetKey", true, 0)), Some(Adapter::Bounds));
+        // A light's scene wired into the translate input of another light
+        assert_eq!(
+            auto_adapter(port_data_type("3D_PointLight", false, 0), port_data_type("3D_PointLight", true, 1)),
+            Some(Adapter::BoundsSize)
+        );
+
+        // Transform's output is generic, so the scene it produces decides the adapter
+        assert_eq!(auto_adapter(port_data_type("3D_Transform", false, 0), port_data_type("Map_GetKey", true, 0)), None);
+        let scene = NodeData::USDSceneData(crate::workspaces::three_d::usd::usd_engine::USDSceneData {
+            stage_path: String::new(),
+            meshes: Vec::new(),
+            lights: Vec::new(),
+            materials: Vec::new(),
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        });
+        assert_eq!(value_adapter(&scene, port_data_type("Map_GetKey", true, 0)), Some(Adapter::Bounds));
+        assert_eq!(value_adapter(&scene, port_data_type("3D_Transform", true, 0)), None);
+        assert_eq!(value_adapter(&NodeData::Integer(2), port_data_type("3D_PointLight", true, 1)), None);
+    }
+}
@@ -0,0 +1,84 @@
+//! Canvas annotations
+//!
+//! Review notes drawn over a graph: arrows, freehand strokes and text callouts.
+//! Annotations belong to the graph they were drawn on and are saved with it.
+//! Positions are in graph (world) coordinates so they stay put while panning
+//! and zooming.
+
+use egui::{Pos2, Vec2};
+use serde::{Deserialize, Serialize};
+use super::math_utils::distance_to_line_segment;
+
+/// Geometry of an annotation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnnotationShape {
+    /// Straight arrow pointing at `to`
+    Arrow { from: Pos2, to: Pos2 },
+    /// Freehand stroke through `points`
+    Freehand { points: Vec<Pos2> },
+    /// Text note with its top-left corner at `position`
+    Callout { position: Pos2, text: String },
+}
+
+/// One annotation on the canvas
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub shape: AnnotationShape,
+    /// RGBA colour
+    pub color: [u8; 4],
+}
+
+/// Approximate size of one callout character in world units, for hit testing
+const CALLOUT_CHAR_SIZE: Vec2 = Vec2::new(7.5, 16.0);
+
+impl Annotation {
+    /// Whether `point` lies within `tolerance` of the annotation
+    pub fn hit(&self, point: Pos2, tolerance: f32) -> bool {
+        match &self.shape {
+            AnnotationShape::Arrow { from, to } => distance_to_line_segment(point, *from, *to) <= tolerance,
+            AnnotationShape::Freehand { points } => match points.as_slice() {
+                [single] => single.distance(point) <= tolerance,
+                points => points.windows(2).any(|pair| distance_to_line_segment(point, pair[0], pair[1]) <= tolerance),
+            },
+            AnnotationShape::Callout { position, text } => {
+                let lines = text.lines().count().max(1) as f32;
+                let columns = text.lines().map(|line| line.chars().count()).max().unwrap_or(0) as f32;
+                let size = Vec2::new(columns * CALLOUT_CHAR_SIZE.x, lines * CALLOUT_CHAR_SIZE.y);
+                egui::Rect::from_min_size(*position, size).expand(tolerance).contains(point)
+            }
+        }
+    }
+}
+
+/// Index of the topmost annotation under `point`
+pub fn annotation_at(annotations: &[Annotation], point: Pos2, tolerance: f32) -> Option<usize> {
+    annotations.iter().rposition(|annotation| annotation.hit(point, tolerance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_testing_prefers_topmost_annotation() {
+        let annotations = vec![
+            Annotation {
+                shape: AnnotationShape::Arrow { from: Pos2::new(0.0, 0.0), to: Pos2::new(100.0, 0.0) },
+                color: [255, 200, 0, 255],
+            },
+            Annotation {
+                shape: AnnotationShape::Freehand { points: vec![Pos2::new(50.0, -20.0), Pos2::new(50.0, 20.0)] },
+                color: [255, 0, 0, 255],
+            },
+            Annotation {
+                shape: AnnotationShape::Callout { position: Pos2::new(200.0, 200.0), text: "Check this".to_string() },
+                color: [255, 255, 255, 255],
+            },
+        ];
+
+        assert_eq!(annotation_at(&annotations, Pos2::new(20.0, 3.0), 5.0), Some(0));
+        assert_eq!(annotation_at(&annotations, Pos2::new(50.0, 1.0), 5.0), Some(1));
+        assert_eq!(annotation_at(&annotations, Pos2::new(230.0, 210.0), 5.0), Some(2));
+        assert_eq!(annotation_at(&annotations, Pos2::new(20.0, 30.0), 5.0), None);
+    }
+}
@@ -19,6 +19,10 @@ pub struct CacheKey {
     pub stage_id: Option<String>,
     /// Output port index within the stage
     pub port_index: usize,
+    /// Timeline frame for outputs of time-dependent nodes, which are cached per frame.
+    /// None for static outputs, which are valid on every frame.
+    #[serde(default)]
+    pub frame: Option<i64>,
 }
 
 impl CacheKey {
@@ -28,6 +32,7 @@ impl CacheKey {
             node_id,
             stage_id: None,
             port_index,
+            frame: None,
         }
     }
     
@@ -37,6 +42,17 @@ impl CacheKey {
             node_id,
             stage_id: Some(stage_id.to_string()),
             port_index,
+            frame: None,
+        }
+    }
+    
+    /// Create a cache key for a time-dependent node output at `frame`
+    pub fn at_frame(node_id: NodeId, port_index: usize, frame: i64) -> Self {
+        Self {
+            node_id,
+            stage_id: None,
+            port_index,
+            frame: Some(frame),
         }
     }
     
@@ -68,6 +84,8 @@ pub enum CacheKeyPattern {
     Node(NodeId),
     /// Match all outputs for a specific stage of a node  
     Stage(NodeId, String),
+    /// Match all outputs of a node cached for one frame
+    Frame(NodeId, i64),
     /// Match a specific cache key exactly
     Exact(CacheKey),
 }
@@ -80,6 +98,7 @@ impl CacheKeyPattern {
             CacheKeyPattern::Stage(node_id, stage) => {
                 key.node_id == *node_id && key.stage_id.as_ref() == Some(stage)
             },
+            CacheKeyPattern::Frame(node_id, frame) => key.node_id == *node_id && key.frame == Some(*frame),
             CacheKeyPattern::Exact(exact_key) => key == exact_key,
         }
     }
@@ -240,6 +259,17 @@ impl UnifiedNodeCache {
         removed_count
     }
     
+    /// Frames cached for the outputs of `node_id`, in order
+    pub fn cached_frames(&self, node_id: NodeId) -> Vec<i64> {
+        let mut frames: Vec<i64> = self.cache.keys()
+            .filter(|key| key.node_id == node_id)
+            .filter_map(|key| key.frame)
+            .collect();
+        frames.sort_unstable();
+        frames.dedup();
+        frames
+    }
+    
     /// Clear all cache entries
     pub fn clear(&mut self) {
         let removed_count = self.cache.len();
@@ -365,4 +395,23 @@ mod tests {
         assert_eq!(stats.cache_hits, 1);
         assert_eq!(stats.cache_misses, 0);
     }
+    
+    #[test]
+    fn test_frame_keys_are_cached_and_invalidated_per_frame() {
+        let mut cache = UnifiedNodeCache::new();
+        for frame in [3, 1, 2] {
+            cache.insert(CacheKey::at_frame(7, 0, frame), OwnedNodeData::shared(NodeData::Integer(frame as i32)));
+        }
+        cache.insert(CacheKey::new(8, 0), OwnedNodeData::shared(NodeData::Integer(0)));
+        
+        assert_eq!(cache.cached_frames(7), vec![1, 2, 3]);
+        assert!(cache.cached_frames(8).is_empty());
+        assert!(!cache.contains(&CacheKey::new(7, 0)));
+        
+        assert_eq!(cache.invalidate(&CacheKeyPattern::Frame(7, 2)), 1);
+        assert_eq!(cache.cached_frames(7), vec![1, 3]);
+        assert_eq!(cache.invalidate(&CacheKeyPattern::Node(7)), 2);
+        assert!(cache.contains(&CacheKey::new(8, 0)));
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,226 @@
+//! Audio Analysis logic - loudness and frequency bands at the timeline frame
+//!
+//! The frame is converted to seconds with the node's frame rate, and a window
+//! of samples centered on that moment is analysed: its RMS level and peak,
+//! and a Hann-windowed FFT summed into logarithmically spaced bands from
+//! 20 Hz up to the Nyquist frequency. A full-scale sine measures 1 in its
+//! band, so band levels compare with the amplitude output.
+
+use std::f32::consts::PI;
+use crate::nodes::interface::{AudioData, NodeData};
+
+/// FFT window sizes the node offers, in samples
+pub const WINDOW_SIZES: [usize; 4] = [512, 1024, 2048, 4096];
+
+/// Lowest frequency of the first band, in Hz
+const LOWEST_FREQUENCY: f32 = 20.0;
+
+/// Core Audio Analysis configuration
+#[derive(Debug, Clone)]
+pub struct AudioAnalysisLogic {
+    /// Frames per second of the timeline
+    pub fps: f32,
+    /// Frames added to the timeline frame, to shift the sound against the animation
+    pub offset: i32,
+    /// Samples analysed per frame; a power of two
+    pub window_size: usize,
+    /// Frequency bands the spectrum is split into
+    pub band_count: usize,
+    /// Band sent to the Band output
+    pub band: usize,
+    /// Multiplier on every level
+    pub gain: f32,
+}
+
+impl Default for AudioAnalysisLogic {
+    fn default() -> Self {
+        Self {
+            fps: 24.0,
+            offset: 0,
+            window_size: 2048,
+            band_count: 8,
+            band: 0,
+            gain: 1.0,
+        }
+    }
+}
+
+/// Levels of the sound around one moment
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Analysis {
+    /// Seconds into the sound
+    pub time: f32,
+    /// RMS level of the window
+    pub amplitude: f32,
+    /// Largest absolute sample of the window
+    pub peak: f32,
+    /// Level of each frequency band, lowest first
+    pub bands: Vec<f32>,
+}
+
+impl AudioAnalysisLogic {
+    /// Outputs for the timeline frame `frame`; silence when no audio is connected
+    pub fn process(&self, inputs: Vec<NodeData>, frame: i64) -> Vec<NodeData> {
+        let analysis = match inputs.first() {
+            Some(NodeData::Audio(audio)) => self.analyze(audio, frame),
+            _ => Analysis { bands: vec![0.0; self.band_count.max(1)], ..Default::default() },
+        };
+        vec![
+            NodeData::Float(analysis.amplitude),
+            NodeData::Float(analysis.peak),
+            NodeData::Float(analysis.bands.get(self.band).copied().unwrap_or(0.0)),
+            NodeData::Buffer(analysis.bands),
+        ]
+    }
+
+    /// Seconds into the sound at the timeline frame `frame`
+    pub fn time(&self, frame: i64) -> f32 {
+        let frame = frame.saturating_add(self.offset as i64);
+        if self.fps > 0.0 { frame as f32 / self.fps } else { 0.0 }
+    }
+
+    /// Analyse the window of `audio` centered on the timeline frame `frame`
+    pub fn analyze(&self, audio: &AudioData, frame: i64) -> Analysis {
+        let size = self.window_size.max(2).next_power_of_two();
+        let time = self.time(frame);
+        let center = (time * audio.sample_rate as f32).round() as i64;
+        let window: Vec<f32> = (0..size as i64)
+            .map(|i| center - size as i64 / 2 + i)
+            .map(|index| usize::try_from(index).ok().and_then(|index| audio.samples.get(index)).copied().unwrap_or(0.0))
+            .collect();
+
+        let amplitude = (window.iter().map(|s| s * s).sum::<f32>() / size as f32).sqrt();
+        let peak = window.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+
+        // Hann window; its sum is size / 2, and each one-sided bin holds half a sine's energy
+        let mut re: Vec<f32> = window.iter().enumerate()
+            .map(|(i, s)| s * 0.5 * (1.0 - (2.0 * PI * i as f32 / size as f32).cos()))
+            .collect();
+        let mut im = vec![0.0; size];
+        fft(&mut re, &mut im);
+        let magnitudes: Vec<f32> = (0..size / 2)
+            .map(|bin| (re[bin] * re[bin] + im[bin] * im[bin]).sqrt() * 4.0 / size as f32)
+            .collect();
+
+        let bin_width = audio.sample_rate as f32 / size as f32;
+        let edges = band_edges(self.band_count, audio.sample_rate);
+        let bands = edges.windows(2).map(|edge| {
+            let first = ((edge[0] / bin_width).ceil() as usize).max(1);
+            let last = ((edge[1] / bin_width).ceil() as usize).min(magnitudes.len());
+            let level = if first < last {
+                magnitudes[first..last].iter().fold(0.0f32, |level, m| level.max(*m))
+            } else {
+                // Narrow low bands can fall between bins; use the bin nearest their middle
+                let nearest = (((edge[0] * edge[1]).sqrt() / bin_width).round() as usize).clamp(1, magnitudes.len().max(2) - 1);
+                magnitudes.get(nearest).copied().unwrap_or(0.0)
+            };
+            level * self.gain
+        }).collect();
+
+        Analysis { time, amplitude: amplitude * self.gain, peak: peak * self.gain, bands }
+    }
+}
+
+/// Frequencies bounding `count` logarithmically spaced bands, from 20 Hz to the Nyquist frequency
+pub fn band_edges(count: usize, sample_rate: u32) -> Vec<f32> {
+    let count = count.max(1);
+    let highest = (sample_rate as f32 / 2.0).max(LOWEST_FREQUENCY * 2.0);
+    (0..=count)
+        .map(|i| LOWEST_FREQUENCY * (highest / LOWEST_FREQUENCY).powf(i as f32 / count as f32))
+        .collect()
+}
+
+/// In-place radix-2 FFT; the length must be a power of two
+pub fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    debug_assert!(n.is_power_of_two() && im.len() == n);
+
+    // Bit-reversed order
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let tr = re[b] * cos - im[b] * sin;
+                let ti = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - tr;
+                im[b] = im[a] - ti;
+                re[a] += tr;
+                im[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Lowest and highest sample in each of `columns` equal slices of `samples`, for drawing the waveform
+pub fn waveform_overview(samples: &[f32], columns: usize) -> Vec<(f32, f32)> {
+    if samples.is_empty() || columns == 0 {
+        return Vec::new();
+    }
+    let per_column = samples.len().div_ceil(columns);
+    samples.chunks(per_column)
+        .map(|chunk| chunk.iter().fold((f32::MAX, f32::MIN), |(low, high), s| (low.min(*s), high.max(*s))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Half a second of a 1 kHz sine at half scale, sampled at 8 kHz
+    fn sine() -> AudioData {
+        AudioData {
+            source: "sine.wav".to_string(),
+            sample_rate: 8000,
+            channels: 1,
+            samples: (0..4000).map(|i| 0.5 * (2.0 * PI * 1000.0 * i as f32 / 8000.0).sin()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_sine_lands_in_its_band() {
+        let logic = AudioAnalysisLogic { window_size: 1024, band_count: 8, ..Default::default() };
+        // Frame 6 is a quarter second in, well inside the sound
+        let analysis = logic.analyze(&sine(), 6);
+        assert!((analysis.time - 0.25).abs() < 1e-6);
+        assert!((analysis.amplitude - 0.5 / 2f32.sqrt()).abs() < 1e-3);
+        assert!((analysis.peak - 0.5).abs() < 1e-3);
+
+        // 1 kHz is in the sixth band, 556 to 1081 Hz
+        let edges = band_edges(8, 8000);
+        assert!(edges[5] < 1000.0 && 1000.0 < edges[6]);
+        assert_eq!(analysis.bands.len(), 8);
+        assert!((analysis.bands[5] - 0.5).abs() < 0.01, "{:?}", analysis.bands);
+        assert!(analysis.bands.iter().enumerate().all(|(band, level)| band == 5 || *level < 0.05));
+    }
+
+    #[test]
+    fn test_outputs_past_the_end_are_silent() {
+        let logic = AudioAnalysisLogic { band: 5, offset: 24, ..Default::default() };
+        let outputs = logic.process(vec![NodeData::Audio(sine())], 0);
+        assert!(matches!(outputs[0], NodeData::Float(level) if level == 0.0));
+        assert!(matches!(&outputs[3], NodeData::Buffer(bands) if bands.len() == 8 && bands.iter().all(|b| *b == 0.0)));
+
+        let outputs = logic.process(vec![NodeData::Audio(sine())], -18);
+        assert!(matches!(outputs[2], NodeData::Float(level) if level > 0.4));
+
+        assert_eq!(waveform_overview(&[0.0, 1.0, -1.0, 0.5, 0.25], 2), vec![(-1.0, 1.0), (0.25, 0.5)]);
+    }
+}
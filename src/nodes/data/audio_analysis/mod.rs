@@ -0,0 +1,54 @@
+//! Audio Analysis Node
+//!
+//! Measures the loudness and frequency bands of a sound at the timeline frame,
+//! so audio can drive parameters. Like the Time node it is a source of time
+//! dependence: it and everything downstream are cached per frame.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::AudioAnalysisLogic;
+pub use parameters::AudioAnalysisNode;
+
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory};
+use crate::nodes::factory::{DataType, PortDefinition, ProcessingCost};
+use egui::Color32;
+
+/// Audio Analysis Node Factory
+#[derive(Default)]
+pub struct AudioAnalysisNodeFactory;
+
+impl NodeFactory for AudioAnalysisNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Data_AudioAnalysis",
+            "Audio Analysis",
+            NodeCategory::new(&["Data"]),
+            "Outputs the level and frequency bands of a sound at the current frame"
+        )
+        .with_color(Color32::from_rgb(90, 130, 90))
+        .with_icon("🎵")
+        .with_inputs(vec![
+            PortDefinition::required("Audio", DataType::Audio)
+                .with_description("Sound from a Read Audio node"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Amplitude", DataType::Float)
+                .with_description("RMS level around the current frame"),
+            PortDefinition::required("Peak", DataType::Float)
+                .with_description("Largest sample around the current frame"),
+            PortDefinition::required("Band", DataType::Float)
+                .with_description("Level of the band picked in the panel"),
+            PortDefinition::required("Bands", DataType::Buffer)
+                .with_description("Level of every band, lowest frequencies first"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["audio", "sound", "fft", "spectrum", "waveform", "music", "reactive", "time", "animation"])
+        .with_subtitle(|node| {
+            let logic = parameters::AudioAnalysisNode::create_logic(node);
+            Some(format!("{} bands, band {}", logic.band_count, logic.band))
+        })
+        .with_processing_cost(ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
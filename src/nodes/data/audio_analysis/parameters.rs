@@ -0,0 +1,201 @@
+//! Audio Analysis node parameters using Pattern A: build_interface method
+//!
+//! Below the settings the panel draws the waveform of the analysed sound with
+//! the current frame marked, and the band levels measured there.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use once_cell::sync::Lazy;
+use crate::nodes::interface::{AudioData, NodeData, ParameterChange};
+use crate::nodes::{Node, NodeId};
+use super::logic::{waveform_overview, Analysis, AudioAnalysisLogic, WINDOW_SIZES};
+
+/// Columns the waveform is reduced to for drawing
+const WAVEFORM_COLUMNS: usize = 512;
+
+/// What each node's panel draws, from its latest cook
+static WAVEFORMS: Lazy<Mutex<HashMap<NodeId, Waveform>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The analysed sound and its levels at the current frame
+#[derive(Debug, Clone, Default)]
+struct Waveform {
+    /// Source and sample count the overview was made from
+    source: (String, usize),
+    duration: f32,
+    overview: Vec<(f32, f32)>,
+    analysis: Analysis,
+}
+
+/// Audio Analysis node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct AudioAnalysisNode;
+
+impl AudioAnalysisNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Audio Analysis");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Frame Rate:");
+            let mut fps = logic.fps;
+            if ui.add(egui::DragValue::new(&mut fps).range(1.0..=240.0).speed(0.1).suffix(" fps")).changed() {
+                changes.push(ParameterChange {
+                    parameter: "fps".to_string(),
+                    value: NodeData::Float(fps),
+                });
+            }
+            ui.label("Offset:");
+            let mut offset = logic.offset;
+            if ui.add(egui::DragValue::new(&mut offset).speed(1.0).suffix(" frames")).changed() {
+                changes.push(ParameterChange {
+                    parameter: "offset".to_string(),
+                    value: NodeData::Integer(offset),
+                });
+            }
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Window:");
+            let mut window_size = logic.window_size;
+            egui::ComboBox::from_id_salt("audio_analysis_window")
+                .selected_text(format!("{} samples", window_size))
+                .show_ui(ui, |ui| {
+                    for size in WINDOW_SIZES {
+                        ui.selectable_value(&mut window_size, size, format!("{} samples", size));
+                    }
+                });
+            if window_size != logic.window_size {
+                changes.push(ParameterChange {
+                    parameter: "window_size".to_string(),
+                    value: NodeData::Integer(window_size as i32),
+                });
+            }
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Bands:");
+            let mut band_count = logic.band_count as i32;
+            if ui.add(egui::DragValue::new(&mut band_count).range(1..=32)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "band_count".to_string(),
+                    value: NodeData::Integer(band_count),
+                });
+            }
+            ui.label("Band Output:");
+            let mut band = logic.band as i32;
+            if ui.add(egui::DragValue::new(&mut band).range(0..=logic.band_count as i32 - 1)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "band".to_string(),
+                    value: NodeData::Integer(band),
+                });
+            }
+        });
+        
+        let mut gain = logic.gain;
+        if ui.add(egui::Slider::new(&mut gain, 0.0..=10.0).text("Gain")).changed() {
+            changes.push(ParameterChange {
+                parameter: "gain".to_string(),
+                value: NodeData::Float(gain),
+            });
+        }
+        
+        ui.separator();
+        
+        let waveform = WAVEFORMS.lock().ok().and_then(|waveforms| waveforms.get(&node.id).cloned());
+        match waveform {
+            Some(waveform) => draw_waveform(ui, &waveform, logic.band),
+            None => {
+                ui.label("Connect a Read Audio node to see its waveform");
+            }
+        }
+        
+        changes
+    }
+    
+    /// Build AudioAnalysisLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> AudioAnalysisLogic {
+        let defaults = AudioAnalysisLogic::default();
+        let get_float = |name: &str, default: f32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        let get_int = |name: &str, default: i32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        let band_count = get_int("band_count", defaults.band_count as i32).clamp(1, 32) as usize;
+        AudioAnalysisLogic {
+            fps: get_float("fps", defaults.fps),
+            offset: get_int("offset", defaults.offset),
+            window_size: get_int("window_size", defaults.window_size as i32).max(2) as usize,
+            band_count,
+            band: (get_int("band", defaults.band as i32).max(0) as usize).min(band_count - 1),
+            gain: get_float("gain", defaults.gain),
+        }
+    }
+    
+    /// Execute the node for the engine's current frame
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, frame: i64) -> Vec<NodeData> {
+        let logic = Self::create_logic(node);
+        if let Some(NodeData::Audio(audio)) = inputs.first() {
+            store_waveform(node.id, audio, logic.analyze(audio, frame));
+        } else if let Ok(mut waveforms) = WAVEFORMS.lock() {
+            waveforms.remove(&node.id);
+        }
+        logic.process(inputs, frame)
+    }
+}
+
+/// Keep what the panel draws; the overview is only rebuilt for a different sound
+fn store_waveform(node_id: NodeId, audio: &AudioData, analysis: Analysis) {
+    let Ok(mut waveforms) = WAVEFORMS.lock() else {
+        return;
+    };
+    let waveform = waveforms.entry(node_id).or_default();
+    let source = (audio.source.clone(), audio.samples.len());
+    if waveform.source != source {
+        waveform.overview = waveform_overview(&audio.samples, WAVEFORM_COLUMNS);
+        waveform.duration = audio.duration();
+        waveform.source = source;
+    }
+    waveform.analysis = analysis;
+}
+
+/// The waveform with the current moment marked, then a bar per band
+fn draw_waveform(ui: &mut egui::Ui, waveform: &Waveform, selected_band: usize) {
+    let analysis = &waveform.analysis;
+    ui.label(format!(
+        "{:.2}s of {:.2}s   level {:.3}   peak {:.3}",
+        analysis.time, waveform.duration, analysis.amplitude, analysis.peak
+    ));
+    
+    let width = ui.available_width();
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 80.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+    let stroke = egui::Stroke::new(1.0, visuals.widgets.inactive.fg_stroke.color);
+    let columns = waveform.overview.len().max(1) as f32;
+    for (column, (low, high)) in waveform.overview.iter().enumerate() {
+        let x = rect.left() + (column as f32 + 0.5) / columns * rect.width();
+        let y = |value: f32| rect.center().y - value.clamp(-1.0, 1.0) * rect.height() * 0.5;
+        painter.line_segment([egui::pos2(x, y(*high)), egui::pos2(x, y(*low))], stroke);
+    }
+    if waveform.duration > 0.0 {
+        let x = rect.left() + (analysis.time / waveform.duration).clamp(0.0, 1.0) * rect.width();
+        painter.line_segment([egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())], egui::Stroke::new(2.0, egui::Color32::from_rgb(230, 150, 90)));
+    }
+    
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 60.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+    let bar_width = rect.width() / analysis.bands.len().max(1) as f32;
+    for (band, level) in analysis.bands.iter().enumerate() {
+        let left = rect.left() + band as f32 * bar_width;
+        let top = rect.bottom() - level.clamp(0.0, 1.0) * rect.height();
+        let color = if band == selected_band { egui::Color32::from_rgb(230, 150, 90) } else { visuals.selection.bg_fill };
+        painter.rect_filled(egui::Rect::from_min_max(egui::pos2(left + 1.0, top), egui::pos2(left + bar_width - 1.0, rect.bottom())), 0.0, color);
+    }
+}
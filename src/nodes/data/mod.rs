@@ -3,5 +3,9 @@
 pub mod constant;   // Modular directory structure
 pub mod variable;   // Modular directory structure
 pub mod usd_file_reader;  // USD file input node
+pub mod read_csv;   // CSV table input node
+pub mod read_json;  // JSON table input node
+pub mod read_audio; // WAV audio input node
+pub mod audio_analysis; // Audio level and spectrum per frame
 
 // Factory and legacy exports removed - unused
\ No newline at end of file
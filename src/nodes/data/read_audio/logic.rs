@@ -0,0 +1,177 @@
+//! Read Audio logic - decoding WAV files
+//!
+//! Integer PCM of 8, 16, 24 and 32 bits and 32 or 64-bit float samples are
+//! read, also from WAVE_FORMAT_EXTENSIBLE files. The channels are averaged
+//! into one, since the analysis nodes look at the sound as a whole.
+
+use crate::nodes::interface::{AudioData, NodeData};
+use crate::nodes::project_paths;
+
+/// File extensions the node reads
+pub const AUDIO_EXTENSIONS: &[&str] = &["wav", "wave"];
+
+const FORMAT_PCM: u16 = 0x0001;
+const FORMAT_FLOAT: u16 = 0x0003;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Core Read Audio configuration
+#[derive(Debug, Clone, Default)]
+pub struct ReadAudioLogic {
+    pub file_path: String,
+}
+
+impl ReadAudioLogic {
+    /// Read the file's samples; None when no file is set or it cannot be read
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+
+        let path = project_paths::resolve_path(&self.file_path);
+        match std::fs::read(&path).map_err(|e| e.to_string()).and_then(|bytes| parse_wav(&bytes)) {
+            Ok(mut audio) => {
+                audio.source = path.to_string_lossy().to_string();
+                vec![NodeData::Audio(audio)]
+            }
+            Err(e) => {
+                log::error!("Read Audio: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+}
+
+/// Decode a RIFF WAVE file, mixing its channels down to one
+pub fn parse_wav(bytes: &[u8]) -> Result<AudioData, String> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("not a WAV file".to_string());
+    }
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &bytes[offset + 8..(offset + 8).saturating_add(size).min(bytes.len())];
+        match id {
+            b"fmt " => format = Some(parse_format(body)?),
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        // Chunks are padded to an even size
+        offset = offset.saturating_add(8 + size + (size & 1));
+    }
+
+    let format = format.ok_or("no fmt chunk")?;
+    let data = data.ok_or("no data chunk")?;
+    let width = format.bits as usize / 8;
+    let frame_size = width * format.channels as usize;
+    let decode: fn(&[u8]) -> f32 = match (format.tag, format.bits) {
+        (FORMAT_PCM, 8) => |b| (b[0] as f32 - 128.0) / 128.0,
+        (FORMAT_PCM, 16) => |b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0,
+        (FORMAT_PCM, 24) => |b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0,
+        (FORMAT_PCM, 32) => |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0,
+        (FORMAT_FLOAT, 32) => |b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        (FORMAT_FLOAT, 64) => |b| f64::from_le_bytes(b[..8].try_into().unwrap()) as f32,
+        (tag, bits) => return Err(format!("unsupported sample format {:#06x} with {} bits", tag, bits)),
+    };
+
+    let samples = data.chunks_exact(frame_size)
+        .map(|frame| frame.chunks_exact(width).map(decode).sum::<f32>() / format.channels as f32)
+        .collect();
+    Ok(AudioData {
+        source: String::new(),
+        sample_rate: format.sample_rate,
+        channels: format.channels,
+        samples,
+    })
+}
+
+/// Sample layout from a fmt chunk
+struct WavFormat {
+    tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    bits: u16,
+}
+
+fn parse_format(body: &[u8]) -> Result<WavFormat, String> {
+    if body.len() < 16 {
+        return Err("fmt chunk is too short".to_string());
+    }
+    let u16_at = |at: usize| u16::from_le_bytes([body[at], body[at + 1]]);
+    let mut tag = u16_at(0);
+    // Extensible files keep the real format in the first bytes of their sub-format GUID
+    if tag == FORMAT_EXTENSIBLE {
+        if body.len() < 26 {
+            return Err("extensible fmt chunk is too short".to_string());
+        }
+        tag = u16_at(24);
+    }
+    let format = WavFormat {
+        tag,
+        channels: u16_at(2),
+        sample_rate: u32::from_le_bytes(body[4..8].try_into().unwrap()),
+        bits: u16_at(14),
+    };
+    if format.channels == 0 || format.sample_rate == 0 || format.bits == 0 || format.bits % 8 != 0 {
+        return Err(format!("invalid format: {} channels, {} Hz, {} bits", format.channels, format.sample_rate, format.bits));
+    }
+    Ok(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A WAV file around `data` with the given fmt fields
+    fn wav(tag: u16, channels: u16, sample_rate: u32, bits: u16, data: &[u8]) -> Vec<u8> {
+        let block_align = channels * bits / 8;
+        let mut fmt = Vec::new();
+        fmt.extend(tag.to_le_bytes());
+        fmt.extend(channels.to_le_bytes());
+        fmt.extend(sample_rate.to_le_bytes());
+        fmt.extend((sample_rate * block_align as u32).to_le_bytes());
+        fmt.extend(block_align.to_le_bytes());
+        fmt.extend(bits.to_le_bytes());
+
+        let mut bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+        // An odd-sized chunk the reader skips, with its padding byte
+        bytes.extend(b"LIST\x03\0\0\0abc\0");
+        bytes.extend(b"fmt ");
+        bytes.extend((fmt.len() as u32).to_le_bytes());
+        bytes.extend(fmt);
+        bytes.extend(b"data");
+        bytes.extend((data.len() as u32).to_le_bytes());
+        bytes.extend(data);
+        let riff_size = (bytes.len() - 8) as u32;
+        bytes[4..8].copy_from_slice(&riff_size.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_stereo_pcm_is_mixed_down() {
+        let data: Vec<u8> = [i16::MAX, 0, -16384, -16384].iter().flat_map(|s| s.to_le_bytes()).collect();
+        let audio = parse_wav(&wav(FORMAT_PCM, 2, 8000, 16, &data)).unwrap();
+        assert_eq!(audio.sample_rate, 8000);
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.samples.len(), 2);
+        assert!((audio.samples[0] - 0.5).abs() < 1e-3);
+        assert!((audio.samples[1] + 0.5).abs() < 1e-6);
+        assert!((audio.duration() - 2.0 / 8000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_float_and_24_bit_samples_decode() {
+        let data: Vec<u8> = [0.25f32, -1.0].iter().flat_map(|s| s.to_le_bytes()).collect();
+        assert_eq!(parse_wav(&wav(FORMAT_FLOAT, 1, 44100, 32, &data)).unwrap().samples, vec![0.25, -1.0]);
+
+        // -2^22 and 2^22 are a half below and above zero
+        let data = [0x00, 0x00, 0xC0, 0x00, 0x00, 0x40];
+        assert_eq!(parse_wav(&wav(FORMAT_PCM, 1, 48000, 24, &data)).unwrap().samples, vec![-0.5, 0.5]);
+
+        assert!(parse_wav(&wav(0x0055, 1, 44100, 16, &[])).is_err());
+        assert!(parse_wav(b"OggS").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! Read Audio Node
+//!
+//! Reads WAV files into audio samples for the Audio Analysis node.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadAudioLogic;
+pub use parameters::ReadAudioNode;
+
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory};
+use crate::nodes::factory::{DataType, PortDefinition, ProcessingCost};
+use egui::Color32;
+
+/// Read Audio Node Factory
+#[derive(Default)]
+pub struct ReadAudioNodeFactory;
+
+impl NodeFactory for ReadAudioNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Data_ReadAudio",
+            "Read Audio",
+            NodeCategory::new(&["Data"]),
+            "Reads a WAV file into audio samples for audio-reactive graphs"
+        )
+        .with_color(Color32::from_rgb(90, 130, 90))
+        .with_icon("🔊")
+        .with_inputs(vec![
+            // No inputs - this is a data source node
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Audio", DataType::Audio)
+                .with_description("Samples of the file, mixed down to mono"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["audio", "sound", "wav", "music", "file", "input", "data", "import"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,153 @@
+//! Read CSV logic - file loading and delimited text parsing
+
+use crate::nodes::project_paths;
+use crate::nodes::interface::{NodeData, TableData, TableValue};
+
+/// Core Read CSV data and functionality
+#[derive(Debug, Clone)]
+pub struct ReadCsvLogic {
+    /// Path of the CSV file
+    pub file_path: String,
+    /// Field delimiter
+    pub delimiter: char,
+    /// Whether the first record holds column names
+    pub has_header: bool,
+    /// Comma-separated column selection (empty = all columns)
+    pub columns: String,
+}
+
+impl Default for ReadCsvLogic {
+    fn default() -> Self {
+        Self {
+            file_path: String::new(),
+            delimiter: ',',
+            has_header: true,
+            columns: String::new(),
+        }
+    }
+}
+
+impl ReadCsvLogic {
+    /// Read the file and return the selected table
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+        
+        match std::fs::read_to_string(project_paths::resolve_path(&self.file_path)) {
+            Ok(text) => {
+                let table = parse_csv(&text, self.delimiter, self.has_header);
+                vec![NodeData::Table(table.select(&self.columns))]
+            }
+            Err(e) => {
+                log::error!("Read CSV: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+}
+
+/// Parse delimited text into a table, honouring double-quoted fields
+pub fn parse_csv(text: &str, delimiter: char, has_header: bool) -> TableData {
+    let mut records = parse_records(text, delimiter).into_iter();
+    
+    let mut columns: Vec<String> = if has_header {
+        records.next().unwrap_or_default().into_iter().map(|c| c.trim().to_string()).collect()
+    } else {
+        Vec::new()
+    };
+    
+    let rows: Vec<Vec<TableValue>> = records
+        .map(|record| record.iter().map(|field| TableValue::infer(field)).collect())
+        .collect();
+    
+    // Headerless files get generated column names; short headers are padded
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    for i in columns.len()..width {
+        columns.push(format!("column_{}", i));
+    }
+    
+    TableData { columns, rows }
+}
+
+/// Split text into records of fields (RFC 4180 style quoting)
+fn parse_records(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            record.push(std::mem::take(&mut field));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            record.push(std::mem::take(&mut field));
+            // Skip blank lines
+            if !(record.len() == 1 && record[0].is_empty()) {
+                records.push(std::mem::take(&mut record));
+            } else {
+                record.clear();
+            }
+        } else {
+            field.push(c);
+        }
+    }
+    
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_with_header() {
+        let table = parse_csv("x,y,name\n1,2.5,a\n3,4,b\n", ',', true);
+        assert_eq!(table.columns, vec!["x", "y", "name"]);
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.rows[0][1], TableValue::Number(2.5));
+        assert_eq!(table.rows[1][2], TableValue::Text("b".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_quoted_fields() {
+        let table = parse_csv("label\n\"hello, \"\"world\"\"\"\n", ',', true);
+        assert_eq!(table.rows[0][0], TableValue::Text("hello, \"world\"".to_string()));
+    }
+
+    #[test]
+    fn test_parse_csv_without_header() {
+        let table = parse_csv("1;2\n3;4", ';', false);
+        assert_eq!(table.columns, vec!["column_0", "column_1"]);
+        assert_eq!(table.row_count(), 2);
+    }
+
+    #[test]
+    fn test_column_selection() {
+        let table = parse_csv("x,y,z\n1,2,3\n", ',', true).select("z, x");
+        assert_eq!(table.columns, vec!["z", "x"]);
+        assert_eq!(table.rows[0], vec![TableValue::Number(3.0), TableValue::Number(1.0)]);
+    }
+}
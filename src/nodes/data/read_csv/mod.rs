@@ -0,0 +1,42 @@
+//! Read CSV Node
+//!
+//! Reads comma (or custom) delimited text files into a table for data-driven graphs.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadCsvLogic;
+pub use parameters::ReadCsvNode;
+
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory};
+use crate::nodes::factory::{DataType, PortDefinition, ProcessingCost};
+use egui::Color32;
+
+/// Read CSV Node Factory
+#[derive(Default)]
+pub struct ReadCsvNodeFactory;
+
+impl NodeFactory for ReadCsvNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Data_ReadCsv",
+            "Read CSV",
+            NodeCategory::new(&["Data"]),
+            "Reads a CSV file into a table with optional column selection"
+        )
+        .with_color(Color32::from_rgb(90, 130, 90))
+        .with_icon("📄")
+        .with_inputs(vec![
+            // No inputs - this is a data source node
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Table", DataType::Table)
+                .with_description("Rows and selected columns from the file"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["csv", "file", "input", "table", "spreadsheet", "data", "import"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
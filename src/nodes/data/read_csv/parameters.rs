@@ -0,0 +1,104 @@
+//! Read CSV node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
+use super::logic::ReadCsvLogic;
+
+/// Read CSV node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ReadCsvNode;
+
+impl ReadCsvNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Read CSV");
+        ui.separator();
+        
+        // File selection
+        ui.horizontal(|ui| {
+            ui.label("CSV File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("CSV Files", &["csv", "tsv", "txt"])
+                .add_filter("All Files", &["*"])
+                .set_title("Select CSV File")
+                .pick_file();
+            if let Some(file_path) = project_paths::path_browser(ui, &logic.file_path, "Select CSV file...", PathKind::Input(&["csv", "tsv", "txt"]), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(file_path),
+                });
+            }
+        });
+        
+        // Parsing options
+        ui.horizontal(|ui| {
+            ui.label("Delimiter:");
+            let mut delimiter = if logic.delimiter == '\t' { "\\t".to_string() } else { logic.delimiter.to_string() };
+            if ui.add(egui::TextEdit::singleline(&mut delimiter).desired_width(30.0)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "delimiter".to_string(),
+                    value: NodeData::String(delimiter),
+                });
+            }
+            ui.label("(use \\t for tab)");
+        });
+        
+        let mut has_header = logic.has_header;
+        if ui.checkbox(&mut has_header, "First row is header").changed() {
+            changes.push(ParameterChange {
+                parameter: "has_header".to_string(),
+                value: NodeData::Boolean(has_header),
+            });
+        }
+        
+        ui.separator();
+        
+        // Column selection
+        ui.horizontal(|ui| {
+            ui.label("Columns:");
+            let mut columns = logic.columns.clone();
+            if ui.add(egui::TextEdit::singleline(&mut columns)
+                .desired_width(200.0)
+                .hint_text("all (e.g. x, y, z)")).changed()
+            {
+                changes.push(ParameterChange {
+                    parameter: "columns".to_string(),
+                    value: NodeData::String(columns),
+                });
+            }
+        });
+        
+        changes
+    }
+    
+    /// Build ReadCsvLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ReadCsvLogic {
+        let defaults = ReadCsvLogic::default();
+        let get_string = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None });
+        
+        let delimiter = match get_string("delimiter").as_deref() {
+            Some("\\t") => '\t',
+            Some(s) => s.chars().next().unwrap_or(defaults.delimiter),
+            None => defaults.delimiter,
+        };
+        
+        ReadCsvLogic {
+            file_path: get_string("file_path").unwrap_or(defaults.file_path),
+            delimiter,
+            has_header: node.parameters.get("has_header")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.has_header),
+            columns: get_string("columns").unwrap_or(defaults.columns),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,183 @@
+//! Read JSON logic - file loading and conversion of JSON records to a table and tree
+
+use crate::nodes::project_paths;
+use crate::nodes::interface::{NodeData, TableData, TableValue, TreeData};
+use serde_json::Value;
+
+/// Core Read JSON data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct ReadJsonLogic {
+    /// Path of the JSON file
+    pub file_path: String,
+    /// JSON pointer to the records (e.g. "/data"); empty = document root
+    pub records_path: String,
+    /// Comma-separated column selection (empty = all columns)
+    pub columns: String,
+}
+
+impl ReadJsonLogic {
+    /// Read the file and return the selected table and the document tree
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None, NodeData::None];
+        }
+        
+        let text = match std::fs::read_to_string(project_paths::resolve_path(&self.file_path)) {
+            Ok(text) => text,
+            Err(e) => {
+                log::error!("Read JSON: failed to read '{}': {}", self.file_path, e);
+                return vec![NodeData::None, NodeData::None];
+            }
+        };
+        
+        let document: Value = match serde_json::from_str(&text) {
+            Ok(document) => document,
+            Err(e) => {
+                log::error!("Read JSON: invalid JSON: {}", e);
+                return vec![NodeData::None, NodeData::None];
+            }
+        };
+        
+        let root_label = std::path::Path::new(&self.file_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.file_path.clone());
+        let tree = NodeData::Tree(TreeData::from_json(&document, &root_label));
+        
+        match table_from_document(&document, &self.records_path) {
+            Ok(table) => vec![NodeData::Table(table.select(&self.columns)), tree],
+            Err(e) => {
+                log::error!("Read JSON: {}", e);
+                vec![NodeData::None, tree]
+            }
+        }
+    }
+}
+
+/// Parse JSON text into a table.
+///
+/// Accepts an array of objects (one row per object), an array of arrays,
+/// an array of scalars (single `value` column) or an object of equal-length
+/// arrays (one column per key).
+pub fn parse_json_table(text: &str, records_path: &str) -> Result<TableData, String> {
+    let document: Value = serde_json::from_str(text)
+        .map_err(|e| format!("invalid JSON: {}", e))?;
+    table_from_document(&document, records_path)
+}
+
+/// Convert an already parsed JSON document into a table
+fn table_from_document(document: &Value, records_path: &str) -> Result<TableData, String> {
+    let records = if records_path.trim().is_empty() {
+        document
+    } else {
+        document.pointer(records_path.trim())
+            .ok_or_else(|| format!("records path '{}' not found", records_path))?
+    };
+    
+    match records {
+        Value::Array(items) => Ok(table_from_array(items)),
+        Value::Object(map) => {
+            let columns: Vec<String> = map.keys().cloned().collect();
+            let length = map.values()
+                .map(|v| v.as_array().map(|a| a.len()).unwrap_or(1))
+                .max()
+                .unwrap_or(0);
+            let rows = (0..length)
+                .map(|i| map.values()
+                    .map(|v| match v {
+                        Value::Array(a) => a.get(i).map(to_table_value).unwrap_or(TableValue::Null),
+                        scalar if i == 0 => to_table_value(scalar),
+                        _ => TableValue::Null,
+                    })
+                    .collect())
+                .collect();
+            Ok(TableData { columns, rows })
+        }
+        _ => Err("records must be an array or an object of arrays".to_string()),
+    }
+}
+
+/// Build a table from a JSON array of records
+fn table_from_array(items: &[Value]) -> TableData {
+    let mut columns: Vec<String> = Vec::new();
+    
+    if items.iter().any(|item| item.is_object()) {
+        // Union of keys, in first-seen order
+        for item in items {
+            if let Value::Object(map) = item {
+                for key in map.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+        }
+        let rows = items.iter()
+            .map(|item| columns.iter()
+                .map(|key| item.get(key).map(to_table_value).unwrap_or(TableValue::Null))
+                .collect())
+            .collect();
+        return TableData { columns, rows };
+    }
+    
+    if items.iter().any(|item| item.is_array()) {
+        let width = items.iter().filter_map(|item| item.as_array()).map(|a| a.len()).max().unwrap_or(0);
+        columns = (0..width).map(|i| format!("column_{}", i)).collect();
+        let rows = items.iter()
+            .map(|item| match item {
+                Value::Array(values) => values.iter().map(to_table_value).collect(),
+                scalar => vec![to_table_value(scalar)],
+            })
+            .collect();
+        return TableData { columns, rows };
+    }
+    
+    TableData {
+        columns: vec!["value".to_string()],
+        rows: items.iter().map(|item| vec![to_table_value(item)]).collect(),
+    }
+}
+
+/// Convert a JSON value into a table cell (nested values are kept as JSON text)
+fn to_table_value(value: &Value) -> TableValue {
+    match value {
+        Value::Null => TableValue::Null,
+        Value::Bool(b) => TableValue::Boolean(*b),
+        Value::Number(n) => n.as_f64().map(TableValue::Number).unwrap_or(TableValue::Null),
+        Value::String(s) => TableValue::Text(s.clone()),
+        nested => TableValue::Text(nested.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_array_of_objects() {
+        let table = parse_json_table(r#"[{"x": 1, "y": 2}, {"x": 3, "name": "b"}]"#, "").unwrap();
+        assert_eq!(table.columns, vec!["x", "y", "name"]);
+        assert_eq!(table.rows[1], vec![TableValue::Number(3.0), TableValue::Null, TableValue::Text("b".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_columnar_object_with_pointer() {
+        let table = parse_json_table(r#"{"data": {"x": [1, 2], "y": [3, 4]}}"#, "/data").unwrap();
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.column("y").unwrap(), vec![TableValue::Number(3.0), TableValue::Number(4.0)]);
+    }
+
+    #[test]
+    fn test_document_tree_paths() {
+        let document: Value = serde_json::from_str(r#"{"items": [{"name": "a"}]}"#).unwrap();
+        let tree = TreeData::from_json(&document, "doc.json");
+        assert_eq!(tree.item_count(), 4);
+        let leaf = tree.find("/items/0/name").unwrap();
+        assert_eq!(leaf.detail.as_deref(), Some("\"a\""));
+    }
+
+    #[test]
+    fn test_missing_records_path() {
+        assert!(parse_json_table("[]", "/missing").is_err());
+    }
+}
@@ -0,0 +1,45 @@
+//! Read JSON Node
+//!
+//! Reads JSON record arrays or columnar objects into a table for data-driven graphs,
+//! and exposes the full document as a tree for the Tree panel.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadJsonLogic;
+pub use parameters::ReadJsonNode;
+
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory};
+use crate::nodes::factory::{DataType, PortDefinition, ProcessingCost};
+use egui::Color32;
+
+/// Read JSON Node Factory
+#[derive(Default)]
+pub struct ReadJsonNodeFactory;
+
+impl NodeFactory for ReadJsonNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Data_ReadJson",
+            "Read JSON",
+            NodeCategory::new(&["Data"]),
+            "Reads a JSON file into a table with optional column selection"
+        )
+        .with_color(Color32::from_rgb(90, 110, 140))
+        .with_icon("{}")
+        .with_inputs(vec![
+            // No inputs - this is a data source node
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Table", DataType::Table)
+                .with_description("Rows and selected columns from the file"),
+            PortDefinition::optional("Tree", DataType::Tree)
+                .with_description("Document hierarchy for the Tree panel"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["json", "file", "input", "table", "spreadsheet", "data", "import"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,88 @@
+//! Read JSON node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
+use super::logic::ReadJsonLogic;
+
+/// Read JSON node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ReadJsonNode;
+
+impl ReadJsonNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Read JSON");
+        ui.separator();
+        
+        // File selection
+        ui.horizontal(|ui| {
+            ui.label("JSON File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("JSON Files", &["json"])
+                .add_filter("All Files", &["*"])
+                .set_title("Select JSON File")
+                .pick_file();
+            if let Some(file_path) = project_paths::path_browser(ui, &logic.file_path, "Select JSON file...", PathKind::Input(&["json"]), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(file_path),
+                });
+            }
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Records Path:");
+            let mut records_path = logic.records_path.clone();
+            if ui.add(egui::TextEdit::singleline(&mut records_path)
+                .desired_width(200.0)
+                .hint_text("root (e.g. /data/points)")).changed()
+            {
+                changes.push(ParameterChange {
+                    parameter: "records_path".to_string(),
+                    value: NodeData::String(records_path),
+                });
+            }
+        });
+        
+        ui.separator();
+        
+        // Column selection
+        ui.horizontal(|ui| {
+            ui.label("Columns:");
+            let mut columns = logic.columns.clone();
+            if ui.add(egui::TextEdit::singleline(&mut columns)
+                .desired_width(200.0)
+                .hint_text("all (e.g. x, y, z)")).changed()
+            {
+                changes.push(ParameterChange {
+                    parameter: "columns".to_string(),
+                    value: NodeData::String(columns),
+                });
+            }
+        });
+        
+        changes
+    }
+    
+    /// Build ReadJsonLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ReadJsonLogic {
+        let get_string = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        
+        ReadJsonLogic {
+            file_path: get_string("file_path"),
+            records_path: get_string("records_path"),
+            columns: get_string("columns"),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::nodes::interface::NodeData;
 use crate::nodes::{Node, NodeId};
+use crate::nodes::project_paths;
 use crate::workspaces::three_d::usd::usd_engine::{USDEngine, USDSceneData};
 use std::path::Path;
 use glam::Mat4;
@@ -30,8 +31,9 @@ pub struct UsdFileReaderLogic {
 impl UsdFileReaderLogic {
     /// Create logic instance from node parameters
     pub fn from_node(node: &Node) -> Self {
+        // Relative paths are stored relative to the project folder
         let file_path = node.parameters.get("file_path")
-            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .and_then(|v| if let NodeData::String(s) = v { Some(project_paths::resolve_path_string(s)) } else { None })
             .unwrap_or_default();
         
         let needs_reload = node.parameters.get("needs_reload")
@@ -372,7 +374,7 @@ impl UsdFileReaderLogic {
     /// Update logic parameters from current node state
     pub fn update_from_node(&mut self, node: &Node) {
         self.file_path = node.parameters.get("file_path")
-            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .and_then(|v| if let NodeData::String(s) = v { Some(project_paths::resolve_path_string(s)) } else { None })
             .unwrap_or_default();
         
         self.needs_reload = node.parameters.get("needs_reload")
@@ -32,10 +32,11 @@ impl NodeFactory for UsdFileReaderNodeFactory {
             // No inputs - this is a data source node
         ])
         .with_outputs(vec![
-            PortDefinition::required("Scene", DataType::Any)
+            PortDefinition::required("Scene", DataType::USDScene)
                 .with_description("USD scene data with geometry, materials, and lights")
         ])
         .with_tags(vec!["usd", "file", "input", "3d", "scene", "geometry", "import"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
         .with_processing_cost(ProcessingCost::Medium)
         .with_workspace_compatibility(vec!["USD", "3D", "General"])
     }
@@ -5,6 +5,7 @@
 
 use crate::nodes::interface::{NodeData, ParameterChange};
 use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
 use egui::Ui;
 
 /// Parameter interface for USD File Reader node
@@ -28,78 +29,29 @@ impl UsdFileReaderParameters {
             ui.label("📁 File Selection");
             ui.separator();
             
-            ui.horizontal(|ui| {
-                ui.label("USD File:");
-                
-                let mut file_path_input = file_path.clone();
-                
-                // File path text input
-                let text_response = ui.add(egui::TextEdit::singleline(&mut file_path_input)
-                    .desired_width(200.0)
-                    .hint_text("Select USD file..."));
-                
-                if text_response.changed() {
-                    changes.push(ParameterChange {
-                        parameter: "file_path".to_string(),
-                        value: NodeData::String(file_path_input.clone()),
-                    });
-                    // Trigger reload by updating a reload flag
-                    changes.push(ParameterChange {
-                        parameter: "needs_reload".to_string(),
-                        value: NodeData::Boolean(true),
-                    });
-                }
-                
-                // Browse button with file dialog
-                if ui.button("Browse...").clicked() {
-                    if let Some(path) = rfd::FileDialog::new()
-                        .add_filter("USD Files", &["usd", "usda", "usdc", "usdz"])
-                        .add_filter("All Files", &["*"])
-                        .set_title("Select USD File")
-                        .pick_file()
-                    {
-                        let path_str = path.display().to_string();
-                        // Only trigger reload if the file path changed OR the file was modified
-                        let should_reload = if path_str != file_path {
-                            // File path changed - definitely need to reload
-                            true
-                        } else {
-                            // Same file path - check if file was modified since last load
-                            // This matches the cache key logic: file_path + modification_timestamp
-                            if let Ok(metadata) = std::fs::metadata(&path_str) {
-                                if let Ok(current_modified) = metadata.modified() {
-                                    // Get the last known modification time from cache key logic
-                                    // For now, we'll be conservative and not reload if same file
-                                    false
-                                } else {
-                                    // Can't read modification time - be safe and reload
-                                    true
-                                }
-                            } else {
-                                // Can't read file metadata - be safe and reload
-                                true
-                            }
-                        };
-                        
-                        if should_reload {
-                            changes.push(ParameterChange {
-                                parameter: "file_path".to_string(),
-                                value: NodeData::String(path_str),
-                            });
-                            changes.push(ParameterChange {
-                                parameter: "needs_reload".to_string(),
-                                value: NodeData::Boolean(true),
-                            });
-                        }
-                    }
-                }
-            });
+            ui.label("USD File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("USD Files", &["usd", "usda", "usdc", "usdz"])
+                .add_filter("All Files", &["*"])
+                .set_title("Select USD File")
+                .pick_file();
+            if let Some(new_path) = project_paths::path_browser(ui, &file_path, "Select USD file...", PathKind::Input(&["usd", "usda", "usdc", "usdz"]), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(new_path),
+                });
+                // Trigger reload by updating a reload flag
+                changes.push(ParameterChange {
+                    parameter: "needs_reload".to_string(),
+                    value: NodeData::Boolean(true),
+                });
+            }
             
             // File info display
             if !file_path.is_empty() {
                 ui.horizontal(|ui| {
                     ui.label("📄");
-                    if std::path::Path::new(&file_path).exists() {
+                    if project_paths::resolve_path(&file_path).exists() {
                         ui.colored_label(egui::Color32::LIGHT_GREEN, "File found");
                     } else {
                         ui.colored_label(egui::Color32::LIGHT_RED, "File not found");
@@ -256,13 +208,13 @@ impl UsdFileReaderParameters {
             
             if file_path.is_empty() {
                 ui.colored_label(egui::Color32::GRAY, "No file selected");
-            } else if !std::path::Path::new(&file_path).exists() {
+            } else if !project_paths::resolve_path(&file_path).exists() {
                 ui.colored_label(egui::Color32::LIGHT_RED, "File not found - check path");
             } else {
                 ui.colored_label(egui::Color32::LIGHT_GREEN, "Ready to load USD file");
                 
                 // Show file size if available
-                if let Ok(metadata) = std::fs::metadata(&file_path) {
+                if let Ok(metadata) = std::fs::metadata(project_paths::resolve_path(&file_path)) {
                     let size_mb = metadata.len() as f64 / (1024.0 * 1024.0);
                     ui.label(format!("File size: {:.2} MB", size_mb));
                 }
@@ -0,0 +1,166 @@
+//! Breakpoints and step-through cooking
+//!
+//! A cook pauses before executing a node that has a breakpoint, leaving that
+//! node and everything after it dirty. Stepping runs the paused node and
+//! pauses again before the next one; continuing runs on to the next
+//! breakpoint. The execution engine owns the debugger and asks it before
+//! every node whether to stop there.
+
+use std::collections::HashSet;
+use crate::nodes::NodeId;
+
+/// Breakpoints and the pause state of the current cook
+#[derive(Debug, Default)]
+pub struct CookDebugger {
+    breakpoints: HashSet<NodeId>,
+    /// Dirty node the cook is paused before
+    paused_at: Option<NodeId>,
+    /// Node released by a step or continue; it runs once without pausing
+    released: Option<NodeId>,
+    /// Pause before the next node whatever its breakpoints (set by a step)
+    break_on_next: bool,
+}
+
+impl CookDebugger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or remove the breakpoint on a node; returns whether it now has one
+    pub fn toggle_breakpoint(&mut self, node_id: NodeId) -> bool {
+        if self.breakpoints.remove(&node_id) {
+            false
+        } else {
+            self.breakpoints.insert(node_id);
+            true
+        }
+    }
+
+    pub fn has_breakpoint(&self, node_id: NodeId) -> bool {
+        self.breakpoints.contains(&node_id)
+    }
+
+    pub fn breakpoints(&self) -> &HashSet<NodeId> {
+        &self.breakpoints
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    /// Node the cook is paused before, if paused
+    pub fn paused_at(&self) -> Option<NodeId> {
+        self.paused_at
+    }
+
+    /// Run the paused node, then pause before the next dirty one
+    pub fn step(&mut self) {
+        if let Some(node_id) = self.paused_at.take() {
+            self.released = Some(node_id);
+            self.break_on_next = true;
+        }
+    }
+
+    /// Run from the paused node on to the next breakpoint
+    pub fn resume(&mut self) {
+        if let Some(node_id) = self.paused_at.take() {
+            self.released = Some(node_id);
+            self.break_on_next = false;
+        }
+    }
+
+    /// Drop the breakpoint and pause of a removed node
+    pub fn forget_node(&mut self, node_id: NodeId) {
+        self.breakpoints.remove(&node_id);
+        if self.paused_at == Some(node_id) {
+            self.paused_at = None;
+        }
+    }
+
+    /// Called before a cook with the dirty nodes; false while paused, so the cook waits for a step or continue
+    pub fn begin_cook(&mut self, dirty_nodes: &HashSet<NodeId>) -> bool {
+        match self.paused_at {
+            Some(node_id) if dirty_nodes.contains(&node_id) => false,
+            Some(_) => {
+                // The paused node got cooked or removed some other way
+                self.paused_at = None;
+                true
+            }
+            None => true,
+        }
+    }
+
+    /// Called before executing each dirty node; true stops the cook before it
+    pub fn should_pause(&mut self, node_id: NodeId) -> bool {
+        if self.released == Some(node_id) {
+            self.released = None;
+            return false;
+        }
+        if self.break_on_next || self.breakpoints.contains(&node_id) {
+            self.paused_at = Some(node_id);
+            self.released = None;
+            self.break_on_next = false;
+            return true;
+        }
+        false
+    }
+
+    /// Called when a cook ran every dirty node (or failed)
+    pub fn end_cook(&mut self) {
+        self.released = None;
+        self.break_on_next = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one cook over `order` like the engine does, returning the nodes executed
+    fn cook(debugger: &mut CookDebugger, order: &[NodeId], dirty: &mut HashSet<NodeId>) -> Vec<NodeId> {
+        let mut executed = Vec::new();
+        if !debugger.begin_cook(dirty) {
+            return executed;
+        }
+        for node_id in order {
+            if !dirty.contains(node_id) {
+                continue;
+            }
+            if debugger.should_pause(*node_id) {
+                return executed;
+            }
+            dirty.remove(node_id);
+            executed.push(*node_id);
+        }
+        debugger.end_cook();
+        executed
+    }
+
+    #[test]
+    fn test_breakpoint_pauses_then_steps_and_continues() {
+        let order = [1, 2, 3, 4];
+        let mut dirty: HashSet<NodeId> = order.iter().copied().collect();
+        let mut debugger = CookDebugger::new();
+        assert!(debugger.toggle_breakpoint(2));
+
+        assert_eq!(cook(&mut debugger, &order, &mut dirty), vec![1]);
+        assert_eq!(debugger.paused_at(), Some(2));
+        // Cooking again while paused does nothing
+        assert!(cook(&mut debugger, &order, &mut dirty).is_empty());
+
+        debugger.step();
+        assert_eq!(cook(&mut debugger, &order, &mut dirty), vec![2]);
+        assert_eq!(debugger.paused_at(), Some(3));
+
+        debugger.resume();
+        assert_eq!(cook(&mut debugger, &order, &mut dirty), vec![3, 4]);
+        assert_eq!(debugger.paused_at(), None);
+
+        // The breakpoint stays for the next cook until toggled off
+        dirty.extend(order);
+        assert_eq!(cook(&mut debugger, &order, &mut dirty), vec![1]);
+        debugger.forget_node(2);
+        assert!(!debugger.has_breakpoint(2));
+        assert_eq!(cook(&mut debugger, &order, &mut dirty), vec![2, 3, 4]);
+    }
+}
@@ -7,12 +7,37 @@
 //! - Execution ordering via topological sort
 //! - Node evaluation triggering
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
 use crate::nodes::{NodeId, NodeGraph, Node, Connection};
 use crate::nodes::interface::NodeData;
 use crate::nodes::hooks::{NodeExecutionHooks, DefaultHooks};
 use crate::nodes::ownership::{OwnershipOptimizer, OwnershipConfig, OwnedNodeData};
 use crate::nodes::cache::{UnifiedNodeCache, CacheKey, CacheKeyPattern};
+use crate::nodes::state_store::NodeStateStore;
+use crate::nodes::debugger::CookDebugger;
+use crate::nodes::execution_log::{self, ExecutionLog};
+use crate::nodes::seed::SeedContext;
+use crate::nodes::expressions;
+use crate::nodes::logic::branch;
+use crate::plugins::events::{self, GraphEvent};
+
+/// Cook timings kept when nobody drains them (e.g. headless use)
+const MAX_PENDING_COOK_TIMINGS: usize = 10_000;
+
+/// Node types whose output depends on the timeline frame by themselves
+const TIME_SOURCE_TYPES: &[&str] = &["Utility_Time", "Data_AudioAnalysis", "Logic_Feedback", "Logic_Timer"];
+
+/// Node types that must cook on every frame change (their cooks have side
+/// effects, such as uploading to the GPU) instead of restoring a cached frame
+const COOK_EVERY_FRAME_TYPES: &[&str] = &["Viewport"];
+
+/// Node type whose untaken input is left uncooked
+const BRANCH_TYPE: &str = "Logic_Branch";
+
+/// Frames kept per time-dependent node; the ones furthest from the current frame go first
+const MAX_CACHED_FRAMES: usize = 240;
 
 /// Represents the execution state of a node
 #[derive(Debug, Clone, PartialEq)]
@@ -32,6 +57,36 @@ pub enum EngineExecutionMode {
     Manual,
 }
 
+/// Which dirty nodes a cook runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvaluationStrategy {
+    /// Cook every dirty node
+    #[default]
+    Push,
+    /// Cook only display and output nodes and what they depend on; other
+    /// branches stay dirty until something displays them
+    Pull,
+}
+
+/// Whether nodes of `type_id` display or output their inputs: the Output and
+/// UI categories (probes, spreadsheets, viewports, render), core or plugin,
+/// where pull evaluation starts
+pub fn is_display_node(type_id: &str) -> bool {
+    crate::nodes::factory::NodeCatalog::current()
+        .node(type_id)
+        .is_some_and(|node| node.category.iter().any(|part| part == "Output" || part == "UI"))
+}
+
+/// Geometry quality shown by viewers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QualityMode {
+    /// Viewers show decimated proxies of heavy geometry
+    #[default]
+    Draft,
+    /// Viewers show the full-resolution result
+    Final,
+}
+
 /// Execution engine for node graphs
 pub struct NodeGraphEngine {
     /// Current execution state for each node
@@ -48,6 +103,32 @@ pub struct NodeGraphEngine {
     execution_mode: EngineExecutionMode,
     /// Ownership optimizer for reducing data clones
     ownership_optimizer: OwnershipOptimizer,
+    /// Persistent state for stateful nodes (survives cache invalidation)
+    state_store: NodeStateStore,
+    /// Number of times each node's output was cooked or restored from the frame cache
+    /// (lets viewers detect fresh outputs)
+    cook_counts: HashMap<NodeId, u64>,
+    /// Wall time of recent successful cooks by node type, drained by the editor's usage statistics
+    cook_timings: Vec<(String, Duration)>,
+    /// Draft/final switch for what viewers are fed
+    quality_mode: QualityMode,
+    /// Viewer proxies by output port, with the cook count they were made from
+    /// (None when the output is light enough to show as is)
+    proxies: HashMap<(NodeId, usize), (u64, Option<NodeData>)>,
+    /// Timeline frame the graph is evaluated at
+    current_frame: i64,
+    /// Time sources and every node downstream of them; their outputs are cached per frame
+    time_dependent: HashSet<NodeId>,
+    /// Breakpoints and the pause state of step-through cooking
+    debugger: CookDebugger,
+    /// Record of cooks and the graph events that trigger them
+    log: ExecutionLog,
+    /// Project seed random nodes draw from
+    seeds: SeedContext,
+    /// Push (cook everything dirty) or pull (cook what displays need)
+    evaluation_strategy: EvaluationStrategy,
+    /// Start of the clock nodes schedule wake-ups against
+    clock_start: Instant,
 }
 
 impl NodeGraphEngine {
@@ -81,6 +162,9 @@ impl NodeGraphEngine {
         hooks.insert("3D_Cone".to_string(), geometry_hooks.clone_box());
         hooks.insert("3D_Plane".to_string(), geometry_hooks.clone_box());
         hooks.insert("3D_Capsule".to_string(), geometry_hooks.clone_box());
+        hooks.insert("3D_Torus".to_string(), geometry_hooks.clone_box());
+        hooks.insert("3D_Disk".to_string(), geometry_hooks.clone_box());
+        hooks.insert("3D_Tube".to_string(), geometry_hooks.clone_box());
         
         Self {
             node_states: HashMap::new(),
@@ -90,6 +174,18 @@ impl NodeGraphEngine {
             execution_hooks: hooks,
             execution_mode: EngineExecutionMode::Auto, // Default to auto
             ownership_optimizer: OwnershipOptimizer::with_default_config(),
+            state_store: NodeStateStore::new(),
+            cook_counts: HashMap::new(),
+            cook_timings: Vec::new(),
+            quality_mode: QualityMode::default(),
+            proxies: HashMap::new(),
+            current_frame: 0,
+            time_dependent: HashSet::new(),
+            debugger: CookDebugger::new(),
+            log: ExecutionLog::new(),
+            seeds: SeedContext::default(),
+            evaluation_strategy: EvaluationStrategy::default(),
+            clock_start: Instant::now(),
         }
     }
 
@@ -104,14 +200,16 @@ impl NodeGraphEngine {
         self.dirty_nodes.insert(node_id);
         
         // Invalidate all cache entries for this node (all stages and ports)
-        let invalidated_count = self.unified_cache.invalidate(&CacheKeyPattern::Node(node_id));
-        if invalidated_count > 0 {
-            println!("🗑️ Invalidated {} cache entries for node {}", invalidated_count, node_id);
-        }
+        self.unified_cache.invalidate(&CacheKeyPattern::Node(node_id));
         
         // Propagate dirty state to downstream nodes
         self.propagate_dirty_downstream(node_id, graph);
         
+        // Nodes with expressions reading this node's parameters may get new values
+        for dependent_id in expressions::dependents_of(node_id, graph) {
+            self.mark_dirty(dependent_id, graph);
+        }
+        
         // Invalidate execution order cache
         self.execution_order_cache = None;
     }
@@ -211,18 +309,20 @@ impl NodeGraphEngine {
             *in_degree.get_mut(&connection.to_node).unwrap() += 1;
         }
         
-        // Kahn's algorithm for topological sort
-        let mut queue = VecDeque::new();
+        // Kahn's algorithm for topological sort. Ready nodes are taken lowest id
+        // first, so the order depends only on the graph and not on hash map
+        // iteration, keeping cooks (and anything order-sensitive) reproducible.
+        let mut queue = BinaryHeap::new();
         let mut result = Vec::new();
         
         // Start with nodes that have no dependencies
         for (&node_id, &degree) in &in_degree {
             if degree == 0 {
-                queue.push_back(node_id);
+                queue.push(Reverse(node_id));
             }
         }
         
-        while let Some(node_id) = queue.pop_front() {
+        while let Some(Reverse(node_id)) = queue.pop() {
             result.push(node_id);
             
             // Update dependencies of downstream nodes
@@ -232,7 +332,7 @@ impl NodeGraphEngine {
                     *degree -= 1;
                     
                     if *degree == 0 {
-                        queue.push_back(neighbor);
+                        queue.push(Reverse(neighbor));
                     }
                 }
             }
@@ -253,14 +353,28 @@ impl NodeGraphEngine {
     /// Execute all dirty nodes in dependency order
     /// This method executes regardless of execution mode - caller must check mode
     pub fn execute_dirty_nodes(&mut self, graph: &NodeGraph) -> Result<(), String> {
+        // A cook paused at a breakpoint waits for a step or continue
+        if !self.debugger.begin_cook(&self.dirty_nodes) {
+            return Ok(());
+        }
+
+        // Nodes that scheduled a cook for now (timers, pulses ending) go dirty with everything downstream
+        for node_id in self.state_store.due_nodes(self.clock_ms()) {
+            if graph.nodes.contains_key(&node_id) {
+                self.mark_dirty(node_id, graph);
+            }
+        }
+
         // Analyze graph for ownership optimization before execution
         self.ownership_optimizer.analyze_graph(graph);
+        self.refresh_time_dependence(graph);
         
         // Debug: Show all node states
         // Node states checked
         
-        if self.dirty_nodes.is_empty() {
-            // No dirty nodes to execute
+        if self.dirty_nodes.is_empty() || self.evaluation_strategy == EvaluationStrategy::Pull
+            || graph.nodes.values().any(|node| node.type_id == BRANCH_TYPE) {
+            // No dirty nodes to execute (or only branches a pull or an untaken input skipped)
             
             // Check if we have any new nodes that need initial execution
             for &node_id in graph.nodes.keys() {
@@ -271,26 +385,49 @@ impl NodeGraphEngine {
             }
             
             // If we found new nodes, try execution again
-            if !self.dirty_nodes.is_empty() {
-                // Executing newly discovered dirty nodes
-            } else {
+            if self.dirty_nodes.is_empty() {
                 return Ok(());
             }
-        } else {
-            // Executing dirty nodes
         }
         
         let execution_order = self.get_execution_order(graph)?;
+        // In pull mode only what display nodes depend on is cooked
+        let needed = match self.evaluation_strategy {
+            EvaluationStrategy::Push => None,
+            EvaluationStrategy::Pull => Some(Self::pulled_nodes(graph)),
+        };
+        
+        // Branch conditions cook first so the untaken side of each branch can be skipped
+        let condition_nodes = Self::branch_condition_nodes(graph);
+        let (conditions_first, rest): (Vec<NodeId>, Vec<NodeId>) = execution_order.iter()
+            .partition(|node_id| condition_nodes.contains(node_id));
+        let mut untaken = HashSet::new();
         
         // Only execute nodes that are dirty and in our execution order
-        for &node_id in &execution_order {
+        for (position, &node_id) in conditions_first.iter().chain(&rest).enumerate() {
+            if position == conditions_first.len() {
+                untaken = self.untaken_branch_nodes(graph, &execution_order);
+            }
+            if untaken.contains(&node_id) || needed.as_ref().is_some_and(|needed| !needed.contains(&node_id)) {
+                continue;
+            }
             if self.dirty_nodes.contains(&node_id) {
-                self.execute_single_node(node_id, graph)?;
+                if self.debugger.should_pause(node_id) {
+                    // Leave this node and the rest dirty until stepped or continued
+                    self.ownership_optimizer.reset_consumption_tracking();
+                    return Ok(());
+                }
+                if let Err(e) = self.execute_single_node(node_id, graph) {
+                    self.debugger.end_cook();
+                    return Err(e);
+                }
             }
         }
+        self.debugger.end_cook();
         
-        // Clear dirty set after successful execution
-        self.dirty_nodes.clear();
+        // Clear dirty set after successful execution, keeping the branches a pull or an untaken input skipped
+        self.dirty_nodes.retain(|node_id| graph.nodes.contains_key(node_id)
+            && (untaken.contains(node_id) || needed.as_ref().is_some_and(|needed| !needed.contains(node_id))));
         
         // Reset ownership tracking for next execution cycle
         self.ownership_optimizer.reset_consumption_tracking();
@@ -300,26 +437,105 @@ impl NodeGraphEngine {
         Ok(())
     }
 
+    /// Display nodes of `graph` and every node upstream of them
+    fn pulled_nodes(graph: &NodeGraph) -> HashSet<NodeId> {
+        let mut upstream: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for connection in &graph.connections {
+            upstream.entry(connection.to_node).or_default().push(connection.from_node);
+        }
+        let mut needed = HashSet::new();
+        let mut stack: Vec<NodeId> = graph.nodes.values()
+            .filter(|node| is_display_node(&node.type_id))
+            .map(|node| node.id)
+            .collect();
+        while let Some(node_id) = stack.pop() {
+            if needed.insert(node_id) {
+                stack.extend(upstream.get(&node_id).into_iter().flatten().copied());
+            }
+        }
+        needed
+    }
+
+    /// Nodes upstream of any Branch node's condition input
+    fn branch_condition_nodes(graph: &NodeGraph) -> HashSet<NodeId> {
+        let mut upstream: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for connection in &graph.connections {
+            upstream.entry(connection.to_node).or_default().push(connection.from_node);
+        }
+        let mut conditions = HashSet::new();
+        let mut stack: Vec<NodeId> = graph.connections.iter()
+            .filter(|connection| connection.to_port == branch::logic::CONDITION_INPUT
+                && graph.nodes.get(&connection.to_node).is_some_and(|node| node.type_id == BRANCH_TYPE))
+            .map(|connection| connection.from_node)
+            .collect();
+        while let Some(node_id) = stack.pop() {
+            if conditions.insert(node_id) {
+                stack.extend(upstream.get(&node_id).into_iter().flatten().copied());
+            }
+        }
+        conditions
+    }
+
+    /// Nodes whose outputs only reach the input a Branch node is not taking
+    ///
+    /// Conditions must already be cooked. A node is untaken when it has
+    /// connections and each one goes to an untaken input or an untaken node;
+    /// walking the order backwards settles consumers before their sources.
+    fn untaken_branch_nodes(&mut self, graph: &NodeGraph, execution_order: &[NodeId]) -> HashSet<NodeId> {
+        let mut untaken_inputs: HashMap<NodeId, usize> = HashMap::new();
+        for node in graph.nodes.values().filter(|node| node.type_id == BRANCH_TYPE) {
+            let condition_key = graph.connections.iter()
+                .find(|connection| connection.to_node == node.id && connection.to_port == branch::logic::CONDITION_INPUT)
+                .map(|connection| self.output_key(connection.from_node, connection.from_port));
+            let condition = condition_key.and_then(|key| self.unified_cache.get(&key));
+            let evaluated_node = expressions::evaluated_node(node, graph);
+            let logic = branch::parameters::BranchNode::create_logic(evaluated_node.as_ref().unwrap_or(node));
+            untaken_inputs.insert(node.id, branch::logic::BranchLogic::untaken_input(logic.resolve(condition)));
+        }
+        let mut untaken = HashSet::new();
+        if untaken_inputs.is_empty() {
+            return untaken;
+        }
+        for &node_id in execution_order.iter().rev() {
+            let mut outgoing = graph.connections.iter().filter(|connection| connection.from_node == node_id).peekable();
+            if outgoing.peek().is_some() && outgoing.all(|connection| {
+                untaken_inputs.get(&connection.to_node) == Some(&connection.to_port) || untaken.contains(&connection.to_node)
+            }) {
+                untaken.insert(node_id);
+            }
+        }
+        untaken
+    }
+
     /// Execute a single node
     fn execute_single_node(&mut self, node_id: NodeId, graph: &NodeGraph) -> Result<(), String> {
-        let node = graph.nodes.get(&node_id)
+        let stored_node = graph.nodes.get(&node_id)
             .ok_or_else(|| format!("Node {} not found", node_id))?;
+        // Parameters driven by expressions are evaluated for this cook only
+        let evaluated_node = expressions::evaluated_node(stored_node, graph);
+        let node = evaluated_node.as_ref().unwrap_or(stored_node);
 
         // Executing node
+        let cook_start = Instant::now();
         
         // Mark as computing
         self.node_states.insert(node_id, NodeState::Computing);
+        if events::is_listening() {
+            events::emit(GraphEvent::CookStarted { node_id, node_type: node.type_id.clone() });
+        }
         
         // Call pre-execution hook
+        let mut warnings = Vec::new();
         if let Some(hooks) = self.execution_hooks.get_mut(&node.type_id) {
             if let Err(e) = hooks.before_execution(node, graph) {
-                eprintln!("Pre-execution hook failed for {}: {}", node.type_id, e);
                 // Continue execution even if hook fails
+                warnings.push(format!("pre-execution hook failed: {}", e));
             }
         }
         
         // Collect inputs from upstream nodes
         let inputs = self.collect_node_inputs(node_id, graph);
+        let input_summary = execution_log::summarize_inputs(&inputs);
         
         // Check for custom execution via hooks first, then fall back to standard dispatch
         let outputs = if self.execution_hooks.contains_key(&node.type_id) {
@@ -334,6 +550,9 @@ impl NodeGraphEngine {
             // Put the hook back
             self.execution_hooks.insert(node.type_id.clone(), hook);
             result
+        } else if let Some(result) = self.dispatch_stateful_execution(node_id, node, inputs.clone()) {
+            // Stateful nodes read and update their slots in the state store
+            result
         } else {
             // No hooks, use standard dispatch
             self.dispatch_node_execution(node, inputs)
@@ -344,6 +563,8 @@ impl NodeGraphEngine {
             Err(e) => {
                 // Node execution failed
                 self.node_states.insert(node_id, NodeState::Error);
+                self.log.record_cook(node_id, &node.title, &node.type_id, cook_start.elapsed(), input_summary, warnings, Err(&e));
+                Self::emit_cook_finished(node_id, node, false, cook_start);
                 return Err(e);
             }
         };
@@ -351,8 +572,8 @@ impl NodeGraphEngine {
         // Call post-execution hook with the outputs
         if let Some(hooks) = self.execution_hooks.get_mut(&node.type_id) {
             if let Err(e) = hooks.after_execution(node, &outputs, graph) {
-                eprintln!("Post-execution hook failed for {}: {}", node.type_id, e);
                 // Continue even if hook fails
+                warnings.push(format!("post-execution hook failed: {}", e));
             }
         }
         
@@ -360,18 +581,39 @@ impl NodeGraphEngine {
         // Caching outputs
         for (port_idx, output) in outputs.into_iter().enumerate() {
             let optimized_output = self.ownership_optimizer.optimize_output(node_id, port_idx, output);
-            let cache_key = CacheKey::new(node_id, port_idx);
+            let cache_key = self.output_key(node_id, port_idx);
             self.unified_cache.insert(cache_key, optimized_output);
         }
+        if self.time_dependent.contains(&node_id) {
+            self.evict_distant_frames(node_id);
+        }
         
         // Mark as clean
         self.node_states.insert(node_id, NodeState::Clean);
         self.dirty_nodes.remove(&node_id);
+        *self.cook_counts.entry(node_id).or_insert(0) += 1;
+        if self.cook_timings.len() < MAX_PENDING_COOK_TIMINGS {
+            self.cook_timings.push((node.type_id.clone(), cook_start.elapsed()));
+        }
+        self.log.record_cook(node_id, &node.title, &node.type_id, cook_start.elapsed(), input_summary, warnings, Ok(()));
+        Self::emit_cook_finished(node_id, node, true, cook_start);
         
         // Node executed successfully
         Ok(())
     }
 
+    /// Tell plugins listening for events that a cook ended
+    fn emit_cook_finished(node_id: NodeId, node: &Node, success: bool, cook_start: Instant) {
+        if events::is_listening() {
+            events::emit(GraphEvent::CookFinished {
+                node_id,
+                node_type: node.type_id.clone(),
+                success,
+                duration_ms: cook_start.elapsed().as_secs_f64() * 1000.0,
+            });
+        }
+    }
+
     /// Collect inputs for a node from connected upstream nodes
     fn collect_node_inputs(&mut self, node_id: NodeId, graph: &NodeGraph) -> Vec<NodeData> {
         let node = match graph.nodes.get(&node_id) {
@@ -380,25 +622,143 @@ impl NodeGraphEngine {
         };
 
         let mut inputs = vec![NodeData::None; node.inputs.len()];
+        // Viewers get proxies of heavy geometry; render and export nodes always get the full result
+        let is_viewer = node.type_id == "Viewport";
         
         // Find all connections feeding into this node
         let mut found_connections = 0;
+        let mut connected_ports = HashSet::new();
         for connection in &graph.connections {
             if connection.to_node == node_id {
                 found_connections += 1;
+                connected_ports.insert(connection.to_port);
                 
                 // Get the output from the source node via unified cache
-                let cache_key = CacheKey::new(connection.from_node, connection.from_port);
-                if let Some(cached_data) = self.unified_cache.get(&cache_key) {
+                let cached_data = if is_viewer {
+                    self.get_viewer_output(connection.from_node, connection.from_port)
+                } else {
+                    let cache_key = self.output_key(connection.from_node, connection.from_port);
+                    self.unified_cache.get(&cache_key).cloned()
+                };
+                if let Some(cached_data) = cached_data {
                     if connection.to_port < inputs.len() {
-                        inputs[connection.to_port] = cached_data.clone();
+                        // Convert between annotated units (e.g. cm output into a meter input)
+                        let from_unit = graph.nodes.get(&connection.from_node)
+                            .and_then(|source| source.outputs.get(connection.from_port))
+                            .map(|port| port.unit)
+                            .unwrap_or_default();
+                        let to_unit = node.inputs[connection.to_port].unit;
+                        let to_type = crate::theme::port_data_type(&node.type_id, true, connection.to_port);
+                        let cached_data = match connection.adapter {
+                            Some(adapter) => adapter.convert(cached_data),
+                            // Generic outputs are converted by the type of the value they produced
+                            None => match graph.nodes.get(&connection.from_node)
+                                .and_then(|source| crate::theme::port_data_type(&source.type_id, false, connection.from_port))
+                            {
+                                None | Some(crate::nodes::DataType::Any) => match crate::nodes::adapters::value_adapter(&cached_data, to_type.clone()) {
+                                    Some(adapter) => adapter.convert(cached_data),
+                                    None => cached_data,
+                                },
+                                Some(_) => cached_data,
+                            },
+                        };
+                        // Values of plugin data types take the type of a typed input, where their plugin converts to it
+                        let cached_data = match cached_data {
+                            NodeData::Custom(custom) => match &to_type {
+                                Some(to) if *to != crate::nodes::DataType::Any => crate::plugins::data_types::convert(&custom, to)
+                                    .unwrap_or(NodeData::Custom(custom)),
+                                _ => NodeData::Custom(custom),
+                            },
+                            data => data,
+                        };
+                        inputs[connection.to_port] = from_unit.convert_data(cached_data, to_unit);
                     }
                 }
             }
         }
+        // Unconnected inputs cook their default from the parameter panel
+        for (port, input) in node.inputs.iter().enumerate() {
+            if connected_ports.contains(&port) {
+                continue;
+            }
+            if let Some(default) = node.parameters.get(&crate::nodes::parameter_schema::port_default_key(&input.name)) {
+                inputs[port] = default.clone();
+            }
+        }
         inputs
     }
 
+    /// Dispatch execution for nodes that keep state between cooks.
+    /// Returns None for stateless node types.
+    fn dispatch_stateful_execution(&mut self, node_id: NodeId, node: &Node, inputs: Vec<NodeData>) -> Option<Result<Vec<NodeData>, String>> {
+        match node.type_id.as_str() {
+            "Logic_Counter" => {
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::counter::parameters::CounterNode::process_node(node, inputs, state)))
+            }
+            "Logic_Accumulate" => {
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::accumulate::parameters::AccumulateNode::process_node(node, inputs, state)))
+            }
+            "Logic_Hold" => {
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::hold::parameters::HoldNode::process_node(node, inputs, state)))
+            }
+            "Logic_Feedback" => {
+                let frame = self.current_frame;
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::feedback::parameters::FeedbackNode::process_node(node, inputs, frame, state)))
+            }
+            "Logic_Timer" => {
+                let (now_ms, frame) = (self.clock_ms(), self.current_frame);
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::timer::parameters::TimerNode::process_node(node, now_ms, frame, state)))
+            }
+            "Logic_Trigger" => {
+                let now_ms = self.clock_ms();
+                let state = self.state_store.slots_mut(node_id);
+                Some(Ok(crate::nodes::logic::trigger::parameters::TriggerNode::process_node(node, inputs, now_ms, state)))
+            }
+            _ => None,
+        }
+    }
+    
+    /// Milliseconds since the engine started, the clock of `WAKE_AT_SLOT`
+    pub fn clock_ms(&self) -> i32 {
+        self.clock_start.elapsed().as_millis().min(i32::MAX as u128) as i32
+    }
+    
+    /// Discard a node's persistent state so its next cook starts fresh
+    pub fn reset_node_state(&mut self, node_id: NodeId, graph: &NodeGraph) {
+        self.state_store.remove(node_id);
+        self.mark_dirty(node_id, graph);
+    }
+    
+    /// Read-only access to persistent node state
+    pub fn state_store(&self) -> &NodeStateStore {
+        &self.state_store
+    }
+
+    /// Recent cooks and graph events
+    pub fn log(&self) -> &ExecutionLog {
+        &self.log
+    }
+
+    /// Recent cooks and graph events, for clearing
+    pub fn log_mut(&mut self) -> &mut ExecutionLog {
+        &mut self.log
+    }
+
+    /// Breakpoints and pause state
+    pub fn debugger(&self) -> &CookDebugger {
+        &self.debugger
+    }
+
+    /// Breakpoints and pause state, for toggling breakpoints and stepping
+    pub fn debugger_mut(&mut self) -> &mut CookDebugger {
+        &mut self.debugger
+    }
+
     /// Dispatch node execution based on node type_id
     fn dispatch_node_execution(&self, node: &Node, inputs: Vec<NodeData>) -> Result<Vec<NodeData>, String> {
         // Use the node type_id to dispatch execution (independent of user-editable title)
@@ -408,8 +768,60 @@ impl NodeGraphEngine {
                 // Executing USD File Reader
                 Ok(crate::nodes::data::usd_file_reader::UsdFileReaderNode::process_node(node, inputs))
             }
+            "Data_ReadCsv" => {
+                Ok(crate::nodes::data::read_csv::ReadCsvNode::process_node(node, inputs))
+            }
+            "Data_ReadJson" => {
+                Ok(crate::nodes::data::read_json::ReadJsonNode::process_node(node, inputs))
+            }
+            "Data_ReadAudio" => {
+                Ok(crate::nodes::data::read_audio::ReadAudioNode::process_node(node, inputs))
+            }
+            "Data_AudioAnalysis" => {
+                Ok(crate::nodes::data::audio_analysis::AudioAnalysisNode::process_node(node, inputs, self.current_frame))
+            }
+            
+            // List nodes
+            "List_Build" => {
+                Ok(crate::nodes::list::build_list::BuildListNode::process_node(node, inputs))
+            }
+            "List_GetItem" => {
+                Ok(crate::nodes::list::get_item::GetItemNode::process_node(node, inputs))
+            }
+            "List_Length" => {
+                Ok(crate::nodes::list::length::LengthNode::process_node(node, inputs))
+            }
+            "List_Slice" => {
+                Ok(crate::nodes::list::slice::SliceNode::process_node(node, inputs))
+            }
+            "List_Sort" => {
+                Ok(crate::nodes::list::sort::SortNode::process_node(node, inputs))
+            }
+            "List_Filter" => {
+                Ok(crate::nodes::list::filter::FilterNode::process_node(node, inputs))
+            }
+            
+            // Map nodes
+            "Map_Compose" => {
+                Ok(crate::nodes::map::compose::ComposeNode::process_node(node, inputs))
+            }
+            "Map_GetKey" => {
+                Ok(crate::nodes::map::get_key::GetKeyNode::process_node(node, inputs))
+            }
+            "Map_Merge" => {
+                Ok(crate::nodes::map::merge::MergeNode::process_node(node, inputs))
+            }
+            "Map_Keys" => {
+                Ok(crate::nodes::map::keys::KeysNode::process_node(node, inputs))
+            }
             
             // Viewport/UI nodes
+            "Utility_Time" => {
+                Ok(crate::nodes::utility::time::TimeNode::process_node(node, self.current_frame))
+            }
+            "Utility_Random" => {
+                Ok(crate::nodes::utility::random::RandomNode::process_node(node, &self.seeds))
+            }
             "Viewport" => {
                 // Executing Viewport node
                 Ok(crate::nodes::three_d::ui::viewport::ViewportNode::process_node(node, &inputs))
@@ -445,6 +857,39 @@ impl NodeGraphEngine {
                 // Executing Divide node
                 Ok(crate::nodes::math::divide::functions::process_divide(inputs))
             }
+            "Math_IntegerConstant" => {
+                Ok(crate::nodes::math::integer_constant::parameters::IntegerConstantNode::process_node(node, inputs))
+            }
+            "Math_IntegerArithmetic" => {
+                Ok(crate::nodes::math::integer_arithmetic::parameters::IntegerArithmeticNode::process_node(node, inputs))
+            }
+            "Math_Modulo" => {
+                Ok(crate::nodes::math::modulo::parameters::ModuloNode::process_node(node, inputs))
+            }
+            "Math_Bitwise" => {
+                Ok(crate::nodes::math::bitwise::parameters::BitwiseNode::process_node(node, inputs))
+            }
+            "Math_ConvertUnits" => {
+                Ok(crate::nodes::math::convert_units::parameters::ConvertUnitsNode::process_node(node, inputs))
+            }
+            "Math_Clamp" => {
+                Ok(crate::nodes::math::clamp::parameters::ClampNode::process_node(node, inputs))
+            }
+            "Math_Remap" => {
+                Ok(crate::nodes::math::remap::parameters::RemapNode::process_node(node, inputs))
+            }
+            "Math_Lerp" => {
+                Ok(crate::nodes::math::lerp::parameters::LerpNode::process_node(node, inputs))
+            }
+            "Math_Smoothstep" => {
+                Ok(crate::nodes::math::smoothstep::parameters::SmoothstepNode::process_node(node, inputs))
+            }
+            "Math_Fit" => {
+                Ok(crate::nodes::math::fit::parameters::FitNode::process_node(node, inputs))
+            }
+            "Math_Function" => {
+                Ok(crate::nodes::math::function::parameters::FunctionNode::process_node(node, inputs))
+            }
             
             // Logic nodes (simple implementations since functions modules don't exist)
             "And" => {
@@ -491,6 +936,29 @@ impl NodeGraphEngine {
                     Ok(vec![NodeData::Boolean(true)])
                 }
             }
+            "Logic_Xor" => {
+                Ok(crate::nodes::logic::xor::parameters::XorNode::process_node(node, inputs))
+            }
+            "Logic_Nand" => {
+                Ok(crate::nodes::logic::nand::parameters::NandNode::process_node(node, inputs))
+            }
+            "Logic_Compare" => {
+                Ok(crate::nodes::logic::compare::parameters::CompareNode::process_node(node, inputs))
+            }
+            "Logic_InRange" => {
+                Ok(crate::nodes::logic::in_range::parameters::InRangeNode::process_node(node, inputs))
+            }
+            "Logic_Branch" => {
+                Ok(crate::nodes::logic::branch::parameters::BranchNode::process_node(node, inputs))
+            }
+            "Logic_BoolToFloat" => {
+                Ok(crate::nodes::logic::bool_to_float::parameters::BoolToFloatNode::process_node(node, inputs))
+            }
+            
+            // Utility nodes
+            "Utility_ComputeShader" => {
+                Ok(crate::nodes::utility::compute_shader::ComputeShaderNode::process_node(node, inputs))
+            }
             
             // Output nodes (simple implementations)
             "Print" => {
@@ -501,6 +969,22 @@ impl NodeGraphEngine {
                 // Print nodes typically pass through their inputs
                 Ok(inputs)
             }
+            "Spreadsheet" => {
+                // Spreadsheet viewer passes its input through; the panel reads the cache
+                Ok(inputs)
+            }
+            "TreeView" => {
+                // Tree viewer passes its input through and exposes the panel selection
+                let selected = node.parameters.get(crate::nodes::interface::TreeData::SELECTED_PATH_PARAMETER)
+                    .cloned()
+                    .unwrap_or_else(|| NodeData::String(String::new()));
+                let tree = inputs.into_iter().next().unwrap_or(NodeData::None);
+                Ok(vec![tree, selected])
+            }
+            "Probe" => {
+                // Probe passes its input through; the editor draws the cached value on the node
+                Ok(vec![inputs.into_iter().next().unwrap_or(NodeData::None)])
+            }
             "Debug" => {
                 // Executing Debug node
                 for (i, input) in inputs.iter().enumerate() {
@@ -579,6 +1063,15 @@ impl NodeGraphEngine {
                 // Executing USD Capsule node
                 Ok(crate::nodes::three_d::geometry::capsule::CapsuleNode::process_node(node, inputs))
             }
+            "3D_Torus" => {
+                Ok(crate::nodes::three_d::geometry::torus::TorusNode::process_node(node, inputs))
+            }
+            "3D_Disk" => {
+                Ok(crate::nodes::three_d::geometry::disk::DiskNode::process_node(node, inputs))
+            }
+            "3D_Tube" => {
+                Ok(crate::nodes::three_d::geometry::tube::TubeNode::process_node(node, inputs))
+            }
             
             // 3D Lighting nodes
             "Point Light" => {
@@ -596,12 +1089,71 @@ impl NodeGraphEngine {
                 // For now, just pass through - implement lighting later
                 Ok(vec![NodeData::None])
             }
+            "3D_DirectionalLight" => {
+                Ok(crate::nodes::three_d::lighting::directional_light::DirectionalLightNode::process_node(node, inputs))
+            }
+            "3D_DomeLight" => {
+                Ok(crate::nodes::three_d::lighting::dome_light::DomeLightNode::process_node(node, inputs))
+            }
+            "3D_RectLight" => {
+                Ok(crate::nodes::three_d::lighting::rect_light::RectLightNode::process_node(node, inputs))
+            }
+            "3D_PointLight" => {
+                Ok(crate::nodes::three_d::lighting::point_light::PointLightNode::process_node(node, inputs))
+            }
+            "3D_SpotLight" => {
+                Ok(crate::nodes::three_d::lighting::spot_light::SpotLightNode::process_node(node, inputs))
+            }
             
             // 3D Modify nodes
             "3D_Reverse" => {
                 // Executing Reverse node
                 Ok(crate::nodes::three_d::modify::reverse::parameters::ReverseNode::process_node(node, inputs))
             }
+            "3D_Transform" => {
+                Ok(crate::nodes::three_d::modify::transform::TransformNode::process_node(node, inputs))
+            }
+            "3D_Prune" => {
+                Ok(crate::nodes::three_d::modify::prune::PruneNode::process_node(node, inputs))
+            }
+            "3D_VariantSelect" => {
+                Ok(crate::nodes::three_d::modify::variant_select::VariantSelectNode::process_node(node, inputs))
+            }
+            "3D_LayerStack" => {
+                Ok(crate::nodes::three_d::modify::layer_stack::LayerStackNode::process_node(node, inputs))
+            }
+            "3D_Scatter" => {
+                Ok(crate::nodes::three_d::modify::scatter::ScatterNode::process_node(node, inputs, &self.seeds))
+            }
+            "3D_AttributeCreate" => {
+                Ok(crate::nodes::three_d::modify::attribute_create::AttributeCreateNode::process_node(node, inputs))
+            }
+            "3D_Boolean" => {
+                Ok(crate::nodes::three_d::modify::boolean::BooleanNode::process_node(node, inputs))
+            }
+            "3D_Normals" => {
+                Ok(crate::nodes::three_d::modify::normals::NormalsNode::process_node(node, inputs))
+            }
+            "3D_Decimate" => {
+                Ok(crate::nodes::three_d::modify::decimate::DecimateNode::process_node(node, inputs))
+            }
+            "3D_DecimatePoints" => {
+                Ok(crate::nodes::three_d::modify::decimate_points::DecimatePointsNode::process_node(node, inputs))
+            }
+
+            // 3D Input nodes
+            "3D_ReadGLTF" => {
+                Ok(crate::nodes::three_d::input::read_gltf::ReadGltfNode::process_node(node, inputs))
+            }
+            "3D_ReadMesh" => {
+                Ok(crate::nodes::three_d::input::read_mesh::ReadMeshNode::process_node(node, inputs))
+            }
+            "3D_ReadPoints" => {
+                Ok(crate::nodes::three_d::input::read_points::ReadPointsNode::process_node(node, inputs))
+            }
+            "3D_ReadVDB" => {
+                Ok(crate::nodes::three_d::input::read_vdb::ReadVdbNode::process_node(node, inputs))
+            }
             
             // 3D Output nodes
             "3D_Render" => {
@@ -611,21 +1163,24 @@ impl NodeGraphEngine {
                     .unwrap_or(false);
                 
                 if should_render {
-                    println!("🎬 Executing Render node '{}' with {} inputs", node.title, inputs.len());
-                    let result = crate::nodes::three_d::output::render::RenderNode::process_node(node, inputs);
+                    let result = crate::nodes::three_d::output::render::RenderNode::process_node(node, inputs, self.current_frame);
                     
                     // The render logic will have already executed and completed
                     // The execution system will need to reset the trigger_render parameter
                     // This is handled by the parameter system when changes are applied
                     Ok(result)
                 } else {
-                    // Return status without executing render
-                    let status = node.parameters.get("last_render_status")
-                        .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+                    // Return the status of the node's latest queued render without rendering
+                    let status = crate::nodes::three_d::output::render::queue::node_status(node.id)
+                        .or_else(|| node.parameters.get("last_render_status")
+                            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None }))
                         .unwrap_or_else(|| "Ready".to_string());
                     Ok(vec![NodeData::String(status)])
                 }
             }
+            "3D_WriteUSD" => {
+                Ok(crate::nodes::three_d::output::write_usd::WriteUSDNode::process_node(node, inputs))
+            }
             
             // Data nodes
             "Constant" => {
@@ -633,6 +1188,10 @@ impl NodeGraphEngine {
                 // For now, just pass through - implement constant value logic later
                 Ok(vec![NodeData::None])
             }
+            "Null" => {
+                // Identity: a named anchor point, the input moves straight through
+                Ok(crate::nodes::utility::null::parameters::NullNode::process_node(node, inputs))
+            }
             "Variable" => {
                 // Executing Variable node
                 // For now, just pass through - implement variable logic later
@@ -657,12 +1216,53 @@ impl NodeGraphEngine {
         self.node_states.get(&node_id).cloned().unwrap_or(NodeState::Clean)
     }
 
+    /// Mark a node as failed outside of execution, such as a plugin node that panicked
+    pub fn mark_error(&mut self, node_id: NodeId) {
+        self.node_states.insert(node_id, NodeState::Error);
+    }
+
+    /// Get how many times a node has executed successfully
+    pub fn get_cook_count(&self, node_id: NodeId) -> u64 {
+        self.cook_counts.get(&node_id).copied().unwrap_or(0)
+    }
+
+    /// Take the (node type, duration) of cooks completed since the last call
+    pub fn take_cook_timings(&mut self) -> Vec<(String, Duration)> {
+        std::mem::take(&mut self.cook_timings)
+    }
+    
     /// Get cached output for a node's port
     pub fn get_cached_output(&mut self, node_id: NodeId, port_idx: usize) -> Option<&NodeData> {
-        let cache_key = CacheKey::new(node_id, port_idx);
+        let cache_key = self.output_key(node_id, port_idx);
         self.unified_cache.get(&cache_key)
     }
     
+    /// Output of a node's port as viewers should show it: a decimated proxy of
+    /// heavy geometry in draft mode, the full result otherwise
+    pub fn get_viewer_output(&mut self, node_id: NodeId, port_idx: usize) -> Option<NodeData> {
+        let cook_count = self.cook_counts.get(&node_id).copied().unwrap_or(0);
+        let cache_key = self.output_key(node_id, port_idx);
+        let output = self.unified_cache.get(&cache_key)?;
+        if self.quality_mode == QualityMode::Final {
+            return Some(output.clone());
+        }
+        
+        // Proxies are made once per cook of the source node
+        let proxy = match self.proxies.get(&(node_id, port_idx)) {
+            Some((made_from, proxy)) if *made_from == cook_count => proxy.clone(),
+            _ => {
+                use crate::nodes::three_d::proxy::{decimate_scene, PROXY_TRIANGLE_BUDGET};
+                let proxy = match output {
+                    NodeData::USDSceneData(scene) => decimate_scene(scene, PROXY_TRIANGLE_BUDGET).map(NodeData::USDSceneData),
+                    _ => None,
+                };
+                self.proxies.insert((node_id, port_idx), (cook_count, proxy.clone()));
+                proxy
+            }
+        };
+        Some(proxy.unwrap_or_else(|| output.clone()))
+    }
+    
     /// Get cached output for a specific stage of a node's port
     pub fn get_cached_stage_output(&mut self, node_id: NodeId, stage_id: &str, port_idx: usize) -> Option<&NodeData> {
         let cache_key = CacheKey::with_stage(node_id, stage_id, port_idx);
@@ -730,15 +1330,16 @@ impl NodeGraphEngine {
 
     /// Handle a new connection being created
     pub fn on_connection_added(&mut self, connection: &Connection, graph: &NodeGraph) {
-        println!("🔗 ExecutionEngine: Connection added {} -> {}", connection.from_node, connection.to_node);
-        
         // Call node-specific connection hooks for the target node
         if let Some(target_node) = graph.nodes.get(&connection.to_node) {
+            let mut warnings = Vec::new();
             if let Some(hooks) = self.execution_hooks.get_mut(&target_node.type_id) {
                 if let Err(e) = hooks.on_input_connection_added(target_node, graph) {
-                    eprintln!("❌ Connection added hook failed for node {}: {}", connection.to_node, e);
+                    warnings.push(format!("connection hook failed: {}", e));
                 }
             }
+            let message = format!("input {} connected from node {}", connection.to_port, connection.from_node);
+            self.log.record_event(connection.to_node, &target_node.title, &target_node.type_id, message, warnings);
         }
         
         // Only mark the target node (viewport) as dirty - it needs to re-render with new input
@@ -757,17 +1358,35 @@ impl NodeGraphEngine {
         }
     }
 
+    /// Handle a connection's adapter being changed or removed: the target re-cooks with the new conversion
+    pub fn on_connection_adapter_changed(&mut self, connection: &Connection, graph: &NodeGraph) {
+        if let Some(target_node) = graph.nodes.get(&connection.to_node) {
+            let adapter = connection.adapter.map_or("no", |adapter| adapter.label());
+            let message = format!("input {} now has {} adapter", connection.to_port, adapter);
+            self.log.record_event(connection.to_node, &target_node.title, &target_node.type_id, message, Vec::new());
+        }
+        self.mark_dirty(connection.to_node, graph);
+        
+        // Execute immediately if in auto mode
+        if self.execution_mode == EngineExecutionMode::Auto {
+            if let Err(e) = self.execute_dirty_nodes(graph) {
+                eprintln!("Auto execution after adapter change failed: {}", e);
+            }
+        }
+    }
+
     /// Handle a connection being removed
     pub fn on_connection_removed(&mut self, connection: &Connection, graph: &NodeGraph) {
-        println!("🔗 ExecutionEngine: Connection removed {} -> {}", connection.from_node, connection.to_node);
-        
         // Call node-specific connection hooks for the target node
         if let Some(target_node) = graph.nodes.get(&connection.to_node) {
+            let mut warnings = Vec::new();
             if let Some(hooks) = self.execution_hooks.get_mut(&target_node.type_id) {
                 if let Err(e) = hooks.on_input_connection_removed(target_node, graph) {
-                    eprintln!("❌ Connection removed hook failed for node {}: {}", connection.to_node, e);
+                    warnings.push(format!("connection hook failed: {}", e));
                 }
             }
+            let message = format!("input {} disconnected from node {}", connection.to_port, connection.from_node);
+            self.log.record_event(connection.to_node, &target_node.title, &target_node.type_id, message, warnings);
         }
         
         // Mark the target node as dirty (this will also propagate downstream)
@@ -859,15 +1478,21 @@ impl NodeGraphEngine {
     pub fn on_node_removed(&mut self, node_id: NodeId, graph: &NodeGraph) {
         // Call node-specific removal hook
         if let Some(node) = graph.nodes.get(&node_id) {
+            let mut warnings = Vec::new();
             if let Some(hooks) = self.execution_hooks.get_mut(&node.type_id) {
                 if let Err(e) = hooks.on_node_removed(node_id) {
-                    eprintln!("Node removal hook failed for {}: {}", node.type_id, e);
+                    warnings.push(format!("removal hook failed: {}", e));
                 }
             }
+            self.log.record_event(node_id, &node.title, &node.type_id, "removed", warnings);
         }
         
-        // Clear output cache for the removed node
+        // Clear output cache and persistent state for the removed node
         self.unified_cache.invalidate(&CacheKeyPattern::Node(node_id));
+        self.state_store.remove(node_id);
+        self.cook_counts.remove(&node_id);
+        self.proxies.retain(|(source, _), _| *source != node_id);
+        self.debugger.forget_node(node_id);
         
         // Find all nodes that were connected to the deleted node
         let mut affected_nodes = Vec::new();
@@ -886,20 +1511,18 @@ impl NodeGraphEngine {
 
     /// Handle a node parameter change
     pub fn on_node_parameter_changed(&mut self, node_id: NodeId, graph: &NodeGraph) {
-        println!("🔧 ExecutionEngine: Parameter changed for node {} in {} mode", node_id, 
-                 if self.execution_mode == EngineExecutionMode::Auto { "Auto" } else { "Manual" });
+        if let Some(node) = graph.nodes.get(&node_id) {
+            self.log.record_event(node_id, &node.title, &node.type_id, "parameter changed", Vec::new());
+        }
         
         // Standard cache invalidation for all nodes
         self.mark_dirty(node_id, graph);
         
         // Execute immediately if in auto mode
         if self.execution_mode == EngineExecutionMode::Auto {
-            println!("🔧 ExecutionEngine: Executing immediately due to parameter change");
             if let Err(e) = self.execute_dirty_nodes(graph) {
                 eprintln!("Auto execution after parameter change failed: {}", e);
             }
-        } else {
-            println!("🔧 ExecutionEngine: Manual mode - waiting for Cook button");
         }
     }
     
@@ -912,6 +1535,126 @@ impl NodeGraphEngine {
     pub fn get_execution_mode(&self) -> EngineExecutionMode {
         self.execution_mode
     }
+    
+    /// Switch between draft and final quality. Viewers re-cook to pick up the change.
+    pub fn set_quality_mode(&mut self, mode: QualityMode, graph: &NodeGraph) {
+        if self.quality_mode == mode {
+            return;
+        }
+        self.quality_mode = mode;
+        let viewers: Vec<NodeId> = graph.nodes.values()
+            .filter(|node| node.type_id == "Viewport")
+            .map(|node| node.id)
+            .collect();
+        for node_id in viewers {
+            self.mark_dirty(node_id, graph);
+        }
+    }
+    
+    /// Get the current draft/final quality
+    pub fn get_quality_mode(&self) -> QualityMode {
+        self.quality_mode
+    }
+    
+    /// Move the timeline to `frame`. Static nodes keep their outputs; time-dependent
+    /// nodes restore the outputs cached for that frame, or become dirty if there are none.
+    pub fn set_frame(&mut self, frame: i64, graph: &NodeGraph) {
+        if self.current_frame == frame {
+            return;
+        }
+        self.current_frame = frame;
+        self.refresh_time_dependence(graph);
+        
+        let time_dependent: Vec<NodeId> = self.time_dependent.iter().copied().collect();
+        for node_id in time_dependent {
+            let Some(node) = graph.nodes.get(&node_id) else {
+                continue;
+            };
+            let cached = !COOK_EVERY_FRAME_TYPES.contains(&node.type_id.as_str())
+                && self.node_states.get(&node_id) == Some(&NodeState::Clean)
+                && (0..node.outputs.len()).all(|port| self.unified_cache.contains(&CacheKey::at_frame(node_id, port, frame)));
+            if cached {
+                *self.cook_counts.entry(node_id).or_insert(0) += 1;
+            } else {
+                // Other frames stay cached; only this one is missing
+                self.node_states.insert(node_id, NodeState::Dirty);
+                self.dirty_nodes.insert(node_id);
+            }
+        }
+    }
+    
+    /// Switch between cooking every dirty node and only what display nodes need
+    pub fn set_evaluation_strategy(&mut self, strategy: EvaluationStrategy) {
+        self.evaluation_strategy = strategy;
+    }
+
+    pub fn get_evaluation_strategy(&self) -> EvaluationStrategy {
+        self.evaluation_strategy
+    }
+
+    /// Set the project seed; a new seed re-cooks the whole graph
+    pub fn set_seed(&mut self, seed: u64, graph: &NodeGraph) {
+        if self.seeds.graph_seed != seed {
+            self.seeds = SeedContext::new(seed);
+            self.mark_all_dirty(graph);
+        }
+    }
+
+    /// Project seed random nodes draw from
+    pub fn seeds(&self) -> &SeedContext {
+        &self.seeds
+    }
+
+    /// Get the timeline frame the graph is evaluated at
+    pub fn get_current_frame(&self) -> i64 {
+        self.current_frame
+    }
+    
+    /// Whether a node's output changes with the timeline frame
+    pub fn is_time_dependent(&self, node_id: NodeId) -> bool {
+        self.time_dependent.contains(&node_id)
+    }
+    
+    /// Recompute which nodes are downstream of a time source
+    fn refresh_time_dependence(&mut self, graph: &NodeGraph) {
+        let mut queue: VecDeque<NodeId> = graph.nodes.values()
+            .filter(|node| TIME_SOURCE_TYPES.contains(&node.type_id.as_str()))
+            .map(|node| node.id)
+            .collect();
+        let mut time_dependent: HashSet<NodeId> = queue.iter().copied().collect();
+        while let Some(node_id) = queue.pop_front() {
+            for downstream_id in self.find_downstream_nodes(node_id, graph) {
+                if time_dependent.insert(downstream_id) {
+                    queue.push_back(downstream_id);
+                }
+            }
+        }
+        self.time_dependent = time_dependent;
+    }
+    
+    /// Cache key of a node's output at the current frame
+    fn output_key(&self, node_id: NodeId, port_idx: usize) -> CacheKey {
+        if self.time_dependent.contains(&node_id) {
+            CacheKey::at_frame(node_id, port_idx, self.current_frame)
+        } else {
+            CacheKey::new(node_id, port_idx)
+        }
+    }
+    
+    /// Drop the cached frames of `node_id` furthest from the current frame beyond the limit
+    fn evict_distant_frames(&mut self, node_id: NodeId) {
+        let mut frames = self.unified_cache.cached_frames(node_id);
+        if frames.len() <= MAX_CACHED_FRAMES {
+            return;
+        }
+        let current_frame = self.current_frame;
+        frames.sort_by_key(|frame| std::cmp::Reverse(frame.abs_diff(current_frame)));
+        let excess = frames.len() - MAX_CACHED_FRAMES;
+        for frame in frames.into_iter().take(excess) {
+            let pattern = CacheKeyPattern::Frame(node_id, frame);
+            self.unified_cache.invalidate(&pattern);
+        }
+    }
 
     /* REMOVED - Now handled by node hooks
     /// Clear GPU mesh cache when USD parameters change - only for connected viewport nodes
@@ -0,0 +1,267 @@
+//! Structured execution log
+//!
+//! The execution engine records every cook here (node, duration, a summary of
+//! its inputs, warnings raised by hooks, the error if it failed) along with
+//! the graph events that trigger cooks, instead of printing traces to the
+//! terminal. The editor shows the log in a filterable window and exports it as
+//! text or JSON.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::time::Duration;
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::nodes::value_preview;
+
+/// Oldest entries are dropped beyond this many
+const MAX_LOG_ENTRIES: usize = 10_000;
+
+/// Longest input summary kept per entry
+const MAX_INPUT_SUMMARY_CHARS: usize = 120;
+
+/// What an entry records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogKind {
+    /// A node cooked successfully
+    Cook,
+    /// A node's cook failed
+    Error,
+    /// A graph change that dirties nodes (connection or parameter edits)
+    Event,
+}
+
+impl LogKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogKind::Cook => "cook",
+            LogKind::Error => "error",
+            LogKind::Event => "event",
+        }
+    }
+}
+
+/// One cook or graph event
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    pub time: chrono::DateTime<chrono::Local>,
+    pub kind: LogKind,
+    pub node_id: NodeId,
+    pub node_title: String,
+    pub node_type: String,
+    /// Wall time of a cook in milliseconds
+    pub duration_ms: Option<f64>,
+    /// Short summary of the inputs a cook received
+    pub inputs: String,
+    pub message: String,
+    pub warnings: Vec<String>,
+}
+
+impl LogEntry {
+    /// "14:02:11.120 cook Sphere (3D_Sphere) 1.20 ms — cooked"
+    pub fn to_line(&self) -> String {
+        let mut line = format!(
+            "{} {} {} ({})",
+            self.time.format("%H:%M:%S%.3f"),
+            self.kind.label(),
+            self.node_title,
+            self.node_type,
+        );
+        if let Some(duration_ms) = self.duration_ms {
+            line.push_str(&format!(" {:.2} ms", duration_ms));
+        }
+        line.push_str(&format!(" — {}", self.message));
+        if !self.inputs.is_empty() {
+            line.push_str(&format!(" [inputs: {}]", self.inputs));
+        }
+        for warning in &self.warnings {
+            line.push_str(&format!(" [warning: {}]", warning));
+        }
+        line
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty() || self.kind == LogKind::Error
+    }
+}
+
+/// Which entries the log window shows
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// Case-insensitive text matched against node title, type and message
+    pub text: String,
+    /// Only entries of these nodes (empty shows every node)
+    pub nodes: Vec<NodeId>,
+    /// Only entries with warnings or errors
+    pub problems_only: bool,
+}
+
+impl LogFilter {
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        if self.problems_only && !entry.has_warnings() {
+            return false;
+        }
+        if !self.nodes.is_empty() && !self.nodes.contains(&entry.node_id) {
+            return false;
+        }
+        let text = self.text.trim().to_lowercase();
+        text.is_empty()
+            || entry.node_title.to_lowercase().contains(&text)
+            || entry.node_type.to_lowercase().contains(&text)
+            || entry.message.to_lowercase().contains(&text)
+    }
+}
+
+/// Recent cooks and graph events, oldest first
+#[derive(Debug, Default)]
+pub struct ExecutionLog {
+    entries: VecDeque<LogEntry>,
+}
+
+impl ExecutionLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a finished cook; `result` holds the error of a failed one
+    pub fn record_cook(&mut self, node_id: NodeId, node_title: &str, node_type: &str, duration: Duration, inputs: String, warnings: Vec<String>, result: Result<(), &str>) {
+        let (kind, message) = match result {
+            Ok(()) => (LogKind::Cook, "cooked".to_string()),
+            Err(e) => (LogKind::Error, format!("failed: {}", e)),
+        };
+        self.push(LogEntry {
+            time: chrono::Local::now(),
+            kind,
+            node_id,
+            node_title: node_title.to_string(),
+            node_type: node_type.to_string(),
+            duration_ms: Some(duration.as_secs_f64() * 1000.0),
+            inputs,
+            message,
+            warnings,
+        });
+    }
+
+    /// Record a graph event concerning a node
+    pub fn record_event(&mut self, node_id: NodeId, node_title: &str, node_type: &str, message: impl Into<String>, warnings: Vec<String>) {
+        self.push(LogEntry {
+            time: chrono::Local::now(),
+            kind: LogKind::Event,
+            node_id,
+            node_title: node_title.to_string(),
+            node_type: node_type.to_string(),
+            duration_ms: None,
+            inputs: String::new(),
+            message: message.into(),
+            warnings,
+        });
+    }
+
+    fn push(&mut self, entry: LogEntry) {
+        self.entries.push_back(entry);
+        while self.entries.len() > MAX_LOG_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn entries(&self) -> &VecDeque<LogEntry> {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Entries passing `filter`, oldest first
+    pub fn filtered<'a>(&'a self, filter: &'a LogFilter) -> impl Iterator<Item = &'a LogEntry> + 'a {
+        self.entries.iter().filter(move |entry| filter.matches(entry))
+    }
+
+    /// Entries passing `filter` as plain text, one per line
+    pub fn to_text(&self, filter: &LogFilter) -> String {
+        self.filtered(filter).map(|entry| entry.to_line() + "\n").collect()
+    }
+
+    /// Entries passing `filter` as a JSON array
+    pub fn to_json(&self, filter: &LogFilter) -> Result<String, String> {
+        let entries: Vec<&LogEntry> = self.filtered(filter).collect();
+        serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())
+    }
+
+    /// Write the entries passing `filter` to `path`: JSON for `.json` files, text otherwise
+    pub fn export_to_file(&self, path: &Path, filter: &LogFilter) -> Result<(), String> {
+        let contents = if path.extension().is_some_and(|ext| ext == "json") {
+            self.to_json(filter)?
+        } else {
+            self.to_text(filter)
+        };
+        std::fs::write(path, contents).map_err(|e| format!("Failed to write execution log: {}", e))
+    }
+}
+
+/// "2.0000, Buffer [100]" for the inputs of a cook; sizes only for heavy data, so it stays cheap
+pub fn summarize_inputs(inputs: &[NodeData]) -> String {
+    let summary = inputs.iter()
+        .map(|input| match input {
+            NodeData::Buffer(values) => format!("Buffer [{}]", values.len()),
+            NodeData::Table(table) => format!("Table {} × {}", table.row_count(), table.column_count()),
+            NodeData::Tree(tree) => format!("Tree [{}]", tree.item_count()),
+            NodeData::PointCloud(points) => format!("Point cloud [{}]", points.point_count()),
+            NodeData::Volume(volume) => format!("Volume [{} grids]", volume.grids.len()),
+            NodeData::Audio(audio) => format!("Audio [{:.2}s]", audio.duration()),
+            NodeData::List(items) => format!("List [{}]", items.len()),
+            NodeData::Map(entries) => format!("Map [{} keys]", entries.len()),
+            NodeData::Custom(data) => crate::plugins::data_types::type_name(&data.type_id),
+            NodeData::Geometry(geometry) => format!("Geometry [{} points]", geometry.vertices.len()),
+            NodeData::Scene(scene) => format!("Scene [{} meshes]", scene.geometry.len()),
+            NodeData::USDSceneData(scene) => format!("USD scene [{} meshes]", scene.meshes.len()),
+            NodeData::USDScenegraphMetadata(metadata) => format!("USD scenegraph [{} meshes]", metadata.meshes.len()),
+            NodeData::Stage(stage) => format!("Stage {}", stage.identifier),
+            NodeData::Material(material) => format!("Material {}", material.id),
+            NodeData::Light(light) => format!("Light {}", light.id),
+            NodeData::Image(image) => format!("Image {} × {}", image.width, image.height),
+            NodeData::None => "none".to_string(),
+            other => value_preview::scalar_text(other).unwrap_or_default(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    value_preview::truncate(&summary, MAX_INPUT_SUMMARY_CHARS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_records_cooks_and_filters_them() {
+        let mut log = ExecutionLog::new();
+        log.record_cook(1, "Sphere", "3D_Sphere", Duration::from_millis(2), String::new(), Vec::new(), Ok(()));
+        log.record_cook(2, "Add", "Math_Add", Duration::from_micros(10), summarize_inputs(&[NodeData::Float(1.5), NodeData::Integer(2)]), Vec::new(), Err("bad input"));
+        log.record_event(2, "Add", "Math_Add", "parameter changed", vec!["hook failed".to_string()]);
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.entries()[1].inputs, "1.5000, 2");
+        assert_eq!(log.entries()[1].kind, LogKind::Error);
+
+        let by_node = LogFilter { nodes: vec![2], ..Default::default() };
+        assert_eq!(log.filtered(&by_node).count(), 2);
+        let by_text = LogFilter { text: "SPHERE".to_string(), ..Default::default() };
+        assert_eq!(log.filtered(&by_text).count(), 1);
+        let problems = LogFilter { problems_only: true, ..Default::default() };
+        assert_eq!(log.filtered(&problems).count(), 2);
+
+        let text = log.to_text(&by_text);
+        assert!(text.contains("cook Sphere (3D_Sphere) 2.00 ms — cooked"));
+        let json: serde_json::Value = serde_json::from_str(&log.to_json(&problems).unwrap()).unwrap();
+        assert_eq!(json[0]["kind"], "error");
+        assert_eq!(json[1]["warnings"][0], "hook failed");
+    }
+}
@@ -0,0 +1,428 @@
+//! Parameter expressions
+//!
+//! Any numeric parameter can be driven by an expression instead of its stored
+//! value, e.g. `ch("Sphere1/radius") * 2`. Expressions are kept per node in
+//! `Node::expressions` and evaluated when the node cooks, so the stored value
+//! stays as the fallback when the expression is removed.
+//!
+//! Syntax: numbers, `+ - * / % ^`, parentheses, the constants `pi` and `e`,
+//! the functions `sin cos tan abs sqrt floor ceil round min max pow clamp`,
+//! and `ch("<node>/<parameter>")`, which reads a parameter of another node in
+//! the same graph by title (or by id). A referenced parameter that has an
+//! expression itself is evaluated first; reference cycles are errors. Nodes
+//! evaluating per-point expressions add names like `P.x` to the syntax.
+
+use std::collections::{HashMap, HashSet};
+use super::graph::NodeGraph;
+use super::interface::NodeData;
+use super::node::{Node, NodeId};
+
+/// Value of `expression` evaluated against `graph`
+pub fn evaluate(expression: &str, graph: &NodeGraph) -> Result<f64, String> {
+    Evaluator { graph: Some(graph), variables: &no_variables, stack: Vec::new() }.evaluate(expression)
+}
+
+/// Value of `expression` with the names resolved by `variables` (e.g. `P.x`)
+/// in scope, as nodes evaluate per-point expressions; `ch()` is unavailable
+pub fn evaluate_with_variables(expression: &str, variables: &dyn Fn(&str) -> Option<f64>) -> Result<f64, String> {
+    Evaluator { graph: None, variables, stack: Vec::new() }.evaluate(expression)
+}
+
+/// The `(node, parameter)` pairs an expression reads through `ch()`
+pub fn references(expression: &str) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    let mut rest = expression;
+    while let Some(start) = rest.find("ch(") {
+        rest = &rest[start + 3..];
+        let trimmed = rest.trim_start();
+        let Some(quoted) = trimmed.strip_prefix('"') else {
+            continue;
+        };
+        if let Some(end) = quoted.find('"') {
+            if let Some((node, parameter)) = quoted[..end].rsplit_once('/') {
+                found.push((node.to_string(), parameter.to_string()));
+            }
+        }
+    }
+    found
+}
+
+/// `expression` with its `ch()` references to the node titled `old` reading
+/// the node titled `new` instead
+pub fn rename_references(expression: &str, old: &str, new: &str) -> String {
+    let mut renamed = String::with_capacity(expression.len());
+    let mut rest = expression;
+    while let Some(start) = rest.find("ch(") {
+        let (before, after) = rest.split_at(start + 3);
+        renamed.push_str(before);
+        rest = after;
+        let spaces = rest.len() - rest.trim_start().len();
+        if let Some(tail) = rest[spaces..].strip_prefix('"').and_then(|quoted| quoted.strip_prefix(old)) {
+            if tail.starts_with('/') {
+                renamed.push_str(&rest[..spaces]);
+                renamed.push('"');
+                renamed.push_str(new);
+                rest = tail;
+            }
+        }
+    }
+    renamed.push_str(rest);
+    renamed
+}
+
+/// Find a node of `graph` by title, falling back to its id
+pub fn find_node<'a>(graph: &'a NodeGraph, name: &str) -> Option<&'a Node> {
+    let mut by_title: Vec<&Node> = graph.nodes.values().filter(|node| node.title == name).collect();
+    by_title.sort_by_key(|node| node.id);
+    by_title.first().copied()
+        .or_else(|| name.parse::<NodeId>().ok().and_then(|id| graph.nodes.get(&id)))
+}
+
+/// Nodes whose expressions read a parameter of `node_id`, directly or through
+/// other expressions
+pub fn dependents_of(node_id: NodeId, graph: &NodeGraph) -> Vec<NodeId> {
+    let mut dependents = Vec::new();
+    let mut seen: HashSet<NodeId> = HashSet::from([node_id]);
+    let mut queue = vec![node_id];
+    while let Some(source) = queue.pop() {
+        for node in graph.nodes.values().filter(|node| !node.expressions.is_empty()) {
+            let reads_source = node.expressions.values()
+                .flat_map(|expression| references(expression))
+                .any(|(name, _)| find_node(graph, &name).is_some_and(|found| found.id == source));
+            if reads_source && seen.insert(node.id) {
+                dependents.push(node.id);
+                queue.push(node.id);
+            }
+        }
+    }
+    dependents.sort_unstable();
+    dependents
+}
+
+/// `node` with its expression-driven parameters replaced by their values, or
+/// None when it has no expressions. Parameters whose expression fails keep
+/// their stored value.
+pub fn evaluated_node(node: &Node, graph: &NodeGraph) -> Option<Node> {
+    if node.expressions.is_empty() {
+        return None;
+    }
+    let mut evaluated = node.clone();
+    for (parameter, value) in evaluate_parameters(node, graph) {
+        match value {
+            Ok(value) => {
+                evaluated.parameters.insert(parameter, value);
+            }
+            Err(e) => log::warn!("{}.{}: {}", node.title, parameter, e),
+        }
+    }
+    Some(evaluated)
+}
+
+/// Value of every expression of `node`, converted to its parameter's type
+pub fn evaluate_parameters(node: &Node, graph: &NodeGraph) -> HashMap<String, Result<NodeData, String>> {
+    node.expressions.iter()
+        .map(|(parameter, expression)| {
+            let mut evaluator = Evaluator { graph: Some(graph), variables: &no_variables, stack: vec![(node.id, parameter.clone())] };
+            let value = evaluator.evaluate(expression)
+                .and_then(|value| convert(node.parameters.get(parameter), value));
+            (parameter.clone(), value)
+        })
+        .collect()
+}
+
+/// Whether a parameter can be driven by an expression
+pub fn is_numeric(value: &NodeData) -> bool {
+    matches!(value, NodeData::Float(_) | NodeData::Integer(_) | NodeData::Boolean(_))
+}
+
+/// Variables of graph-level expressions: none besides the constants
+fn no_variables(_: &str) -> Option<f64> {
+    None
+}
+
+fn convert(current: Option<&NodeData>, value: f64) -> Result<NodeData, String> {
+    match current {
+        Some(NodeData::Float(_)) | None => Ok(NodeData::Float(value as f32)),
+        Some(NodeData::Integer(_)) => Ok(NodeData::Integer(value.round() as i32)),
+        Some(NodeData::Boolean(_)) => Ok(NodeData::Boolean(value != 0.0)),
+        Some(_) => Err("Only numeric parameters can have expressions".to_string()),
+    }
+}
+
+struct Evaluator<'a> {
+    graph: Option<&'a NodeGraph>,
+    /// Names in scope besides `pi` and `e`
+    variables: &'a dyn Fn(&str) -> Option<f64>,
+    /// Parameters being evaluated, to detect reference cycles
+    stack: Vec<(NodeId, String)>,
+}
+
+impl Evaluator<'_> {
+    fn evaluate(&mut self, expression: &str) -> Result<f64, String> {
+        let mut parser = Parser { chars: expression.chars().collect(), pos: 0, evaluator: self };
+        let value = parser.expression()?;
+        parser.skip_whitespace();
+        if parser.pos < parser.chars.len() {
+            return Err(format!("Unexpected \"{}\" in expression", parser.chars[parser.pos]));
+        }
+        Ok(value)
+    }
+
+    fn channel(&mut self, path: &str) -> Result<f64, String> {
+        let (name, parameter) = path.rsplit_once('/')
+            .ok_or_else(|| format!("ch(\"{}\") must name a node and a parameter, like \"Sphere1/radius\"", path))?;
+        let graph = self.graph.ok_or_else(|| "ch() can't be used here".to_string())?;
+        let node = find_node(graph, name).ok_or_else(|| format!("No node named \"{}\"", name))?;
+
+        if let Some(expression) = node.expressions.get(parameter) {
+            let key = (node.id, parameter.to_string());
+            if self.stack.contains(&key) {
+                return Err(format!("Expression cycle through \"{}\"", path));
+            }
+            self.stack.push(key);
+            let value = self.evaluate(expression);
+            self.stack.pop();
+            return value;
+        }
+
+        match node.parameters.get(parameter) {
+            Some(NodeData::Float(value)) => Ok(*value as f64),
+            Some(NodeData::Integer(value)) => Ok(*value as f64),
+            Some(NodeData::Boolean(value)) => Ok(if *value { 1.0 } else { 0.0 }),
+            Some(_) => Err(format!("Parameter \"{}\" is not numeric", path)),
+            None => Err(format!("{} has no parameter \"{}\"", name, parameter)),
+        }
+    }
+}
+
+/// Recursive descent over the expression text, evaluating as it goes
+struct Parser<'a, 'b> {
+    chars: Vec<char>,
+    pos: usize,
+    evaluator: &'a mut Evaluator<'b>,
+}
+
+impl Parser<'_, '_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume `c` if it is the next non-blank character
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.chars.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expression(&mut self) -> Result<f64, String> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<f64, String> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else if self.eat('%') {
+                value %= self.unary()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        if self.eat('-') {
+            return Ok(-self.unary()?);
+        }
+        let base = self.atom()?;
+        if self.eat('^') {
+            return Ok(base.powf(self.unary()?));
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        let Some(&c) = self.chars.get(self.pos) else {
+            return Err("Expression ends unexpectedly".to_string());
+        };
+
+        if self.eat('(') {
+            let value = self.expression()?;
+            return if self.eat(')') { Ok(value) } else { Err("Missing \")\"".to_string()) };
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let start = self.pos;
+            while self.chars.get(self.pos).is_some_and(|c| c.is_ascii_digit() || *c == '.') {
+                self.pos += 1;
+            }
+            let text: String = self.chars[start..self.pos].iter().collect();
+            return text.parse().map_err(|_| format!("Invalid number \"{}\"", text));
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = self.pos;
+            while self.chars.get(self.pos).is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+                self.pos += 1;
+            }
+            // Component access like `P.x` is part of the name
+            while self.chars.get(self.pos) == Some(&'.') && self.chars.get(self.pos + 1).is_some_and(|c| c.is_alphabetic()) {
+                self.pos += 1;
+                while self.chars.get(self.pos).is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+                    self.pos += 1;
+                }
+            }
+            let name: String = self.chars[start..self.pos].iter().collect();
+            if !self.eat('(') {
+                if let Some(value) = (self.evaluator.variables)(&name) {
+                    return Ok(value);
+                }
+                return match name.as_str() {
+                    "pi" => Ok(std::f64::consts::PI),
+                    "e" => Ok(std::f64::consts::E),
+                    _ => Err(format!("Unknown name \"{}\"", name)),
+                };
+            }
+            if name == "ch" {
+                let path = self.string()?;
+                if !self.eat(')') {
+                    return Err("Missing \")\" after ch()".to_string());
+                }
+                return self.evaluator.channel(&path);
+            }
+            let arguments = self.arguments()?;
+            return call(&name, &arguments);
+        }
+
+        Err(format!("Unexpected \"{}\" in expression", c))
+    }
+
+    /// Comma-separated arguments up to the closing parenthesis
+    fn arguments(&mut self) -> Result<Vec<f64>, String> {
+        let mut arguments = Vec::new();
+        if self.eat(')') {
+            return Ok(arguments);
+        }
+        loop {
+            arguments.push(self.expression()?);
+            if self.eat(')') {
+                return Ok(arguments);
+            }
+            if !self.eat(',') {
+                return Err("Expected \",\" or \")\" in function call".to_string());
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if !self.eat('"') {
+            return Err("ch() takes a quoted \"node/parameter\" path".to_string());
+        }
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(|c| *c != '"') {
+            self.pos += 1;
+        }
+        if self.pos >= self.chars.len() {
+            return Err("Unterminated string".to_string());
+        }
+        let text = self.chars[start..self.pos].iter().collect();
+        self.pos += 1;
+        Ok(text)
+    }
+}
+
+fn call(name: &str, arguments: &[f64]) -> Result<f64, String> {
+    let expect = |count: usize| {
+        if arguments.len() == count {
+            Ok(())
+        } else {
+            Err(format!("{}() takes {} argument{}, got {}", name, count, if count == 1 { "" } else { "s" }, arguments.len()))
+        }
+    };
+    match name {
+        "sin" | "cos" | "tan" | "abs" | "sqrt" | "floor" | "ceil" | "round" => {
+            expect(1)?;
+            let x = arguments[0];
+            Ok(match name {
+                "sin" => x.sin(),
+                "cos" => x.cos(),
+                "tan" => x.tan(),
+                "abs" => x.abs(),
+                "sqrt" => x.sqrt(),
+                "floor" => x.floor(),
+                "ceil" => x.ceil(),
+                _ => x.round(),
+            })
+        }
+        "min" | "max" | "pow" => {
+            expect(2)?;
+            let (a, b) = (arguments[0], arguments[1]);
+            Ok(match name {
+                "min" => a.min(b),
+                "max" => a.max(b),
+                _ => a.powf(b),
+            })
+        }
+        "clamp" => {
+            expect(3)?;
+            Ok(arguments[0].max(arguments[1]).min(arguments[2]))
+        }
+        _ => Err(format!("Unknown function \"{}\"", name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_expressions_read_other_parameters_and_track_dependents() {
+        let mut graph = NodeGraph::new();
+        let mut sphere = Node::new(0, "Sphere1", Pos2::ZERO);
+        sphere.parameters.insert("radius".to_string(), NodeData::Float(1.5));
+        let sphere_id = graph.add_node(sphere);
+
+        let mut cube = Node::new(0, "Cube1", Pos2::ZERO);
+        cube.parameters.insert("size".to_string(), NodeData::Float(1.0));
+        cube.parameters.insert("subdivisions".to_string(), NodeData::Integer(1));
+        cube.expressions.insert("size".to_string(), r#"ch("Sphere1/radius") * 2"#.to_string());
+        cube.expressions.insert("subdivisions".to_string(), r#"max(ch("Cube1/size"), 1) + 0.4"#.to_string());
+        let cube_id = graph.add_node(cube);
+
+        assert_eq!(evaluate("2 + 3 * 4 ^ 2 / (1 - -1)", &graph).unwrap(), 26.0);
+        assert!(evaluate("foo(1)", &graph).is_err());
+        assert!(evaluate(r#"ch("Missing/radius")"#, &graph).is_err());
+
+        let evaluated = evaluated_node(&graph.nodes[&cube_id], &graph).unwrap();
+        assert!(matches!(evaluated.parameters.get("size"), Some(NodeData::Float(f)) if *f == 3.0));
+        assert!(matches!(evaluated.parameters.get("subdivisions"), Some(NodeData::Integer(3))));
+        assert!(evaluated_node(&graph.nodes[&sphere_id], &graph).is_none());
+        assert_eq!(dependents_of(sphere_id, &graph), vec![cube_id]);
+
+        // A parameter reading itself through another expression is a cycle, not a hang
+        graph.nodes.get_mut(&cube_id).unwrap().expressions
+            .insert("size".to_string(), r#"ch("Cube1/subdivisions")"#.to_string());
+        let values = evaluate_parameters(&graph.nodes[&cube_id], &graph);
+        assert!(values["size"].as_ref().unwrap_err().contains("cycle"));
+    }
+}
@@ -2,8 +2,10 @@
 
 use egui::{Color32, Pos2, Vec2};
 use crate::nodes::{Node, NodeId, NodeGraph};
-use crate::nodes::interface::PanelType;
+use crate::nodes::interface::{NodeData, PanelType};
+pub use crate::nodes::parameter_schema::ParameterDefinition;
 use std::collections::{HashMap, BTreeMap};
+use std::sync::{Arc, LazyLock, RwLock};
 use log::{debug, info, warn, error};
 
 /// Data types that can flow through ports
@@ -11,6 +13,8 @@ use log::{debug, info, warn, error};
 pub enum DataType {
     /// Floating point number
     Float,
+    /// Signed 32-bit integer
+    Integer,
     /// 3D vector (x, y, z)
     Vector3,
     /// RGB color value
@@ -21,25 +25,72 @@ pub enum DataType {
     Boolean,
     /// USD scene data
     USDScene,
+    /// Flat f32 buffer
+    Buffer,
+    /// Tabular data with named columns
+    Table,
+    /// Hierarchical data for the Tree panel
+    Tree,
+    /// Point cloud
+    PointCloud,
+    /// Voxel grids
+    Volume,
+    /// Audio samples
+    Audio,
+    /// Ordered collection of values
+    List,
+    /// Named values
+    Map,
     /// Any type (for generic ports)
     Any,
 }
 
 impl DataType {
+    /// Every data type, in the order the type legend lists them
+    pub const ALL: [DataType; 16] = [
+        DataType::Float,
+        DataType::Integer,
+        DataType::Vector3,
+        DataType::Color,
+        DataType::String,
+        DataType::Boolean,
+        DataType::USDScene,
+        DataType::Buffer,
+        DataType::Table,
+        DataType::Tree,
+        DataType::PointCloud,
+        DataType::Volume,
+        DataType::Audio,
+        DataType::List,
+        DataType::Map,
+        DataType::Any,
+    ];
+
     /// Check if this data type can connect to another
     pub fn can_connect_to(&self, other: &DataType) -> bool {
         self == other || *self == DataType::Any || *other == DataType::Any
+            // Integers widen losslessly enough into float inputs
+            || (*self == DataType::Integer && *other == DataType::Float)
     }
     
     /// Get a human-readable name for this data type
     pub fn name(&self) -> &'static str {
         match self {
             DataType::Float => "Float",
+            DataType::Integer => "Integer",
             DataType::Vector3 => "Vector3", 
             DataType::Color => "Color",
             DataType::String => "String",
             DataType::Boolean => "Boolean",
             DataType::USDScene => "USDScene",
+            DataType::Buffer => "Buffer",
+            DataType::Table => "Table",
+            DataType::Tree => "Tree",
+            DataType::PointCloud => "PointCloud",
+            DataType::Volume => "Volume",
+            DataType::Audio => "Audio",
+            DataType::List => "List",
+            DataType::Map => "Map",
             DataType::Any => "Any",
         }
     }
@@ -48,11 +99,20 @@ impl DataType {
     pub fn color(&self) -> Color32 {
         match self {
             DataType::Float => Color32::from_rgb(100, 150, 255), // Blue
+            DataType::Integer => Color32::from_rgb(60, 200, 160), // Sea green
             DataType::Vector3 => Color32::from_rgb(255, 100, 100), // Red
             DataType::Color => Color32::from_rgb(255, 200, 100), // Orange
             DataType::String => Color32::from_rgb(100, 255, 100), // Green
             DataType::Boolean => Color32::from_rgb(255, 100, 255), // Magenta
             DataType::USDScene => Color32::from_rgb(255, 165, 0), // Orange
+            DataType::Buffer => Color32::from_rgb(0, 200, 200), // Teal
+            DataType::Table => Color32::from_rgb(200, 200, 120), // Khaki
+            DataType::Tree => Color32::from_rgb(120, 180, 90), // Olive green
+            DataType::PointCloud => Color32::from_rgb(170, 120, 240), // Lavender
+            DataType::Volume => Color32::from_rgb(140, 200, 220), // Pale cyan
+            DataType::Audio => Color32::from_rgb(230, 150, 90), // Apricot
+            DataType::List => Color32::from_rgb(220, 220, 220), // Off-white
+            DataType::Map => Color32::from_rgb(240, 200, 170), // Peach
             DataType::Any => Color32::from_rgb(150, 150, 150), // Gray
         }
     }
@@ -133,6 +193,12 @@ pub struct PortDefinition {
     pub data_type: DataType,
     pub optional: bool,
     pub description: Option<String>,
+    /// Physical unit of float values on this port
+    pub unit: crate::nodes::units::Unit,
+    /// Repeats as it is connected; only the last input of a node can be variadic
+    pub variadic: bool,
+    /// Value cooked while the input is unconnected, editable in the parameter panel
+    pub default: Option<NodeData>,
 }
 
 impl PortDefinition {
@@ -143,6 +209,9 @@ impl PortDefinition {
             data_type,
             optional: false,
             description: None,
+            unit: crate::nodes::units::Unit::None,
+            variadic: false,
+            default: None,
         }
     }
     
@@ -153,6 +222,9 @@ impl PortDefinition {
             data_type,
             optional: true,
             description: None,
+            unit: crate::nodes::units::Unit::None,
+            variadic: false,
+            default: None,
         }
     }
     
@@ -161,6 +233,26 @@ impl PortDefinition {
         self.description = Some(description.to_string());
         self
     }
+    
+    /// Annotate the port with a physical unit
+    pub fn with_unit(mut self, unit: crate::nodes::units::Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Let the port repeat: connecting its last copy adds another after it,
+    /// named with the next number or letter (see [`crate::nodes::port::next_variadic_name`])
+    pub fn variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+
+    /// Give the input a default, used while it is unconnected and shown
+    /// under Input Defaults in the parameter panel
+    pub fn with_default(mut self, default: NodeData) -> Self {
+        self.default = Some(default);
+        self
+    }
 }
 
 /// Panel positioning preferences
@@ -231,6 +323,8 @@ pub struct NodeMetadata {
     pub inputs: Vec<PortDefinition>,
     pub outputs: Vec<PortDefinition>,
     pub allow_multiple_connections: bool,
+    /// Declared parameters with defaults, ranges and UI hints
+    pub parameters: Vec<ParameterDefinition>,
     
     // Execution behavior
     pub execution_mode: ExecutionMode,
@@ -240,9 +334,42 @@ pub struct NodeMetadata {
     // Advanced properties
     pub is_workspace_node: bool,
     pub supports_preview: bool,
+    /// Secondary label drawn under the title, built from the node's parameters
+    pub subtitle: Option<NodeSubtitle>,
+}
+
+/// Builds a node's secondary title line from its parameters (None hides the line)
+pub type NodeSubtitle = fn(&Node) -> Option<String>;
+
+/// Extra node height reserved for the subtitle line
+pub const SUBTITLE_LINE_HEIGHT: f32 = 14.0;
+
+/// Subtitle showing the file name stored in the string parameter `parameter`
+pub fn file_name_subtitle(node: &Node, parameter: &str) -> Option<String> {
+    match node.parameters.get(parameter)? {
+        crate::nodes::interface::NodeData::String(path) if !path.is_empty() => Some(
+            std::path::Path::new(path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.clone()),
+        ),
+        _ => None,
+    }
 }
 
 impl NodeMetadata {
+    /// First (output, input) port pair that can wire this node into `target`.
+    /// Outputs are tried in order, each against the target's inputs in order;
+    /// inputs listed in `occupied_inputs` are skipped.
+    pub fn first_compatible_connection(&self, target: &NodeMetadata, occupied_inputs: &[usize]) -> Option<(usize, usize)> {
+        self.outputs.iter().enumerate().find_map(|(output_idx, output)| {
+            target.inputs.iter().enumerate()
+                .filter(|(input_idx, _)| !occupied_inputs.contains(input_idx))
+                .find(|(_, input)| output.data_type.can_connect_to(&input.data_type))
+                .map(|(input_idx, _)| (output_idx, input_idx))
+        })
+    }
+    
     /// Create node metadata with sensible defaults
     pub fn new(
         node_type: &'static str,
@@ -277,6 +404,7 @@ impl NodeMetadata {
             inputs: vec![],
             outputs: vec![],
             allow_multiple_connections: true,
+            parameters: vec![],
             
             // Execution behavior - sensible defaults
             execution_mode: ExecutionMode::OnDemand,
@@ -286,6 +414,7 @@ impl NodeMetadata {
             // Advanced properties - defaults
             is_workspace_node: false,
             supports_preview: false,
+            subtitle: None,
         }
     }
     
@@ -365,6 +494,11 @@ impl NodeMetadata {
         self
     }
     
+    pub fn with_subtitle(mut self, subtitle: NodeSubtitle) -> Self {
+        self.subtitle = Some(subtitle);
+        self
+    }
+    
     pub fn with_preview_support(mut self, supports_preview: bool) -> Self {
         self.supports_preview = supports_preview;
         self
@@ -385,6 +519,11 @@ impl NodeMetadata {
         self
     }
     
+    pub fn with_parameters(mut self, parameters: Vec<ParameterDefinition>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+    
     pub fn with_workspace_compatibility(mut self, workspaces: Vec<&'static str>) -> Self {
         self.workspace_compatibility = workspaces;
         self
@@ -421,16 +560,26 @@ pub trait NodeFactory: Send + Sync {
         // Add inputs
         for input in &meta.inputs {
             node.add_input(&input.name);
+            if let Some(port) = node.inputs.last_mut() {
+                port.unit = input.unit;
+                port.variadic = input.variadic;
+            }
         }
         
         // Add outputs  
         for output in &meta.outputs {
             node.add_output(&output.name);
+            if let Some(port) = node.outputs.last_mut() {
+                port.unit = output.unit;
+            }
         }
         
         // Set panel type from metadata
         node.set_panel_type(meta.panel_type);
         
+        // Declared parameter defaults
+        crate::nodes::parameter_schema::apply_defaults(&mut node, &meta.parameters);
+        
         // CRITICAL: Update port positions after adding ports
         node.update_port_positions();
         
@@ -447,6 +596,51 @@ pub trait NodeFactory: Send + Sync {
 type NodeCreator = fn(Pos2) -> Node;
 type MetadataProvider = fn() -> NodeMetadata;
 
+/// What menus and search need to know about a registered node, for core and
+/// plugin nodes alike
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredNode {
+    pub node_type: String,
+    pub display_name: String,
+    pub description: String,
+    pub category: Vec<String>,
+    pub tags: Vec<String>,
+    pub workspace_compatibility: Vec<String>,
+    pub inputs: Vec<DataType>,
+    pub outputs: Vec<DataType>,
+    /// Whether the last input repeats as it is connected
+    pub variadic: bool,
+    /// Whether the node comes from a plugin
+    pub is_plugin: bool,
+}
+
+impl RegisteredNode {
+    /// Whether the node belongs in any of `workspaces`. Nodes that declare no
+    /// compatibility belong everywhere, and an empty filter matches every node.
+    pub fn is_compatible_with(&self, workspaces: &[&str]) -> bool {
+        workspaces.is_empty()
+            || self.workspace_compatibility.is_empty()
+            || workspaces.iter().any(|workspace| self.workspace_compatibility.iter().any(|compat| compat == workspace))
+    }
+
+    /// How well the node matches a search query, None if it does not match.
+    /// Lower is better: name prefix, then name, then tags, then description.
+    fn search_rank(&self, query: &str) -> Option<u8> {
+        let name = self.display_name.to_lowercase();
+        if name.starts_with(query) {
+            Some(0)
+        } else if name.contains(query) || self.node_type.to_lowercase().contains(query) {
+            Some(1)
+        } else if self.tags.iter().any(|tag| tag.to_lowercase().contains(query)) {
+            Some(2)
+        } else if self.description.to_lowercase().contains(query) {
+            Some(3)
+        } else {
+            None
+        }
+    }
+}
+
 /// Registry for managing node factories
 pub struct NodeRegistry {
     creators: BTreeMap<String, NodeCreator>,
@@ -470,6 +664,37 @@ impl NodeRegistry {
         }
     }
     
+    /// Core nodes that declare compatibility with `workspace` (or none at all),
+    /// plus the nodes of every loaded plugin
+    pub fn for_workspace(workspace: &str) -> Self {
+        let mut registry = Self::default();
+        registry.retain_core_nodes(|metadata| {
+            metadata.workspace_compatibility.is_empty() || metadata.workspace_compatibility.contains(&workspace)
+        });
+        if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+            if let Err(e) = plugin_host.register_plugin_nodes(&mut registry) {
+                warn!("Failed to register plugin nodes for workspace {}: {}", workspace, e);
+            }
+        }
+        registry
+    }
+    
+    /// Drop the core nodes whose metadata does not satisfy `keep`
+    pub fn retain_core_nodes(&mut self, keep: impl Fn(&NodeMetadata) -> bool) {
+        let dropped: Vec<String> = self.metadata_providers.iter()
+            .filter(|(_, provider)| !keep(&provider()))
+            .map(|(node_type, _)| node_type.clone())
+            .collect();
+        for node_type in &dropped {
+            self.creators.remove(node_type);
+            self.metadata_providers.remove(node_type);
+        }
+        for node_types in self.categories.values_mut() {
+            node_types.retain(|node_type| !dropped.contains(node_type));
+        }
+        self.categories.retain(|_, node_types| !node_types.is_empty());
+    }
+    
     /// Register a node factory
     pub fn register<T: NodeFactory + 'static>(&mut self) {
         let metadata = T::metadata();
@@ -496,6 +721,14 @@ impl NodeRegistry {
             if let Some(metadata_provider) = self.metadata_providers.get(node_type) {
                 let metadata = metadata_provider();
                 node.set_panel_type(metadata.panel_type);
+                // Factories with their own create() still get the declared defaults
+                crate::nodes::parameter_schema::apply_defaults(&mut node, &metadata.parameters);
+                crate::nodes::parameter_schema::apply_port_defaults(&mut node, &metadata.inputs);
+                // Make room for the subtitle line under the title
+                if metadata.subtitle.is_some() {
+                    node.size.y += SUBTITLE_LINE_HEIGHT;
+                    node.update_port_positions();
+                }
             }
             
             return Some(node);
@@ -580,7 +813,7 @@ impl NodeRegistry {
                 nodle_plugin_sdk::PanelType::Combined => crate::nodes::interface::PanelType::Parameter, // Fallback
                 nodle_plugin_sdk::PanelType::Viewer => crate::nodes::interface::PanelType::Viewport, // Map to viewport
                 nodle_plugin_sdk::PanelType::Editor => crate::nodes::interface::PanelType::Parameter, // Map to parameter
-                nodle_plugin_sdk::PanelType::Inspector => crate::nodes::interface::PanelType::Parameter, // Map to parameter
+                nodle_plugin_sdk::PanelType::Inspector => crate::nodes::interface::PanelType::Inspector,
                 nodle_plugin_sdk::PanelType::Tree => crate::nodes::interface::PanelType::Parameter, // Map to parameter
                 nodle_plugin_sdk::PanelType::Spreadsheet => crate::nodes::interface::PanelType::Parameter, // Map to parameter
             };
@@ -625,16 +858,11 @@ impl NodeRegistry {
                 debug!("Node has viewport panel type - storing in global plugin manager");
                 println!("🔧 FACTORY: {} has viewport panel type - storing in global plugin manager", metadata_test.display_name);
                 
-                // For viewport nodes, store in global plugin manager for viewport rendering
-                if let Some(plugin_manager) = crate::workspace::get_global_plugin_manager() {
-                    if let Ok(mut manager) = plugin_manager.lock() {
-                        debug!("Storing viewport plugin node {} in global manager", node_id);
-                        println!("🔧 FACTORY: Storing viewport plugin node {} in global manager", node_id);
-                        manager.store_plugin_node_instance(node_id, plugin_node);
-                    } else {
-                        warn!("Failed to lock plugin manager for viewport node storage");
-                        println!("🔧 FACTORY: Failed to lock plugin manager for viewport node storage");
-                    }
+                // For viewport nodes, store in the global plugin host for viewport rendering
+                if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+                    debug!("Storing viewport plugin node {} in global manager", node_id);
+                    println!("🔧 FACTORY: Storing viewport plugin node {} in global manager", node_id);
+                    plugin_host.store_instance(node_id, plugin_node);
                 } else {
                     warn!("No global plugin manager available for viewport node storage");
                     println!("🔧 FACTORY: No global plugin manager available for viewport node storage");
@@ -700,7 +928,7 @@ impl NodeRegistry {
                     nodle_plugin_sdk::PanelType::Combined => crate::nodes::interface::PanelType::Parameter, // Fallback to Parameter
                     nodle_plugin_sdk::PanelType::Viewer => crate::nodes::interface::PanelType::Viewport, // Map to viewport
                     nodle_plugin_sdk::PanelType::Editor => crate::nodes::interface::PanelType::Parameter, // Map to parameter
-                    nodle_plugin_sdk::PanelType::Inspector => crate::nodes::interface::PanelType::Parameter, // Map to parameter
+                    nodle_plugin_sdk::PanelType::Inspector => crate::nodes::interface::PanelType::Inspector,
                     nodle_plugin_sdk::PanelType::Tree => crate::nodes::interface::PanelType::Parameter, // Map to parameter
                     nodle_plugin_sdk::PanelType::Spreadsheet => crate::nodes::interface::PanelType::Parameter, // Map to parameter
                 },
@@ -730,7 +958,7 @@ impl NodeRegistry {
                         nodle_plugin_sdk::DataType::USDScene => DataType::USDScene,
                         nodle_plugin_sdk::DataType::Any => DataType::Any,
                         // Map new rich types to appropriate core types
-                        nodle_plugin_sdk::DataType::Integer => DataType::Float, // Map integers to floats
+                        nodle_plugin_sdk::DataType::Integer => DataType::Integer,
                         nodle_plugin_sdk::DataType::Scene => DataType::USDScene, // Map scene to USD
                         nodle_plugin_sdk::DataType::Geometry => DataType::USDScene, // Map geometry to USD
                         nodle_plugin_sdk::DataType::Material => DataType::USDScene, // Map material to USD
@@ -741,6 +969,9 @@ impl NodeRegistry {
                     },
                     optional: p.optional,
                     description: p.description.clone(),
+                    unit: crate::nodes::units::Unit::None,
+                    variadic: false,
+                    default: None,
                 }).collect(),
                 outputs: plugin_meta.outputs.iter().map(|p| PortDefinition {
                     name: p.name.clone(),
@@ -753,7 +984,7 @@ impl NodeRegistry {
                         nodle_plugin_sdk::DataType::USDScene => DataType::USDScene,
                         nodle_plugin_sdk::DataType::Any => DataType::Any,
                         // Map new rich types to appropriate core types
-                        nodle_plugin_sdk::DataType::Integer => DataType::Float, // Map integers to floats
+                        nodle_plugin_sdk::DataType::Integer => DataType::Integer,
                         nodle_plugin_sdk::DataType::Scene => DataType::USDScene, // Map scene to USD
                         nodle_plugin_sdk::DataType::Geometry => DataType::USDScene, // Map geometry to USD
                         nodle_plugin_sdk::DataType::Material => DataType::USDScene, // Map material to USD
@@ -764,8 +995,12 @@ impl NodeRegistry {
                     },
                     optional: p.optional,
                     description: p.description.clone(),
+                    unit: crate::nodes::units::Unit::None,
+                    variadic: false,
+                    default: None,
                 }).collect(),
                 allow_multiple_connections: plugin_meta.allow_multiple_connections,
+                parameters: Vec::new(),
                 execution_mode: match plugin_meta.execution_mode {
                     nodle_plugin_sdk::ExecutionMode::Realtime => ExecutionMode::Realtime,
                     nodle_plugin_sdk::ExecutionMode::OnDemand => ExecutionMode::OnDemand,
@@ -782,6 +1017,7 @@ impl NodeRegistry {
                 requires_gpu: plugin_meta.requires_gpu,
                 is_workspace_node: plugin_meta.is_workspace_node,
                 supports_preview: plugin_meta.supports_preview,
+                subtitle: None,
             });
         }
         
@@ -837,7 +1073,7 @@ impl NodeRegistry {
                 nodle_plugin_sdk::PanelType::Combined => PanelType::Parameter, // Fallback to Parameter
                 nodle_plugin_sdk::PanelType::Viewer => PanelType::Viewport, // Map to viewport
                 nodle_plugin_sdk::PanelType::Editor => PanelType::Parameter, // Map to parameter
-                nodle_plugin_sdk::PanelType::Inspector => PanelType::Parameter, // Map to parameter
+                nodle_plugin_sdk::PanelType::Inspector => PanelType::Inspector,
                 nodle_plugin_sdk::PanelType::Tree => PanelType::Parameter, // Map to parameter
                 nodle_plugin_sdk::PanelType::Spreadsheet => PanelType::Parameter, // Map to parameter
             },
@@ -863,14 +1099,21 @@ impl NodeRegistry {
                 data_type: self.convert_plugin_data_type(&input.data_type),
                 optional: input.optional,
                 description: input.description.clone(),
+                unit: crate::nodes::units::Unit::None,
+                variadic: false,
+                default: None,
             }).collect(),
             outputs: plugin_meta.outputs.iter().map(|output| PortDefinition {
                 name: output.name.clone(),
                 data_type: self.convert_plugin_data_type(&output.data_type),
                 optional: output.optional,
                 description: output.description.clone(),
+                unit: crate::nodes::units::Unit::None,
+                variadic: false,
+                default: None,
             }).collect(),
             allow_multiple_connections: plugin_meta.allow_multiple_connections,
+            parameters: Vec::new(),
             
             // Execution behavior
             execution_mode: match plugin_meta.execution_mode {
@@ -891,6 +1134,7 @@ impl NodeRegistry {
             // Advanced properties
             is_workspace_node: plugin_meta.is_workspace_node,
             supports_preview: plugin_meta.supports_preview,
+            subtitle: None,
         }
     }
     
@@ -905,7 +1149,7 @@ impl NodeRegistry {
             nodle_plugin_sdk::DataType::USDScene => DataType::USDScene,
             nodle_plugin_sdk::DataType::Any => DataType::Any,
             // Map new rich types to appropriate core types
-            nodle_plugin_sdk::DataType::Integer => DataType::Float, // Map integers to floats
+            nodle_plugin_sdk::DataType::Integer => DataType::Integer,
             nodle_plugin_sdk::DataType::Scene => DataType::USDScene, // Map scene to USD
             nodle_plugin_sdk::DataType::Geometry => DataType::USDScene, // Map geometry to USD
             nodle_plugin_sdk::DataType::Material => DataType::USDScene, // Map material to USD
@@ -948,6 +1192,93 @@ impl NodeRegistry {
         self.get_node_metadata(node_type)
     }
     
+    /// Every registered node, core and plugin, sorted by display name
+    pub fn registered_nodes(&self) -> Vec<RegisteredNode> {
+        let mut nodes: Vec<RegisteredNode> = self.metadata_providers.iter()
+            .map(|(node_type, provider)| {
+                let metadata = provider();
+                RegisteredNode {
+                    node_type: node_type.clone(),
+                    display_name: metadata.display_name.to_string(),
+                    description: metadata.description.to_string(),
+                    category: metadata.category.path().to_vec(),
+                    tags: metadata.tags.iter().map(|tag| tag.to_string()).collect(),
+                    workspace_compatibility: metadata.workspace_compatibility.iter().map(|w| w.to_string()).collect(),
+                    inputs: metadata.inputs.iter().map(|port| port.data_type.clone()).collect(),
+                    outputs: metadata.outputs.iter().map(|port| port.data_type.clone()).collect(),
+                    variadic: metadata.inputs.last().is_some_and(|port| port.variadic),
+                    is_plugin: false,
+                }
+            })
+            .collect();
+        
+        // Plugin nodes are described by their cached metadata, so plugin code is not called
+        for node_type in self.plugin_factories.keys() {
+            let Some(metadata) = self.plugin_metadata_cache.get(node_type) else {
+                error!("Missing cached metadata for plugin node type: {}", node_type);
+                continue;
+            };
+            nodes.push(RegisteredNode {
+                node_type: node_type.clone(),
+                display_name: metadata.display_name.clone(),
+                description: metadata.description.clone(),
+                category: metadata.category.path().to_vec(),
+                tags: metadata.tags.clone(),
+                workspace_compatibility: metadata.workspace_compatibility.clone(),
+                inputs: metadata.inputs.iter().map(|port| self.convert_plugin_data_type(&port.data_type)).collect(),
+                outputs: metadata.outputs.iter().map(|port| self.convert_plugin_data_type(&port.data_type)).collect(),
+                variadic: false,
+                is_plugin: true,
+            });
+        }
+        
+        nodes.sort_by(|a, b| a.display_name.cmp(&b.display_name).then_with(|| a.node_type.cmp(&b.node_type)));
+        nodes
+    }
+    
+    /// Nodes whose category is `path` or nested under it
+    pub fn nodes_under_category(&self, path: &[&str]) -> Vec<RegisteredNode> {
+        self.registered_nodes().into_iter()
+            .filter(|node| node.category.len() >= path.len() && node.category.iter().zip(path).all(|(a, b)| a == b))
+            .collect()
+    }
+    
+    /// Nodes tagged with `tag` (case-insensitive)
+    pub fn nodes_with_tag(&self, tag: &str) -> Vec<RegisteredNode> {
+        self.registered_nodes().into_iter()
+            .filter(|node| node.tags.iter().any(|node_tag| node_tag.eq_ignore_ascii_case(tag)))
+            .collect()
+    }
+    
+    /// Nodes with an input that `data_type` can be connected to
+    pub fn nodes_accepting(&self, data_type: &DataType) -> Vec<RegisteredNode> {
+        self.registered_nodes().into_iter()
+            .filter(|node| node.inputs.iter().any(|input| data_type.can_connect_to(input)))
+            .collect()
+    }
+    
+    /// Nodes with an output that can be connected to an input of `data_type`
+    pub fn nodes_producing(&self, data_type: &DataType) -> Vec<RegisteredNode> {
+        self.registered_nodes().into_iter()
+            .filter(|node| node.outputs.iter().any(|output| output.can_connect_to(data_type)))
+            .collect()
+    }
+    
+    /// Nodes compatible with `workspace_filter` that match `query`, best matches first
+    pub fn search(&self, query: &str, workspace_filter: &[&str]) -> Vec<RegisteredNode> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut ranked: Vec<(u8, RegisteredNode)> = self.registered_nodes().into_iter()
+            .filter(|node| node.is_compatible_with(workspace_filter))
+            .filter_map(|node| node.search_rank(&query).map(|rank| (rank, node)))
+            .collect();
+        // Stable sort keeps nodes of equal rank in display name order
+        ranked.sort_by_key(|(rank, _)| *rank);
+        ranked.into_iter().map(|(_, node)| node).collect()
+    }
+    
     /// Generate menu structure from registered node categories
     pub fn generate_menu_structure(&self, workspace_filter: &[&str]) -> Vec<crate::workspace::WorkspaceMenuItem> {
         use crate::workspace::WorkspaceMenuItem;
@@ -956,93 +1287,30 @@ impl NodeRegistry {
         // Group nodes by their category paths - use BTreeMap for deterministic ordering
         let mut category_groups: BTreeMap<Vec<String>, Vec<(String, String)>> = BTreeMap::new();
         
-        // Process core nodes
-        for (node_type, provider) in &self.metadata_providers {
-            let metadata = provider();
-            
-            // NODE-CENTRIC: Only include nodes that declare compatibility with this workspace
-            let is_compatible = if workspace_filter.is_empty() {
-                // If no workspace filter, include all nodes
-                true
-            } else if metadata.workspace_compatibility.is_empty() {
-                // If node declares no specific compatibility, include in all workspaces (legacy behavior)
-                true
-            } else {
-                // Check if node explicitly declares compatibility with this workspace
-                workspace_filter.iter().any(|workspace| 
-                    metadata.workspace_compatibility.iter().any(|compat| compat == workspace)
-                )
-            };
+        // NODE-CENTRIC: Only include nodes that declare compatibility with this workspace
+        for node in self.registered_nodes() {
+            if !node.is_compatible_with(workspace_filter) {
+                continue;
+            }
+            let category_path = node.category;
             
-            if is_compatible {
-                let category_path = metadata.category.path();
-                
-                // Skip the first segment if it matches the workspace (e.g., "3D")
-                let menu_path = if !workspace_filter.is_empty() && 
-                                 !category_path.is_empty() && 
-                                 workspace_filter.contains(&category_path[0].as_str()) {
-                    if category_path.len() > 1 {
-                        category_path[1..].to_vec()
-                    } else {
-                        vec!["General".to_string()]
-                    }
+            // Skip the first segment if it matches the workspace (e.g., "3D")
+            let menu_path = if !workspace_filter.is_empty() && 
+                             !category_path.is_empty() && 
+                             workspace_filter.contains(&category_path[0].as_str()) {
+                if category_path.len() > 1 {
+                    category_path[1..].to_vec()
                 } else {
-                    category_path.to_vec()
-                };
-                
-                category_groups
-                    .entry(menu_path)
-                    .or_insert_with(Vec::new)
-                    .push((metadata.display_name.to_string(), node_type.clone()));
-            }
-        }
-        
-        // Process plugin nodes
-        for (node_type, _factory) in &self.plugin_factories {
-            // Use cached metadata instead of calling factory.metadata() repeatedly
-            let metadata = match self.plugin_metadata_cache.get(node_type) {
-                Some(cached_metadata) => cached_metadata,
-                None => {
-                    error!("Missing cached metadata for plugin node type: {}", node_type);
-                    continue;
+                    vec!["General".to_string()]
                 }
-            };
-            
-            // NODE-CENTRIC: Only include nodes that declare compatibility with this workspace
-            let is_compatible = if workspace_filter.is_empty() {
-                // If no workspace filter, include all nodes
-                true
-            } else if metadata.workspace_compatibility.is_empty() {
-                // If node declares no specific compatibility, include in all workspaces (legacy behavior)
-                true
             } else {
-                // Check if node explicitly declares compatibility with this workspace
-                workspace_filter.iter().any(|workspace| 
-                    metadata.workspace_compatibility.iter().any(|compat| compat == workspace)
-                )
+                category_path
             };
             
-            if is_compatible {
-                let category_path = metadata.category.path();
-                
-                // Skip the first segment if it matches the workspace (e.g., "3D")
-                let menu_path = if !workspace_filter.is_empty() && 
-                                 !category_path.is_empty() && 
-                                 workspace_filter.contains(&category_path[0].as_str()) {
-                    if category_path.len() > 1 {
-                        category_path[1..].to_vec()
-                    } else {
-                        vec!["General".to_string()]
-                    }
-                } else {
-                    category_path.to_vec()
-                };
-                
-                category_groups
-                    .entry(menu_path)
-                    .or_insert_with(Vec::new)
-                    .push((metadata.display_name.clone(), node_type.clone()));
-            }
+            category_groups
+                .entry(menu_path)
+                .or_insert_with(Vec::new)
+                .push((node.display_name, node.node_type));
         }
         
         // Convert category groups to hierarchical menu items
@@ -1165,22 +1433,61 @@ impl Default for NodeRegistry {
         registry.register::<crate::nodes::math::subtract::SubtractNodeFactory>();
         registry.register::<crate::nodes::math::multiply::MultiplyNodeFactory>();
         registry.register::<crate::nodes::math::divide::DivideNodeFactory>();
+        registry.register::<crate::nodes::math::integer_constant::IntegerConstantNodeFactory>();
+        registry.register::<crate::nodes::math::integer_arithmetic::IntegerArithmeticNodeFactory>();
+        registry.register::<crate::nodes::math::modulo::ModuloNodeFactory>();
+        registry.register::<crate::nodes::math::bitwise::BitwiseNodeFactory>();
+        registry.register::<crate::nodes::math::convert_units::ConvertUnitsNodeFactory>();
+        registry.register::<crate::nodes::math::clamp::ClampNodeFactory>();
+        registry.register::<crate::nodes::math::remap::RemapNodeFactory>();
+        registry.register::<crate::nodes::math::lerp::LerpNodeFactory>();
+        registry.register::<crate::nodes::math::smoothstep::SmoothstepNodeFactory>();
+        registry.register::<crate::nodes::math::fit::FitNodeFactory>();
+        registry.register::<crate::nodes::math::function::FunctionNodeFactory>();
         
         // Register modular logic nodes
         registry.register::<crate::nodes::logic::and::AndNodeFactory>();
         registry.register::<crate::nodes::logic::or::OrNodeFactory>();
         registry.register::<crate::nodes::logic::not::NotNodeFactory>();
+        registry.register::<crate::nodes::logic::xor::XorNodeFactory>();
+        registry.register::<crate::nodes::logic::nand::NandNodeFactory>();
+        registry.register::<crate::nodes::logic::compare::CompareNodeFactory>();
+        registry.register::<crate::nodes::logic::in_range::InRangeNodeFactory>();
+        registry.register::<crate::nodes::logic::bool_to_float::BoolToFloatNodeFactory>();
+        registry.register::<crate::nodes::logic::counter::CounterNodeFactory>();
+        registry.register::<crate::nodes::logic::accumulate::AccumulateNodeFactory>();
+        registry.register::<crate::nodes::logic::hold::HoldNodeFactory>();
+        registry.register::<crate::nodes::logic::feedback::FeedbackNodeFactory>();
+        registry.register::<crate::nodes::logic::timer::TimerNodeFactory>();
+        registry.register::<crate::nodes::logic::trigger::TriggerNodeFactory>();
+        registry.register::<crate::nodes::logic::branch::BranchNodeFactory>();
         
         // Register modular data nodes
         registry.register::<crate::nodes::data::constant::ConstantNodeFactory>();
         registry.register::<crate::nodes::data::variable::VariableNodeFactory>();
         registry.register::<crate::nodes::data::usd_file_reader::UsdFileReaderNodeFactory>();
+        registry.register::<crate::nodes::data::read_csv::ReadCsvNodeFactory>();
+        registry.register::<crate::nodes::data::read_json::ReadJsonNodeFactory>();
+        registry.register::<crate::nodes::data::read_audio::ReadAudioNodeFactory>();
+        registry.register::<crate::nodes::data::audio_analysis::AudioAnalysisNodeFactory>();
+        registry.register::<crate::nodes::list::build_list::BuildListNode>();
+        registry.register::<crate::nodes::list::get_item::GetItemNode>();
+        registry.register::<crate::nodes::list::length::LengthNode>();
+        registry.register::<crate::nodes::list::slice::SliceNode>();
+        registry.register::<crate::nodes::list::sort::SortNode>();
+        registry.register::<crate::nodes::list::filter::FilterNode>();
+        registry.register::<crate::nodes::map::compose::ComposeNode>();
+        registry.register::<crate::nodes::map::get_key::GetKeyNode>();
+        registry.register::<crate::nodes::map::merge::MergeNode>();
+        registry.register::<crate::nodes::map::keys::KeysNode>();
         
         // Register modular output nodes
         registry.register::<crate::nodes::output::PrintNodeFactory>();
         registry.register::<crate::nodes::output::DebugNodeFactory>();
         registry.register::<crate::nodes::output::ConsoleNodeFactory>();
-        // ScenegraphNodeFactory is now only registered in 3D workspace
+        registry.register::<crate::nodes::output::SpreadsheetNodeFactory>();
+        registry.register::<crate::nodes::output::TreeViewNodeFactory>();
+        registry.register::<crate::nodes::output::ProbeNodeFactory>();
         
         // Register 3D nodes and their interface versions
         registry.register::<crate::nodes::three_d::transform::TranslateNode>();
@@ -1193,13 +1500,155 @@ impl Default for NodeRegistry {
         registry.register::<crate::nodes::three_d::geometry::ConeNodeFactory>();
         registry.register::<crate::nodes::three_d::geometry::PlaneNodeFactory>();
         registry.register::<crate::nodes::three_d::geometry::CapsuleNodeFactory>();
+        registry.register::<crate::nodes::three_d::geometry::TorusNodeFactory>();
+        registry.register::<crate::nodes::three_d::geometry::DiskNodeFactory>();
+        registry.register::<crate::nodes::three_d::geometry::TubeNodeFactory>();
         registry.register::<crate::nodes::three_d::lighting::PointLightNode>();
         registry.register::<crate::nodes::three_d::lighting::DirectionalLightNode>();
         registry.register::<crate::nodes::three_d::lighting::SpotLightNode>();
+        registry.register::<crate::nodes::three_d::lighting::DomeLightNode>();
+        registry.register::<crate::nodes::three_d::lighting::RectLightNode>();
+        registry.register::<crate::nodes::three_d::input::ReadGltfNode>();
+        registry.register::<crate::nodes::three_d::input::ReadMeshNode>();
+        registry.register::<crate::nodes::three_d::input::ReadPointsNode>();
+        registry.register::<crate::nodes::three_d::input::ReadVdbNode>();
+        registry.register::<crate::nodes::three_d::modify::ReverseNode>();
+        registry.register::<crate::nodes::three_d::modify::TransformNode>();
+        registry.register::<crate::nodes::three_d::modify::PruneNode>();
+        registry.register::<crate::nodes::three_d::modify::VariantSelectNode>();
+        registry.register::<crate::nodes::three_d::modify::LayerStackNode>();
+        registry.register::<crate::nodes::three_d::modify::ScatterNode>();
+        registry.register::<crate::nodes::three_d::modify::AttributeCreateNode>();
+        registry.register::<crate::nodes::three_d::modify::BooleanNode>();
+        registry.register::<crate::nodes::three_d::modify::NormalsNode>();
+        registry.register::<crate::nodes::three_d::modify::DecimateNode>();
+        registry.register::<crate::nodes::three_d::modify::DecimatePointsNode>();
         registry.register::<crate::nodes::three_d::ui::viewport::ViewportNode>();
+        registry.register::<crate::nodes::three_d::ui::scenegraph::ScenegraphNodeFactory>();
+        registry.register::<crate::nodes::three_d::ui::attributes::AttributesNodeFactory>();
+        registry.register::<crate::nodes::three_d::output::RenderNodeFactory>();
+        registry.register::<crate::nodes::three_d::output::WriteUSDNode>();
+        
+        // Register utility nodes
+        registry.register::<crate::nodes::utility::NullNode>();
+        registry.register::<crate::nodes::utility::TestNode>();
+        registry.register::<crate::nodes::utility::compute_shader::ComputeShaderNode>();
+        registry.register::<crate::nodes::utility::time::TimeNode>();
+        registry.register::<crate::nodes::utility::random::RandomNode>();
         
         // USD nodes now loaded via comprehensive USD plugin
         
         registry
     }
-}
\ No newline at end of file
+}
+
+/// Metadata of every core node type and of the node types of the loaded
+/// plugins, for lookups that only read it: port types, parameter and input
+/// schemas, node styles. Shared by the whole editor and rebuilt whenever the
+/// loaded plugins change; plugin factories are not kept, so holding a catalog
+/// never keeps plugin code alive.
+pub struct NodeCatalog {
+    /// Plugin snapshot generation the catalog describes, None before plugins are initialized
+    plugin_generation: Option<u64>,
+    metadata: HashMap<String, NodeMetadata>,
+    nodes: Vec<RegisteredNode>,
+    /// Index into `nodes` by node type
+    node_indices: HashMap<String, usize>,
+}
+
+static NODE_CATALOG: LazyLock<RwLock<Arc<NodeCatalog>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(NodeCatalog::build(None))));
+
+impl NodeCatalog {
+    /// The catalog for the plugins loaded now
+    pub fn current() -> Arc<NodeCatalog> {
+        let plugin_generation = crate::workspace::get_plugin_host().map(|host| host.snapshot().generation);
+        let catalog = match NODE_CATALOG.read() {
+            Ok(catalog) => catalog.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        if catalog.plugin_generation == plugin_generation {
+            return catalog;
+        }
+        let catalog = Arc::new(NodeCatalog::build(plugin_generation));
+        match NODE_CATALOG.write() {
+            Ok(mut current) => *current = catalog.clone(),
+            Err(poisoned) => *poisoned.into_inner() = catalog.clone(),
+        }
+        catalog
+    }
+
+    fn build(plugin_generation: Option<u64>) -> Self {
+        let mut registry = NodeRegistry::default();
+        if plugin_generation.is_some() {
+            if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+                if let Err(e) = plugin_host.register_plugin_nodes(&mut registry) {
+                    warn!("Failed to register plugin nodes for the node catalog: {}", e);
+                }
+            }
+        }
+        let nodes = registry.registered_nodes();
+        let metadata = nodes.iter()
+            .filter_map(|node| Some((node.node_type.clone(), registry.get_metadata(&node.node_type)?)))
+            .collect();
+        let node_indices = nodes.iter().enumerate().map(|(index, node)| (node.node_type.clone(), index)).collect();
+        Self { plugin_generation, metadata, nodes, node_indices }
+    }
+
+    /// Metadata of `node_type`, a core or plugin node type
+    pub fn metadata(&self, node_type: &str) -> Option<&NodeMetadata> {
+        self.metadata.get(node_type)
+    }
+
+    /// Every node type, sorted by display name
+    pub fn nodes(&self) -> &[RegisteredNode] {
+        &self.nodes
+    }
+
+    /// Menu and port description of `node_type`
+    pub fn node(&self, node_type: &str) -> Option<&RegisteredNode> {
+        self.node_indices.get(node_type).map(|&index| &self.nodes[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node_types(nodes: &[RegisteredNode]) -> Vec<&str> {
+        nodes.iter().map(|node| node.node_type.as_str()).collect()
+    }
+
+    #[test]
+    fn test_workspace_registries_and_queries_come_from_node_metadata() {
+        let registry = NodeRegistry::for_workspace("3D");
+        assert!(registry.get_metadata("3D_Cube").is_some());
+        assert!(registry.get_metadata("Viewport").is_some());
+        // Logic nodes declare other workspaces only
+        assert!(registry.get_metadata("Logic_And").is_none());
+        assert!(registry.nodes_in_category(&NodeCategory::new(&["Logic", "Boolean"])).is_empty());
+
+        let all = NodeRegistry::default();
+        assert_eq!(all.search("cub", &["3D"]).first().map(|node| node.node_type.as_str()), Some("3D_Cube"));
+        assert!(all.search("cube", &["Logic"]).is_empty());
+        assert!(all.search("  ", &[]).is_empty());
+
+        assert!(node_types(&all.nodes_with_tag("Primitive")).contains(&"3D_Cube"));
+        assert!(node_types(&all.nodes_under_category(&["3D"])).contains(&"3D_Cube"));
+        let scene_consumers = all.nodes_accepting(&DataType::USDScene);
+        assert!(node_types(&scene_consumers).contains(&"Viewport"));
+        assert!(!node_types(&scene_consumers).contains(&"Logic_And"));
+        assert!(node_types(&all.nodes_producing(&DataType::USDScene)).contains(&"3D_Cube"));
+    }
+
+    #[test]
+    fn test_catalog_describes_every_registered_node() {
+        let catalog = NodeCatalog::current();
+        let registry = NodeRegistry::default();
+        assert_eq!(catalog.nodes().len(), registry.registered_nodes().len());
+        assert_eq!(catalog.metadata("3D_Cube").map(|metadata| metadata.parameters.len()),
+            registry.get_metadata("3D_Cube").map(|metadata| metadata.parameters.len()));
+        assert!(catalog.metadata("Unknown").is_none());
+        assert!(Arc::ptr_eq(&catalog, &NodeCatalog::current()));
+    }
+}
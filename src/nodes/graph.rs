@@ -2,7 +2,10 @@
 
 use super::node::{Node, NodeId};
 use super::port::PortId;
-use std::collections::HashMap;
+use super::adapters::Adapter;
+use super::annotation::Annotation;
+use super::wire_style::WireStyle;
+use std::collections::{BTreeMap, HashMap};
 use serde::{Deserialize, Serialize};
 
 /// Represents a connection between two ports on different nodes
@@ -12,6 +15,19 @@ pub struct Connection {
     pub from_port: PortId,
     pub to_node: NodeId,
     pub to_port: PortId,
+    /// Text drawn at the middle of the wire; the output's name for named wires
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub label: String,
+    /// Drawn as a labelled stub at each end instead of a wire across the canvas
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub named: bool,
+    /// Converts values between mismatched port types, shown as a badge on the wire
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adapter: Option<Adapter>,
+}
+
+fn is_false(flag: &bool) -> bool {
+    !*flag
 }
 
 impl Connection {
@@ -22,6 +38,9 @@ impl Connection {
             from_port,
             to_node,
             to_port,
+            label: String::new(),
+            named: false,
+            adapter: None,
         }
     }
 }
@@ -32,6 +51,26 @@ pub struct NodeGraph {
     pub nodes: HashMap<NodeId, Node>,
     pub connections: Vec<Connection>,
     next_node_id: NodeId,
+    /// Review notes drawn over this graph (absent in older files)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    /// Project-wide random seed (see [`crate::nodes::seed`]); only the root graph's is used
+    #[serde(default, skip_serializing_if = "is_default_seed")]
+    pub seed: u64,
+    /// Named groups of nodes to select again later (absent in older files)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub selection_sets: BTreeMap<String, Vec<NodeId>>,
+    /// How wires are routed on the canvas; only the root graph's is used
+    #[serde(default, skip_serializing_if = "is_default_wire_style")]
+    pub wire_style: WireStyle,
+}
+
+fn is_default_seed(seed: &u64) -> bool {
+    *seed == crate::nodes::seed::DEFAULT_SEED
+}
+
+fn is_default_wire_style(style: &WireStyle) -> bool {
+    *style == WireStyle::default()
 }
 
 impl NodeGraph {
@@ -41,6 +80,10 @@ impl NodeGraph {
             nodes: HashMap::new(),
             connections: Vec::new(),
             next_node_id: 0,
+            annotations: Vec::new(),
+            seed: crate::nodes::seed::DEFAULT_SEED,
+            selection_sets: BTreeMap::new(),
+            wire_style: WireStyle::default(),
         }
     }
 
@@ -67,8 +110,18 @@ impl NodeGraph {
     /// Removes a node and all its connections
     pub fn remove_node(&mut self, node_id: NodeId) -> Option<Node> {
         // Remove all connections to/from this node
+        let fed: Vec<NodeId> = self.connections.iter()
+            .filter(|conn| conn.from_node == node_id)
+            .map(|conn| conn.to_node)
+            .collect();
         self.connections
             .retain(|conn| conn.from_node != node_id && conn.to_node != node_id);
+        for fed_node in fed {
+            self.sync_variadic_inputs(fed_node);
+        }
+        for nodes in self.selection_sets.values_mut() {
+            nodes.retain(|id| *id != node_id);
+        }
         
         // Remove the node
         self.nodes.remove(&node_id)
@@ -91,7 +144,9 @@ impl NodeGraph {
 
         // Note: Port validation is handled at the UI level for better user experience
 
+        let to_node = connection.to_node;
         self.connections.push(connection);
+        self.sync_variadic_inputs(to_node);
         Ok(())
     }
     
@@ -104,12 +159,99 @@ impl NodeGraph {
     /// Removes a connection by index
     pub fn remove_connection(&mut self, index: usize) -> Option<Connection> {
         if index < self.connections.len() {
-            Some(self.connections.remove(index))
+            let connection = self.connections.remove(index);
+            self.sync_variadic_inputs(connection.to_node);
+            Some(connection)
         } else {
             None
         }
     }
 
+    /// Grow or trim a node's variadic inputs to match its connections (see
+    /// [`Node::sync_variadic_inputs`]); returns whether they changed
+    pub fn sync_variadic_inputs(&mut self, node_id: NodeId) -> bool {
+        let connections = &self.connections;
+        self.nodes.get_mut(&node_id).is_some_and(|node| node.sync_variadic_inputs(|port| {
+            connections.iter().any(|connection| connection.to_node == node_id && connection.to_port == port)
+        }))
+    }
+
+    /// `name`, or `name` with its trailing number bumped (`Sphere` → `Sphere2`,
+    /// `Sphere2` → `Sphere3`) until no node other than `except` has that title
+    pub fn unique_title(&self, name: &str, except: Option<NodeId>) -> String {
+        let taken = |title: &str| self.nodes.values().any(|node| Some(node.id) != except && node.title == title);
+        if !taken(name) {
+            return name.to_string();
+        }
+        let base = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let mut number = name[base.len()..].parse::<u64>().map_or(2, |number| number + 1);
+        loop {
+            let candidate = format!("{}{}", base, number);
+            if !taken(&candidate) {
+                return candidate;
+            }
+            number += 1;
+        }
+    }
+
+    /// Give a node a new title, made unique within this graph, and point the
+    /// `ch()` references of other nodes' expressions at it. Returns the title
+    /// given, or None when the node is missing or the name is blank.
+    pub fn rename_node(&mut self, node_id: NodeId, name: &str) -> Option<String> {
+        // Quotes would end the reference inside ch("...")
+        let name = name.replace('"', "");
+        let name = name.trim();
+        let old_title = self.nodes.get(&node_id)?.title.clone();
+        if name.is_empty() {
+            return None;
+        }
+        let title = self.unique_title(name, Some(node_id));
+        if title == old_title {
+            return Some(title);
+        }
+        // Only references that resolved to this node follow it
+        let references_node = super::expressions::find_node(self, &old_title).is_some_and(|node| node.id == node_id);
+        if references_node {
+            for node in self.nodes.values_mut() {
+                for expression in node.expressions.values_mut() {
+                    *expression = super::expressions::rename_references(expression, &old_title, &title);
+                }
+            }
+        }
+        self.nodes.get_mut(&node_id)?.title = title.clone();
+        Some(title)
+    }
+
+    /// Outputs that can be picked up by name: the label and source of every
+    /// labelled connection, sorted by label. A label used on several outputs
+    /// is listed once per output.
+    pub fn named_outputs(&self) -> Vec<(String, NodeId, PortId)> {
+        let mut outputs: Vec<(String, NodeId, PortId)> = self.connections.iter()
+            .filter(|connection| !connection.label.is_empty())
+            .map(|connection| (connection.label.clone(), connection.from_node, connection.from_port))
+            .collect();
+        outputs.sort();
+        outputs.dedup();
+        outputs
+    }
+
+    /// Node feeding the lowest connected input of `node_id`
+    pub fn first_upstream(&self, node_id: NodeId) -> Option<NodeId> {
+        self.connections.iter()
+            .filter(|connection| connection.to_node == node_id)
+            .min_by_key(|connection| connection.to_port)
+            .map(|connection| connection.from_node)
+    }
+
+    /// Node fed by the lowest connected output of `node_id`; of several, the leftmost
+    pub fn first_downstream(&self, node_id: NodeId) -> Option<NodeId> {
+        let x = |id: NodeId| self.nodes.get(&id).map_or(0.0, |node| node.position.x);
+        self.connections.iter()
+            .filter(|connection| connection.from_node == node_id)
+            .min_by(|a, b| a.from_port.cmp(&b.from_port).then_with(|| x(a.to_node).total_cmp(&x(b.to_node))))
+            .map(|connection| connection.to_node)
+    }
+
     /// Updates port positions for all nodes
     pub fn update_all_port_positions(&mut self) {
         for node in self.nodes.values_mut() {
@@ -122,4 +264,114 @@ impl Default for NodeGraph {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::port::next_variadic_name;
+    use egui::Pos2;
+
+    #[test]
+    fn test_renamed_nodes_stay_unique_and_keep_their_references() {
+        let mut graph = NodeGraph::new();
+        let sphere = graph.add_node(Node::new(0, "Sphere", Pos2::ZERO));
+        let other = graph.add_node(Node::new(0, "Sphere2", Pos2::ZERO));
+        let mut cube = Node::new(0, "Cube", Pos2::ZERO);
+        cube.expressions.insert("size".to_string(), r#"ch("Sphere/radius") + ch( "Sphere2/radius")"#.to_string());
+        let cube = graph.add_node(cube);
+
+        assert_eq!(graph.unique_title("Sphere", None), "Sphere3");
+        assert_eq!(graph.unique_title("Sphere", Some(sphere)), "Sphere");
+        // Taking a used name keeps the node's own numbered title
+        assert_eq!(graph.rename_node(other, "Sphere").as_deref(), Some("Sphere2"));
+        assert_eq!(graph.rename_node(sphere, " \"Ball\" ").as_deref(), Some("Ball"));
+        assert_eq!(graph.rename_node(sphere, "  "), None);
+        assert_eq!(graph.rename_node(other, "Ball7").as_deref(), Some("Ball7"));
+        assert_eq!(graph.unique_title("Ball7", None), "Ball8");
+        assert_eq!(graph.nodes[&cube].expressions["size"], r#"ch("Ball/radius") + ch( "Ball7/radius")"#);
+    }
+
+    #[test]
+    fn test_node_tags_and_notes_are_saved_only_when_set() {
+        let mut graph = NodeGraph::new();
+        let plain = graph.add_node(Node::new(0, "Plain", Pos2::ZERO));
+        let mut tagged = Node::new(0, "Tagged", Pos2::ZERO);
+        tagged.tag_color = Some(egui::Color32::from_rgb(150, 60, 60));
+        tagged.tags = vec!["review".to_string()];
+        tagged.notes = "Check the scale".to_string();
+        let tagged = graph.add_node(tagged);
+
+        let json = serde_json::to_value(&graph).unwrap();
+        assert!(json["nodes"][plain.to_string()].get("notes").is_none());
+        let loaded: NodeGraph = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.nodes[&tagged].tag_color, Some(egui::Color32::from_rgb(150, 60, 60)));
+        assert_eq!(loaded.nodes[&tagged].tags, vec!["review".to_string()]);
+        assert_eq!(loaded.nodes[&tagged].notes, "Check the scale");
+        assert_eq!(loaded.nodes[&plain].tag_color, None);
+    }
+
+    #[test]
+    fn test_first_neighbors_follow_the_lowest_port() {
+        let mut graph = NodeGraph::new();
+        let a = graph.add_node(Node::new(0, "A", Pos2::new(0.0, 0.0)));
+        let b = graph.add_node(Node::new(0, "B", Pos2::new(200.0, 0.0)));
+        let c = graph.add_node(Node::new(0, "C", Pos2::new(100.0, 100.0)));
+        let d = graph.add_node(Node::new(0, "D", Pos2::new(-50.0, 100.0)));
+        graph.connections.push(Connection::new(b, 0, c, 1));
+        graph.connections.push(Connection::new(a, 0, c, 0));
+        graph.connections.push(Connection::new(a, 0, d, 0));
+
+        assert_eq!(graph.first_upstream(c), Some(a));
+        assert_eq!(graph.first_upstream(a), None);
+        // Both targets hang off output 0, so the leftmost wins
+        assert_eq!(graph.first_downstream(a), Some(d));
+        assert_eq!(graph.first_downstream(b), Some(c));
+        assert_eq!(graph.first_downstream(c), None);
+    }
+
+    #[test]
+    fn test_labelled_outputs_are_listed_once_and_labels_are_saved() {
+        let mut graph = NodeGraph::new();
+        let source = graph.add_node(Node::new(0, "Source", Pos2::ZERO));
+        let a = graph.add_node(Node::new(0, "A", Pos2::ZERO));
+        let b = graph.add_node(Node::new(0, "B", Pos2::ZERO));
+        graph.connections.push(Connection { label: "mask".to_string(), ..Connection::new(source, 0, a, 0) });
+        graph.connections.push(Connection { label: "mask".to_string(), named: true, ..Connection::new(source, 0, b, 0) });
+        graph.connections.push(Connection::new(source, 1, b, 1));
+
+        assert_eq!(graph.named_outputs(), vec![("mask".to_string(), source, 0)]);
+
+        let json = serde_json::to_value(&graph).unwrap();
+        assert!(json["connections"][2].get("label").is_none());
+        assert!(json["connections"][2].get("named").is_none());
+        let loaded: NodeGraph = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.connections, graph.connections);
+    }
+
+    #[test]
+    fn test_variadic_inputs_grow_as_connected_and_trim_when_freed() {
+        assert_eq!(next_variadic_name("Item 9"), "Item 10");
+        assert_eq!(next_variadic_name("B"), "C");
+        assert_eq!(next_variadic_name("Z"), "Z 2");
+
+        let mut graph = NodeGraph::new();
+        let source = graph.add_node(Node::new(0, "Source", Pos2::ZERO));
+        let mut list = Node::new(0, "Build List", Pos2::ZERO);
+        list.add_input("List").add_input("Item 1");
+        list.inputs[1].variadic = true;
+        let list = graph.add_node(list);
+        let names = |graph: &NodeGraph| graph.nodes[&list].inputs.iter().map(|port| port.name.clone()).collect::<Vec<_>>();
+
+        graph.add_connection_by_ids(source, 0, list, 1).unwrap();
+        graph.add_connection_by_ids(source, 0, list, 2).unwrap();
+        assert_eq!(names(&graph), ["List", "Item 1", "Item 2", "Item 3"]);
+        assert!(graph.nodes[&list].inputs[3].variadic);
+
+        // Freeing Item 2 drops the spare Item 3 but keeps Item 2 to connect again
+        graph.remove_connection(1);
+        assert_eq!(names(&graph), ["List", "Item 1", "Item 2"]);
+        graph.remove_node(source);
+        assert_eq!(names(&graph), ["List", "Item 1"]);
+    }
+}
@@ -0,0 +1,431 @@
+//! Structural diff and three-way merge of node graphs
+//!
+//! Nodes are matched by id, which is stable across saves, so two revisions of
+//! a project file from version control can be compared directly. A node whose
+//! type changed under the same id is treated as removed and re-added.
+
+use egui::{Pos2, Rect};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use super::graph::{Connection, NodeGraph};
+use super::node::{Node, NodeId};
+
+/// Node moves smaller than this (canvas units) are not reported
+const POSITION_TOLERANCE: f32 = 0.5;
+
+/// Summary of a node that exists on only one side of a diff
+#[derive(Debug, Clone)]
+pub struct DiffNode {
+    pub id: NodeId,
+    pub title: String,
+    pub type_id: String,
+    /// Node rectangle in canvas coordinates, for drawing removed nodes as ghosts
+    pub rect: Rect,
+}
+
+impl DiffNode {
+    fn from_node(node: &Node) -> Self {
+        Self {
+            id: node.id,
+            title: node.title.clone(),
+            type_id: node.type_id.clone(),
+            rect: node.get_rect(),
+        }
+    }
+}
+
+/// A node present on both sides whose contents differ
+#[derive(Debug, Clone)]
+pub struct NodeChange {
+    pub id: NodeId,
+    pub title: String,
+    /// What changed: "title", "position", "visible", "subgraph" or "parameter <name>"
+    pub fields: Vec<String>,
+}
+
+/// A connection present on only one side, with a readable label
+#[derive(Debug, Clone)]
+pub struct ConnectionChange {
+    pub connection: Connection,
+    pub label: String,
+}
+
+/// Differences between an old and a new revision of a graph
+#[derive(Debug, Clone, Default)]
+pub struct GraphDiff {
+    pub added_nodes: Vec<DiffNode>,
+    pub removed_nodes: Vec<DiffNode>,
+    pub changed_nodes: Vec<NodeChange>,
+    pub added_connections: Vec<ConnectionChange>,
+    pub removed_connections: Vec<ConnectionChange>,
+}
+
+impl GraphDiff {
+    /// Whether the two graphs are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_connections.is_empty()
+            && self.removed_connections.is_empty()
+    }
+
+    /// One line per difference, prefixed with `+`, `-` or `~`
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for node in &self.added_nodes {
+            lines.push(format!("+ node {} {} ({})", node.id, node.title, node.type_id));
+        }
+        for node in &self.removed_nodes {
+            lines.push(format!("- node {} {} ({})", node.id, node.title, node.type_id));
+        }
+        for change in &self.changed_nodes {
+            lines.push(format!("~ node {} {}: {}", change.id, change.title, change.fields.join(", ")));
+        }
+        for change in &self.added_connections {
+            lines.push(format!("+ connection {}", change.label));
+        }
+        for change in &self.removed_connections {
+            lines.push(format!("- connection {}", change.label));
+        }
+        lines
+    }
+}
+
+/// Compare two revisions of a graph
+pub fn diff_graphs(old: &NodeGraph, new: &NodeGraph) -> GraphDiff {
+    let mut diff = GraphDiff::default();
+
+    for id in sorted_ids(old, new) {
+        match (old.nodes.get(&id), new.nodes.get(&id)) {
+            (Some(old_node), Some(new_node)) if old_node.type_id == new_node.type_id => {
+                let fields = changed_fields(old_node, new_node);
+                if !fields.is_empty() {
+                    diff.changed_nodes.push(NodeChange { id, title: new_node.title.clone(), fields });
+                }
+            }
+            (old_node, new_node) => {
+                if let Some(node) = old_node {
+                    diff.removed_nodes.push(DiffNode::from_node(node));
+                }
+                if let Some(node) = new_node {
+                    diff.added_nodes.push(DiffNode::from_node(node));
+                }
+            }
+        }
+    }
+
+    for connection in &new.connections {
+        if !old.connections.contains(connection) {
+            diff.added_connections.push(ConnectionChange {
+                connection: connection.clone(),
+                label: describe_connection(new, connection),
+            });
+        }
+    }
+    for connection in &old.connections {
+        if !new.connections.contains(connection) {
+            diff.removed_connections.push(ConnectionChange {
+                connection: connection.clone(),
+                label: describe_connection(old, connection),
+            });
+        }
+    }
+
+    diff
+}
+
+/// Result of a three-way merge
+#[derive(Debug)]
+pub struct MergeResult {
+    pub graph: NodeGraph,
+    /// Edits that could not be combined; "ours" was kept for each of them
+    pub conflicts: Vec<String>,
+}
+
+/// Merge two revisions (`ours`, `theirs`) that both started from `base`.
+/// Non-overlapping edits are combined field by field; where both sides changed
+/// the same field differently, our value is kept and a conflict is reported.
+/// Nodes added on both sides under the same id are kept, theirs under a new id.
+pub fn merge_three_way(base: &NodeGraph, ours: &NodeGraph, theirs: &NodeGraph) -> MergeResult {
+    let mut graph = ours.clone();
+    let mut conflicts = Vec::new();
+    // Ids of their nodes in the merged graph, where they differ
+    let mut their_ids: HashMap<NodeId, NodeId> = HashMap::new();
+    let mut colliding: Vec<&Node> = Vec::new();
+
+    for (&id, their_node) in &theirs.nodes {
+        match (base.nodes.get(&id), ours.nodes.get(&id)) {
+            (Some(base_node), Some(our_node)) => {
+                if their_node.type_id != base_node.type_id {
+                    // They replaced the node; take theirs unless we replaced it too
+                    if our_node.type_id == base_node.type_id {
+                        graph.nodes.insert(id, their_node.clone());
+                    } else if our_node.type_id != their_node.type_id {
+                        conflicts.push(format!("node {} {}: replaced on both sides; kept ours", id, our_node.title));
+                    }
+                } else if our_node.type_id == base_node.type_id {
+                    let merged = merge_node(base_node, our_node, their_node, &mut conflicts);
+                    graph.nodes.insert(id, merged);
+                }
+            }
+            (Some(base_node), None) => {
+                // Deleted by us; keep it deleted unless they edited it
+                if !changed_fields(base_node, their_node).is_empty() {
+                    conflicts.push(format!("node {} {}: deleted in ours but changed in theirs; kept their version", id, their_node.title));
+                    graph.add_node_with_id(id, their_node.clone());
+                }
+            }
+            (None, Some(our_node)) => {
+                // Added on both sides under the same id
+                let identical = our_node.type_id == their_node.type_id && changed_fields(our_node, their_node).is_empty();
+                if !identical {
+                    colliding.push(their_node);
+                }
+            }
+            (None, None) => {
+                graph.add_node_with_id(id, their_node.clone());
+            }
+        }
+    }
+
+    for (&id, base_node) in &base.nodes {
+        if theirs.nodes.contains_key(&id) {
+            continue;
+        }
+        // Deleted by them; keep ours only if we edited it
+        if let Some(our_node) = ours.nodes.get(&id) {
+            if changed_fields(base_node, our_node).is_empty() {
+                graph.remove_node(id);
+            } else {
+                conflicts.push(format!("node {} {}: deleted in theirs but changed in ours; kept our version", id, our_node.title));
+            }
+        }
+    }
+
+    // Re-id colliding additions after all fixed ids are placed so fresh ids cannot clash
+    colliding.sort_by_key(|node| node.id);
+    for their_node in colliding {
+        let new_id = graph.add_node(their_node.clone());
+        their_ids.insert(their_node.id, new_id);
+    }
+
+    // Connections: keep ours unless they removed a base connection, then add theirs
+    graph.connections.retain(|connection| {
+        !base.connections.contains(connection) || theirs.connections.contains(connection)
+    });
+    for connection in &theirs.connections {
+        if base.connections.contains(connection) {
+            continue;
+        }
+        let mut connection = connection.clone();
+        connection.from_node = *their_ids.get(&connection.from_node).unwrap_or(&connection.from_node);
+        connection.to_node = *their_ids.get(&connection.to_node).unwrap_or(&connection.to_node);
+        if graph.connections.contains(&connection) {
+            continue;
+        }
+        let input_taken = graph.connections.iter()
+            .any(|existing| existing.to_node == connection.to_node && existing.to_port == connection.to_port);
+        if input_taken {
+            conflicts.push(format!("input {} connected on both sides; kept ours", describe_input(&graph, &connection)));
+            continue;
+        }
+        graph.connections.push(connection);
+    }
+
+    // Drop connections whose nodes or ports did not survive the merge
+    let nodes = &graph.nodes;
+    graph.connections.retain(|connection| {
+        nodes.get(&connection.from_node).is_some_and(|node| connection.from_port < node.outputs.len())
+            && nodes.get(&connection.to_node).is_some_and(|node| connection.to_port < node.inputs.len())
+    });
+
+    MergeResult { graph, conflicts }
+}
+
+/// Ids present in either graph, ascending
+fn sorted_ids(a: &NodeGraph, b: &NodeGraph) -> BTreeSet<NodeId> {
+    a.nodes.keys().chain(b.nodes.keys()).copied().collect()
+}
+
+/// Compare values through their serialized form (node data has no `PartialEq`)
+fn same<T: Serialize + ?Sized>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+fn same_position(a: Pos2, b: Pos2) -> bool {
+    (a - b).length() < POSITION_TOLERANCE
+}
+
+/// Names of the fields that differ between two versions of a node
+fn changed_fields(old: &Node, new: &Node) -> Vec<String> {
+    let mut fields = Vec::new();
+    if old.title != new.title {
+        fields.push("title".to_string());
+    }
+    if !same_position(old.position, new.position) {
+        fields.push("position".to_string());
+    }
+    if old.visible != new.visible {
+        fields.push("visible".to_string());
+    }
+    if !same(&old.node_type, &new.node_type) {
+        fields.push("subgraph".to_string());
+    }
+    let names: BTreeSet<&String> = old.parameters.keys().chain(new.parameters.keys()).collect();
+    for name in names {
+        if !same(&old.parameters.get(name), &new.parameters.get(name)) {
+            fields.push(format!("parameter {}", name));
+        }
+    }
+    fields
+}
+
+/// Pick the merged value of one field, reporting a conflict if both sides changed it
+fn merge_field<T: Clone>(
+    field: &str,
+    node: &Node,
+    base: &T,
+    ours: &T,
+    theirs: &T,
+    eq: impl Fn(&T, &T) -> bool,
+    conflicts: &mut Vec<String>,
+) -> T {
+    if eq(ours, theirs) || eq(theirs, base) {
+        ours.clone()
+    } else if eq(ours, base) {
+        theirs.clone()
+    } else {
+        conflicts.push(format!("node {} {}: {} changed on both sides; kept ours", node.id, node.title, field));
+        ours.clone()
+    }
+}
+
+/// Combine the edits made to one node on both sides
+fn merge_node(base: &Node, ours: &Node, theirs: &Node, conflicts: &mut Vec<String>) -> Node {
+    let mut merged = ours.clone();
+    merged.title = merge_field("title", ours, &base.title, &ours.title, &theirs.title, |a, b| a == b, conflicts);
+    merged.position = merge_field("position", ours, &base.position, &ours.position, &theirs.position, |a, b| same_position(*a, *b), conflicts);
+    merged.visible = merge_field("visible", ours, &base.visible, &ours.visible, &theirs.visible, |a, b| a == b, conflicts);
+
+    // Ports belong to the node type (workspace port mappings), so they travel with it
+    if !same(&ours.node_type, &theirs.node_type) && !same(&theirs.node_type, &base.node_type) {
+        if same(&ours.node_type, &base.node_type) {
+            merged.node_type = theirs.node_type.clone();
+            merged.inputs = theirs.inputs.clone();
+            merged.outputs = theirs.outputs.clone();
+        } else {
+            conflicts.push(format!("node {} {}: subgraph changed on both sides; kept ours", ours.id, ours.title));
+        }
+    }
+
+    let names: BTreeSet<&String> = base.parameters.keys()
+        .chain(ours.parameters.keys())
+        .chain(theirs.parameters.keys())
+        .collect();
+    for name in names {
+        let value = merge_field(
+            &format!("parameter {}", name),
+            ours,
+            &base.parameters.get(name),
+            &ours.parameters.get(name),
+            &theirs.parameters.get(name),
+            |a, b| same(a, b),
+            conflicts,
+        );
+        match value {
+            Some(value) => {
+                merged.parameters.insert(name.clone(), value.clone());
+            }
+            None => {
+                merged.parameters.remove(name);
+            }
+        }
+    }
+
+    merged.update_port_positions();
+    merged
+}
+
+/// "Sphere.Geometry → Viewport.Scene"
+fn describe_connection(graph: &NodeGraph, connection: &Connection) -> String {
+    let from = graph.nodes.get(&connection.from_node);
+    let from_title = from.map_or_else(|| format!("#{}", connection.from_node), |node| node.title.clone());
+    let from_port = from
+        .and_then(|node| node.outputs.get(connection.from_port))
+        .map_or_else(|| connection.from_port.to_string(), |port| port.display_name().into_owned());
+    format!("{}.{} → {}", from_title, from_port, describe_input(graph, connection))
+}
+
+/// "Viewport.Scene"
+fn describe_input(graph: &NodeGraph, connection: &Connection) -> String {
+    let to = graph.nodes.get(&connection.to_node);
+    let to_title = to.map_or_else(|| format!("#{}", connection.to_node), |node| node.title.clone());
+    let to_port = to
+        .and_then(|node| node.inputs.get(connection.to_port))
+        .map_or_else(|| connection.to_port.to_string(), |port| port.display_name().into_owned());
+    format!("{}.{}", to_title, to_port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::interface::NodeData;
+
+    fn node(title: &str, x: f32) -> Node {
+        let mut node = Node::new(0, title, Pos2::new(x, 0.0));
+        node.type_id = title.to_string();
+        node.add_input("In").add_output("Out");
+        node
+    }
+
+    fn base_graph() -> NodeGraph {
+        let mut graph = NodeGraph::new();
+        let a = graph.add_node(node("A", 0.0));
+        let b = graph.add_node(node("B", 200.0));
+        graph.add_connection_by_ids(a, 0, b, 0).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed_nodes() {
+        let old = base_graph();
+        let mut new = old.clone();
+        new.remove_node(0);
+        new.add_node(node("C", 400.0));
+        let b = new.nodes.get_mut(&1).unwrap();
+        b.parameters.insert("radius".to_string(), NodeData::Float(2.0));
+
+        let diff = diff_graphs(&old, &new);
+        assert_eq!(diff.removed_nodes.len(), 1);
+        assert_eq!(diff.added_nodes[0].title, "C");
+        assert_eq!(diff.changed_nodes[0].fields, vec!["parameter radius".to_string()]);
+        assert_eq!(diff.removed_connections.len(), 1);
+        assert!(diff_graphs(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_merge_combines_independent_edits_and_reports_conflicts() {
+        let base = base_graph();
+        let mut ours = base.clone();
+        ours.nodes.get_mut(&0).unwrap().title = "Renamed".to_string();
+        ours.nodes.get_mut(&1).unwrap().parameters.insert("radius".to_string(), NodeData::Float(1.0));
+        let mut theirs = base.clone();
+        theirs.nodes.get_mut(&1).unwrap().position = Pos2::new(300.0, 50.0);
+        theirs.nodes.get_mut(&1).unwrap().parameters.insert("radius".to_string(), NodeData::Float(3.0));
+        let added = theirs.add_node(node("C", 400.0));
+        ours.add_node(node("D", 400.0));
+
+        let result = merge_three_way(&base, &ours, &theirs);
+        let graph = &result.graph;
+        assert_eq!(graph.nodes[&0].title, "Renamed");
+        assert_eq!(graph.nodes[&1].position, Pos2::new(300.0, 50.0));
+        // Both sides set radius differently: ours wins and the conflict is reported
+        assert!(matches!(graph.nodes[&1].parameters.get("radius"), Some(NodeData::Float(v)) if *v == 1.0));
+        assert_eq!(result.conflicts.len(), 1);
+        // Both added a node with the same id; theirs is kept under a fresh id
+        assert_eq!(graph.nodes[&added].title, "D");
+        assert!(graph.nodes.values().any(|node| node.title == "C"));
+        assert_eq!(graph.connections.len(), 1);
+    }
+}
@@ -0,0 +1,258 @@
+//! Graph statistics and memory report
+//!
+//! Walks a graph and the execution engine's cache to count nodes per type and
+//! connections, and estimates the memory held by each node's cached outputs.
+//! The estimates count array and string contents, not allocator overhead, so
+//! they are meant for spotting heavy nodes and tracking trends (the report can
+//! be dumped to JSON for CI), not as exact figures.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::mem::size_of;
+use std::path::Path;
+use crate::nodes::{NodeGraph, NodeGraphEngine, NodeId};
+use crate::nodes::interface::{GeometryData, NodeData, TableValue, TreeItem};
+use crate::workspaces::three_d::usd::usd_engine::{AttributeValue, PrimvarValues, USDMeshGeometry};
+
+/// Cached data and connections of one node
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeReport {
+    pub node_id: NodeId,
+    pub title: String,
+    pub type_id: String,
+    /// Cached output values (ports, stages and frames)
+    pub cached_outputs: usize,
+    pub cached_bytes: usize,
+    pub incoming_connections: usize,
+    pub outgoing_connections: usize,
+}
+
+/// Nodes of one type
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeReport {
+    pub type_id: String,
+    pub count: usize,
+    pub cached_bytes: usize,
+}
+
+/// Snapshot of a graph's size and memory use
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphReport {
+    pub generated_at: chrono::DateTime<chrono::Local>,
+    pub node_count: usize,
+    pub connection_count: usize,
+    pub total_cached_bytes: usize,
+    /// Buffers and textures of the 3D viewport; None when no viewport has drawn
+    pub gpu_bytes: Option<u64>,
+    /// Most common types first
+    pub node_types: Vec<TypeReport>,
+    /// Heaviest nodes first
+    pub nodes: Vec<NodeReport>,
+}
+
+impl GraphReport {
+    /// Report on `graph` with the outputs `engine` holds for it
+    pub fn build(graph: &NodeGraph, engine: &NodeGraphEngine, gpu_bytes: Option<u64>) -> Self {
+        let mut nodes: Vec<NodeReport> = graph.nodes.values()
+            .map(|node| {
+                let entries = engine.unified_cache.get_node_entries(node.id);
+                NodeReport {
+                    node_id: node.id,
+                    title: node.title.clone(),
+                    type_id: node.type_id.clone(),
+                    cached_outputs: entries.len(),
+                    cached_bytes: entries.iter().map(|(_, value)| estimated_size(value)).sum(),
+                    incoming_connections: graph.connections.iter().filter(|c| c.to_node == node.id).count(),
+                    outgoing_connections: graph.connections.iter().filter(|c| c.from_node == node.id).count(),
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| b.cached_bytes.cmp(&a.cached_bytes).then(a.node_id.cmp(&b.node_id)));
+
+        let mut types: HashMap<&str, TypeReport> = HashMap::new();
+        for node in &nodes {
+            let entry = types.entry(node.type_id.as_str()).or_insert_with(|| TypeReport {
+                type_id: node.type_id.clone(),
+                count: 0,
+                cached_bytes: 0,
+            });
+            entry.count += 1;
+            entry.cached_bytes += node.cached_bytes;
+        }
+        let mut node_types: Vec<TypeReport> = types.into_values().collect();
+        node_types.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.type_id.cmp(&b.type_id)));
+
+        Self {
+            generated_at: chrono::Local::now(),
+            node_count: nodes.len(),
+            connection_count: graph.connections.len(),
+            total_cached_bytes: nodes.iter().map(|node| node.cached_bytes).sum(),
+            gpu_bytes,
+            node_types,
+            nodes,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn save_json(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_json()?).map_err(|e| format!("Failed to write graph report: {}", e))
+    }
+}
+
+/// Approximate bytes held by a value: array and string contents, not allocator overhead
+pub fn estimated_size(value: &NodeData) -> usize {
+    let payload = match value {
+        NodeData::String(text) | NodeData::Any(text) => text.len(),
+        NodeData::Buffer(values) => values.len() * size_of::<f32>(),
+        NodeData::Table(table) => {
+            let headers: usize = table.columns.iter().map(String::len).sum();
+            let cells: usize = table.rows.iter().flatten().map(|cell| match cell {
+                TableValue::Text(text) => size_of::<TableValue>() + text.len(),
+                _ => size_of::<TableValue>(),
+            }).sum();
+            headers + cells
+        }
+        NodeData::Tree(tree) => tree.roots.iter().map(tree_item_size).sum(),
+        NodeData::PointCloud(points) => points.source.len()
+            + (points.positions.len() + points.colors.len()) * size_of::<glam::Vec3>(),
+        NodeData::Volume(volume) => volume.source.len()
+            + volume.grids.iter().map(|grid| {
+                size_of::<crate::nodes::interface::VolumeGrid>() + grid.name.len()
+                    + grid.values.as_ref().map_or(0, |values| {
+                        values.leaves.iter().map(|leaf| size_of::<[i32; 3]>() + leaf.values.len() * size_of::<f32>()).sum::<usize>()
+                            + values.tiles.len() * size_of::<crate::nodes::interface::VolumeTile>()
+                    })
+            }).sum::<usize>(),
+        NodeData::Audio(audio) => audio.source.len() + audio.samples.len() * size_of::<f32>(),
+        NodeData::List(items) => items.iter().map(estimated_size).sum(),
+        NodeData::Map(entries) => entries.iter().map(|(key, value)| key.len() + estimated_size(value)).sum(),
+        NodeData::Custom(data) => data.type_id.len() + data.value.to_string().len(),
+        NodeData::Geometry(geometry) => geometry_size(geometry),
+        NodeData::Scene(scene) => {
+            scene.geometry.iter().map(geometry_size).sum::<usize>()
+                + scene.transforms.len() * size_of::<[[f32; 4]; 4]>()
+        }
+        NodeData::Stage(stage) => stage.prims.iter().map(String::len).sum(),
+        NodeData::USDSceneData(scene) => scene.meshes.iter().map(usd_mesh_size).sum::<usize>()
+            + scene.lights.iter().map(|light| light.prim_path.len() + light.light_type.len()).sum::<usize>()
+            + scene.materials.iter().map(|material| material.prim_path.len()).sum::<usize>()
+            + scene.instancers.iter().map(|instancer| {
+                instancer.prototypes.iter().map(usd_mesh_size).sum::<usize>()
+                    + instancer.proto_indices.len() * size_of::<u32>()
+                    + (instancer.positions.len() + instancer.scales.len()) * size_of::<glam::Vec3>()
+                    + instancer.orientations.len() * size_of::<glam::Quat>()
+            }).sum::<usize>(),
+        NodeData::USDScenegraphMetadata(metadata) => {
+            metadata.meshes.iter().map(|mesh| mesh.prim_path.len()).sum()
+        }
+        _ => 0,
+    };
+    size_of::<NodeData>() + payload
+}
+
+fn tree_item_size(item: &TreeItem) -> usize {
+    item.path.len()
+        + item.label.len()
+        + item.detail.as_ref().map_or(0, String::len)
+        + item.children.iter().map(tree_item_size).sum::<usize>()
+}
+
+fn geometry_size(geometry: &GeometryData) -> usize {
+    geometry.vertices.len() * size_of::<[f32; 3]>()
+        + geometry.indices.len() * size_of::<u32>()
+        + geometry.normals.len() * size_of::<[f32; 3]>()
+        + geometry.uvs.len() * size_of::<[f32; 2]>()
+}
+
+fn usd_mesh_size(mesh: &USDMeshGeometry) -> usize {
+    let primvars: usize = mesh.primvars.iter().map(|primvar| match &primvar.values {
+        PrimvarValues::Float(values) => values.len() * 4,
+        PrimvarValues::Float2(values) => values.len() * 8,
+        PrimvarValues::Float3(values) => values.len() * 12,
+        PrimvarValues::Int(values) => values.len() * 4,
+        PrimvarValues::String(values) => values.iter().map(String::len).sum(),
+    } + primvar.indices.as_ref().map_or(0, |indices| indices.len() * 4)).sum();
+    let attributes: usize = mesh.attributes.iter().map(|attribute| match &attribute.value {
+        AttributeValue::BoolArray(values) => values.len(),
+        AttributeValue::IntArray(values) => values.len() * 4,
+        AttributeValue::FloatArray(values) => values.len() * 4,
+        AttributeValue::DoubleArray(values) => values.len() * 8,
+        AttributeValue::StringArray(values) | AttributeValue::TokenArray(values)
+        | AttributeValue::AssetArray(values) | AttributeValue::Relationship(values) => values.iter().map(String::len).sum(),
+        AttributeValue::Float2Array(values) | AttributeValue::TexCoord2fArray(values) => values.len() * 8,
+        AttributeValue::Float3Array(values) | AttributeValue::Color3fArray(values) | AttributeValue::Normal3fArray(values)
+        | AttributeValue::Point3fArray(values) | AttributeValue::Vector3fArray(values) => values.len() * 12,
+        AttributeValue::Matrix4dArray(values) => values.len() * 64,
+        AttributeValue::TimeSamples(samples) => samples.iter().map(|(_, value)| 8 + value.len()).sum(),
+        _ => size_of::<AttributeValue>(),
+    }).sum();
+    mesh.prim_path.len()
+        + mesh.vertices.len() * 12
+        + mesh.indices.len() * 4
+        + mesh.normals.len() * 12
+        + mesh.uvs.len() * 8
+        + mesh.vertex_colors.as_ref().map_or(0, |colors| colors.len() * 12)
+        + primvars
+        + attributes
+}
+
+/// Human-readable byte count ("512 B", "1.5 KB", "3.2 MB")
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::{Connection, Node};
+    use crate::nodes::cache::CacheKey;
+    use crate::nodes::ownership::OwnedNodeData;
+
+    #[test]
+    fn test_report_counts_types_connections_and_cached_bytes() {
+        let mut graph = NodeGraph::new();
+        for (id, type_id) in [(1, "Add"), (2, "Add"), (3, "Multiply")] {
+            let mut node = Node::new(id, type_id, egui::Pos2::ZERO);
+            node.type_id = type_id.to_string();
+            graph.nodes.insert(id, node);
+        }
+        graph.connections.push(Connection::new(1, 0, 3, 0));
+        graph.connections.push(Connection::new(2, 0, 3, 1));
+
+        let mut engine = NodeGraphEngine::new();
+        let buffer = NodeData::Buffer(vec![0.0; 1000]);
+        engine.unified_cache.insert(CacheKey::new(3, 0), OwnedNodeData::owned(buffer.clone()));
+
+        let report = GraphReport::build(&graph, &engine, None);
+        assert_eq!(report.node_count, 3);
+        assert_eq!(report.connection_count, 2);
+        assert_eq!(report.node_types[0].type_id, "Add");
+        assert_eq!(report.node_types[0].count, 2);
+        assert_eq!(report.nodes[0].node_id, 3);
+        assert_eq!(report.nodes[0].incoming_connections, 2);
+        assert_eq!(report.nodes[0].cached_bytes, estimated_size(&buffer));
+        assert_eq!(report.total_cached_bytes, size_of::<NodeData>() + 4000);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"][0]["cached_outputs"], 1);
+        assert!(json["gpu_bytes"].is_null());
+
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -2,7 +2,7 @@
 
 use egui::{Ui, DragValue, ComboBox, Color32};
 use crate::nodes::NodeId;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use serde::{Serialize, Deserialize};
 
 /// Types of interface panels that nodes can specify
@@ -50,6 +50,24 @@ pub enum NodeData {
     Color([f32; 4]),
     String(String),
     Boolean(bool),
+    /// Flat f32 buffer (e.g. compute shader results)
+    Buffer(Vec<f32>),
+    /// Tabular rows and named columns (CSV/JSON)
+    Table(TableData),
+    /// Hierarchical items shown by the Tree panel
+    Tree(TreeData),
+    /// Unconnected points with optional colors (LAS/PLY scans)
+    PointCloud(PointCloudData),
+    /// Sparse voxel grids read from OpenVDB files
+    Volume(VolumeData),
+    /// Decoded audio samples
+    Audio(AudioData),
+    /// Ordered items of any types
+    List(Vec<NodeData>),
+    /// Values of any types by name, in key order
+    Map(BTreeMap<String, NodeData>),
+    /// Value of a data type provided by a plugin
+    Custom(CustomData),
     Any(String), // Generic reference/handle
     None, // Empty/null value
 }
@@ -138,10 +156,423 @@ pub enum ImageFormat {
     HDR,
 }
 
+/// Tabular data with named columns, as read from CSV or JSON
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableData {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<TableValue>>,
+}
+
+/// A single table cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TableValue {
+    Number(f64),
+    Text(String),
+    Boolean(bool),
+    Null,
+}
+
+impl TableValue {
+    /// Infer a typed cell from raw text (numbers, true/false, empty = Null)
+    pub fn infer(raw: &str) -> Self {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            TableValue::Null
+        } else if let Ok(n) = trimmed.parse::<f64>() {
+            TableValue::Number(n)
+        } else if trimmed.eq_ignore_ascii_case("true") {
+            TableValue::Boolean(true)
+        } else if trimmed.eq_ignore_ascii_case("false") {
+            TableValue::Boolean(false)
+        } else {
+            TableValue::Text(raw.to_string())
+        }
+    }
+    
+    /// Numeric view of the cell (booleans map to 0/1)
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            TableValue::Number(n) => Some(*n),
+            TableValue::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            TableValue::Text(s) => s.trim().parse().ok(),
+            TableValue::Null => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TableValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableValue::Number(n) => write!(f, "{}", n),
+            TableValue::Text(s) => write!(f, "{}", s),
+            TableValue::Boolean(b) => write!(f, "{}", b),
+            TableValue::Null => Ok(()),
+        }
+    }
+}
+
+impl TableData {
+    /// Number of rows
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+    
+    /// Number of columns
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+    
+    /// Index of a column by name
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == name)
+    }
+    
+    /// Cells of a column by name (missing cells yield Null)
+    pub fn column(&self, name: &str) -> Option<Vec<TableValue>> {
+        let index = self.column_index(name)?;
+        Some(self.rows.iter()
+            .map(|row| row.get(index).cloned().unwrap_or(TableValue::Null))
+            .collect())
+    }
+    
+    /// Keep only the named columns, in the given order.
+    /// `selection` is a comma-separated list; an empty selection keeps every column.
+    pub fn select(&self, selection: &str) -> TableData {
+        let names: Vec<&str> = selection.split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if names.is_empty() {
+            return self.clone();
+        }
+        
+        let indices: Vec<usize> = names.iter()
+            .filter_map(|name| self.column_index(name))
+            .collect();
+        TableData {
+            columns: indices.iter().map(|&i| self.columns[i].clone()).collect(),
+            rows: self.rows.iter()
+                .map(|row| indices.iter()
+                    .map(|&i| row.get(i).cloned().unwrap_or(TableValue::Null))
+                    .collect())
+                .collect(),
+        }
+    }
+}
+
+/// Points without connectivity, as read from laser scans and photogrammetry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointCloudData {
+    /// File the points were read from, for display
+    pub source: String,
+    pub positions: Vec<glam::Vec3>,
+    /// Per-point RGB in 0..1; empty when the points have no colors
+    pub colors: Vec<glam::Vec3>,
+}
+
+impl PointCloudData {
+    /// Number of points
+    pub fn point_count(&self) -> usize {
+        self.positions.len()
+    }
+    
+    /// Whether every point has a color
+    pub fn has_colors(&self) -> bool {
+        !self.colors.is_empty() && self.colors.len() == self.positions.len()
+    }
+    
+    /// Axis-aligned bounds of the points; None for an empty cloud
+    pub fn bounds(&self) -> Option<(glam::Vec3, glam::Vec3)> {
+        let first = *self.positions.first()?;
+        Some(self.positions.iter().fold((first, first), |(min, max), p| (min.min(*p), max.max(*p))))
+    }
+}
+
+/// Value of a data type a plugin registered (see `plugins::data_types`): the
+/// type's ID and the value as JSON. Written to project files in the form the
+/// type's serialization hook gives and read back through its other hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomData {
+    pub type_id: String,
+    pub value: serde_json::Value,
+}
+
+/// How custom values appear in project files
+#[derive(Serialize, Deserialize)]
+struct SavedCustomData {
+    type_id: String,
+    value: serde_json::Value,
+}
+
+impl Serialize for CustomData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedCustomData {
+            type_id: self.type_id.clone(),
+            value: crate::plugins::data_types::save_value(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomData {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved = SavedCustomData::deserialize(deserializer)?;
+        let value = crate::plugins::data_types::load_value(&saved.type_id, saved.value);
+        Ok(Self { type_id: saved.type_id, value })
+    }
+}
+
+/// Sound read from an audio file, mixed down to one channel for analysis
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioData {
+    /// File the sound was read from, for display
+    pub source: String,
+    /// Samples per second
+    pub sample_rate: u32,
+    /// Channels in the file before the mixdown
+    pub channels: u16,
+    /// Mono samples in -1..1
+    pub samples: Vec<f32>,
+}
+
+impl AudioData {
+    /// Length in seconds
+    pub fn duration(&self) -> f32 {
+        if self.sample_rate == 0 { 0.0 } else { self.samples.len() as f32 / self.sample_rate as f32 }
+    }
+}
+
+/// Grids of an OpenVDB file
+///
+/// Grids keep their descriptions, and the voxel values where the reader could
+/// decode them, for display; renderers read the voxels from `source` themselves.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeData {
+    /// Absolute path of the .vdb file
+    pub source: String,
+    pub grids: Vec<VolumeGrid>,
+}
+
+impl VolumeData {
+    /// World-space corners of every grid's bounds
+    pub fn bounds(&self) -> Option<(glam::Vec3, glam::Vec3)> {
+        self.grids.iter()
+            .flat_map(|grid| grid.world_corners())
+            .fold(None, |bounds, p| Some(match bounds {
+                Some((min, max)) => (glam::Vec3::min(min, p), glam::Vec3::max(max, p)),
+                None => (p, p),
+            }))
+    }
+}
+
+/// One named grid of a volume, such as density or temperature
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeGrid {
+    pub name: String,
+    /// Voxel type from the file, e.g. "float" or "vec3s"
+    pub value_type: String,
+    /// "fog volume", "level set", "staggered" or "unknown"
+    pub grid_class: String,
+    /// Inclusive index-space bounds of the active voxels
+    pub index_min: [i32; 3],
+    pub index_max: [i32; 3],
+    pub active_voxels: u64,
+    /// Index space (voxel centers) to world space
+    pub transform: glam::Mat4,
+    /// Decoded scalar values; None for unsupported types or compression
+    pub values: Option<VolumeValues>,
+}
+
+impl VolumeGrid {
+    /// Edge lengths of a voxel in world units
+    pub fn voxel_size(&self) -> glam::Vec3 {
+        glam::Vec3::new(
+            self.transform.x_axis.truncate().length(),
+            self.transform.y_axis.truncate().length(),
+            self.transform.z_axis.truncate().length(),
+        )
+    }
+
+    /// The eight world-space corners of the voxels' outer faces
+    pub fn world_corners(&self) -> [glam::Vec3; 8] {
+        let min = glam::IVec3::from(self.index_min).as_vec3() - 0.5;
+        let max = glam::IVec3::from(self.index_max).as_vec3() + 0.5;
+        std::array::from_fn(|corner| self.transform.transform_point3(glam::Vec3::new(
+            if corner & 1 == 0 { min.x } else { max.x },
+            if corner & 2 == 0 { min.y } else { max.y },
+            if corner & 4 == 0 { min.z } else { max.z },
+        )))
+    }
+}
+
+/// Scalar voxel values as 8³ leaf blocks plus constant tiles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeValues {
+    /// Value of every voxel outside the leaves and tiles
+    pub background: f32,
+    pub leaves: Vec<VolumeLeaf>,
+    pub tiles: Vec<VolumeTile>,
+}
+
+impl VolumeValues {
+    /// A lookup of the value at any voxel
+    pub fn sampler(&self) -> VolumeSampler<'_> {
+        VolumeSampler {
+            values: self,
+            leaves: self.leaves.iter().enumerate().map(|(index, leaf)| (leaf.origin, index)).collect(),
+            tiles: self.tiles.iter().map(|tile| ((tile.size, tile.origin), tile.value)).collect(),
+            tile_sizes: self.tiles.iter().map(|tile| tile.size).collect::<HashSet<_>>().into_iter().collect(),
+        }
+    }
+}
+
+/// 8×8×8 voxels; values are indexed x-major, `(x * 8 + y) * 8 + z`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeLeaf {
+    pub origin: [i32; 3],
+    pub values: Vec<f32>,
+}
+
+/// A cube of voxels sharing one active value
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeTile {
+    pub origin: [i32; 3],
+    /// Edge length in voxels, a power of two
+    pub size: i32,
+    pub value: f32,
+}
+
+/// Voxel lookup built by [`VolumeValues::sampler`]
+pub struct VolumeSampler<'a> {
+    values: &'a VolumeValues,
+    leaves: HashMap<[i32; 3], usize>,
+    tiles: HashMap<(i32, [i32; 3]), f32>,
+    tile_sizes: Vec<i32>,
+}
+
+impl VolumeSampler<'_> {
+    /// Value of the voxel at `ijk`
+    pub fn value(&self, ijk: [i32; 3]) -> f32 {
+        let [i, j, k] = ijk;
+        if let Some(&index) = self.leaves.get(&[i & !7, j & !7, k & !7]) {
+            let offset = (((i & 7) << 6) | ((j & 7) << 3) | (k & 7)) as usize;
+            if let Some(value) = self.values.leaves[index].values.get(offset) {
+                return *value;
+            }
+        }
+        self.tile_sizes.iter()
+            .find_map(|&size| self.tiles.get(&(size, [i & !(size - 1), j & !(size - 1), k & !(size - 1)])))
+            .copied()
+            .unwrap_or(self.values.background)
+    }
+}
+
+/// Hierarchical data that any node can output for display in the Tree panel
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreeData {
+    pub roots: Vec<TreeItem>,
+}
+
+/// A single item in a tree hierarchy
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreeItem {
+    /// Unique path of the item (e.g. "/World/Mesh"); written back on selection
+    pub path: String,
+    /// Text shown in the tree
+    pub label: String,
+    /// Optional secondary text (type, value) shown next to the label
+    pub detail: Option<String>,
+    pub children: Vec<TreeItem>,
+}
+
+impl TreeItem {
+    /// Create a leaf item
+    pub fn new(path: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            label: label.into(),
+            detail: None,
+            children: Vec::new(),
+        }
+    }
+    
+    /// Attach secondary text
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+    
+    /// Whether this item or any descendant matches a case-insensitive query
+    pub fn matches(&self, query_lower: &str) -> bool {
+        self.label.to_lowercase().contains(query_lower)
+            || self.children.iter().any(|child| child.matches(query_lower))
+    }
+    
+    /// Number of items in this subtree, including itself
+    pub fn item_count(&self) -> usize {
+        1 + self.children.iter().map(|child| child.item_count()).sum::<usize>()
+    }
+}
+
+impl TreeData {
+    /// Node parameter that the Tree panel writes the selected item path into
+    pub const SELECTED_PATH_PARAMETER: &str = "selected_path";
+    
+    /// Total number of items in the tree
+    pub fn item_count(&self) -> usize {
+        self.roots.iter().map(|root| root.item_count()).sum()
+    }
+    
+    /// Find an item by path
+    pub fn find(&self, path: &str) -> Option<&TreeItem> {
+        fn find_in<'a>(items: &'a [TreeItem], path: &str) -> Option<&'a TreeItem> {
+            for item in items {
+                if item.path == path {
+                    return Some(item);
+                }
+                if let Some(found) = find_in(&item.children, path) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find_in(&self.roots, path)
+    }
+    
+    /// Build a tree mirroring a JSON document (objects and arrays become branches)
+    pub fn from_json(value: &serde_json::Value, root_label: &str) -> Self {
+        fn build(value: &serde_json::Value, path: String, label: String) -> TreeItem {
+            match value {
+                serde_json::Value::Object(map) => {
+                    let mut item = TreeItem::new(path.clone(), label)
+                        .with_detail(format!("{{{}}}", map.len()));
+                    item.children = map.iter()
+                        .map(|(key, child)| build(child, format!("{}/{}", path, key), key.clone()))
+                        .collect();
+                    item
+                }
+                serde_json::Value::Array(items) => {
+                    let mut item = TreeItem::new(path.clone(), label)
+                        .with_detail(format!("[{}]", items.len()));
+                    item.children = items.iter().enumerate()
+                        .map(|(index, child)| build(child, format!("{}/{}", path, index), format!("[{}]", index)))
+                        .collect();
+                    item
+                }
+                scalar => TreeItem::new(path, label).with_detail(scalar.to_string()),
+            }
+        }
+        
+        Self {
+            roots: vec![build(value, String::new(), root_label.to_string())],
+        }
+    }
+}
+
 /// Parameters that can be controlled in interface panels
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InterfaceParameter {
-    Float { value: f32, min: f32, max: f32, step: f32 },
+    Float { value: f32, min: f32, max: f32, step: f32, #[serde(default)] unit: crate::nodes::units::Unit },
     Integer { value: i32, min: i32, max: i32 },
     Vector3 { value: [f32; 3] },
     Color { value: [f32; 4] },
@@ -155,11 +586,12 @@ impl InterfaceParameter {
     /// Render the parameter in the UI and return if it changed
     pub fn render(&mut self, ui: &mut Ui, label: &str) -> bool {
         match self {
-            InterfaceParameter::Float { value, min, max, step } => {
+            InterfaceParameter::Float { value, min, max, step, unit } => {
                 ui.add(DragValue::new(value)
                     .speed(*step)
                     .range(*min..=*max)
-                    .prefix(format!("{}: ", label)))
+                    .prefix(format!("{}: ", label))
+                    .suffix(unit.suffix()))
                     .changed()
             }
             InterfaceParameter::Integer { value, min, max } => {
@@ -348,6 +780,8 @@ pub struct NodePanelState {
     pub original_position: Option<egui::Pos2>,
     /// Unstacked panel ID for avoiding conflicts
     pub unstacked_panel_id: Option<u64>,
+    /// Node output (node, port) a viewer shows instead of its own input, while pinned
+    pub viewer_source: Option<(NodeId, usize)>,
     /// Cached parameter values
     pub parameter_cache: Vec<(&'static str, InterfaceParameter)>,
 }
@@ -367,6 +801,7 @@ impl NodePanelState {
             rect: None,
             original_position: None,
             unstacked_panel_id: None,
+            viewer_source: None,
             parameter_cache: Vec::new(),
         }
     }
@@ -381,6 +816,41 @@ impl NodePanelState {
     }
 }
 
+/// Saved layout of a single node's panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PanelLayoutEntry {
+    pub node_id: NodeId,
+    pub panel_type: Option<PanelType>,
+    pub visible: bool,
+    pub open: bool,
+    pub minimized: bool,
+    pub stacked: bool,
+    pub pinned: bool,
+    pub custom_name: Option<String>,
+    pub fit_name: bool,
+    /// Window rect as [min_x, min_y, max_x, max_y]
+    pub rect: Option<[f32; 4]>,
+    /// Node output pinned to this viewer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer_source: Option<(NodeId, usize)>,
+}
+
+/// Saved layout of all panels, persisted in the project file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PanelLayout {
+    pub panels: Vec<PanelLayoutEntry>,
+    /// Rects of the shared stacked windows per panel type
+    pub stack_rects: Vec<(PanelType, [f32; 4])>,
+}
+
+fn rect_to_array(rect: egui::Rect) -> [f32; 4] {
+    [rect.min.x, rect.min.y, rect.max.x, rect.max.y]
+}
+
+fn rect_from_array(values: [f32; 4]) -> egui::Rect {
+    egui::Rect::from_min_max(egui::pos2(values[0], values[1]), egui::pos2(values[2], values[3]))
+}
+
 /// Manager for all node interface panels - now simplified with consolidated state
 #[derive(Default)]
 pub struct InterfacePanelManager {
@@ -390,6 +860,11 @@ pub struct InterfacePanelManager {
     stacking_initiators: HashMap<PanelType, NodeId>,
     stacking_order: HashMap<PanelType, Vec<NodeId>>,
     stack_positions: HashMap<PanelType, egui::Pos2>,
+    /// Last drawn rect of each shared stacked window
+    stack_rects: HashMap<PanelType, egui::Rect>,
+    /// Panels whose saved rect must be applied on their next draw (after loading a file)
+    pending_rect_restores: HashSet<NodeId>,
+    pending_stack_restores: HashSet<PanelType>,
 }
 
 impl InterfacePanelManager {
@@ -539,6 +1014,23 @@ impl InterfacePanelManager {
         self.get_state(node_id).map(|s| s.pinned).unwrap_or(false)
     }
     
+    /// Pin the output `port` of `source` to the viewer `viewer_id`, or unpin it with None
+    pub fn set_viewer_source(&mut self, viewer_id: NodeId, source: Option<(NodeId, usize)>) {
+        self.get_or_create_state(viewer_id).viewer_source = source;
+    }
+    
+    /// Node output pinned to a viewer, if any
+    pub fn get_viewer_source(&self, viewer_id: NodeId) -> Option<(NodeId, usize)> {
+        self.get_state(viewer_id).and_then(|s| s.viewer_source)
+    }
+    
+    /// Nodes whose output is pinned to at least one viewer
+    pub fn pinned_viewer_sources(&self) -> HashSet<NodeId> {
+        self.node_states.values()
+            .filter_map(|state| state.viewer_source.map(|(source, _)| source))
+            .collect()
+    }
+    
     /// Set panel type
     pub fn set_panel_type(&mut self, node_id: NodeId, panel_type: PanelType) {
         let state = self.get_or_create_state(node_id);
@@ -634,6 +1126,94 @@ impl InterfacePanelManager {
         }
     }
     
+    /// Record the rect a panel's window was drawn at
+    pub fn set_panel_rect(&mut self, node_id: NodeId, rect: egui::Rect) {
+        self.get_or_create_state(node_id).rect = Some(rect);
+    }
+    
+    /// Last recorded rect of a panel's window
+    pub fn get_panel_rect(&self, node_id: NodeId) -> Option<egui::Rect> {
+        self.get_state(node_id).and_then(|s| s.rect)
+    }
+    
+    /// Saved rect to force onto the panel's window once after a layout was loaded
+    pub fn take_pending_rect(&mut self, node_id: NodeId) -> Option<egui::Rect> {
+        if self.pending_rect_restores.remove(&node_id) {
+            self.get_panel_rect(node_id)
+        } else {
+            None
+        }
+    }
+    
+    /// Record the rect the shared stacked window of a panel type was drawn at
+    pub fn set_stack_rect(&mut self, panel_type: PanelType, rect: egui::Rect) {
+        self.stack_rects.insert(panel_type, rect);
+    }
+    
+    /// Saved stacked window rect to force once after a layout was loaded
+    pub fn take_pending_stack_rect(&mut self, panel_type: PanelType) -> Option<egui::Rect> {
+        if self.pending_stack_restores.remove(&panel_type) {
+            self.stack_rects.get(&panel_type).copied()
+        } else {
+            None
+        }
+    }
+    
+    /// Snapshot the panel layout for saving with the project
+    pub fn export_layout(&self) -> PanelLayout {
+        let mut panels: Vec<PanelLayoutEntry> = self.node_states.iter()
+            .map(|(&node_id, state)| PanelLayoutEntry {
+                node_id,
+                panel_type: state.panel_type,
+                visible: state.visible,
+                open: state.open,
+                minimized: state.minimized,
+                stacked: state.stacked,
+                pinned: state.pinned,
+                custom_name: state.custom_name.clone(),
+                fit_name: state.fit_name,
+                rect: state.rect.map(rect_to_array),
+                viewer_source: state.viewer_source,
+            })
+            .collect();
+        panels.sort_by_key(|entry| entry.node_id);
+        
+        let mut stack_rects: Vec<(PanelType, [f32; 4])> = self.stack_rects.iter()
+            .map(|(&panel_type, &rect)| (panel_type, rect_to_array(rect)))
+            .collect();
+        stack_rects.sort_by_key(|(panel_type, _)| format!("{:?}", panel_type));
+        
+        PanelLayout { panels, stack_rects }
+    }
+    
+    /// Replace all panel state with a loaded layout; saved rects are applied on next draw
+    pub fn import_layout(&mut self, layout: &PanelLayout) {
+        *self = Self::default();
+        for entry in &layout.panels {
+            let state = NodePanelState {
+                visible: entry.visible,
+                open: entry.open,
+                minimized: entry.minimized,
+                stacked: entry.stacked,
+                pinned: entry.pinned,
+                panel_type: entry.panel_type,
+                custom_name: entry.custom_name.clone(),
+                fit_name: entry.fit_name,
+                rect: entry.rect.map(rect_from_array),
+                viewer_source: entry.viewer_source,
+                ..NodePanelState::new()
+            };
+            if state.rect.is_some() {
+                self.pending_rect_restores.insert(entry.node_id);
+            }
+            self.node_states.insert(entry.node_id, state);
+        }
+        for &(panel_type, rect) in &layout.stack_rects {
+            self.stack_rects.insert(panel_type, rect_from_array(rect));
+            self.pending_stack_restores.insert(panel_type);
+        }
+    }
+    
     /// Get unique window ID for unstacked panel (returns None if panel was never unstacked)
     pub fn get_unstacked_panel_id(&self, node_id: NodeId) -> Option<u64> {
         self.get_state(node_id).and_then(|s| s.unstacked_panel_id)
@@ -0,0 +1,57 @@
+//! Build List node functional operations - collecting inputs into a list
+
+use crate::nodes::interface::NodeData;
+
+/// Core Build List configuration
+#[derive(Debug, Clone)]
+pub struct BuildListLogic {
+    /// Leave out unconnected and empty items instead of adding None
+    pub skip_empty: bool,
+}
+
+impl Default for BuildListLogic {
+    fn default() -> Self {
+        Self { skip_empty: true }
+    }
+}
+
+impl BuildListLogic {
+    /// The List input's items followed by every item input
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut inputs = inputs.into_iter();
+        let mut items = inputs.next().as_ref().and_then(as_list).unwrap_or_default();
+        items.extend(inputs.filter(|item| !(self.skip_empty && matches!(item, NodeData::None))));
+        vec![NodeData::List(items)]
+    }
+}
+
+/// Items of a list; buffers count as lists of floats
+pub fn as_list(data: &NodeData) -> Option<Vec<NodeData>> {
+    match data {
+        NodeData::List(items) => Some(items.clone()),
+        NodeData::Buffer(values) => Some(values.iter().map(|v| NodeData::Float(*v)).collect()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_append_to_the_list_input() {
+        let inputs = vec![
+            NodeData::Buffer(vec![1.0]),
+            NodeData::String("a".to_string()),
+            NodeData::None,
+            NodeData::Integer(2),
+        ];
+        let result = BuildListLogic::default().process(inputs.clone());
+        assert!(matches!(&result[0], NodeData::List(items) if items.len() == 3
+            && matches!(items[0], NodeData::Float(v) if v == 1.0)
+            && matches!(items[2], NodeData::Integer(2))));
+
+        let result = BuildListLogic { skip_empty: false }.process(inputs);
+        assert!(matches!(&result[0], NodeData::List(items) if items.len() == 4 && matches!(items[2], NodeData::None)));
+    }
+}
@@ -0,0 +1,45 @@
+//! Build List node module - collecting values into a list
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Appending the item inputs to the List input
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::BuildListLogic;
+pub use parameters::BuildListNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::BuildListNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_Build",
+            "Build List",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Collects its inputs into a list, after the items of the List input"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("🗒")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::optional("List", crate::nodes::DataType::List)
+                .with_description("Items to start from"),
+            crate::nodes::PortDefinition::optional("Item 1", crate::nodes::DataType::Any)
+                .with_description("Value appended to the list; connecting the last item adds another")
+                .variadic(),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("The collected items"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::boolean("skip_empty", "Skip Empty Items", true)
+                .with_tooltip("Leave out unconnected items instead of adding None"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "collection", "build", "append", "items"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,25 @@
+//! Build List node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::BuildListLogic;
+
+/// Build List node
+#[derive(Debug, Clone, Default)]
+pub struct BuildListNode;
+
+impl BuildListNode {
+    /// Build BuildListLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> BuildListLogic {
+        BuildListLogic {
+            skip_empty: node.parameters.get("skip_empty")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(BuildListLogic::default().skip_empty),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,65 @@
+//! Filter node functional operations - keeping the list items that pass a test
+//!
+//! With a Mask connected, items are kept where the mask's item at the same
+//! position is true (or non-zero). Otherwise numeric items are compared
+//! against the node's value like the Compare node does, and non-numeric items
+//! are dropped.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::list::build_list::logic::as_list;
+use crate::nodes::logic::compare::logic::{extract_float, CompareLogic};
+
+/// Core Filter configuration
+#[derive(Debug, Clone, Default)]
+pub struct FilterLogic {
+    /// Test applied as `item <op> value` when no mask is connected
+    pub compare: CompareLogic,
+    /// Right-hand side of the test
+    pub value: f32,
+}
+
+impl FilterLogic {
+    /// The items passing the mask or comparison
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let items = inputs.first().and_then(as_list).unwrap_or_default();
+        let kept = match inputs.get(1).and_then(as_list) {
+            Some(mask) => items.into_iter()
+                .zip(mask.iter().map(|keep| extract_float(keep).is_some_and(|keep| keep != 0.0)))
+                .filter_map(|(item, keep)| keep.then_some(item))
+                .collect(),
+            None => items.into_iter()
+                .filter(|item| extract_float(item).is_some_and(|value| self.compare.compare(value, self.value)))
+                .collect(),
+        };
+        vec![NodeData::List(kept)]
+    }
+
+    /// Short form of the test, such as `> 0.5`
+    pub fn describe(&self) -> String {
+        format!("{} {}", self.compare.operation.symbol(), self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[f32]) -> NodeData {
+        NodeData::List(values.iter().map(|v| NodeData::Float(*v)).collect())
+    }
+
+    fn count(result: &[NodeData]) -> usize {
+        if let NodeData::List(items) = &result[0] { items.len() } else { 0 }
+    }
+
+    #[test]
+    fn test_filter_by_comparison_or_mask() {
+        let logic = FilterLogic { compare: CompareLogic::default(), value: 1.0 };
+        assert_eq!(count(&logic.process(vec![list(&[0.5, 1.5, 2.0, 1.0])])), 2);
+
+        let mask = NodeData::List(vec![NodeData::Boolean(true), NodeData::Boolean(false), NodeData::Integer(1)]);
+        let result = logic.process(vec![list(&[0.5, 1.5, 2.0, 1.0]), mask]);
+        // The mask is shorter than the list; items without a mask entry are dropped
+        assert!(matches!(&result[0], NodeData::List(items) if items.len() == 2 && matches!(items[1], NodeData::Float(v) if v == 2.0)));
+    }
+}
@@ -0,0 +1,53 @@
+//! Filter node module - list items passing a test
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Mask and comparison filtering
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::FilterLogic;
+pub use parameters::FilterNode;
+
+use crate::nodes::logic::compare::logic::CompareOperation;
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::FilterNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_Filter",
+            "Filter",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Keeps the items of a list where a mask is true, or the numbers passing a comparison"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("⧩")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("List to filter"),
+            crate::nodes::PortDefinition::optional("Mask", crate::nodes::DataType::List)
+                .with_description("Booleans choosing the items to keep; replaces the comparison"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("The kept items, in their order"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::choice(
+                "operation",
+                "Keep Items",
+                CompareOperation::ALL.iter().map(|operation| operation.name()).collect(),
+                CompareOperation::Greater.name(),
+            )
+            .with_tooltip("Comparison each numeric item must pass against the value"),
+            crate::nodes::ParameterDefinition::float("value", "Value", 0.0)
+                .with_tooltip("Right-hand side of the comparison"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "filter", "select", "where", "mask", "compare"])
+        .with_subtitle(|node| Some(parameters::FilterNode::create_logic(node).describe()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,31 @@
+//! Filter node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::{CompareLogic, CompareOperation};
+use crate::nodes::Node;
+use super::logic::FilterLogic;
+
+/// Filter node
+#[derive(Debug, Clone, Default)]
+pub struct FilterNode;
+
+impl FilterNode {
+    /// Build FilterLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> FilterLogic {
+        let defaults = CompareLogic::default();
+        FilterLogic {
+            compare: CompareLogic {
+                operation: node.parameters.get("operation")
+                    .and_then(|v| if let NodeData::String(s) = v { Some(CompareOperation::from_name(s)) } else { None })
+                    .unwrap_or(defaults.operation),
+                ..defaults
+            },
+            value: node.float_parameter("value", 0.0),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,46 @@
+//! Get Item node functional operations - indexing into a list
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::list::build_list::logic::as_list;
+use crate::nodes::math::integer_arithmetic::logic::extract_integer;
+
+/// Core Get Item configuration
+#[derive(Debug, Clone, Default)]
+pub struct GetItemLogic {
+    /// Index when the Index input is not connected; negative counts from the end
+    pub index: i32,
+}
+
+impl GetItemLogic {
+    /// The item at the index; None when the index is out of range
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let items = inputs.first().and_then(as_list).unwrap_or_default();
+        let index = inputs.get(1).and_then(extract_integer).unwrap_or(self.index);
+        let item = resolve_index(items.len(), index).and_then(|index| items.into_iter().nth(index));
+        vec![item.unwrap_or(NodeData::None)]
+    }
+}
+
+/// Position of `index` in a list of `len` items, with negative indices counting from the end
+pub fn resolve_index(len: usize, index: i32) -> Option<usize> {
+    let resolved = if index < 0 { len as i64 + index as i64 } else { index as i64 };
+    usize::try_from(resolved).ok().filter(|resolved| *resolved < len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_indices_count_from_the_end() {
+        assert_eq!(resolve_index(3, 0), Some(0));
+        assert_eq!(resolve_index(3, -1), Some(2));
+        assert_eq!(resolve_index(3, 3), None);
+        assert_eq!(resolve_index(3, -4), None);
+
+        let list = NodeData::List(vec![NodeData::Integer(10), NodeData::Integer(20)]);
+        let logic = GetItemLogic { index: -1 };
+        assert!(matches!(logic.process(vec![list.clone()])[0], NodeData::Integer(20)));
+        assert!(matches!(logic.process(vec![list, NodeData::Integer(5)])[0], NodeData::None));
+    }
+}
@@ -0,0 +1,45 @@
+//! Get Item node module - one item of a list by index
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Index resolution
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::GetItemLogic;
+pub use parameters::GetItemNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::GetItemNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_GetItem",
+            "Get Item",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Outputs the item of a list at an index; negative indices count from the end"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("☝")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("List to read from"),
+            crate::nodes::PortDefinition::optional("Index", crate::nodes::DataType::Integer)
+                .with_description("Position of the item; overrides the Index parameter"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Item", crate::nodes::DataType::Any)
+                .with_description("The item, or None when the index is out of range"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::integer("index", "Index", 0)
+                .with_tooltip("Position of the item; -1 is the last item"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "item", "index", "element", "get"])
+        .with_subtitle(|node| Some(format!("[{}]", parameters::GetItemNode::create_logic(node).index)))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
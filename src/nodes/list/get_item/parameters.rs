@@ -0,0 +1,25 @@
+//! Get Item node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::GetItemLogic;
+
+/// Get Item node
+#[derive(Debug, Clone, Default)]
+pub struct GetItemNode;
+
+impl GetItemNode {
+    /// Build GetItemLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> GetItemLogic {
+        GetItemLogic {
+            index: node.parameters.get("index")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
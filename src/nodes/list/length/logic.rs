@@ -0,0 +1,19 @@
+//! Length node functional operations - counting list items
+
+use crate::nodes::interface::NodeData;
+
+/// Core Length functionality; the node has no settings
+#[derive(Debug, Clone, Default)]
+pub struct LengthLogic;
+
+impl LengthLogic {
+    /// Number of items of the list input; anything else has none
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let len = match inputs.first() {
+            Some(NodeData::List(items)) => items.len(),
+            Some(NodeData::Buffer(values)) => values.len(),
+            _ => 0,
+        };
+        vec![NodeData::Integer(len.min(i32::MAX as usize) as i32)]
+    }
+}
@@ -0,0 +1,38 @@
+//! Length node module - the number of items in a list
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Counting the items
+//! - parameters.rs: Execution entry point; the node has no parameters
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::LengthLogic;
+pub use parameters::LengthNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::LengthNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_Length",
+            "Length",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Outputs the number of items in a list"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("#")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("List to count"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Length", crate::nodes::DataType::Integer)
+                .with_description("Number of items"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "length", "count", "size"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
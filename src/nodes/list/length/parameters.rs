@@ -0,0 +1,16 @@
+//! Length node parameters - the node has none
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::LengthLogic;
+
+/// Length node
+#[derive(Debug, Clone, Default)]
+pub struct LengthNode;
+
+impl LengthNode {
+    /// Execute the node on its inputs
+    pub fn process_node(_node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        LengthLogic.process(inputs)
+    }
+}
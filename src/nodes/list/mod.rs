@@ -0,0 +1,8 @@
+//! List nodes - building, reading and reshaping lists of values
+
+pub mod build_list;  // Collect inputs into a list
+pub mod get_item;    // One item by index
+pub mod length;      // Item count
+pub mod slice;       // A run of items
+pub mod sort;        // Items in order
+pub mod filter;      // Items passing a test
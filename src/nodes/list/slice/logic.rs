@@ -0,0 +1,62 @@
+//! Slice node functional operations - taking a run of list items
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::list::build_list::logic::as_list;
+
+/// Core Slice configuration
+#[derive(Debug, Clone)]
+pub struct SliceLogic {
+    /// First item taken; negative counts from the end
+    pub start: i32,
+    /// Items taken; negative takes everything to the end
+    pub count: i32,
+    /// Take every `step`th item
+    pub step: i32,
+}
+
+impl Default for SliceLogic {
+    fn default() -> Self {
+        Self { start: 0, count: -1, step: 1 }
+    }
+}
+
+impl SliceLogic {
+    /// The selected items of the list input
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let items = inputs.first().and_then(as_list).unwrap_or_default();
+        vec![NodeData::List(self.slice(items))]
+    }
+
+    /// `count` items from `start`, every `step`th; a start before the list begins at its first item
+    pub fn slice(&self, items: Vec<NodeData>) -> Vec<NodeData> {
+        let len = items.len() as i64;
+        let start = if self.start < 0 { (len + self.start as i64).max(0) } else { self.start as i64 };
+        let count = if self.count < 0 { usize::MAX } else { self.count as usize };
+        items.into_iter()
+            .skip(start.min(len) as usize)
+            .step_by(self.step.max(1) as usize)
+            .take(count)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(count: i32) -> Vec<NodeData> {
+        (0..count).map(NodeData::Integer).collect()
+    }
+
+    fn values(items: &[NodeData]) -> Vec<i32> {
+        items.iter().filter_map(|item| if let NodeData::Integer(i) = item { Some(*i) } else { None }).collect()
+    }
+
+    #[test]
+    fn test_slices() {
+        assert_eq!(values(&SliceLogic::default().slice(numbers(4))), vec![0, 1, 2, 3]);
+        assert_eq!(values(&SliceLogic { start: 1, count: 2, step: 1 }.slice(numbers(5))), vec![1, 2]);
+        assert_eq!(values(&SliceLogic { start: -3, count: -1, step: 2 }.slice(numbers(5))), vec![2, 4]);
+        assert_eq!(values(&SliceLogic { start: 9, count: -1, step: 1 }.slice(numbers(5))), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,54 @@
+//! Slice node module - a run of items from a list
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Start, count and step selection
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::SliceLogic;
+pub use parameters::SliceNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::SliceNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_Slice",
+            "Slice",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Outputs a run of a list's items from a start position, optionally every few items"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("✂")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("List to take items from"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("The selected items"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::integer("start", "Start", 0)
+                .with_tooltip("First item taken; negative counts from the end"),
+            crate::nodes::ParameterDefinition::integer("count", "Count", -1)
+                .with_tooltip("Number of items taken; -1 takes everything to the end"),
+            crate::nodes::ParameterDefinition::integer("step", "Step", 1)
+                .with_min(1.0)
+                .with_tooltip("Take every Nth item"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "slice", "range", "sublist", "take", "skip"])
+        .with_subtitle(|node| {
+            let logic = parameters::SliceNode::create_logic(node);
+            Some(match logic.count {
+                count if count < 0 => format!("[{}..]", logic.start),
+                count => format!("[{}..+{}]", logic.start, count),
+            })
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
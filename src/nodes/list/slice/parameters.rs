@@ -0,0 +1,29 @@
+//! Slice node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::SliceLogic;
+
+/// Slice node
+#[derive(Debug, Clone, Default)]
+pub struct SliceNode;
+
+impl SliceNode {
+    /// Build SliceLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> SliceLogic {
+        let defaults = SliceLogic::default();
+        let get_int = |name: &str, default: i32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        SliceLogic {
+            start: get_int("start", defaults.start),
+            count: get_int("count", defaults.count),
+            step: get_int("step", defaults.step),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,74 @@
+//! Sort node functional operations - ordering list items
+//!
+//! Numbers (and booleans, as 0 and 1) sort by value, strings by text; numbers
+//! come before strings and other items keep their order after both.
+
+use std::cmp::Ordering;
+use crate::nodes::interface::NodeData;
+use crate::nodes::list::build_list::logic::as_list;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Order names stored in the node's parameter
+pub const SORT_ORDERS: [&str; 2] = ["Ascending", "Descending"];
+
+/// Core Sort configuration
+#[derive(Debug, Clone, Default)]
+pub struct SortLogic {
+    pub descending: bool,
+}
+
+impl SortLogic {
+    /// The list input's items in order
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut items = inputs.first().and_then(as_list).unwrap_or_default();
+        // Stable, so equal items keep their order either way
+        items.sort_by(|a, b| {
+            let ordering = compare_items(a, b);
+            if self.descending && rank(a) == rank(b) { ordering.reverse() } else { ordering }
+        });
+        vec![NodeData::List(items)]
+    }
+}
+
+/// Numbers first, then strings, then everything else
+fn rank(item: &NodeData) -> u8 {
+    match item {
+        _ if extract_float(item).is_some() => 0,
+        NodeData::String(_) => 1,
+        _ => 2,
+    }
+}
+
+/// Ascending order of two list items
+pub fn compare_items(a: &NodeData, b: &NodeData) -> Ordering {
+    match (a, b) {
+        (NodeData::String(a), NodeData::String(b)) => a.cmp(b),
+        _ => match (extract_float(a), extract_float(b)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            _ => rank(a).cmp(&rank(b)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers_then_strings_then_the_rest() {
+        let list = NodeData::List(vec![
+            NodeData::String("b".to_string()),
+            NodeData::None,
+            NodeData::Float(2.5),
+            NodeData::String("a".to_string()),
+            NodeData::Integer(1),
+            NodeData::Integer(3),
+        ]);
+        let describe = |result: Vec<NodeData>| match &result[0] {
+            NodeData::List(items) => items.iter().map(|item| crate::nodes::value_preview::scalar_text(item).unwrap_or_default()).collect::<Vec<_>>(),
+            _ => panic!("Expected a list"),
+        };
+        assert_eq!(describe(SortLogic::default().process(vec![list.clone()])), vec!["1", "2.5000", "3", "\"a\"", "\"b\"", ""]);
+        assert_eq!(describe(SortLogic { descending: true }.process(vec![list])), vec!["3", "2.5000", "1", "\"b\"", "\"a\"", ""]);
+    }
+}
@@ -0,0 +1,43 @@
+//! Sort node module - list items in order
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Ordering numbers and strings
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::SortLogic;
+pub use parameters::SortNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::SortNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "List_Sort",
+            "Sort",
+            crate::nodes::NodeCategory::new(&["List"]),
+            "Sorts a list's numbers by value and strings alphabetically"
+        )
+        .with_color(egui::Color32::from_rgb(80, 80, 100))
+        .with_icon("⇅")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("List to sort"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("List", crate::nodes::DataType::List)
+                .with_description("The items in order: numbers, then strings, then anything else"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::choice("order", "Order", logic::SORT_ORDERS.to_vec(), logic::SORT_ORDERS[0])
+                .with_tooltip("Smallest or largest first"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["list", "array", "sort", "order", "ascending", "descending"])
+        .with_subtitle(|node| Some(logic::SORT_ORDERS[parameters::SortNode::create_logic(node).descending as usize].to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,23 @@
+//! Sort node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{SortLogic, SORT_ORDERS};
+
+/// Sort node
+#[derive(Debug, Clone, Default)]
+pub struct SortNode;
+
+impl SortNode {
+    /// Build SortLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> SortLogic {
+        SortLogic {
+            descending: matches!(node.parameters.get("order"), Some(NodeData::String(order)) if order == SORT_ORDERS[1]),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
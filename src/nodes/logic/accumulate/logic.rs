@@ -0,0 +1,140 @@
+//! Accumulate functional operations - running totals over engine-owned state
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::state_store::NodeStateSlots;
+
+const TOTAL_SLOT: &str = "total";
+const SAMPLES_SLOT: &str = "samples";
+const GENERATION_SLOT: &str = "reset_generation";
+
+/// How incoming values combine with the running total
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccumulateMode {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Average,
+}
+
+impl AccumulateMode {
+    /// All modes in UI order
+    pub const ALL: [AccumulateMode; 5] = [
+        AccumulateMode::Sum,
+        AccumulateMode::Product,
+        AccumulateMode::Min,
+        AccumulateMode::Max,
+        AccumulateMode::Average,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            AccumulateMode::Sum => "Sum",
+            AccumulateMode::Product => "Product",
+            AccumulateMode::Min => "Min",
+            AccumulateMode::Max => "Max",
+            AccumulateMode::Average => "Average",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Sum)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|m| m.name() == name).unwrap_or(AccumulateMode::Sum)
+    }
+}
+
+/// Core Accumulate configuration
+#[derive(Debug, Clone)]
+pub struct AccumulateLogic {
+    pub mode: AccumulateMode,
+    /// Total after a reset (ignored by Min/Max/Average until the first sample)
+    pub initial: f32,
+    /// Value used when the Value input is unconnected
+    pub value: f32,
+    /// Bumped by the panel's Reset button
+    pub reset_generation: i32,
+}
+
+impl Default for AccumulateLogic {
+    fn default() -> Self {
+        Self {
+            mode: AccumulateMode::Sum,
+            initial: 0.0,
+            value: 1.0,
+            reset_generation: 0,
+        }
+    }
+}
+
+impl AccumulateLogic {
+    /// Fold this cook's value into the node's persistent total
+    pub fn process(&self, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let value = match inputs.first() {
+            Some(NodeData::Float(f)) => *f,
+            Some(NodeData::Integer(i)) => *i as f32,
+            _ => self.value,
+        };
+        let reset_input = matches!(inputs.get(1), Some(NodeData::Boolean(true)));
+        // A fresh node accumulates straight away; explicit resets output the initial value
+        let generation_changed = matches!(state.get(GENERATION_SLOT), Some(NodeData::Integer(g)) if *g != self.reset_generation);
+        
+        let (total, samples) = if reset_input || generation_changed {
+            (self.initial, 0)
+        } else {
+            let total = match state.get(TOTAL_SLOT) { Some(NodeData::Float(f)) => *f, _ => self.initial };
+            let samples = match state.get(SAMPLES_SLOT) { Some(NodeData::Integer(i)) => *i, _ => 0 };
+            (self.combine(total, samples, value), samples.saturating_add(1))
+        };
+        
+        state.insert(TOTAL_SLOT.to_string(), NodeData::Float(total));
+        state.insert(SAMPLES_SLOT.to_string(), NodeData::Integer(samples));
+        state.insert(GENERATION_SLOT.to_string(), NodeData::Integer(self.reset_generation));
+        
+        vec![NodeData::Float(total), NodeData::Integer(samples)]
+    }
+    
+    /// Combine the running total (over `samples` values) with a new value
+    pub fn combine(&self, total: f32, samples: i32, value: f32) -> f32 {
+        if samples == 0 && self.mode != AccumulateMode::Sum && self.mode != AccumulateMode::Product {
+            return value;
+        }
+        match self.mode {
+            AccumulateMode::Sum => total + value,
+            AccumulateMode::Product => total * value,
+            AccumulateMode::Min => total.min(value),
+            AccumulateMode::Max => total.max(value),
+            AccumulateMode::Average => total + (value - total) / (samples as f32 + 1.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(result: &[NodeData]) -> f32 {
+        match result[0] {
+            NodeData::Float(f) => f,
+            _ => panic!("Expected Float result"),
+        }
+    }
+
+    #[test]
+    fn test_accumulate_sum_persists_and_resets() {
+        let logic = AccumulateLogic::default();
+        let mut state = NodeStateSlots::new();
+        assert_eq!(total(&logic.process(vec![NodeData::Float(2.0)], &mut state)), 2.0);
+        assert_eq!(total(&logic.process(vec![NodeData::Float(3.0)], &mut state)), 5.0);
+        assert_eq!(total(&logic.process(vec![NodeData::Float(3.0), NodeData::Boolean(true)], &mut state)), 0.0);
+    }
+
+    #[test]
+    fn test_accumulate_average() {
+        let logic = AccumulateLogic { mode: AccumulateMode::Average, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        logic.process(vec![NodeData::Float(2.0)], &mut state);
+        let result = logic.process(vec![NodeData::Float(4.0)], &mut state);
+        assert!((total(&result) - 3.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,44 @@
+//! Accumulate node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (state kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Stateful accumulator folding each cook's input into a running total
+#[derive(Default)]
+pub struct AccumulateNodeFactory;
+
+impl NodeFactory for AccumulateNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Accumulate",
+            "Accumulate",
+            NodeCategory::new(&["Logic", "State"]),
+            "Folds the input into a running sum, product, min, max or average across executions"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("Σ")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value added on each cook"),
+            PortDefinition::optional("Reset", DataType::Boolean)
+                .with_description("Return to the initial value while TRUE"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Total", DataType::Float)
+                .with_description("Accumulated value"),
+            PortDefinition::optional("Samples", DataType::Integer)
+                .with_description("Number of values accumulated since the last reset"),
+        ])
+        .with_tags(vec!["logic", "state", "accumulate", "sum", "running", "average", "integrate"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
@@ -0,0 +1,88 @@
+//! Accumulate node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::{AccumulateLogic, AccumulateMode};
+
+/// Accumulate node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct AccumulateNode;
+
+impl AccumulateNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Accumulate Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Mode:");
+            let mut mode = logic.mode;
+            egui::ComboBox::from_id_salt("accumulate_mode")
+                .selected_text(mode.name())
+                .show_ui(ui, |ui| {
+                    for m in AccumulateMode::ALL {
+                        if ui.selectable_value(&mut mode, m, m.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "mode".to_string(),
+                                value: NodeData::String(m.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        for (param, label, current) in [
+            ("initial", "Initial:", logic.initial),
+            ("value", "Value:", logic.value),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(0.1)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Float(value),
+                    });
+                }
+            });
+        }
+        
+        ui.separator();
+        
+        if ui.button("Reset").clicked() {
+            changes.push(ParameterChange {
+                parameter: "reset_generation".to_string(),
+                value: NodeData::Integer(logic.reset_generation.wrapping_add(1)),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build AccumulateLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> AccumulateLogic {
+        let defaults = AccumulateLogic::default();
+        let get_float = |name: &str, default: f32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        AccumulateLogic {
+            mode: node.parameters.get("mode")
+                .and_then(|v| if let NodeData::String(s) = v { Some(AccumulateMode::from_name(s)) } else { None })
+                .unwrap_or(defaults.mode),
+            initial: get_float("initial", defaults.initial),
+            value: get_float("value", defaults.value),
+            reset_generation: node.parameters.get("reset_generation")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(defaults.reset_generation),
+        }
+    }
+    
+    /// Execute the node against its persistent state
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs, state)
+    }
+}
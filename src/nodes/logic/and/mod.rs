@@ -12,6 +12,7 @@ pub mod parameters;
 
 use egui::Color32;
 use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
 
 /// AND logic node that performs boolean AND operation
 #[derive(Default)]
@@ -29,9 +30,11 @@ impl NodeFactory for AndNodeFactory {
         .with_icon("&")
         .with_inputs(vec![
             PortDefinition::optional("A", DataType::Boolean)
-                .with_description("First boolean input"),
+                .with_description("First boolean input")
+                .with_default(NodeData::Boolean(false)),
             PortDefinition::optional("B", DataType::Boolean)
-                .with_description("Second boolean input"),
+                .with_description("Second boolean input")
+                .with_default(NodeData::Boolean(false)),
         ])
         .with_outputs(vec![
             PortDefinition::required("Result", DataType::Boolean)
@@ -0,0 +1,42 @@
+//! Bool to Float functional operations - boolean conversion logic
+
+use crate::nodes::interface::NodeData;
+
+/// Core Bool to Float data and functionality
+#[derive(Debug, Clone)]
+pub struct BoolToFloatLogic {
+    /// Input value when not connected
+    pub input: bool,
+    /// Output when the input is TRUE
+    pub true_value: f32,
+    /// Output when the input is FALSE
+    pub false_value: f32,
+}
+
+impl Default for BoolToFloatLogic {
+    fn default() -> Self {
+        Self {
+            input: false,
+            true_value: 1.0,
+            false_value: 0.0,
+        }
+    }
+}
+
+impl BoolToFloatLogic {
+    /// Process input data and perform the conversion
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let input = match inputs.first() {
+            Some(NodeData::Boolean(b)) => *b,
+            Some(NodeData::Float(f)) => *f != 0.0,
+            Some(NodeData::Integer(i)) => *i != 0,
+            _ => self.input,
+        };
+        vec![NodeData::Float(self.convert(input))]
+    }
+    
+    /// Map a boolean onto the configured values
+    pub fn convert(&self, value: bool) -> f32 {
+        if value { self.true_value } else { self.false_value }
+    }
+}
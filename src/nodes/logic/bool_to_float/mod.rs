@@ -0,0 +1,40 @@
+//! Bool to Float node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Converts a boolean into a configurable float value
+#[derive(Default)]
+pub struct BoolToFloatNodeFactory;
+
+impl NodeFactory for BoolToFloatNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_BoolToFloat",
+            "Bool to Float",
+            NodeCategory::new(&["Logic", "Conversion"]),
+            "Converts a boolean into a float (TRUE and FALSE values are configurable)"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("⇢")
+        .with_inputs(vec![
+            PortDefinition::optional("Input", DataType::Boolean)
+                .with_description("Boolean to convert"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Value", DataType::Float)
+                .with_description("True value or false value"),
+        ])
+        .with_tags(vec!["logic", "boolean", "float", "convert", "conversion"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
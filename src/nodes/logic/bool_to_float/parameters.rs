@@ -0,0 +1,66 @@
+//! Bool to Float node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::BoolToFloatLogic;
+
+/// Bool to Float node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct BoolToFloatNode;
+
+impl BoolToFloatNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Bool to Float Parameters");
+        ui.separator();
+        
+        let mut input = logic.input;
+        if ui.checkbox(&mut input, "Input").changed() {
+            changes.push(ParameterChange {
+                parameter: "input".to_string(),
+                value: NodeData::Boolean(input),
+            });
+        }
+        
+        for (param, label, current) in [
+            ("true_value", "True Value:", logic.true_value),
+            ("false_value", "False Value:", logic.false_value),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(0.1)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Float(value),
+                    });
+                }
+            });
+        }
+        
+        ui.separator();
+        ui.label(format!("Current Result: {}", logic.convert(logic.input)));
+        
+        changes
+    }
+    
+    /// Build BoolToFloatLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> BoolToFloatLogic {
+        let defaults = BoolToFloatLogic::default();
+        BoolToFloatLogic {
+            input: node.parameters.get("input")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.input),
+            true_value: node.float_parameter("true_value", defaults.true_value),
+            false_value: node.float_parameter("false_value", defaults.false_value),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
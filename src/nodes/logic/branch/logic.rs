@@ -0,0 +1,76 @@
+//! Branch node functional operations - picking one of two inputs
+//!
+//! The execution engine asks which input a branch leaves untaken before
+//! cooking, and skips nodes that only feed that input.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Input port of the condition
+pub const CONDITION_INPUT: usize = 0;
+/// Input port passed on when the condition holds
+pub const TRUE_INPUT: usize = 1;
+/// Input port passed on when the condition does not hold
+pub const FALSE_INPUT: usize = 2;
+
+/// Core Branch data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct BranchLogic {
+    /// Condition when the Condition input is not connected
+    pub condition: bool,
+}
+
+impl BranchLogic {
+    /// Pass on the input the condition picks
+    pub fn process(&self, mut inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let condition = self.resolve(inputs.get(CONDITION_INPUT));
+        let picked = Self::taken_input(condition);
+        let value = if picked < inputs.len() {
+            inputs.swap_remove(picked)
+        } else {
+            NodeData::None
+        };
+        vec![value]
+    }
+
+    /// The condition from the input's value, or the parameter when nothing usable arrives
+    pub fn resolve(&self, input: Option<&NodeData>) -> bool {
+        input.and_then(extract_float)
+            .map(|value| value != 0.0)
+            .unwrap_or(self.condition)
+    }
+
+    /// Input port passed on for `condition`
+    pub fn taken_input(condition: bool) -> usize {
+        if condition { TRUE_INPUT } else { FALSE_INPUT }
+    }
+
+    /// Input port left alone for `condition`
+    pub fn untaken_input(condition: bool) -> usize {
+        Self::taken_input(!condition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_branch_passes_the_picked_input() {
+        let logic = BranchLogic::default();
+        let inputs = |condition| vec![condition, NodeData::String("yes".to_string()), NodeData::Float(2.0)];
+        assert!(matches!(&logic.process(inputs(NodeData::Boolean(true)))[0], NodeData::String(s) if s == "yes"));
+        assert!(matches!(logic.process(inputs(NodeData::Boolean(false)))[0], NodeData::Float(f) if f == 2.0));
+        // Numbers count as true when non-zero
+        assert!(matches!(logic.process(inputs(NodeData::Integer(3)))[0], NodeData::String(_)));
+    }
+
+    #[test]
+    fn test_unconnected_condition_uses_the_parameter() {
+        let logic = BranchLogic { condition: true };
+        assert!(logic.resolve(Some(&NodeData::None)));
+        assert!(!logic.resolve(Some(&NodeData::Boolean(false))));
+        assert_eq!(BranchLogic::untaken_input(true), FALSE_INPUT);
+        assert_eq!(BranchLogic::untaken_input(false), TRUE_INPUT);
+    }
+}
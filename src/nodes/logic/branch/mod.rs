@@ -0,0 +1,45 @@
+//! Branch node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Branch node that passes on one of two inputs depending on a condition
+#[derive(Default)]
+pub struct BranchNodeFactory;
+
+impl NodeFactory for BranchNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Branch",
+            "If / Branch",
+            NodeCategory::new(&["Logic", "Flow"]),
+            "Outputs True or False depending on the condition; nodes feeding only the other input are not cooked"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("⑂")
+        .with_inputs(vec![
+            PortDefinition::optional("Condition", DataType::Boolean)
+                .with_description("Which input to pass on; numbers count as true when non-zero"),
+            PortDefinition::optional("True", DataType::Any)
+                .with_description("Passed on when the condition holds"),
+            PortDefinition::optional("False", DataType::Any)
+                .with_description("Passed on when the condition does not hold"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Any)
+                .with_description("The input picked by the condition"),
+        ])
+        .with_tags(vec!["logic", "if", "branch", "switch", "condition", "lazy"])
+        .with_subtitle(|node| Some(if parameters::BranchNode::create_logic(node).condition { "True" } else { "False" }.to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
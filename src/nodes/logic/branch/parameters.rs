@@ -0,0 +1,48 @@
+//! Branch node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::BranchLogic;
+
+/// Branch node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct BranchNode;
+
+impl BranchNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Branch Parameters");
+        ui.separator();
+        
+        // Fallback condition when not connected
+        let mut condition = logic.condition;
+        if ui.checkbox(&mut condition, "Condition").changed() {
+            changes.push(ParameterChange {
+                parameter: "condition".to_string(),
+                value: NodeData::Boolean(condition),
+            });
+        }
+        
+        ui.separator();
+        ui.label("Nodes that only feed the input not taken are left uncooked.");
+        
+        changes
+    }
+    
+    /// Build BranchLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> BranchLogic {
+        BranchLogic {
+            condition: node.parameters.get("condition")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,156 @@
+//! Compare node functional operations - numeric comparison logic
+
+use crate::nodes::interface::NodeData;
+
+/// Comparison operator applied as `A <op> B`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOperation {
+    Greater,
+    Less,
+    GreaterEqual,
+    LessEqual,
+    Equal,
+    NotEqual,
+}
+
+impl CompareOperation {
+    /// All operations in UI order
+    pub const ALL: [CompareOperation; 6] = [
+        CompareOperation::Greater,
+        CompareOperation::Less,
+        CompareOperation::GreaterEqual,
+        CompareOperation::LessEqual,
+        CompareOperation::Equal,
+        CompareOperation::NotEqual,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompareOperation::Greater => "Greater",
+            CompareOperation::Less => "Less",
+            CompareOperation::GreaterEqual => "Greater or Equal",
+            CompareOperation::LessEqual => "Less or Equal",
+            CompareOperation::Equal => "Equal",
+            CompareOperation::NotEqual => "Not Equal",
+        }
+    }
+    
+    /// Operator symbol for display
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CompareOperation::Greater => ">",
+            CompareOperation::Less => "<",
+            CompareOperation::GreaterEqual => ">=",
+            CompareOperation::LessEqual => "<=",
+            CompareOperation::Equal => "==",
+            CompareOperation::NotEqual => "!=",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Greater)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter()
+            .copied()
+            .find(|op| op.name() == name)
+            .unwrap_or(CompareOperation::Greater)
+    }
+}
+
+/// Defaults of the A and B inputs
+pub const INPUT_DEFAULTS: [f32; 2] = [0.0, 0.0];
+
+/// Core Compare data and functionality
+#[derive(Debug, Clone)]
+pub struct CompareLogic {
+    /// Comparison operator
+    pub operation: CompareOperation,
+    /// Tolerance used by Equal/Not Equal and the "or Equal" operations
+    pub epsilon: f32,
+}
+
+impl Default for CompareLogic {
+    fn default() -> Self {
+        Self {
+            operation: CompareOperation::Greater,
+            epsilon: 1e-5,
+        }
+    }
+}
+
+impl CompareLogic {
+    /// Process input data and perform the comparison; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let b = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        vec![NodeData::Boolean(self.compare(a, b))]
+    }
+    
+    /// Compare two values with the configured operation
+    pub fn compare(&self, a: f32, b: f32) -> bool {
+        let epsilon = self.epsilon.abs();
+        match self.operation {
+            CompareOperation::Greater => a > b,
+            CompareOperation::Less => a < b,
+            CompareOperation::GreaterEqual => a >= b - epsilon,
+            CompareOperation::LessEqual => a <= b + epsilon,
+            CompareOperation::Equal => (a - b).abs() <= epsilon,
+            CompareOperation::NotEqual => (a - b).abs() > epsilon,
+        }
+    }
+}
+
+/// Extract a numeric value from node data
+pub fn extract_float(data: &NodeData) -> Option<f32> {
+    match data {
+        NodeData::Float(f) => Some(*f),
+        NodeData::Integer(i) => Some(*i as f32),
+        NodeData::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_equal_within_epsilon() {
+        let logic = CompareLogic { operation: CompareOperation::Equal, epsilon: 0.01, ..Default::default() };
+        assert!(logic.compare(1.0, 1.005));
+        assert!(!logic.compare(1.0, 1.02));
+    }
+
+    #[test]
+    fn test_compare_not_equal_within_epsilon() {
+        let logic = CompareLogic { operation: CompareOperation::NotEqual, epsilon: 0.01, ..Default::default() };
+        assert!(!logic.compare(1.0, 1.005));
+        assert!(logic.compare(1.0, 1.02));
+    }
+
+    #[test]
+    fn test_compare_or_equal_within_epsilon() {
+        let logic = CompareLogic { operation: CompareOperation::GreaterEqual, epsilon: 0.01, ..Default::default() };
+        assert!(logic.compare(0.995, 1.0));
+        assert!(!logic.compare(0.98, 1.0));
+        let logic = CompareLogic { operation: CompareOperation::LessEqual, ..logic };
+        assert!(logic.compare(1.005, 1.0));
+        assert!(!logic.compare(1.02, 1.0));
+    }
+
+    #[test]
+    fn test_compare_process_uses_fallbacks() {
+        let logic = CompareLogic::default();
+        let result = logic.process(vec![NodeData::None, NodeData::Float(-1.0)]);
+        assert!(matches!(result[0], NodeData::Boolean(true)));
+        let result = logic.process(vec![NodeData::Float(3.0)]);
+        assert!(matches!(result[0], NodeData::Boolean(true)));
+        let result = logic.process(vec![NodeData::None, NodeData::Float(5.0)]);
+        assert_eq!(result.len(), 1);
+        if let NodeData::Boolean(value) = &result[0] {
+            assert!(!value);
+        } else {
+            panic!("Expected Boolean result");
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Compare node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Compare node that produces a boolean from two numeric inputs
+#[derive(Default)]
+pub struct CompareNodeFactory;
+
+impl NodeFactory for CompareNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Compare",
+            "Compare",
+            NodeCategory::new(&["Logic", "Comparison"]),
+            "Compares two numbers (>, <, >=, <=, ==, !=) with an epsilon tolerance for equality"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("⋚")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Float)
+                .with_description("Left-hand value")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("B", DataType::Float)
+                .with_description("Right-hand value")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Boolean)
+                .with_description("Result of A <op> B"),
+        ])
+        .with_tags(vec!["logic", "compare", "comparison", "greater", "less", "equal", "epsilon"])
+        .with_subtitle(|node| Some(format!("A {} B", parameters::CompareNode::create_logic(node).operation.symbol())))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
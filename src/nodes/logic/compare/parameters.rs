@@ -0,0 +1,68 @@
+//! Compare node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{CompareLogic, CompareOperation};
+
+/// Compare node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct CompareNode;
+
+impl CompareNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (A and B are edited under the generated Input Defaults)
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Compare Parameters");
+        ui.separator();
+        
+        // Operation selection
+        ui.horizontal(|ui| {
+            ui.label("Operation:");
+            let mut operation = logic.operation;
+            egui::ComboBox::from_id_salt("compare_operation")
+                .selected_text(format!("{} ({})", operation.name(), operation.symbol()))
+                .show_ui(ui, |ui| {
+                    for op in CompareOperation::ALL {
+                        if ui.selectable_value(&mut operation, op, format!("{} ({})", op.name(), op.symbol())).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "operation".to_string(),
+                                value: NodeData::String(op.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Epsilon:");
+            let mut epsilon = logic.epsilon;
+            if ui.add(egui::DragValue::new(&mut epsilon).speed(0.0001).range(0.0..=1.0).max_decimals(6)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "epsilon".to_string(),
+                    value: NodeData::Float(epsilon),
+                });
+            }
+        });
+        
+        changes
+    }
+    
+    /// Build CompareLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> CompareLogic {
+        let defaults = CompareLogic::default();
+        CompareLogic {
+            operation: node.parameters.get("operation")
+                .and_then(|v| if let NodeData::String(s) = v { Some(CompareOperation::from_name(s)) } else { None })
+                .unwrap_or(defaults.operation),
+            epsilon: node.float_parameter("epsilon", defaults.epsilon),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,116 @@
+//! Counter functional operations - stepping logic over engine-owned state
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::state_store::NodeStateSlots;
+
+const COUNT_SLOT: &str = "count";
+const LAST_TRIGGER_SLOT: &str = "last_trigger";
+const GENERATION_SLOT: &str = "reset_generation";
+
+/// Core Counter configuration
+#[derive(Debug, Clone)]
+pub struct CounterLogic {
+    /// Value after a reset
+    pub start: i32,
+    /// Amount added per advance
+    pub step: i32,
+    /// Wrap range length (0 = never wrap)
+    pub wrap_at: i32,
+    /// Bumped by the panel's Reset button
+    pub reset_generation: i32,
+}
+
+impl Default for CounterLogic {
+    fn default() -> Self {
+        Self {
+            start: 0,
+            step: 1,
+            wrap_at: 0,
+            reset_generation: 0,
+        }
+    }
+}
+
+impl CounterLogic {
+    /// Advance the counter using (and updating) the node's persistent state
+    pub fn process(&self, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let trigger = match inputs.first() {
+            Some(NodeData::Boolean(b)) => Some(*b),
+            _ => None,
+        };
+        let reset_input = matches!(inputs.get(1), Some(NodeData::Boolean(true)));
+        let generation_changed = !matches!(state.get(GENERATION_SLOT), Some(NodeData::Integer(g)) if *g == self.reset_generation);
+        
+        let current = match state.get(COUNT_SLOT) {
+            Some(NodeData::Integer(count)) if !reset_input && !generation_changed => Some(*count),
+            _ => None,
+        };
+        
+        let count = match current {
+            // Fresh or reset: start over without advancing
+            None => self.start,
+            Some(count) => {
+                let advance = match trigger {
+                    Some(now) => now && !matches!(state.get(LAST_TRIGGER_SLOT), Some(NodeData::Boolean(true))),
+                    None => true,
+                };
+                if advance { self.wrap(count.wrapping_add(self.step)) } else { count }
+            }
+        };
+        
+        state.insert(COUNT_SLOT.to_string(), NodeData::Integer(count));
+        state.insert(LAST_TRIGGER_SLOT.to_string(), NodeData::Boolean(trigger.unwrap_or(false)));
+        state.insert(GENERATION_SLOT.to_string(), NodeData::Integer(self.reset_generation));
+        
+        vec![NodeData::Integer(count)]
+    }
+    
+    /// Keep the value within [start, start + wrap_at) when wrapping is enabled
+    pub fn wrap(&self, value: i32) -> i32 {
+        if self.wrap_at > 0 {
+            self.start + (value - self.start).rem_euclid(self.wrap_at)
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(result: &[NodeData]) -> i32 {
+        match result[0] {
+            NodeData::Integer(i) => i,
+            _ => panic!("Expected Integer result"),
+        }
+    }
+
+    #[test]
+    fn test_counter_advances_each_cook() {
+        let logic = CounterLogic::default();
+        let mut state = NodeStateSlots::new();
+        assert_eq!(count(&logic.process(vec![NodeData::None, NodeData::None], &mut state)), 0);
+        assert_eq!(count(&logic.process(vec![NodeData::None, NodeData::None], &mut state)), 1);
+        assert_eq!(count(&logic.process(vec![NodeData::None, NodeData::None], &mut state)), 2);
+    }
+
+    #[test]
+    fn test_counter_rising_edge_and_reset() {
+        let logic = CounterLogic::default();
+        let mut state = NodeStateSlots::new();
+        logic.process(vec![NodeData::Boolean(false), NodeData::None], &mut state);
+        assert_eq!(count(&logic.process(vec![NodeData::Boolean(true), NodeData::None], &mut state)), 1);
+        // Held trigger does not advance again
+        assert_eq!(count(&logic.process(vec![NodeData::Boolean(true), NodeData::None], &mut state)), 1);
+        assert_eq!(count(&logic.process(vec![NodeData::Boolean(false), NodeData::Boolean(true)], &mut state)), 0);
+    }
+
+    #[test]
+    fn test_counter_wraps() {
+        let logic = CounterLogic { wrap_at: 3, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        let values: Vec<i32> = (0..5).map(|_| count(&logic.process(vec![], &mut state))).collect();
+        assert_eq!(values, vec![0, 1, 2, 0, 1]);
+    }
+}
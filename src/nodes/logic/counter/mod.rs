@@ -0,0 +1,42 @@
+//! Counter node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (state kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Stateful counter that advances each cook or on trigger rising edges
+#[derive(Default)]
+pub struct CounterNodeFactory;
+
+impl NodeFactory for CounterNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Counter",
+            "Counter",
+            NodeCategory::new(&["Logic", "State"]),
+            "Counts cooks (or Trigger rising edges) and keeps its value between executions"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("⏲")
+        .with_inputs(vec![
+            PortDefinition::optional("Trigger", DataType::Boolean)
+                .with_description("Advance on FALSE→TRUE; when unconnected every cook advances"),
+            PortDefinition::optional("Reset", DataType::Boolean)
+                .with_description("Return to the start value while TRUE"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Count", DataType::Integer)
+                .with_description("Current counter value"),
+        ])
+        .with_tags(vec!["logic", "state", "counter", "increment", "iteration", "frame"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
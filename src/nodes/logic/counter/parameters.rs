@@ -0,0 +1,69 @@
+//! Counter node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::CounterLogic;
+
+/// Counter node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct CounterNode;
+
+impl CounterNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Counter Parameters");
+        ui.separator();
+        
+        for (param, label, current) in [
+            ("start", "Start:", logic.start),
+            ("step", "Step:", logic.step),
+            ("wrap_at", "Wrap At:", logic.wrap_at),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(1.0)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Integer(value),
+                    });
+                }
+            });
+        }
+        ui.label("Wrap At 0 disables wrapping");
+        
+        ui.separator();
+        
+        if ui.button("Reset").clicked() {
+            changes.push(ParameterChange {
+                parameter: "reset_generation".to_string(),
+                value: NodeData::Integer(logic.reset_generation.wrapping_add(1)),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build CounterLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> CounterLogic {
+        let defaults = CounterLogic::default();
+        let get_int = |name: &str, default: i32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        CounterLogic {
+            start: get_int("start", defaults.start),
+            step: get_int("step", defaults.step),
+            wrap_at: get_int("wrap_at", defaults.wrap_at),
+            reset_generation: get_int("reset_generation", defaults.reset_generation),
+        }
+    }
+    
+    /// Execute the node against its persistent state
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs, state)
+    }
+}
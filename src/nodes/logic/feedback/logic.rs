@@ -0,0 +1,168 @@
+//! Feedback functional operations - frame-to-frame state over the engine's state store
+//!
+//! The node steps once per timeline frame. Cooking the same frame again (for
+//! a parameter change) recomputes that frame's step from the value carried
+//! into it instead of stepping twice. Going back in time, reaching the reset
+//! frame, the Reset input and the panel's Reset button all start over.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+use crate::nodes::state_store::NodeStateSlots;
+
+const FRAME_SLOT: &str = "frame";
+const CARRY_IN_SLOT: &str = "carry_in";
+const CARRY_SLOT: &str = "carry";
+const GENERATION_SLOT: &str = "reset_generation";
+
+/// What the node carries between frames
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedbackMode {
+    /// Outputs the input of the previous frame
+    Previous,
+    /// Moves toward the input by a fraction each frame
+    Smooth,
+    /// Adds the input each frame
+    Accumulate,
+}
+
+impl FeedbackMode {
+    /// All modes in UI order
+    pub const ALL: [FeedbackMode; 3] = [
+        FeedbackMode::Previous,
+        FeedbackMode::Smooth,
+        FeedbackMode::Accumulate,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            FeedbackMode::Previous => "Previous Frame",
+            FeedbackMode::Smooth => "Smooth",
+            FeedbackMode::Accumulate => "Accumulate",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Previous Frame)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|m| m.name() == name).unwrap_or(FeedbackMode::Previous)
+    }
+}
+
+/// Core Feedback configuration
+#[derive(Debug, Clone)]
+pub struct FeedbackLogic {
+    pub mode: FeedbackMode,
+    /// Carried value after a reset; Smooth starts from the input instead
+    pub initial: f32,
+    /// Value used when the Value input is unconnected
+    pub value: f32,
+    /// Share of the previous value kept each frame by Smooth, 0..1
+    pub smoothing: f32,
+    /// Frame at or before which the node resets (the start of playback)
+    pub reset_frame: i32,
+    /// Bumped by the panel's Reset button
+    pub reset_generation: i32,
+}
+
+impl Default for FeedbackLogic {
+    fn default() -> Self {
+        Self {
+            mode: FeedbackMode::Previous,
+            initial: 0.0,
+            value: 0.0,
+            smoothing: 0.8,
+            reset_frame: 1,
+            reset_generation: 0,
+        }
+    }
+}
+
+impl FeedbackLogic {
+    /// Step the node's carried value to `frame`
+    pub fn process(&self, inputs: Vec<NodeData>, frame: i64, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(self.value);
+        let frame = frame.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        let reset_input = matches!(inputs.get(1), Some(NodeData::Boolean(true)));
+        let generation_changed = matches!(state.get(GENERATION_SLOT), Some(NodeData::Integer(g)) if *g != self.reset_generation);
+        let last_frame = match state.get(FRAME_SLOT) { Some(NodeData::Integer(f)) => Some(*f), _ => None };
+        let stored = |slot: &str| match state.get(slot) { Some(NodeData::Float(f)) => Some(*f), _ => None };
+        
+        let carry_in = match last_frame {
+            _ if reset_input || generation_changed || frame <= self.reset_frame => None,
+            Some(last) if frame == last => stored(CARRY_IN_SLOT),
+            Some(last) if frame > last => stored(CARRY_SLOT),
+            // A new node, or time went backwards
+            _ => None,
+        };
+        let carry_in = carry_in.unwrap_or(match self.mode {
+            FeedbackMode::Smooth => value,
+            _ => self.initial,
+        });
+        let (carry, result) = self.step(carry_in, value);
+        
+        state.insert(FRAME_SLOT.to_string(), NodeData::Integer(frame));
+        state.insert(CARRY_IN_SLOT.to_string(), NodeData::Float(carry_in));
+        state.insert(CARRY_SLOT.to_string(), NodeData::Float(carry));
+        state.insert(GENERATION_SLOT.to_string(), NodeData::Integer(self.reset_generation));
+        
+        vec![NodeData::Float(result), NodeData::Float(carry_in)]
+    }
+    
+    /// One frame's step: the value carried on and the value output
+    pub fn step(&self, carry_in: f32, value: f32) -> (f32, f32) {
+        match self.mode {
+            FeedbackMode::Previous => (value, carry_in),
+            FeedbackMode::Smooth => {
+                let smoothed = value + (carry_in - value) * self.smoothing.clamp(0.0, 1.0);
+                (smoothed, smoothed)
+            }
+            FeedbackMode::Accumulate => (carry_in + value, carry_in + value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(output: &[NodeData]) -> f32 {
+        match output[0] {
+            NodeData::Float(f) => f,
+            _ => panic!("Expected Float result"),
+        }
+    }
+
+    #[test]
+    fn test_previous_frame_steps_once_per_frame() {
+        let logic = FeedbackLogic { initial: -1.0, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        assert_eq!(result(&logic.process(vec![NodeData::Float(10.0)], 1, &mut state)), -1.0);
+        assert_eq!(result(&logic.process(vec![NodeData::Float(20.0)], 2, &mut state)), 10.0);
+        // Cooking frame 2 again doesn't step
+        assert_eq!(result(&logic.process(vec![NodeData::Float(25.0)], 2, &mut state)), 10.0);
+        assert_eq!(result(&logic.process(vec![NodeData::Float(30.0)], 3, &mut state)), 25.0);
+    }
+
+    #[test]
+    fn test_smoothing_and_resets() {
+        let logic = FeedbackLogic { mode: FeedbackMode::Smooth, smoothing: 0.5, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        // Smoothing starts from the first input
+        assert_eq!(result(&logic.process(vec![NodeData::Float(4.0)], 1, &mut state)), 4.0);
+        assert_eq!(result(&logic.process(vec![NodeData::Float(0.0)], 2, &mut state)), 2.0);
+        assert_eq!(result(&logic.process(vec![NodeData::Float(0.0)], 3, &mut state)), 1.0);
+        // Rewinding and the Reset input both start over
+        assert_eq!(result(&logic.process(vec![NodeData::Float(8.0)], 2, &mut state)), 8.0);
+        assert_eq!(result(&logic.process(vec![NodeData::Float(6.0), NodeData::Boolean(true)], 3, &mut state)), 6.0);
+    }
+
+    #[test]
+    fn test_accumulate_per_frame() {
+        let logic = FeedbackLogic { mode: FeedbackMode::Accumulate, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        for frame in 1..=4 {
+            logic.process(vec![NodeData::Float(1.5)], frame, &mut state);
+        }
+        assert_eq!(result(&logic.process(vec![NodeData::Float(1.5)], 4, &mut state)), 6.0);
+    }
+}
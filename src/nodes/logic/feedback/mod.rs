@@ -0,0 +1,45 @@
+//! Feedback node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (state kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Stateful node stepping a carried value once per timeline frame
+#[derive(Default)]
+pub struct FeedbackNodeFactory;
+
+impl NodeFactory for FeedbackNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Feedback",
+            "Feedback",
+            NodeCategory::new(&["Logic", "State"]),
+            "Carries a value from frame to frame: the previous frame's input, an exponentially smoothed input, or a per-frame running total"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("↺")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value fed in on each frame"),
+            PortDefinition::optional("Reset", DataType::Boolean)
+                .with_description("Return to the initial value while TRUE"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("Delayed, smoothed or accumulated value"),
+            PortDefinition::optional("Previous", DataType::Float)
+                .with_description("Value carried in from the previous frame"),
+        ])
+        .with_tags(vec!["logic", "state", "feedback", "previous", "delay", "smooth", "lag", "damp", "accumulate", "frame"])
+        .with_subtitle(|node| Some(parameters::FeedbackNode::create_logic(node).mode.name().to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General", "3D"])
+    }
+}
@@ -0,0 +1,116 @@
+//! Feedback node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::{FeedbackLogic, FeedbackMode};
+
+/// Feedback node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct FeedbackNode;
+
+impl FeedbackNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Feedback Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Mode:");
+            let mut mode = logic.mode;
+            egui::ComboBox::from_id_salt("feedback_mode")
+                .selected_text(mode.name())
+                .show_ui(ui, |ui| {
+                    for m in FeedbackMode::ALL {
+                        if ui.selectable_value(&mut mode, m, m.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "mode".to_string(),
+                                value: NodeData::String(m.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        if logic.mode == FeedbackMode::Smooth {
+            ui.horizontal(|ui| {
+                ui.label("Smoothing:");
+                let mut smoothing = logic.smoothing;
+                if ui.add(egui::Slider::new(&mut smoothing, 0.0..=1.0)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: "smoothing".to_string(),
+                        value: NodeData::Float(smoothing),
+                    });
+                }
+            });
+        }
+        
+        for (param, label, current) in [
+            ("initial", "Initial:", logic.initial),
+            ("value", "Value:", logic.value),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(0.1)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Float(value),
+                    });
+                }
+            });
+        }
+        
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Reset at frame:");
+            let mut reset_frame = logic.reset_frame;
+            if ui.add(egui::DragValue::new(&mut reset_frame).speed(1.0)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "reset_frame".to_string(),
+                    value: NodeData::Integer(reset_frame),
+                });
+            }
+        });
+        ui.label("Also resets when the timeline moves backwards.");
+        
+        if ui.button("Reset").clicked() {
+            changes.push(ParameterChange {
+                parameter: "reset_generation".to_string(),
+                value: NodeData::Integer(logic.reset_generation.wrapping_add(1)),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build FeedbackLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> FeedbackLogic {
+        let defaults = FeedbackLogic::default();
+        let get_float = |name: &str, default: f32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        let get_int = |name: &str, default: i32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        FeedbackLogic {
+            mode: node.parameters.get("mode")
+                .and_then(|v| if let NodeData::String(s) = v { Some(FeedbackMode::from_name(s)) } else { None })
+                .unwrap_or(defaults.mode),
+            initial: get_float("initial", defaults.initial),
+            value: get_float("value", defaults.value),
+            smoothing: get_float("smoothing", defaults.smoothing),
+            reset_frame: get_int("reset_frame", defaults.reset_frame),
+            reset_generation: get_int("reset_generation", defaults.reset_generation),
+        }
+    }
+    
+    /// Execute the node against its persistent state at the timeline frame
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, frame: i64, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs, frame, state)
+    }
+}
@@ -0,0 +1,63 @@
+//! Hold functional operations - latching logic over engine-owned state
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::state_store::NodeStateSlots;
+
+const HELD_SLOT: &str = "held";
+
+/// Core Hold configuration
+#[derive(Debug, Clone, Default)]
+pub struct HoldLogic {
+    /// Hold state used when the Hold input is unconnected
+    pub hold: bool,
+}
+
+impl HoldLogic {
+    /// Pass the input through, or return the latched value while holding
+    pub fn process(&self, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let hold = match inputs.get(1) {
+            Some(NodeData::Boolean(b)) => *b,
+            _ => self.hold,
+        };
+        let value = inputs.into_iter().next().unwrap_or(NodeData::None);
+        
+        if hold {
+            // Latch whatever arrived last before holding; the first cook latches the current value
+            let held = state.entry(HELD_SLOT.to_string()).or_insert(value).clone();
+            vec![held, NodeData::Boolean(true)]
+        } else {
+            state.insert(HELD_SLOT.to_string(), value.clone());
+            vec![value, NodeData::Boolean(false)]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold_latches_last_value() {
+        let logic = HoldLogic::default();
+        let mut state = NodeStateSlots::new();
+        
+        let live = logic.process(vec![NodeData::Float(1.0), NodeData::Boolean(false)], &mut state);
+        assert!(matches!(live[0], NodeData::Float(f) if f == 1.0));
+        
+        let held = logic.process(vec![NodeData::Float(5.0), NodeData::Boolean(true)], &mut state);
+        assert!(matches!(held[0], NodeData::Float(f) if f == 1.0));
+        assert!(matches!(held[1], NodeData::Boolean(true)));
+        
+        let released = logic.process(vec![NodeData::Float(5.0), NodeData::Boolean(false)], &mut state);
+        assert!(matches!(released[0], NodeData::Float(f) if f == 5.0));
+    }
+
+    #[test]
+    fn test_hold_parameter_used_when_unconnected() {
+        let logic = HoldLogic { hold: true };
+        let mut state = NodeStateSlots::new();
+        logic.process(vec![NodeData::Integer(2), NodeData::None], &mut state);
+        let held = logic.process(vec![NodeData::Integer(9), NodeData::None], &mut state);
+        assert!(matches!(held[0], NodeData::Integer(2)));
+    }
+}
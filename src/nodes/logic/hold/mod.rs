@@ -0,0 +1,44 @@
+//! Hold (gate/latch) node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (latched value kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Latch that freezes its input while Hold is TRUE and passes it through otherwise
+#[derive(Default)]
+pub struct HoldNodeFactory;
+
+impl NodeFactory for HoldNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Hold",
+            "Hold",
+            NodeCategory::new(&["Logic", "State"]),
+            "Passes its input through, or keeps outputting the last value while Hold is TRUE"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("⏸")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Any)
+                .with_description("Value to pass through or latch"),
+            PortDefinition::optional("Hold", DataType::Boolean)
+                .with_description("Freeze the output while TRUE (overrides the Hold parameter)"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Value", DataType::Any)
+                .with_description("Live or latched value"),
+            PortDefinition::optional("Holding", DataType::Boolean)
+                .with_description("TRUE while the output is frozen"),
+        ])
+        .with_tags(vec!["logic", "state", "hold", "latch", "gate", "freeze", "sample"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General", "3D"])
+    }
+}
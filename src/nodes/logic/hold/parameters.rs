@@ -0,0 +1,46 @@
+//! Hold node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::HoldLogic;
+
+/// Hold node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct HoldNode;
+
+impl HoldNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Hold Parameters");
+        ui.separator();
+        
+        let mut hold = logic.hold;
+        if ui.checkbox(&mut hold, "Hold (freeze output)").changed() {
+            changes.push(ParameterChange {
+                parameter: "hold".to_string(),
+                value: NodeData::Boolean(hold),
+            });
+        }
+        ui.label("A connected Hold input overrides this toggle");
+        
+        changes
+    }
+    
+    /// Build HoldLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> HoldLogic {
+        HoldLogic {
+            hold: node.parameters.get("hold")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(false),
+        }
+    }
+    
+    /// Execute the node against its persistent state
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs, state)
+    }
+}
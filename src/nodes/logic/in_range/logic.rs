@@ -0,0 +1,71 @@
+//! In Range node functional operations - bounds test
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Defaults of the Value, Min and Max inputs
+pub const INPUT_DEFAULTS: [f32; 3] = [0.5, 0.0, 1.0];
+
+/// Core In Range data and functionality
+#[derive(Debug, Clone)]
+pub struct InRangeLogic {
+    /// Whether values equal to a bound count as inside
+    pub inclusive: bool,
+    /// Tolerance at the bounds; widens inclusive ranges and narrows exclusive ones
+    pub epsilon: f32,
+}
+
+impl Default for InRangeLogic {
+    fn default() -> Self {
+        Self {
+            inclusive: true,
+            epsilon: 1e-5,
+        }
+    }
+}
+
+impl InRangeLogic {
+    /// Process input data and test the range; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let min = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let max = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        vec![NodeData::Boolean(self.contains(value, min, max))]
+    }
+
+    /// Whether `value` lies between `min` and `max`; swapped bounds are put in order
+    pub fn contains(&self, value: f32, min: f32, max: f32) -> bool {
+        let (low, high) = if min <= max { (min, max) } else { (max, min) };
+        let epsilon = self.epsilon.abs();
+        if self.inclusive {
+            value >= low - epsilon && value <= high + epsilon
+        } else {
+            value > low + epsilon && value < high - epsilon
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inclusive_and_exclusive_bounds() {
+        let logic = InRangeLogic { epsilon: 0.01, ..Default::default() };
+        assert!(logic.contains(1.005, 0.0, 1.0));
+        assert!(!logic.contains(1.02, 0.0, 1.0));
+        let logic = InRangeLogic { inclusive: false, ..logic };
+        assert!(!logic.contains(0.995, 0.0, 1.0));
+        assert!(logic.contains(0.5, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_swapped_bounds_and_fallbacks() {
+        let logic = InRangeLogic::default();
+        assert!(logic.contains(0.5, 1.0, 0.0));
+        let result = logic.process(vec![NodeData::None, NodeData::Float(1.0), NodeData::Integer(0)]);
+        assert!(matches!(result[0], NodeData::Boolean(true)));
+        let result = logic.process(vec![NodeData::None, NodeData::Float(4.0), NodeData::Integer(6)]);
+        assert!(matches!(result[0], NodeData::Boolean(false)));
+    }
+}
@@ -0,0 +1,56 @@
+//! In Range node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+use crate::nodes::parameter_schema::port_default_key;
+
+/// In Range node that tests whether a value lies between two bounds
+#[derive(Default)]
+pub struct InRangeNodeFactory;
+
+impl NodeFactory for InRangeNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_InRange",
+            "In Range",
+            NodeCategory::new(&["Logic", "Comparison"]),
+            "True when a number lies between Min and Max, with inclusive or exclusive bounds and an epsilon tolerance"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("∈")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to test")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("Min", DataType::Float)
+                .with_description("Lower bound")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("Max", DataType::Float)
+                .with_description("Upper bound")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Boolean)
+                .with_description("Whether Value lies in the range"),
+        ])
+        .with_tags(vec!["logic", "compare", "range", "between", "within", "bounds", "epsilon"])
+        .with_subtitle(|node| {
+            let logic = parameters::InRangeNode::create_logic(node);
+            let (open, close) = if logic.inclusive { ('[', ']') } else { ('(', ')') };
+            let min = node.float_parameter(&port_default_key("Min"), logic::INPUT_DEFAULTS[1]);
+            let max = node.float_parameter(&port_default_key("Max"), logic::INPUT_DEFAULTS[2]);
+            Some(format!("{}{}, {}{}", open, min, max, close))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
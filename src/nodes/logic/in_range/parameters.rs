@@ -0,0 +1,58 @@
+//! In Range node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::InRangeLogic;
+
+/// In Range node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct InRangeNode;
+
+impl InRangeNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (Value, Min and Max are edited under the generated Input Defaults)
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("In Range Parameters");
+        ui.separator();
+        
+        let mut inclusive = logic.inclusive;
+        if ui.checkbox(&mut inclusive, "Include bounds").changed() {
+            changes.push(ParameterChange {
+                parameter: "inclusive".to_string(),
+                value: NodeData::Boolean(inclusive),
+            });
+        }
+        
+        ui.horizontal(|ui| {
+            ui.label("Epsilon:");
+            let mut epsilon = logic.epsilon;
+            if ui.add(egui::DragValue::new(&mut epsilon).speed(0.0001).range(0.0..=1.0).max_decimals(6)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "epsilon".to_string(),
+                    value: NodeData::Float(epsilon),
+                });
+            }
+        });
+        
+        changes
+    }
+    
+    /// Build InRangeLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> InRangeLogic {
+        let defaults = InRangeLogic::default();
+        InRangeLogic {
+            inclusive: node.parameters.get("inclusive")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.inclusive),
+            epsilon: node.float_parameter("epsilon", defaults.epsilon),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -3,10 +3,34 @@
 pub mod and;    // AND logic gate with Pattern A interface
 pub mod or;     // OR/XOR logic gate with Pattern A interface
 pub mod not;    // NOT/BUFFER logic gate with Pattern A interface
+pub mod xor;    // XOR/XNOR logic gate with Pattern A interface
+pub mod nand;   // NAND logic gate with Pattern A interface
+pub mod compare;        // Numeric comparison with epsilon tolerance
+pub mod in_range;       // Bounds test with epsilon tolerance
+pub mod bool_to_float;  // Boolean to float converter
+pub mod counter;        // Stateful counter
+pub mod accumulate;     // Stateful running total
+pub mod hold;           // Stateful gate/latch
+pub mod feedback;       // Stateful frame-to-frame delay/smoothing
+pub mod timer;          // Interval pulses in seconds or frames
+pub mod trigger;        // Threshold crossing pulses
+pub mod branch;         // If/else routing with lazy inputs
 
 // Export all node factories
 pub use and::AndNodeFactory;
 pub use or::OrNodeFactory;
 pub use not::NotNodeFactory;
+pub use xor::XorNodeFactory;
+pub use nand::NandNodeFactory;
+pub use compare::CompareNodeFactory;
+pub use in_range::InRangeNodeFactory;
+pub use bool_to_float::BoolToFloatNodeFactory;
+pub use counter::CounterNodeFactory;
+pub use accumulate::AccumulateNodeFactory;
+pub use hold::HoldNodeFactory;
+pub use feedback::FeedbackNodeFactory;
+pub use timer::TimerNodeFactory;
+pub use trigger::TriggerNodeFactory;
+pub use branch::BranchNodeFactory;
 
 // Parameter struct exports removed - unused
\ No newline at end of file
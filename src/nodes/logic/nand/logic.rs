@@ -0,0 +1,35 @@
+//! NAND node functional operations - NOT-AND logic
+
+use crate::nodes::interface::NodeData;
+
+/// Core NAND data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct NandLogic {
+    /// First input value
+    pub a: bool,
+    /// Second input value
+    pub b: bool,
+}
+
+impl NandLogic {
+    /// Process input data and perform NAND operation
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut a = self.a;
+        let mut b = self.b;
+        
+        // Connected inputs override the parameter values
+        if let Some(NodeData::Boolean(val)) = inputs.first() {
+            a = *val;
+        }
+        if let Some(NodeData::Boolean(val)) = inputs.get(1) {
+            b = *val;
+        }
+        
+        vec![NodeData::Boolean(Self::evaluate(a, b))]
+    }
+    
+    /// Evaluate NAND for a pair of values
+    pub fn evaluate(a: bool, b: bool) -> bool {
+        !(a && b)
+    }
+}
@@ -0,0 +1,45 @@
+//! NAND node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// NAND logic node that performs boolean NOT-AND
+#[derive(Default)]
+pub struct NandNodeFactory;
+
+impl NodeFactory for NandNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Nand",
+            "NAND",
+            NodeCategory::new(&["Logic", "Boolean"]),
+            "Logical NAND operation (true unless both inputs are true)"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("⊼")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Boolean)
+                .with_description("First boolean input")
+                .with_default(NodeData::Boolean(false)),
+            PortDefinition::optional("B", DataType::Boolean)
+                .with_description("Second boolean input")
+                .with_default(NodeData::Boolean(false)),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Boolean)
+                .with_description("NOT (A AND B)"),
+        ])
+        .with_tags(vec!["logic", "boolean", "nand", "gate", "universal"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
@@ -0,0 +1,45 @@
+//! NAND node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::NandLogic;
+
+/// NAND node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct NandNode;
+
+impl NandNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (A and B are edited under the generated Input Defaults)
+    pub fn build_interface(_node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        ui.heading("NAND Logic Parameters");
+        ui.separator();
+        
+        ui.label("Truth Table:");
+        ui.label("A | B | Result");
+        for a in [false, true] {
+            for b in [false, true] {
+                ui.label(format!("{} | {} | {}",
+                    if a { "T" } else { "F" },
+                    if b { "T" } else { "F" },
+                    if NandLogic::evaluate(a, b) { "T" } else { "F" }
+                ));
+            }
+        }
+        
+        Vec::new()
+    }
+    
+    /// Build NandLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> NandLogic {
+        NandLogic {
+            a: node.bool_parameter("input_a", false),
+            b: node.bool_parameter("input_b", false),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -38,38 +38,6 @@ impl OrNode {
         ui.heading("OR Logic Parameters");
         ui.separator();
         
-        // Input A (when not connected)
-        ui.horizontal(|ui| {
-            ui.label("Input A:");
-            let mut input_a = node.parameters.get("input_a")
-                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
-                .unwrap_or(false);
-            
-            if ui.checkbox(&mut input_a, "").changed() {
-                changes.push(ParameterChange {
-                    parameter: "input_a".to_string(),
-                    value: NodeData::Boolean(input_a),
-                });
-            }
-        });
-        
-        // Input B (when not connected)
-        ui.horizontal(|ui| {
-            ui.label("Input B:");
-            let mut input_b = node.parameters.get("input_b")
-                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
-                .unwrap_or(false);
-            
-            if ui.checkbox(&mut input_b, "").changed() {
-                changes.push(ParameterChange {
-                    parameter: "input_b".to_string(),
-                    value: NodeData::Boolean(input_b),
-                });
-            }
-        });
-        
-        ui.separator();
-        
         // Logic operation modes
         ui.horizontal(|ui| {
             ui.label("Exclusive (XOR):");
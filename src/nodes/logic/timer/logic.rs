@@ -0,0 +1,150 @@
+//! Timer functional operations - interval pulses over engine-owned state
+//!
+//! In Seconds mode the node schedules its own cooks through the state
+//! store's wake slot: once at the next due time to pulse, and once right
+//! after a pulse to drop it again, so downstream sees a clean rising edge.
+//! In Frames mode the pulse follows the timeline frame alone.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::state_store::{NodeStateSlots, WAKE_AT_SLOT};
+
+const NEXT_DUE_SLOT: &str = "next_due_ms";
+const COUNT_SLOT: &str = "count";
+
+/// What the interval is measured in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerUnit {
+    Seconds,
+    Frames,
+}
+
+impl TimerUnit {
+    /// All units in UI order
+    pub const ALL: [TimerUnit; 2] = [TimerUnit::Seconds, TimerUnit::Frames];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimerUnit::Seconds => "Seconds",
+            TimerUnit::Frames => "Frames",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Seconds)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|u| u.name() == name).unwrap_or(TimerUnit::Seconds)
+    }
+}
+
+/// Core Timer configuration
+#[derive(Debug, Clone)]
+pub struct TimerLogic {
+    pub unit: TimerUnit,
+    /// Seconds between pulses in Seconds mode
+    pub seconds: f32,
+    /// Frames between pulses in Frames mode
+    pub frames: i32,
+    /// Paused timers keep their count and never pulse
+    pub enabled: bool,
+}
+
+impl Default for TimerLogic {
+    fn default() -> Self {
+        Self {
+            unit: TimerUnit::Seconds,
+            seconds: 1.0,
+            frames: 24,
+            enabled: true,
+        }
+    }
+}
+
+impl TimerLogic {
+    /// Short description for the node's subtitle
+    pub fn summary(&self) -> String {
+        match self.unit {
+            TimerUnit::Seconds => format!("every {}s", self.seconds),
+            TimerUnit::Frames => format!("every {} frames", self.frames.max(1)),
+        }
+    }
+    
+    /// Pulse if the interval has elapsed at engine clock `now_ms` / timeline `frame`
+    pub fn process(&self, now_ms: i32, frame: i64, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        match self.unit {
+            TimerUnit::Seconds => self.process_seconds(now_ms, state),
+            TimerUnit::Frames => {
+                state.remove(WAKE_AT_SLOT);
+                let every = self.frames.max(1) as i64;
+                let pulse = self.enabled && frame.rem_euclid(every) == 0;
+                let count = frame.div_euclid(every).clamp(0, i32::MAX as i64) as i32;
+                vec![NodeData::Boolean(pulse), NodeData::Integer(count)]
+            }
+        }
+    }
+    
+    fn process_seconds(&self, now_ms: i32, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let interval_ms = ((self.seconds.max(0.01) * 1000.0) as i32).max(1);
+        let mut count = match state.get(COUNT_SLOT) { Some(NodeData::Integer(c)) => *c, _ => 0 };
+        let next_due = match state.get(NEXT_DUE_SLOT) {
+            Some(NodeData::Integer(due)) => *due,
+            // The first pulse comes one interval after the timer starts
+            _ => now_ms.saturating_add(interval_ms),
+        };
+        
+        if !self.enabled {
+            state.remove(WAKE_AT_SLOT);
+            state.remove(NEXT_DUE_SLOT);
+            return vec![NodeData::Boolean(false), NodeData::Integer(count)];
+        }
+        
+        let pulse = now_ms >= next_due;
+        let next_due = if pulse {
+            count = count.saturating_add(1);
+            // Missed intervals (e.g. while the editor was busy) don't pile up
+            now_ms.saturating_add(interval_ms)
+        } else {
+            next_due
+        };
+        let wake_at = if pulse { now_ms } else { next_due };
+        
+        state.insert(COUNT_SLOT.to_string(), NodeData::Integer(count));
+        state.insert(NEXT_DUE_SLOT.to_string(), NodeData::Integer(next_due));
+        state.insert(WAKE_AT_SLOT.to_string(), NodeData::Integer(wake_at));
+        vec![NodeData::Boolean(pulse), NodeData::Integer(count)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pulse(output: &[NodeData]) -> bool {
+        matches!(output[0], NodeData::Boolean(true))
+    }
+
+    #[test]
+    fn test_seconds_pulse_then_drop_and_reschedule() {
+        let logic = TimerLogic { seconds: 0.5, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        assert!(!pulse(&logic.process(0, 0, &mut state)));
+        assert!(matches!(state.get(WAKE_AT_SLOT), Some(NodeData::Integer(500))));
+        
+        let output = logic.process(520, 0, &mut state);
+        assert!(pulse(&output));
+        assert!(matches!(output[1], NodeData::Integer(1)));
+        // Woken straight away to end the pulse
+        assert!(matches!(state.get(WAKE_AT_SLOT), Some(NodeData::Integer(520))));
+        
+        assert!(!pulse(&logic.process(540, 0, &mut state)));
+        assert!(matches!(state.get(WAKE_AT_SLOT), Some(NodeData::Integer(1020))));
+    }
+
+    #[test]
+    fn test_frames_pulse_on_multiples() {
+        let logic = TimerLogic { unit: TimerUnit::Frames, frames: 12, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        assert!(pulse(&logic.process(0, 24, &mut state)));
+        assert!(!pulse(&logic.process(0, 25, &mut state)));
+        assert!(state.get(WAKE_AT_SLOT).is_none());
+    }
+}
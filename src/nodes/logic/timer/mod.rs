@@ -0,0 +1,39 @@
+//! Timer node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (state kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Pulses on a fixed interval of wall-clock seconds or timeline frames
+#[derive(Default)]
+pub struct TimerNodeFactory;
+
+impl NodeFactory for TimerNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Timer",
+            "Timer",
+            NodeCategory::new(&["Logic", "State"]),
+            "Pulses every N seconds while the editor runs, or every N timeline frames, re-cooking everything downstream"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("⏱")
+        .with_outputs(vec![
+            PortDefinition::required("Pulse", DataType::Boolean)
+                .with_description("TRUE for one cook each time the interval elapses"),
+            PortDefinition::optional("Count", DataType::Integer)
+                .with_description("Number of pulses so far"),
+        ])
+        .with_tags(vec!["logic", "state", "timer", "interval", "pulse", "tick", "clock", "refresh", "poll"])
+        .with_subtitle(|node| Some(parameters::TimerNode::create_logic(node).summary()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
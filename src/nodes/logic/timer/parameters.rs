@@ -0,0 +1,100 @@
+//! Timer node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::{TimerLogic, TimerUnit};
+
+/// Timer node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct TimerNode;
+
+impl TimerNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Timer Parameters");
+        ui.separator();
+        
+        let mut enabled = logic.enabled;
+        if ui.checkbox(&mut enabled, "Running").changed() {
+            changes.push(ParameterChange {
+                parameter: "enabled".to_string(),
+                value: NodeData::Boolean(enabled),
+            });
+        }
+        
+        ui.horizontal(|ui| {
+            ui.label("Interval in:");
+            let mut unit = logic.unit;
+            egui::ComboBox::from_id_salt("timer_unit")
+                .selected_text(unit.name())
+                .show_ui(ui, |ui| {
+                    for u in TimerUnit::ALL {
+                        if ui.selectable_value(&mut unit, u, u.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "unit".to_string(),
+                                value: NodeData::String(u.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        ui.horizontal(|ui| {
+            ui.label("Every:");
+            match logic.unit {
+                TimerUnit::Seconds => {
+                    let mut seconds = logic.seconds;
+                    if ui.add(egui::DragValue::new(&mut seconds).speed(0.1).range(0.01..=86400.0).suffix(" s")).changed() {
+                        changes.push(ParameterChange {
+                            parameter: "seconds".to_string(),
+                            value: NodeData::Float(seconds),
+                        });
+                    }
+                }
+                TimerUnit::Frames => {
+                    let mut frames = logic.frames;
+                    if ui.add(egui::DragValue::new(&mut frames).speed(1.0).range(1..=100000).suffix(" frames")).changed() {
+                        changes.push(ParameterChange {
+                            parameter: "frames".to_string(),
+                            value: NodeData::Integer(frames),
+                        });
+                    }
+                }
+            }
+        });
+        
+        if logic.unit == TimerUnit::Seconds {
+            ui.label("Counts wall-clock time while the editor runs with auto execution.");
+        }
+        
+        changes
+    }
+    
+    /// Build TimerLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> TimerLogic {
+        let defaults = TimerLogic::default();
+        TimerLogic {
+            unit: node.parameters.get("unit")
+                .and_then(|v| if let NodeData::String(s) = v { Some(TimerUnit::from_name(s)) } else { None })
+                .unwrap_or(defaults.unit),
+            seconds: node.parameters.get("seconds")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.seconds),
+            frames: node.parameters.get("frames")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(defaults.frames),
+            enabled: node.parameters.get("enabled")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.enabled),
+        }
+    }
+    
+    /// Execute the node against its persistent state at the engine clock and timeline frame
+    pub fn process_node(node: &Node, now_ms: i32, frame: i64, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(now_ms, frame, state)
+    }
+}
@@ -0,0 +1,133 @@
+//! Trigger functional operations - threshold crossings over engine-owned state
+//!
+//! A crossing pulses for one cook; the node then asks the engine, through the
+//! state store's wake slot, to cook it again right away so the pulse drops.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+use crate::nodes::state_store::{NodeStateSlots, WAKE_AT_SLOT};
+
+const ABOVE_SLOT: &str = "above";
+const COUNT_SLOT: &str = "count";
+
+/// Which crossings pulse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CrossingDirection {
+    Rising,
+    Falling,
+    Either,
+}
+
+impl CrossingDirection {
+    /// All directions in UI order
+    pub const ALL: [CrossingDirection; 3] = [
+        CrossingDirection::Rising,
+        CrossingDirection::Falling,
+        CrossingDirection::Either,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            CrossingDirection::Rising => "Rising",
+            CrossingDirection::Falling => "Falling",
+            CrossingDirection::Either => "Either",
+        }
+    }
+    
+    /// Arrow for display
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CrossingDirection::Rising => "↑",
+            CrossingDirection::Falling => "↓",
+            CrossingDirection::Either => "↕",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Rising)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|d| d.name() == name).unwrap_or(CrossingDirection::Rising)
+    }
+}
+
+/// Core Trigger configuration
+#[derive(Debug, Clone)]
+pub struct TriggerLogic {
+    pub direction: CrossingDirection,
+    /// Level to cross when the Threshold input is unconnected
+    pub threshold: f32,
+    /// Value used when the Value input is unconnected
+    pub value: f32,
+}
+
+impl Default for TriggerLogic {
+    fn default() -> Self {
+        Self {
+            direction: CrossingDirection::Rising,
+            threshold: 0.5,
+            value: 0.0,
+        }
+    }
+}
+
+impl TriggerLogic {
+    /// Compare this cook's value with the last one seen, pulsing on a crossing
+    pub fn process(&self, inputs: Vec<NodeData>, now_ms: i32, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(self.value);
+        let threshold = inputs.get(1).and_then(extract_float).unwrap_or(self.threshold);
+        let above = value > threshold;
+        let mut count = match state.get(COUNT_SLOT) { Some(NodeData::Integer(c)) => *c, _ => 0 };
+        
+        // The first value seen only sets the side; it isn't a crossing
+        let pulse = match state.get(ABOVE_SLOT) {
+            Some(NodeData::Boolean(was_above)) if *was_above != above => match self.direction {
+                CrossingDirection::Rising => above,
+                CrossingDirection::Falling => !above,
+                CrossingDirection::Either => true,
+            },
+            _ => false,
+        };
+        if pulse {
+            count = count.saturating_add(1);
+            state.insert(WAKE_AT_SLOT.to_string(), NodeData::Integer(now_ms));
+        } else {
+            state.remove(WAKE_AT_SLOT);
+        }
+        
+        state.insert(ABOVE_SLOT.to_string(), NodeData::Boolean(above));
+        state.insert(COUNT_SLOT.to_string(), NodeData::Integer(count));
+        vec![NodeData::Boolean(pulse), NodeData::Boolean(above), NodeData::Integer(count)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pulse(logic: &TriggerLogic, value: f32, state: &mut NodeStateSlots) -> bool {
+        matches!(logic.process(vec![NodeData::Float(value)], 0, state)[0], NodeData::Boolean(true))
+    }
+
+    #[test]
+    fn test_rising_crossings_pulse_once() {
+        let logic = TriggerLogic::default();
+        let mut state = NodeStateSlots::new();
+        assert!(!pulse(&logic, 0.0, &mut state));
+        assert!(pulse(&logic, 1.0, &mut state));
+        assert!(state.contains_key(WAKE_AT_SLOT));
+        // The follow-up cook with the same value drops the pulse
+        assert!(!pulse(&logic, 1.0, &mut state));
+        assert!(!state.contains_key(WAKE_AT_SLOT));
+        assert!(!pulse(&logic, 0.0, &mut state));
+    }
+
+    #[test]
+    fn test_either_direction_counts_both_ways() {
+        let logic = TriggerLogic { direction: CrossingDirection::Either, ..Default::default() };
+        let mut state = NodeStateSlots::new();
+        pulse(&logic, 0.0, &mut state);
+        assert!(pulse(&logic, 1.0, &mut state));
+        assert!(pulse(&logic, 0.0, &mut state));
+        assert!(matches!(state.get(COUNT_SLOT), Some(NodeData::Integer(2))));
+    }
+}
@@ -0,0 +1,50 @@
+//! Trigger node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic (state kept in the engine's state store)
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Pulses when a watched value crosses a threshold
+#[derive(Default)]
+pub struct TriggerNodeFactory;
+
+impl NodeFactory for TriggerNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Trigger",
+            "Trigger",
+            NodeCategory::new(&["Logic", "State"]),
+            "Pulses for one cook when the watched value crosses the threshold upward, downward or either way"
+        )
+        .with_color(Color32::from_rgb(50, 45, 70))
+        .with_icon("⚡")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to watch"),
+            PortDefinition::optional("Threshold", DataType::Float)
+                .with_description("Level the value must cross (overrides the Threshold parameter)"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Pulse", DataType::Boolean)
+                .with_description("TRUE for one cook after a crossing"),
+            PortDefinition::optional("Above", DataType::Boolean)
+                .with_description("Whether the value is above the threshold"),
+            PortDefinition::optional("Count", DataType::Integer)
+                .with_description("Number of crossings so far"),
+        ])
+        .with_tags(vec!["logic", "state", "trigger", "threshold", "crossing", "edge", "pulse", "alert", "watch"])
+        .with_subtitle(|node| {
+            let logic = parameters::TriggerNode::create_logic(node);
+            Some(format!("{} {}", logic.direction.symbol(), logic.threshold))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
@@ -0,0 +1,76 @@
+//! Trigger node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::state_store::NodeStateSlots;
+use crate::nodes::Node;
+use super::logic::{CrossingDirection, TriggerLogic};
+
+/// Trigger node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct TriggerNode;
+
+impl TriggerNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Trigger Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Crossing:");
+            let mut direction = logic.direction;
+            egui::ComboBox::from_id_salt("trigger_direction")
+                .selected_text(format!("{} {}", direction.symbol(), direction.name()))
+                .show_ui(ui, |ui| {
+                    for d in CrossingDirection::ALL {
+                        if ui.selectable_value(&mut direction, d, format!("{} {}", d.symbol(), d.name())).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "direction".to_string(),
+                                value: NodeData::String(d.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        for (param, label, current) in [
+            ("threshold", "Threshold:", logic.threshold),
+            ("value", "Value:", logic.value),
+        ] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(0.1)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Float(value),
+                    });
+                }
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build TriggerLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> TriggerLogic {
+        let defaults = TriggerLogic::default();
+        let get_float = |name: &str, default: f32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        TriggerLogic {
+            direction: node.parameters.get("direction")
+                .and_then(|v| if let NodeData::String(s) = v { Some(CrossingDirection::from_name(s)) } else { None })
+                .unwrap_or(defaults.direction),
+            threshold: get_float("threshold", defaults.threshold),
+            value: get_float("value", defaults.value),
+        }
+    }
+    
+    /// Execute the node against its persistent state at the engine clock
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, now_ms: i32, state: &mut NodeStateSlots) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs, now_ms, state)
+    }
+}
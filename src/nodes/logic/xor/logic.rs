@@ -0,0 +1,43 @@
+//! XOR node functional operations - exclusive OR logic
+
+use crate::nodes::interface::NodeData;
+
+/// Core XOR data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct XorLogic {
+    /// First input value
+    pub a: bool,
+    /// Second input value
+    pub b: bool,
+    /// Whether to invert the result (XNOR)
+    pub invert_result: bool,
+}
+
+impl XorLogic {
+    /// Process input data and perform XOR operation
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut a = self.a;
+        let mut b = self.b;
+        
+        // Connected inputs override the parameter values
+        if let Some(NodeData::Boolean(val)) = inputs.first() {
+            a = *val;
+        }
+        if let Some(NodeData::Boolean(val)) = inputs.get(1) {
+            b = *val;
+        }
+        
+        vec![NodeData::Boolean(Self::evaluate(a, b, self.invert_result))]
+    }
+    
+    /// Evaluate XOR for a pair of values
+    pub fn evaluate(a: bool, b: bool, invert_result: bool) -> bool {
+        let result = a ^ b;
+        if invert_result { !result } else { result }
+    }
+    
+    /// Get the operation name for display
+    pub fn get_operation_name(&self) -> &'static str {
+        if self.invert_result { "XNOR" } else { "XOR" }
+    }
+}
@@ -0,0 +1,45 @@
+//! XOR node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// XOR logic node that performs boolean exclusive OR
+#[derive(Default)]
+pub struct XorNodeFactory;
+
+impl NodeFactory for XorNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Logic_Xor",
+            "XOR",
+            NodeCategory::new(&["Logic", "Boolean"]),
+            "Logical exclusive OR (or XNOR when inverted)"
+        )
+        .with_color(Color32::from_rgb(40, 50, 70))
+        .with_icon("^")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Boolean)
+                .with_description("First boolean input")
+                .with_default(NodeData::Boolean(false)),
+            PortDefinition::optional("B", DataType::Boolean)
+                .with_description("Second boolean input")
+                .with_default(NodeData::Boolean(false)),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Boolean)
+                .with_description("A XOR B (or XNOR if inverted)"),
+        ])
+        .with_tags(vec!["logic", "boolean", "xor", "xnor", "exclusive", "gate"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Logic", "Programming", "General"])
+    }
+}
@@ -0,0 +1,63 @@
+//! XOR node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::XorLogic;
+
+/// XOR node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct XorNode;
+
+impl XorNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        
+        ui.heading("XOR Logic Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Invert Result:");
+            let mut invert_result = node.bool_parameter("invert_result", false);
+            if ui.checkbox(&mut invert_result, "Invert output (XNOR)").changed() {
+                changes.push(ParameterChange {
+                    parameter: "invert_result".to_string(),
+                    value: NodeData::Boolean(invert_result),
+                });
+            }
+        });
+        
+        ui.separator();
+        
+        // Truth table for the current mode
+        let logic = Self::create_logic(node);
+        ui.label(format!("Truth Table ({}):", logic.get_operation_name()));
+        ui.label("A | B | Result");
+        for a in [false, true] {
+            for b in [false, true] {
+                let result = XorLogic::evaluate(a, b, logic.invert_result);
+                ui.label(format!("{} | {} | {}",
+                    if a { "T" } else { "F" },
+                    if b { "T" } else { "F" },
+                    if result { "T" } else { "F" }
+                ));
+            }
+        }
+        
+        changes
+    }
+    
+    /// Build XorLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> XorLogic {
+        XorLogic {
+            a: node.bool_parameter("input_a", false),
+            b: node.bool_parameter("input_b", false),
+            invert_result: node.bool_parameter("invert_result", false),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
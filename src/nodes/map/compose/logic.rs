@@ -0,0 +1,64 @@
+//! Compose node functional operations - naming inputs into a map
+
+use std::collections::BTreeMap;
+use crate::nodes::interface::NodeData;
+
+/// Value inputs after the Map input; chain Compose nodes for more
+pub const VALUE_INPUTS: usize = 4;
+
+/// Parameters holding the key of each value input
+pub const KEY_PARAMETERS: [&str; VALUE_INPUTS] = ["key_1", "key_2", "key_3", "key_4"];
+
+/// Core Compose configuration
+#[derive(Debug, Clone)]
+pub struct ComposeLogic {
+    /// Key of each value input; inputs with an empty key are left out
+    pub keys: [String; VALUE_INPUTS],
+}
+
+impl Default for ComposeLogic {
+    fn default() -> Self {
+        Self { keys: ["a", "b", "c", "d"].map(String::from) }
+    }
+}
+
+impl ComposeLogic {
+    /// The Map input's entries with the value inputs added; unconnected values are left out
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut inputs = inputs.into_iter();
+        let mut entries = inputs.next().as_ref().and_then(as_map).unwrap_or_default();
+        for (key, value) in self.keys.iter().zip(inputs) {
+            if !key.is_empty() && !matches!(value, NodeData::None) {
+                entries.insert(key.clone(), value);
+            }
+        }
+        vec![NodeData::Map(entries)]
+    }
+}
+
+/// Entries of a map
+pub fn as_map(data: &NodeData) -> Option<BTreeMap<String, NodeData>> {
+    match data {
+        NodeData::Map(entries) => Some(entries.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_are_added_under_their_keys() {
+        let base = NodeData::Map(BTreeMap::from([
+            ("a".to_string(), NodeData::Integer(1)),
+            ("z".to_string(), NodeData::Boolean(true)),
+        ]));
+        let logic = ComposeLogic { keys: ["a", "", "samples", "d"].map(String::from) };
+        let result = logic.process(vec![base, NodeData::Integer(2), NodeData::Float(0.5), NodeData::Integer(64), NodeData::None]);
+        let NodeData::Map(entries) = &result[0] else { panic!("Expected a map") };
+        // The new value replaces the Map input's; the empty key and the unconnected input add nothing
+        assert_eq!(entries.keys().collect::<Vec<_>>(), vec!["a", "samples", "z"]);
+        assert!(matches!(entries["a"], NodeData::Integer(2)));
+    }
+}
@@ -0,0 +1,49 @@
+//! Compose node module - named values bundled into a map
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Adding the value inputs to the Map input
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ComposeLogic;
+pub use parameters::ComposeNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ComposeNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        let defaults = logic::ComposeLogic::default();
+        let mut inputs = vec![
+            crate::nodes::PortDefinition::optional("Map", crate::nodes::DataType::Map)
+                .with_description("Entries to start from; chain Compose nodes for more than four values"),
+        ];
+        inputs.extend((1..=logic::VALUE_INPUTS).map(|index| {
+            crate::nodes::PortDefinition::optional(&format!("Value {}", index), crate::nodes::DataType::Any)
+                .with_description("Value stored under the matching key")
+        }));
+        let labels = ["Key 1", "Key 2", "Key 3", "Key 4"];
+        crate::nodes::NodeMetadata::new(
+            "Map_Compose",
+            "Compose",
+            crate::nodes::NodeCategory::new(&["Map"]),
+            "Bundles its inputs into a map under the keys set in the panel"
+        )
+        .with_color(egui::Color32::from_rgb(100, 85, 75))
+        .with_icon("🗃")
+        .with_inputs(inputs)
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Map", crate::nodes::DataType::Map)
+                .with_description("The Map input's entries plus the connected values"),
+        ])
+        .with_parameters(logic::KEY_PARAMETERS.iter().zip(labels).zip(&defaults.keys).map(|((name, label), key)| {
+            crate::nodes::ParameterDefinition::string(*name, label, key)
+                .with_tooltip("Key of the value input; leave empty to skip the input")
+        }).collect())
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["map", "dictionary", "dict", "struct", "record", "bundle", "compose", "pack"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
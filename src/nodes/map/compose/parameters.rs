@@ -0,0 +1,26 @@
+//! Compose node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{ComposeLogic, KEY_PARAMETERS};
+
+/// Compose node
+#[derive(Debug, Clone, Default)]
+pub struct ComposeNode;
+
+impl ComposeNode {
+    /// Build ComposeLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ComposeLogic {
+        let defaults = ComposeLogic::default();
+        ComposeLogic {
+            keys: std::array::from_fn(|index| node.parameters.get(KEY_PARAMETERS[index])
+                .and_then(|v| if let NodeData::String(s) = v { Some(s.trim().to_string()) } else { None })
+                .unwrap_or_else(|| defaults.keys[index].clone())),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
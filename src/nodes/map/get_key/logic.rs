@@ -0,0 +1,46 @@
+//! Get Key node functional operations - reading one value of a map
+
+use crate::nodes::interface::NodeData;
+
+/// Core Get Key configuration
+#[derive(Debug, Clone, Default)]
+pub struct GetKeyLogic {
+    /// Key when the Key input is not connected
+    pub key: String,
+}
+
+impl GetKeyLogic {
+    /// The value under the key and whether it was there; the Default input stands in for missing keys
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let key = match inputs.get(1) {
+            Some(NodeData::String(key)) => key.as_str(),
+            _ => self.key.as_str(),
+        };
+        let value = match inputs.first() {
+            Some(NodeData::Map(entries)) => entries.get(key).cloned(),
+            _ => None,
+        };
+        let found = value.is_some();
+        let fallback = inputs.get(2).cloned().unwrap_or(NodeData::None);
+        vec![value.unwrap_or(fallback), NodeData::Boolean(found)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_missing_keys_fall_back() {
+        let map = NodeData::Map(BTreeMap::from([("samples".to_string(), NodeData::Integer(64))]));
+        let logic = GetKeyLogic { key: "samples".to_string() };
+        let result = logic.process(vec![map.clone()]);
+        assert!(matches!(result[0], NodeData::Integer(64)));
+        assert!(matches!(result[1], NodeData::Boolean(true)));
+
+        let result = logic.process(vec![map, NodeData::String("bounces".to_string()), NodeData::Integer(4)]);
+        assert!(matches!(result[0], NodeData::Integer(4)));
+        assert!(matches!(result[1], NodeData::Boolean(false)));
+    }
+}
@@ -0,0 +1,49 @@
+//! Get Key node module - one value of a map
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Lookup with a fallback value
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::GetKeyLogic;
+pub use parameters::GetKeyNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::GetKeyNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Map_GetKey",
+            "Get Key",
+            crate::nodes::NodeCategory::new(&["Map"]),
+            "Outputs the value stored under a key of a map"
+        )
+        .with_color(egui::Color32::from_rgb(100, 85, 75))
+        .with_icon("🔑")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Map", crate::nodes::DataType::Map)
+                .with_description("Map to read from"),
+            crate::nodes::PortDefinition::optional("Key", crate::nodes::DataType::String)
+                .with_description("Key to look up; overrides the Key parameter"),
+            crate::nodes::PortDefinition::optional("Default", crate::nodes::DataType::Any)
+                .with_description("Value output when the key is missing"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Value", crate::nodes::DataType::Any)
+                .with_description("The value under the key, or the Default input"),
+            crate::nodes::PortDefinition::optional("Found", crate::nodes::DataType::Boolean)
+                .with_description("Whether the map has the key"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("key", "Key", "")
+                .with_tooltip("Key to look up"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["map", "dictionary", "dict", "struct", "key", "lookup", "get", "decompose", "unpack"])
+        .with_subtitle(|node| Some(parameters::GetKeyNode::create_logic(node).key).filter(|key| !key.is_empty()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
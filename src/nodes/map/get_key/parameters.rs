@@ -0,0 +1,25 @@
+//! Get Key node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::GetKeyLogic;
+
+/// Get Key node
+#[derive(Debug, Clone, Default)]
+pub struct GetKeyNode;
+
+impl GetKeyNode {
+    /// Build GetKeyLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> GetKeyLogic {
+        GetKeyLogic {
+            key: node.parameters.get("key")
+                .and_then(|v| if let NodeData::String(s) = v { Some(s.trim().to_string()) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,20 @@
+//! Keys node functional operations - a map's keys and values as lists
+
+use crate::nodes::interface::NodeData;
+
+/// Core Keys functionality; the node has no settings
+#[derive(Debug, Clone, Default)]
+pub struct KeysLogic;
+
+impl KeysLogic {
+    /// The keys, and the values in the same order
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let (keys, values) = match inputs.into_iter().next() {
+            Some(NodeData::Map(entries)) => entries.into_iter()
+                .map(|(key, value)| (NodeData::String(key), value))
+                .unzip(),
+            _ => (Vec::new(), Vec::new()),
+        };
+        vec![NodeData::List(keys), NodeData::List(values)]
+    }
+}
@@ -0,0 +1,40 @@
+//! Keys node module - a map's keys and values as lists
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Splitting the entries
+//! - parameters.rs: Execution entry point; the node has no parameters
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::KeysLogic;
+pub use parameters::KeysNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::KeysNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Map_Keys",
+            "Keys",
+            crate::nodes::NodeCategory::new(&["Map"]),
+            "Outputs the keys of a map and its values, in key order, as lists"
+        )
+        .with_color(egui::Color32::from_rgb(100, 85, 75))
+        .with_icon("🗝")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Map", crate::nodes::DataType::Map)
+                .with_description("Map to split"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Keys", crate::nodes::DataType::List)
+                .with_description("Every key, sorted"),
+            crate::nodes::PortDefinition::optional("Values", crate::nodes::DataType::List)
+                .with_description("The value of each key, in the same order"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["map", "dictionary", "dict", "struct", "keys", "values", "decompose", "unpack"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
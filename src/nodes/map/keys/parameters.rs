@@ -0,0 +1,16 @@
+//! Keys node parameters - the node has none
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::KeysLogic;
+
+/// Keys node
+#[derive(Debug, Clone, Default)]
+pub struct KeysNode;
+
+impl KeysNode {
+    /// Execute the node on its inputs
+    pub fn process_node(_node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        KeysLogic.process(inputs)
+    }
+}
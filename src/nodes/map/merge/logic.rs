@@ -0,0 +1,51 @@
+//! Merge node functional operations - combining maps
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::map::compose::logic::as_map;
+use std::collections::BTreeMap;
+
+/// Core Merge configuration
+#[derive(Debug, Clone, Default)]
+pub struct MergeLogic {
+    /// Keep the first map's value where several have a key, instead of the last's
+    pub keep_first: bool,
+}
+
+impl MergeLogic {
+    /// Every entry of the maps; shared keys take the last map's value unless `keep_first`
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut merged = BTreeMap::new();
+        for entries in inputs.iter().filter_map(as_map) {
+            for (key, value) in entries {
+                if self.keep_first {
+                    merged.entry(key).or_insert(value);
+                } else {
+                    merged.insert(key, value);
+                }
+            }
+        }
+        vec![NodeData::Map(merged)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_keys_take_the_preferred_value() {
+        let a = NodeData::Map(BTreeMap::from([("x".to_string(), NodeData::Integer(1)), ("y".to_string(), NodeData::Integer(2))]));
+        let b = NodeData::Map(BTreeMap::from([("y".to_string(), NodeData::Integer(3))]));
+        let value = |logic: MergeLogic| match &logic.process(vec![a.clone(), b.clone()])[0] {
+            NodeData::Map(entries) => (entries.len(), matches!(entries["y"], NodeData::Integer(3))),
+            _ => panic!("Expected a map"),
+        };
+        assert_eq!(value(MergeLogic::default()), (2, true));
+        assert_eq!(value(MergeLogic { keep_first: true }), (2, false));
+
+        // Maps after B follow the same rule
+        let c = NodeData::Map(BTreeMap::from([("y".to_string(), NodeData::Integer(4)), ("z".to_string(), NodeData::Integer(5))]));
+        let merged = MergeLogic::default().process(vec![a.clone(), NodeData::None, b.clone(), c]);
+        assert!(matches!(&merged[0], NodeData::Map(entries) if entries.len() == 3 && matches!(entries["y"], NodeData::Integer(4))));
+    }
+}
@@ -0,0 +1,45 @@
+//! Merge node module - maps combined into one
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Combining entries, resolving shared keys
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::MergeLogic;
+pub use parameters::MergeNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::MergeNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Map_Merge",
+            "Merge",
+            crate::nodes::NodeCategory::new(&["Map"]),
+            "Combines the entries of its maps; later maps win where several have a key"
+        )
+        .with_color(egui::Color32::from_rgb(100, 85, 75))
+        .with_icon("⊕")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::optional("A", crate::nodes::DataType::Map)
+                .with_description("First map"),
+            crate::nodes::PortDefinition::optional("B", crate::nodes::DataType::Map)
+                .with_description("Next map, overriding the ones before it by default; connecting the last map adds another")
+                .variadic(),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Map", crate::nodes::DataType::Map)
+                .with_description("Every entry of the input maps"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::boolean("keep_first", "Keep First Values", false)
+                .with_tooltip("Where several maps have a key, keep the first map's value instead of the last's"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["map", "dictionary", "dict", "struct", "merge", "combine", "override", "update"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
@@ -0,0 +1,23 @@
+//! Merge node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::MergeLogic;
+
+/// Merge node
+#[derive(Debug, Clone, Default)]
+pub struct MergeNode;
+
+impl MergeNode {
+    /// Build MergeLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> MergeLogic {
+        MergeLogic {
+            keep_first: matches!(node.parameters.get("keep_first"), Some(NodeData::Boolean(true))),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
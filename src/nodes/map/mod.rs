@@ -0,0 +1,6 @@
+//! Map nodes - bundling named values into one wire and reading them back
+
+pub mod compose;  // Named inputs into a map
+pub mod get_key;  // One value by key
+pub mod merge;    // Two maps into one
+pub mod keys;     // Keys and values as lists
@@ -8,6 +8,8 @@ pub mod shading;
 pub mod textures; 
 pub mod math;
 pub mod utilities;
+pub mod mtlx;
+pub mod preview;
 
 // Re-export commonly used types
 pub use shading::*;
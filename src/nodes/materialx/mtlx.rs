@@ -0,0 +1,666 @@
+//! MaterialX document import and export
+//!
+//! Translates between `.mtlx` documents and the node networks of the MaterialX
+//! workspace. Each workspace node stands for one MaterialX node category, and
+//! its ports map onto the category's inputs and outputs. Unconnected inputs keep
+//! their value in a parameter named after the MaterialX input, so a network
+//! survives a round trip. Nodes inside `<nodegraph>` elements are flattened into
+//! the network on import.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::{Connection, Node, NodeGraph, NodeId};
+use egui::Pos2;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::{math, shading, textures, utilities};
+
+/// MaterialX version written on export
+const MATERIALX_VERSION: &str = "1.38";
+
+/// Parameter holding the MaterialX type of a node whose type differs from its category's usual one
+const TYPE_PARAMETER: &str = "mtlx:type";
+
+/// Horizontal and vertical spacing of imported nodes
+const COLUMN_WIDTH: f32 = 220.0;
+const ROW_HEIGHT: f32 = 140.0;
+
+/// How a workspace node maps onto a MaterialX node category
+pub struct MtlxNodeDef {
+    pub type_id: &'static str,
+    /// Title new nodes get, used to recognize nodes saved without a type id
+    pub title: &'static str,
+    pub category: &'static str,
+    /// MaterialX type of the node's output
+    pub output_type: &'static str,
+    /// Port name, MaterialX input and its type; an empty type follows the node's type
+    pub inputs: &'static [(&'static str, &'static str, &'static str)],
+    /// Port name and MaterialX output
+    pub outputs: &'static [(&'static str, &'static str)],
+    create: fn(Pos2) -> Node,
+}
+
+/// Node categories the MaterialX workspace can represent
+pub const NODE_DEFS: &[MtlxNodeDef] = &[
+    MtlxNodeDef {
+        type_id: "MaterialX_StandardSurface", title: "Standard Surface", category: "standard_surface", output_type: "surfaceshader",
+        inputs: &[
+            ("Base Color", "base_color", "color3"), ("Metallic", "metalness", "float"),
+            ("Roughness", "specular_roughness", "float"), ("Normal", "normal", "vector3"),
+            ("Emission", "emission_color", "color3"), ("Opacity", "opacity", "color3"),
+        ],
+        outputs: &[("Surface", "out")],
+        create: shading::create_standard_surface_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_SurfaceShader", title: "Surface Shader", category: "surfacematerial", output_type: "material",
+        inputs: &[("Surface", "surfaceshader", "surfaceshader")],
+        outputs: &[("Shader", "out")],
+        create: shading::create_surface_shader_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Image", title: "Image", category: "image", output_type: "color3",
+        inputs: &[("File", "file", "filename"), ("UV", "texcoord", "vector2")],
+        outputs: &[("Color", "out")],
+        create: textures::create_image_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Noise", title: "Noise", category: "fractal3d", output_type: "color3",
+        inputs: &[("UV", "position", "vector3"), ("Scale", "amplitude", "float"), ("Octaves", "octaves", "integer")],
+        outputs: &[("Color", "out")],
+        create: textures::create_noise_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Checkerboard", title: "Checkerboard", category: "checkerboard", output_type: "color3",
+        inputs: &[("UV", "texcoord", "vector2"), ("Color1", "color1", "color3"), ("Color2", "color2", "color3")],
+        outputs: &[("Color", "out")],
+        create: textures::create_checkerboard_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_DotProduct", title: "Dot Product", category: "dotproduct", output_type: "float",
+        inputs: &[("Vector A", "in1", "vector3"), ("Vector B", "in2", "vector3")],
+        outputs: &[("Result", "out")],
+        create: math::create_dot_product_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Normalize", title: "Normalize", category: "normalize", output_type: "vector3",
+        inputs: &[("Vector", "in", "")],
+        outputs: &[("Normalized", "out")],
+        create: math::create_normalize_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_CrossProduct", title: "Cross Product", category: "crossproduct", output_type: "vector3",
+        inputs: &[("Vector A", "in1", "vector3"), ("Vector B", "in2", "vector3")],
+        outputs: &[("Result", "out")],
+        create: math::create_cross_product_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Mix", title: "Mix", category: "mix", output_type: "color3",
+        inputs: &[("Input A", "bg", ""), ("Input B", "fg", ""), ("Mix Factor", "mix", "float")],
+        outputs: &[("Output", "out")],
+        create: utilities::create_mix_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Switch", title: "Switch", category: "switch", output_type: "color3",
+        inputs: &[("Input A", "in1", ""), ("Input B", "in2", ""), ("Selector", "which", "float")],
+        outputs: &[("Output", "out")],
+        create: utilities::create_switch_node,
+    },
+    MtlxNodeDef {
+        type_id: "MaterialX_Constant", title: "Constant", category: "constant", output_type: "color3",
+        inputs: &[("Value", "value", "")],
+        outputs: &[("Output", "out")],
+        create: utilities::create_constant_node,
+    },
+];
+
+/// Definition of a workspace node type, e.g. "MaterialX_Mix"
+pub fn node_def(type_id: &str) -> Option<&'static MtlxNodeDef> {
+    NODE_DEFS.iter().find(|def| def.type_id == type_id)
+}
+
+/// Definition a node is exported with; nodes from older files are recognized by title
+pub fn node_def_of(node: &Node) -> Option<&'static MtlxNodeDef> {
+    node_def(&node.type_id)
+        .or_else(|| NODE_DEFS.iter().find(|def| node.type_id == "Unknown" && def.title == node.title))
+}
+
+/// Create the workspace node of a definition
+pub fn create_node(def: &MtlxNodeDef, position: Pos2) -> Node {
+    let mut node = (def.create)(position);
+    node.type_id = def.type_id.to_string();
+    node
+}
+
+/// A network read from a MaterialX document
+#[derive(Debug, Default)]
+pub struct MtlxImport {
+    /// Nodes added to the graph, in document order
+    pub nodes: Vec<NodeId>,
+    /// Elements and inputs that have no equivalent and were left out
+    pub warnings: Vec<String>,
+}
+
+/// A MaterialX document written from a network
+#[derive(Debug, Default)]
+pub struct MtlxExport {
+    pub document: String,
+    /// Nodes and connections that have no equivalent and were left out
+    pub warnings: Vec<String>,
+}
+
+/// Write the MaterialX nodes of `graph` as a `.mtlx` document
+pub fn export_document(graph: &NodeGraph) -> MtlxExport {
+    let mut warnings = Vec::new();
+    let mut ids: Vec<NodeId> = graph.nodes.keys().copied().collect();
+    ids.sort_unstable();
+
+    // Unique element names from the node titles
+    let mut names = HashMap::new();
+    let mut taken = HashSet::new();
+    for id in &ids {
+        let node = &graph.nodes[id];
+        if node_def_of(node).is_none() {
+            warnings.push(format!("{} has no MaterialX equivalent and was left out", node.title));
+            continue;
+        }
+        let base = element_name(&node.title);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(name.clone()) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        names.insert(*id, name);
+    }
+
+    let mut document = format!("<?xml version=\"1.0\"?>\n<materialx version=\"{}\">\n", MATERIALX_VERSION);
+    let mut unassigned_shaders = Vec::new();
+    for id in &ids {
+        let (Some(name), Some(def)) = (names.get(id), node_def_of(&graph.nodes[id])) else {
+            continue;
+        };
+        let node = &graph.nodes[id];
+        let node_type = node_type(node, def);
+        let mut inputs = Vec::new();
+        let mut written = HashSet::new();
+
+        for (port_index, port) in node.inputs.iter().enumerate() {
+            let connection = graph.connections.iter().find(|c| c.to_node == *id && c.to_port == port_index);
+            let mapped = def.inputs.iter().find(|(port_name, _, _)| *port_name == port.name);
+            let Some(&(_, input, input_type)) = mapped else {
+                if connection.is_some() {
+                    warnings.push(format!("{} input {} has no MaterialX equivalent; its connection was left out", node.title, port.name));
+                }
+                continue;
+            };
+            let input_type = if input_type.is_empty() { node_type } else { input_type };
+            if let Some((connection, source)) = connection.and_then(|c| Some((c, graph.nodes.get(&c.from_node)?))) {
+                let output = node_def_of(source).and_then(|source_def| {
+                    let port = source.outputs.get(connection.from_port)?;
+                    source_def.outputs.iter().find(|(port_name, _)| *port_name == port.name).map(|(_, output)| *output)
+                });
+                match (names.get(&connection.from_node), output) {
+                    (Some(source_name), Some(output)) => {
+                        let output = if output == "out" { String::new() } else { format!(" output=\"{}\"", escape(output)) };
+                        inputs.push(format!("    <input name=\"{}\" type=\"{}\" nodename=\"{}\"{} />", input, input_type, escape(source_name), output));
+                        written.insert(input);
+                        continue;
+                    }
+                    _ => warnings.push(format!("Connection from {} to {} has no MaterialX equivalent and was left out", source.title, node.title)),
+                }
+            }
+            if let Some(value) = node.parameters.get(input).and_then(|value| format_value(value, input_type)) {
+                inputs.push(format!("    <input name=\"{}\" type=\"{}\" value=\"{}\" />", input, input_type, escape(&value)));
+                written.insert(input);
+            }
+        }
+
+        // Inputs the node has no port for, kept from an import
+        let mut extra: Vec<_> = node.parameters.iter()
+            .filter(|(parameter, _)| parameter.as_str() != TYPE_PARAMETER && !written.contains(parameter.as_str()))
+            .filter(|(parameter, _)| !def.inputs.iter().any(|(_, input, _)| *input == parameter.as_str()))
+            .collect();
+        extra.sort_by(|a, b| a.0.cmp(b.0));
+        for (parameter, value) in extra {
+            if let Some(value_type) = value_type(value) {
+                if let Some(value) = format_value(value, value_type) {
+                    inputs.push(format!("    <input name=\"{}\" type=\"{}\" value=\"{}\" />", escape(parameter), value_type, escape(&value)));
+                }
+            }
+        }
+
+        if inputs.is_empty() {
+            document.push_str(&format!("  <{} name=\"{}\" type=\"{}\" />\n", def.category, escape(name), node_type));
+        } else {
+            document.push_str(&format!("  <{} name=\"{}\" type=\"{}\">\n{}\n  </{}>\n", def.category, escape(name), node_type, inputs.join("\n"), def.category));
+        }
+
+        let feeds_material = graph.connections.iter().any(|c| c.from_node == *id && names.contains_key(&c.to_node));
+        if node_type == "surfaceshader" && !feeds_material {
+            unassigned_shaders.push(name.clone());
+        }
+    }
+
+    // Shaders need a material to be picked up by renderers
+    for shader in unassigned_shaders {
+        let mut material = format!("{}_material", shader);
+        while !taken.insert(material.clone()) {
+            material.push('_');
+        }
+        document.push_str(&format!(
+            "  <surfacematerial name=\"{}\" type=\"material\">\n    <input name=\"surfaceshader\" type=\"surfaceshader\" nodename=\"{}\" />\n  </surfacematerial>\n",
+            escape(&material), escape(&shader)
+        ));
+    }
+    document.push_str("</materialx>\n");
+
+    MtlxExport { document, warnings }
+}
+
+/// Read a `.mtlx` document into `graph`, laying the network out to the right of `origin`
+pub fn import_document(text: &str, graph: &mut NodeGraph, origin: Pos2) -> Result<MtlxImport, String> {
+    let root = parse_xml(text)?;
+    if root.name != "materialx" {
+        return Err(format!("Expected a <materialx> document, found <{}>", root.name));
+    }
+
+    // Every node element with the nodegraph it sits in
+    let mut elements = Vec::new();
+    let mut nodegraphs = HashMap::new();
+    let mut warnings = Vec::new();
+    for child in &root.children {
+        match child.name.as_str() {
+            "nodegraph" => {
+                let graph_name = child.attr("name").unwrap_or_default();
+                nodegraphs.insert(graph_name, child);
+                for grandchild in &child.children {
+                    if !matches!(grandchild.name.as_str(), "input" | "output") {
+                        elements.push((graph_name, grandchild));
+                    }
+                }
+            }
+            // Definitions and looks have no node equivalent
+            "input" | "output" | "nodedef" | "implementation" | "look" | "collection" | "geominfo" | "typedef" => {}
+            _ => elements.push(("", child)),
+        }
+    }
+
+    // Create the nodes
+    let mut created: HashMap<(&str, &str), (NodeId, &'static MtlxNodeDef)> = HashMap::new();
+    let mut imported = Vec::new();
+    for (scope, element) in &elements {
+        let name = element.attr("name").unwrap_or(element.name.as_str());
+        let Some(def) = NODE_DEFS.iter().find(|def| def.category == element.name) else {
+            warnings.push(format!("<{}> {} has no node equivalent and was left out", element.name, name));
+            continue;
+        };
+        let mut node = create_node(def, origin);
+        node.title = name.to_string();
+        if let Some(node_type) = element.attr("type").filter(|node_type| *node_type != def.output_type) {
+            node.parameters.insert(TYPE_PARAMETER.to_string(), NodeData::String(node_type.to_string()));
+        }
+        let node_id = graph.add_node(node);
+        created.insert((*scope, name), (node_id, def));
+        imported.push((node_id, *scope, *element, def));
+    }
+
+    // Values and connections
+    for (node_id, scope, element, def) in &imported {
+        for input in element.children.iter().filter(|child| child.name == "input") {
+            let input_name = input.attr("name").unwrap_or_default();
+            let node_title = graph.nodes[node_id].title.clone();
+            let port = def.inputs.iter().find(|(_, name, _)| *name == input_name).map(|(port, _, _)| *port);
+            let port_index = port.and_then(|port| graph.nodes[node_id].inputs.iter().position(|p| p.name == port));
+
+            // Inputs inside a nodegraph may forward to the nodegraph's interface, which lives at the top level
+            let interface = input.attr("interfacename").and_then(|interface| {
+                nodegraphs.get(scope)?.children.iter()
+                    .find(|child| child.name == "input" && child.attr("name") == Some(interface))
+            });
+            let (input, input_scope) = match interface {
+                Some(interface) => (interface, ""),
+                None => (input, *scope),
+            };
+
+            if let Some(source) = resolve_source(input, input_scope, &nodegraphs) {
+                let from = created.get(&(source.0, source.1));
+                let connected = from.and_then(|(from_node, from_def)| {
+                    let output_port = from_def.outputs.iter().find(|(_, output)| *output == source.2)?.0;
+                    let from_port = graph.nodes[from_node].outputs.iter().position(|p| p.name == output_port)?;
+                    let to_port = port_index?;
+                    graph.add_connection(Connection::new(*from_node, from_port, *node_id, to_port)).ok()
+                });
+                if connected.is_none() {
+                    warnings.push(format!("Connection from {} to {}.{} has no node equivalent and was left out", source.1, node_title, input_name));
+                }
+            } else if let Some(value) = input.attr("value") {
+                let input_type = input.attr("type").unwrap_or("string");
+                match parse_value(value, input_type) {
+                    Some(value) => {
+                        if let Some(node) = graph.nodes.get_mut(node_id) {
+                            node.parameters.insert(input_name.to_string(), value);
+                        }
+                    }
+                    None => warnings.push(format!("{}.{} has a {} value, which nodes cannot hold; it was left out", node_title, input_name, input_type)),
+                }
+            }
+        }
+    }
+
+    let nodes: Vec<NodeId> = imported.iter().map(|(node_id, ..)| *node_id).collect();
+    layout(graph, &nodes, origin);
+    Ok(MtlxImport { nodes, warnings })
+}
+
+/// Scope, node name and output an input is connected to
+fn resolve_source<'a>(input: &'a Element, scope: &'a str, nodegraphs: &HashMap<&'a str, &'a Element>) -> Option<(&'a str, &'a str, &'a str)> {
+    if let Some(nodename) = input.attr("nodename") {
+        return Some((scope, nodename, input.attr("output").unwrap_or("out")));
+    }
+    // A nodegraph output: follow it to the node inside
+    let graph_name = input.attr("nodegraph")?;
+    let nodegraph = nodegraphs.get(graph_name)?;
+    let output = nodegraph.children.iter()
+        .filter(|child| child.name == "output")
+        .find(|child| match input.attr("output") {
+            Some(name) => child.attr("name") == Some(name),
+            None => true,
+        })?;
+    Some((graph_name, output.attr("nodename")?, output.attr("output").unwrap_or("out")))
+}
+
+/// Place imported nodes in columns by how far they are from the network's sources
+fn layout(graph: &mut NodeGraph, nodes: &[NodeId], origin: Pos2) {
+    let imported: HashSet<NodeId> = nodes.iter().copied().collect();
+    let mut depth: HashMap<NodeId, usize> = nodes.iter().map(|id| (*id, 0)).collect();
+    for _ in 0..nodes.len() {
+        let mut changed = false;
+        for connection in graph.connections.iter().filter(|c| imported.contains(&c.from_node) && imported.contains(&c.to_node)) {
+            let wanted = depth[&connection.from_node] + 1;
+            if wanted > depth[&connection.to_node] && wanted <= nodes.len() {
+                depth.insert(connection.to_node, wanted);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut rows: BTreeMap<usize, usize> = BTreeMap::new();
+    for node_id in nodes {
+        let column = depth[node_id];
+        let row = rows.entry(column).or_default();
+        if let Some(node) = graph.nodes.get_mut(node_id) {
+            node.position = origin + egui::vec2(column as f32 * COLUMN_WIDTH, *row as f32 * ROW_HEIGHT);
+            node.update_port_positions();
+        }
+        *row += 1;
+    }
+}
+
+/// MaterialX type of a node, which may differ from its category's usual one
+fn node_type<'a>(node: &'a Node, def: &'a MtlxNodeDef) -> &'a str {
+    match node.parameters.get(TYPE_PARAMETER) {
+        Some(NodeData::String(node_type)) => node_type,
+        _ => def.output_type,
+    }
+}
+
+/// A valid MaterialX element name from a node title
+fn element_name(title: &str) -> String {
+    let mut name: String = title.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, 'N');
+    }
+    name
+}
+
+/// MaterialX type a parameter value is written as
+fn value_type(value: &NodeData) -> Option<&'static str> {
+    match value {
+        NodeData::Float(_) => Some("float"),
+        NodeData::Integer(_) => Some("integer"),
+        NodeData::Boolean(_) => Some("boolean"),
+        NodeData::Color(_) => Some("color3"),
+        NodeData::Vector3(_) => Some("vector3"),
+        NodeData::String(_) => Some("string"),
+        _ => None,
+    }
+}
+
+fn format_value(value: &NodeData, value_type: &str) -> Option<String> {
+    let join = |values: &[f32]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+    match value {
+        NodeData::Float(v) if matches!(value_type, "color3" | "vector3") => Some(join(&[*v; 3])),
+        NodeData::Float(v) => Some(v.to_string()),
+        NodeData::Integer(v) => Some(v.to_string()),
+        NodeData::Boolean(v) => Some(v.to_string()),
+        NodeData::Color(c) if value_type == "color4" => Some(join(&c[..])),
+        NodeData::Color(c) => Some(join(&c[..3])),
+        NodeData::Vector3(v) => Some(join(&v[..])),
+        NodeData::String(s) if s.is_empty() => None,
+        NodeData::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn parse_value(value: &str, value_type: &str) -> Option<NodeData> {
+    let floats = || value.split(',').map(|v| v.trim().parse::<f32>()).collect::<Result<Vec<_>, _>>().ok();
+    match value_type {
+        "float" => value.trim().parse().ok().map(NodeData::Float),
+        "integer" => value.trim().parse().ok().map(NodeData::Integer),
+        "boolean" => value.trim().parse().ok().map(NodeData::Boolean),
+        "color3" => floats().filter(|v| v.len() == 3).map(|v| NodeData::Color([v[0], v[1], v[2], 1.0])),
+        "color4" => floats().filter(|v| v.len() == 4).map(|v| NodeData::Color([v[0], v[1], v[2], v[3]])),
+        "vector3" => floats().filter(|v| v.len() == 3).map(|v| NodeData::Vector3([v[0], v[1], v[2]])),
+        "string" | "filename" => Some(NodeData::String(value.to_string())),
+        _ => None,
+    }
+}
+
+/// An XML element: just enough of XML for MaterialX documents
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+}
+
+/// Parse the root element of an XML document; text content is ignored
+fn parse_xml(text: &str) -> Result<Element, String> {
+    let mut stack = vec![Element::default()];
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after.find("-->").ok_or("Unterminated comment")?;
+            rest = &after[end + 3..];
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest.find('>').ok_or("Unterminated declaration")?;
+            rest = &rest[end + 1..];
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').ok_or("Unterminated closing tag")?;
+            let name = after[..end].trim();
+            if stack.len() < 2 || stack.last().map(|element| element.name.as_str()) != Some(name) {
+                return Err(format!("Unexpected closing tag </{}>", name));
+            }
+            let element = stack.pop().unwrap_or_default();
+            stack.last_mut().ok_or("Unbalanced tags")?.children.push(element);
+            rest = &after[end + 1..];
+        } else {
+            let end = tag_end(rest).ok_or("Unterminated tag")?;
+            let tag = &rest[1..end];
+            let self_closing = tag.ends_with('/');
+            let element = parse_tag(tag.trim_end_matches('/'))?;
+            if self_closing {
+                stack.last_mut().ok_or("Unbalanced tags")?.children.push(element);
+            } else {
+                stack.push(element);
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    if stack.len() != 1 {
+        return Err(format!("Unclosed tag <{}>", stack.last().map(|element| element.name.as_str()).unwrap_or_default()));
+    }
+    stack.pop().and_then(|document| document.children.into_iter().next()).ok_or_else(|| "No root element".to_string())
+}
+
+/// Index of the '>' closing the tag that starts `text`, skipping quoted values
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_tag(tag: &str) -> Result<Element, String> {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let mut element = Element { name: tag[..name_end].to_string(), ..Default::default() };
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let equals = rest.find('=').ok_or_else(|| format!("Attribute without value in <{}>", element.name))?;
+        let key = rest[..equals].trim().to_string();
+        let value_start = rest[equals + 1..].trim_start();
+        let quote = value_start.chars().next().filter(|c| *c == '"' || *c == '\'')
+            .ok_or_else(|| format!("Unquoted attribute {} in <{}>", key, element.name))?;
+        let value_end = value_start[1..].find(quote).ok_or_else(|| format!("Unterminated attribute {} in <{}>", key, element.name))?;
+        element.attributes.push((key, unescape(&value_start[1..value_end + 1])));
+        rest = value_start[value_end + 2..].trim_start();
+    }
+    Ok(element)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0"?>
+<materialx version="1.38">
+  <!-- A textured, slightly metallic surface -->
+  <nodegraph name="NG_marble">
+    <input name="scale" type="float" value="2" />
+    <checkerboard name="checker" type="color3">
+      <input name="color1" type="color3" value="0.9, 0.9, 0.85" />
+    </checkerboard>
+    <mix name="blend" type="color3">
+      <input name="bg" type="color3" nodename="checker" />
+      <input name="fg" type="color3" value="0.2, 0.1, 0.05" />
+      <input name="mix" type="float" interfacename="scale" />
+    </mix>
+    <output name="out_color" type="color3" nodename="blend" />
+  </nodegraph>
+  <standard_surface name="SR_marble" type="surfaceshader">
+    <input name="base_color" type="color3" nodegraph="NG_marble" output="out_color" />
+    <input name="metalness" type="float" value="0.25" />
+    <input name="coat" type="float" value="0.5" />
+    <input name="coat_affect_color" type="vector2" value="1, 0" />
+  </standard_surface>
+  <surfacematerial name="M_marble" type="material">
+    <input name="surfaceshader" type="surfaceshader" nodename="SR_marble" />
+  </surfacematerial>
+  <place2d name="stray" type="vector2" />
+</materialx>
+"#;
+
+    fn node_by_title<'a>(graph: &'a NodeGraph, title: &str) -> &'a Node {
+        graph.nodes.values().find(|node| node.title == title).unwrap()
+    }
+
+    #[test]
+    fn test_import_builds_connected_network() {
+        let mut graph = NodeGraph::new();
+        let import = import_document(DOCUMENT, &mut graph, Pos2::ZERO).unwrap();
+        assert_eq!(import.nodes.len(), 4);
+        // The unknown category and the vector2 input are reported, not dropped silently
+        assert_eq!(import.warnings.len(), 2, "{:?}", import.warnings);
+
+        let surface = node_by_title(&graph, "SR_marble");
+        assert_eq!(surface.type_id, "MaterialX_StandardSurface");
+        assert!(matches!(surface.parameters.get("metalness"), Some(NodeData::Float(v)) if *v == 0.25));
+        assert!(matches!(surface.parameters.get("coat"), Some(NodeData::Float(v)) if *v == 0.5));
+        let blend = node_by_title(&graph, "blend");
+        assert!(matches!(blend.parameters.get("mix"), Some(NodeData::Float(v)) if *v == 2.0));
+
+        // checker → blend.Input A, blend → SR_marble.Base Color, SR_marble → M_marble.Surface
+        assert_eq!(graph.connections.len(), 3);
+        let checker = node_by_title(&graph, "checker").id;
+        assert!(graph.connections.iter().any(|c| c.from_node == checker && c.to_node == blend.id && c.to_port == 0));
+        assert!(graph.connections.iter().any(|c| c.from_node == blend.id && c.to_node == surface.id && c.to_port == 0));
+        assert!(node_by_title(&graph, "M_marble").position.x > surface.position.x);
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let mut graph = NodeGraph::new();
+        import_document(DOCUMENT, &mut graph, Pos2::ZERO).unwrap();
+        let export = export_document(&graph);
+        assert!(export.warnings.is_empty(), "{:?}", export.warnings);
+        assert!(export.document.contains("<input name=\"base_color\" type=\"color3\" nodename=\"blend\" />"));
+        assert!(export.document.contains("<input name=\"coat\" type=\"float\" value=\"0.5\" />"));
+
+        let mut reimported = NodeGraph::new();
+        let import = import_document(&export.document, &mut reimported, Pos2::ZERO).unwrap();
+        assert!(import.warnings.is_empty(), "{:?}", import.warnings);
+        assert_eq!(reimported.nodes.len(), graph.nodes.len());
+        assert_eq!(reimported.connections.len(), graph.connections.len());
+        let value = |graph: &NodeGraph, title, input| match node_by_title(graph, title).parameters.get(input) {
+            Some(NodeData::Color(c)) => Some(*c),
+            _ => None,
+        };
+        assert_eq!(value(&reimported, "checker", "color1"), value(&graph, "checker", "color1"));
+    }
+
+    #[test]
+    fn test_export_adds_material_and_skips_unknown_nodes() {
+        let mut graph = NodeGraph::new();
+        let mut image = textures::create_image_node(Pos2::ZERO);
+        image.parameters.insert("file".to_string(), NodeData::String("textures/a&b.png".to_string()));
+        let image = graph.add_node(image);
+        // Created before type ids were set; recognized by title
+        let surface = graph.add_node(shading::create_standard_surface_node(Pos2::ZERO));
+        let other = graph.add_node(Node::new(0, "Add", Pos2::ZERO));
+        graph.add_connection(Connection::new(image, 0, surface, 0)).unwrap();
+        graph.add_connection(Connection::new(image, 1, surface, 1)).unwrap();
+        graph.add_connection(Connection::new(other, 0, surface, 2)).unwrap_or_default();
+
+        let export = export_document(&graph);
+        assert!(export.document.contains("<input name=\"file\" type=\"filename\" value=\"textures/a&amp;b.png\" />"));
+        assert!(export.document.contains("<surfacematerial name=\"Standard_Surface_material\""));
+        // The Add node and the Alpha output have no MaterialX equivalent
+        assert!(export.warnings.len() >= 2, "{:?}", export.warnings);
+        assert!(parse_xml(&export.document).is_ok());
+    }
+
+    #[test]
+    fn test_malformed_documents_are_rejected() {
+        let mut graph = NodeGraph::new();
+        assert!(import_document("<materialx><mix name=\"a\"></materialx>", &mut graph, Pos2::ZERO).is_err());
+        assert!(import_document("<gltf />", &mut graph, Pos2::ZERO).is_err());
+        assert!(graph.nodes.is_empty());
+    }
+}
@@ -0,0 +1,334 @@
+//! Live material preview for MaterialX parameter panels
+//!
+//! The shader network upstream of a node is reduced on the CPU to what the
+//! preview ball can show: a base color that is solid, a checkerboard or a
+//! noise between two colors, plus metalness, roughness, emission and opacity.
+//! The panel re-evaluates the network every time it is drawn, so the ball
+//! follows parameter edits and new connections immediately.
+
+use super::mtlx;
+use crate::gpu::material_preview_callback::{MaterialPreviewCallback, MaterialPreviewUniforms};
+use crate::nodes::interface::NodeData;
+use crate::nodes::{project_paths, Node, NodeGraph, NodeId};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Size of the preview in the panel
+const PREVIEW_SIZE: f32 = 128.0;
+
+/// Deepest network the evaluation follows, which also stops cycles
+const MAX_DEPTH: usize = 32;
+
+/// Average color of each image file, so textures are not reloaded every frame
+static IMAGE_COLORS: Lazy<Mutex<HashMap<String, Option<[f32; 3]>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Pattern a color varies by over the surface
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    Solid,
+    Checker,
+    Noise,
+}
+
+/// A color as the preview can draw it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreviewColor {
+    pub pattern: Pattern,
+    /// The color of a solid, and the first color of a pattern
+    pub a: [f32; 3],
+    pub b: [f32; 3],
+    /// Pattern repeats across the surface
+    pub scale: f32,
+}
+
+impl PreviewColor {
+    pub fn solid(color: [f32; 3]) -> Self {
+        Self { pattern: Pattern::Solid, a: color, b: color, scale: 1.0 }
+    }
+
+    /// Mean color over the surface
+    pub fn average(&self) -> [f32; 3] {
+        match self.pattern {
+            Pattern::Solid => self.a,
+            _ => lerp3(self.a, self.b, 0.5),
+        }
+    }
+
+    /// Blend towards `other`; two patterns keep the first and blend the second's average in
+    pub fn lerp(&self, other: &PreviewColor, t: f32) -> Self {
+        match (self.pattern, other.pattern) {
+            (Pattern::Solid, Pattern::Solid) => Self::solid(lerp3(self.a, other.a, t)),
+            (Pattern::Solid, _) => Self { a: lerp3(self.a, other.a, t), b: lerp3(self.a, other.b, t), ..*other },
+            (_, Pattern::Solid) => Self { a: lerp3(self.a, other.a, t), b: lerp3(self.b, other.a, t), ..*self },
+            _ => {
+                let average = other.average();
+                Self { a: lerp3(self.a, average, t), b: lerp3(self.b, average, t), ..*self }
+            }
+        }
+    }
+}
+
+/// What the preview ball is shaded with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreviewMaterial {
+    pub base: PreviewColor,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emission: [f32; 3],
+    pub opacity: f32,
+}
+
+impl Default for PreviewMaterial {
+    fn default() -> Self {
+        Self { base: PreviewColor::solid([0.8, 0.8, 0.8]), metallic: 0.0, roughness: 0.2, emission: [0.0; 3], opacity: 1.0 }
+    }
+}
+
+impl PreviewMaterial {
+    pub fn uniforms(&self, plane: bool) -> MaterialPreviewUniforms {
+        let kind = match self.base.pattern {
+            Pattern::Solid => 0.0,
+            Pattern::Checker => 1.0,
+            Pattern::Noise => 2.0,
+        };
+        let [ar, ag, ab] = self.base.a;
+        let [br, bg, bb] = self.base.b;
+        let [er, eg, eb] = self.emission;
+        MaterialPreviewUniforms {
+            base_a: [ar, ag, ab, kind],
+            base_b: [br, bg, bb, self.base.scale],
+            emission: [er, eg, eb, self.opacity],
+            params: [self.metallic, self.roughness, if plane { 1.0 } else { 0.0 }, 0.0],
+        }
+    }
+}
+
+/// Material to preview for a node: the surface of shader nodes, otherwise the node's output as a base color
+pub fn evaluate_material(graph: &NodeGraph, node_id: NodeId) -> PreviewMaterial {
+    let Some(node) = graph.nodes.get(&node_id) else {
+        return PreviewMaterial::default();
+    };
+    match mtlx::node_def_of(node).map(|def| def.type_id) {
+        Some("MaterialX_StandardSurface") => surface(graph, node, 0),
+        Some("MaterialX_SurfaceShader") => upstream(graph, node, "Surface")
+            .filter(|(source, _)| mtlx::node_def_of(source).is_some_and(|def| def.type_id == "MaterialX_StandardSurface"))
+            .map(|(source, _)| surface(graph, source, 1))
+            .unwrap_or_default(),
+        _ => PreviewMaterial { base: color_output(graph, node, 0), roughness: 0.5, ..Default::default() },
+    }
+}
+
+fn surface(graph: &NodeGraph, node: &Node, depth: usize) -> PreviewMaterial {
+    let defaults = PreviewMaterial::default();
+    PreviewMaterial {
+        base: input_color(graph, node, "Base Color", "base_color", defaults.base, depth),
+        metallic: input_float(graph, node, "Metallic", "metalness", defaults.metallic, depth),
+        roughness: input_float(graph, node, "Roughness", "specular_roughness", defaults.roughness, depth),
+        emission: input_color(graph, node, "Emission", "emission_color", PreviewColor::solid(defaults.emission), depth).average(),
+        opacity: luminance(input_color(graph, node, "Opacity", "opacity", PreviewColor::solid([1.0; 3]), depth).average()),
+    }
+}
+
+/// Color a node outputs
+fn color_output(graph: &NodeGraph, node: &Node, depth: usize) -> PreviewColor {
+    let grey = PreviewColor::solid([0.5; 3]);
+    if depth > MAX_DEPTH {
+        return grey;
+    }
+    let Some(def) = mtlx::node_def_of(node) else {
+        return grey;
+    };
+    let depth = depth + 1;
+    match def.type_id {
+        "MaterialX_StandardSurface" => surface(graph, node, depth).base,
+        "MaterialX_SurfaceShader" => upstream(graph, node, "Surface")
+            .map(|(source, _)| color_output(graph, source, depth))
+            .unwrap_or(grey),
+        "MaterialX_Constant" => input_color(graph, node, "Value", "value", grey, depth),
+        "MaterialX_Image" => {
+            let file = match node.parameters.get("file") {
+                Some(NodeData::String(file)) => file.as_str(),
+                _ => "",
+            };
+            image_color(file).map(PreviewColor::solid).unwrap_or(grey)
+        }
+        "MaterialX_Checkerboard" => PreviewColor {
+            pattern: Pattern::Checker,
+            a: input_color(graph, node, "Color1", "color1", PreviewColor::solid([1.0; 3]), depth).average(),
+            b: input_color(graph, node, "Color2", "color2", PreviewColor::solid([0.0; 3]), depth).average(),
+            scale: 8.0,
+        },
+        "MaterialX_Noise" => {
+            let amplitude = input_float(graph, node, "Scale", "amplitude", 1.0, depth);
+            PreviewColor { pattern: Pattern::Noise, a: [0.0; 3], b: [amplitude; 3], scale: 6.0 }
+        }
+        "MaterialX_Mix" => {
+            let background = input_color(graph, node, "Input A", "bg", PreviewColor::solid([0.0; 3]), depth);
+            let foreground = input_color(graph, node, "Input B", "fg", PreviewColor::solid([0.0; 3]), depth);
+            background.lerp(&foreground, input_float(graph, node, "Mix Factor", "mix", 0.0, depth).clamp(0.0, 1.0))
+        }
+        "MaterialX_Switch" => {
+            if input_float(graph, node, "Selector", "which", 0.0, depth) < 1.0 {
+                input_color(graph, node, "Input A", "in1", grey, depth)
+            } else {
+                input_color(graph, node, "Input B", "in2", grey, depth)
+            }
+        }
+        "MaterialX_Normalize" => {
+            let [x, y, z] = input_color(graph, node, "Vector", "in", grey, depth).average();
+            let length = (x * x + y * y + z * z).sqrt().max(f32::EPSILON);
+            PreviewColor::solid([x / length, y / length, z / length])
+        }
+        "MaterialX_DotProduct" => {
+            let [ax, ay, az] = input_color(graph, node, "Vector A", "in1", grey, depth).average();
+            let [bx, by, bz] = input_color(graph, node, "Vector B", "in2", grey, depth).average();
+            PreviewColor::solid([ax * bx + ay * by + az * bz; 3])
+        }
+        "MaterialX_CrossProduct" => {
+            let [ax, ay, az] = input_color(graph, node, "Vector A", "in1", grey, depth).average();
+            let [bx, by, bz] = input_color(graph, node, "Vector B", "in2", grey, depth).average();
+            PreviewColor::solid([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+        }
+        _ => grey,
+    }
+}
+
+/// Node connected to an input port, and the output it is connected from
+fn upstream<'a>(graph: &'a NodeGraph, node: &Node, port: &str) -> Option<(&'a Node, usize)> {
+    let port_index = node.inputs.iter().position(|p| p.name == port)?;
+    let connection = graph.connections.iter().find(|c| c.to_node == node.id && c.to_port == port_index)?;
+    graph.nodes.get(&connection.from_node).map(|source| (source, connection.from_port))
+}
+
+/// An input's color: from its connection, else from its parameter, else the default
+fn input_color(graph: &NodeGraph, node: &Node, port: &str, parameter: &str, default: PreviewColor, depth: usize) -> PreviewColor {
+    if let Some((source, _)) = upstream(graph, node, port) {
+        return color_output(graph, source, depth);
+    }
+    match node.parameters.get(parameter) {
+        Some(NodeData::Color([r, g, b, _])) => PreviewColor::solid([*r, *g, *b]),
+        Some(NodeData::Vector3(v)) => PreviewColor::solid(*v),
+        Some(NodeData::Float(v)) => PreviewColor::solid([*v; 3]),
+        _ => default,
+    }
+}
+
+/// An input's value: a connected color counts by its luminance
+fn input_float(graph: &NodeGraph, node: &Node, port: &str, parameter: &str, default: f32, depth: usize) -> f32 {
+    if let Some((source, _)) = upstream(graph, node, port) {
+        return luminance(color_output(graph, source, depth).average());
+    }
+    match node.parameters.get(parameter) {
+        Some(NodeData::Float(v)) => *v,
+        Some(NodeData::Integer(v)) => *v as f32,
+        Some(NodeData::Color(c)) => luminance([c[0], c[1], c[2]]),
+        _ => default,
+    }
+}
+
+/// Average linear color of an image file; None when it cannot be read
+fn image_color(file: &str) -> Option<[f32; 3]> {
+    if file.is_empty() {
+        return None;
+    }
+    let mut cache = IMAGE_COLORS.lock().ok()?;
+    *cache.entry(file.to_string()).or_insert_with(|| {
+        let image = image::open(project_paths::resolve_path(file)).ok()?.thumbnail(16, 16).to_rgb8();
+        let count = (image.width() * image.height()).max(1) as f32;
+        let mut sum = [0.0f32; 3];
+        for pixel in image.pixels() {
+            for (channel, value) in sum.iter_mut().zip(pixel.0) {
+                // Textures are stored sRGB encoded
+                *channel += (value as f32 / 255.0).powf(2.2);
+            }
+        }
+        Some(sum.map(|channel| channel / count))
+    })
+}
+
+fn lerp3(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t, a[2] + (b[2] - a[2]) * t]
+}
+
+fn luminance([r, g, b]: [f32; 3]) -> f32 {
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Draw the preview ball of a node, with a toggle between sphere and plane
+pub fn show(ui: &mut egui::Ui, graph: &NodeGraph, node_id: NodeId) {
+    let plane_id = egui::Id::new(("materialx_preview_plane", node_id));
+    let mut plane = ui.data(|data| data.get_temp::<bool>(plane_id)).unwrap_or(false);
+
+    ui.horizontal(|ui| {
+        ui.label("Preview");
+        ui.selectable_value(&mut plane, false, "Sphere");
+        ui.selectable_value(&mut plane, true, "Plane");
+    });
+    ui.data_mut(|data| data.insert_temp(plane_id, plane));
+
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(PREVIEW_SIZE, PREVIEW_SIZE), egui::Sense::hover());
+    let material = evaluate_material(graph, node_id);
+    ui.painter().add(egui_wgpu::Callback::new_paint_callback(
+        rect,
+        MaterialPreviewCallback { key: node_id as u64, uniforms: material.uniforms(plane) },
+    ));
+    ui.separator();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::Connection;
+    use egui::Pos2;
+
+    fn add(graph: &mut NodeGraph, type_id: &str) -> NodeId {
+        graph.add_node(mtlx::create_node(mtlx::node_def(type_id).unwrap(), Pos2::ZERO))
+    }
+
+    #[test]
+    fn test_surface_parameters_reach_the_preview() {
+        let mut graph = NodeGraph::new();
+        let surface = add(&mut graph, "MaterialX_StandardSurface");
+        let node = graph.nodes.get_mut(&surface).unwrap();
+        node.parameters.insert("base_color".to_string(), NodeData::Color([1.0, 0.0, 0.0, 1.0]));
+        node.parameters.insert("metalness".to_string(), NodeData::Float(1.0));
+
+        let material = evaluate_material(&graph, surface);
+        assert_eq!(material.base, PreviewColor::solid([1.0, 0.0, 0.0]));
+        assert_eq!(material.metallic, 1.0);
+        assert_eq!(material.opacity, 1.0);
+    }
+
+    #[test]
+    fn test_connected_patterns_are_evaluated() {
+        let mut graph = NodeGraph::new();
+        let checker = add(&mut graph, "MaterialX_Checkerboard");
+        let constant = add(&mut graph, "MaterialX_Constant");
+        let mix = add(&mut graph, "MaterialX_Mix");
+        let surface = add(&mut graph, "MaterialX_StandardSurface");
+        let material = add(&mut graph, "MaterialX_SurfaceShader");
+        graph.nodes.get_mut(&constant).unwrap().parameters.insert("value".to_string(), NodeData::Color([0.0, 0.0, 1.0, 1.0]));
+        graph.nodes.get_mut(&mix).unwrap().parameters.insert("mix".to_string(), NodeData::Float(0.5));
+        for (from, to, port) in [(checker, mix, 0), (constant, mix, 1), (mix, surface, 0), (surface, material, 0)] {
+            graph.add_connection(Connection::new(from, 0, to, port)).unwrap();
+        }
+
+        let preview = evaluate_material(&graph, material);
+        assert_eq!(preview.base.pattern, Pattern::Checker);
+        assert_eq!(preview.base.a, [0.5, 0.5, 1.0]);
+        assert_eq!(preview.base.b, [0.0, 0.0, 0.5]);
+        // A texture node previews its output as the base color
+        assert_eq!(evaluate_material(&graph, constant).base, PreviewColor::solid([0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_cycles_do_not_recurse_forever() {
+        let mut graph = NodeGraph::new();
+        let a = add(&mut graph, "MaterialX_Mix");
+        let b = add(&mut graph, "MaterialX_Mix");
+        graph.add_connection(Connection::new(a, 0, b, 0)).unwrap();
+        graph.add_connection(Connection::new(b, 0, a, 0)).unwrap();
+        assert_eq!(evaluate_material(&graph, a).base.pattern, Pattern::Solid);
+    }
+}
@@ -1,6 +1,7 @@
 //! MaterialX shading nodes for surface and BSDF operations
 
 use crate::nodes::Node;
+use crate::nodes::interface::{NodeData, ParameterChange};
 use egui::{Color32, Pos2};
 
 /// Create a MaterialX Standard Surface node
@@ -18,10 +19,51 @@ pub fn create_standard_surface_node(position: Pos2) -> Node {
     
     // Output
     node.add_output("Surface");
+
+    // Values of unconnected inputs, named after the MaterialX inputs
+    node.parameters.insert("base_color".to_string(), NodeData::Color([0.8, 0.8, 0.8, 1.0]));
+    node.parameters.insert("metalness".to_string(), NodeData::Float(0.0));
+    node.parameters.insert("specular_roughness".to_string(), NodeData::Float(0.2));
+    node.parameters.insert("emission_color".to_string(), NodeData::Color([0.0, 0.0, 0.0, 1.0]));
+    node.parameters.insert("opacity".to_string(), NodeData::Color([1.0, 1.0, 1.0, 1.0]));
     
     node
 }
 
+/// Parameter interface of the Standard Surface node: values of its unconnected inputs
+pub fn build_standard_surface_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+    let mut changes = Vec::new();
+    ui.heading("Standard Surface");
+    ui.separator();
+
+    for (label, parameter) in [("Base Color", "base_color"), ("Emission", "emission_color"), ("Opacity", "opacity")] {
+        let mut color = match node.parameters.get(parameter) {
+            Some(NodeData::Color(color)) => *color,
+            _ => [0.0, 0.0, 0.0, 1.0],
+        };
+        ui.horizontal(|ui| {
+            ui.label(format!("{}:", label));
+            if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
+                changes.push(ParameterChange { parameter: parameter.to_string(), value: NodeData::Color(color) });
+            }
+        });
+    }
+    for (label, parameter) in [("Metallic", "metalness"), ("Roughness", "specular_roughness")] {
+        let mut value = match node.parameters.get(parameter) {
+            Some(NodeData::Float(value)) => *value,
+            _ => 0.0,
+        };
+        ui.horizontal(|ui| {
+            ui.label(format!("{}:", label));
+            if ui.add(egui::Slider::new(&mut value, 0.0..=1.0)).changed() {
+                changes.push(ParameterChange { parameter: parameter.to_string(), value: NodeData::Float(value) });
+            }
+        });
+    }
+
+    changes
+}
+
 /// Create a MaterialX Surface Shader node
 pub fn create_surface_shader_node(position: Pos2) -> Node {
     let mut node = Node::new(0, "Surface Shader", position)
@@ -1,21 +1,56 @@
 //! MaterialX texture and image nodes
 
 use crate::nodes::Node;
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::project_paths::{self, PathKind};
 use egui::{Color32, Pos2};
 
+/// Image formats MaterialX image nodes read
+const TEXTURE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "exr", "tif", "tiff", "hdr", "tx"];
+
 /// Create a MaterialX Image node
 pub fn create_image_node(position: Pos2) -> Node {
     let mut node = Node::new(0, "Image", position)
         .with_color(Color32::from_rgb(140, 180, 140)); // Green-ish for textures
+    node.type_id = "MaterialX_Image".to_string();
 
     node.add_input("File");
     node.add_input("UV");
     node.add_output("Color");
     node.add_output("Alpha");
+    node.parameters.insert("file".to_string(), NodeData::String(String::new()));
     
     node
 }
 
+/// Parameter interface of the Image node: the texture file it reads
+pub fn build_image_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+    let mut changes = Vec::new();
+    let file = match node.parameters.get("file") {
+        Some(NodeData::String(file)) => file.clone(),
+        _ => String::new(),
+    };
+
+    ui.heading("Image");
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("File:");
+        let browse = || rfd::FileDialog::new()
+            .add_filter("Images", TEXTURE_EXTENSIONS)
+            .add_filter("All Files", &["*"])
+            .set_title("Select Texture")
+            .pick_file();
+        if let Some(file) = project_paths::path_browser(ui, &file, "Select texture...", PathKind::Input(TEXTURE_EXTENSIONS), browse) {
+            changes.push(ParameterChange {
+                parameter: "file".to_string(),
+                value: NodeData::String(file),
+            });
+        }
+    });
+
+    changes
+}
+
 /// Create a MaterialX Noise node
 pub fn create_noise_node(position: Pos2) -> Node {
     let mut node = Node::new(0, "Noise", position)
@@ -61,18 +61,14 @@ pub fn create_shader_workspace_node(position: Pos2) -> Node {
     internal_graph.add_node(surface_node);
     
     // Connect base color to surface
-    let _ = internal_graph.add_connection(Connection {
-        from_node: 1,
-        from_port: 0,
-        to_node: 2,
-        to_port: 0,
-    });
+    let _ = internal_graph.add_connection(Connection::new(1, 0, 2, 0));
     
     // Set up the workspace node with the internal graph
     workspace_node.node_type = NodeType::Workspace {
         graph: internal_graph,
         workspace_type: "MaterialX Shader".to_string(),
         port_mappings: Vec::new(),
+        reference: None,
     };
     
     // Add external ports with mappings to internal nodes
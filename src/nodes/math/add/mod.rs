@@ -12,6 +12,7 @@ pub mod parameters;
 
 use egui::Color32;
 use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
 
 /// Addition node that takes two numeric inputs and produces their sum
 #[derive(Default)]
@@ -29,9 +30,11 @@ impl NodeFactory for AddNodeFactory {
         .with_icon("➕")
         .with_inputs(vec![
             PortDefinition::required("A", DataType::Float)
-                .with_description("First input value"),
+                .with_description("First input value")
+                .with_default(NodeData::Float(0.0)),
             PortDefinition::required("B", DataType::Float)
-                .with_description("Second input value"),
+                .with_description("Second input value")
+                .with_default(NodeData::Float(0.0)),
         ])
         .with_outputs(vec![
             PortDefinition::required("Result", DataType::Float)
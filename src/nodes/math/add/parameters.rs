@@ -35,38 +35,6 @@ impl AddNode {
         ui.heading("Addition Parameters");
         ui.separator();
         
-        // Input A
-        ui.horizontal(|ui| {
-            ui.label("Input A:");
-            let mut input_a = node.parameters.get("input_a")
-                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
-                .unwrap_or(0.0);
-            
-            if ui.add(egui::Slider::new(&mut input_a, f32::NEG_INFINITY..=f32::INFINITY).step_by(0.1)).changed() {
-                changes.push(ParameterChange {
-                    parameter: "input_a".to_string(),
-                    value: NodeData::Float(input_a),
-                });
-            }
-        });
-        
-        // Input B
-        ui.horizontal(|ui| {
-            ui.label("Input B:");
-            let mut input_b = node.parameters.get("input_b")
-                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
-                .unwrap_or(0.0);
-            
-            if ui.add(egui::Slider::new(&mut input_b, f32::NEG_INFINITY..=f32::INFINITY).step_by(0.1)).changed() {
-                changes.push(ParameterChange {
-                    parameter: "input_b".to_string(),
-                    value: NodeData::Float(input_b),
-                });
-            }
-        });
-        
-        ui.separator();
-        
         // Precision
         ui.horizontal(|ui| {
             ui.label("Precision:");
@@ -0,0 +1,94 @@
+//! Bitwise functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::math::integer_arithmetic::logic::extract_integer;
+
+/// Bitwise operation applied to A (and B)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitwiseOperation {
+    And,
+    Or,
+    Xor,
+    Not,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BitwiseOperation {
+    /// All operations in UI order
+    pub const ALL: [BitwiseOperation; 6] = [
+        BitwiseOperation::And,
+        BitwiseOperation::Or,
+        BitwiseOperation::Xor,
+        BitwiseOperation::Not,
+        BitwiseOperation::ShiftLeft,
+        BitwiseOperation::ShiftRight,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            BitwiseOperation::And => "AND",
+            BitwiseOperation::Or => "OR",
+            BitwiseOperation::Xor => "XOR",
+            BitwiseOperation::Not => "NOT",
+            BitwiseOperation::ShiftLeft => "Shift Left",
+            BitwiseOperation::ShiftRight => "Shift Right",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to AND)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|op| op.name() == name).unwrap_or(BitwiseOperation::And)
+    }
+    
+    /// Apply the operation; shift amounts are masked to 0..31
+    pub fn apply(&self, a: i32, b: i32) -> i32 {
+        match self {
+            BitwiseOperation::And => a & b,
+            BitwiseOperation::Or => a | b,
+            BitwiseOperation::Xor => a ^ b,
+            BitwiseOperation::Not => !a,
+            BitwiseOperation::ShiftLeft => a.wrapping_shl(b as u32),
+            BitwiseOperation::ShiftRight => a.wrapping_shr(b as u32),
+        }
+    }
+}
+
+/// Core Bitwise data and functionality
+#[derive(Debug, Clone)]
+pub struct BitwiseLogic {
+    pub a: i32,
+    pub b: i32,
+    pub operation: BitwiseOperation,
+}
+
+impl Default for BitwiseLogic {
+    fn default() -> Self {
+        Self { a: 0, b: 0, operation: BitwiseOperation::And }
+    }
+}
+
+impl BitwiseLogic {
+    /// Process input data, falling back to parameters for unconnected inputs
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_integer).unwrap_or(self.a);
+        let b = inputs.get(1).and_then(extract_integer).unwrap_or(self.b);
+        vec![NodeData::Integer(self.operation.apply(a, b))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitwise_operations() {
+        assert_eq!(BitwiseOperation::And.apply(0b1100, 0b1010), 0b1000);
+        assert_eq!(BitwiseOperation::Or.apply(0b1100, 0b1010), 0b1110);
+        assert_eq!(BitwiseOperation::Xor.apply(0b1100, 0b1010), 0b0110);
+        assert_eq!(BitwiseOperation::Not.apply(0, 123), -1);
+        assert_eq!(BitwiseOperation::ShiftLeft.apply(1, 4), 16);
+        assert_eq!(BitwiseOperation::ShiftRight.apply(-16, 2), -4);
+    }
+}
@@ -0,0 +1,41 @@
+//! Bitwise node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Bitwise operation node (AND, OR, XOR, NOT, shifts)
+#[derive(Default)]
+pub struct BitwiseNodeFactory;
+
+impl NodeFactory for BitwiseNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Bitwise",
+            "Bitwise",
+            NodeCategory::new(&["Math", "Integer"]),
+            "Bitwise AND, OR, XOR, NOT and shifts on 32-bit integers"
+        )
+        .with_color(Color32::from_rgb(40, 60, 55))
+        .with_icon("&")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Integer)
+                .with_description("First operand"),
+            PortDefinition::optional("B", DataType::Integer)
+                .with_description("Second operand or shift amount (unused by NOT)"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Integer)
+                .with_description("Bitwise result"),
+        ])
+        .with_tags(vec!["math", "integer", "int", "bitwise", "bits", "mask", "shift"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
@@ -0,0 +1,75 @@
+//! Bitwise node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{BitwiseLogic, BitwiseOperation};
+
+/// Bitwise node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct BitwiseNode;
+
+impl BitwiseNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Bitwise Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Operation:");
+            let mut operation = logic.operation;
+            egui::ComboBox::from_id_salt("bitwise_operation")
+                .selected_text(operation.name())
+                .show_ui(ui, |ui| {
+                    for op in BitwiseOperation::ALL {
+                        if ui.selectable_value(&mut operation, op, op.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "operation".to_string(),
+                                value: NodeData::String(op.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        ui.separator();
+        
+        for (param, label, current) in [("a", "A:", logic.a), ("b", "B:", logic.b)] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(1.0)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Integer(value),
+                    });
+                }
+            });
+        }
+        
+        ui.separator();
+        let result = logic.operation.apply(logic.a, logic.b);
+        ui.label(format!("Current Result: {}", result));
+        ui.monospace(format!("{:032b}", result));
+        
+        changes
+    }
+    
+    /// Build BitwiseLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> BitwiseLogic {
+        BitwiseLogic {
+            a: node.int_parameter("a", 0),
+            b: node.int_parameter("b", 0),
+            operation: node.parameters.get("operation")
+                .and_then(|v| if let NodeData::String(s) = v { Some(BitwiseOperation::from_name(s)) } else { None })
+                .unwrap_or(BitwiseOperation::And),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
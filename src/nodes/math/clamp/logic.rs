@@ -0,0 +1,40 @@
+//! Clamp node functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Defaults of the Value, Min and Max inputs
+pub const INPUT_DEFAULTS: [f32; 3] = [0.5, 0.0, 1.0];
+
+/// Core Clamp functionality
+#[derive(Debug, Clone, Default)]
+pub struct ClampLogic;
+
+impl ClampLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let min = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let max = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        vec![NodeData::Float(self.compute(value, min, max))]
+    }
+
+    /// Limit `value` to the range between the bounds; swapped bounds are put in order
+    pub fn compute(&self, value: f32, min: f32, max: f32) -> f32 {
+        let (low, high) = if min <= max { (min, max) } else { (max, min) };
+        value.clamp(low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_limits_and_orders_bounds() {
+        let logic = ClampLogic::default();
+        assert_eq!(logic.compute(1.5, 0.0, 1.0), 1.0);
+        assert_eq!(logic.compute(-2.0, 1.0, -1.0), -1.0);
+        assert_eq!(logic.compute(0.25, 0.0, 1.0), 0.25);
+    }
+}
@@ -0,0 +1,48 @@
+//! Clamp node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Limits a value to the range between Min and Max
+#[derive(Default)]
+pub struct ClampNodeFactory;
+
+impl NodeFactory for ClampNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Clamp",
+            "Clamp",
+            NodeCategory::new(&["Math", "Range"]),
+            "Limits a value to the range between Min and Max"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("⊓")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to limit")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("Min", DataType::Float)
+                .with_description("Lower bound")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("Max", DataType::Float)
+                .with_description("Upper bound")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("Value limited to [Min, Max]"),
+        ])
+        .with_tags(vec!["math", "clamp", "limit", "range", "min", "max"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
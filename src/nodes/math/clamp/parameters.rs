@@ -0,0 +1,30 @@
+//! Clamp node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::ClampLogic;
+
+/// Clamp node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ClampNode;
+
+impl ClampNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (Value, Min and Max are edited under the generated Input Defaults)
+    pub fn build_interface(_node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        ui.heading("Clamp Parameters");
+        ui.separator();
+        
+        Vec::new()
+    }
+    
+    /// Build ClampLogic from the node's stored parameters
+    pub fn create_logic(_node: &Node) -> ClampLogic {
+        ClampLogic
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,54 @@
+//! Convert Units functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::units::Unit;
+
+/// Core Convert Units data and functionality
+#[derive(Debug, Clone)]
+pub struct ConvertUnitsLogic {
+    /// Unit of the incoming value
+    pub from_unit: Unit,
+    /// Unit of the outgoing value
+    pub to_unit: Unit,
+    /// Value used when the input is unconnected (in the From unit)
+    pub value: f32,
+}
+
+impl Default for ConvertUnitsLogic {
+    fn default() -> Self {
+        Self {
+            from_unit: Unit::Meters,
+            to_unit: Unit::Centimeters,
+            value: 1.0,
+        }
+    }
+}
+
+impl ConvertUnitsLogic {
+    /// Convert the input (or the fallback value) from one unit to the other
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let value = match inputs.into_iter().next() {
+            Some(NodeData::Integer(i)) => NodeData::Float(i as f32),
+            Some(NodeData::None) | None => NodeData::Float(self.value),
+            Some(data) => data,
+        };
+        vec![self.from_unit.convert_data(value, self.to_unit)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_fallback_value() {
+        let logic = ConvertUnitsLogic { from_unit: Unit::Meters, to_unit: Unit::Millimeters, value: 2.0 };
+        assert!(matches!(logic.process(vec![NodeData::None])[0], NodeData::Float(v) if (v - 2000.0).abs() < 1e-2));
+    }
+
+    #[test]
+    fn test_incompatible_units_pass_through() {
+        let logic = ConvertUnitsLogic { from_unit: Unit::Degrees, to_unit: Unit::Seconds, value: 0.0 };
+        assert!(matches!(logic.process(vec![NodeData::Float(90.0)])[0], NodeData::Float(v) if v == 90.0));
+    }
+}
@@ -0,0 +1,42 @@
+//! Convert Units node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::units::Unit;
+
+/// Explicit unit conversion between lengths, angles or times
+#[derive(Default)]
+pub struct ConvertUnitsNodeFactory;
+
+impl NodeFactory for ConvertUnitsNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_ConvertUnits",
+            "Convert Units",
+            NodeCategory::math(),
+            "Converts a float, vector or buffer between units (m/cm/mm, degrees/radians, seconds/frames)"
+        )
+        .with_color(Color32::from_rgb(45, 60, 70))
+        .with_icon("📏")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value in the From unit")
+                .with_unit(Unit::Meters),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Value", DataType::Float)
+                .with_description("Value in the To unit")
+                .with_unit(Unit::Centimeters),
+        ])
+        .with_tags(vec!["math", "units", "convert", "meters", "degrees", "radians", "frames", "seconds"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
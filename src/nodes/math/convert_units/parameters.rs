@@ -0,0 +1,90 @@
+//! Convert Units node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::units::{self, Unit};
+use crate::nodes::Node;
+use super::logic::ConvertUnitsLogic;
+
+/// Convert Units node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ConvertUnitsNode;
+
+impl ConvertUnitsNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Convert Units Parameters");
+        ui.separator();
+        
+        let mut from_unit = logic.from_unit;
+        let mut to_unit = logic.to_unit;
+        ui.horizontal(|ui| {
+            ui.label("From:");
+            if units::unit_combo_box(ui, ("convert_units_from", node.id), &mut from_unit, None) {
+                changes.push(ParameterChange {
+                    parameter: "from_unit".to_string(),
+                    value: NodeData::String(from_unit.name().to_string()),
+                });
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("To:");
+            if units::unit_combo_box(ui, ("convert_units_to", node.id), &mut to_unit, from_unit.kind()) {
+                changes.push(ParameterChange {
+                    parameter: "to_unit".to_string(),
+                    value: NodeData::String(to_unit.name().to_string()),
+                });
+            }
+        });
+        
+        // Keep port annotations in sync so connections convert into/out of this node
+        if let Some(port) = node.inputs.get_mut(0) {
+            port.unit = from_unit;
+        }
+        if let Some(port) = node.outputs.get_mut(0) {
+            port.unit = to_unit;
+        }
+        
+        if from_unit.kind().is_some() && from_unit.kind() != to_unit.kind() {
+            ui.colored_label(egui::Color32::YELLOW, "⚠ Units measure different quantities; value passes through");
+        }
+        
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("Value:");
+            let mut value = logic.value;
+            if units::unit_drag_value(ui, &mut value, from_unit, 0.1).changed() {
+                changes.push(ParameterChange {
+                    parameter: "value".to_string(),
+                    value: NodeData::Float(value),
+                });
+            }
+        });
+        ui.label("Used when the Value input is unconnected");
+        
+        ui.separator();
+        ui.label(format!("Result: {}{}", from_unit.convert(logic.value, to_unit), to_unit.suffix()));
+        
+        changes
+    }
+    
+    /// Build ConvertUnitsLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ConvertUnitsLogic {
+        let defaults = ConvertUnitsLogic::default();
+        let get_unit = |name: &str, default: Unit| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(Unit::from_name(s)) } else { None })
+            .unwrap_or(default);
+        ConvertUnitsLogic {
+            from_unit: get_unit("from_unit", defaults.from_unit),
+            to_unit: get_unit("to_unit", defaults.to_unit),
+            value: node.float_parameter("value", defaults.value),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
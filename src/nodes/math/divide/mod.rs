@@ -14,6 +14,7 @@ pub use parameters::DivideNode;
 
 use egui::Color32;
 use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
 
 /// Division node that takes two numeric inputs and produces their quotient
 #[derive(Default)]
@@ -31,9 +32,11 @@ impl NodeFactory for DivideNodeFactory {
         .with_icon("÷")
         .with_inputs(vec![
             PortDefinition::required("A", DataType::Float)
-                .with_description("Dividend (number to be divided)"),
+                .with_description("Dividend (number to be divided)")
+                .with_default(NodeData::Float(0.0)),
             PortDefinition::required("B", DataType::Float)
-                .with_description("Divisor (number to divide by)"),
+                .with_description("Divisor (number to divide by)")
+                .with_default(NodeData::Float(1.0)),
         ])
         .with_outputs(vec![
             PortDefinition::required("Result", DataType::Float)
@@ -0,0 +1,57 @@
+//! Fit node functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Defaults of the Value, Min and Max inputs
+pub const INPUT_DEFAULTS: [f32; 3] = [0.5, 0.0, 1.0];
+
+/// Core Fit data and functionality
+#[derive(Debug, Clone)]
+pub struct FitLogic {
+    /// Keep the result inside 0..1
+    pub clamp: bool,
+}
+
+impl Default for FitLogic {
+    fn default() -> Self {
+        Self { clamp: true }
+    }
+}
+
+impl FitLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let min = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let max = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        vec![NodeData::Float(self.compute(value, min, max))]
+    }
+
+    /// Position of `value` between `min` and `max` as 0..1
+    pub fn compute(&self, value: f32, min: f32, max: f32) -> f32 {
+        let t = inverse_lerp(min, max, value);
+        if self.clamp { t.clamp(0.0, 1.0) } else { t }
+    }
+}
+
+/// Where `value` lies from `min` (0) to `max` (1); an empty range gives 0
+pub fn inverse_lerp(min: f32, max: f32, value: f32) -> f32 {
+    if max == min { 0.0 } else { (value - min) / (max - min) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_normalizes_and_clamps() {
+        let logic = FitLogic::default();
+        assert_eq!(logic.compute(15.0, 10.0, 20.0), 0.5);
+        assert_eq!(logic.compute(30.0, 10.0, 20.0), 1.0);
+        // An empty range fits everything to 0
+        assert_eq!(logic.compute(3.0, 2.0, 2.0), 0.0);
+        let logic = FitLogic { clamp: false, ..logic };
+        assert_eq!(logic.compute(30.0, 10.0, 20.0), 2.0);
+    }
+}
@@ -0,0 +1,48 @@
+//! Fit node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Where a value lies between Min and Max as 0..1, the inverse of Lerp
+#[derive(Default)]
+pub struct FitNodeFactory;
+
+impl NodeFactory for FitNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Fit",
+            "Fit",
+            NodeCategory::new(&["Math", "Range"]),
+            "Where a value lies between Min and Max as 0..1, the inverse of Lerp"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("⊡")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to fit")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("Min", DataType::Float)
+                .with_description("Value that fits to 0")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("Max", DataType::Float)
+                .with_description("Value that fits to 1")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("Position of Value in the range, 0 at Min and 1 at Max"),
+        ])
+        .with_tags(vec!["math", "fit", "normalize", "inverse lerp", "range", "fraction"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
@@ -0,0 +1,176 @@
+//! Math Function functional operations
+//!
+//! Results stay finite where the math allows: out-of-domain inputs (square
+//! roots of negatives, arcsines past ±1, modulo by zero) give 0 or the nearest
+//! defined value instead of NaN, so one bad input doesn't poison a chain.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Function applied to A, and to B where it takes two arguments
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatFunction {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Floor,
+    Ceil,
+    Round,
+    Abs,
+    Sqrt,
+    Power,
+    Modulo,
+}
+
+impl FloatFunction {
+    /// All functions in UI order
+    pub const ALL: [FloatFunction; 14] = [
+        FloatFunction::Sin,
+        FloatFunction::Cos,
+        FloatFunction::Tan,
+        FloatFunction::Asin,
+        FloatFunction::Acos,
+        FloatFunction::Atan,
+        FloatFunction::Atan2,
+        FloatFunction::Floor,
+        FloatFunction::Ceil,
+        FloatFunction::Round,
+        FloatFunction::Abs,
+        FloatFunction::Sqrt,
+        FloatFunction::Power,
+        FloatFunction::Modulo,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            FloatFunction::Sin => "Sin",
+            FloatFunction::Cos => "Cos",
+            FloatFunction::Tan => "Tan",
+            FloatFunction::Asin => "Asin",
+            FloatFunction::Acos => "Acos",
+            FloatFunction::Atan => "Atan",
+            FloatFunction::Atan2 => "Atan2",
+            FloatFunction::Floor => "Floor",
+            FloatFunction::Ceil => "Ceil",
+            FloatFunction::Round => "Round",
+            FloatFunction::Abs => "Abs",
+            FloatFunction::Sqrt => "Sqrt",
+            FloatFunction::Power => "Power",
+            FloatFunction::Modulo => "Modulo",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Sin)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|function| function.name() == name).unwrap_or(FloatFunction::Sin)
+    }
+    
+    /// Whether B is used
+    pub fn uses_b(&self) -> bool {
+        matches!(self, FloatFunction::Atan2 | FloatFunction::Power | FloatFunction::Modulo)
+    }
+    
+    /// Whether the function takes an angle
+    pub fn takes_angle(&self) -> bool {
+        matches!(self, FloatFunction::Sin | FloatFunction::Cos | FloatFunction::Tan)
+    }
+    
+    /// Whether the function returns an angle
+    pub fn returns_angle(&self) -> bool {
+        matches!(self, FloatFunction::Asin | FloatFunction::Acos | FloatFunction::Atan | FloatFunction::Atan2)
+    }
+    
+    /// Apply the function to radians and plain values
+    pub fn apply(&self, a: f32, b: f32) -> f32 {
+        let result = match self {
+            FloatFunction::Sin => a.sin(),
+            FloatFunction::Cos => a.cos(),
+            FloatFunction::Tan => a.tan(),
+            FloatFunction::Asin => a.clamp(-1.0, 1.0).asin(),
+            FloatFunction::Acos => a.clamp(-1.0, 1.0).acos(),
+            FloatFunction::Atan => a.atan(),
+            FloatFunction::Atan2 => a.atan2(b),
+            FloatFunction::Floor => a.floor(),
+            FloatFunction::Ceil => a.ceil(),
+            FloatFunction::Round => a.round(),
+            FloatFunction::Abs => a.abs(),
+            FloatFunction::Sqrt => a.max(0.0).sqrt(),
+            FloatFunction::Power => a.powf(b),
+            // Euclidean, so negative values wrap into [0, |B|) like a repeating cycle
+            FloatFunction::Modulo => if b == 0.0 { 0.0 } else { a.rem_euclid(b) },
+        };
+        if result.is_nan() { 0.0 } else { result }
+    }
+}
+
+/// Defaults of the A and B inputs
+pub const INPUT_DEFAULTS: [f32; 2] = [0.0, 1.0];
+
+/// Core Math Function data and functionality
+#[derive(Debug, Clone)]
+pub struct FunctionLogic {
+    pub function: FloatFunction,
+    /// Angles going in and out of the trigonometric functions are in degrees
+    pub degrees: bool,
+}
+
+impl Default for FunctionLogic {
+    fn default() -> Self {
+        Self { function: FloatFunction::Sin, degrees: true }
+    }
+}
+
+impl FunctionLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let b = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        vec![NodeData::Float(self.compute(a, b))]
+    }
+    
+    /// Apply the function, converting angles from and to degrees when set
+    pub fn compute(&self, a: f32, b: f32) -> f32 {
+        let a = if self.degrees && self.function.takes_angle() { a.to_radians() } else { a };
+        let result = self.function.apply(a, b);
+        if self.degrees && self.function.returns_angle() { result.to_degrees() } else { result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compute(function: FloatFunction, a: f32, b: f32) -> f32 {
+        FunctionLogic { function, ..Default::default() }.compute(a, b)
+    }
+
+    #[test]
+    fn test_trigonometry_in_degrees() {
+        assert!((compute(FloatFunction::Sin, 90.0, 0.0) - 1.0).abs() < 1e-6);
+        assert!((compute(FloatFunction::Atan2, 1.0, -1.0) - 135.0).abs() < 1e-4);
+        let radians = FunctionLogic { function: FloatFunction::Cos, degrees: false, ..Default::default() };
+        assert!((radians.compute(std::f32::consts::PI, 0.0) + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rounding_powers_and_remainders() {
+        assert_eq!(compute(FloatFunction::Floor, -1.5, 0.0), -2.0);
+        assert_eq!(compute(FloatFunction::Ceil, 1.2, 0.0), 2.0);
+        assert_eq!(compute(FloatFunction::Round, 2.5, 0.0), 3.0);
+        assert_eq!(compute(FloatFunction::Power, 2.0, 10.0), 1024.0);
+        assert_eq!(compute(FloatFunction::Modulo, -1.0, 4.0), 3.0);
+    }
+
+    #[test]
+    fn test_out_of_domain_inputs_stay_finite() {
+        assert_eq!(compute(FloatFunction::Sqrt, -4.0, 0.0), 0.0);
+        assert_eq!(compute(FloatFunction::Modulo, 5.0, 0.0), 0.0);
+        assert_eq!(compute(FloatFunction::Power, -8.0, 0.5), 0.0);
+        assert!((compute(FloatFunction::Asin, 2.0, 0.0) - 90.0).abs() < 1e-4);
+    }
+}
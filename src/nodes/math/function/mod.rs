@@ -0,0 +1,49 @@
+//! Math Function node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Elementary float functions: trigonometry, rounding, powers and remainders
+#[derive(Default)]
+pub struct FunctionNodeFactory;
+
+impl NodeFactory for FunctionNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Function",
+            "Math Function",
+            NodeCategory::new(&["Math", "Functions"]),
+            "Sin, Cos, Tan, their inverses and Atan2, Floor, Ceil, Round, Abs, Sqrt, Power and Modulo on floats"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("ƒ")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Float)
+                .with_description("Value, angle, or Y for Atan2")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("B", DataType::Float)
+                .with_description("Exponent for Power, divisor for Modulo, X for Atan2")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("The function of A (and B)"),
+        ])
+        .with_tags(vec![
+            "math", "function", "trigonometry", "sin", "cos", "tan", "atan2", "floor", "ceil", "round",
+            "abs", "absolute", "sqrt", "square root", "power", "pow", "modulo", "fmod",
+        ])
+        .with_subtitle(|node| Some(parameters::FunctionNode::create_logic(node).function.name().to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
@@ -0,0 +1,68 @@
+//! Math Function node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{FloatFunction, FunctionLogic};
+
+/// Math Function node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct FunctionNode;
+
+impl FunctionNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (A and B are edited under the generated Input Defaults)
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Math Function Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Function:");
+            let mut function = logic.function;
+            egui::ComboBox::from_id_salt("float_function")
+                .selected_text(function.name())
+                .show_ui(ui, |ui| {
+                    for option in FloatFunction::ALL {
+                        if ui.selectable_value(&mut function, option, option.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "function".to_string(),
+                                value: NodeData::String(option.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        if logic.function.takes_angle() || logic.function.returns_angle() {
+            let mut degrees = logic.degrees;
+            if ui.checkbox(&mut degrees, "Angles in degrees").changed() {
+                changes.push(ParameterChange {
+                    parameter: "degrees".to_string(),
+                    value: NodeData::Boolean(degrees),
+                });
+            }
+        }
+        
+        changes
+    }
+    
+    /// Build FunctionLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> FunctionLogic {
+        let defaults = FunctionLogic::default();
+        FunctionLogic {
+            function: node.parameters.get("function")
+                .and_then(|v| if let NodeData::String(s) = v { Some(FloatFunction::from_name(s)) } else { None })
+                .unwrap_or(defaults.function),
+            degrees: node.parameters.get("degrees")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.degrees),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
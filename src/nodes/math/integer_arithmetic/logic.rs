@@ -0,0 +1,112 @@
+//! Integer Arithmetic functional operations
+
+use crate::nodes::interface::NodeData;
+
+/// Integer arithmetic operation applied as `A <op> B`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegerOperation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Min,
+    Max,
+}
+
+impl IntegerOperation {
+    /// All operations in UI order
+    pub const ALL: [IntegerOperation; 6] = [
+        IntegerOperation::Add,
+        IntegerOperation::Subtract,
+        IntegerOperation::Multiply,
+        IntegerOperation::Divide,
+        IntegerOperation::Min,
+        IntegerOperation::Max,
+    ];
+    
+    /// Parameter string stored on the node
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntegerOperation::Add => "Add",
+            IntegerOperation::Subtract => "Subtract",
+            IntegerOperation::Multiply => "Multiply",
+            IntegerOperation::Divide => "Divide",
+            IntegerOperation::Min => "Min",
+            IntegerOperation::Max => "Max",
+        }
+    }
+    
+    /// Parse from the stored parameter string (defaults to Add)
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|op| op.name() == name).unwrap_or(IntegerOperation::Add)
+    }
+    
+    /// Apply the operation; overflow wraps and division by zero yields 0
+    pub fn apply(&self, a: i32, b: i32) -> i32 {
+        match self {
+            IntegerOperation::Add => a.wrapping_add(b),
+            IntegerOperation::Subtract => a.wrapping_sub(b),
+            IntegerOperation::Multiply => a.wrapping_mul(b),
+            IntegerOperation::Divide => a.checked_div(b).unwrap_or(0),
+            IntegerOperation::Min => a.min(b),
+            IntegerOperation::Max => a.max(b),
+        }
+    }
+}
+
+/// Core Integer Arithmetic data and functionality
+#[derive(Debug, Clone)]
+pub struct IntegerArithmeticLogic {
+    pub a: i32,
+    pub b: i32,
+    pub operation: IntegerOperation,
+}
+
+impl Default for IntegerArithmeticLogic {
+    fn default() -> Self {
+        Self { a: 0, b: 0, operation: IntegerOperation::Add }
+    }
+}
+
+impl IntegerArithmeticLogic {
+    /// Process input data, falling back to parameters for unconnected inputs
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_integer).unwrap_or(self.a);
+        let b = inputs.get(1).and_then(extract_integer).unwrap_or(self.b);
+        vec![NodeData::Integer(self.operation.apply(a, b))]
+    }
+}
+
+/// Extract an integer from node data (floats truncate toward zero)
+pub fn extract_integer(data: &NodeData) -> Option<i32> {
+    match data {
+        NodeData::Integer(i) => Some(*i),
+        NodeData::Float(f) => Some(*f as i32),
+        NodeData::Boolean(b) => Some(*b as i32),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_divide_truncates_and_handles_zero() {
+        assert_eq!(IntegerOperation::Divide.apply(7, 2), 3);
+        assert_eq!(IntegerOperation::Divide.apply(-7, 2), -3);
+        assert_eq!(IntegerOperation::Divide.apply(7, 0), 0);
+    }
+
+    #[test]
+    fn test_integer_add_wraps() {
+        assert_eq!(IntegerOperation::Add.apply(i32::MAX, 1), i32::MIN);
+    }
+
+    #[test]
+    fn test_process_uses_fallbacks() {
+        let logic = IntegerArithmeticLogic { a: 4, b: 5, operation: IntegerOperation::Multiply };
+        let result = logic.process(vec![NodeData::None, NodeData::Integer(3)]);
+        assert!(matches!(result[0], NodeData::Integer(12)));
+    }
+}
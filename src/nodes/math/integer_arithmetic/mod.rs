@@ -0,0 +1,42 @@
+//! Integer Arithmetic node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Integer arithmetic node (add, subtract, multiply, divide, min, max)
+#[derive(Default)]
+pub struct IntegerArithmeticNodeFactory;
+
+impl NodeFactory for IntegerArithmeticNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_IntegerArithmetic",
+            "Integer Math",
+            NodeCategory::new(&["Math", "Integer"]),
+            "Wrapping integer arithmetic with truncating division"
+        )
+        .with_color(Color32::from_rgb(40, 60, 55))
+        .with_icon("±")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Integer)
+                .with_description("First operand"),
+            PortDefinition::optional("B", DataType::Integer)
+                .with_description("Second operand"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Integer)
+                .with_description("A <op> B"),
+        ])
+        .with_tags(vec!["math", "integer", "int", "arithmetic", "add", "subtract", "multiply", "divide"])
+        .with_subtitle(|node| Some(parameters::IntegerArithmeticNode::create_logic(node).operation.name().to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
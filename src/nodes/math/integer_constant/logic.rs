@@ -0,0 +1,16 @@
+//! Integer Constant functional operations
+
+use crate::nodes::interface::NodeData;
+
+/// Core Integer Constant data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct IntegerConstantLogic {
+    pub value: i32,
+}
+
+impl IntegerConstantLogic {
+    /// Output the stored value
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        vec![NodeData::Integer(self.value)]
+    }
+}
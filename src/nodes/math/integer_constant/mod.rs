@@ -0,0 +1,35 @@
+//! Integer Constant node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Integer constant source node
+#[derive(Default)]
+pub struct IntegerConstantNodeFactory;
+
+impl NodeFactory for IntegerConstantNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_IntegerConstant",
+            "Integer",
+            NodeCategory::new(&["Math", "Integer"]),
+            "Outputs a constant integer value"
+        )
+        .with_color(Color32::from_rgb(40, 60, 55))
+        .with_icon("#")
+        .with_outputs(vec![
+            PortDefinition::required("Value", DataType::Integer)
+                .with_description("Constant integer value"),
+        ])
+        .with_tags(vec!["math", "integer", "int", "constant", "counter", "index"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
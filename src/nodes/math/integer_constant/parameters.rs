@@ -0,0 +1,47 @@
+//! Integer Constant node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::IntegerConstantLogic;
+
+/// Integer Constant node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct IntegerConstantNode;
+
+impl IntegerConstantNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Integer Parameters");
+        ui.separator();
+        
+        ui.horizontal(|ui| {
+            ui.label("Value:");
+            let mut value = logic.value;
+            if ui.add(egui::DragValue::new(&mut value).speed(1.0)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "value".to_string(),
+                    value: NodeData::Integer(value),
+                });
+            }
+        });
+        
+        changes
+    }
+    
+    /// Build IntegerConstantLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> IntegerConstantLogic {
+        IntegerConstantLogic {
+            value: node.parameters.get("value")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(0),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,48 @@
+//! Lerp / Mix node functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+
+/// Defaults of the A, B and T inputs
+pub const INPUT_DEFAULTS: [f32; 3] = [0.0, 1.0, 0.5];
+
+/// Core Lerp / Mix data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct LerpLogic {
+    /// Keep T inside 0..1 so the result stays between A and B
+    pub clamp: bool,
+}
+
+impl LerpLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let b = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let t = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        vec![NodeData::Float(self.compute(a, b, t))]
+    }
+
+    /// Blend from `a` to `b` by `t`
+    pub fn compute(&self, a: f32, b: f32, t: f32) -> f32 {
+        lerp(a, b, if self.clamp { t.clamp(0.0, 1.0) } else { t })
+    }
+}
+
+/// `a` at `t` = 0 to `b` at `t` = 1, extrapolating beyond
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_blends_and_clamps_t() {
+        let logic = LerpLogic::default();
+        assert_eq!(logic.compute(2.0, 4.0, 0.5), 3.0);
+        assert_eq!(logic.compute(2.0, 4.0, 1.5), 5.0);
+        let logic = LerpLogic { clamp: true, ..logic };
+        assert_eq!(logic.compute(2.0, 4.0, 1.5), 4.0);
+    }
+}
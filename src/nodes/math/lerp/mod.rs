@@ -0,0 +1,48 @@
+//! Lerp / Mix node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Blends linearly from A to B by T (0 gives A, 1 gives B)
+#[derive(Default)]
+pub struct LerpNodeFactory;
+
+impl NodeFactory for LerpNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Lerp",
+            "Lerp / Mix",
+            NodeCategory::new(&["Math", "Range"]),
+            "Blends linearly from A to B by T (0 gives A, 1 gives B)"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("⧖")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Float)
+                .with_description("Value at T = 0")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("B", DataType::Float)
+                .with_description("Value at T = 1")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("T", DataType::Float)
+                .with_description("Blend factor")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("A + (B - A) × T"),
+        ])
+        .with_tags(vec!["math", "lerp", "mix", "blend", "interpolate", "linear"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
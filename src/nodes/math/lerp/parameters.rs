@@ -0,0 +1,46 @@
+//! Lerp / Mix node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::LerpLogic;
+
+/// Lerp / Mix node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct LerpNode;
+
+impl LerpNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (A, B and T are edited under the generated Input Defaults)
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Lerp / Mix Parameters");
+        ui.separator();
+        
+        let mut clamp = logic.clamp;
+        if ui.checkbox(&mut clamp, "Clamp T to 0..1").changed() {
+            changes.push(ParameterChange {
+                parameter: "clamp".to_string(),
+                value: NodeData::Boolean(clamp),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build LerpLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> LerpLogic {
+        let defaults = LerpLogic::default();
+        LerpLogic {
+            clamp: node.parameters.get("clamp")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.clamp),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -5,5 +5,16 @@ pub mod add;             // Modular directory structure
 pub mod subtract;        // Modular directory structure
 pub mod multiply;        // Modular directory structure
 pub mod divide;          // Modular directory structure
+pub mod integer_constant;    // Integer constant source
+pub mod integer_arithmetic;  // Integer add/subtract/multiply/divide/min/max
+pub mod modulo;              // Integer remainder
+pub mod bitwise;             // Bitwise AND/OR/XOR/NOT/shifts
+pub mod convert_units;       // Length/angle/time unit conversion
+pub mod clamp;               // Limit to a range
+pub mod remap;               // Map between ranges
+pub mod lerp;                // Linear blend
+pub mod smoothstep;          // Eased 0..1 ramp
+pub mod fit;                 // Inverse lerp to 0..1
+pub mod function;            // Trigonometry, rounding, powers and float modulo
 
 // Factory exports removed - unused
\ No newline at end of file
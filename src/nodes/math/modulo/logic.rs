@@ -0,0 +1,48 @@
+//! Modulo functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::math::integer_arithmetic::logic::extract_integer;
+
+/// Core Modulo data and functionality
+#[derive(Debug, Clone)]
+pub struct ModuloLogic {
+    pub a: i32,
+    pub b: i32,
+    /// Use Euclidean remainder so negative dividends still wrap into [0, |B|)
+    pub euclidean: bool,
+}
+
+impl Default for ModuloLogic {
+    fn default() -> Self {
+        Self { a: 0, b: 1, euclidean: true }
+    }
+}
+
+impl ModuloLogic {
+    /// Process input data, falling back to parameters for unconnected inputs
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let a = inputs.first().and_then(extract_integer).unwrap_or(self.a);
+        let b = inputs.get(1).and_then(extract_integer).unwrap_or(self.b);
+        vec![NodeData::Integer(self.modulo(a, b))]
+    }
+    
+    /// Remainder of a / b; a zero divisor yields 0
+    pub fn modulo(&self, a: i32, b: i32) -> i32 {
+        let result = if self.euclidean { a.checked_rem_euclid(b) } else { a.checked_rem(b) };
+        result.unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modulo_euclidean_vs_truncated() {
+        let euclidean = ModuloLogic { euclidean: true, ..Default::default() };
+        let truncated = ModuloLogic { euclidean: false, ..Default::default() };
+        assert_eq!(euclidean.modulo(-1, 4), 3);
+        assert_eq!(truncated.modulo(-1, 4), -1);
+        assert_eq!(euclidean.modulo(5, 0), 0);
+    }
+}
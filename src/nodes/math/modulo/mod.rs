@@ -0,0 +1,41 @@
+//! Modulo node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Integer modulo node
+#[derive(Default)]
+pub struct ModuloNodeFactory;
+
+impl NodeFactory for ModuloNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Modulo",
+            "Modulo",
+            NodeCategory::new(&["Math", "Integer"]),
+            "Integer remainder of A / B, truncated or Euclidean (always non-negative)"
+        )
+        .with_color(Color32::from_rgb(40, 60, 55))
+        .with_icon("%")
+        .with_inputs(vec![
+            PortDefinition::optional("A", DataType::Integer)
+                .with_description("Dividend"),
+            PortDefinition::optional("B", DataType::Integer)
+                .with_description("Divisor"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Integer)
+                .with_description("A mod B"),
+        ])
+        .with_tags(vec!["math", "integer", "int", "modulo", "remainder", "wrap", "cycle"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
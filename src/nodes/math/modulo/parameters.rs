@@ -0,0 +1,68 @@
+//! Modulo node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::ModuloLogic;
+
+/// Modulo node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ModuloNode;
+
+impl ModuloNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Modulo Parameters");
+        ui.separator();
+        
+        let mut euclidean = logic.euclidean;
+        if ui.checkbox(&mut euclidean, "Euclidean (non-negative result)").changed() {
+            changes.push(ParameterChange {
+                parameter: "euclidean".to_string(),
+                value: NodeData::Boolean(euclidean),
+            });
+        }
+        
+        ui.separator();
+        
+        for (param, label, current) in [("a", "A:", logic.a), ("b", "B:", logic.b)] {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                let mut value = current;
+                if ui.add(egui::DragValue::new(&mut value).speed(1.0)).changed() {
+                    changes.push(ParameterChange {
+                        parameter: param.to_string(),
+                        value: NodeData::Integer(value),
+                    });
+                }
+            });
+        }
+        
+        ui.separator();
+        ui.label(format!("Current Result: {}", logic.modulo(logic.a, logic.b)));
+        
+        changes
+    }
+    
+    /// Build ModuloLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ModuloLogic {
+        let defaults = ModuloLogic::default();
+        let get_int = |name: &str, default: i32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        ModuloLogic {
+            a: get_int("a", defaults.a),
+            b: get_int("b", defaults.b),
+            euclidean: node.parameters.get("euclidean")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.euclidean),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
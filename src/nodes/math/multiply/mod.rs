@@ -28,9 +28,11 @@ impl NodeFactory for MultiplyNodeFactory {
         .with_icon("×")
         .with_inputs(vec![
             crate::nodes::PortDefinition::required("A", crate::nodes::DataType::Float)
-                .with_description("First multiplicand"),
+                .with_description("First multiplicand")
+                .with_default(crate::nodes::interface::NodeData::Float(1.0)),
             crate::nodes::PortDefinition::required("B", crate::nodes::DataType::Float)
-                .with_description("Second multiplicand"),
+                .with_description("Second multiplicand")
+                .with_default(crate::nodes::interface::NodeData::Float(1.0)),
         ])
         .with_outputs(vec![
             crate::nodes::PortDefinition::required("Result", crate::nodes::DataType::Float)
@@ -0,0 +1,55 @@
+//! Remap Range node functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+use crate::nodes::math::fit::logic::inverse_lerp;
+use crate::nodes::math::lerp::logic::lerp;
+
+/// Defaults of the Value, From Min, From Max, To Min and To Max inputs
+pub const INPUT_DEFAULTS: [f32; 5] = [0.5, 0.0, 1.0, 0.0, 10.0];
+
+/// Core Remap Range data and functionality
+#[derive(Debug, Clone, Default)]
+pub struct RemapLogic {
+    /// Keep the result inside the target range
+    pub clamp: bool,
+}
+
+impl RemapLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let value = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let from_min = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let from_max = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        let to_min = inputs.get(3).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[3]);
+        let to_max = inputs.get(4).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[4]);
+        vec![NodeData::Float(self.compute(value, from_min, from_max, to_min, to_max))]
+    }
+
+    /// Map `value` from the source range to the target range
+    pub fn compute(&self, value: f32, from_min: f32, from_max: f32, to_min: f32, to_max: f32) -> f32 {
+        let result = lerp(to_min, to_max, inverse_lerp(from_min, from_max, value));
+        if self.clamp {
+            result.clamp(to_min.min(to_max), to_min.max(to_max))
+        } else {
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remap_extrapolates_unless_clamped() {
+        let logic = RemapLogic::default();
+        assert_eq!(logic.compute(0.5, 0.0, 1.0, 10.0, 20.0), 15.0);
+        assert_eq!(logic.compute(2.0, 0.0, 1.0, 10.0, 20.0), 30.0);
+        // Reversed target ranges flip the direction
+        assert_eq!(logic.compute(0.25, 0.0, 1.0, 1.0, 0.0), 0.75);
+        let logic = RemapLogic { clamp: true, ..logic };
+        assert_eq!(logic.compute(2.0, 0.0, 1.0, 10.0, 20.0), 20.0);
+        assert_eq!(logic.compute(2.0, 0.0, 1.0, 20.0, 10.0), 10.0);
+    }
+}
@@ -0,0 +1,54 @@
+//! Remap Range node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Maps a value from one range to another, optionally clamped to the target range
+#[derive(Default)]
+pub struct RemapNodeFactory;
+
+impl NodeFactory for RemapNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Remap",
+            "Remap Range",
+            NodeCategory::new(&["Math", "Range"]),
+            "Maps a value from one range to another, optionally clamped to the target range"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("⇔")
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to remap")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("From Min", DataType::Float)
+                .with_description("Start of the source range")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("From Max", DataType::Float)
+                .with_description("End of the source range")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+            PortDefinition::optional("To Min", DataType::Float)
+                .with_description("Start of the target range")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[3])),
+            PortDefinition::optional("To Max", DataType::Float)
+                .with_description("End of the target range")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[4])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("Value moved into the target range"),
+        ])
+        .with_tags(vec!["math", "remap", "range", "fit", "map", "rescale", "efit"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
@@ -0,0 +1,46 @@
+//! Remap Range node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::RemapLogic;
+
+/// Remap Range node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct RemapNode;
+
+impl RemapNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (Value and the ranges are edited under the generated Input Defaults)
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Remap Range Parameters");
+        ui.separator();
+        
+        let mut clamp = logic.clamp;
+        if ui.checkbox(&mut clamp, "Clamp to target range").changed() {
+            changes.push(ParameterChange {
+                parameter: "clamp".to_string(),
+                value: NodeData::Boolean(clamp),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build RemapLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> RemapLogic {
+        let defaults = RemapLogic::default();
+        RemapLogic {
+            clamp: node.parameters.get("clamp")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.clamp),
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,46 @@
+//! Smoothstep node functional operations
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::logic::compare::logic::extract_float;
+use crate::nodes::math::fit::logic::inverse_lerp;
+
+/// Defaults of the Edge 0, Edge 1 and Value inputs
+pub const INPUT_DEFAULTS: [f32; 3] = [0.0, 1.0, 0.5];
+
+/// Core Smoothstep functionality
+#[derive(Debug, Clone, Default)]
+pub struct SmoothstepLogic;
+
+impl SmoothstepLogic {
+    /// Process input data; unconnected inputs arrive as their Input Defaults
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let edge0 = inputs.first().and_then(extract_float).unwrap_or(INPUT_DEFAULTS[0]);
+        let edge1 = inputs.get(1).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[1]);
+        let value = inputs.get(2).and_then(extract_float).unwrap_or(INPUT_DEFAULTS[2]);
+        vec![NodeData::Float(self.compute(edge0, edge1, value))]
+    }
+
+    /// Hermite ramp, 0 at or before `edge0` and 1 at or past `edge1`; equal edges give a hard step
+    pub fn compute(&self, edge0: f32, edge1: f32, value: f32) -> f32 {
+        if edge0 == edge1 {
+            return if value < edge0 { 0.0 } else { 1.0 };
+        }
+        let t = inverse_lerp(edge0, edge1, value).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smoothstep_eases_between_edges() {
+        let logic = SmoothstepLogic::default();
+        assert_eq!(logic.compute(0.0, 1.0, -1.0), 0.0);
+        assert_eq!(logic.compute(0.0, 1.0, 0.5), 0.5);
+        assert!((logic.compute(0.0, 1.0, 0.25) - 0.15625).abs() < 1e-6);
+        assert_eq!(logic.compute(0.0, 1.0, 2.0), 1.0);
+        assert_eq!(logic.compute(1.0, 1.0, 1.0), 1.0);
+    }
+}
@@ -0,0 +1,48 @@
+//! Smoothstep node implementation
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Core computation logic
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
+
+/// Smooth 0..1 ramp of a value between two edges, with eased ends
+#[derive(Default)]
+pub struct SmoothstepNodeFactory;
+
+impl NodeFactory for SmoothstepNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Math_Smoothstep",
+            "Smoothstep",
+            NodeCategory::new(&["Math", "Range"]),
+            "Smooth 0..1 ramp of a value between two edges, with eased ends"
+        )
+        .with_color(Color32::from_rgb(50, 55, 75))
+        .with_icon("∫")
+        .with_inputs(vec![
+            PortDefinition::optional("Edge 0", DataType::Float)
+                .with_description("Value where the ramp starts (result 0)")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[0])),
+            PortDefinition::optional("Edge 1", DataType::Float)
+                .with_description("Value where the ramp ends (result 1)")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[1])),
+            PortDefinition::optional("Value", DataType::Float)
+                .with_description("Value to ramp")
+                .with_default(NodeData::Float(logic::INPUT_DEFAULTS[2])),
+        ])
+        .with_outputs(vec![
+            PortDefinition::required("Result", DataType::Float)
+                .with_description("Eased 0..1 ramp"),
+        ])
+        .with_tags(vec!["math", "smoothstep", "ease", "ramp", "step", "interpolate"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["Math", "General"])
+    }
+}
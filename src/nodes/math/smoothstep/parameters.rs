@@ -0,0 +1,30 @@
+//! Smoothstep node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::SmoothstepLogic;
+
+/// Smoothstep node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct SmoothstepNode;
+
+impl SmoothstepNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    /// (Edge 0, Edge 1 and Value are edited under the generated Input Defaults)
+    pub fn build_interface(_node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        ui.heading("Smoothstep Parameters");
+        ui.separator();
+        
+        Vec::new()
+    }
+    
+    /// Build SmoothstepLogic from the node's stored parameters
+    pub fn create_logic(_node: &Node) -> SmoothstepLogic {
+        SmoothstepLogic
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
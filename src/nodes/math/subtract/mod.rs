@@ -12,6 +12,7 @@ pub mod parameters;
 
 use egui::Color32;
 use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+use crate::nodes::interface::NodeData;
 
 /// Subtraction node that takes two numeric inputs and produces their difference
 #[derive(Default)]
@@ -29,9 +30,11 @@ impl NodeFactory for SubtractNodeFactory {
         .with_icon("➖")
         .with_inputs(vec![
             PortDefinition::required("A", DataType::Float)
-                .with_description("Minuend (value to subtract from)"),
+                .with_description("Minuend (value to subtract from)")
+                .with_default(NodeData::Float(0.0)),
             PortDefinition::required("B", DataType::Float)
-                .with_description("Subtrahend (value to subtract)"),
+                .with_description("Subtrahend (value to subtract)")
+                .with_default(NodeData::Float(0.0)),
         ])
         .with_outputs(vec![
             PortDefinition::required("Result", DataType::Float)
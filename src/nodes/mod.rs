@@ -2,6 +2,12 @@
 
 // Core node system modules
 pub mod graph;
+pub mod annotation;
+pub mod graph_diff;
+pub mod expressions;
+pub mod parameter_io;
+pub mod parameter_schema;
+pub mod project_paths;
 pub mod node;
 pub mod port;
 pub mod math_utils;
@@ -12,11 +18,23 @@ pub mod execution_engine;
 pub mod hooks;
 pub mod ownership;
 pub mod cache;
+pub mod state_store;
+pub mod debugger;
+pub mod execution_log;
+pub mod seed;
+pub mod units;
+pub mod adapters;
+pub mod value_preview;
+pub mod graph_stats;
+pub mod node_query;
+pub mod wire_style;
 
 // Generic node implementations
 pub mod math;
 pub mod logic;
 pub mod data;
+pub mod list;
+pub mod map;
 pub mod output;
 pub mod utility;
 
@@ -34,7 +52,7 @@ pub use port::PortId;
 // Re-export factory types
 pub use factory::{
     NodeFactory, NodeMetadata, NodeCategory,
-    DataType, PortDefinition,
+    DataType, PortDefinition, ParameterDefinition,
 };
 
 // Re-export interface types
@@ -1,6 +1,6 @@
 //! Node types and core node functionality
 
-use super::port::{Port, PortType};
+use super::port::{next_variadic_name, Port, PortId, PortType};
 use super::graph::NodeGraph;
 use super::interface::{PanelType, NodeData};
 use egui::{Color32, Pos2, Rect, Vec2};
@@ -37,6 +37,10 @@ pub enum NodeType {
         workspace_type: String,
         /// Port mappings between external and internal ports
         port_mappings: Vec<PortMapping>,
+        /// Subgraph file this workspace is linked to (None = embedded graph).
+        /// The internal graph is a cached copy refreshed by "Reload References".
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reference: Option<String>,
     },
 }
 
@@ -66,6 +70,18 @@ pub struct Node {
     /// Node parameters for interface panels
     #[serde(default)]
     pub parameters: HashMap<String, NodeData>,
+    /// Expressions driving numeric parameters, by parameter name (see `nodes::expressions`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub expressions: HashMap<String, String>,
+    /// Color the user tagged the node with, replacing its category color
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "option_color32_serde")]
+    pub tag_color: Option<Color32>,
+    /// Free-form labels the user gave the node
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Note shown when hovering the node and atop its parameter panel
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
     /// Plugin node instance (if this is a plugin node)
     #[serde(skip)]
     pub plugin_node: Option<Box<dyn nodle_plugin_sdk::PluginNode>>,
@@ -87,6 +103,10 @@ impl std::fmt::Debug for Node {
             .field("visible", &self.visible)
             .field("panel_type", &self.panel_type)
             .field("parameters", &self.parameters)
+            .field("expressions", &self.expressions)
+            .field("tag_color", &self.tag_color)
+            .field("tags", &self.tags)
+            .field("notes", &self.notes)
             .field("plugin_node", &if self.plugin_node.is_some() { "Some(PluginNode)" } else { "None" })
             .finish()
     }
@@ -108,6 +128,10 @@ impl Clone for Node {
             visible: self.visible,
             panel_type: self.panel_type,
             parameters: self.parameters.clone(),
+            expressions: self.expressions.clone(),
+            tag_color: self.tag_color,
+            tags: self.tags.clone(),
+            notes: self.notes.clone(),
             plugin_node: None, // Plugin nodes cannot be cloned, so we set to None
         }
     }
@@ -131,6 +155,10 @@ impl Node {
             visible: true,
             panel_type: None, // Will be set by factory or with_panel_type()
             parameters: HashMap::new(),
+            expressions: HashMap::new(),
+            tag_color: None,
+            tags: Vec::new(),
+            notes: String::new(),
             plugin_node: None, // Initialize plugin node as None
         };
         
@@ -156,11 +184,16 @@ impl Node {
                 graph: NodeGraph::new(),
                 workspace_type: workspace_type_str,
                 port_mappings: vec![],
+                reference: None,
             },
             button_states: [false, false],
             visible: true,
             panel_type: None, // Workspace nodes typically don't have panels
             parameters: HashMap::new(),
+            expressions: HashMap::new(),
+            tag_color: None,
+            tags: Vec::new(),
+            notes: String::new(),
             plugin_node: None, // Initialize plugin node as None
         };
         
@@ -211,11 +244,75 @@ impl Node {
         }
     }
 
+    /// Keep one unconnected copy of a trailing variadic input at the end:
+    /// add a copy once the last is connected and drop spare copies after an
+    /// unconnected one. `connected` tells whether an input has a connection.
+    /// Returns whether the inputs changed.
+    pub fn sync_variadic_inputs(&mut self, connected: impl Fn(PortId) -> bool) -> bool {
+        if !self.inputs.last().is_some_and(|port| port.variadic) {
+            return false;
+        }
+        let mut changed = false;
+        // Ports are only removed from the end, so connections keep their port indices
+        while let [.., before, _] = self.inputs.as_slice() {
+            let last = self.inputs.len() - 1;
+            if !before.variadic || connected(last - 1) || connected(last) {
+                break;
+            }
+            self.inputs.pop();
+            changed = true;
+        }
+        let last = self.inputs.len() - 1;
+        if connected(last) {
+            let mut port = Port::new(last + 1, next_variadic_name(&self.inputs[last].name), PortType::Input);
+            port.unit = self.inputs[last].unit;
+            port.variadic = true;
+            self.inputs.push(port);
+            changed = true;
+        }
+        if changed {
+            // Widen the node so the ports don't run past its edges
+            let width = (self.inputs.len() + 1) as f32 * theme::dimensions().port_spacing;
+            self.size.x = self.size.x.max(width);
+            self.update_port_positions();
+        }
+        changed
+    }
+
+    /// Value of the float parameter `name`, or `default` when it is unset or not a float
+    pub fn float_parameter(&self, name: &str, default: f32) -> f32 {
+        self.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default)
+    }
+
+    /// Value of the integer parameter `name`, or `default` when it is unset or not an integer
+    pub fn int_parameter(&self, name: &str, default: i32) -> i32 {
+        self.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default)
+    }
+
+    /// Value of the boolean parameter `name`, or `default` when it is unset or not a boolean
+    pub fn bool_parameter(&self, name: &str, default: bool) -> bool {
+        self.parameters.get(name)
+            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+            .unwrap_or(default)
+    }
+
     /// Returns the bounding rectangle of the node
     pub fn get_rect(&self) -> Rect {
         Rect::from_min_size(self.position, self.size)
     }
 
+    /// Top and bottom of the body gradient: the tag color if set, else the theme's
+    pub fn body_gradient(&self) -> (Color32, Color32) {
+        match self.tag_color {
+            Some(color) => (color, color.linear_multiply(0.5).to_opaque()),
+            None => theme::theme().node_gradient(&self.type_id),
+        }
+    }
+
     /// Sets the color of the node
     pub fn with_color(mut self, color: Color32) -> Self {
         self.color = color;
@@ -278,6 +375,25 @@ impl Node {
         }
     }
     
+    /// Subgraph file path if this workspace node references an external subgraph
+    pub fn get_reference(&self) -> Option<&str> {
+        match &self.node_type {
+            NodeType::Workspace { reference, .. } => reference.as_deref(),
+            NodeType::Regular => None,
+        }
+    }
+    
+    /// Link this workspace node to a subgraph file (or embed it again with None)
+    pub fn set_reference(&mut self, path: Option<String>) -> Result<(), &'static str> {
+        match &mut self.node_type {
+            NodeType::Workspace { reference, .. } => {
+                *reference = path;
+                Ok(())
+            }
+            NodeType::Regular => Err("Cannot reference a subgraph from a regular node"),
+        }
+    }
+    
     /// Get port mappings if this is a workspace node
     pub fn get_port_mappings(&self) -> Option<&Vec<PortMapping>> {
         match &self.node_type {
@@ -471,6 +587,26 @@ mod vec2_serde {
     }
 }
 
+mod option_color32_serde {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(color: &Option<Color32>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        color.map(|color| [color.r(), color.g(), color.b(), color.a()]).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Color32>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let color = Option::<[u8; 4]>::deserialize(deserializer)?;
+        Ok(color.map(|[r, g, b, a]| Color32::from_rgba_unmultiplied(r, g, b, a)))
+    }
+}
+
 mod color32_serde {
     use super::*;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
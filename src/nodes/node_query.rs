@@ -0,0 +1,160 @@
+//! Node queries for the Find Nodes dialog
+//!
+//! A query is a list of terms separated by spaces; a node matches when every
+//! term does. Terms are `type:<text>` (type id contains the text), `title:<text>`
+//! or bare text (title contains it), `tag:<text>` (the node has that tag),
+//! `is:error` (the last cook failed) and parameter comparisons such as
+//! `radius > 2`, `mode == Smooth` or `enabled != 0`. Text matching ignores case.
+
+use super::graph::NodeGraph;
+use super::interface::NodeData;
+use super::logic::compare::logic::{extract_float, CompareLogic, CompareOperation};
+use super::node::{Node, NodeId};
+
+/// One condition of a query
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryTerm {
+    Type(String),
+    Title(String),
+    Tag(String),
+    Error,
+    /// `<parameter> <operator> <value>`
+    Parameter { name: String, operation: CompareOperation, value: String },
+}
+
+/// Parsed query; every term must match
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeQuery {
+    pub terms: Vec<QueryTerm>,
+}
+
+impl NodeQuery {
+    /// Parse the query text; an empty query matches every node
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let terms = split_terms(text).iter().map(|term| parse_term(term)).collect::<Result<_, _>>()?;
+        Ok(Self { terms })
+    }
+
+    /// Whether `node` meets every term; `failed` tells whether its last cook failed
+    pub fn matches(&self, node: &Node, failed: bool) -> bool {
+        self.terms.iter().all(|term| match term {
+            QueryTerm::Type(text) => contains_ignoring_case(&node.type_id, text),
+            QueryTerm::Title(text) => contains_ignoring_case(&node.title, text),
+            QueryTerm::Tag(text) => node.tags.iter().any(|tag| tag.eq_ignore_ascii_case(text)),
+            QueryTerm::Error => failed,
+            QueryTerm::Parameter { name, operation, value } => node.parameters.iter()
+                .find(|(parameter, _)| parameter.eq_ignore_ascii_case(name))
+                .is_some_and(|(_, stored)| compare_parameter(stored, *operation, value)),
+        })
+    }
+}
+
+/// Ids of the nodes of `graph` matching `query`, in id order
+pub fn find_nodes(graph: &NodeGraph, query: &NodeQuery, failed: &dyn Fn(NodeId) -> bool) -> Vec<NodeId> {
+    let mut found: Vec<NodeId> = graph.nodes.values()
+        .filter(|node| query.matches(node, failed(node.id)))
+        .map(|node| node.id)
+        .collect();
+    found.sort_unstable();
+    found
+}
+
+/// Split on spaces, keeping `radius > 2` together as one term
+fn split_terms(text: &str) -> Vec<String> {
+    let is_operator = |c: char| matches!(c, '<' | '>' | '=' | '!');
+    let mut terms: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            while chars.peek().is_some_and(|next| next.is_whitespace()) {
+                chars.next();
+            }
+            let joins = current.ends_with(is_operator) || chars.peek().is_some_and(|next| is_operator(*next));
+            if !joins && !current.is_empty() {
+                terms.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    terms
+}
+
+fn parse_term(term: &str) -> Result<QueryTerm, String> {
+    // Longest symbols first, so ">=" isn't read as ">"
+    let mut operations: Vec<(&str, CompareOperation)> = CompareOperation::ALL.iter().map(|op| (op.symbol(), *op)).collect();
+    operations.push(("=", CompareOperation::Equal));
+    operations.sort_by_key(|(symbol, _)| std::cmp::Reverse(symbol.len()));
+    for (symbol, operation) in operations {
+        if let Some((name, value)) = term.split_once(symbol) {
+            if name.is_empty() || value.is_empty() {
+                return Err(format!("\"{}\" needs a parameter and a value", term));
+            }
+            return Ok(QueryTerm::Parameter { name: name.to_string(), operation, value: value.to_string() });
+        }
+    }
+    match term.split_once(':') {
+        Some(("type", text)) => Ok(QueryTerm::Type(text.to_string())),
+        Some(("title", text)) => Ok(QueryTerm::Title(text.to_string())),
+        Some(("tag", text)) => Ok(QueryTerm::Tag(text.to_string())),
+        Some(("is", state)) if state.eq_ignore_ascii_case("error") => Ok(QueryTerm::Error),
+        Some(("is", state)) => Err(format!("Unknown state \"{}\"; try is:error", state)),
+        _ => Ok(QueryTerm::Title(term.to_string())),
+    }
+}
+
+fn contains_ignoring_case(text: &str, part: &str) -> bool {
+    text.to_lowercase().contains(&part.to_lowercase())
+}
+
+/// Numbers compare numerically; other values only support == and !=
+fn compare_parameter(stored: &NodeData, operation: CompareOperation, value: &str) -> bool {
+    if let (Some(a), Ok(b)) = (extract_float(stored), value.parse::<f32>()) {
+        return CompareLogic { operation, ..Default::default() }.compare(a, b);
+    }
+    let text = match stored {
+        NodeData::String(text) => text.clone(),
+        NodeData::Boolean(flag) => flag.to_string(),
+        _ => return false,
+    };
+    match operation {
+        CompareOperation::Equal => text.eq_ignore_ascii_case(value),
+        CompareOperation::NotEqual => !text.eq_ignore_ascii_case(value),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_queries_combine_type_title_tag_parameter_and_error_terms() {
+        let mut graph = NodeGraph::new();
+        let mut big = Node::new(0, "Big Sphere", Pos2::ZERO);
+        big.type_id = "3D_Sphere".to_string();
+        big.tags = vec!["Hero".to_string()];
+        big.parameters.insert("radius".to_string(), NodeData::Float(3.0));
+        big.parameters.insert("mode".to_string(), NodeData::String("Smooth".to_string()));
+        let big = graph.add_node(big);
+        let mut small = Node::new(0, "Small Sphere", Pos2::ZERO);
+        small.type_id = "3D_Sphere".to_string();
+        small.parameters.insert("radius".to_string(), NodeData::Integer(1));
+        let small = graph.add_node(small);
+
+        let find = |text: &str| find_nodes(&graph, &NodeQuery::parse(text).unwrap(), &|id| id == small);
+        assert_eq!(find("type:sphere"), vec![big, small]);
+        assert_eq!(find("type:sphere radius > 2"), vec![big]);
+        assert_eq!(find("radius>=1 sphere"), vec![big, small]);
+        assert_eq!(find("tag:hero mode = smooth"), vec![big]);
+        assert_eq!(find("is:error"), vec![small]);
+        assert_eq!(find(""), vec![big, small]);
+        assert!(NodeQuery::parse("radius >").is_err());
+        assert!(NodeQuery::parse("is:happy").is_err());
+    }
+}
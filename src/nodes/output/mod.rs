@@ -4,10 +4,16 @@
 pub mod debug;           // Modular directory structure
 pub mod print;           // Modular directory structure
 pub mod console;         // Console output node
+pub mod spreadsheet;     // Spreadsheet viewer node
+pub mod tree_view;       // Tree viewer node
+pub mod probe;           // Inline value display node
 // scenegraph module moved to nodes::three_d::ui::scenegraph
 
 // Export all modular node factories
 pub use debug::DebugNodeFactory;
 pub use print::PrintNodeFactory;
 pub use console::{ConsoleLogic, ConsoleNodeFactory};
+pub use spreadsheet::SpreadsheetNodeFactory;
+pub use tree_view::TreeViewNodeFactory;
+pub use probe::ProbeNodeFactory;
 // scenegraph exports now available through nodes::three_d::ui::scenegraph
\ No newline at end of file
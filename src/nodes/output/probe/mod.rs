@@ -0,0 +1,67 @@
+//! Probe node implementation
+//!
+//! Passes its input through unchanged and shows the value on its own body in
+//! the graph, refreshed on every cook, so a value can be watched without
+//! opening a panel.
+
+use egui::{Color32, Pos2, Vec2};
+use crate::nodes::{Node, NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Size of a probe node; taller than usual to leave room for the value
+pub const PROBE_NODE_SIZE: Vec2 = Vec2::new(170.0, 96.0);
+
+/// Lines of the value shown on the node body
+pub const PROBE_LINES: usize = 4;
+
+/// Probe node factory
+#[derive(Default)]
+pub struct ProbeNodeFactory;
+
+impl NodeFactory for ProbeNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Probe",
+            "Probe",
+            NodeCategory::output(),
+            "Shows the value flowing into it on the node itself and passes it through"
+        )
+        .with_color(Color32::from_rgb(70, 60, 35))
+        .with_icon("🔎")
+        .with_size_hint(PROBE_NODE_SIZE)
+        .with_inputs(vec![
+            PortDefinition::optional("Value", DataType::Any)
+                .with_description("Value to watch"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::optional("Value", DataType::Any)
+                .with_description("Pass-through of the input"),
+        ])
+        .with_tags(vec!["output", "probe", "watch", "inspect", "debug", "value"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+
+    fn create(position: Pos2) -> Node {
+        let meta = Self::metadata();
+        let mut node = Node::new(0, meta.display_name, position);
+        node.set_type_id(meta.node_type);
+        node.color = meta.color;
+        node.size = PROBE_NODE_SIZE;
+        node.add_input("Value");
+        node.add_output("Value");
+        node.update_port_positions();
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_node_has_room_for_its_value() {
+        let node = ProbeNodeFactory::create(Pos2::ZERO);
+        assert_eq!(node.type_id, "Probe");
+        assert_eq!(node.size, PROBE_NODE_SIZE);
+        assert_eq!((node.inputs.len(), node.outputs.len()), (1, 1));
+    }
+}
@@ -0,0 +1,35 @@
+//! Spreadsheet node implementation
+//!
+//! Viewer node that shows whatever flows into it in a spreadsheet panel
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Spreadsheet viewer node factory
+#[derive(Default)]
+pub struct SpreadsheetNodeFactory;
+
+impl NodeFactory for SpreadsheetNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "Spreadsheet",
+            "Spreadsheet",
+            NodeCategory::output(),
+            "Displays tables, buffers, USD points and attributes in a sortable, filterable spreadsheet"
+        )
+        .with_color(Color32::from_rgb(60, 80, 60))
+        .with_icon("📊")
+        .with_panel_type(crate::nodes::interface::PanelType::Spreadsheet)
+        .with_inputs(vec![
+            PortDefinition::optional("Data", DataType::Any)
+                .with_description("Data to display"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::optional("Data", DataType::Any)
+                .with_description("Pass-through of the input"),
+        ])
+        .with_workspace_compatibility(vec!["3D", "USD", "General", "Programming"])
+        .with_tags(vec!["output", "spreadsheet", "table", "viewer", "inspect", "attributes"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
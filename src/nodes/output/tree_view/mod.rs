@@ -0,0 +1,38 @@
+//! Tree View node implementation
+//!
+//! Viewer node that shows hierarchical data flowing into it in a tree panel and
+//! outputs the path of the item selected there
+
+use egui::Color32;
+use crate::nodes::{NodeFactory, NodeMetadata, NodeCategory, DataType, PortDefinition};
+
+/// Tree View viewer node factory
+#[derive(Default)]
+pub struct TreeViewNodeFactory;
+
+impl NodeFactory for TreeViewNodeFactory {
+    fn metadata() -> NodeMetadata {
+        NodeMetadata::new(
+            "TreeView",
+            "Tree View",
+            NodeCategory::output(),
+            "Displays hierarchical data in a searchable tree and outputs the selected item path"
+        )
+        .with_color(Color32::from_rgb(60, 75, 50))
+        .with_icon("🌳")
+        .with_panel_type(crate::nodes::interface::PanelType::Tree)
+        .with_inputs(vec![
+            PortDefinition::optional("Tree", DataType::Tree)
+                .with_description("Hierarchy to display"),
+        ])
+        .with_outputs(vec![
+            PortDefinition::optional("Tree", DataType::Tree)
+                .with_description("Pass-through of the input"),
+            PortDefinition::optional("Selected", DataType::String)
+                .with_description("Path of the item selected in the tree panel"),
+        ])
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+        .with_tags(vec!["output", "tree", "hierarchy", "viewer", "inspect", "outliner"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+    }
+}
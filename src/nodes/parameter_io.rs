@@ -0,0 +1,196 @@
+//! Parameter set import/export
+//!
+//! A parameter set is a small JSON document holding one node's parameter values,
+//! so parameter presets can be produced by external tools. The format is
+//! described by the JSON schema in `docs/schemas/parameter_set.schema.json`.
+//! Imports are checked against the target node: unknown keys, values of the
+//! wrong type and values outside the node's declared parameter schema are
+//! rejected with one message per problem, and nothing is applied unless the
+//! whole set is valid.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use super::node::Node;
+use super::interface::NodeData;
+use super::parameter_schema::ParameterDefinition;
+
+/// Value of the `format` field
+pub const PARAMETER_SET_FORMAT: &str = "nodle-parameters";
+
+/// Current parameter set version
+pub const PARAMETER_SET_VERSION: u32 = 1;
+
+/// JSON schema describing parameter set files
+pub const PARAMETER_SET_SCHEMA: &str = include_str!("../../docs/schemas/parameter_set.schema.json");
+
+/// One node's parameters as stored in a parameter set file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParameterSet {
+    pub format: String,
+    pub version: u32,
+    pub node_type: String,
+    /// Sorted so exported files diff cleanly
+    pub parameters: BTreeMap<String, Value>,
+}
+
+/// JSON form of a parameter value; None for data that has no parameter-set form
+fn to_json(value: &NodeData) -> Option<Value> {
+    match value {
+        NodeData::Float(v) => Some(Value::from(*v as f64)),
+        NodeData::Integer(v) => Some(Value::from(*v)),
+        NodeData::Boolean(v) => Some(Value::from(*v)),
+        NodeData::String(s) | NodeData::Any(s) => Some(Value::from(s.as_str())),
+        NodeData::Vector3(v) => Some(Value::from(v.iter().map(|c| *c as f64).collect::<Vec<f64>>())),
+        NodeData::Color(c) => Some(Value::from(c.iter().map(|c| *c as f64).collect::<Vec<f64>>())),
+        _ => None,
+    }
+}
+
+/// Type name used in error messages
+fn type_name(value: &NodeData) -> &'static str {
+    match value {
+        NodeData::Float(_) => "a number",
+        NodeData::Integer(_) => "a whole number",
+        NodeData::Boolean(_) => "true or false",
+        NodeData::String(_) | NodeData::Any(_) => "a string",
+        NodeData::Vector3(_) => "an array of 3 numbers",
+        NodeData::Color(_) => "an array of 3 or 4 numbers",
+        _ => "not settable from a parameter set",
+    }
+}
+
+/// Read up to `max` numbers from a JSON array with at least `min` entries
+fn numbers(value: &Value, min: usize, max: usize) -> Option<Vec<f32>> {
+    let items = value.as_array().filter(|items| (min..=max).contains(&items.len()))?;
+    items.iter().map(|item| item.as_f64().map(|v| v as f32)).collect()
+}
+
+/// Convert a JSON value to the type of `current`, None on mismatch
+fn from_json(value: &Value, current: &NodeData) -> Option<NodeData> {
+    match current {
+        NodeData::Float(_) => value.as_f64().map(|v| NodeData::Float(v as f32)),
+        NodeData::Integer(_) => value.as_i64()
+            .and_then(|v| i32::try_from(v).ok())
+            .map(NodeData::Integer),
+        NodeData::Boolean(_) => value.as_bool().map(NodeData::Boolean),
+        NodeData::String(_) => value.as_str().map(|s| NodeData::String(s.to_string())),
+        NodeData::Any(_) => value.as_str().map(|s| NodeData::Any(s.to_string())),
+        NodeData::Vector3(_) => numbers(value, 3, 3).map(|v| NodeData::Vector3([v[0], v[1], v[2]])),
+        NodeData::Color(_) => numbers(value, 3, 4)
+            .map(|v| NodeData::Color([v[0], v[1], v[2], v.get(3).copied().unwrap_or(1.0)])),
+        _ => None,
+    }
+}
+
+/// Export a node's parameters (those with a JSON form) as a parameter set
+pub fn export_parameters(node: &Node) -> ParameterSet {
+    ParameterSet {
+        format: PARAMETER_SET_FORMAT.to_string(),
+        version: PARAMETER_SET_VERSION,
+        node_type: node.type_id.clone(),
+        parameters: node.parameters.iter()
+            .filter_map(|(name, value)| to_json(value).map(|json| (name.clone(), json)))
+            .collect(),
+    }
+}
+
+/// Parse and validate a parameter set for `node` and its declared `schema`.
+/// Returns the converted values, or every problem found (unknown keys, type
+/// mismatches, out-of-range values, wrong node type).
+pub fn import_parameters(node: &Node, json: &str, schema: &[ParameterDefinition]) -> Result<Vec<(String, NodeData)>, Vec<String>> {
+    let set: ParameterSet = serde_json::from_str(json)
+        .map_err(|e| vec![format!("Not a valid parameter set: {}", e)])?;
+    if set.format != PARAMETER_SET_FORMAT {
+        return Err(vec![format!("Unexpected format \"{}\" (expected \"{}\")", set.format, PARAMETER_SET_FORMAT)]);
+    }
+    if set.version > PARAMETER_SET_VERSION {
+        return Err(vec![format!("Parameter set version {} is newer than supported version {}", set.version, PARAMETER_SET_VERSION)]);
+    }
+    if set.node_type != node.type_id {
+        return Err(vec![format!("Parameters are for node type \"{}\" but {} is \"{}\"", set.node_type, node.title, node.type_id)]);
+    }
+
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for (name, value) in &set.parameters {
+        let Some(current) = node.parameters.get(name) else {
+            errors.push(format!("Unknown parameter \"{}\" for {}", name, node.type_id));
+            continue;
+        };
+        let Some(converted) = from_json(value, current) else {
+            errors.push(format!("Parameter \"{}\" must be {}, got {}", name, type_name(current), value));
+            continue;
+        };
+        match schema.iter().find(|definition| definition.name == name) {
+            Some(definition) => match definition.validate(&converted) {
+                Ok(converted) => values.push((name.clone(), converted)),
+                Err(e) => errors.push(e),
+            },
+            None => values.push((name.clone(), converted)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    fn sphere() -> Node {
+        let mut node = Node::new(0, "Sphere", Pos2::ZERO);
+        node.type_id = "3D_Sphere".to_string();
+        node.parameters.insert("radius".to_string(), NodeData::Float(1.0));
+        node.parameters.insert("segments".to_string(), NodeData::Integer(16));
+        node.parameters.insert("color".to_string(), NodeData::Color([1.0, 1.0, 1.0, 1.0]));
+        node
+    }
+
+    #[test]
+    fn test_export_then_import_round_trips() {
+        let node = sphere();
+        let json = serde_json::to_string(&export_parameters(&node)).unwrap();
+        let values = import_parameters(&node, &json, &[]).unwrap();
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn test_import_reports_unknown_keys_and_type_mismatches() {
+        let json = r#"{
+            "format": "nodle-parameters", "version": 1, "node_type": "3D_Sphere",
+            "parameters": {"radius": "big", "segments": 2.5, "height": 3.0, "color": [0.5, 0.2, 0.1]}
+        }"#;
+        let errors = import_parameters(&sphere(), json, &[]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().any(|e| e.contains("Unknown parameter \"height\"")));
+        assert!(errors.iter().any(|e| e.contains("\"segments\" must be a whole number")));
+
+        let wrong_type = json.replace("3D_Sphere", "3D_Cube");
+        assert!(import_parameters(&sphere(), &wrong_type, &[]).unwrap_err()[0].contains("3D_Cube"));
+    }
+
+    #[test]
+    fn test_import_checks_declared_ranges() {
+        let schema = [ParameterDefinition::integer("segments", "Segments", 16).with_range(3.0, 128.0)];
+        let json = r#"{
+            "format": "nodle-parameters", "version": 1, "node_type": "3D_Sphere",
+            "parameters": {"segments": 2}
+        }"#;
+        let errors = import_parameters(&sphere(), json, &schema).unwrap_err();
+        assert_eq!(errors, vec!["Parameter \"segments\" must be between 3 and 128, got 2".to_string()]);
+        assert_eq!(import_parameters(&sphere(), &json.replace(": 2}", ": 24}"), &schema).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_schema_is_valid_json() {
+        let schema: Value = serde_json::from_str(PARAMETER_SET_SCHEMA).unwrap();
+        assert_eq!(schema["properties"]["format"]["const"], PARAMETER_SET_FORMAT);
+    }
+}
@@ -0,0 +1,486 @@
+//! Declarative parameter schemas
+//!
+//! Nodes declare their parameters in [`NodeMetadata`](super::NodeMetadata):
+//! name, default (which also fixes the type), allowed range or options, drag
+//! step, tooltip and group. Defaults are filled in when a node is created, the
+//! parameter panel builds a consistent interface for nodes that have no
+//! dedicated one, and values from the panel or from parameter set files are
+//! validated against the declaration.
+//!
+//! Numbers have two ranges: the hard range (`min`/`max`) that values must lie
+//! in, and an optional soft range covering the usual values, which the
+//! panel's sliders span. Values typed past the slider are clamped to the hard
+//! range, and stored values outside it are flagged next to their field.
+//!
+//! Inputs can declare a default too (see `PortDefinition::with_default`). It is
+//! stored as a parameter named after the port (`A` → `input_a`), edited under
+//! Input Defaults while the input is unconnected, and cooked in its place.
+
+use super::factory::PortDefinition;
+use super::interface::{NodeData, ParameterChange};
+use super::node::Node;
+use super::port::PortId;
+
+/// One declared node parameter
+#[derive(Debug, Clone)]
+pub struct ParameterDefinition {
+    /// Key in `Node::parameters`
+    pub name: &'static str,
+    /// Label shown in the parameter panel
+    pub label: &'static str,
+    /// Value of new nodes; its variant is the parameter's type
+    pub default: NodeData,
+    /// Hard limits of numeric values
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Range of the panel's slider; values may be typed outside it, within the hard limits
+    pub soft_min: Option<f64>,
+    pub soft_max: Option<f64>,
+    /// Drag speed of numeric fields
+    pub step: Option<f64>,
+    /// Allowed values of a string parameter, shown as a dropdown
+    pub options: Vec<&'static str>,
+    pub tooltip: Option<&'static str>,
+    /// Heading the parameter is listed under
+    pub group: Option<&'static str>,
+}
+
+impl ParameterDefinition {
+    fn new(name: &'static str, label: &'static str, default: NodeData) -> Self {
+        Self {
+            name,
+            label,
+            default,
+            min: None,
+            max: None,
+            soft_min: None,
+            soft_max: None,
+            step: None,
+            options: Vec::new(),
+            tooltip: None,
+            group: None,
+        }
+    }
+
+    pub fn float(name: &'static str, label: &'static str, default: f32) -> Self {
+        Self::new(name, label, NodeData::Float(default))
+    }
+
+    pub fn integer(name: &'static str, label: &'static str, default: i32) -> Self {
+        Self::new(name, label, NodeData::Integer(default))
+    }
+
+    pub fn boolean(name: &'static str, label: &'static str, default: bool) -> Self {
+        Self::new(name, label, NodeData::Boolean(default))
+    }
+
+    pub fn string(name: &'static str, label: &'static str, default: &str) -> Self {
+        Self::new(name, label, NodeData::String(default.to_string()))
+    }
+
+    pub fn vector3(name: &'static str, label: &'static str, default: [f32; 3]) -> Self {
+        Self::new(name, label, NodeData::Vector3(default))
+    }
+
+    pub fn color(name: &'static str, label: &'static str, default: [f32; 4]) -> Self {
+        Self::new(name, label, NodeData::Color(default))
+    }
+
+    /// String parameter restricted to `options`
+    pub fn choice(name: &'static str, label: &'static str, options: Vec<&'static str>, default: &str) -> Self {
+        Self {
+            options,
+            ..Self::string(name, label, default)
+        }
+    }
+
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Span the slider over `min..=max` without limiting typed values to it
+    pub fn with_soft_range(mut self, min: f64, max: f64) -> Self {
+        self.soft_min = Some(min);
+        self.soft_max = Some(max);
+        self
+    }
+
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn with_tooltip(mut self, tooltip: &'static str) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
+
+    pub fn in_group(mut self, group: &'static str) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Check `value` against the declaration, returning it converted to the
+    /// parameter's type (whole numbers are accepted for float parameters)
+    pub fn validate(&self, value: &NodeData) -> Result<NodeData, String> {
+        let value = match (&self.default, value) {
+            (NodeData::Float(_), NodeData::Integer(i)) => NodeData::Float(*i as f32),
+            (NodeData::Float(_), NodeData::Float(_))
+            | (NodeData::Integer(_), NodeData::Integer(_))
+            | (NodeData::Boolean(_), NodeData::Boolean(_))
+            | (NodeData::String(_), NodeData::String(_))
+            | (NodeData::Vector3(_), NodeData::Vector3(_))
+            | (NodeData::Color(_), NodeData::Color(_)) => value.clone(),
+            _ => return Err(format!("Parameter \"{}\" has the wrong type", self.name)),
+        };
+
+        let number = match &value {
+            NodeData::Float(f) => Some(*f as f64),
+            NodeData::Integer(i) => Some(*i as f64),
+            _ => None,
+        };
+        if let Some(number) = number {
+            let below = self.min.is_some_and(|min| number < min);
+            let above = self.max.is_some_and(|max| number > max);
+            if below || above {
+                return Err(format!("Parameter \"{}\" must be {}, got {}", self.name, self.range_text(), number));
+            }
+        }
+
+        if let NodeData::String(s) = &value {
+            if !self.options.is_empty() && !self.options.contains(&s.as_str()) {
+                return Err(format!("Parameter \"{}\" must be one of {}, got \"{}\"", self.name, self.options.join(", "), s));
+            }
+        }
+        Ok(value)
+    }
+
+    /// `value` brought within the hard limits; other values are returned as they are
+    pub fn clamp(&self, value: NodeData) -> NodeData {
+        let clamp = |number: f64| {
+            let number = self.min.map_or(number, |min| number.max(min));
+            self.max.map_or(number, |max| number.min(max))
+        };
+        match value {
+            NodeData::Float(f) => NodeData::Float(clamp(f as f64) as f32),
+            NodeData::Integer(i) => NodeData::Integer(clamp(i as f64) as i32),
+            value => value,
+        }
+    }
+
+    /// Range of the slider: the soft range, narrowed to the hard limits, or
+    /// the hard limits alone; None when either end is unbounded
+    pub fn slider_range(&self) -> Option<(f64, f64)> {
+        let min = match (self.soft_min, self.min) {
+            (Some(soft), Some(hard)) => soft.max(hard),
+            (soft, hard) => soft.or(hard)?,
+        };
+        let max = match (self.soft_max, self.max) {
+            (Some(soft), Some(hard)) => soft.min(hard),
+            (soft, hard) => soft.or(hard)?,
+        };
+        Some((min, max))
+    }
+
+    fn range_text(&self) -> String {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => format!("between {} and {}", min, max),
+            (Some(min), None) => format!("at least {}", min),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => "a number".to_string(),
+        }
+    }
+
+    /// The node's value of this parameter, or the default if it is missing or
+    /// of the wrong type; out of range values are kept so the field shows them
+    fn current_value(&self, node: &Node) -> NodeData {
+        stored_value(node, self.name, self)
+    }
+
+    /// Why the node's stored value of this parameter is invalid, if it is
+    pub fn validation_message(&self, node: &Node) -> Option<String> {
+        node.parameters.get(self.name).and_then(|value| self.validate(value).err())
+    }
+}
+
+/// Value of `key` on `node` converted to the type of `definition`, or its default
+fn stored_value(node: &Node, key: &str, definition: &ParameterDefinition) -> NodeData {
+    let converted = match (&definition.default, node.parameters.get(key)) {
+        (NodeData::Float(_), Some(NodeData::Integer(i))) => Some(NodeData::Float(*i as f32)),
+        (default, Some(value)) if std::mem::discriminant(default) == std::mem::discriminant(value) => Some(value.clone()),
+        _ => None,
+    };
+    converted.unwrap_or_else(|| definition.default.clone())
+}
+
+/// Show `message` in red under a parameter's field
+fn validation_label(ui: &mut egui::Ui, message: &str) {
+    ui.colored_label(egui::Color32::from_rgb(230, 120, 80), format!("⚠ {}", message));
+}
+
+/// Give `node` the default of every declared parameter it does not have yet
+pub fn apply_defaults(node: &mut Node, schema: &[ParameterDefinition]) {
+    for definition in schema {
+        node.parameters.entry(definition.name.to_string())
+            .or_insert_with(|| definition.default.clone());
+    }
+}
+
+/// Parameter holding the default of the input named `port`
+pub fn port_default_key(port: &str) -> String {
+    format!("input_{}", port.trim().to_lowercase().replace(' ', "_"))
+}
+
+/// Give `node` the declared default of every input it has no default for yet
+pub fn apply_port_defaults(node: &mut Node, inputs: &[PortDefinition]) {
+    for input in inputs {
+        if let Some(default) = &input.default {
+            node.parameters.entry(port_default_key(&input.name))
+                .or_insert_with(|| default.clone());
+        }
+    }
+}
+
+/// Validate `changes` against `schema`, dropping (and logging) invalid ones.
+/// Parameters the schema does not declare pass through unchanged.
+pub fn validate_changes(schema: &[ParameterDefinition], changes: Vec<ParameterChange>) -> Vec<ParameterChange> {
+    changes.into_iter()
+        .filter_map(|change| match schema.iter().find(|definition| definition.name == change.parameter) {
+            Some(definition) => match definition.validate(&change.value) {
+                Ok(value) => Some(ParameterChange { parameter: change.parameter, value }),
+                Err(e) => {
+                    log::warn!("{}", e);
+                    None
+                }
+            },
+            None => Some(change),
+        })
+        .collect()
+}
+
+/// Parameter panel generated from `schema`, grouped in declaration order
+pub fn build_schema_interface(node: &Node, ui: &mut egui::Ui, schema: &[ParameterDefinition]) -> Vec<ParameterChange> {
+    let mut changes = Vec::new();
+
+    ui.heading(format!("{} Parameters", node.title));
+    ui.separator();
+
+    let mut groups: Vec<Option<&'static str>> = Vec::new();
+    for definition in schema {
+        if !groups.contains(&definition.group) {
+            groups.push(definition.group);
+        }
+    }
+
+    for group in groups {
+        if let Some(group) = group {
+            ui.add_space(4.0);
+            ui.label(egui::RichText::new(group).strong());
+        }
+        for definition in schema.iter().filter(|definition| definition.group == group) {
+            ui.horizontal(|ui| {
+                let label = ui.label(format!("{}:", definition.label));
+                if let Some(tooltip) = definition.tooltip {
+                    label.on_hover_text(tooltip);
+                }
+                if let Some(value) = parameter_widget(ui, node, definition) {
+                    changes.push(ParameterChange {
+                        parameter: definition.name.to_string(),
+                        value,
+                    });
+                }
+            });
+            if let Some(message) = definition.validation_message(node) {
+                validation_label(ui, &message);
+            }
+        }
+    }
+
+    ui.separator();
+    if ui.button("Reset to Defaults").clicked() {
+        changes.extend(schema.iter().map(|definition| ParameterChange {
+            parameter: definition.name.to_string(),
+            value: definition.default.clone(),
+        }));
+    }
+
+    changes
+}
+
+/// Input Defaults section: a field for the default of every unconnected
+/// input declaring one; `connected` tells whether an input has a connection
+pub fn build_port_default_interface(node: &Node, ui: &mut egui::Ui, inputs: &[PortDefinition], connected: &dyn Fn(PortId) -> bool) -> Vec<ParameterChange> {
+    let mut changes = Vec::new();
+    if inputs.iter().all(|input| input.default.is_none()) {
+        return changes;
+    }
+
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new("Input Defaults").strong());
+    for (port, input) in inputs.iter().enumerate() {
+        let Some(default) = &input.default else {
+            continue;
+        };
+        let key = port_default_key(&input.name);
+        ui.horizontal(|ui| {
+            let label = ui.label(format!("{}:", input.name));
+            if let Some(description) = &input.description {
+                label.on_hover_text(description.as_str());
+            }
+            if connected(port) {
+                ui.weak("connected");
+                return;
+            }
+            // Port defaults take the type of their declared value, like parameters
+            let definition = ParameterDefinition::new("", "", default.clone());
+            let value = stored_value(node, &key, &definition);
+            if let Some(value) = value_widget(ui, (node.id, key.as_str()), value, &definition) {
+                changes.push(ParameterChange { parameter: key.clone(), value });
+            }
+        });
+    }
+    changes
+}
+
+/// Widget editing one parameter; returns the new value when it changed
+fn parameter_widget(ui: &mut egui::Ui, node: &Node, definition: &ParameterDefinition) -> Option<NodeData> {
+    value_widget(ui, (node.id, definition.name), definition.current_value(node), definition)
+}
+
+/// Widget editing `value` within the ranges, step and options of `definition`.
+/// Numbers get a slider over the slider range when there is one, and a drag
+/// field otherwise; typed numbers are clamped to the hard limits.
+fn value_widget(ui: &mut egui::Ui, id_salt: impl std::hash::Hash, value: NodeData, definition: &ParameterDefinition) -> Option<NodeData> {
+    let changed = match value {
+        NodeData::Float(mut value) => {
+            let speed = definition.step.unwrap_or(0.1);
+            let response = match definition.slider_range() {
+                Some((min, max)) => ui.add(egui::Slider::new(&mut value, min as f32..=max as f32)
+                    .clamping(egui::SliderClamping::Never)
+                    .drag_value_speed(speed)),
+                None => ui.add(egui::DragValue::new(&mut value).speed(speed)),
+            };
+            response.changed().then_some(NodeData::Float(value))
+        }
+        NodeData::Integer(mut value) => {
+            let speed = definition.step.unwrap_or(1.0);
+            let response = match definition.slider_range() {
+                Some((min, max)) => ui.add(egui::Slider::new(&mut value, min as i32..=max as i32)
+                    .clamping(egui::SliderClamping::Never)
+                    .drag_value_speed(speed)),
+                None => ui.add(egui::DragValue::new(&mut value).speed(speed)),
+            };
+            response.changed().then_some(NodeData::Integer(value))
+        }
+        NodeData::Boolean(mut value) => {
+            ui.checkbox(&mut value, "").changed().then_some(NodeData::Boolean(value))
+        }
+        NodeData::String(mut value) if !definition.options.is_empty() => {
+            let mut changed = false;
+            egui::ComboBox::from_id_salt(id_salt)
+                .selected_text(value.clone())
+                .show_ui(ui, |ui| {
+                    for option in &definition.options {
+                        changed |= ui.selectable_value(&mut value, option.to_string(), *option).changed();
+                    }
+                });
+            changed.then_some(NodeData::String(value))
+        }
+        NodeData::String(mut value) => {
+            ui.text_edit_singleline(&mut value).changed().then_some(NodeData::String(value))
+        }
+        NodeData::Vector3(mut value) => {
+            let speed = definition.step.unwrap_or(0.1);
+            let mut changed = false;
+            for component in value.iter_mut() {
+                changed |= ui.add(egui::DragValue::new(component).speed(speed)).changed();
+            }
+            changed.then_some(NodeData::Vector3(value))
+        }
+        NodeData::Color(mut value) => {
+            ui.color_edit_button_rgba_unmultiplied(&mut value).changed().then_some(NodeData::Color(value))
+        }
+        _ => None,
+    };
+    changed.map(|value| definition.clamp(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_values_are_checked_against_the_declaration() {
+        let fps = ParameterDefinition::float("fps", "Frame Rate", 24.0).with_range(1.0, 240.0);
+        assert!(matches!(fps.validate(&NodeData::Integer(30)), Ok(NodeData::Float(f)) if f == 30.0));
+        assert!(fps.validate(&NodeData::Float(0.5)).unwrap_err().contains("between 1 and 240"));
+        assert!(fps.validate(&NodeData::Boolean(true)).is_err());
+
+        let mode = ParameterDefinition::choice("mode", "Mode", vec!["primitive", "mesh"], "primitive");
+        assert!(mode.validate(&NodeData::String("mesh".to_string())).is_ok());
+        assert!(mode.validate(&NodeData::String("voxels".to_string())).is_err());
+
+        let mut node = Node::new(1, "Time", Pos2::ZERO);
+        node.parameters.insert("fps".to_string(), NodeData::Float(60.0));
+        apply_defaults(&mut node, &[fps.clone(), mode.clone()]);
+        assert!(matches!(node.parameters.get("fps"), Some(NodeData::Float(f)) if *f == 60.0));
+        assert!(matches!(node.parameters.get("mode"), Some(NodeData::String(s)) if s == "primitive"));
+
+        let changes = validate_changes(&[fps, mode], vec![
+            ParameterChange { parameter: "fps".to_string(), value: NodeData::Float(500.0) },
+            ParameterChange { parameter: "mode".to_string(), value: NodeData::String("mesh".to_string()) },
+            ParameterChange { parameter: "undeclared".to_string(), value: NodeData::Integer(3) },
+        ]);
+        let names: Vec<&str> = changes.iter().map(|change| change.parameter.as_str()).collect();
+        assert_eq!(names, vec!["mode", "undeclared"]);
+    }
+
+    #[test]
+    fn test_sliders_span_the_soft_range_and_typed_values_clamp_to_the_hard_range() {
+        let size = ParameterDefinition::float("size", "Size", 2.0).with_min(0.01).with_soft_range(0.1, 10.0);
+        assert_eq!(size.slider_range(), Some((0.1, 10.0)));
+        assert!(matches!(size.clamp(NodeData::Float(50.0)), NodeData::Float(f) if f == 50.0));
+        assert!(matches!(size.clamp(NodeData::Float(-1.0)), NodeData::Float(f) if f == 0.01));
+        assert!(size.validate(&NodeData::Float(50.0)).is_ok());
+
+        // The soft range is narrowed to the hard limits, and hard limits alone span the slider
+        let segments = ParameterDefinition::integer("segments", "Segments", 16).with_range(3.0, 64.0).with_soft_range(0.0, 128.0);
+        assert_eq!(segments.slider_range(), Some((3.0, 64.0)));
+        assert!(matches!(segments.clamp(NodeData::Integer(1000)), NodeData::Integer(64)));
+        assert_eq!(ParameterDefinition::float("seed", "Seed", 0.0).with_min(0.0).slider_range(), None);
+
+        // Stored values outside the hard range are kept for display and flagged
+        let mut node = Node::new(1, "Torus", Pos2::ZERO);
+        node.parameters.insert("segments".to_string(), NodeData::Integer(200));
+        assert!(matches!(segments.current_value(&node), NodeData::Integer(200)));
+        assert!(segments.validation_message(&node).unwrap().contains("between 3 and 64"));
+        node.parameters.insert("segments".to_string(), NodeData::Integer(32));
+        assert_eq!(segments.validation_message(&node), None);
+    }
+
+    #[test]
+    fn test_declared_port_defaults_are_stored_under_the_port_name() {
+        assert_eq!(port_default_key("A"), "input_a");
+        assert_eq!(port_default_key("Item 1"), "input_item_1");
+
+        let inputs = vec![
+            PortDefinition::required("A", crate::nodes::DataType::Float).with_default(NodeData::Float(1.0)),
+            PortDefinition::required("B", crate::nodes::DataType::Float).with_default(NodeData::Float(2.0)),
+            PortDefinition::optional("Scene", crate::nodes::DataType::USDScene),
+        ];
+        let mut node = Node::new(1, "Add", Pos2::ZERO);
+        node.parameters.insert("input_b".to_string(), NodeData::Float(5.0));
+        apply_port_defaults(&mut node, &inputs);
+        assert!(matches!(node.parameters.get("input_a"), Some(NodeData::Float(f)) if *f == 1.0));
+        assert!(matches!(node.parameters.get("input_b"), Some(NodeData::Float(f)) if *f == 5.0));
+        assert!(!node.parameters.contains_key("input_scene"));
+    }
+}
@@ -2,6 +2,7 @@
 
 use egui::Pos2;
 use serde::{Deserialize, Serialize};
+use crate::nodes::units::Unit;
 
 /// Unique identifier for a port
 pub type PortId = usize;
@@ -21,6 +22,20 @@ pub struct Port {
     pub port_type: PortType,
     #[serde(with = "pos2_serde")]
     pub position: Pos2,
+    /// Physical unit of float values on this port (converted across connections)
+    #[serde(default, skip_serializing_if = "is_unitless")]
+    pub unit: Unit,
+    /// Input that repeats: connecting the last copy adds another after it
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub variadic: bool,
+}
+
+fn is_unitless(unit: &Unit) -> bool {
+    *unit == Unit::None
+}
+
+fn is_false(flag: &bool) -> bool {
+    !*flag
 }
 
 impl Port {
@@ -31,6 +46,17 @@ impl Port {
             name: name.into(),
             port_type,
             position: Pos2::ZERO,
+            unit: Unit::None,
+            variadic: false,
+        }
+    }
+
+    /// Port name with its unit suffix, for hover labels
+    pub fn display_name(&self) -> std::borrow::Cow<'_, str> {
+        if self.unit == Unit::None {
+            std::borrow::Cow::Borrowed(&self.name)
+        } else {
+            std::borrow::Cow::Owned(format!("{} ({})", self.name, self.unit.suffix().trim()))
         }
     }
 
@@ -45,6 +71,19 @@ impl Port {
     }
 }
 
+/// Name of the copy added after a variadic input: the trailing number or a
+/// single letter counts up (`Item 1` → `Item 2`, `B` → `C`), anything else gets a 2
+pub fn next_variadic_name(name: &str) -> String {
+    let base = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    if let Ok(number) = name[base.len()..].parse::<u64>() {
+        return format!("{}{}", base, number + 1);
+    }
+    match name.as_bytes() {
+        [letter] if letter.is_ascii_uppercase() && *letter != b'Z' => ((letter + 1) as char).to_string(),
+        _ => format!("{} 2", name),
+    }
+}
+
 // Serde helper module for Pos2
 mod pos2_serde {
     use super::*;
@@ -0,0 +1,311 @@
+//! Project root and relative file paths
+//!
+//! The project root is the folder of the open project file. File-path
+//! parameters may be stored relative to it (`textures/wood.png`) so a project
+//! folder can be moved or checked out elsewhere; nodes resolve them to absolute
+//! paths when they cook. Paths are stored with `/` separators on every platform.
+//! Environment variables (`$ASSETS/wood.png`, `${SHOW}/shots`) and a leading
+//! `~` are expanded when a path is resolved, so studio-wide locations need not
+//! be baked into projects.
+
+use egui::Ui;
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+
+/// Folder of the open project, None for unsaved projects
+static PROJECT_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Paths picked in any path field this session, most recent first
+static RECENT_PATHS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Entries kept in the recent-paths dropdown
+const MAX_RECENT_PATHS: usize = 12;
+
+/// What a path parameter points at, with the file extensions it accepts (empty for any)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathKind {
+    /// An existing file that is read
+    Input(&'static [&'static str]),
+    /// A file that is written; its folder must exist
+    Output(&'static [&'static str]),
+}
+
+impl PathKind {
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            PathKind::Input(extensions) | PathKind::Output(extensions) => extensions,
+        }
+    }
+
+    /// Whether `path` has one of the accepted extensions
+    pub fn accepts(&self, path: &str) -> bool {
+        let extensions = self.extensions();
+        extensions.is_empty() || Path::new(path).extension()
+            .is_some_and(|ext| extensions.iter().any(|accepted| ext.eq_ignore_ascii_case(accepted)))
+    }
+}
+
+/// Set the project root (called by the editor when the project file changes)
+pub fn set_project_root(root: Option<PathBuf>) {
+    if let Ok(mut current) = PROJECT_ROOT.write() {
+        if *current != root {
+            log::info!("Project root: {}", root.as_ref().map_or_else(|| "(none)".to_string(), |root| root.display().to_string()));
+            *current = root;
+        }
+    }
+}
+
+/// Current project root
+pub fn project_root() -> Option<PathBuf> {
+    PROJECT_ROOT.read().ok().and_then(|root| root.clone())
+}
+
+/// Resolve a stored path parameter against the project root for cooking
+pub fn resolve_path(value: &str) -> PathBuf {
+    resolve_against(&expand_env_vars(value), project_root().as_deref())
+}
+
+/// Resolve a stored path parameter to a string, for code that passes paths on as text
+pub fn resolve_path_string(value: &str) -> String {
+    resolve_path(value).display().to_string()
+}
+
+/// Resolve `value` against `root`; absolute and empty paths are returned unchanged
+pub fn resolve_against(value: &str, root: Option<&Path>) -> PathBuf {
+    let path = Path::new(value);
+    match root {
+        Some(root) if !value.is_empty() && path.is_relative() => root.join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Expand `$VAR`, `${VAR}` and a leading `~` from the environment.
+/// Unknown variables are left as written.
+pub fn expand_env_vars(value: &str) -> String {
+    expand_with(value, |name| std::env::var(name).ok())
+}
+
+fn expand_with(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    if let Some(after) = rest.strip_prefix('~') {
+        if after.is_empty() || after.starts_with('/') || after.starts_with('\\') {
+            if let Some(home) = dirs::home_dir() {
+                expanded.push_str(&home.display().to_string());
+                rest = after;
+            }
+        }
+    }
+
+    while let Some(start) = rest.find('$') {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let (name, consumed) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = after.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(after.len());
+                (&after[..end], end)
+            }
+        };
+        match lookup(name).filter(|_| !name.is_empty()) {
+            Some(value) => expanded.push_str(&value),
+            None => expanded.push_str(&rest[start..start + 1 + consumed]),
+        }
+        rest = &after[consumed..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Check a path parameter: inputs must exist, outputs need an existing folder.
+/// Empty values are not checked.
+pub fn validate_path(value: &str, kind: PathKind) -> Result<(), String> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    let resolved = resolve_path(value);
+    match kind {
+        PathKind::Input(_) if !resolved.is_file() => Err(format!("File not found: {}", resolved.display())),
+        PathKind::Output(_) => match resolved.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            Some(folder) if !folder.is_dir() => Err(format!("Folder does not exist: {}", folder.display())),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Add `path` to the front of the recent paths
+pub fn remember_recent_path(path: &str) {
+    if path.is_empty() {
+        return;
+    }
+    if let Ok(mut recent) = RECENT_PATHS.write() {
+        recent.retain(|existing| existing != path);
+        recent.insert(0, path.to_string());
+        recent.truncate(MAX_RECENT_PATHS);
+    }
+}
+
+/// Recent paths a field of `kind` accepts, most recent first
+pub fn recent_paths(kind: PathKind) -> Vec<String> {
+    RECENT_PATHS.read()
+        .map(|recent| recent.iter().filter(|path| kind.accepts(path)).cloned().collect())
+        .unwrap_or_default()
+}
+
+/// `path` relative to `base` (both absolute), using `..` where needed.
+/// None when they share no root, e.g. different drives on Windows.
+pub fn relative_to(path: &Path, base: &Path) -> Option<String> {
+    let path_components: Vec<Component> = path.components().collect();
+    let base_components: Vec<Component> = base.components().collect();
+    if !path.is_absolute() || path_components.first() != base_components.first() {
+        return None;
+    }
+
+    let common = path_components.iter()
+        .zip(&base_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts: Vec<String> = vec!["..".to_string(); base_components.len() - common];
+    parts.extend(path_components[common..].iter().map(|part| part.as_os_str().to_string_lossy().into_owned()));
+    Some(if parts.is_empty() { ".".to_string() } else { parts.join("/") })
+}
+
+/// Path parameter field: text entry with environment variable expansion, a
+/// Browse button, files dropped from the OS, a recent-paths dropdown, an
+/// absolute/relative toggle and a warning when the path fails [`validate_path`].
+/// `browse` opens the file dialog. Browsed and dropped files keep the form
+/// (relative or absolute) of the current value. Returns the new value when it changed.
+pub fn path_browser(ui: &mut Ui, value: &str, hint: &str, kind: PathKind, browse: impl FnOnce() -> Option<PathBuf>) -> Option<String> {
+    let root = project_root();
+    let expanded = expand_env_vars(value);
+    let has_variables = expanded != value;
+    let is_relative = !value.is_empty() && Path::new(&expanded).is_relative();
+    // Picked files are stored in the same form as the current value
+    let stored_form = |path: PathBuf| -> String {
+        let relative = if is_relative {
+            root.as_deref().and_then(|root| relative_to(&path, root))
+        } else {
+            None
+        };
+        relative.unwrap_or_else(|| path.display().to_string())
+    };
+    let mut new_value = None;
+
+    ui.horizontal(|ui| {
+        let mut text = value.to_string();
+        let response = ui.add(egui::TextEdit::singleline(&mut text)
+            .desired_width(200.0)
+            .hint_text(hint));
+        if is_relative || has_variables {
+            response.clone().on_hover_text(format!("Resolves to {}", resolve_path(value).display()));
+        }
+        if response.changed() {
+            new_value = Some(text);
+        }
+        if response.lost_focus() && validate_path(value, kind).is_ok() {
+            remember_recent_path(value);
+        }
+
+        // Files dragged in from the file manager
+        let (hovering_files, dropped) = ui.ctx().input(|input| (
+            !input.raw.hovered_files.is_empty(),
+            input.raw.dropped_files.iter().find_map(|file| file.path.clone()),
+        ));
+        if hovering_files && response.contains_pointer() {
+            ui.painter().rect_stroke(response.rect, 2.0, ui.visuals().selection.stroke, egui::StrokeKind::Outside);
+        }
+        if let Some(path) = dropped.filter(|path| response.contains_pointer() && kind.accepts(&path.display().to_string())) {
+            let path = stored_form(path);
+            remember_recent_path(&path);
+            new_value = Some(path);
+        }
+
+        if ui.button("Browse...").clicked() {
+            if let Some(path) = browse() {
+                let path = stored_form(path);
+                remember_recent_path(&path);
+                new_value = Some(path);
+            }
+        }
+
+        let recent = recent_paths(kind);
+        ui.add_enabled_ui(!recent.is_empty(), |ui| {
+            ui.menu_button("▾", |ui| {
+                for path in recent {
+                    if ui.button(&path).clicked() {
+                        new_value = Some(path);
+                        ui.close_menu();
+                    }
+                }
+            }).response.on_hover_text("Recent paths");
+        });
+
+        // Toggle between the absolute and project-relative form of the same file
+        let toggle_label = if is_relative { "Rel" } else { "Abs" };
+        let can_toggle = root.is_some() && !value.is_empty() && !has_variables;
+        let toggle = ui.add_enabled(can_toggle, egui::Button::new(toggle_label).small())
+            .on_hover_text("Store this path relative to the project folder or as an absolute path")
+            .on_disabled_hover_text("Save the project to store paths relative to its folder; paths with variables are kept as written");
+        if toggle.clicked() {
+            if let Some(root) = &root {
+                new_value = if is_relative {
+                    Some(resolve_against(value, Some(root)).display().to_string())
+                } else {
+                    relative_to(Path::new(value), root)
+                };
+            }
+        }
+
+        if let Err(problem) = validate_path(value, kind) {
+            ui.colored_label(egui::Color32::from_rgb(230, 160, 60), "⚠").on_hover_text(problem);
+        }
+    });
+
+    new_value.filter(|new_value| new_value != value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_paths_round_trip_through_the_root() {
+        let root = std::env::temp_dir().join("project");
+        let inside = root.join("assets").join("chair.usda");
+        let outside = std::env::temp_dir().join("shared").join("wood.png");
+
+        assert_eq!(relative_to(&inside, &root).unwrap(), "assets/chair.usda");
+        let up = relative_to(&outside, &root).unwrap();
+        assert_eq!(up, "../shared/wood.png");
+        assert_eq!(resolve_against("assets/chair.usda", Some(&root)), inside);
+        // Absolute and empty values are left alone
+        assert_eq!(resolve_against(&outside.display().to_string(), Some(&root)), outside);
+        assert_eq!(resolve_against("", Some(&root)), PathBuf::new());
+    }
+
+    #[test]
+    fn test_variables_expand_and_paths_are_checked_by_kind() {
+        let lookup = |name: &str| (name == "ASSETS").then(|| "/studio/assets".to_string());
+        assert_eq!(expand_with("$ASSETS/wood.png", lookup), "/studio/assets/wood.png");
+        assert_eq!(expand_with("${ASSETS}_old/wood.png", lookup), "/studio/assets_old/wood.png");
+        assert_eq!(expand_with("$MISSING/wood.png", lookup), "$MISSING/wood.png");
+        assert_eq!(expand_with("${ASSETS", lookup), "${ASSETS");
+        assert_eq!(expand_with("price$", lookup), "price$");
+
+        const IMAGES: &[&str] = &["png", "exr"];
+        assert!(PathKind::Input(IMAGES).accepts("textures/WOOD.PNG"));
+        assert!(!PathKind::Input(IMAGES).accepts("chair.usda"));
+        assert!(PathKind::Output(&[]).accepts("anything"));
+
+        let existing = std::env::temp_dir();
+        let missing = existing.join("nodle_missing_folder").join("render.png");
+        assert!(validate_path("", PathKind::Input(IMAGES)).is_ok());
+        assert!(validate_path(&missing.display().to_string(), PathKind::Input(IMAGES)).unwrap_err().contains("File not found"));
+        assert!(validate_path(&missing.display().to_string(), PathKind::Output(IMAGES)).unwrap_err().contains("Folder does not exist"));
+        assert!(validate_path(&existing.join("render.png").display().to_string(), PathKind::Output(IMAGES)).is_ok());
+    }
+}
@@ -0,0 +1,118 @@
+//! Graph-wide random seed
+//!
+//! Nodes that produce random values (noise, scattering, jitter) draw them from
+//! a [`SeededRng`] derived from the project's seed, the node's id and a stream
+//! name, never from thread-local randomness. The generator is a fixed
+//! algorithm (SplitMix64) rather than a library default, so the same file
+//! gives the same values run-to-run, across machines and across dependency
+//! upgrades. Changing the project seed re-cooks the graph with new values.
+
+use crate::nodes::NodeId;
+
+/// Seed of new projects
+pub const DEFAULT_SEED: u64 = 0;
+
+/// Project seed as seen by nodes during a cook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedContext {
+    pub graph_seed: u64,
+}
+
+impl Default for SeedContext {
+    fn default() -> Self {
+        Self { graph_seed: DEFAULT_SEED }
+    }
+}
+
+impl SeedContext {
+    pub fn new(graph_seed: u64) -> Self {
+        Self { graph_seed }
+    }
+
+    /// Seed of one random stream of a node; streams let a node draw independent sequences
+    pub fn node_seed(&self, node_id: NodeId, stream: &str) -> u64 {
+        let mut seed = mix(self.graph_seed ^ mix(node_id as u64));
+        // FNV-1a over the stream name: stable, unlike std's hashers
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in stream.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        seed ^= hash;
+        mix(seed)
+    }
+
+    /// Generator for one random stream of a node
+    pub fn rng(&self, node_id: NodeId, stream: &str) -> SeededRng {
+        SeededRng::new(self.node_seed(node_id, stream))
+    }
+}
+
+/// SplitMix64 finalizer
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Small deterministic random number generator (SplitMix64)
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        mix(self.state)
+    }
+
+    /// Uniform in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        // The top 24 bits fill an f32 mantissa exactly
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform in [min, max)
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Uniform in [min, max] (both inclusive)
+    pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        let (low, high) = if min <= max { (min, max) } else { (max, min) };
+        let span = (high as i64 - low as i64 + 1) as u64;
+        (low as i64 + (self.next_u64() % span) as i64) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streams_are_reproducible_and_independent() {
+        // Reference output of SplitMix64 for seed 0; guards against the algorithm changing
+        assert_eq!(SeededRng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let context = SeedContext::new(42);
+        let first: Vec<f32> = {
+            let mut rng = context.rng(7, "points");
+            (0..8).map(|_| rng.next_f32()).collect()
+        };
+        let mut again = context.rng(7, "points");
+        assert_eq!((0..8).map(|_| again.next_f32()).collect::<Vec<_>>(), first);
+        assert!(first.iter().all(|value| (0.0..1.0).contains(value)));
+
+        assert_ne!(context.node_seed(7, "points"), context.node_seed(8, "points"));
+        assert_ne!(context.node_seed(7, "points"), context.node_seed(7, "colors"));
+        assert_ne!(context.node_seed(7, "points"), SeedContext::new(43).node_seed(7, "points"));
+
+        let mut rng = SeededRng::new(1);
+        assert!((0..100).map(|_| rng.range_i32(-2, 2)).all(|value| (-2..=2).contains(&value)));
+    }
+}
@@ -0,0 +1,95 @@
+//! Persistent per-node state for stateful nodes
+//!
+//! Output caches are invalidated whenever a node is marked dirty, so nodes that
+//! must remember values between cooks (counters, accumulators, latches) keep
+//! them here instead. The execution engine owns the store, hands each stateful
+//! node only its own slots, and drops them when the node is removed.
+//!
+//! A node can also ask to be cooked again later, without any input changing,
+//! by writing the engine clock time to wake at into its `WAKE_AT_SLOT`.
+
+use std::collections::HashMap;
+use crate::nodes::{NodeId, interface::NodeData};
+
+/// Slot holding the engine clock time (ms) at which a node wants its next cook
+pub const WAKE_AT_SLOT: &str = "wake_at_ms";
+
+/// Named state values belonging to a single node
+pub type NodeStateSlots = HashMap<String, NodeData>;
+
+/// Store of persistent node state keyed by node id
+#[derive(Debug, Default)]
+pub struct NodeStateStore {
+    states: HashMap<NodeId, NodeStateSlots>,
+}
+
+impl NodeStateStore {
+    /// Create an empty state store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mutable slots for a node, created on first access
+    pub fn slots_mut(&mut self, node_id: NodeId) -> &mut NodeStateSlots {
+        self.states.entry(node_id).or_default()
+    }
+
+    /// Read a single state value
+    pub fn get(&self, node_id: NodeId, slot: &str) -> Option<&NodeData> {
+        self.states.get(&node_id).and_then(|slots| slots.get(slot))
+    }
+
+    /// Nodes whose requested wake time has come by `now_ms`
+    pub fn due_nodes(&self, now_ms: i32) -> Vec<NodeId> {
+        self.states.iter()
+            .filter(|(_, slots)| matches!(slots.get(WAKE_AT_SLOT), Some(NodeData::Integer(at)) if *at <= now_ms))
+            .map(|(node_id, _)| *node_id)
+            .collect()
+    }
+
+    /// Discard all state for a node (reset or removal)
+    pub fn remove(&mut self, node_id: NodeId) {
+        self.states.remove(&node_id);
+    }
+
+    /// Discard all stored state
+    pub fn clear(&mut self) {
+        self.states.clear();
+    }
+
+    /// Number of nodes holding state
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Whether any node holds state
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slots_persist_until_removed() {
+        let mut store = NodeStateStore::new();
+        store.slots_mut(1).insert("count".to_string(), NodeData::Integer(3));
+        assert!(matches!(store.get(1, "count"), Some(NodeData::Integer(3))));
+        assert!(store.get(2, "count").is_none());
+
+        store.remove(1);
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_due_nodes_are_those_past_their_wake_time() {
+        let mut store = NodeStateStore::new();
+        store.slots_mut(1).insert(WAKE_AT_SLOT.to_string(), NodeData::Integer(100));
+        store.slots_mut(2).insert(WAKE_AT_SLOT.to_string(), NodeData::Integer(500));
+        store.slots_mut(3).insert("count".to_string(), NodeData::Integer(0));
+        assert!(store.due_nodes(50).is_empty());
+        assert_eq!(store.due_nodes(100), vec![1]);
+    }
+}
@@ -362,6 +362,7 @@ impl CapsuleLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -144,6 +144,7 @@ impl ConeLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
     
@@ -259,6 +260,7 @@ impl ConeLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -112,6 +112,7 @@ impl CubeLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
     
@@ -186,6 +187,7 @@ impl CubeLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -5,7 +5,7 @@ pub mod logic;
 
 use crate::nodes::interface::{NodeData, ParameterChange};
 use crate::nodes::{Node, NodeFactory, NodeMetadata, NodeCategory};
-use crate::nodes::factory::{DataType, PortDefinition, ProcessingCost};
+use crate::nodes::factory::{DataType, ParameterDefinition, PortDefinition, ProcessingCost};
 use egui::{Color32, Ui};
 
 /// USD-based Cube Node Factory
@@ -26,9 +26,22 @@ impl NodeFactory for CubeNodeFactory {
             // No inputs - this is a geometry source node
         ])
         .with_outputs(vec![
-            PortDefinition::required("Scene", DataType::Any)
+            PortDefinition::required("Scene", DataType::USDScene)
                 .with_description("USD scene data with cube geometry")
         ])
+        .with_parameters(vec![
+            ParameterDefinition::choice("mode", "Mode", vec!["primitive", "mesh"], "primitive")
+                .with_tooltip("USD procedural primitive or tessellated mesh"),
+            ParameterDefinition::float("size", "Size", 2.0).with_range(0.1, 10.0).in_group("Size"),
+            ParameterDefinition::float("size_x", "Size X", 2.0).with_range(0.1, 10.0).in_group("Size"),
+            ParameterDefinition::float("size_y", "Size Y", 2.0).with_range(0.1, 10.0).in_group("Size"),
+            ParameterDefinition::float("size_z", "Size Z", 2.0).with_range(0.1, 10.0).in_group("Size"),
+            ParameterDefinition::integer("subdivisions_x", "Subdivisions X", 1).with_range(1.0, 20.0).in_group("Mesh Subdivision"),
+            ParameterDefinition::integer("subdivisions_y", "Subdivisions Y", 1).with_range(1.0, 20.0).in_group("Mesh Subdivision"),
+            ParameterDefinition::integer("subdivisions_z", "Subdivisions Z", 1).with_range(1.0, 20.0).in_group("Mesh Subdivision"),
+            ParameterDefinition::boolean("smooth_normals", "Smooth Normals", false).in_group("Mesh Subdivision"),
+            ParameterDefinition::boolean("generate_uvs", "Generate UVs", true).in_group("Mesh Subdivision"),
+        ])
         .with_tags(vec!["geometry", "primitive", "cube", "3d", "mesh", "usd"])
         .with_processing_cost(ProcessingCost::Low)
         .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
@@ -50,20 +63,9 @@ impl NodeFactory for CubeNodeFactory {
         // Set panel type to Parameter
         node.set_panel_type(crate::nodes::interface::PanelType::Parameter);
         
-        // Initialize default parameters
-        node.parameters.insert("mode".to_string(), NodeData::String("primitive".to_string()));
+        // Internal flag; the user parameters come from the metadata schema
         node.parameters.insert("needs_reload".to_string(), NodeData::Boolean(false));
-        node.parameters.insert("size".to_string(), NodeData::Float(2.0));
-        node.parameters.insert("size_x".to_string(), NodeData::Float(2.0));
-        node.parameters.insert("size_y".to_string(), NodeData::Float(2.0));
-        node.parameters.insert("size_z".to_string(), NodeData::Float(2.0));
-        
-        // Mesh subdivision parameters
-        node.parameters.insert("subdivisions_x".to_string(), NodeData::Integer(1));
-        node.parameters.insert("subdivisions_y".to_string(), NodeData::Integer(1));
-        node.parameters.insert("subdivisions_z".to_string(), NodeData::Integer(1));
-        node.parameters.insert("smooth_normals".to_string(), NodeData::Boolean(false));
-        node.parameters.insert("generate_uvs".to_string(), NodeData::Boolean(true));
+        crate::nodes::parameter_schema::apply_defaults(&mut node, &meta.parameters);
         
         // Update port positions
         node.update_port_positions();
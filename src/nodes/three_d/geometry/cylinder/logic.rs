@@ -161,6 +161,7 @@ impl CylinderLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
     
@@ -266,6 +267,7 @@ impl CylinderLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
 }
\ No newline at end of file
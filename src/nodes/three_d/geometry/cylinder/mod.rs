@@ -25,7 +25,7 @@ impl NodeFactory for CylinderNodeFactory {
             // No inputs - this is a geometry source node
         ])
         .with_outputs(vec![
-            PortDefinition::required("Scene", DataType::Any)
+            PortDefinition::required("Scene", DataType::USDScene)
                 .with_description("USD scene data with cylinder geometry")
         ])
         .with_tags(vec!["geometry", "primitive", "cylinder", "3d", "mesh", "usd"])
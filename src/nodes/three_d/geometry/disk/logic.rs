@@ -0,0 +1,150 @@
+//! Disk node logic implementation
+//!
+//! A flat disk in the XZ plane facing +Y, or an annulus when the inner radius
+//! is above zero. UsdGeom has no disk schema, so primitive mode outputs a
+//! fixed, light tessellation and mesh mode one with the chosen subdivisions.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::normals::logic::{NormalsLogic, NormalsMode};
+use crate::workspaces::three_d::usd::usd_engine::{USDSceneData, USDMeshGeometry};
+use glam::{Mat4, Vec3, Vec2};
+use std::f32::consts::PI;
+
+/// Subdivisions of the primitive-mode disk (around the axis, rings)
+const PRIMITIVE_SUBDIVISIONS: (i32, i32) = (24, 1);
+
+pub struct DiskLogic {
+    mode: String,
+    radius: f32,
+    inner_radius: f32,
+    subdivisions_axis: i32,
+    subdivisions_rings: i32,
+    smooth_normals: bool,
+    generate_uvs: bool,
+    node_id: crate::nodes::NodeId,
+}
+
+impl DiskLogic {
+    pub fn from_node(node: &Node) -> Self {
+        Self {
+            mode: node.parameters.get("mode")
+                .and_then(|d| if let NodeData::String(s) = d { Some(s.clone()) } else { None })
+                .unwrap_or_else(|| "primitive".to_string()),
+            radius: node.parameters.get("radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(1.0),
+            inner_radius: node.parameters.get("inner_radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(0.0),
+            subdivisions_axis: node.parameters.get("subdivisions_axis")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(32),
+            subdivisions_rings: node.parameters.get("subdivisions_rings")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(1),
+            smooth_normals: node.parameters.get("smooth_normals")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            generate_uvs: node.parameters.get("generate_uvs")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            node_id: node.id,
+        }
+    }
+    
+    pub fn process(&mut self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let (stage_path, prim_path, mesh) = if self.mode == "primitive" {
+            let (axis, rings) = PRIMITIVE_SUBDIVISIONS;
+            (format!("procedural://disk_node_{}", self.node_id), "/Disk", self.generate_mesh(axis, rings))
+        } else {
+            let mesh = self.generate_mesh(self.subdivisions_axis, self.subdivisions_rings);
+            (format!("procedural://disk_mesh_node_{}", self.node_id), "/DiskMesh", mesh)
+        };
+        
+        vec![NodeData::USDSceneData(USDSceneData {
+            stage_path,
+            meshes: vec![USDMeshGeometry { prim_path: prim_path.to_string(), ..mesh }],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        })]
+    }
+    
+    /// Rings of points from the inner to the outer radius; UVs are planar over the disk
+    pub fn generate_mesh(&self, subdivisions_axis: i32, subdivisions_rings: i32) -> USDMeshGeometry {
+        let axis = subdivisions_axis.max(3);
+        let rings = subdivisions_rings.max(1);
+        let outer = self.radius.max(0.0);
+        let inner = self.inner_radius.clamp(0.0, outer);
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut uvs = Vec::new();
+        
+        for i in 0..=axis {
+            let (sin_a, cos_a) = (2.0 * PI * i as f32 / axis as f32).sin_cos();
+            for k in 0..=rings {
+                let radius = inner + (outer - inner) * k as f32 / rings as f32;
+                vertices.push(Vec3::new(radius * cos_a, 0.0, radius * sin_a));
+                if self.generate_uvs {
+                    let extent = outer.max(f32::EPSILON) * 2.0;
+                    uvs.push(Vec2::new(radius * cos_a / extent + 0.5, radius * sin_a / extent + 0.5));
+                }
+            }
+        }
+        
+        let row = rings + 1;
+        for i in 0..axis {
+            for k in 0..rings {
+                let a = (i * row + k) as u32;
+                let b = a + row as u32;
+                let c = b + 1;
+                let d = a + 1;
+                // Counter-clockwise seen from above; the innermost triangle collapses without a hole
+                if k > 0 || inner > 0.0 {
+                    indices.extend([a, b, d]);
+                }
+                indices.extend([b, c, d]);
+            }
+        }
+        
+        let mut mesh = USDMeshGeometry {
+            prim_path: "/DiskMesh".to_string(),
+            normals: vec![Vec3::Y; vertices.len()],
+            vertices,
+            indices,
+            uvs,
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        };
+        if !self.smooth_normals {
+            NormalsLogic { mode: NormalsMode::Face, ..Default::default() }.apply(&mut mesh);
+        }
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_disk_and_annulus_face_up() {
+        let mut node = Node::new(1, "Disk", Pos2::ZERO);
+        for inner_radius in [0.0, 0.5] {
+            node.parameters.insert("inner_radius".to_string(), NodeData::Float(inner_radius));
+            let mesh = DiskLogic::from_node(&node).generate_mesh(8, 2);
+            let faces = if inner_radius > 0.0 { 8 * 2 * 2 } else { 8 * 3 };
+            assert_eq!(mesh.indices.len(), faces * 3);
+            for face in mesh.indices.chunks_exact(3) {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[face[i] as usize]);
+                assert!((b - a).cross(c - a).y > 0.0);
+                assert!(mesh.vertices[face[0] as usize].length() >= inner_radius - 1e-5);
+            }
+        }
+    }
+}
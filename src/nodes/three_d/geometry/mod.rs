@@ -6,11 +6,18 @@ pub mod cylinder;
 pub mod cone;
 pub mod plane;
 pub mod capsule;
+pub mod torus;
+pub mod disk;
+pub mod tube;
 pub mod hooks;
+pub mod widgets;
 
 pub use cube::{CubeNode, CubeNodeFactory};
 pub use sphere::{SphereNode, SphereNodeFactory};
 pub use cylinder::{CylinderNode, CylinderNodeFactory};
 pub use cone::{ConeNode, ConeNodeFactory};
 pub use plane::{PlaneNode, PlaneNodeFactory};
-pub use capsule::{CapsuleNode, CapsuleNodeFactory};
\ No newline at end of file
+pub use capsule::{CapsuleNode, CapsuleNodeFactory};
+pub use torus::{TorusNode, TorusNodeFactory};
+pub use disk::{DiskNode, DiskNodeFactory};
+pub use tube::{TubeNode, TubeNodeFactory};
\ No newline at end of file
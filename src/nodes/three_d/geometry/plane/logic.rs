@@ -114,6 +114,7 @@ impl PlaneLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
     
@@ -187,6 +188,7 @@ impl PlaneLogic {
             lights: vec![],
             materials: vec![],
             up_axis: "Y".to_string(),
+            instancers: Vec::new(),
         }
     }
 }
\ No newline at end of file
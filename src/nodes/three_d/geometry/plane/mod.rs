@@ -25,7 +25,7 @@ impl NodeFactory for PlaneNodeFactory {
             // No inputs - this is a geometry source node
         ])
         .with_outputs(vec![
-            PortDefinition::required("Scene", DataType::Any)
+            PortDefinition::required("Scene", DataType::USDScene)
                 .with_description("USD scene data with plane geometry")
         ])
         .with_tags(vec!["geometry", "primitive", "plane", "3d", "mesh", "usd"])
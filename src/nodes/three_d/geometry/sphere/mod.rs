@@ -26,7 +26,7 @@ impl NodeFactory for SphereNodeFactory {
             // No inputs - this is a geometry source node
         ])
         .with_outputs(vec![
-            PortDefinition::required("Scene", DataType::Any)
+            PortDefinition::required("Scene", DataType::USDScene)
                 .with_description("USD scene data with sphere geometry")
         ])
         .with_tags(vec!["geometry", "primitive", "sphere", "3d", "mesh", "usd"])
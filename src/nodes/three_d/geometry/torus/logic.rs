@@ -0,0 +1,149 @@
+//! Torus node logic implementation
+//!
+//! UsdGeom has no torus schema, so primitive mode outputs a fixed, light
+//! tessellation and mesh mode one with the chosen subdivisions.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::normals::logic::{NormalsLogic, NormalsMode};
+use crate::workspaces::three_d::usd::usd_engine::{USDSceneData, USDMeshGeometry};
+use glam::{Mat4, Vec3, Vec2};
+use std::f32::consts::PI;
+
+/// Subdivisions of the primitive-mode torus (around the axis, around the tube)
+const PRIMITIVE_SUBDIVISIONS: (i32, i32) = (24, 12);
+
+pub struct TorusLogic {
+    mode: String,
+    major_radius: f32,
+    minor_radius: f32,
+    subdivisions_axis: i32,
+    subdivisions_tube: i32,
+    smooth_normals: bool,
+    generate_uvs: bool,
+    node_id: crate::nodes::NodeId,
+}
+
+impl TorusLogic {
+    pub fn from_node(node: &Node) -> Self {
+        Self {
+            mode: node.parameters.get("mode")
+                .and_then(|d| if let NodeData::String(s) = d { Some(s.clone()) } else { None })
+                .unwrap_or_else(|| "primitive".to_string()),
+            major_radius: node.parameters.get("major_radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(1.0),
+            minor_radius: node.parameters.get("minor_radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(0.25),
+            subdivisions_axis: node.parameters.get("subdivisions_axis")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(32),
+            subdivisions_tube: node.parameters.get("subdivisions_tube")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(16),
+            smooth_normals: node.parameters.get("smooth_normals")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            generate_uvs: node.parameters.get("generate_uvs")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            node_id: node.id,
+        }
+    }
+    
+    pub fn process(&mut self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let (stage_path, prim_path, mesh) = if self.mode == "primitive" {
+            let (axis, tube) = PRIMITIVE_SUBDIVISIONS;
+            (format!("procedural://torus_node_{}", self.node_id), "/Torus", self.generate_mesh(axis, tube))
+        } else {
+            let mesh = self.generate_mesh(self.subdivisions_axis, self.subdivisions_tube);
+            (format!("procedural://torus_mesh_node_{}", self.node_id), "/TorusMesh", mesh)
+        };
+        
+        vec![NodeData::USDSceneData(USDSceneData {
+            stage_path,
+            meshes: vec![USDMeshGeometry { prim_path: prim_path.to_string(), ..mesh }],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        })]
+    }
+    
+    /// Torus around the Y axis; U runs around the axis and V around the tube
+    pub fn generate_mesh(&self, subdivisions_axis: i32, subdivisions_tube: i32) -> USDMeshGeometry {
+        let axis = subdivisions_axis.max(3);
+        let tube = subdivisions_tube.max(3);
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut normals = Vec::new();
+        let mut uvs = Vec::new();
+        
+        // Seam rows and columns are duplicated so UVs wrap cleanly
+        for i in 0..=axis {
+            let u = i as f32 / axis as f32;
+            let (sin_u, cos_u) = (2.0 * PI * u).sin_cos();
+            let radial = Vec3::new(cos_u, 0.0, sin_u);
+            for j in 0..=tube {
+                let v = j as f32 / tube as f32;
+                let (sin_v, cos_v) = (2.0 * PI * v).sin_cos();
+                let normal = radial * cos_v + Vec3::Y * sin_v;
+                vertices.push(radial * self.major_radius + normal * self.minor_radius);
+                normals.push(normal);
+                if self.generate_uvs {
+                    uvs.push(Vec2::new(u, v));
+                }
+            }
+        }
+        
+        let row = tube + 1;
+        for i in 0..axis {
+            for j in 0..tube {
+                let a = (i * row + j) as u32;
+                let b = a + row as u32;
+                let c = b + 1;
+                let d = a + 1;
+                // Counter-clockwise seen from outside
+                indices.extend([a, c, b, a, d, c]);
+            }
+        }
+        
+        let mut mesh = USDMeshGeometry {
+            prim_path: "/TorusMesh".to_string(),
+            vertices,
+            indices,
+            normals,
+            uvs,
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        };
+        if !self.smooth_normals {
+            NormalsLogic { mode: NormalsMode::Face, ..Default::default() }.apply(&mut mesh);
+        }
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_torus_faces_point_outward() {
+        let mut node = Node::new(1, "Torus", Pos2::ZERO);
+        node.parameters.insert("mode".to_string(), NodeData::String("mesh".to_string()));
+        let logic = TorusLogic::from_node(&node);
+        let mesh = logic.generate_mesh(8, 6);
+        assert_eq!(mesh.vertices.len(), 9 * 7);
+        assert_eq!(mesh.indices.len(), 8 * 6 * 6);
+        for face in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[face[i] as usize]);
+            let normal = mesh.normals[face[0] as usize];
+            assert!((b - a).cross(c - a).dot(normal) > 0.0);
+        }
+    }
+}
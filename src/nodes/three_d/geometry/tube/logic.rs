@@ -0,0 +1,196 @@
+//! Tube node logic implementation
+//!
+//! A hollow cylinder along Y: outer and inner walls joined by ring-shaped
+//! caps, or an open cylinder (outer wall only) when the inner radius is zero.
+//! UsdGeom has no tube schema, so primitive mode outputs a fixed, light
+//! tessellation and mesh mode one with the chosen subdivisions.
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::normals::logic::{NormalsLogic, NormalsMode};
+use crate::workspaces::three_d::usd::usd_engine::{USDSceneData, USDMeshGeometry};
+use glam::{Mat4, Vec3, Vec2};
+use std::f32::consts::PI;
+
+/// Subdivisions of the primitive-mode tube (around the axis, along the height)
+const PRIMITIVE_SUBDIVISIONS: (i32, i32) = (24, 1);
+
+pub struct TubeLogic {
+    mode: String,
+    radius: f32,
+    inner_radius: f32,
+    height: f32,
+    subdivisions_axis: i32,
+    subdivisions_height: i32,
+    smooth_normals: bool,
+    generate_uvs: bool,
+    node_id: crate::nodes::NodeId,
+}
+
+/// Mesh under construction
+#[derive(Default)]
+struct TubeBuilder {
+    vertices: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    indices: Vec<u32>,
+}
+
+impl TubeBuilder {
+    /// Grid of `columns` × `rows` quads from `point(u, v)`, wound so `normal(u, v)` faces out
+    fn grid(&mut self, columns: i32, rows: i32, point: impl Fn(f32, f32) -> Vec3, normal: impl Fn(f32, f32) -> Vec3, uv: impl Fn(f32, f32) -> Vec2) {
+        let first = self.vertices.len() as u32;
+        for i in 0..=columns {
+            for j in 0..=rows {
+                let (u, v) = (i as f32 / columns as f32, j as f32 / rows as f32);
+                self.vertices.push(point(u, v));
+                self.normals.push(normal(u, v));
+                self.uvs.push(uv(u, v));
+            }
+        }
+        let row = rows as u32 + 1;
+        for i in 0..columns as u32 {
+            for j in 0..rows as u32 {
+                let a = first + i * row + j;
+                let (b, c, d) = (a + row, a + row + 1, a + 1);
+                // Flip the quad when its corners turn the wrong way round the normal
+                let [pa, pb, pd] = [a, b, d].map(|index| self.vertices[index as usize]);
+                let facing = (pb - pa).cross(pd - pa).dot(self.normals[a as usize]);
+                if facing >= 0.0 {
+                    self.indices.extend([a, b, d, b, c, d]);
+                } else {
+                    self.indices.extend([a, d, b, b, d, c]);
+                }
+            }
+        }
+    }
+}
+
+impl TubeLogic {
+    pub fn from_node(node: &Node) -> Self {
+        Self {
+            mode: node.parameters.get("mode")
+                .and_then(|d| if let NodeData::String(s) = d { Some(s.clone()) } else { None })
+                .unwrap_or_else(|| "primitive".to_string()),
+            radius: node.parameters.get("radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(1.0),
+            inner_radius: node.parameters.get("inner_radius")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(0.8),
+            height: node.parameters.get("height")
+                .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+                .unwrap_or(2.0),
+            subdivisions_axis: node.parameters.get("subdivisions_axis")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(32),
+            subdivisions_height: node.parameters.get("subdivisions_height")
+                .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+                .unwrap_or(1),
+            smooth_normals: node.parameters.get("smooth_normals")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            generate_uvs: node.parameters.get("generate_uvs")
+                .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+                .unwrap_or(true),
+            node_id: node.id,
+        }
+    }
+    
+    pub fn process(&mut self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let (stage_path, prim_path, mesh) = if self.mode == "primitive" {
+            let (axis, height) = PRIMITIVE_SUBDIVISIONS;
+            (format!("procedural://tube_node_{}", self.node_id), "/Tube", self.generate_mesh(axis, height))
+        } else {
+            let mesh = self.generate_mesh(self.subdivisions_axis, self.subdivisions_height);
+            (format!("procedural://tube_mesh_node_{}", self.node_id), "/TubeMesh", mesh)
+        };
+        
+        vec![NodeData::USDSceneData(USDSceneData {
+            stage_path,
+            meshes: vec![USDMeshGeometry { prim_path: prim_path.to_string(), ..mesh }],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        })]
+    }
+    
+    /// Walls split into `subdivisions_height` bands; each cap is a single ring of quads
+    pub fn generate_mesh(&self, subdivisions_axis: i32, subdivisions_height: i32) -> USDMeshGeometry {
+        let axis = subdivisions_axis.max(3);
+        let bands = subdivisions_height.max(1);
+        let outer = self.radius.max(0.0);
+        let inner = self.inner_radius.clamp(0.0, outer);
+        let half_height = self.height / 2.0;
+        let around = |u: f32| {
+            let (sin_a, cos_a) = (2.0 * PI * u).sin_cos();
+            Vec3::new(cos_a, 0.0, sin_a)
+        };
+        let mut builder = TubeBuilder::default();
+        
+        // Outer wall
+        builder.grid(axis, bands,
+            |u, v| around(u) * outer + Vec3::Y * (v * self.height - half_height),
+            |u, _| around(u),
+            |u, v| Vec2::new(u, v));
+        if inner > 0.0 {
+            // Inner wall, facing the axis
+            builder.grid(axis, bands,
+                |u, v| around(u) * inner + Vec3::Y * (v * self.height - half_height),
+                |u, _| -around(u),
+                |u, v| Vec2::new(u, v));
+            // Caps between the walls; UVs are planar over the outer radius
+            for (y, normal) in [(half_height, Vec3::Y), (-half_height, Vec3::NEG_Y)] {
+                builder.grid(axis, 1,
+                    |u, v| around(u) * (inner + (outer - inner) * v) + Vec3::Y * y,
+                    |_, _| normal,
+                    |u, v| {
+                        let point = around(u) * (inner + (outer - inner) * v) / (2.0 * outer);
+                        Vec2::new(point.x + 0.5, point.z + 0.5)
+                    });
+            }
+        }
+        
+        let mut mesh = USDMeshGeometry {
+            prim_path: "/TubeMesh".to_string(),
+            vertices: builder.vertices,
+            indices: builder.indices,
+            normals: builder.normals,
+            uvs: if self.generate_uvs { builder.uvs } else { Vec::new() },
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        };
+        if !self.smooth_normals {
+            NormalsLogic { mode: NormalsMode::Face, ..Default::default() }.apply(&mut mesh);
+        }
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Pos2;
+
+    #[test]
+    fn test_tube_walls_and_caps_face_out() {
+        let mut node = Node::new(1, "Tube", Pos2::ZERO);
+        node.parameters.insert("smooth_normals".to_string(), NodeData::Boolean(false));
+        let mesh = TubeLogic::from_node(&node).generate_mesh(8, 2);
+        // Outer and inner walls of 8 × 2 quads, two caps of 8 quads
+        assert_eq!(mesh.indices.len(), (8 * 2 * 2 + 8 * 2) * 6);
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+        // Outward winding gives a positive volume: an octagonal ring of height 2
+        let volume: f32 = mesh.indices.chunks_exact(3)
+            .map(|face| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[face[i] as usize]);
+                a.dot(b.cross(c)) / 6.0
+            })
+            .sum();
+        let expected = 4.0 * (PI / 4.0).sin() * (1.0 - 0.8 * 0.8) * 2.0;
+        assert!((volume - expected).abs() < 1e-4, "{} != {}", volume, expected);
+    }
+}
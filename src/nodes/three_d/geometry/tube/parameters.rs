@@ -0,0 +1,70 @@
+//! Tube node parameter interface with primitive/mesh toggle
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::three_d::geometry::widgets::{float_parameter, integer_parameter, boolean_parameter};
+use egui::{Ui, ComboBox, Separator};
+
+pub struct TubeParameters;
+
+impl TubeParameters {
+    pub fn build_interface(node: &mut Node, ui: &mut Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        
+        // Get current mode
+        let mut current_mode = node.parameters.get("mode")
+            .and_then(|d| if let NodeData::String(s) = d { Some(s.clone()) } else { None })
+            .unwrap_or_else(|| "primitive".to_string());
+        
+        let is_primitive_mode = current_mode == "primitive";
+        
+        // Mode selector
+        ui.horizontal(|ui| {
+            ComboBox::from_label("Mode")
+                .selected_text(if is_primitive_mode { "Primitive" } else { "Mesh" })
+                .show_ui(ui, |ui| {
+                    for (value, label) in [("primitive", "Primitive"), ("mesh", "Mesh")] {
+                        if ui.selectable_value(&mut current_mode, value.to_string(), label).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "mode".to_string(),
+                                value: NodeData::String(value.to_string()),
+                            });
+                        }
+                    }
+                });
+        });
+        
+        ui.add(Separator::default());
+        
+        float_parameter(node, ui, &mut changes, "radius", "Radius", 1.0, 0.01..=100.0);
+        float_parameter(node, ui, &mut changes, "inner_radius", "Inner Radius", 0.8, 0.0..=100.0);
+        float_parameter(node, ui, &mut changes, "height", "Height", 2.0, 0.01..=100.0);
+
+        ui.add(Separator::default());
+        
+        // Mesh subdivision parameters (disabled in primitive mode)
+        ui.label("Mesh Subdivision:");
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(!is_primitive_mode, |ui| {
+                integer_parameter(node, ui, &mut changes, "subdivisions_axis", "Axis", 32, 3..=256);
+                integer_parameter(node, ui, &mut changes, "subdivisions_height", "Height", 1, 1..=64);
+            });
+        });
+        
+        // Mesh options
+        ui.add_enabled_ui(!is_primitive_mode, |ui| {
+            boolean_parameter(node, ui, &mut changes, "smooth_normals", "Smooth Normals", true);
+            boolean_parameter(node, ui, &mut changes, "generate_uvs", "Generate UVs", true);
+        });
+        
+        // Show mode info
+        ui.add(Separator::default());
+        if is_primitive_mode {
+            ui.label("🔧 Primitive mode: Fixed-resolution tube (UsdGeom has no tube primitive)");
+        } else {
+            ui.label("🔧 Mesh mode: Generates tessellated tube mesh");
+        }
+        
+        changes
+    }
+}
@@ -0,0 +1,53 @@
+//! Parameter widgets shared by the hand-written primitive interfaces
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use egui::{Ui, DragValue};
+use std::ops::RangeInclusive;
+
+/// Drag value editing the float parameter `name`, showing `default` while it is unset
+pub fn float_parameter(node: &Node, ui: &mut Ui, changes: &mut Vec<ParameterChange>, name: &str, label: &str, default: f32, range: RangeInclusive<f32>) {
+    let mut value = node.parameters.get(name)
+        .and_then(|d| if let NodeData::Float(f) = d { Some(*f) } else { None })
+        .unwrap_or(default);
+    if ui.add(DragValue::new(&mut value)
+        .speed(0.05)
+        .range(range)
+        .prefix(format!("{}: ", label)))
+        .changed() {
+        changes.push(ParameterChange {
+            parameter: name.to_string(),
+            value: NodeData::Float(value),
+        });
+    }
+}
+
+/// Drag value editing the integer parameter `name`, showing `default` while it is unset
+pub fn integer_parameter(node: &Node, ui: &mut Ui, changes: &mut Vec<ParameterChange>, name: &str, label: &str, default: i32, range: RangeInclusive<i32>) {
+    let mut value = node.parameters.get(name)
+        .and_then(|d| if let NodeData::Integer(i) = d { Some(*i) } else { None })
+        .unwrap_or(default);
+    if ui.add(DragValue::new(&mut value)
+        .speed(1)
+        .range(range)
+        .prefix(format!("{}: ", label)))
+        .changed() {
+        changes.push(ParameterChange {
+            parameter: name.to_string(),
+            value: NodeData::Integer(value),
+        });
+    }
+}
+
+/// Checkbox editing the boolean parameter `name`, showing `default` while it is unset
+pub fn boolean_parameter(node: &Node, ui: &mut Ui, changes: &mut Vec<ParameterChange>, name: &str, label: &str, default: bool) {
+    let mut value = node.parameters.get(name)
+        .and_then(|d| if let NodeData::Boolean(b) = d { Some(*b) } else { None })
+        .unwrap_or(default);
+    if ui.checkbox(&mut value, label).changed() {
+        changes.push(ParameterChange {
+            parameter: name.to_string(),
+            value: NodeData::Boolean(value),
+        });
+    }
+}
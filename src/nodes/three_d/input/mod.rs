@@ -0,0 +1,9 @@
+//! 3D input nodes - reading scenes, points and volumes from non-USD files
+pub mod read_gltf;
+pub mod read_mesh;
+pub mod read_points;
+pub mod read_vdb;
+pub use read_gltf::ReadGltfNode;
+pub use read_mesh::ReadMeshNode;
+pub use read_points::ReadPointsNode;
+pub use read_vdb::ReadVdbNode;
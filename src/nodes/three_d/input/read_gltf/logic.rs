@@ -0,0 +1,253 @@
+//! Read glTF node functional operations - converting glTF scenes to USD scene data
+//!
+//! The default scene of the file is walked from its root nodes. Every node
+//! becomes a level of the prim path and every mesh primitive a mesh prim
+//! under it, with the node's world transform. Materials keep their PBR
+//! factors and are bound to the meshes using them; primitives without vertex
+//! colors show their material's base color. glTF is Y-up with bottom-left
+//! texture origin flipped from USD's, so the V coordinate is mirrored.
+
+use std::collections::HashSet;
+use std::path::Path;
+use glam::{Mat4, Vec2, Vec3};
+use crate::nodes::interface::NodeData;
+use crate::nodes::project_paths;
+use crate::nodes::three_d::modify::normals::logic::NormalsLogic;
+use crate::workspaces::three_d::usd::usd_engine::{AttributeValue, USDAttribute, USDMaterialData, USDMeshGeometry, USDSceneData};
+
+/// File extensions the node reads
+pub const GLTF_EXTENSIONS: &[&str] = &["gltf", "glb"];
+
+/// Core Read glTF configuration
+#[derive(Debug, Clone, Default)]
+pub struct ReadGltfLogic {
+    pub file_path: String,
+}
+
+impl ReadGltfLogic {
+    /// Read the file into a USD scene; None when no file is set or it cannot be read
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+
+        match load_gltf(&project_paths::resolve_path(&self.file_path)) {
+            Ok(scene) => vec![NodeData::USDSceneData(scene)],
+            Err(e) => {
+                log::error!("Read glTF: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+}
+
+/// Load a .gltf or .glb file with its buffers
+pub fn load_gltf(path: &Path) -> Result<USDSceneData, String> {
+    let (document, buffers, _images) = gltf::import(path).map_err(|e| e.to_string())?;
+    let root = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    Ok(convert_gltf(&document, &buffers, &root, &path.to_string_lossy()))
+}
+
+/// Convert a loaded glTF document; prims go under `/<root_name>`
+pub fn convert_gltf(document: &gltf::Document, buffers: &[gltf::buffer::Data], root_name: &str, stage_path: &str) -> USDSceneData {
+    let root = format!("/{}", prim_name(root_name, "Root"));
+    let mut taken = HashSet::new();
+
+    let materials: Vec<USDMaterialData> = document.materials().map(|material| {
+        let name = prim_name(material.name().unwrap_or_default(), &format!("Material_{}", material.index().unwrap_or(0)));
+        let pbr = material.pbr_metallic_roughness();
+        let [r, g, b, _] = pbr.base_color_factor();
+        USDMaterialData {
+            prim_path: unique_path(&format!("{}/Materials/{}", root, name), &mut taken),
+            diffuse_color: Vec3::new(r, g, b),
+            metallic: pbr.metallic_factor(),
+            roughness: pbr.roughness_factor(),
+        }
+    }).collect();
+
+    let mut converter = Converter { buffers, materials: &materials, meshes: Vec::new(), taken };
+    if let Some(scene) = document.default_scene().or_else(|| document.scenes().next()) {
+        for node in scene.nodes() {
+            converter.visit(&node, Mat4::IDENTITY, &root);
+        }
+    }
+
+    USDSceneData {
+        stage_path: stage_path.to_string(),
+        meshes: converter.meshes,
+        lights: Vec::new(),
+        materials,
+        up_axis: "Y".to_string(),
+        instancers: Vec::new(),
+    }
+}
+
+/// Walks the node hierarchy collecting meshes
+struct Converter<'a> {
+    buffers: &'a [gltf::buffer::Data],
+    materials: &'a [USDMaterialData],
+    meshes: Vec<USDMeshGeometry>,
+    /// Prim paths in use, so same-named siblings stay apart
+    taken: HashSet<String>,
+}
+
+impl Converter<'_> {
+    fn visit(&mut self, node: &gltf::Node, parent: Mat4, parent_path: &str) {
+        let transform = parent * Mat4::from_cols_array_2d(&node.transform().matrix());
+        let name = prim_name(node.name().unwrap_or_default(), &format!("Node_{}", node.index()));
+        let path = unique_path(&format!("{}/{}", parent_path, name), &mut self.taken);
+
+        if let Some(mesh) = node.mesh() {
+            let mesh_name = prim_name(mesh.name().unwrap_or_default(), &format!("Mesh_{}", mesh.index()));
+            let primitive_count = mesh.primitives().len();
+            for (index, primitive) in mesh.primitives().enumerate() {
+                let prim_path = if primitive_count == 1 {
+                    format!("{}/{}", path, mesh_name)
+                } else {
+                    format!("{}/{}_{}", path, mesh_name, index)
+                };
+                match self.convert_primitive(&primitive, unique_path(&prim_path, &mut self.taken), transform) {
+                    Ok(mesh) => self.meshes.push(mesh),
+                    Err(e) => log::warn!("Read glTF: skipping {}: {}", prim_path, e),
+                }
+            }
+        }
+
+        for child in node.children() {
+            self.visit(&child, transform, &path);
+        }
+    }
+
+    fn convert_primitive(&self, primitive: &gltf::Primitive, prim_path: String, transform: Mat4) -> Result<USDMeshGeometry, String> {
+        if primitive.mode() != gltf::mesh::Mode::Triangles {
+            return Err(format!("{:?} primitives are not supported", primitive.mode()));
+        }
+        let reader = primitive.reader(|buffer| self.buffers.get(buffer.index()).map(|data| &data.0[..]));
+        let vertices: Vec<Vec3> = reader.read_positions()
+            .ok_or("no positions")?
+            .map(Vec3::from)
+            .collect();
+        let indices: Vec<u32> = match reader.read_indices() {
+            Some(indices) => indices.into_u32().collect(),
+            None => (0..vertices.len() as u32).collect(),
+        };
+        if let Some(index) = indices.iter().find(|index| **index as usize >= vertices.len()) {
+            return Err(format!("index {} is out of range", index));
+        }
+        let normals = reader.read_normals().map(|normals| normals.map(Vec3::from).collect()).unwrap_or_default();
+        let uvs = reader.read_tex_coords(0)
+            .map(|uvs| uvs.into_f32().map(|[u, v]| Vec2::new(u, 1.0 - v)).collect())
+            .unwrap_or_default();
+
+        let material = primitive.material().index().and_then(|index| self.materials.get(index));
+        let vertex_colors = reader.read_colors(0)
+            .map(|colors| colors.into_rgb_f32().map(Vec3::from).collect())
+            .or_else(|| material.map(|material| vec![material.diffuse_color; vertices.len()]));
+        let attributes = material.map(|material| vec![USDAttribute {
+            name: "material:binding".to_string(),
+            value_type: "rel".to_string(),
+            value: AttributeValue::Relationship(vec![material.prim_path.clone()]),
+            is_custom: false,
+            metadata: Default::default(),
+        }]).unwrap_or_default();
+
+        let mut mesh = USDMeshGeometry {
+            prim_path,
+            vertices,
+            indices,
+            normals,
+            uvs,
+            vertex_colors,
+            transform,
+            primvars: Vec::new(),
+            attributes,
+        };
+        if mesh.normals.len() != mesh.vertices.len() {
+            NormalsLogic::default().apply(&mut mesh);
+        }
+        Ok(mesh)
+    }
+}
+
+/// A valid prim name from a glTF name, or `fallback` for unnamed elements
+pub fn prim_name(name: &str, fallback: &str) -> String {
+    let name: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if name.trim_matches('_').is_empty() {
+        fallback.to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// `path`, or `path_2`, `path_3`... if it is already taken
+pub fn unique_path(path: &str, taken: &mut HashSet<String>) -> String {
+    let mut unique = path.to_string();
+    let mut suffix = 2;
+    while !taken.insert(unique.clone()) {
+        unique = format!("{}_{}", path, suffix);
+        suffix += 1;
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A red triangle under a translated parent node, with the buffer inlined
+    const TRIANGLE: &str = r#"{
+        "asset": {"version": "2.0"},
+        "scene": 0,
+        "scenes": [{"nodes": [0]}],
+        "nodes": [
+            {"name": "Parent", "translation": [0, 2, 0], "children": [1]},
+            {"name": "Leaf node", "mesh": 0}
+        ],
+        "meshes": [{"name": "Tri", "primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "material": 0}]}],
+        "materials": [{"name": "Red", "pbrMetallicRoughness": {"baseColorFactor": [1, 0, 0, 1], "metallicFactor": 0.5, "roughnessFactor": 0.25}}],
+        "buffers": [{"byteLength": 44, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAAABAAIAAAA="}],
+        "bufferViews": [
+            {"buffer": 0, "byteOffset": 0, "byteLength": 36},
+            {"buffer": 0, "byteOffset": 36, "byteLength": 6}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_gltf_hierarchy_meshes_and_materials_convert() {
+        let (document, buffers, _) = gltf::import_slice(TRIANGLE.as_bytes()).unwrap();
+        let scene = convert_gltf(&document, &buffers, "triangle", "triangle.gltf");
+
+        assert_eq!(scene.up_axis, "Y");
+        assert_eq!(scene.materials.len(), 1);
+        assert_eq!(scene.materials[0].prim_path, "/triangle/Materials/Red");
+        assert_eq!(scene.materials[0].metallic, 0.5);
+
+        assert_eq!(scene.meshes.len(), 1);
+        let mesh = &scene.meshes[0];
+        assert_eq!(mesh.prim_path, "/triangle/Parent/Leaf_node/Tri");
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+        assert_eq!(mesh.transform.transform_point3(Vec3::ZERO), Vec3::new(0.0, 2.0, 0.0));
+        // Normals are computed when the file has none
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+        assert_eq!(mesh.vertex_colors.as_ref().map(|colors| colors[0]), Some(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(matches!(&mesh.attributes[0].value, AttributeValue::Relationship(targets) if targets[0] == "/triangle/Materials/Red"));
+    }
+
+    #[test]
+    fn test_prim_names_are_made_valid_and_unique() {
+        assert_eq!(prim_name("Body Mesh.001", "Mesh_0"), "Body_Mesh_001");
+        assert_eq!(prim_name("", "Node_3"), "Node_3");
+        assert_eq!(prim_name("3D", "Node_0"), "_3D");
+        let mut taken = HashSet::new();
+        assert_eq!(unique_path("/Root/A", &mut taken), "/Root/A");
+        assert_eq!(unique_path("/Root/A", &mut taken), "/Root/A_2");
+    }
+}
@@ -0,0 +1,38 @@
+//! Read glTF node module - converting glTF/GLB assets into USD scene data
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Loading the file and converting meshes, materials and hierarchy
+//! - parameters.rs: File selection interface
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadGltfLogic;
+pub use parameters::ReadGltfNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ReadGltfNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_ReadGLTF",
+            "Read glTF",
+            crate::nodes::NodeCategory::new(&["3D", "Input"]),
+            "Reads a .gltf or .glb file into a USD scene with its meshes, materials and node hierarchy"
+        )
+        .with_color(egui::Color32::from_rgb(100, 150, 200))
+        .with_icon("📦")
+        .with_inputs(vec![
+            // No inputs - this is a scene source node
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Scene", crate::nodes::DataType::USDScene)
+                .with_description("USD scene data converted from the file"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "input", "gltf", "glb", "file", "import", "read", "mesh", "asset"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
@@ -0,0 +1,657 @@
+//! Read Mesh node functional operations - parsing OBJ, STL and PLY files
+//!
+//! These formats carry bare polygon meshes, so the parsers only read what a
+//! USD mesh can hold: points, polygons (fan-triangulated), and where present
+//! normals, UVs and vertex colors. OBJ objects and groups become separate
+//! mesh prims; STL and PLY files give a single mesh. STL triangles are welded
+//! by position so normals can be smoothed up to the usual cusp angle.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use glam::{Mat4, Vec2, Vec3};
+use crate::nodes::interface::NodeData;
+use crate::nodes::project_paths;
+use crate::nodes::three_d::input::read_gltf::logic::{prim_name, unique_path};
+use crate::nodes::three_d::modify::normals::logic::NormalsLogic;
+use crate::workspaces::three_d::usd::usd_engine::{USDMeshGeometry, USDSceneData};
+
+/// File extensions the node reads
+pub const MESH_EXTENSIONS: &[&str] = &["obj", "stl", "ply"];
+
+/// Up axis the file was authored with; written to the scene so viewers orient it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UpAxis {
+    #[default]
+    Y,
+    Z,
+}
+
+impl UpAxis {
+    pub const ALL: [UpAxis; 2] = [UpAxis::Y, UpAxis::Z];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpAxis::Y => "Y",
+            UpAxis::Z => "Z",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|axis| axis.name() == name)
+    }
+}
+
+/// Core Read Mesh configuration
+#[derive(Debug, Clone, Default)]
+pub struct ReadMeshLogic {
+    pub file_path: String,
+    pub up_axis: UpAxis,
+}
+
+impl ReadMeshLogic {
+    /// Read the file into a USD scene; None when no file is set or it cannot be read
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+
+        match load_mesh_file(&project_paths::resolve_path(&self.file_path), self.up_axis) {
+            Ok(scene) => vec![NodeData::USDSceneData(scene)],
+            Err(e) => {
+                log::error!("Read Mesh: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+}
+
+/// A parsed mesh before it becomes a prim; attribute lists shorter than
+/// `vertices` were only partly present in the file and are dropped
+#[derive(Debug, Clone, Default)]
+pub struct ImportedMesh {
+    pub name: String,
+    pub vertices: Vec<Vec3>,
+    pub indices: Vec<u32>,
+    pub normals: Vec<Vec3>,
+    pub uvs: Vec<Vec2>,
+    pub colors: Vec<Vec3>,
+}
+
+impl ImportedMesh {
+    fn named(name: &str) -> Self {
+        Self { name: name.to_string(), ..Default::default() }
+    }
+
+    /// Triangulate a polygon of vertex indices as a fan
+    fn add_polygon(&mut self, polygon: &[u32]) {
+        for i in 1..polygon.len().saturating_sub(1) {
+            self.indices.extend_from_slice(&[polygon[0], polygon[i], polygon[i + 1]]);
+        }
+    }
+
+    fn into_geometry(self, prim_path: String) -> USDMeshGeometry {
+        let count = self.vertices.len();
+        let mut mesh = USDMeshGeometry {
+            prim_path,
+            vertices: self.vertices,
+            indices: self.indices,
+            normals: if self.normals.len() == count { self.normals } else { Vec::new() },
+            uvs: if self.uvs.len() == count { self.uvs } else { Vec::new() },
+            vertex_colors: (self.colors.len() == count).then_some(self.colors),
+            transform: Mat4::IDENTITY,
+            primvars: Vec::new(),
+            attributes: Vec::new(),
+        };
+        if mesh.normals.is_empty() {
+            NormalsLogic::default().apply(&mut mesh);
+        }
+        mesh
+    }
+}
+
+/// Load an .obj, .stl or .ply file, choosing the parser by extension
+pub fn load_mesh_file(path: &Path, up_axis: UpAxis) -> Result<USDSceneData, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    let meshes = match extension.as_str() {
+        "obj" => parse_obj(&String::from_utf8_lossy(&bytes))?,
+        "stl" => vec![parse_stl(&bytes)?],
+        "ply" => vec![parse_ply(&bytes)?],
+        other => return Err(format!("unsupported file type '.{}'", other)),
+    };
+    let root = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    Ok(build_scene(meshes, &root, &path.to_string_lossy(), up_axis))
+}
+
+/// Put parsed meshes under `/<root_name>` in a new scene
+pub fn build_scene(meshes: Vec<ImportedMesh>, root_name: &str, stage_path: &str, up_axis: UpAxis) -> USDSceneData {
+    let root = format!("/{}", prim_name(root_name, "Root"));
+    let mut taken = HashSet::new();
+    USDSceneData {
+        stage_path: stage_path.to_string(),
+        meshes: meshes.into_iter()
+            .filter(|mesh| !mesh.indices.is_empty())
+            .map(|mesh| {
+                let path = unique_path(&format!("{}/{}", root, prim_name(&mesh.name, "Mesh")), &mut taken);
+                mesh.into_geometry(path)
+            })
+            .collect(),
+        lights: Vec::new(),
+        materials: Vec::new(),
+        up_axis: up_axis.name().to_string(),
+        instancers: Vec::new(),
+    }
+}
+
+fn parse_floats<'a>(parts: impl Iterator<Item = &'a str>, line: usize) -> Result<Vec<f32>, String> {
+    parts.map(|part| part.parse::<f32>().map_err(|_| format!("line {}: '{}' is not a number", line, part)))
+        .collect()
+}
+
+/// Resolve a 1-based (or negative, relative) OBJ index into a list of `count` items
+fn obj_index(token: &str, count: usize, line: usize) -> Result<Option<usize>, String> {
+    if token.is_empty() {
+        return Ok(None);
+    }
+    let index: i64 = token.parse().map_err(|_| format!("line {}: '{}' is not an index", line, token))?;
+    let resolved = if index < 0 { count as i64 + index } else { index - 1 };
+    if resolved < 0 || resolved >= count as i64 {
+        return Err(format!("line {}: index {} is out of range", line, index));
+    }
+    Ok(Some(resolved as usize))
+}
+
+/// Parse Wavefront OBJ text; each `o`/`g` statement starts a new mesh
+pub fn parse_obj(text: &str) -> Result<Vec<ImportedMesh>, String> {
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    let mut uvs = Vec::new();
+    let mut normals = Vec::new();
+    let mut meshes = vec![ImportedMesh::named("Mesh")];
+    // Position/UV/normal combinations already turned into a vertex of the current mesh
+    let mut corners: HashMap<(usize, Option<usize>, Option<usize>), u32> = HashMap::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line_number = number + 1;
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("v") => {
+                let values = parse_floats(parts, line_number)?;
+                if values.len() < 3 {
+                    return Err(format!("line {}: a vertex needs three coordinates", line_number));
+                }
+                positions.push(Vec3::new(values[0], values[1], values[2]));
+                // Common extension: v x y z r g b
+                colors.push((values.len() >= 6).then(|| Vec3::new(values[3], values[4], values[5])));
+            }
+            Some("vt") => {
+                let values = parse_floats(parts, line_number)?;
+                uvs.push(Vec2::new(values.first().copied().unwrap_or(0.0), values.get(1).copied().unwrap_or(0.0)));
+            }
+            Some("vn") => {
+                let values = parse_floats(parts, line_number)?;
+                if values.len() < 3 {
+                    return Err(format!("line {}: a normal needs three components", line_number));
+                }
+                normals.push(Vec3::new(values[0], values[1], values[2]));
+            }
+            Some("o") | Some("g") => {
+                let name = parts.collect::<Vec<_>>().join("_");
+                let current = meshes.last_mut().expect("there is always a current mesh");
+                if current.indices.is_empty() {
+                    current.name = name;
+                } else {
+                    meshes.push(ImportedMesh::named(&name));
+                    corners.clear();
+                }
+            }
+            Some("f") => {
+                let mesh = meshes.last_mut().expect("there is always a current mesh");
+                let mut polygon = Vec::new();
+                for corner in parts {
+                    let mut fields = corner.split('/');
+                    let position = obj_index(fields.next().unwrap_or_default(), positions.len(), line_number)?
+                        .ok_or_else(|| format!("line {}: face corner '{}' has no vertex", line_number, corner))?;
+                    let uv = obj_index(fields.next().unwrap_or_default(), uvs.len(), line_number)?;
+                    let normal = obj_index(fields.next().unwrap_or_default(), normals.len(), line_number)?;
+                    let index = *corners.entry((position, uv, normal)).or_insert_with(|| {
+                        mesh.vertices.push(positions[position]);
+                        if let Some(color) = colors[position] {
+                            mesh.colors.push(color);
+                        }
+                        if let Some(uv) = uv {
+                            mesh.uvs.push(uvs[uv]);
+                        }
+                        if let Some(normal) = normal {
+                            mesh.normals.push(normals[normal]);
+                        }
+                        mesh.vertices.len() as u32 - 1
+                    });
+                    polygon.push(index);
+                }
+                mesh.add_polygon(&polygon);
+            }
+            _ => {}
+        }
+    }
+
+    meshes.retain(|mesh| !mesh.indices.is_empty());
+    Ok(meshes)
+}
+
+/// Parse a binary or ASCII STL file
+pub fn parse_stl(bytes: &[u8]) -> Result<ImportedMesh, String> {
+    // Binary files can also start with "solid", so trust a matching triangle count first
+    let binary_count = bytes.get(80..84).map(|count| u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize);
+    if binary_count.is_some_and(|count| bytes.len() == 84 + count * 50) {
+        return parse_binary_stl(bytes);
+    }
+    let text = String::from_utf8_lossy(bytes);
+    if text.trim_start().starts_with("solid") {
+        parse_ascii_stl(&text)
+    } else {
+        Err("not an STL file".to_string())
+    }
+}
+
+fn parse_binary_stl(bytes: &[u8]) -> Result<ImportedMesh, String> {
+    let mut mesh = ImportedMesh::named("Mesh");
+    let mut welded = HashMap::new();
+    for facet in bytes[84..].chunks_exact(50) {
+        let float = |offset: usize| f32::from_le_bytes([facet[offset], facet[offset + 1], facet[offset + 2], facet[offset + 3]]);
+        // Skip the facet normal: normals are recomputed from the welded mesh
+        let polygon: Vec<u32> = (0..3)
+            .map(|corner| {
+                let offset = 12 + corner * 12;
+                weld(&mut mesh, &mut welded, Vec3::new(float(offset), float(offset + 4), float(offset + 8)))
+            })
+            .collect();
+        mesh.add_polygon(&polygon);
+    }
+    Ok(mesh)
+}
+
+fn parse_ascii_stl(text: &str) -> Result<ImportedMesh, String> {
+    let mut mesh = ImportedMesh::named("Mesh");
+    let mut welded = HashMap::new();
+    let mut polygon = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("solid") => {
+                mesh.name = parts.collect::<Vec<_>>().join("_");
+            }
+            Some("outer") => polygon.clear(),
+            Some("vertex") => {
+                let values = parse_floats(parts, number + 1)?;
+                if values.len() < 3 {
+                    return Err(format!("line {}: a vertex needs three coordinates", number + 1));
+                }
+                polygon.push(weld(&mut mesh, &mut welded, Vec3::new(values[0], values[1], values[2])));
+            }
+            Some("endloop") => mesh.add_polygon(&polygon),
+            _ => {}
+        }
+    }
+    Ok(mesh)
+}
+
+/// Index of the vertex at `position`, adding it if no earlier vertex is there
+fn weld(mesh: &mut ImportedMesh, welded: &mut HashMap<[u32; 3], u32>, position: Vec3) -> u32 {
+    *welded.entry(position.to_array().map(f32::to_bits)).or_insert_with(|| {
+        mesh.vertices.push(position);
+        mesh.vertices.len() as u32 - 1
+    })
+}
+
+/// Scalar types of PLY properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlyType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl PlyType {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "char" | "int8" => PlyType::I8,
+            "uchar" | "uint8" => PlyType::U8,
+            "short" | "int16" => PlyType::I16,
+            "ushort" | "uint16" => PlyType::U16,
+            "int" | "int32" => PlyType::I32,
+            "uint" | "uint32" => PlyType::U32,
+            "float" | "float32" => PlyType::F32,
+            "double" | "float64" => PlyType::F64,
+            _ => return None,
+        })
+    }
+
+    /// Size in bytes of a binary value
+    fn size(&self) -> usize {
+        match self {
+            PlyType::I8 | PlyType::U8 => 1,
+            PlyType::I16 | PlyType::U16 => 2,
+            PlyType::I32 | PlyType::U32 | PlyType::F32 => 4,
+            PlyType::F64 => 8,
+        }
+    }
+
+    /// Scale bringing integer color channels into 0..1
+    fn color_scale(&self) -> f64 {
+        match self {
+            PlyType::U8 => 1.0 / 255.0,
+            PlyType::U16 => 1.0 / 65535.0,
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PlyProperty {
+    name: String,
+    ty: PlyType,
+    /// Type of the item count for list properties
+    list_count: Option<PlyType>,
+}
+
+#[derive(Debug, Clone)]
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<PlyProperty>,
+}
+
+/// Reads property values from the body of a PLY file
+enum PlyBody<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary { data: &'a [u8], position: usize, big_endian: bool },
+}
+
+impl PlyBody<'_> {
+    /// The most items of `element` the rest of the body can hold, counting empty lists
+    fn capacity(&self, element: &PlyElement) -> usize {
+        match self {
+            PlyBody::Ascii(tokens) => tokens.clone().count() / element.properties.len().max(1),
+            PlyBody::Binary { data, position, .. } => {
+                let smallest_item: usize = element.properties.iter()
+                    .map(|property| property.list_count.unwrap_or(property.ty).size())
+                    .sum();
+                (data.len() - position) / smallest_item.max(1)
+            }
+        }
+    }
+
+    fn read(&mut self, ty: PlyType) -> Result<f64, String> {
+        match self {
+            PlyBody::Ascii(tokens) => {
+                let token = tokens.next().ok_or("unexpected end of data")?;
+                token.parse().map_err(|_| format!("'{}' is not a number", token))
+            }
+            PlyBody::Binary { data, position, big_endian } => {
+                fn bytes<const N: usize>(data: &[u8], position: &mut usize, big_endian: bool) -> Result<[u8; N], String> {
+                    let slice = data.get(*position..*position + N).ok_or("unexpected end of data")?;
+                    *position += N;
+                    let mut array: [u8; N] = slice.try_into().expect("slice has N bytes");
+                    if big_endian {
+                        array.reverse();
+                    }
+                    Ok(array)
+                }
+                let (data, big_endian) = (*data, *big_endian);
+                Ok(match ty {
+                    PlyType::I8 => i8::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::U8 => u8::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::I16 => i16::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::U16 => u16::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::I32 => i32::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::U32 => u32::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::F32 => f32::from_le_bytes(bytes(data, position, big_endian)?) as f64,
+                    PlyType::F64 => f64::from_le_bytes(bytes(data, position, big_endian)?),
+                })
+            }
+        }
+    }
+}
+
+/// Parse an ASCII or binary PLY file's `vertex` and `face` elements
+pub fn parse_ply(bytes: &[u8]) -> Result<ImportedMesh, String> {
+    if !bytes.starts_with(b"ply") {
+        return Err("not a PLY file".to_string());
+    }
+    let header_end = bytes.windows(10).position(|window| window == b"end_header").ok_or("missing end_header")?;
+    let body_start = bytes[header_end..].iter().position(|&byte| byte == b'\n').map(|offset| header_end + offset + 1).unwrap_or(bytes.len());
+    let header = String::from_utf8_lossy(&bytes[..header_end]);
+
+    let mut format = "";
+    let mut elements: Vec<PlyElement> = Vec::new();
+    for line in header.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        match parts.as_slice() {
+            ["format", name, ..] => format = *name,
+            ["element", name, count] => elements.push(PlyElement {
+                name: name.to_string(),
+                count: count.parse().map_err(|_| format!("bad element count '{}'", count))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, ty, name] => {
+                let property = PlyProperty {
+                    name: name.to_string(),
+                    ty: PlyType::from_name(ty).ok_or_else(|| format!("unknown property type '{}'", ty))?,
+                    list_count: Some(PlyType::from_name(count).ok_or_else(|| format!("unknown property type '{}'", count))?),
+                };
+                elements.last_mut().ok_or("property before any element")?.properties.push(property);
+            }
+            ["property", ty, name] => {
+                let property = PlyProperty {
+                    name: name.to_string(),
+                    ty: PlyType::from_name(ty).ok_or_else(|| format!("unknown property type '{}'", ty))?,
+                    list_count: None,
+                };
+                elements.last_mut().ok_or("property before any element")?.properties.push(property);
+            }
+            _ => {}
+        }
+    }
+
+    let body_text;
+    let mut body = match format {
+        "ascii" => {
+            body_text = String::from_utf8_lossy(&bytes[body_start..]);
+            PlyBody::Ascii(body_text.split_ascii_whitespace())
+        }
+        "binary_little_endian" => PlyBody::Binary { data: &bytes[body_start..], position: 0, big_endian: false },
+        "binary_big_endian" => PlyBody::Binary { data: &bytes[body_start..], position: 0, big_endian: true },
+        other => return Err(format!("unsupported PLY format '{}'", other)),
+    };
+
+    let mut mesh = ImportedMesh::named("Mesh");
+    for element in &elements {
+        if element.properties.is_empty() {
+            // Nothing to read, so the count says nothing about the body
+            continue;
+        }
+        let capacity = body.capacity(element);
+        if element.count > capacity {
+            return Err(format!("element '{}' lists {} items but the file can hold at most {}", element.name, element.count, capacity));
+        }
+        for _ in 0..element.count {
+            let mut values: HashMap<&str, (f64, PlyType)> = HashMap::new();
+            let mut polygon = Vec::new();
+            for property in &element.properties {
+                if let Some(count_type) = property.list_count {
+                    let count = body.read(count_type)? as usize;
+                    for _ in 0..count {
+                        polygon.push(body.read(property.ty)? as u32);
+                    }
+                } else {
+                    values.insert(property.name.as_str(), (body.read(property.ty)?, property.ty));
+                }
+            }
+            match element.name.as_str() {
+                "vertex" => {
+                    let get = |names: &[&str]| names.iter().find_map(|name| values.get(name)).map(|(value, ty)| (*value, *ty));
+                    let vector = |x: &[&str], y: &[&str], z: &[&str]| match (get(x), get(y), get(z)) {
+                        (Some((x, ty)), Some((y, _)), Some((z, _))) => Some((Vec3::new(x as f32, y as f32, z as f32), ty)),
+                        _ => None,
+                    };
+                    let (position, _) = vector(&["x"], &["y"], &["z"]).ok_or("vertex without x, y and z")?;
+                    mesh.vertices.push(position);
+                    if let Some((normal, _)) = vector(&["nx"], &["ny"], &["nz"]) {
+                        mesh.normals.push(normal);
+                    }
+                    if let Some((color, ty)) = vector(&["red", "r"], &["green", "g"], &["blue", "b"]) {
+                        mesh.colors.push(color * ty.color_scale() as f32);
+                    }
+                    if let (Some((u, _)), Some((v, _))) = (get(&["s", "u", "texture_u"]), get(&["t", "v", "texture_v"])) {
+                        mesh.uvs.push(Vec2::new(u as f32, v as f32));
+                    }
+                }
+                "face" if !polygon.is_empty() => mesh.add_polygon(&polygon),
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(index) = mesh.indices.iter().find(|&&index| index as usize >= mesh.vertices.len()) {
+        return Err(format!("face index {} is out of range", index));
+    }
+    Ok(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obj_groups_quads_and_negative_indices() {
+        let obj = "\
+o Quad
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+f 1/1 2/2 3/3 4/4
+g Tri
+v 0 0 1 1 0 0
+v 1 0 1 1 0 0
+v 0 1 1 1 0 0
+f -3 -2 -1
+";
+        let meshes = parse_obj(obj).unwrap();
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(meshes[0].name, "Quad");
+        assert_eq!(meshes[0].indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(meshes[0].uvs.len(), 4);
+        assert_eq!(meshes[1].name, "Tri");
+        assert_eq!(meshes[1].vertices[0], Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(meshes[1].colors, vec![Vec3::X; 3]);
+
+        let scene = build_scene(meshes, "shapes", "shapes.obj", UpAxis::Z);
+        assert_eq!(scene.up_axis, "Z");
+        assert_eq!(scene.meshes[0].prim_path, "/shapes/Quad");
+        assert_eq!(scene.meshes[1].normals.len(), scene.meshes[1].vertices.len());
+        assert!(parse_obj("v 0 0 0\nf 1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn test_stl_ascii_and_binary_are_welded() {
+        let ascii = "\
+solid part
+facet normal 0 0 1
+ outer loop
+  vertex 0 0 0
+  vertex 1 0 0
+  vertex 0 1 0
+ endloop
+endfacet
+facet normal 0 0 1
+ outer loop
+  vertex 1 0 0
+  vertex 1 1 0
+  vertex 0 1 0
+ endloop
+endfacet
+endsolid part
+";
+        let mesh = parse_stl(ascii.as_bytes()).unwrap();
+        assert_eq!(mesh.name, "part");
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.indices, vec![0, 1, 2, 1, 3, 2]);
+
+        let mut binary = vec![0u8; 80];
+        binary.extend_from_slice(&1u32.to_le_bytes());
+        for value in [0.0f32, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            binary.extend_from_slice(&value.to_le_bytes());
+        }
+        binary.extend_from_slice(&[0, 0]);
+        let mesh = parse_stl(&binary).unwrap();
+        assert_eq!(mesh.vertices, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_ply_ascii_and_binary_read_vertices_colors_and_faces() {
+        let header = "\
+ply
+format {format} 1.0
+comment made by hand
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+";
+        let ascii = header.replace("{format}", "ascii") + "\
+0 0 0 255 0 0
+1 0 0 255 0 0
+1 1 0 255 0 0
+0 1 0 255 0 0
+4 0 1 2 3
+";
+        let mesh = parse_ply(ascii.as_bytes()).unwrap();
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(mesh.colors[0], Vec3::X);
+
+        let mut binary = header.replace("{format}", "binary_big_endian").into_bytes();
+        for (x, y) in [(0.0f32, 0.0f32), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+            for value in [x, y, 0.0] {
+                binary.extend_from_slice(&value.to_be_bytes());
+            }
+            binary.extend_from_slice(&[0, 255, 0]);
+        }
+        binary.push(3);
+        for index in [0i32, 1, 2] {
+            binary.extend_from_slice(&index.to_be_bytes());
+        }
+        let mesh = parse_ply(&binary).unwrap();
+        assert_eq!(mesh.vertices[2], Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(mesh.colors[0], Vec3::Y);
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_ply_element_counts_beyond_the_data_are_rejected() {
+        let ascii = "ply\nformat ascii 1.0\nelement vertex 18446744073709551615\nproperty float x\nend_header\n0 1 2\n";
+        assert!(parse_ply(ascii.as_bytes()).unwrap_err().contains("at most 3"));
+
+        let mut binary = b"ply\nformat binary_little_endian 1.0\nelement empty 18446744073709551615\nelement face 1000000\nproperty list uchar int vertex_indices\nend_header\n".to_vec();
+        binary.extend_from_slice(&[0, 0]);
+        assert!(parse_ply(&binary).unwrap_err().contains("at most 2"));
+    }
+}
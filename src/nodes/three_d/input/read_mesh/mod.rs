@@ -0,0 +1,38 @@
+//! Read Mesh node module - importing OBJ, STL and PLY meshes as USD scene data
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Lightweight OBJ, STL and PLY parsers and scene conversion
+//! - parameters.rs: File selection interface
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadMeshLogic;
+pub use parameters::ReadMeshNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ReadMeshNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_ReadMesh",
+            "Read Mesh",
+            crate::nodes::NodeCategory::new(&["3D", "Input"]),
+            "Reads a simple .obj, .stl or .ply mesh, such as a scan or CAD export, into a USD scene"
+        )
+        .with_color(egui::Color32::from_rgb(100, 150, 200))
+        .with_icon("🗿")
+        .with_inputs(vec![
+            // No inputs - this is a scene source node
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Scene", crate::nodes::DataType::USDScene)
+                .with_description("USD scene data with the file's meshes"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "input", "obj", "stl", "ply", "file", "import", "read", "mesh", "scan", "cad"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
@@ -0,0 +1,71 @@
+//! Read Mesh node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
+use super::logic::{ReadMeshLogic, UpAxis, MESH_EXTENSIONS};
+
+/// Read Mesh node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ReadMeshNode;
+
+impl ReadMeshNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+
+        ui.heading("Read Mesh");
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("Mesh Files", MESH_EXTENSIONS)
+                .add_filter("All Files", &["*"])
+                .set_title("Select Mesh File")
+                .pick_file();
+            if let Some(file_path) = project_paths::path_browser(ui, &logic.file_path, "Select mesh file...", PathKind::Input(MESH_EXTENSIONS), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(file_path),
+                });
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Up Axis:");
+            let mut up_axis = logic.up_axis;
+            egui::ComboBox::from_id_salt("read_mesh_up_axis")
+                .selected_text(up_axis.name())
+                .show_ui(ui, |ui| {
+                    for axis in UpAxis::ALL {
+                        if ui.selectable_value(&mut up_axis, axis, axis.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "up_axis".to_string(),
+                                value: NodeData::String(axis.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        }).response.on_hover_text("CAD exports and many scans are Z-up");
+
+        changes
+    }
+
+    /// Build ReadMeshLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ReadMeshLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        ReadMeshLogic {
+            file_path: text("file_path"),
+            up_axis: UpAxis::from_name(&text("up_axis")).unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,231 @@
+//! Read Point Cloud node functional operations - parsing LAS and PLY point files
+//!
+//! LAS files store points as scaled integers after a fixed header; formats
+//! 2, 3, 5, 7, 8 and 10 add 16-bit RGB, and the rest fall back to the
+//! intensity as gray. PLY files reuse the Read Mesh parser and keep only its
+//! vertices. Survey data is usually Z-up and far from the origin, so the
+//! points can be turned Y-up and centered.
+
+use std::path::Path;
+use glam::Vec3;
+use crate::nodes::interface::{NodeData, PointCloudData};
+use crate::nodes::project_paths;
+use crate::nodes::three_d::input::read_mesh::logic::{parse_ply, UpAxis};
+
+/// File extensions the node reads
+pub const POINT_EXTENSIONS: &[&str] = &["las", "ply"];
+
+/// Core Read Point Cloud configuration
+#[derive(Debug, Clone)]
+pub struct ReadPointsLogic {
+    pub file_path: String,
+    pub up_axis: UpAxis,
+    /// Move the bounding box center to the origin
+    pub center: bool,
+}
+
+impl Default for ReadPointsLogic {
+    fn default() -> Self {
+        Self {
+            file_path: String::new(),
+            up_axis: UpAxis::Z,
+            center: true,
+        }
+    }
+}
+
+impl ReadPointsLogic {
+    /// Read the file into a point cloud; None when no file is set or it cannot be read
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+
+        match load_point_file(&project_paths::resolve_path(&self.file_path)) {
+            Ok(mut points) => {
+                self.orient(&mut points);
+                points.source = self.file_path.clone();
+                vec![NodeData::PointCloud(points)]
+            }
+            Err(e) => {
+                log::error!("Read Point Cloud: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+
+    /// Turn Z-up points Y-up and center them if requested
+    pub fn orient(&self, points: &mut PointCloudData) {
+        if self.up_axis == UpAxis::Z {
+            for position in &mut points.positions {
+                *position = Vec3::new(position.x, position.z, -position.y);
+            }
+        }
+        if self.center {
+            if let Some((min, max)) = points.bounds() {
+                let center = (min + max) * 0.5;
+                for position in &mut points.positions {
+                    *position -= center;
+                }
+            }
+        }
+    }
+}
+
+/// Load a .las or .ply file, picking the parser by extension
+pub fn load_point_file(path: &Path) -> Result<PointCloudData, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "las" => parse_las(&bytes),
+        "ply" => {
+            let mesh = parse_ply(&bytes)?;
+            let colors = if mesh.colors.len() == mesh.vertices.len() { mesh.colors } else { Vec::new() };
+            Ok(PointCloudData { source: String::new(), positions: mesh.vertices, colors })
+        }
+        other => Err(format!("unsupported point file extension '{}'", other)),
+    }
+}
+
+/// Byte offset of the RGB channels within a LAS point record of `format`
+fn las_rgb_offset(format: u8) -> Option<usize> {
+    match format {
+        2 => Some(20),
+        3 | 5 => Some(28),
+        7 | 8 | 10 => Some(30),
+        _ => None,
+    }
+}
+
+/// Parse an uncompressed LAS 1.0-1.4 file
+pub fn parse_las(bytes: &[u8]) -> Result<PointCloudData, String> {
+    if !bytes.starts_with(b"LASF") {
+        return Err("not a LAS file".to_string());
+    }
+    let read = |offset: usize, len: usize| bytes.get(offset..offset + len).ok_or("truncated LAS header");
+    let u16_at = |offset: usize| read(offset, 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+    let u32_at = |offset: usize| read(offset, 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let f64_at = |offset: usize| read(offset, 8).map(|b| f64::from_le_bytes(b.try_into().unwrap_or_default()));
+
+    let version_minor = *bytes.get(25).ok_or("truncated LAS header")?;
+    let point_offset = u32_at(96)? as usize;
+    // The top bits flag LAZ compression
+    let raw_format = *bytes.get(104).ok_or("truncated LAS header")?;
+    if raw_format & 0x80 != 0 {
+        return Err("compressed LAZ files are not supported".to_string());
+    }
+    let format = raw_format & 0x3f;
+    let record_length = u16_at(105)? as usize;
+    let mut count = u32_at(107)? as u64;
+    if count == 0 && version_minor >= 4 {
+        count = read(247, 8).map(|b| u64::from_le_bytes(b.try_into().unwrap_or_default()))?;
+    }
+    let scale = [f64_at(131)?, f64_at(139)?, f64_at(147)?];
+    let offset = [f64_at(155)?, f64_at(163)?, f64_at(171)?];
+    if record_length < 20 {
+        return Err(format!("point records of {} bytes are too short", record_length));
+    }
+
+    let rgb_offset = las_rgb_offset(format).filter(|rgb| rgb + 6 <= record_length);
+    let available = bytes.len().saturating_sub(point_offset) / record_length;
+    if count > available as u64 {
+        return Err(format!("truncated LAS file: the header lists {} points but only {} are present", count, available));
+    }
+    let count = count as usize;
+
+    let mut positions = Vec::with_capacity(count);
+    let mut raw_colors = Vec::with_capacity(count);
+    for index in 0..count {
+        let record = &bytes[point_offset + index * record_length..][..record_length];
+        let int = |at: usize| i32::from_le_bytes([record[at], record[at + 1], record[at + 2], record[at + 3]]) as f64;
+        let short = |at: usize| u16::from_le_bytes([record[at], record[at + 1]]) as f32;
+        positions.push(Vec3::new(
+            (int(0) * scale[0] + offset[0]) as f32,
+            (int(4) * scale[1] + offset[1]) as f32,
+            (int(8) * scale[2] + offset[2]) as f32,
+        ));
+        raw_colors.push(match rgb_offset {
+            Some(at) => Vec3::new(short(at), short(at + 2), short(at + 4)),
+            None => Vec3::splat(short(12)),
+        });
+    }
+
+    // Colors are nominally 16-bit but many writers store 8-bit values; intensity has no fixed range
+    let max = raw_colors.iter().fold(0.0f32, |max, color| max.max(color.max_element()));
+    let range = if rgb_offset.is_some() {
+        if max <= 255.0 { 255.0 } else { 65535.0 }
+    } else {
+        max
+    };
+    let colors = if range > 0.0 {
+        raw_colors.into_iter().map(|color| color / range).collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(PointCloudData { source: String::new(), positions, colors })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A LAS 1.2 file with format 2 (RGB) points
+    fn las_file(points: &[([i32; 3], [u16; 3])]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 227];
+        bytes[..4].copy_from_slice(b"LASF");
+        bytes[24] = 1;
+        bytes[25] = 2;
+        bytes[94..96].copy_from_slice(&227u16.to_le_bytes());
+        bytes[96..100].copy_from_slice(&227u32.to_le_bytes());
+        bytes[104] = 2;
+        bytes[105..107].copy_from_slice(&26u16.to_le_bytes());
+        bytes[107..111].copy_from_slice(&(points.len() as u32).to_le_bytes());
+        for (axis, scale) in [0.01f64, 0.01, 0.01].iter().enumerate() {
+            bytes[131 + axis * 8..139 + axis * 8].copy_from_slice(&scale.to_le_bytes());
+        }
+        bytes[155..163].copy_from_slice(&100.0f64.to_le_bytes());
+        for (position, color) in points {
+            let mut record = vec![0u8; 26];
+            for axis in 0..3 {
+                record[axis * 4..axis * 4 + 4].copy_from_slice(&position[axis].to_le_bytes());
+                record[20 + axis * 2..22 + axis * 2].copy_from_slice(&color[axis].to_le_bytes());
+            }
+            bytes.extend(record);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_las_points_are_scaled_offset_and_colored() {
+        let bytes = las_file(&[([100, 200, 300], [65535, 0, 0]), ([-100, 0, 50], [0, 32768, 65535])]);
+        let points = parse_las(&bytes).unwrap();
+
+        assert_eq!(points.point_count(), 2);
+        assert!((points.positions[0] - Vec3::new(101.0, 2.0, 3.0)).length() < 1e-4);
+        assert!((points.positions[1] - Vec3::new(99.0, 0.0, 0.5)).length() < 1e-4);
+        assert_eq!(points.colors[0], Vec3::new(1.0, 0.0, 0.0));
+        assert!((points.colors[1].y - 0.5).abs() < 1e-3);
+
+        assert!(parse_las(b"PLY not a las file").is_err());
+    }
+
+    #[test]
+    fn test_truncated_las_file_is_an_error() {
+        let bytes = las_file(&[([0, 0, 0], [0, 0, 0]), ([1, 1, 1], [0, 0, 0])]);
+        let error = parse_las(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert!(error.contains("truncated"), "{}", error);
+    }
+
+    #[test]
+    fn test_z_up_points_are_turned_and_centered() {
+        let logic = ReadPointsLogic::default();
+        let mut points = PointCloudData {
+            source: String::new(),
+            positions: vec![Vec3::new(10.0, 0.0, 0.0), Vec3::new(12.0, 0.0, 4.0)],
+            colors: Vec::new(),
+        };
+        logic.orient(&mut points);
+        assert_eq!(points.positions, vec![Vec3::new(-1.0, -2.0, 0.0), Vec3::new(1.0, 2.0, 0.0)]);
+    }
+}
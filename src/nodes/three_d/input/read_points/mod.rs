@@ -0,0 +1,38 @@
+//! Read Point Cloud node module - importing LAS and PLY point files
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: LAS parser, PLY vertex reading and orientation
+//! - parameters.rs: File selection interface
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadPointsLogic;
+pub use parameters::ReadPointsNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ReadPointsNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_ReadPoints",
+            "Read Point Cloud",
+            crate::nodes::NodeCategory::new(&["3D", "Input"]),
+            "Reads a .las or .ply point cloud, such as a lidar or photogrammetry scan"
+        )
+        .with_color(egui::Color32::from_rgb(100, 150, 200))
+        .with_icon("⁘")
+        .with_inputs(vec![
+            // No inputs - this is a point source node
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Points", crate::nodes::DataType::PointCloud)
+                .with_description("Point positions with their colors"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "input", "las", "ply", "lidar", "scan", "points", "point cloud", "file", "read"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
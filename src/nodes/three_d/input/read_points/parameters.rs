@@ -0,0 +1,86 @@
+//! Read Point Cloud node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
+use crate::nodes::three_d::input::read_mesh::logic::UpAxis;
+use super::logic::{ReadPointsLogic, POINT_EXTENSIONS};
+
+/// Read Point Cloud node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ReadPointsNode;
+
+impl ReadPointsNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+
+        ui.heading("Read Point Cloud");
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("Point Files", POINT_EXTENSIONS)
+                .add_filter("All Files", &["*"])
+                .set_title("Select Point Cloud File")
+                .pick_file();
+            if let Some(file_path) = project_paths::path_browser(ui, &logic.file_path, "Select point cloud file...", PathKind::Input(POINT_EXTENSIONS), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(file_path),
+                });
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Up Axis:");
+            let mut up_axis = logic.up_axis;
+            egui::ComboBox::from_id_salt("read_points_up_axis")
+                .selected_text(up_axis.name())
+                .show_ui(ui, |ui| {
+                    for axis in UpAxis::ALL {
+                        if ui.selectable_value(&mut up_axis, axis, axis.name()).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "up_axis".to_string(),
+                                value: NodeData::String(axis.name().to_string()),
+                            });
+                        }
+                    }
+                });
+        }).response.on_hover_text("Lidar surveys are Z-up; Z-up points are turned Y-up");
+
+        let mut center = logic.center;
+        if ui.checkbox(&mut center, "Center at Origin")
+            .on_hover_text("Surveys use georeferenced coordinates far from the origin")
+            .changed()
+        {
+            changes.push(ParameterChange {
+                parameter: "center".to_string(),
+                value: NodeData::Boolean(center),
+            });
+        }
+
+        changes
+    }
+
+    /// Build ReadPointsLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ReadPointsLogic {
+        let defaults = ReadPointsLogic::default();
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None });
+        ReadPointsLogic {
+            file_path: text("file_path").unwrap_or_default(),
+            up_axis: text("up_axis").and_then(|name| UpAxis::from_name(&name)).unwrap_or(defaults.up_axis),
+            center: node.parameters.get("center")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.center),
+        }
+    }
+
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,652 @@
+//! Read VDB node functional operations - parsing OpenVDB files
+//!
+//! Every grid's name, type, class, transform and active bounds come from the
+//! file header and grid metadata. Scalar grids (float and double, also when
+//! saved as half) with the standard 5-4-3 tree are decoded as well when they
+//! are stored uncompressed or zip-compressed, so the viewport can slice them.
+//! Blosc compression is not supported and such grids keep only their bounds;
+//! Hydra renders read the file themselves and see every grid either way.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+use glam::{DMat4, DVec3, IVec3, Mat4};
+use crate::nodes::interface::{NodeData, VolumeData, VolumeGrid, VolumeLeaf, VolumeTile, VolumeValues};
+use crate::nodes::project_paths;
+use crate::nodes::three_d::input::read_gltf::logic::{prim_name, unique_path};
+
+/// File extensions the node reads
+pub const VDB_EXTENSIONS: &[&str] = &["vdb"];
+
+/// " BDV" as the file's leading 64-bit integer
+const MAGIC: i64 = 0x5644_4220;
+/// First file version with per-grid and node mask compression
+const MIN_VERSION: u32 = 222;
+
+const COMPRESS_ZIP: u32 = 0x1;
+const COMPRESS_ACTIVE_MASK: u32 = 0x2;
+const COMPRESS_BLOSC: u32 = 0x4;
+
+/// Separates a grid's name from the suffix that keeps it unique in the file
+const NAME_SUFFIX_SEPARATOR: char = '\u{1e}';
+
+/// Core Read VDB configuration
+#[derive(Debug, Clone, Default)]
+pub struct ReadVdbLogic {
+    pub file_path: String,
+}
+
+impl ReadVdbLogic {
+    /// Read the file's grids; None when no file is set or it cannot be read
+    pub fn process(&self, _inputs: Vec<NodeData>) -> Vec<NodeData> {
+        if self.file_path.is_empty() {
+            return vec![NodeData::None];
+        }
+
+        let path = project_paths::resolve_path(&self.file_path);
+        match std::fs::read(&path).map_err(|e| e.to_string()).and_then(|bytes| parse_vdb(&bytes)) {
+            Ok(grids) => vec![NodeData::Volume(VolumeData {
+                source: path.to_string_lossy().to_string(),
+                grids,
+            })],
+            Err(e) => {
+                log::error!("Read VDB: failed to read '{}': {}", self.file_path, e);
+                vec![NodeData::None]
+            }
+        }
+    }
+}
+
+/// A USD layer with a Volume prim reading `volume`'s grids from its file, for Hydra renders
+pub fn volume_layer(volume: &VolumeData) -> String {
+    let stem = Path::new(&volume.source).file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    let prim = prim_name(&stem, "Volume");
+    let mut taken = HashSet::new();
+    let mut relationships = String::new();
+    let mut fields = String::new();
+    for grid in &volume.grids {
+        let field = unique_path(&prim_name(&grid.name, "field"), &mut taken);
+        relationships.push_str(&format!("    rel field:{} = </{}/{}>\n", field, prim, field));
+        fields.push_str(&format!(
+            "\n    def OpenVDBAsset \"{}\"\n    {{\n        asset filePath = @{}@\n        token fieldName = {:?}\n    }}\n",
+            field, volume.source, grid.name
+        ));
+    }
+    format!("#usda 1.0\n(\n    upAxis = \"Y\"\n)\n\ndef Volume \"{}\"\n{{\n{}{}}}\n", prim, relationships, fields)
+}
+
+/// Parse the grids of an OpenVDB file
+pub fn parse_vdb(bytes: &[u8]) -> Result<Vec<VolumeGrid>, String> {
+    let mut reader = Reader { bytes, pos: 0 };
+    if reader.i64()? != MAGIC {
+        return Err("not an OpenVDB file".to_string());
+    }
+    let version = reader.u32()?;
+    if version < MIN_VERSION {
+        return Err(format!("file format version {} is too old", version));
+    }
+    // Library major and minor version
+    reader.take(8)?;
+    let has_grid_offsets = reader.u8()? != 0;
+    // UUID
+    reader.take(36)?;
+    read_metadata(&mut reader)?;
+    if !has_grid_offsets {
+        return Err("files without grid offsets are not supported".to_string());
+    }
+
+    let grid_count = reader.i32()?.max(0);
+    let mut grids: Vec<VolumeGrid> = Vec::new();
+    let mut unique_names = Vec::new();
+    // Instance grids share the tree of the grid they name
+    let mut instances = Vec::new();
+    for _ in 0..grid_count {
+        let unique_name = reader.string()?;
+        let grid_type = reader.string()?;
+        let parent = reader.string()?;
+        let grid_pos = reader.i64()?;
+        let _block_pos = reader.i64()?;
+        let end_pos = reader.i64()?;
+
+        reader.seek(grid_pos)?;
+        let name = unique_name.split(NAME_SUFFIX_SEPARATOR).next().unwrap_or_default().to_string();
+        let (half, tree_type) = match grid_type.strip_suffix("_HalfFloat") {
+            Some(tree_type) => (true, tree_type),
+            None => (false, grid_type.as_str()),
+        };
+        let grid = read_grid(&mut reader, name, tree_type, half, parent.is_empty())?;
+        if !parent.is_empty() {
+            instances.push((grids.len(), parent));
+        }
+        grids.push(grid);
+        unique_names.push(unique_name);
+        reader.seek(end_pos)?;
+    }
+
+    for (index, parent) in instances {
+        if let Some(parent) = unique_names.iter().position(|name| *name == parent) {
+            grids[index].values = grids[parent].values.clone();
+        }
+    }
+    Ok(grids)
+}
+
+/// Compression, metadata, transform and, for supported scalar types, the tree of one grid
+fn read_grid(reader: &mut Reader, name: String, tree_type: &str, half: bool, has_tree: bool) -> Result<VolumeGrid, String> {
+    let compression = reader.u32()?;
+    let metadata = read_metadata(reader)?;
+    let transform = read_transform(reader)?;
+
+    let value_type = tree_type.strip_prefix("Tree_")
+        .and_then(|rest| rest.split('_').next())
+        .unwrap_or(tree_type)
+        .to_string();
+    let vec3i = |key: &str| metadata.get(key)
+        .filter(|(_, value)| value.len() == 12)
+        .map(|(_, value)| std::array::from_fn(|axis| i32::from_le_bytes([value[axis * 4], value[axis * 4 + 1], value[axis * 4 + 2], value[axis * 4 + 3]])));
+    let index_bounds: Option<([i32; 3], [i32; 3])> = vec3i("file_bbox_min").zip(vec3i("file_bbox_max"));
+    let mut grid = VolumeGrid {
+        name,
+        value_type,
+        grid_class: metadata.get("class")
+            .map(|(_, value)| String::from_utf8_lossy(value).to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        index_min: index_bounds.map(|(min, _)| min).unwrap_or_default(),
+        index_max: index_bounds.map(|(_, max)| max).unwrap_or_default(),
+        active_voxels: metadata.get("file_voxel_count")
+            .filter(|(_, value)| value.len() == 8)
+            .map(|(_, value)| i64::from_le_bytes(std::array::from_fn(|i| value[i])).max(0) as u64)
+            .unwrap_or_default(),
+        transform,
+        values: None,
+    };
+
+    let value_size = match tree_type {
+        "Tree_float_5_4_3" => 4,
+        "Tree_double_5_4_3" => 8,
+        _ => return Ok(grid),
+    };
+    if !has_tree {
+        return Ok(grid);
+    }
+    let format = ValueFormat { compression, half, value_size };
+    match TreeReader::read(reader, &format) {
+        Ok(tree) => {
+            if index_bounds.is_none() {
+                if let Some((min, max)) = tree.active_bounds {
+                    grid.index_min = min.to_array();
+                    grid.index_max = max.to_array();
+                }
+            }
+            if !metadata.contains_key("file_voxel_count") {
+                grid.active_voxels = tree.active_voxels;
+            }
+            grid.values = Some(tree.values);
+        }
+        Err(e) => log::warn!("Read VDB: voxels of grid '{}' not loaded: {}", grid.name, e),
+    }
+    Ok(grid)
+}
+
+/// Metadata entries by name: type name and raw value
+fn read_metadata(reader: &mut Reader) -> Result<HashMap<String, (String, Vec<u8>)>, String> {
+    let count = reader.u32()?;
+    let mut metadata = HashMap::new();
+    for _ in 0..count {
+        let name = reader.string()?;
+        let type_name = reader.string()?;
+        let size = reader.u32()? as usize;
+        metadata.insert(name, (type_name, reader.take(size)?.to_vec()));
+    }
+    Ok(metadata)
+}
+
+/// The grid's map from index to world space
+fn read_transform(reader: &mut Reader) -> Result<Mat4, String> {
+    let map_type = reader.string()?;
+    let (translation, scale) = match map_type.as_str() {
+        "ScaleMap" | "UniformScaleMap" => {
+            let scale = reader.dvec3()?;
+            // Voxel size and inverse scales derived from it
+            reader.take(4 * 24)?;
+            (DVec3::ZERO, scale)
+        }
+        "ScaleTranslateMap" | "UniformScaleTranslateMap" => {
+            let translation = reader.dvec3()?;
+            let scale = reader.dvec3()?;
+            reader.take(4 * 24)?;
+            (translation, scale)
+        }
+        "TranslationMap" => (reader.dvec3()?, DVec3::ONE),
+        "AffineMap" => {
+            // Row-major for row vectors, which is glam's column-major layout for column vectors
+            let mut matrix = [0.0; 16];
+            for value in &mut matrix {
+                *value = reader.f64()?;
+            }
+            return Ok(DMat4::from_cols_array(&matrix).as_mat4());
+        }
+        other => return Err(format!("unsupported transform '{}'", other)),
+    };
+    Ok((DMat4::from_translation(translation) * DMat4::from_scale(scale)).as_mat4())
+}
+
+/// How a grid stores its values
+struct ValueFormat {
+    compression: u32,
+    /// Values are 16-bit floats; backgrounds and inactive values stay full size
+    half: bool,
+    /// Bytes of one full-size value
+    value_size: usize,
+}
+
+impl ValueFormat {
+    fn value(&self, reader: &mut Reader) -> Result<f32, String> {
+        if self.value_size == 8 {
+            Ok(reader.f64()? as f32)
+        } else {
+            reader.f32()
+        }
+    }
+
+    /// A node's `count` values, restoring the inactive ones mask compression left out
+    fn read_values(&self, reader: &mut Reader, count: usize, value_mask: &[u64], background: f32) -> Result<Vec<f32>, String> {
+        let metadata = reader.u8()?;
+        let mut inactive = [if metadata == 0 { background } else { -background }, background];
+        if matches!(metadata, 2 | 4 | 5) {
+            inactive[0] = self.value(reader)?;
+            if metadata == 5 {
+                inactive[1] = self.value(reader)?;
+            }
+        }
+        // Picks between the two inactive values
+        let selection = if matches!(metadata, 3..=5) { Some(reader.mask(count)?) } else { None };
+
+        let stored = if self.compression & COMPRESS_ACTIVE_MASK != 0 && metadata != 6 {
+            count_on(value_mask)
+        } else {
+            count
+        };
+        let element = if self.half { 2 } else { self.value_size };
+        let len = stored.checked_mul(element).ok_or("value count out of range")?;
+        let data = self.read_data(reader, len)?;
+        let values: Vec<f32> = data.chunks_exact(element).map(|bytes| match element {
+            2 => half_to_f32(u16::from_le_bytes([bytes[0], bytes[1]])),
+            4 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            _ => f64::from_le_bytes(std::array::from_fn(|i| bytes[i])) as f32,
+        }).collect();
+        if stored == count {
+            return Ok(values);
+        }
+
+        let mut active = values.into_iter();
+        Ok((0..count).map(|index| {
+            if is_on(value_mask, index) {
+                active.next().unwrap_or(background)
+            } else {
+                inactive[selection.as_ref().map_or(0, |selection| is_on(selection, index) as usize)]
+            }
+        }).collect())
+    }
+
+    /// `len` bytes, inflated if the grid is zip-compressed; anything else is an error
+    fn read_data(&self, reader: &mut Reader, len: usize) -> Result<Vec<u8>, String> {
+        let data = if self.compression & (COMPRESS_ZIP | COMPRESS_BLOSC) == 0 {
+            reader.take(len)?.to_vec()
+        } else {
+            // A negative size marks data stored uncompressed because compressing didn't shrink it
+            let stored = reader.i64()?;
+            let stored_len = usize::try_from(stored.unsigned_abs()).map_err(|_| "value size out of range")?;
+            let bytes = reader.take(stored_len)?;
+            if stored <= 0 {
+                bytes.to_vec()
+            } else if self.compression & COMPRESS_BLOSC != 0 {
+                return Err("Blosc compression is not supported".to_string());
+            } else {
+                let mut data = Vec::with_capacity(len);
+                flate2::read::ZlibDecoder::new(bytes).take(len as u64 + 1).read_to_end(&mut data).map_err(|e| e.to_string())?;
+                data
+            }
+        };
+        if data.len() != len {
+            return Err(format!("expected {} bytes of values, found {}", len, data.len()));
+        }
+        Ok(data)
+    }
+}
+
+/// Reads a 5-4-3 tree: the topology of every node, then the values of the leaves
+struct TreeReader {
+    values: VolumeValues,
+    active_voxels: u64,
+    active_bounds: Option<(IVec3, IVec3)>,
+}
+
+impl TreeReader {
+    fn read(reader: &mut Reader, format: &ValueFormat) -> Result<Self, String> {
+        let _buffer_count = reader.i32()?;
+        let background = format.value(reader)?;
+        let mut tree = TreeReader {
+            values: VolumeValues { background, leaves: Vec::new(), tiles: Vec::new() },
+            active_voxels: 0,
+            active_bounds: None,
+        };
+
+        let tile_count = reader.u32()?;
+        let child_count = reader.u32()?;
+        for _ in 0..tile_count {
+            let origin = reader.ivec3()?;
+            let value = format.value(reader)?;
+            if reader.u8()? != 0 {
+                tree.add_tile(VolumeTile { origin, size: 1 << 12, value });
+            }
+        }
+        for _ in 0..child_count {
+            let origin = reader.ivec3()?;
+            tree.read_internal(reader, format, origin, 5)?;
+        }
+
+        // Leaf buffers follow in the order the topology listed the leaves
+        for index in 0..tree.values.leaves.len() {
+            let mask = reader.mask(512)?;
+            let values = format.read_values(reader, 512, &mask, background)?;
+            let origin = IVec3::from(tree.values.leaves[index].origin);
+            for voxel in (0..512).filter(|&voxel| is_on(&mask, voxel)) {
+                let ijk = origin + IVec3::new((voxel >> 6) as i32, ((voxel >> 3) & 7) as i32, (voxel & 7) as i32);
+                tree.grow_bounds(ijk, ijk);
+                tree.active_voxels += 1;
+            }
+            tree.values.leaves[index].values = values;
+        }
+        Ok(tree)
+    }
+
+    /// Topology of an internal node with `log2dim` children per axis: masks, tile values, then children
+    fn read_internal(&mut self, reader: &mut Reader, format: &ValueFormat, origin: [i32; 3], log2dim: u32) -> Result<(), String> {
+        // Log2 of a child's edge length in voxels
+        let child_log2 = if log2dim == 5 { 7 } else { 3 };
+        let count = 1usize << (3 * log2dim);
+        let child_mask = reader.mask(count)?;
+        let value_mask = reader.mask(count)?;
+        let values = format.read_values(reader, count, &value_mask, self.values.background)?;
+
+        let axis_mask = (1 << log2dim) - 1;
+        for index in 0..count {
+            let local = [index >> (2 * log2dim), (index >> log2dim) & axis_mask, index & axis_mask];
+            let child_origin: [i32; 3] = std::array::from_fn(|axis| origin[axis] + ((local[axis] as i32) << child_log2));
+            if is_on(&child_mask, index) {
+                if log2dim == 5 {
+                    self.read_internal(reader, format, child_origin, 4)?;
+                } else {
+                    // The leaf's value mask is read again with its values
+                    reader.mask(512)?;
+                    self.values.leaves.push(VolumeLeaf { origin: child_origin, values: Vec::new() });
+                }
+            } else if is_on(&value_mask, index) {
+                let value = *values.get(index).ok_or("missing tile value")?;
+                self.add_tile(VolumeTile { origin: child_origin, size: 1 << child_log2, value });
+            }
+        }
+        Ok(())
+    }
+
+    fn add_tile(&mut self, tile: VolumeTile) {
+        let min = IVec3::from(tile.origin);
+        self.grow_bounds(min, min + IVec3::splat(tile.size - 1));
+        self.active_voxels += (tile.size as u64).pow(3);
+        self.values.tiles.push(tile);
+    }
+
+    fn grow_bounds(&mut self, min: IVec3, max: IVec3) {
+        self.active_bounds = Some(match self.active_bounds {
+            Some((low, high)) => (low.min(min), high.max(max)),
+            None => (min, max),
+        });
+    }
+}
+
+/// Little-endian reads from the file's bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or("unexpected end of file")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+
+    fn seek(&mut self, pos: i64) -> Result<(), String> {
+        if pos < 0 || pos as usize > self.bytes.len() {
+            return Err(format!("offset {} is outside the file", pos));
+        }
+        self.pos = pos as usize;
+        Ok(())
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.bytes()?))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.bytes()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_le_bytes(self.bytes()?))
+    }
+
+    fn f64(&mut self) -> Result<f64, String> {
+        Ok(f64::from_le_bytes(self.bytes()?))
+    }
+
+    fn ivec3(&mut self) -> Result<[i32; 3], String> {
+        Ok([self.i32()?, self.i32()?, self.i32()?])
+    }
+
+    fn dvec3(&mut self) -> Result<DVec3, String> {
+        Ok(DVec3::new(self.f64()?, self.f64()?, self.f64()?))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).to_string())
+    }
+
+    /// A node mask of `bits` bits as 64-bit words
+    fn mask(&mut self, bits: usize) -> Result<Vec<u64>, String> {
+        (0..bits / 64).map(|_| Ok(u64::from_le_bytes(self.bytes()?))).collect()
+    }
+}
+
+fn is_on(mask: &[u64], index: usize) -> bool {
+    mask.get(index >> 6).is_some_and(|word| (word >> (index & 63)) & 1 == 1)
+}
+
+fn count_on(mask: &[u64]) -> usize {
+    mask.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// IEEE 754 half precision to single
+fn half_to_f32(bits: u16) -> f32 {
+    let exponent = (bits >> 10) & 0x1f;
+    let mantissa = (bits & 0x3ff) as u32;
+    let magnitude = match exponent {
+        0 => mantissa as f32 * 2f32.powi(-24),
+        31 if mantissa == 0 => f32::INFINITY,
+        31 => f32::NAN,
+        _ => f32::from_bits(((exponent as u32 + 112) << 23) | (mantissa << 13)),
+    };
+    if bits & 0x8000 != 0 { -magnitude } else { magnitude }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+    use std::io::Write;
+
+    fn string(out: &mut Vec<u8>, text: &str) {
+        out.extend((text.len() as u32).to_le_bytes());
+        out.extend(text.as_bytes());
+    }
+
+    /// A file with one float grid holding two active voxels in one leaf at the origin
+    fn vdb_file(compression: u32) -> Vec<u8> {
+        let data = |out: &mut Vec<u8>, bytes: &[u8]| {
+            if compression & COMPRESS_ZIP == 0 {
+                out.extend(bytes);
+            } else {
+                let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(bytes).unwrap();
+                let zipped = encoder.finish().unwrap();
+                out.extend((zipped.len() as i64).to_le_bytes());
+                out.extend(zipped);
+            }
+        };
+        // Mask compression with every inactive value the background, so only active values are stored
+        let values = |out: &mut Vec<u8>, active: &[f32]| {
+            out.push(0);
+            let bytes: Vec<u8> = active.iter().flat_map(|value| value.to_le_bytes()).collect();
+            data(out, &bytes);
+        };
+        let mask = |out: &mut Vec<u8>, bits: usize, on: &[usize]| {
+            let mut words = vec![0u64; bits / 64];
+            for &index in on {
+                words[index >> 6] |= 1 << (index & 63);
+            }
+            out.extend(words.iter().flat_map(|word| word.to_le_bytes()));
+        };
+        let leaf_voxels = [0, (1 << 6) | (2 << 3) | 3];
+
+        let mut out = Vec::new();
+        out.extend(MAGIC.to_le_bytes());
+        out.extend(224u32.to_le_bytes());
+        out.extend(9u32.to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.push(1);
+        out.extend([b'0'; 36]);
+        out.extend(0u32.to_le_bytes());
+        out.extend(1i32.to_le_bytes());
+
+        string(&mut out, "density");
+        string(&mut out, "Tree_float_5_4_3");
+        string(&mut out, "");
+        let grid_pos = out.len() as i64 + 24;
+        out.extend(grid_pos.to_le_bytes());
+        out.extend(0i64.to_le_bytes());
+        let end_pos_at = out.len();
+        out.extend(0i64.to_le_bytes());
+
+        out.extend(compression.to_le_bytes());
+        out.extend(1u32.to_le_bytes());
+        string(&mut out, "class");
+        string(&mut out, "string");
+        string(&mut out, "fog volume");
+        string(&mut out, "UniformScaleTranslateMap");
+        for vector in [[1.0f64, 2.0, 3.0], [0.5; 3], [0.5; 3], [2.0; 3], [4.0; 3], [1.0; 3]] {
+            out.extend(vector.iter().flat_map(|value| value.to_le_bytes()));
+        }
+
+        out.extend(1i32.to_le_bytes());
+        out.extend(0f32.to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.extend(1u32.to_le_bytes());
+        out.extend([0u8; 12]);
+        mask(&mut out, 32768, &[0]);
+        mask(&mut out, 32768, &[]);
+        values(&mut out, &[]);
+        mask(&mut out, 4096, &[0]);
+        mask(&mut out, 4096, &[]);
+        values(&mut out, &[]);
+        mask(&mut out, 512, &leaf_voxels);
+
+        mask(&mut out, 512, &leaf_voxels);
+        values(&mut out, &[1.0, 0.25]);
+
+        let end_pos = out.len() as i64;
+        out[end_pos_at..end_pos_at + 8].copy_from_slice(&end_pos.to_le_bytes());
+        out
+    }
+
+    #[test]
+    fn test_vdb_grids_are_described_and_decoded() {
+        for compression in [COMPRESS_ACTIVE_MASK, COMPRESS_ACTIVE_MASK | COMPRESS_ZIP] {
+            let grids = parse_vdb(&vdb_file(compression)).unwrap();
+            assert_eq!(grids.len(), 1);
+            let grid = &grids[0];
+            assert_eq!(grid.name, "density");
+            assert_eq!(grid.value_type, "float");
+            assert_eq!(grid.grid_class, "fog volume");
+            assert_eq!(grid.active_voxels, 2);
+            assert_eq!((grid.index_min, grid.index_max), ([0, 0, 0], [1, 2, 3]));
+            assert_eq!(grid.voxel_size(), Vec3::splat(0.5));
+            assert_eq!(grid.transform.transform_point3(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(1.5, 3.0, 4.5));
+
+            let values = grid.values.as_ref().unwrap();
+            let sampler = values.sampler();
+            assert_eq!(sampler.value([0, 0, 0]), 1.0);
+            assert_eq!(sampler.value([1, 2, 3]), 0.25);
+            assert_eq!(sampler.value([1, 1, 1]), 0.0);
+            assert_eq!(sampler.value([100, 0, 0]), 0.0);
+        }
+        assert!(parse_vdb(b"not a vdb file").is_err());
+    }
+
+    #[test]
+    fn test_truncated_and_mis_sized_values_are_errors() {
+        // Cut anywhere, a file either fails to parse or leaves the grid without values
+        for compression in [COMPRESS_ACTIVE_MASK, COMPRESS_ACTIVE_MASK | COMPRESS_ZIP] {
+            let file = vdb_file(compression);
+            for len in 0..file.len() {
+                if let Ok(grids) = parse_vdb(&file[..len]) {
+                    assert!(grids.iter().all(|grid| grid.values.is_none()), "cut at {}", len);
+                }
+            }
+        }
+
+        // Sizes that disagree with the values expected
+        let zip = ValueFormat { compression: COMPRESS_ZIP, half: false, value_size: 4 };
+        let uncompressed: Vec<u8> = [(-4i64).to_le_bytes().as_slice(), &[0; 4]].concat();
+        assert!(zip.read_data(&mut Reader { bytes: &uncompressed, pos: 0 }, 8).is_err());
+        assert_eq!(zip.read_data(&mut Reader { bytes: &uncompressed, pos: 0 }, 4).unwrap(), vec![0; 4]);
+        let plain = ValueFormat { compression: 0, half: false, value_size: 4 };
+        assert!(plain.read_values(&mut Reader { bytes: &[0, 0, 0, 0, 0], pos: 0 }, 2, &[0b11], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_half_floats_and_volume_layer() {
+        assert_eq!(half_to_f32(0x3c00), 1.0);
+        assert_eq!(half_to_f32(0xc000), -2.0);
+        assert_eq!(half_to_f32(0x3555), 0.333_251_95);
+
+        let volume = VolumeData {
+            source: "/shots/smoke.vdb".to_string(),
+            grids: vec![VolumeGrid { name: "density".to_string(), ..Default::default() }],
+        };
+        let layer = volume_layer(&volume);
+        assert!(layer.contains("def Volume \"smoke\""));
+        assert!(layer.contains("rel field:density = </smoke/density>"));
+        assert!(layer.contains("asset filePath = @/shots/smoke.vdb@"));
+        assert!(layer.contains("token fieldName = \"density\""));
+    }
+}
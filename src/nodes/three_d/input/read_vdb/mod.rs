@@ -0,0 +1,38 @@
+//! Read VDB node module - loading OpenVDB volumes
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: OpenVDB header, metadata and tree parsing, and the USD layer for Hydra
+//! - parameters.rs: File selection interface
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ReadVdbLogic;
+pub use parameters::ReadVdbNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ReadVdbNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_ReadVDB",
+            "Read VDB",
+            crate::nodes::NodeCategory::new(&["3D", "Input"]),
+            "Reads the grids of an OpenVDB volume, such as smoke or clouds, for inspection and rendering"
+        )
+        .with_color(egui::Color32::from_rgb(100, 150, 200))
+        .with_icon("☁")
+        .with_inputs(vec![
+            // No inputs - this is a volume source node
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Volume", crate::nodes::DataType::Volume)
+                .with_description("The file's grids with their bounds and, where readable, voxels"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "input", "vdb", "openvdb", "volume", "voxel", "smoke", "fog", "file", "read"])
+        .with_subtitle(|node| crate::nodes::factory::file_name_subtitle(node, "file_path"))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
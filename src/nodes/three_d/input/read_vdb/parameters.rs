@@ -0,0 +1,52 @@
+//! Read VDB node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use crate::nodes::project_paths::{self, PathKind};
+use super::logic::{ReadVdbLogic, VDB_EXTENSIONS};
+
+/// Read VDB node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ReadVdbNode;
+
+impl ReadVdbNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+
+        ui.heading("Read VDB");
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            let browse = || rfd::FileDialog::new()
+                .add_filter("OpenVDB Files", VDB_EXTENSIONS)
+                .add_filter("All Files", &["*"])
+                .set_title("Select VDB File")
+                .pick_file();
+            if let Some(file_path) = project_paths::path_browser(ui, &logic.file_path, "Select VDB file...", PathKind::Input(VDB_EXTENSIONS), browse) {
+                changes.push(ParameterChange {
+                    parameter: "file_path".to_string(),
+                    value: NodeData::String(file_path),
+                });
+            }
+        });
+
+        changes
+    }
+
+    /// Build ReadVdbLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ReadVdbLogic {
+        ReadVdbLogic {
+            file_path: node.parameters.get("file_path")
+                .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -1,33 +1,45 @@
-//! Directional light node module - enhanced NodeFactory system
+//! Directional light node module - UsdLux DistantLight
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Building the light's shape inputs
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
 
 pub mod logic;
 pub mod parameters;
 
-// logic::DirectionalLightLogic removed - only used internally
 pub use parameters::DirectionalLightNode;
 
 use crate::nodes::NodeFactory;
+use super::usd_light;
 
 impl NodeFactory for parameters::DirectionalLightNode {
     fn metadata() -> crate::nodes::NodeMetadata {
+        let mut schema = usd_light::light_parameters(parameters::DEFAULT_INTENSITY);
+        schema.extend(vec![
+            crate::nodes::ParameterDefinition::float("angle", "Angle", 0.53)
+                .with_range(0.0, 180.0)
+                .with_step(0.01)
+                .with_tooltip("Angular diameter of the source in degrees; larger angles give softer shadows")
+                .in_group("Shape"),
+        ]);
         crate::nodes::NodeMetadata::new(
             "3D_DirectionalLight",
             "Directional Light",
             crate::nodes::NodeCategory::new(&["3D", "Lighting"]),
-            "Creates a directional light with interface panel controls"
+            "Adds a UsdLux distant light, parallel rays like the sun, to a USD scene"
         )
         .with_color(egui::Color32::from_rgb(255, 255, 150))
         .with_icon("☀️")
-        .with_inputs(vec![
-            crate::nodes::PortDefinition::optional("Transform", crate::nodes::DataType::Any)
-                .with_description("Optional transform matrix to position the light"),
-        ])
-        .with_outputs(vec![
-            crate::nodes::PortDefinition::required("Light", crate::nodes::DataType::Any)
-                .with_description("Light output for scene"),
-        ])
-        .with_tags(vec!["3d", "lighting", "directional", "sun", "parallel", "interface"])
-        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_inputs(usd_light::light_inputs())
+        .with_outputs(usd_light::light_outputs())
+        .with_parameters(schema)
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "lighting", "usd", "usdlux", "distant", "sun", "directional", "parallel"])
+        .with_subtitle(|node| {
+            let logic = parameters::DirectionalLightNode::create_logic(node);
+            Some(format!("{:.2}° · {:.1}", logic.angle, logic.common.intensity))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
         .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,23 @@
+//! Dome Light node functional operations - light from a sphere around the whole scene
+
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::lighting::usd_light::{asset_attribute, LightCommon};
+
+/// Core Dome Light configuration
+#[derive(Debug, Clone)]
+pub struct DomeLightLogic {
+    pub common: LightCommon,
+    pub texture_file: String,
+}
+
+impl DomeLightLogic {
+    /// Add the light to the incoming USD scene, or output it in a new one
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut attributes = Vec::new();
+        if !self.texture_file.trim().is_empty() {
+            attributes.push(asset_attribute("inputs:texture:file", self.texture_file.trim()));
+        }
+        self.common.process(node_id, "DomeLight", attributes, inputs)
+    }
+}
@@ -0,0 +1,44 @@
+//! Dome Light node module - UsdLux DomeLight
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Building the light's shape inputs
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use parameters::DomeLightNode;
+
+use crate::nodes::NodeFactory;
+use super::usd_light;
+
+impl NodeFactory for parameters::DomeLightNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        let mut schema = usd_light::light_parameters(parameters::DEFAULT_INTENSITY);
+        schema.extend(vec![
+            crate::nodes::ParameterDefinition::string("texture_file", "Texture", "")
+                .with_tooltip("Latitude-longitude environment map (.hdr, .exr); empty lights the dome with the color alone")
+                .in_group("Environment"),
+        ]);
+        crate::nodes::NodeMetadata::new(
+            "3D_DomeLight",
+            "Dome Light",
+            crate::nodes::NodeCategory::new(&["3D", "Lighting"]),
+            "Adds a UsdLux dome light, lighting the scene from every direction, optionally from an environment map"
+        )
+        .with_color(egui::Color32::from_rgb(150, 200, 255))
+        .with_icon("🌐")
+        .with_inputs(usd_light::light_inputs())
+        .with_outputs(usd_light::light_outputs())
+        .with_parameters(schema)
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "lighting", "usd", "usdlux", "dome", "environment", "hdri", "ibl", "sky"])
+        .with_subtitle(|node| {
+            let logic = parameters::DomeLightNode::create_logic(node);
+            std::path::Path::new(logic.texture_file.trim()).file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
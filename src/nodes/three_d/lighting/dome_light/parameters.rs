@@ -0,0 +1,30 @@
+//! Dome Light node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::lighting::usd_light::LightCommon;
+use super::logic::DomeLightLogic;
+
+/// Default intensity of new Dome Light nodes
+pub const DEFAULT_INTENSITY: f32 = 1.0;
+
+/// Dome Light node
+#[derive(Debug, Clone, Default)]
+pub struct DomeLightNode;
+
+impl DomeLightNode {
+    /// Build DomeLightLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> DomeLightLogic {
+        DomeLightLogic {
+            common: LightCommon::from_node(node, DEFAULT_INTENSITY),
+            texture_file: node.parameters.get("texture_file")
+                .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
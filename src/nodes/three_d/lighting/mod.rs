@@ -1,9 +1,14 @@
 //! 3D Lighting nodes
 
+pub mod usd_light;          // Shared by the UsdLux light nodes below
 pub mod point_light;        // Modular directory structure
 pub mod directional_light;  // Modular directory structure
 pub mod spot_light;         // Modular directory structure
+pub mod dome_light;
+pub mod rect_light;
 
 pub use point_light::PointLightNode;
 pub use directional_light::DirectionalLightNode;
-pub use spot_light::SpotLightNode;
\ No newline at end of file
+pub use spot_light::SpotLightNode;
+pub use dome_light::DomeLightNode;
+pub use rect_light::RectLightNode;
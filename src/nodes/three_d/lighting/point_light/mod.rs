@@ -1,34 +1,52 @@
-//! Point light node module - enhanced NodeFactory system
+//! Point light node module - UsdLux SphereLight
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Building the light's shape inputs
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
 
 pub mod logic;
 pub mod parameters;
 
-// logic::PointLightLogic removed - only used internally
 pub use parameters::PointLightNode;
 
-use crate::nodes::NodeFactory;
+use crate::nodes::{NodeFactory, ParameterDefinition};
+use super::usd_light;
+
+/// Shape parameters of the sphere lights (Point and Spot)
+pub fn sphere_parameters() -> Vec<ParameterDefinition> {
+    vec![
+        ParameterDefinition::float("radius", "Radius", 0.5)
+            .with_min(0.0)
+            .with_step(0.01)
+            .in_group("Shape"),
+        ParameterDefinition::boolean("treat_as_point", "Treat as Point", true)
+            .with_tooltip("Light from an infinitely small point, ignoring the radius")
+            .in_group("Shape"),
+    ]
+}
 
 impl NodeFactory for parameters::PointLightNode {
     fn metadata() -> crate::nodes::NodeMetadata {
-        // Create new metadata specifically for the interface version
+        let mut schema = usd_light::light_parameters(parameters::DEFAULT_INTENSITY);
+        schema.extend(sphere_parameters());
         crate::nodes::NodeMetadata::new(
             "3D_PointLight",
             "Point Light",
             crate::nodes::NodeCategory::new(&["3D", "Lighting"]),
-            "Creates a point light with interface panel controls"
+            "Adds a UsdLux sphere light, shining in every direction from a point or a sphere, to a USD scene"
         )
         .with_color(egui::Color32::from_rgb(255, 255, 150))
         .with_icon("💡")
-        .with_inputs(vec![
-            crate::nodes::PortDefinition::optional("Transform", crate::nodes::DataType::Any)
-                .with_description("Optional transform matrix to position the light"),
-        ])
-        .with_outputs(vec![
-            crate::nodes::PortDefinition::required("Light", crate::nodes::DataType::Any)
-                .with_description("Light output for scene"),
-        ])
-        .with_tags(vec!["3d", "lighting", "point", "omnidirectional", "interface"])
-        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_inputs(usd_light::light_inputs())
+        .with_outputs(usd_light::light_outputs())
+        .with_parameters(schema)
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "lighting", "usd", "usdlux", "point", "sphere", "omnidirectional", "bulb"])
+        .with_subtitle(|node| {
+            let logic = parameters::PointLightNode::create_logic(node);
+            Some(logic.describe())
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
         .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,24 @@
+//! Rect Light node functional operations - a rectangular area light emitting down its -Z axis
+
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::lighting::usd_light::{float_attribute, LightCommon};
+
+/// Core Rect Light configuration
+#[derive(Debug, Clone)]
+pub struct RectLightLogic {
+    pub common: LightCommon,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl RectLightLogic {
+    /// Add the light to the incoming USD scene, or output it in a new one
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let attributes = vec![
+            float_attribute("inputs:width", self.width),
+            float_attribute("inputs:height", self.height),
+        ];
+        self.common.process(node_id, "RectLight", attributes, inputs)
+    }
+}
@@ -0,0 +1,48 @@
+//! Rect Light node module - UsdLux RectLight
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Building the light's shape inputs
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use parameters::RectLightNode;
+
+use crate::nodes::NodeFactory;
+use super::usd_light;
+
+impl NodeFactory for parameters::RectLightNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        let mut schema = usd_light::light_parameters(parameters::DEFAULT_INTENSITY);
+        schema.extend(vec![
+            crate::nodes::ParameterDefinition::float("width", "Width", 1.0)
+                .with_min(0.0)
+                .with_step(0.01)
+                .in_group("Shape"),
+            crate::nodes::ParameterDefinition::float("height", "Height", 1.0)
+                .with_min(0.0)
+                .with_step(0.01)
+                .in_group("Shape"),
+        ]);
+        crate::nodes::NodeMetadata::new(
+            "3D_RectLight",
+            "Rect Light",
+            crate::nodes::NodeCategory::new(&["3D", "Lighting"]),
+            "Adds a UsdLux rectangular area light to a USD scene"
+        )
+        .with_color(egui::Color32::from_rgb(255, 220, 180))
+        .with_icon("▭")
+        .with_inputs(usd_light::light_inputs())
+        .with_outputs(usd_light::light_outputs())
+        .with_parameters(schema)
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "lighting", "usd", "usdlux", "rect", "area", "softbox", "panel"])
+        .with_subtitle(|node| {
+            let logic = parameters::RectLightNode::create_logic(node);
+            Some(format!("{:.2} × {:.2}", logic.width, logic.height))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
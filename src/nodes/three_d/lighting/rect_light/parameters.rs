@@ -0,0 +1,29 @@
+//! Rect Light node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::lighting::usd_light::LightCommon;
+use super::logic::RectLightLogic;
+
+/// Default intensity of new Rect Light nodes
+pub const DEFAULT_INTENSITY: f32 = 10.0;
+
+/// Rect Light node
+#[derive(Debug, Clone, Default)]
+pub struct RectLightNode;
+
+impl RectLightNode {
+    /// Build RectLightLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> RectLightLogic {
+        RectLightLogic {
+            common: LightCommon::from_node(node, DEFAULT_INTENSITY),
+            width: node.float_parameter("width", 1.0),
+            height: node.float_parameter("height", 1.0),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
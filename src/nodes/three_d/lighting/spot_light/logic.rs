@@ -1,108 +1,45 @@
-//! Spot light node functional operations - light calculation logic
+//! Spot light node functional operations - a sphere light limited to a cone by the UsdLux ShapingAPI
 
-use crate::nodes::interface::{NodeData, LightData, LightType};
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::lighting::point_light::logic::PointLightLogic;
+use crate::nodes::three_d::lighting::usd_light::float_attribute;
 
-/// Core spot light data and functionality
+/// Core spot light configuration
 #[derive(Debug, Clone)]
 pub struct SpotLightLogic {
-    /// Light position in world space
-    pub position: [f32; 3],
-    /// Light direction (normalized vector)
-    pub direction: [f32; 3],
-    /// Light color (RGB)
-    pub color: [f32; 3],
-    /// Light intensity/brightness
-    pub intensity: f32,
-    /// Cone angle in radians
+    /// The sphere light being shaped
+    pub point: PointLightLogic,
+    /// Angle in degrees from the -Z axis to the edge of the cone
     pub cone_angle: f32,
-    /// Inner cone angle for soft falloff
-    pub inner_cone_angle: f32,
-    /// Attenuation settings
-    pub constant_attenuation: f32,
-    pub linear_attenuation: f32,
-    pub quadratic_attenuation: f32,
-    /// Whether light casts shadows
-    pub cast_shadows: bool,
+    /// Fraction of the cone, from the edge in, over which the light fades
+    pub cone_softness: f32,
 }
 
-impl Default for SpotLightLogic {
-    fn default() -> Self {
-        Self {
-            position: [0.0, 2.0, 0.0], // Above origin
-            direction: [0.0, -1.0, 0.0], // Pointing down
-            color: [1.0, 1.0, 1.0], // White light
-            intensity: 1.0,
-            cone_angle: std::f32::consts::PI * 0.25, // 45 degrees
-            inner_cone_angle: std::f32::consts::PI * 0.15, // 27 degrees
-            constant_attenuation: 1.0,
-            linear_attenuation: 0.09,
-            quadratic_attenuation: 0.032,
-            cast_shadows: true,
-        }
+impl SpotLightLogic {
+    /// Add the light to the incoming USD scene, or output it in a new one
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut attributes = self.point.attributes();
+        attributes.push(float_attribute("inputs:shaping:cone:angle", self.cone_angle));
+        attributes.push(float_attribute("inputs:shaping:cone:softness", self.cone_softness));
+        self.point.common.process(node_id, "SphereLight", attributes, inputs)
     }
 }
 
-impl SpotLightLogic {
-    /// Process input data and generate light data
-    pub fn process(&self, inputs: Vec<NodeData>, node_id: usize) -> Vec<NodeData> {
-        // Generate light data
-        let light_data = LightData {
-            id: format!("spot_light_{}", node_id),
-            light_type: LightType::Spot { 
-                direction: self.direction, 
-                cone_angle: self.cone_angle 
-            },
-            position: self.position,
-            color: self.color,
-            intensity: self.intensity,
-        };
-        
-        let _ = inputs; // Suppress unused warning
-        vec![NodeData::Light(light_data)]
-    }
-    
-    /// Normalize the direction vector
-    pub fn normalize_direction(&mut self) {
-        let length = (self.direction[0].powi(2) + self.direction[1].powi(2) + self.direction[2].powi(2)).sqrt();
-        if length > 0.0 {
-            self.direction[0] /= length;
-            self.direction[1] /= length;
-            self.direction[2] /= length;
-        }
-    }
-    
-    /// Calculate spot light falloff at given angle from center
-    pub fn calculate_spot_falloff(&self, angle_from_center: f32) -> f32 {
-        if angle_from_center > self.cone_angle {
-            0.0 // Outside cone
-        } else if angle_from_center < self.inner_cone_angle {
-            1.0 // Full intensity
-        } else {
-            // Smooth falloff between inner and outer cone
-            let t = (self.cone_angle - angle_from_center) / (self.cone_angle - self.inner_cone_angle);
-            t * t // Quadratic falloff
-        }
-    }
-    
-    /// Get effective light range
-    pub fn get_effective_range(&self) -> f32 {
-        // Similar to point light calculation
-        let target_attenuation = 0.01;
-        let a = self.quadratic_attenuation;
-        let b = self.linear_attenuation;
-        let c = self.constant_attenuation - (1.0 / target_attenuation);
-        
-        if a != 0.0 {
-            let discriminant = b * b - 4.0 * a * c;
-            if discriminant >= 0.0 {
-                (-b + discriminant.sqrt()) / (2.0 * a)
-            } else {
-                50.0 // Default range
-            }
-        } else if b != 0.0 {
-            -c / b
-        } else {
-            50.0 // Default range
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::Node;
+    use crate::nodes::three_d::lighting::spot_light::SpotLightNode;
+
+    #[test]
+    fn test_spot_light_is_a_shaped_sphere_light() {
+        let node = Node::new(7, "Spot Light", egui::Pos2::ZERO);
+        let outputs = SpotLightNode::create_logic(&node).process(node.id, Vec::new());
+        let NodeData::USDSceneData(scene) = &outputs[0] else { panic!("expected a USD scene") };
+        let light = &scene.lights[0];
+        assert_eq!(light.lux_type(), Some("SphereLight"));
+        let names: Vec<&str> = light.attributes.iter().map(|attribute| attribute.name.as_str()).collect();
+        assert_eq!(names, ["inputs:radius", "treatAsPoint", "inputs:shaping:cone:angle", "inputs:shaping:cone:softness", "inputs:shadow:enable"]);
     }
-}
\ No newline at end of file
+}
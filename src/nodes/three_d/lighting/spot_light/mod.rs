@@ -1,33 +1,52 @@
-//! Spot light node module - enhanced NodeFactory system
+//! Spot light node module - UsdLux SphereLight shaped into a cone
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Building the light's shape and shaping inputs
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
 
 pub mod logic;
 pub mod parameters;
 
-// logic::SpotLightLogic removed - only used internally
 pub use parameters::SpotLightNode;
 
 use crate::nodes::NodeFactory;
+use super::point_light::{parameters::DEFAULT_INTENSITY, sphere_parameters};
+use super::usd_light;
 
 impl NodeFactory for parameters::SpotLightNode {
     fn metadata() -> crate::nodes::NodeMetadata {
+        let mut schema = usd_light::light_parameters(DEFAULT_INTENSITY);
+        schema.extend(sphere_parameters());
+        schema.extend(vec![
+            crate::nodes::ParameterDefinition::float("cone_angle", "Cone Angle", 45.0)
+                .with_range(0.0, 180.0)
+                .with_step(0.5)
+                .with_tooltip("Angle in degrees from the light's -Z axis to the edge of the cone")
+                .in_group("Cone"),
+            crate::nodes::ParameterDefinition::float("cone_softness", "Softness", 0.4)
+                .with_range(0.0, 1.0)
+                .with_step(0.01)
+                .with_tooltip("How far in from the edge the light fades; 0 gives a hard edge")
+                .in_group("Cone"),
+        ]);
         crate::nodes::NodeMetadata::new(
             "3D_SpotLight",
             "Spot Light",
             crate::nodes::NodeCategory::new(&["3D", "Lighting"]),
-            "Creates a spot light with interface panel controls"
+            "Adds a UsdLux sphere light limited to a cone, shining down its -Z axis, to a USD scene"
         )
         .with_color(egui::Color32::from_rgb(255, 255, 150))
         .with_icon("🔦")
-        .with_inputs(vec![
-            crate::nodes::PortDefinition::optional("Transform", crate::nodes::DataType::Any)
-                .with_description("Optional transform matrix to position the light"),
-        ])
-        .with_outputs(vec![
-            crate::nodes::PortDefinition::required("Light", crate::nodes::DataType::Any)
-                .with_description("Light output for scene"),
-        ])
-        .with_tags(vec!["3d", "lighting", "spot", "cone", "directional", "interface"])
-        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_inputs(usd_light::light_inputs())
+        .with_outputs(usd_light::light_outputs())
+        .with_parameters(schema)
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "lighting", "usd", "usdlux", "spot", "cone", "shaping"])
+        .with_subtitle(|node| {
+            let logic = parameters::SpotLightNode::create_logic(node);
+            Some(format!("{:.0}° · {:.1}", logic.cone_angle, logic.point.common.intensity))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
         .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
     }
-}
\ No newline at end of file
+}
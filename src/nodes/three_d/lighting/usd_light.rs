@@ -0,0 +1,317 @@
+//! Shared parts of the UsdLux light nodes (Point, Spot, Directional, Dome, Rect)
+//!
+//! Each node outputs a USD scene holding its light, or adds the light to the
+//! scene connected to its "Scene" input, so graphs light their own scenes in
+//! the viewport and in Hydra renders. Every light has an intensity, exposure,
+//! color and transform; the nodes add the inputs specific to their shape.
+
+use glam::Vec3;
+use crate::nodes::{Node, NodeFactory, NodeGraph, NodeId, ParameterDefinition, PortDefinition, DataType};
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::modify::transform::TransformLogic;
+use crate::workspaces::three_d::usd::usd_engine::{AttributeValue, USDAttribute, USDLightData, USDSceneData};
+
+/// Index of the optional scene input the light is added to
+const SCENE_INPUT: usize = 0;
+/// Index of the translate input overriding the parameter
+const TRANSLATE_INPUT: usize = 1;
+/// Index of the rotate input overriding the parameter
+const ROTATE_INPUT: usize = 2;
+
+/// Inputs shared by every light node
+pub fn light_inputs() -> Vec<PortDefinition> {
+    vec![
+        PortDefinition::optional("Scene", DataType::Any)
+            .with_description("USD scene the light is added to; without one the light is output on its own"),
+        PortDefinition::optional("Translate", DataType::Vector3)
+            .with_description("Position of the light, overriding the Translate parameter"),
+        PortDefinition::optional("Rotate", DataType::Vector3)
+            .with_description("Rotation of the light in degrees, overriding the Rotate parameter"),
+    ]
+}
+
+/// Output shared by every light node
+pub fn light_outputs() -> Vec<PortDefinition> {
+    vec![
+        PortDefinition::required("Scene", DataType::USDScene)
+            .with_description("USD scene with the light"),
+    ]
+}
+
+/// Parameters shared by every light node
+pub fn light_parameters(intensity: f32) -> Vec<ParameterDefinition> {
+    vec![
+        ParameterDefinition::float("intensity", "Intensity", intensity)
+            .with_min(0.0)
+            .with_step(0.1)
+            .in_group("Light"),
+        ParameterDefinition::float("exposure", "Exposure", 0.0)
+            .with_step(0.1)
+            .with_tooltip("Brightness in stops; each stop doubles the intensity")
+            .in_group("Light"),
+        ParameterDefinition::color("color", "Color", [1.0, 1.0, 1.0, 1.0])
+            .in_group("Light"),
+        ParameterDefinition::boolean("cast_shadows", "Cast Shadows", true)
+            .in_group("Light"),
+        ParameterDefinition::vector3("translate", "Translate", [0.0, 0.0, 0.0])
+            .with_step(0.1)
+            .in_group("Transform"),
+        ParameterDefinition::vector3("rotate", "Rotate", [0.0, 0.0, 0.0])
+            .with_tooltip("Rotation about X, Y and Z in degrees; lights shine down their -Z axis")
+            .in_group("Transform"),
+    ]
+}
+
+/// Light settings shared by every light node
+#[derive(Debug, Clone)]
+pub struct LightCommon {
+    pub intensity: f32,
+    pub exposure: f32,
+    pub color: Vec3,
+    /// Written as the ShadowAPI's `inputs:shadow:enable`
+    pub cast_shadows: bool,
+    pub translate: Vec3,
+    /// Rotation about X, Y and Z in degrees
+    pub rotate: Vec3,
+}
+
+impl LightCommon {
+    /// Read the shared parameters of a light node
+    pub fn from_node(node: &Node, default_intensity: f32) -> Self {
+        let vector = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Vector3(v) = v { Some(Vec3::from(*v)) } else { None })
+            .unwrap_or(Vec3::ZERO);
+        Self {
+            intensity: node.float_parameter("intensity", default_intensity),
+            exposure: node.float_parameter("exposure", 0.0),
+            color: node.parameters.get("color")
+                .and_then(|v| if let NodeData::Color(c) = v { Some(Vec3::new(c[0], c[1], c[2])) } else { None })
+                .unwrap_or(Vec3::ONE),
+            cast_shadows: node.bool_parameter("cast_shadows", true),
+            translate: vector("translate"),
+            rotate: vector("rotate"),
+        }
+    }
+
+    /// Build the light and add it to the scene input, or to a new scene
+    pub fn process(&self, node_id: NodeId, light_type: &str, mut attributes: Vec<USDAttribute>, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let vector_input = |index: usize| match inputs.get(index) {
+            Some(NodeData::Vector3(v)) => Some(Vec3::from(*v)),
+            _ => None,
+        };
+        let transform = TransformLogic {
+            translate: vector_input(TRANSLATE_INPUT).unwrap_or(self.translate),
+            rotate: vector_input(ROTATE_INPUT).unwrap_or(self.rotate),
+            ..Default::default()
+        };
+
+        attributes.push(bool_attribute("inputs:shadow:enable", self.cast_shadows));
+        let light = USDLightData {
+            prim_path: format!("/Lights/{}_{}", light_type, node_id),
+            light_type: light_type.to_string(),
+            intensity: self.intensity,
+            color: self.color,
+            transform: transform.matrix(),
+            exposure: self.exposure,
+            attributes,
+        };
+        let mut scene = match inputs.into_iter().nth(SCENE_INPUT) {
+            Some(NodeData::USDSceneData(scene)) => scene,
+            _ => USDSceneData {
+                stage_path: format!("procedural://light_node_{}", node_id),
+                meshes: Vec::new(),
+                lights: Vec::new(),
+                materials: Vec::new(),
+                up_axis: "Y".to_string(),
+                instancers: Vec::new(),
+            },
+        };
+        scene.lights.push(light);
+        vec![NodeData::USDSceneData(scene)]
+    }
+}
+
+/// Bring light nodes saved by older versions onto the UsdLux nodes, in `graph`
+/// and the graphs nested in it.
+///
+/// Sphere Light nodes become Point Lights with a radius. The per-channel
+/// colors, positions, directions and cone angles of the old Point, Spot and
+/// Directional lights carry over; their attenuation and shadow resolution have
+/// no UsdLux equivalent and are dropped. Their Transform input is replaced by
+/// the scene inputs, so wires into it are removed.
+pub fn upgrade_legacy_lights(graph: &mut NodeGraph) {
+    let mut rebuilt = Vec::new();
+    for node in graph.nodes.values_mut() {
+        if upgrade_legacy_light(node) {
+            rebuilt.push(node.id);
+        }
+        if let Some(internal_graph) = node.get_internal_graph_mut() {
+            upgrade_legacy_lights(internal_graph);
+        }
+    }
+    graph.connections.retain(|connection| !rebuilt.contains(&connection.to_node));
+}
+
+/// Upgrade one node; true when its ports were rebuilt
+fn upgrade_legacy_light(node: &mut Node) -> bool {
+    if node.type_id == "3D_SphereLight" {
+        node.type_id = "3D_PointLight".to_string();
+        node.parameters.insert("treat_as_point".to_string(), NodeData::Boolean(false));
+        return false;
+    }
+    // The old lights output NodeData::Light on a port named "Light"
+    if !node.outputs.first().is_some_and(|port| port.name == "Light") {
+        return false;
+    }
+    let fresh = match node.type_id.as_str() {
+        "3D_PointLight" => super::PointLightNode::create(node.position),
+        "3D_SpotLight" => super::SpotLightNode::create(node.position),
+        "3D_DirectionalLight" => super::DirectionalLightNode::create(node.position),
+        _ => return false,
+    };
+    let legacy = std::mem::replace(&mut node.parameters, fresh.parameters);
+    node.inputs = fresh.inputs;
+    node.outputs = fresh.outputs;
+    node.update_port_positions();
+
+    let float = |name: &str, default: f32| match legacy.get(name) {
+        Some(NodeData::Float(value)) => *value,
+        _ => default,
+    };
+    node.parameters.insert("intensity".to_string(), NodeData::Float(float("intensity", 1.0)));
+    node.parameters.insert("color".to_string(), NodeData::Color([float("color_r", 1.0), float("color_g", 1.0), float("color_b", 1.0), 1.0]));
+    if let Some(NodeData::Boolean(cast_shadows)) = legacy.get("cast_shadows") {
+        node.parameters.insert("cast_shadows".to_string(), NodeData::Boolean(*cast_shadows));
+    }
+    if node.type_id != "3D_DirectionalLight" {
+        let position = [float("position_x", 0.0), float("position_y", 2.0), float("position_z", 0.0)];
+        node.parameters.insert("translate".to_string(), NodeData::Vector3(position));
+    }
+    if node.type_id != "3D_PointLight" {
+        let direction = Vec3::new(float("direction_x", 0.0), float("direction_y", -1.0), float("direction_z", 0.0));
+        node.parameters.insert("rotate".to_string(), NodeData::Vector3(rotation_towards(direction).to_array()));
+    }
+    if node.type_id == "3D_SpotLight" {
+        // The old cone angles were in radians, with the inner cone at full intensity
+        let outer = float("cone_angle", std::f32::consts::PI * 0.25);
+        let inner = float("inner_cone_angle", std::f32::consts::PI * 0.15);
+        let softness = if outer > 0.0 { (1.0 - inner / outer).clamp(0.0, 1.0) } else { 0.0 };
+        node.parameters.insert("cone_angle".to_string(), NodeData::Float(outer.to_degrees()));
+        node.parameters.insert("cone_softness".to_string(), NodeData::Float(softness));
+    }
+    true
+}
+
+/// Rotation in degrees, applied X then Y, turning a light's -Z axis towards `direction`
+fn rotation_towards(direction: Vec3) -> Vec3 {
+    let direction = direction.try_normalize().unwrap_or(Vec3::NEG_Z);
+    let pitch = direction.y.clamp(-1.0, 1.0).asin();
+    let yaw = (-direction.x).atan2(-direction.z);
+    Vec3::new(pitch.to_degrees(), yaw.to_degrees(), 0.0)
+}
+
+/// A light-specific float input, e.g. `inputs:radius`
+pub fn float_attribute(name: &str, value: f32) -> USDAttribute {
+    USDAttribute {
+        name: name.to_string(),
+        value_type: "float".to_string(),
+        value: AttributeValue::Float(value),
+        is_custom: false,
+        metadata: Default::default(),
+    }
+}
+
+/// A light-specific boolean input, e.g. `treatAsPoint`
+pub fn bool_attribute(name: &str, value: bool) -> USDAttribute {
+    USDAttribute {
+        name: name.to_string(),
+        value_type: "bool".to_string(),
+        value: AttributeValue::Bool(value),
+        is_custom: false,
+        metadata: Default::default(),
+    }
+}
+
+/// A light-specific asset input, e.g. `inputs:texture:file`
+pub fn asset_attribute(name: &str, path: &str) -> USDAttribute {
+    USDAttribute {
+        name: name.to_string(),
+        value_type: "asset".to_string(),
+        value: AttributeValue::Asset(path.to_string()),
+        is_custom: false,
+        metadata: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_is_added_to_the_incoming_scene() {
+        let common = LightCommon {
+            intensity: 2.0,
+            exposure: 1.0,
+            color: Vec3::new(1.0, 0.5, 0.25),
+            cast_shadows: false,
+            translate: Vec3::new(0.0, 5.0, 0.0),
+            rotate: Vec3::ZERO,
+        };
+
+        let outputs = common.process(3, "SphereLight", vec![float_attribute("inputs:radius", 0.5)], Vec::new());
+        let NodeData::USDSceneData(scene) = &outputs[0] else { panic!("expected a USD scene") };
+        assert_eq!(scene.stage_path, "procedural://light_node_3");
+        assert_eq!(scene.lights.len(), 1);
+        let light = &scene.lights[0];
+        assert_eq!(light.lux_type(), Some("SphereLight"));
+        assert_eq!(light.transform.w_axis.truncate(), Vec3::new(0.0, 5.0, 0.0));
+        assert_eq!(light.attributes[0].name, "inputs:radius");
+        assert!(matches!(light.attributes[1].value, AttributeValue::Bool(false)));
+
+        // Connected inputs override the parameters and the light joins the scene
+        let inputs = vec![outputs[0].clone(), NodeData::Vector3([1.0, 2.0, 3.0])];
+        let NodeData::USDSceneData(scene) = &common.process(4, "DistantLight", Vec::new(), inputs)[0] else { panic!("expected a USD scene") };
+        assert_eq!(scene.stage_path, "procedural://light_node_3");
+        assert_eq!(scene.lights.len(), 2);
+        assert_eq!(scene.lights[1].transform.w_axis.truncate(), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_legacy_lights_are_upgraded_on_load() {
+        let mut graph = NodeGraph::new();
+        let mut spot = Node::new(0, "Spot Light", egui::Pos2::ZERO);
+        spot.set_type_id("3D_SpotLight");
+        spot.add_input("Transform");
+        spot.add_output("Light");
+        for (name, value) in [("color_g", 0.5), ("position_y", 3.0), ("direction_x", 1.0), ("direction_y", 0.0),
+            ("cone_angle", std::f32::consts::FRAC_PI_3), ("inner_cone_angle", std::f32::consts::FRAC_PI_6)] {
+            spot.parameters.insert(name.to_string(), NodeData::Float(value));
+        }
+        spot.parameters.insert("cast_shadows".to_string(), NodeData::Boolean(false));
+        spot.parameters.insert("shadow_resolution".to_string(), NodeData::Integer(2048));
+        let spot = graph.add_node(spot);
+        let mut sphere = Node::new(0, "Sphere Light", egui::Pos2::ZERO);
+        sphere.set_type_id("3D_SphereLight");
+        sphere.add_output("Scene");
+        let sphere = graph.add_node(sphere);
+        graph.add_connection_by_ids(sphere, 0, spot, 0).unwrap();
+
+        upgrade_legacy_lights(&mut graph);
+
+        let node = &graph.nodes[&spot];
+        assert_eq!(node.outputs[0].name, "Scene");
+        assert!(graph.connections.is_empty());
+        assert!(!node.parameters.contains_key("shadow_resolution"));
+        assert!(matches!(node.parameters["color"], NodeData::Color(color) if color == [1.0, 0.5, 1.0, 1.0]));
+        let logic = crate::nodes::three_d::lighting::SpotLightNode::create_logic(node);
+        assert!(!logic.point.common.cast_shadows);
+        assert_eq!(logic.point.common.translate, Vec3::new(0.0, 3.0, 0.0));
+        assert!((logic.cone_angle - 60.0).abs() < 1e-4 && (logic.cone_softness - 0.5).abs() < 1e-6);
+        let transform = TransformLogic { rotate: logic.point.common.rotate, ..Default::default() };
+        assert!(transform.matrix().transform_vector3(Vec3::NEG_Z).abs_diff_eq(Vec3::X, 1e-5));
+
+        let node = &graph.nodes[&sphere];
+        assert_eq!(node.type_id, "3D_PointLight");
+        assert!(!node.bool_parameter("treat_as_point", true));
+    }
+}
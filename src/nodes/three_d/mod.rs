@@ -6,6 +6,8 @@ pub mod lighting;
 pub mod modify;
 pub mod ui;
 pub mod output;
+pub mod proxy;
+pub mod input;
 
 // Re-exports removed - these were unused wildcard imports
 
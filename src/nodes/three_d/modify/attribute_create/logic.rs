@@ -0,0 +1,276 @@
+//! Attribute Create node functional operations - writing primvars from expressions
+//!
+//! The value is one expression per component, separated by commas, e.g.
+//! `1, P.y * 0.5, 0` for a float3. Expressions use the syntax of parameter
+//! expressions (see [`crate::nodes::expressions`]) with these names in scope:
+//!
+//! - `P.x P.y P.z`: the point's position in the mesh's own space
+//! - `N.x N.y N.z`: its normal (0 when the mesh has none per point)
+//! - `uv.x uv.y`: its texture coordinate (0 when the mesh has none per point)
+//! - `ptnum`, `numpt`: the point's index and the number of points
+//! - `old`, or `old.x old.y old.z`: the primvar's current value, to edit it
+//!
+//! Constant primvars are evaluated once, at the mesh's centroid.
+
+use glam::{Vec2, Vec3};
+use crate::nodes::expressions;
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::modify::prune::logic::matches_prim;
+use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDMeshGeometry, USDPrimvar};
+
+/// Value type of the created primvar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeType {
+    Float,
+    Float2,
+    #[default]
+    Float3,
+}
+
+impl AttributeType {
+    pub const NAMES: [&'static str; 3] = ["float", "float2", "float3"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "float" => Some(Self::Float),
+            "float2" => Some(Self::Float2),
+            "float3" => Some(Self::Float3),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[self.components() - 1]
+    }
+
+    pub fn components(&self) -> usize {
+        match self {
+            Self::Float => 1,
+            Self::Float2 => 2,
+            Self::Float3 => 3,
+        }
+    }
+}
+
+/// Core Attribute Create configuration
+#[derive(Debug, Clone, Default)]
+pub struct AttributeCreateLogic {
+    /// Prim patterns (as in the Prune node) of the meshes to edit; empty edits every mesh
+    pub prims: Vec<String>,
+    /// Primvar name, without the `primvars:` namespace
+    pub name: String,
+    pub attribute_type: AttributeType,
+    /// One value per point ("vertex") or one per mesh ("constant")
+    pub per_point: bool,
+    /// Expression of each component
+    pub components: Vec<String>,
+}
+
+/// Split "1, max(P.y, 0), 0" into its top-level comma-separated expressions
+pub fn split_components(text: &str) -> Vec<String> {
+    let mut components = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                components.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    components.push(current.trim().to_string());
+    components.retain(|component| !component.is_empty());
+    components
+}
+
+/// Attributes of one point, as seen by the expressions
+struct Point {
+    position: Vec3,
+    normal: Vec3,
+    uv: Vec2,
+    index: usize,
+    count: usize,
+    old: [f32; 3],
+}
+
+impl Point {
+    fn variable(&self, name: &str) -> Option<f64> {
+        let value = match name {
+            "P.x" => self.position.x,
+            "P.y" => self.position.y,
+            "P.z" => self.position.z,
+            "N.x" => self.normal.x,
+            "N.y" => self.normal.y,
+            "N.z" => self.normal.z,
+            "uv.x" | "uv.u" => self.uv.x,
+            "uv.y" | "uv.v" => self.uv.y,
+            "ptnum" => self.index as f32,
+            "numpt" => self.count as f32,
+            "old" | "old.x" | "old.r" => self.old[0],
+            "old.y" | "old.g" => self.old[1],
+            "old.z" | "old.b" => self.old[2],
+            _ => return None,
+        };
+        Some(value as f64)
+    }
+}
+
+impl AttributeCreateLogic {
+    fn bare_name(&self) -> &str {
+        let name = self.name.trim();
+        name.strip_prefix("primvars:").unwrap_or(name)
+    }
+
+    /// Create or replace the primvar on the matching meshes of the incoming scene
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(mut scene) = input else {
+            return vec![input];
+        };
+        if self.bare_name().is_empty() {
+            return vec![NodeData::USDSceneData(scene)];
+        }
+        let meshes = scene.meshes.iter_mut()
+            .filter(|mesh| self.prims.is_empty() || self.prims.iter().any(|pattern| matches_prim(pattern, &mesh.prim_path)));
+        for mesh in meshes {
+            if let Err(e) = self.apply(mesh) {
+                log::warn!("Attribute Create: {} on {}", e, mesh.prim_path);
+            }
+        }
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Evaluate the expressions over `mesh` and store the result as a primvar
+    pub fn apply(&self, mesh: &mut USDMeshGeometry) -> Result<(), String> {
+        let components = self.attribute_type.components();
+        let expressions: Vec<&String> = match self.components.len() {
+            1 => vec![&self.components[0]; components],
+            count if count == components => self.components.iter().collect(),
+            count => return Err(format!("{} takes {} values, got {}", self.attribute_type.name(), components, count)),
+        };
+
+        let name = self.bare_name().to_string();
+        let old = mesh.primvars.iter()
+            .find(|primvar| primvar.name.strip_prefix("primvars:").unwrap_or(&primvar.name) == name)
+            .map(|primvar| primvar.values.clone());
+        let old_value = |index: usize| -> [f32; 3] {
+            let pick = |len: usize| if len == 1 { 0 } else { index };
+            match &old {
+                Some(PrimvarValues::Float(values)) => values.get(pick(values.len())).map_or([0.0; 3], |&v| [v, 0.0, 0.0]),
+                Some(PrimvarValues::Float2(values)) => values.get(pick(values.len())).map_or([0.0; 3], |v| [v.x, v.y, 0.0]),
+                Some(PrimvarValues::Float3(values)) => values.get(pick(values.len())).map_or([0.0; 3], |v| v.to_array()),
+                _ => [0.0; 3],
+            }
+        };
+
+        let count = mesh.vertices.len();
+        let points: Vec<Point> = if self.per_point {
+            (0..count).map(|index| Point {
+                position: mesh.vertices[index],
+                normal: if mesh.normals.len() == count { mesh.normals[index] } else { Vec3::ZERO },
+                uv: if mesh.uvs.len() == count { mesh.uvs[index] } else { Vec2::ZERO },
+                index,
+                count,
+                old: old_value(index),
+            }).collect()
+        } else {
+            let centroid = mesh.vertices.iter().sum::<Vec3>() / count.max(1) as f32;
+            vec![Point { position: centroid, normal: Vec3::ZERO, uv: Vec2::ZERO, index: 0, count, old: old_value(0) }]
+        };
+
+        let mut values = Vec::with_capacity(points.len());
+        for point in &points {
+            let mut value = [0.0f32; 3];
+            for (component, expression) in expressions.iter().enumerate() {
+                value[component] = expressions::evaluate_with_variables(expression, &|name| point.variable(name))? as f32;
+            }
+            values.push(value);
+        }
+
+        let values = match self.attribute_type {
+            AttributeType::Float => PrimvarValues::Float(values.iter().map(|v| v[0]).collect()),
+            AttributeType::Float2 => PrimvarValues::Float2(values.iter().map(|v| Vec2::new(v[0], v[1])).collect()),
+            AttributeType::Float3 => PrimvarValues::Float3(values.iter().map(|&v| Vec3::from(v)).collect()),
+        };
+        // The viewport draws displayColor from the mesh's vertex colors
+        if name == "displayColor" {
+            if let PrimvarValues::Float3(colors) = &values {
+                mesh.vertex_colors = Some(colors.clone());
+            }
+        }
+        mesh.primvars.retain(|primvar| primvar.name.strip_prefix("primvars:").unwrap_or(&primvar.name) != name);
+        mesh.primvars.push(USDPrimvar {
+            name,
+            interpolation: if self.per_point { "vertex" } else { "constant" }.to_string(),
+            data_type: self.attribute_type.name().to_string(),
+            values,
+            indices: None,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+
+    fn quad() -> USDMeshGeometry {
+        USDMeshGeometry {
+            prim_path: "/World/Quad".to_string(),
+            vertices: vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::new(0.0, 2.0, 0.0)],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            normals: vec![],
+            uvs: vec![],
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_primvars_are_created_and_edited_from_expressions() {
+        assert_eq!(split_components("1, max(P.y, 0), 0"), vec!["1", "max(P.y, 0)", "0"]);
+
+        let mut mesh = quad();
+        let color = AttributeCreateLogic {
+            name: "primvars:displayColor".to_string(),
+            attribute_type: AttributeType::Float3,
+            per_point: true,
+            components: split_components("1, P.y / 2, ptnum"),
+            ..Default::default()
+        };
+        color.apply(&mut mesh).unwrap();
+        assert_eq!(mesh.primvars[0].name, "displayColor");
+        assert_eq!(mesh.vertex_colors.as_ref().unwrap()[2], Vec3::new(1.0, 1.0, 2.0));
+
+        // Editing reads the current value; a single expression fills every component
+        let darken = AttributeCreateLogic { components: vec!["old * 0.5".to_string()], ..color.clone() };
+        darken.apply(&mut mesh).unwrap();
+        assert_eq!(mesh.primvars.len(), 1);
+        assert_eq!(mesh.vertex_colors.as_ref().unwrap()[2], Vec3::new(0.5, 0.5, 0.5));
+
+        let width = AttributeCreateLogic {
+            name: "width".to_string(),
+            attribute_type: AttributeType::Float,
+            per_point: false,
+            components: vec!["P.y + 1".to_string()],
+            ..Default::default()
+        };
+        width.apply(&mut mesh).unwrap();
+        assert!(matches!(&mesh.primvars[1].values, PrimvarValues::Float(values) if values == &vec![2.0]));
+
+        let wrong = AttributeCreateLogic { components: vec!["1".to_string(), "2".to_string()], ..color };
+        assert!(wrong.apply(&mut mesh).is_err());
+        assert!(AttributeCreateLogic { components: vec!["Q.x".to_string()], ..width }.apply(&mut mesh).is_err());
+    }
+}
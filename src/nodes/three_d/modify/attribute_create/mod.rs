@@ -0,0 +1,57 @@
+//! Attribute Create node module - set primvars on the meshes of a USD scene
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Evaluating the value expressions per point and storing the primvar
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::AttributeCreateLogic;
+pub use parameters::AttributeCreateNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::AttributeCreateNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_AttributeCreate",
+            "Attribute Create",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Creates or edits a primvar on the meshes of a USD scene, from a constant or an expression over each point's position, normal and UV"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("✎")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data to edit"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data with the primvar set"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("prims", "Prims", "")
+                .with_tooltip("Prim patterns of the meshes to edit, as in the Prune node; empty edits every mesh"),
+            crate::nodes::ParameterDefinition::string("name", "Name", "displayColor")
+                .with_tooltip("Primvar to create, or to replace when the mesh already has it")
+                .in_group("Attribute"),
+            crate::nodes::ParameterDefinition::choice("type", "Type", logic::AttributeType::NAMES.to_vec(), "float3")
+                .in_group("Attribute"),
+            crate::nodes::ParameterDefinition::choice("interpolation", "Interpolation", vec!["vertex", "constant"], "vertex")
+                .with_tooltip("One value per point, or one value for the whole mesh")
+                .in_group("Attribute"),
+            crate::nodes::ParameterDefinition::string("value", "Value", "1, 1, 1")
+                .with_tooltip("One expression per component separated by commas, or one for all of them. Available: P.x P.y P.z, N.x N.y N.z, uv.x uv.y, ptnum, numpt and old (the current value, e.g. old.x * 0.5)")
+                .in_group("Attribute"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "attribute", "primvar", "wrangle", "edit", "color", "expression", "usd"])
+        .with_subtitle(|node| {
+            let logic = parameters::AttributeCreateNode::create_logic(node);
+            Some(format!("{} = {}", logic.name, logic.components.join(", ")))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
@@ -0,0 +1,31 @@
+//! Attribute Create node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::prune::PruneLogic;
+use super::logic::{split_components, AttributeCreateLogic, AttributeType};
+
+/// Attribute Create node
+#[derive(Debug, Clone, Default)]
+pub struct AttributeCreateNode;
+
+impl AttributeCreateNode {
+    /// Build AttributeCreateLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> AttributeCreateLogic {
+        let text = |name: &str, default: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_else(|| default.to_string());
+        AttributeCreateLogic {
+            prims: PruneLogic::parse_patterns(&text("prims", "")),
+            name: text("name", "displayColor").trim().to_string(),
+            attribute_type: AttributeType::from_name(&text("type", "float3")).unwrap_or_default(),
+            per_point: text("interpolation", "vertex") == "vertex",
+            components: split_components(&text("value", "1, 1, 1")),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,450 @@
+//! Boolean node functional operations - constructive solid geometry on meshes
+//!
+//! Both scenes are flattened to world-space polygons and combined with BSP
+//! trees: each tree clips away the other's polygons lying inside (or outside)
+//! it, and what is left of both forms the result. The inputs should be
+//! closed meshes; open ones give holes where they do not enclose a volume.
+//! Primvars and UVs of the inputs are not carried over.
+
+use glam::{Mat4, Vec3};
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{USDMeshGeometry, USDSceneData};
+
+/// How the two meshes combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BooleanOperation {
+    /// Everything inside either mesh
+    #[default]
+    Union,
+    /// The first mesh with the second carved out of it
+    Difference,
+    /// Only what is inside both meshes
+    Intersection,
+}
+
+impl BooleanOperation {
+    pub const NAMES: [&'static str; 3] = ["Union", "Difference", "Intersection"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Union" => Some(Self::Union),
+            "Difference" => Some(Self::Difference),
+            "Intersection" => Some(Self::Intersection),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Union => "Union",
+            Self::Difference => "Difference",
+            Self::Intersection => "Intersection",
+        }
+    }
+}
+
+/// How the convex polygons left by clipping are split into triangles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Triangulation {
+    /// Triangles from the polygon's first corner; no new points
+    #[default]
+    Fan,
+    /// Triangles around a point added at the polygon's center; avoids slivers
+    Centroid,
+}
+
+impl Triangulation {
+    pub const NAMES: [&'static str; 2] = ["Fan", "Centroid"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Fan" => Some(Self::Fan),
+            "Centroid" => Some(Self::Centroid),
+            _ => None,
+        }
+    }
+}
+
+/// Core Boolean configuration
+#[derive(Debug, Clone)]
+pub struct BooleanLogic {
+    pub operation: BooleanOperation,
+    /// Distance within which points count as lying on a plane
+    pub tolerance: f32,
+    pub triangulation: Triangulation,
+}
+
+impl Default for BooleanLogic {
+    fn default() -> Self {
+        Self {
+            operation: BooleanOperation::Union,
+            tolerance: 1e-5,
+            triangulation: Triangulation::Fan,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Plane {
+    normal: Vec3,
+    w: f32,
+}
+
+const COPLANAR: u8 = 0;
+const FRONT: u8 = 1;
+const BACK: u8 = 2;
+const SPANNING: u8 = 3;
+
+impl Plane {
+    /// Plane of a triangle, or None when it is degenerate
+    fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<Self> {
+        let normal = (b - a).cross(c - a).try_normalize()?;
+        Some(Self { normal, w: normal.dot(a) })
+    }
+
+    fn flip(&mut self) {
+        self.normal = -self.normal;
+        self.w = -self.w;
+    }
+
+    /// Sort `polygon` by side of this plane, splitting it when it crosses the plane
+    fn split(
+        &self,
+        polygon: Polygon,
+        tolerance: f32,
+        coplanar_front: &mut Vec<Polygon>,
+        coplanar_back: &mut Vec<Polygon>,
+        front: &mut Vec<Polygon>,
+        back: &mut Vec<Polygon>,
+    ) {
+        let sides: Vec<u8> = polygon.vertices.iter()
+            .map(|&vertex| {
+                let distance = self.normal.dot(vertex) - self.w;
+                if distance < -tolerance { BACK } else if distance > tolerance { FRONT } else { COPLANAR }
+            })
+            .collect();
+        match sides.iter().fold(COPLANAR, |all, side| all | side) {
+            COPLANAR if self.normal.dot(polygon.plane.normal) > 0.0 => coplanar_front.push(polygon),
+            COPLANAR => coplanar_back.push(polygon),
+            FRONT => front.push(polygon),
+            BACK => back.push(polygon),
+            _ => {
+                let (mut front_vertices, mut back_vertices) = (Vec::new(), Vec::new());
+                let count = polygon.vertices.len();
+                for i in 0..count {
+                    let j = (i + 1) % count;
+                    let (vi, vj) = (polygon.vertices[i], polygon.vertices[j]);
+                    if sides[i] != BACK {
+                        front_vertices.push(vi);
+                    }
+                    if sides[i] != FRONT {
+                        back_vertices.push(vi);
+                    }
+                    if sides[i] | sides[j] == SPANNING {
+                        let t = (self.w - self.normal.dot(vi)) / self.normal.dot(vj - vi);
+                        let vertex = vi.lerp(vj, t);
+                        front_vertices.push(vertex);
+                        back_vertices.push(vertex);
+                    }
+                }
+                if front_vertices.len() >= 3 {
+                    front.push(Polygon { vertices: front_vertices, plane: polygon.plane });
+                }
+                if back_vertices.len() >= 3 {
+                    back.push(Polygon { vertices: back_vertices, plane: polygon.plane });
+                }
+            }
+        }
+    }
+}
+
+/// Convex polygon in world space
+#[derive(Debug, Clone)]
+struct Polygon {
+    vertices: Vec<Vec3>,
+    plane: Plane,
+}
+
+impl Polygon {
+    fn flip(&mut self) {
+        self.vertices.reverse();
+        self.plane.flip();
+    }
+}
+
+/// BSP tree node: the polygons on its plane, and the trees in front of and behind it
+#[derive(Debug, Default)]
+struct BspNode {
+    plane: Option<Plane>,
+    front: Option<Box<BspNode>>,
+    back: Option<Box<BspNode>>,
+    polygons: Vec<Polygon>,
+}
+
+impl BspNode {
+    fn new(polygons: Vec<Polygon>, tolerance: f32) -> Self {
+        let mut node = Self::default();
+        node.build(polygons, tolerance);
+        node
+    }
+
+    /// Swap solid and empty space
+    fn invert(&mut self) {
+        for polygon in &mut self.polygons {
+            polygon.flip();
+        }
+        if let Some(plane) = &mut self.plane {
+            plane.flip();
+        }
+        if let Some(front) = &mut self.front {
+            front.invert();
+        }
+        if let Some(back) = &mut self.back {
+            back.invert();
+        }
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// The parts of `polygons` outside the solid of this tree
+    fn clip_polygons(&self, polygons: Vec<Polygon>, tolerance: f32) -> Vec<Polygon> {
+        let Some(plane) = self.plane else {
+            return polygons;
+        };
+        let (mut front, mut back, mut coplanar_back) = (Vec::new(), Vec::new(), Vec::new());
+        for polygon in polygons {
+            let mut coplanar_front = Vec::new();
+            plane.split(polygon, tolerance, &mut coplanar_front, &mut coplanar_back, &mut front, &mut back);
+            front.append(&mut coplanar_front);
+        }
+        back.append(&mut coplanar_back);
+        let mut front = match &self.front {
+            Some(node) => node.clip_polygons(front, tolerance),
+            None => front,
+        };
+        if let Some(node) = &self.back {
+            front.extend(node.clip_polygons(back, tolerance));
+        }
+        front
+    }
+
+    /// Remove the polygons of this tree inside the solid of `other`
+    fn clip_to(&mut self, other: &BspNode, tolerance: f32) {
+        self.polygons = other.clip_polygons(std::mem::take(&mut self.polygons), tolerance);
+        if let Some(front) = &mut self.front {
+            front.clip_to(other, tolerance);
+        }
+        if let Some(back) = &mut self.back {
+            back.clip_to(other, tolerance);
+        }
+    }
+
+    fn all_polygons(&self) -> Vec<Polygon> {
+        let mut polygons = self.polygons.clone();
+        if let Some(front) = &self.front {
+            polygons.extend(front.all_polygons());
+        }
+        if let Some(back) = &self.back {
+            polygons.extend(back.all_polygons());
+        }
+        polygons
+    }
+
+    fn build(&mut self, polygons: Vec<Polygon>, tolerance: f32) {
+        let Some(first) = polygons.first() else {
+            return;
+        };
+        let plane = *self.plane.get_or_insert(first.plane);
+        let (mut front, mut back, mut coplanar_back) = (Vec::new(), Vec::new(), Vec::new());
+        for polygon in polygons {
+            plane.split(polygon, tolerance, &mut self.polygons, &mut coplanar_back, &mut front, &mut back);
+        }
+        self.polygons.append(&mut coplanar_back);
+        if !front.is_empty() {
+            self.front.get_or_insert_with(Default::default).build(front, tolerance);
+        }
+        if !back.is_empty() {
+            self.back.get_or_insert_with(Default::default).build(back, tolerance);
+        }
+    }
+}
+
+/// World-space triangles of the active meshes of `scene`
+fn scene_polygons(scene: &USDSceneData) -> Vec<Polygon> {
+    let mut polygons = Vec::new();
+    for mesh in scene.meshes.iter().filter(|mesh| mesh.is_active()) {
+        for face in mesh.indices.chunks_exact(3) {
+            let Some(corners) = face.iter()
+                .map(|&index| mesh.vertices.get(index as usize).map(|&v| mesh.transform.transform_point3(v)))
+                .collect::<Option<Vec<_>>>() else { continue };
+            if let Some(plane) = Plane::from_points(corners[0], corners[1], corners[2]) {
+                polygons.push(Polygon { vertices: corners, plane });
+            }
+        }
+    }
+    polygons
+}
+
+impl BooleanLogic {
+    /// Combine the first input's meshes with the second's
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let mut inputs = inputs.into_iter();
+        let mut scene = match inputs.next() {
+            Some(NodeData::USDSceneData(scene)) => scene,
+            _ => return vec![NodeData::None],
+        };
+        let Some(NodeData::USDSceneData(other)) = inputs.next() else {
+            log::warn!("Boolean: no second scene connected; passing the first through");
+            return vec![NodeData::USDSceneData(scene)];
+        };
+
+        let mesh = self.combine(&scene, &other, format!("/Boolean_{}", node_id));
+        // The result only exists in memory, so the scene no longer matches any file
+        scene.stage_path = format!("procedural://boolean_node_{}", node_id);
+        scene.meshes = vec![mesh];
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Single world-space mesh of `a` combined with `b`
+    pub fn combine(&self, a: &USDSceneData, b: &USDSceneData, prim_path: String) -> USDMeshGeometry {
+        let tolerance = self.tolerance.max(0.0);
+        let mut a = BspNode::new(scene_polygons(a), tolerance);
+        let mut b = BspNode::new(scene_polygons(b), tolerance);
+        match self.operation {
+            BooleanOperation::Union => {
+                a.clip_to(&b, tolerance);
+                b.clip_to(&a, tolerance);
+                b.invert();
+                b.clip_to(&a, tolerance);
+                b.invert();
+                a.build(b.all_polygons(), tolerance);
+            }
+            BooleanOperation::Difference => {
+                a.invert();
+                a.clip_to(&b, tolerance);
+                b.clip_to(&a, tolerance);
+                b.invert();
+                b.clip_to(&a, tolerance);
+                b.invert();
+                a.build(b.all_polygons(), tolerance);
+                a.invert();
+            }
+            BooleanOperation::Intersection => {
+                a.invert();
+                b.clip_to(&a, tolerance);
+                b.invert();
+                a.clip_to(&b, tolerance);
+                b.clip_to(&a, tolerance);
+                a.build(b.all_polygons(), tolerance);
+                a.invert();
+            }
+        }
+        self.triangulate(a.all_polygons(), prim_path)
+    }
+
+    /// Mesh of the polygons, each with its own points and flat normal
+    fn triangulate(&self, polygons: Vec<Polygon>, prim_path: String) -> USDMeshGeometry {
+        let (mut vertices, mut normals, mut indices) = (Vec::new(), Vec::new(), Vec::new());
+        for polygon in polygons {
+            let first = vertices.len() as u32;
+            let count = polygon.vertices.len() as u32;
+            vertices.extend(&polygon.vertices);
+            match self.triangulation {
+                Triangulation::Fan => {
+                    for i in 1..count - 1 {
+                        indices.extend([first, first + i, first + i + 1]);
+                    }
+                }
+                Triangulation::Centroid => {
+                    let center = first + count;
+                    vertices.push(polygon.vertices.iter().sum::<Vec3>() / count as f32);
+                    for i in 0..count {
+                        indices.extend([center, first + i, first + (i + 1) % count]);
+                    }
+                }
+            }
+            normals.resize(vertices.len(), polygon.plane.normal);
+        }
+        USDMeshGeometry {
+            prim_path,
+            vertices,
+            indices,
+            normals,
+            uvs: Vec::new(),
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unit cube centered at `center`, wound outward
+    fn cube(center: Vec3) -> USDSceneData {
+        let vertices = [
+            [-0.5, -0.5, -0.5], [0.5, -0.5, -0.5], [0.5, 0.5, -0.5], [-0.5, 0.5, -0.5],
+            [-0.5, -0.5, 0.5], [0.5, -0.5, 0.5], [0.5, 0.5, 0.5], [-0.5, 0.5, 0.5],
+        ];
+        USDSceneData {
+            stage_path: "procedural://test".to_string(),
+            meshes: vec![USDMeshGeometry {
+                prim_path: "/Cube".to_string(),
+                vertices: vertices.iter().map(|&v| Vec3::from(v)).collect(),
+                indices: vec![
+                    0, 3, 2, 0, 2, 1, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4,
+                    3, 7, 6, 3, 6, 2, 0, 4, 7, 0, 7, 3, 1, 2, 6, 1, 6, 5,
+                ],
+                normals: vec![],
+                uvs: vec![],
+                vertex_colors: None,
+                transform: Mat4::from_translation(center),
+                primvars: vec![],
+                attributes: vec![],
+            }],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: vec![],
+        }
+    }
+
+    /// Enclosed volume; negative when the mesh is wound inward
+    fn volume(mesh: &USDMeshGeometry) -> f32 {
+        mesh.indices.chunks_exact(3)
+            .map(|face| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[face[i] as usize]);
+                a.dot(b.cross(c)) / 6.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_operations_give_the_expected_volumes() {
+        let (a, b) = (cube(Vec3::ZERO), cube(Vec3::new(0.5, 0.0, 0.0)));
+        for (operation, expected) in [
+            (BooleanOperation::Union, 1.5),
+            (BooleanOperation::Difference, 0.5),
+            (BooleanOperation::Intersection, 0.5),
+        ] {
+            for triangulation in [Triangulation::Fan, Triangulation::Centroid] {
+                let logic = BooleanLogic { operation, triangulation, ..Default::default() };
+                let mesh = logic.combine(&a, &b, "/Boolean".to_string());
+                assert!((volume(&mesh) - expected).abs() < 1e-4, "{:?} {:?}: {}", operation, triangulation, volume(&mesh));
+                assert_eq!(mesh.normals.len(), mesh.vertices.len());
+            }
+        }
+
+        // Disjoint cubes have nothing in common
+        let logic = BooleanLogic { operation: BooleanOperation::Intersection, ..Default::default() };
+        assert!(logic.combine(&a, &cube(Vec3::new(3.0, 0.0, 0.0)), "/Boolean".to_string()).indices.is_empty());
+
+        let outputs = BooleanLogic::default().process(2, vec![NodeData::USDSceneData(a), NodeData::USDSceneData(b)]);
+        let NodeData::USDSceneData(scene) = &outputs[0] else { panic!("expected a USD scene") };
+        assert_eq!(scene.meshes.len(), 1);
+        assert_eq!(scene.stage_path, "procedural://boolean_node_2");
+    }
+}
@@ -0,0 +1,51 @@
+//! Boolean node module - union, difference and intersection of meshes
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: BSP-tree clipping of the two meshes and triangulating the result
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::BooleanLogic;
+pub use parameters::BooleanNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::BooleanNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Boolean",
+            "Boolean",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Combines the meshes of two USD scenes by union, difference or intersection into a single mesh"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("◐")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("A", crate::nodes::DataType::Any)
+                .with_description("USD scene whose meshes are combined; a difference carves from these"),
+            crate::nodes::PortDefinition::required("B", crate::nodes::DataType::Any)
+                .with_description("USD scene whose meshes are combined with A's"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("Scene A with its meshes replaced by the result, in world space"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::choice("operation", "Operation", logic::BooleanOperation::NAMES.to_vec(), "Union")
+                .with_tooltip("Union keeps everything inside either mesh, Difference removes B from A, Intersection keeps what is inside both"),
+            crate::nodes::ParameterDefinition::float("tolerance", "Tolerance", 1e-5)
+                .with_min(0.0)
+                .with_step(1e-5)
+                .with_tooltip("Distance within which points count as lying on a face; raise it when coplanar faces leave slivers"),
+            crate::nodes::ParameterDefinition::choice("triangulation", "Triangulation", logic::Triangulation::NAMES.to_vec(), "Fan")
+                .with_tooltip("Fan splits cut faces from a corner; Centroid adds a center point, giving fewer thin triangles"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "boolean", "csg", "union", "difference", "subtract", "intersection", "mesh"])
+        .with_subtitle(|node| Some(parameters::BooleanNode::create_logic(node).operation.name().to_string()))
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
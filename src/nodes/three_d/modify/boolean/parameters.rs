@@ -0,0 +1,30 @@
+//! Boolean node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{BooleanLogic, BooleanOperation, Triangulation};
+
+/// Boolean node
+#[derive(Debug, Clone, Default)]
+pub struct BooleanNode;
+
+impl BooleanNode {
+    /// Build BooleanLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> BooleanLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        BooleanLogic {
+            operation: BooleanOperation::from_name(&text("operation")).unwrap_or_default(),
+            tolerance: node.parameters.get("tolerance")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(BooleanLogic::default().tolerance),
+            triangulation: Triangulation::from_name(&text("triangulation")).unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
@@ -0,0 +1,429 @@
+//! Decimate node functional operations - quadric edge-collapse simplification
+//!
+//! Every point accumulates the planes of its faces as an error quadric; the
+//! edge whose collapse moves the surface least is collapsed first, placing
+//! the merged point where the combined quadric is smallest. Collapses that
+//! would flip a face are skipped. Open edges, including UV seams where points
+//! are split, get extra constraint planes so outlines and seams stay put.
+//! Per-point, per-face and per-corner data follow the points and faces kept.
+
+use glam::{DMat3, DMat4, DVec3, Vec3};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::modify::prune::logic::matches_prim;
+use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDMeshGeometry};
+
+/// Weight of the planes holding open edges in place
+const BOUNDARY_WEIGHT: f64 = 1000.0;
+/// Collapses turning a face's normal by more than this (as a cosine) are skipped
+const MIN_NORMAL_DOT: f64 = 0.2;
+
+/// How the face budget is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimateTarget {
+    /// A fraction of each mesh's faces
+    #[default]
+    Ratio,
+    /// A face count per mesh
+    FaceCount,
+}
+
+impl DecimateTarget {
+    pub const NAMES: [&'static str; 2] = ["Ratio", "Face Count"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Ratio" => Some(Self::Ratio),
+            "Face Count" => Some(Self::FaceCount),
+            _ => None,
+        }
+    }
+}
+
+/// Core Decimate configuration
+#[derive(Debug, Clone)]
+pub struct DecimateLogic {
+    /// Prim patterns (as in the Prune node) of the meshes to simplify; empty simplifies every mesh
+    pub prims: Vec<String>,
+    pub target: DecimateTarget,
+    /// Fraction of the faces kept in Ratio mode
+    pub ratio: f32,
+    /// Faces kept in Face Count mode
+    pub face_count: usize,
+    /// Hold open edges and UV seams in place
+    pub preserve_boundaries: bool,
+}
+
+impl Default for DecimateLogic {
+    fn default() -> Self {
+        Self {
+            prims: Vec::new(),
+            target: DecimateTarget::Ratio,
+            ratio: 0.5,
+            face_count: 1000,
+            preserve_boundaries: true,
+        }
+    }
+}
+
+/// A possible collapse of point `remove` into point `keep`
+struct Candidate {
+    cost: f64,
+    keep: usize,
+    remove: usize,
+    /// Versions of both points when the candidate was computed; stale once either changes
+    versions: (u32, u32),
+    position: DVec3,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Reversed, so the max-heap pops the cheapest collapse
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// Quadric of the plane through `point` with unit `normal`
+fn plane_quadric(normal: DVec3, point: DVec3, weight: f64) -> DMat4 {
+    let plane = normal.extend(-normal.dot(point));
+    DMat4::from_cols(plane * plane.x, plane * plane.y, plane * plane.z, plane * plane.w) * weight
+}
+
+fn quadric_error(quadric: &DMat4, position: DVec3) -> f64 {
+    let v = position.extend(1.0);
+    v.dot(*quadric * v)
+}
+
+/// Mesh being simplified
+struct Simplifier {
+    positions: Vec<DVec3>,
+    faces: Vec<[usize; 3]>,
+    face_alive: Vec<bool>,
+    faces_of_point: Vec<Vec<usize>>,
+    quadrics: Vec<DMat4>,
+    versions: Vec<u32>,
+    point_alive: Vec<bool>,
+}
+
+impl Simplifier {
+    fn new(mesh: &USDMeshGeometry, preserve_boundaries: bool) -> Self {
+        let positions: Vec<DVec3> = mesh.vertices.iter().map(|v| v.as_dvec3()).collect();
+        let faces: Vec<[usize; 3]> = mesh.indices.chunks_exact(3)
+            .map(|face| [face[0] as usize, face[1] as usize, face[2] as usize])
+            .collect();
+        let mut faces_of_point = vec![Vec::new(); positions.len()];
+        let mut quadrics = vec![DMat4::ZERO; positions.len()];
+        let mut edge_faces: HashMap<(usize, usize), usize> = HashMap::new();
+        for (face_index, face) in faces.iter().enumerate() {
+            let [a, b, c] = face.map(|index| positions[index]);
+            let normal = (b - a).cross(c - a);
+            let area = normal.length() * 0.5;
+            let quadric = plane_quadric(normal.normalize_or_zero(), a, area);
+            for (corner, &point) in face.iter().enumerate() {
+                faces_of_point[point].push(face_index);
+                quadrics[point] += quadric;
+                let next = face[(corner + 1) % 3];
+                *edge_faces.entry((point.min(next), point.max(next))).or_default() += 1;
+            }
+        }
+        if preserve_boundaries {
+            for face in &faces {
+                let [a, b, c] = face.map(|index| positions[index]);
+                let normal = (b - a).cross(c - a).normalize_or_zero();
+                for corner in 0..3 {
+                    let (p, q) = (face[corner], face[(corner + 1) % 3]);
+                    if edge_faces.get(&(p.min(q), p.max(q))) != Some(&1) {
+                        continue;
+                    }
+                    let edge = positions[q] - positions[p];
+                    let side = edge.cross(normal).normalize_or_zero();
+                    let quadric = plane_quadric(side, positions[p], BOUNDARY_WEIGHT * edge.length_squared());
+                    quadrics[p] += quadric;
+                    quadrics[q] += quadric;
+                }
+            }
+        }
+        Self {
+            face_alive: vec![true; faces.len()],
+            versions: vec![0; positions.len()],
+            point_alive: vec![true; positions.len()],
+            positions,
+            faces,
+            faces_of_point,
+            quadrics,
+        }
+    }
+
+    /// Cheapest way of collapsing the edge between `keep` and `remove`
+    fn candidate(&self, keep: usize, remove: usize) -> Candidate {
+        let quadric = self.quadrics[keep] + self.quadrics[remove];
+        let (p, q) = (self.positions[keep], self.positions[remove]);
+        let mut options = vec![p, q, (p + q) * 0.5];
+        // The quadric's minimum, when it has one
+        let system = DMat3::from_mat4(quadric);
+        if system.determinant().abs() > 1e-12 {
+            options.insert(0, system.inverse() * -quadric.col(3).truncate());
+        }
+        let (cost, position) = options.into_iter()
+            .map(|position| (quadric_error(&quadric, position), position))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap_or((0.0, p));
+        Candidate { cost, keep, remove, versions: (self.versions[keep], self.versions[remove]), position }
+    }
+
+    /// Live points sharing a live face with `point`
+    fn neighbours(&self, point: usize) -> HashSet<usize> {
+        self.faces_of_point[point].iter()
+            .filter(|&&face| self.face_alive[face])
+            .flat_map(|&face| self.faces[face])
+            .filter(|&other| other != point)
+            .collect()
+    }
+
+    /// False when moving both points to `position` would flip or squash one of their faces
+    fn keeps_orientation(&self, candidate: &Candidate) -> bool {
+        for &point in &[candidate.keep, candidate.remove] {
+            for &face_index in &self.faces_of_point[point] {
+                let face = self.faces[face_index];
+                if !self.face_alive[face_index] || (face.contains(&candidate.keep) && face.contains(&candidate.remove)) {
+                    continue;
+                }
+                let [a, b, c] = face.map(|index| self.positions[index]);
+                let before = (b - a).cross(c - a);
+                let [a, b, c] = face.map(|index| if index == point { candidate.position } else { self.positions[index] });
+                let after = (b - a).cross(c - a);
+                if before.normalize_or_zero().dot(after.normalize_or_zero()) < MIN_NORMAL_DOT {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Collapse edges until at most `target` faces are left or no collapse is possible
+    fn simplify(&mut self, target: usize) {
+        let mut alive = self.faces.len();
+        let mut heap = BinaryHeap::new();
+        let mut edges = HashSet::new();
+        for face in &self.faces {
+            for corner in 0..3 {
+                let (p, q) = (face[corner], face[(corner + 1) % 3]);
+                if edges.insert((p.min(q), p.max(q))) {
+                    heap.push(self.candidate(p.min(q), p.max(q)));
+                }
+            }
+        }
+
+        while alive > target {
+            let Some(candidate) = heap.pop() else { break };
+            let (keep, remove) = (candidate.keep, candidate.remove);
+            if !self.point_alive[keep] || !self.point_alive[remove]
+                || candidate.versions != (self.versions[keep], self.versions[remove])
+                || !self.keeps_orientation(&candidate)
+            {
+                continue;
+            }
+
+            self.positions[keep] = candidate.position;
+            self.quadrics[keep] = self.quadrics[keep] + self.quadrics[remove];
+            self.point_alive[remove] = false;
+            self.versions[keep] += 1;
+            for face_index in std::mem::take(&mut self.faces_of_point[remove]) {
+                if !self.face_alive[face_index] {
+                    continue;
+                }
+                let face = &mut self.faces[face_index];
+                if face.contains(&keep) {
+                    self.face_alive[face_index] = false;
+                    alive -= 1;
+                } else {
+                    for index in face.iter_mut().filter(|index| **index == remove) {
+                        *index = keep;
+                    }
+                    self.faces_of_point[keep].push(face_index);
+                }
+            }
+            for neighbour in self.neighbours(keep) {
+                heap.push(self.candidate(keep, neighbour));
+            }
+        }
+    }
+}
+
+/// Values of the kept points, faces or corners of an array with one value per original one
+fn pick<T: Clone>(values: &mut Vec<T>, original_len: usize, kept: &[usize]) {
+    if values.len() == original_len {
+        *values = kept.iter().map(|&index| values[index].clone()).collect();
+    }
+}
+
+fn pick_primvar(values: &mut PrimvarValues, original_len: usize, kept: &[usize]) {
+    match values {
+        PrimvarValues::Float(values) => pick(values, original_len, kept),
+        PrimvarValues::Float2(values) => pick(values, original_len, kept),
+        PrimvarValues::Float3(values) => pick(values, original_len, kept),
+        PrimvarValues::Int(values) => pick(values, original_len, kept),
+        PrimvarValues::String(values) => pick(values, original_len, kept),
+    }
+}
+
+impl DecimateLogic {
+    /// Simplify the matching meshes of the incoming scene
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(mut scene) = input else {
+            return vec![input];
+        };
+        for mesh in scene.meshes.iter_mut()
+            .filter(|mesh| self.prims.is_empty() || self.prims.iter().any(|pattern| matches_prim(pattern, &mesh.prim_path)))
+        {
+            self.apply(mesh);
+        }
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Faces `mesh` is reduced to
+    pub fn target_faces(&self, face_count: usize) -> usize {
+        match self.target {
+            DecimateTarget::Ratio => (face_count as f32 * self.ratio.clamp(0.0, 1.0)).ceil() as usize,
+            DecimateTarget::FaceCount => self.face_count.min(face_count),
+        }
+    }
+
+    /// Simplify `mesh` in place
+    pub fn apply(&self, mesh: &mut USDMeshGeometry) {
+        let face_count = mesh.indices.len() / 3;
+        let target = self.target_faces(face_count);
+        if target >= face_count || mesh.indices.iter().any(|&index| index as usize >= mesh.vertices.len()) {
+            return;
+        }
+        let mut simplifier = Simplifier::new(mesh, self.preserve_boundaries);
+        simplifier.simplify(target);
+
+        let kept_faces: Vec<usize> = (0..face_count).filter(|&face| simplifier.face_alive[face]).collect();
+        let kept_corners: Vec<usize> = kept_faces.iter().flat_map(|&face| [face * 3, face * 3 + 1, face * 3 + 2]).collect();
+        let mut kept_points = Vec::new();
+        let mut new_index = vec![u32::MAX; mesh.vertices.len()];
+        let mut indices = Vec::with_capacity(kept_corners.len());
+        for &face in &kept_faces {
+            for point in simplifier.faces[face] {
+                if new_index[point] == u32::MAX {
+                    new_index[point] = kept_points.len() as u32;
+                    kept_points.push(point);
+                }
+                indices.push(new_index[point]);
+            }
+        }
+
+        let point_count = mesh.vertices.len();
+        mesh.vertices = kept_points.iter().map(|&point| simplifier.positions[point].as_vec3()).collect();
+        pick(&mut mesh.normals, point_count, &kept_points);
+        pick(&mut mesh.uvs, point_count, &kept_points);
+        if let Some(colors) = &mut mesh.vertex_colors {
+            pick(colors, point_count, &kept_points);
+        }
+        for primvar in &mut mesh.primvars {
+            let kept: &[usize] = match primvar.interpolation.as_str() {
+                "vertex" | "varying" => &kept_points,
+                "uniform" => &kept_faces,
+                "faceVarying" => &kept_corners,
+                _ => continue,
+            };
+            let original_len = match primvar.interpolation.as_str() {
+                "vertex" | "varying" => point_count,
+                "uniform" => face_count,
+                _ => face_count * 3,
+            };
+            match &mut primvar.indices {
+                Some(indices) => pick(indices, original_len, kept),
+                None => pick_primvar(&mut primvar.values, original_len, kept),
+            }
+        }
+        mesh.indices = indices;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Mat4, Vec2};
+
+    /// Flat grid of `size` × `size` quads on the ground plane, covering [0, 1]²
+    fn grid(size: usize) -> USDMeshGeometry {
+        let mut vertices = Vec::new();
+        let mut uvs = Vec::new();
+        for z in 0..=size {
+            for x in 0..=size {
+                let uv = Vec2::new(x as f32, z as f32) / size as f32;
+                vertices.push(Vec3::new(uv.x, 0.0, uv.y));
+                uvs.push(uv);
+            }
+        }
+        let mut indices = Vec::new();
+        let row = size as u32 + 1;
+        for z in 0..size as u32 {
+            for x in 0..size as u32 {
+                let corner = z * row + x;
+                indices.extend([corner, corner + row, corner + 1, corner + 1, corner + row, corner + row + 1]);
+            }
+        }
+        USDMeshGeometry {
+            prim_path: "/World/Grid".to_string(),
+            normals: vec![Vec3::Y; vertices.len()],
+            vertices,
+            indices,
+            uvs,
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_flat_grid_simplifies_without_moving_its_outline() {
+        let mut mesh = grid(10);
+        let logic = DecimateLogic { ratio: 0.25, ..Default::default() };
+        assert_eq!(logic.target_faces(200), 50);
+        logic.apply(&mut mesh);
+
+        let faces = mesh.indices.len() / 3;
+        assert!(faces > 0 && faces <= 50, "{} faces", faces);
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+        assert_eq!(mesh.uvs.len(), mesh.vertices.len());
+        assert!(mesh.indices.iter().all(|&index| (index as usize) < mesh.vertices.len()));
+        assert!(mesh.vertices.iter().all(|v| v.y.abs() < 1e-5));
+        // The four corners survive and nothing leaves the square
+        for corner in [Vec3::ZERO, Vec3::X, Vec3::Z, Vec3::new(1.0, 0.0, 1.0)] {
+            assert!(mesh.vertices.iter().any(|v| (*v - corner).length() < 1e-4), "lost corner {:?}", corner);
+        }
+        assert!(mesh.vertices.iter().all(|v| (-1e-4..=1.0 + 1e-4).contains(&v.x) && (-1e-4..=1.0 + 1e-4).contains(&v.z)));
+        // No face was flipped
+        for face in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[face[i] as usize]);
+            assert!((b - a).cross(c - a).y > 0.0);
+        }
+
+        // A face count above the mesh's leaves it alone
+        let mut untouched = grid(2);
+        DecimateLogic { target: DecimateTarget::FaceCount, face_count: 100, ..Default::default() }.apply(&mut untouched);
+        assert_eq!(untouched.indices.len(), 24);
+    }
+}
@@ -0,0 +1,61 @@
+//! Decimate node module - polygon reduction for lighter meshes
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Quadric edge collapse and remapping the mesh's data
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::DecimateLogic;
+pub use parameters::DecimateNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::DecimateNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Decimate",
+            "Decimate",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Reduces the faces of USD meshes to a ratio or count, keeping their shape, for lighter interactive previews"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("▽")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data to simplify"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data with simplified meshes"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("prims", "Prims", "")
+                .with_tooltip("Prim patterns of the meshes to simplify, as in the Prune node; empty simplifies every mesh"),
+            crate::nodes::ParameterDefinition::choice("target", "Target", logic::DecimateTarget::NAMES.to_vec(), "Ratio")
+                .with_tooltip("Keep a fraction of each mesh's faces, or a fixed number of faces per mesh"),
+            crate::nodes::ParameterDefinition::float("ratio", "Ratio", 0.5)
+                .with_range(0.0, 1.0)
+                .with_step(0.01)
+                .with_tooltip("Fraction of the faces kept in Ratio mode"),
+            crate::nodes::ParameterDefinition::integer("face_count", "Face Count", 1000)
+                .with_range(0.0, 10_000_000.0)
+                .with_soft_range(0.0, 100_000.0)
+                .with_tooltip("Faces kept per mesh in Face Count mode"),
+            crate::nodes::ParameterDefinition::boolean("preserve_boundaries", "Preserve Boundaries", true)
+                .with_tooltip("Hold open edges and UV seams in place so outlines and textures don't tear"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "decimate", "simplify", "reduce", "polyreduce", "lod", "optimize"])
+        .with_subtitle(|node| {
+            let logic = parameters::DecimateNode::create_logic(node);
+            Some(match logic.target {
+                logic::DecimateTarget::Ratio => format!("{:.0}%", logic.ratio * 100.0),
+                logic::DecimateTarget::FaceCount => format!("{} faces", logic.face_count),
+            })
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
@@ -0,0 +1,38 @@
+//! Decimate node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::prune::PruneLogic;
+use super::logic::{DecimateLogic, DecimateTarget};
+
+/// Decimate node
+#[derive(Debug, Clone, Default)]
+pub struct DecimateNode;
+
+impl DecimateNode {
+    /// Build DecimateLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> DecimateLogic {
+        let defaults = DecimateLogic::default();
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        DecimateLogic {
+            prims: PruneLogic::parse_patterns(&text("prims")),
+            target: DecimateTarget::from_name(&text("target")).unwrap_or_default(),
+            ratio: node.parameters.get("ratio")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.ratio),
+            face_count: node.parameters.get("face_count")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some((*i).max(0) as usize) } else { None })
+                .unwrap_or(defaults.face_count),
+            preserve_boundaries: node.parameters.get("preserve_boundaries")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.preserve_boundaries),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
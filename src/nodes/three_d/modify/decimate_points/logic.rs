@@ -0,0 +1,156 @@
+//! Decimate Points node functional operations - thinning point clouds
+//!
+//! Ratio mode keeps an evenly spread, deterministic subset of the points in
+//! their original order, so scan lines stay recognisable. Voxel Grid mode
+//! merges all points within each grid cell into their average position and
+//! color, which evens out the density of scans that are dense up close.
+
+use std::collections::HashMap;
+use glam::{IVec3, Vec3};
+use crate::nodes::interface::{NodeData, PointCloudData};
+
+/// How points are thinned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointDecimateMode {
+    /// Keep a fraction of the points
+    #[default]
+    Ratio,
+    /// Average the points of each grid cell
+    VoxelGrid,
+}
+
+impl PointDecimateMode {
+    pub const NAMES: [&'static str; 2] = ["Ratio", "Voxel Grid"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Ratio" => Some(Self::Ratio),
+            "Voxel Grid" => Some(Self::VoxelGrid),
+            _ => None,
+        }
+    }
+}
+
+/// Core Decimate Points configuration
+#[derive(Debug, Clone)]
+pub struct DecimatePointsLogic {
+    pub mode: PointDecimateMode,
+    /// Fraction of the points kept in Ratio mode
+    pub ratio: f32,
+    /// Cell edge length in Voxel Grid mode
+    pub voxel_size: f32,
+}
+
+impl Default for DecimatePointsLogic {
+    fn default() -> Self {
+        Self {
+            mode: PointDecimateMode::Ratio,
+            ratio: 0.25,
+            voxel_size: 0.05,
+        }
+    }
+}
+
+impl DecimatePointsLogic {
+    /// Thin the input point cloud; other data passes through unchanged
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::PointCloud(points) = input else {
+            return vec![input];
+        };
+        vec![NodeData::PointCloud(self.apply(&points))]
+    }
+
+    /// The thinned copy of `points`
+    pub fn apply(&self, points: &PointCloudData) -> PointCloudData {
+        match self.mode {
+            PointDecimateMode::Ratio => self.keep_ratio(points),
+            PointDecimateMode::VoxelGrid if self.voxel_size > 0.0 => self.merge_voxels(points),
+            PointDecimateMode::VoxelGrid => points.clone(),
+        }
+    }
+
+    /// Keep point i whenever the running count `(i + 1) * ratio` reaches a new whole number
+    fn keep_ratio(&self, points: &PointCloudData) -> PointCloudData {
+        let ratio = self.ratio.clamp(0.0, 1.0) as f64;
+        let kept: Vec<usize> = (0..points.point_count())
+            .filter(|&i| ((i + 1) as f64 * ratio).floor() > (i as f64 * ratio).floor())
+            .collect();
+        let colors = if points.has_colors() {
+            kept.iter().map(|&i| points.colors[i]).collect()
+        } else {
+            Vec::new()
+        };
+        PointCloudData {
+            source: points.source.clone(),
+            positions: kept.iter().map(|&i| points.positions[i]).collect(),
+            colors,
+        }
+    }
+
+    /// One averaged point per occupied cell, in the order cells are first seen
+    fn merge_voxels(&self, points: &PointCloudData) -> PointCloudData {
+        let has_colors = points.has_colors();
+        let mut cells: HashMap<IVec3, usize> = HashMap::new();
+        // Sums of position and color, and the number of points, per cell
+        let mut sums: Vec<(Vec3, Vec3, u32)> = Vec::new();
+        for (i, position) in points.positions.iter().enumerate() {
+            let cell = (*position / self.voxel_size).floor().as_ivec3();
+            let index = *cells.entry(cell).or_insert_with(|| {
+                sums.push((Vec3::ZERO, Vec3::ZERO, 0));
+                sums.len() - 1
+            });
+            let sum = &mut sums[index];
+            sum.0 += *position;
+            if has_colors {
+                sum.1 += points.colors[i];
+            }
+            sum.2 += 1;
+        }
+        PointCloudData {
+            source: points.source.clone(),
+            positions: sums.iter().map(|(position, _, count)| *position / *count as f32).collect(),
+            colors: if has_colors {
+                sums.iter().map(|(_, color, count)| *color / *count as f32).collect()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(count: usize) -> PointCloudData {
+        PointCloudData {
+            source: String::new(),
+            positions: (0..count).map(|i| Vec3::new(i as f32 * 0.1, 0.0, 0.0)).collect(),
+            colors: (0..count).map(|i| Vec3::splat(i as f32 / count as f32)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_ratio_keeps_an_even_subset() {
+        let logic = DecimatePointsLogic { ratio: 0.25, ..Default::default() };
+        let thinned = logic.apply(&line(100));
+        assert_eq!(thinned.point_count(), 25);
+        assert_eq!(thinned.colors.len(), 25);
+        assert_eq!(thinned.positions[0], Vec3::new(0.3, 0.0, 0.0));
+        assert_eq!(logic.apply(&line(100)), thinned);
+    }
+
+    #[test]
+    fn test_voxel_grid_averages_each_cell() {
+        let logic = DecimatePointsLogic { mode: PointDecimateMode::VoxelGrid, voxel_size: 0.5, ..Default::default() };
+        let thinned = logic.apply(&line(10));
+        // Cells hold points 0-4 and 5-9
+        assert_eq!(thinned.point_count(), 2);
+        assert!((thinned.positions[0].x - 0.2).abs() < 1e-5);
+        assert!((thinned.positions[1].x - 0.7).abs() < 1e-5);
+        assert!((thinned.colors[0].x - 0.2).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,57 @@
+//! Decimate Points node module - thinning point clouds for interactive display
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Ratio subsampling and voxel grid averaging
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::DecimatePointsLogic;
+pub use parameters::DecimatePointsNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::DecimatePointsNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_DecimatePoints",
+            "Decimate Points",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Thins a point cloud to a fraction of its points or one point per grid cell"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("⁙")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Points", crate::nodes::DataType::PointCloud)
+                .with_description("Point cloud to thin"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Points", crate::nodes::DataType::PointCloud)
+                .with_description("The remaining points"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::choice("mode", "Mode", logic::PointDecimateMode::NAMES.to_vec(), "Ratio")
+                .with_tooltip("Keep an even fraction of the points, or average the points in each grid cell"),
+            crate::nodes::ParameterDefinition::float("ratio", "Ratio", 0.25)
+                .with_range(0.0, 1.0)
+                .with_step(0.01)
+                .with_tooltip("Fraction of the points kept in Ratio mode"),
+            crate::nodes::ParameterDefinition::float("voxel_size", "Voxel Size", 0.05)
+                .with_range(0.001, 10.0)
+                .with_step(0.001)
+                .with_tooltip("Edge length of the grid cells in Voxel Grid mode"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "decimate", "points", "point cloud", "thin", "subsample", "voxel", "lidar"])
+        .with_subtitle(|node| {
+            let logic = parameters::DecimatePointsNode::create_logic(node);
+            Some(match logic.mode {
+                logic::PointDecimateMode::Ratio => format!("{:.0}%", logic.ratio * 100.0),
+                logic::PointDecimateMode::VoxelGrid => format!("{} voxels", logic.voxel_size),
+            })
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
@@ -0,0 +1,30 @@
+//! Decimate Points node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{DecimatePointsLogic, PointDecimateMode};
+
+/// Decimate Points node
+#[derive(Debug, Clone, Default)]
+pub struct DecimatePointsNode;
+
+impl DecimatePointsNode {
+    /// Build DecimatePointsLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> DecimatePointsLogic {
+        let defaults = DecimatePointsLogic::default();
+        let float = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None });
+        DecimatePointsLogic {
+            mode: node.parameters.get("mode")
+                .and_then(|v| if let NodeData::String(s) = v { PointDecimateMode::from_name(s) } else { None })
+                .unwrap_or_default(),
+            ratio: float("ratio").unwrap_or(defaults.ratio),
+            voxel_size: float("voxel_size").unwrap_or(defaults.voxel_size),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,202 @@
+//! Layer Stack node functional operations - composing USD layers
+//!
+//! Each input is a layer; the stack lists them strongest first, as USD's
+//! `subLayerPaths` does, and muted layers are left out. When every active
+//! layer is a file on disk the stack is composed by USD itself, so opinions
+//! merge down to single attributes. Otherwise (procedural scenes, or no USD
+//! support) the stack is merged prim by prim: the strongest layer defining a
+//! prim path provides the whole prim.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{USDSceneData, GLOBAL_USD_ENGINE};
+
+/// Number of layer inputs
+pub const LAYER_COUNT: usize = 4;
+
+/// Stage path of each layer input at a node's last cook (None when unconnected)
+pub static LAYER_INPUTS: LazyLock<RwLock<HashMap<NodeId, Vec<Option<String>>>>> = LazyLock::new(|| {
+    RwLock::new(HashMap::new())
+});
+
+/// Stage paths last seen on a node's layer inputs
+pub fn layer_inputs_for(node_id: NodeId) -> Vec<Option<String>> {
+    LAYER_INPUTS.read().ok()
+        .and_then(|inputs| inputs.get(&node_id).cloned())
+        .unwrap_or_else(|| vec![None; LAYER_COUNT])
+}
+
+/// Core Layer Stack configuration
+#[derive(Debug, Clone)]
+pub struct LayerStackLogic {
+    /// Input indices, strongest first; always a permutation of all inputs
+    pub order: Vec<usize>,
+    /// Input indices left out of the composition
+    pub muted: HashSet<usize>,
+}
+
+impl Default for LayerStackLogic {
+    fn default() -> Self {
+        Self {
+            order: (0..LAYER_COUNT).collect(),
+            muted: HashSet::new(),
+        }
+    }
+}
+
+/// Input indices from 1-based text like "3 1 2", skipping invalid and repeated ones
+pub fn parse_indices(text: &str) -> Vec<usize> {
+    let mut indices = Vec::new();
+    for index in text.split(|c: char| c == ',' || c.is_whitespace()).filter_map(|part| part.parse::<usize>().ok()) {
+        if (1..=LAYER_COUNT).contains(&index) && !indices.contains(&(index - 1)) {
+            indices.push(index - 1);
+        }
+    }
+    indices
+}
+
+/// "3 1 2" for input indices [2, 0, 1]
+pub fn format_indices(indices: &[usize]) -> String {
+    indices.iter().map(|index| (index + 1).to_string()).collect::<Vec<_>>().join(" ")
+}
+
+impl LayerStackLogic {
+    /// Order from the text of the order parameter; inputs it leaves out go last
+    pub fn from_text(order: &str, muted: &str) -> Self {
+        let mut order = parse_indices(order);
+        let missing: Vec<usize> = (0..LAYER_COUNT).filter(|index| !order.contains(index)).collect();
+        order.extend(missing);
+        Self {
+            order,
+            muted: parse_indices(muted).into_iter().collect(),
+        }
+    }
+
+    /// Compose the layer inputs
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let scenes: Vec<Option<USDSceneData>> = (0..LAYER_COUNT)
+            .map(|index| match inputs.get(index) {
+                Some(NodeData::USDSceneData(scene)) => Some(scene.clone()),
+                _ => None,
+            })
+            .collect();
+        if let Ok(mut layer_inputs) = LAYER_INPUTS.write() {
+            layer_inputs.insert(node_id, scenes.iter().map(|scene| scene.as_ref().map(|scene| scene.stage_path.clone())).collect());
+        }
+
+        let stack: Vec<&USDSceneData> = self.order.iter()
+            .filter(|index| !self.muted.contains(index))
+            .filter_map(|&index| scenes[index].as_ref())
+            .collect();
+        match stack.as_slice() {
+            [] => return vec![NodeData::None],
+            [only] => return vec![NodeData::USDSceneData((*only).clone())],
+            _ => {}
+        }
+
+        let on_disk = stack.iter().all(|scene| Path::new(&scene.stage_path).is_file());
+        if on_disk {
+            let layer_paths: Vec<String> = stack.iter().map(|scene| scene.stage_path.clone()).collect();
+            let output_path = std::env::temp_dir()
+                .join(format!("nodle_layer_stack_{}.usda", node_id))
+                .to_string_lossy()
+                .to_string();
+            if let Ok(mut engine) = GLOBAL_USD_ENGINE.lock() {
+                match engine.compose_layers(&layer_paths, &output_path) {
+                    Ok(composed) => return vec![NodeData::USDSceneData(composed)],
+                    Err(e) => log::warn!("Layer Stack: {}; merging prims instead", e),
+                }
+            }
+        }
+        vec![NodeData::USDSceneData(merge_layers(&stack, node_id))]
+    }
+}
+
+/// Merge scenes strongest first: the first layer defining a prim path provides it
+pub fn merge_layers(stack: &[&USDSceneData], node_id: NodeId) -> USDSceneData {
+    let mut composed = USDSceneData {
+        stage_path: format!("procedural://layer_stack_node_{}", node_id),
+        meshes: Vec::new(),
+        lights: Vec::new(),
+        materials: Vec::new(),
+        // Stage metadata comes from the strongest layer
+        up_axis: stack.first().map_or_else(|| "Y".to_string(), |scene| scene.up_axis.clone()),
+        instancers: Vec::new(),
+    };
+    let mut defined = HashSet::new();
+    for scene in stack {
+        for mesh in &scene.meshes {
+            if defined.insert(mesh.prim_path.clone()) {
+                composed.meshes.push(mesh.clone());
+            }
+        }
+        for light in &scene.lights {
+            if defined.insert(light.prim_path.clone()) {
+                composed.lights.push(light.clone());
+            }
+        }
+        for material in &scene.materials {
+            if defined.insert(material.prim_path.clone()) {
+                composed.materials.push(material.clone());
+            }
+        }
+        for instancer in &scene.instancers {
+            if defined.insert(instancer.prim_path.clone()) {
+                composed.instancers.push(instancer.clone());
+            }
+        }
+    }
+    composed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Mat4, Vec3};
+    use crate::workspaces::three_d::usd::usd_engine::USDMeshGeometry;
+
+    fn layer(name: &str, prims: &[&str]) -> USDSceneData {
+        USDSceneData {
+            stage_path: format!("procedural://{}", name),
+            meshes: prims.iter().map(|path| USDMeshGeometry {
+                prim_path: path.to_string(),
+                vertices: vec![Vec3::ZERO],
+                indices: vec![],
+                normals: vec![],
+                uvs: vec![],
+                vertex_colors: None,
+                transform: Mat4::from_translation(Vec3::splat(name.len() as f32)),
+                primvars: vec![],
+                attributes: vec![],
+            }).collect(),
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_stronger_layers_win_and_muted_layers_drop_out() {
+        let logic = LayerStackLogic::from_text("2 1", "3");
+        assert_eq!(logic.order, vec![1, 0, 2, 3]);
+        assert_eq!(format_indices(&logic.order), "2 1 3 4");
+
+        let inputs = vec![
+            NodeData::USDSceneData(layer("base", &["/World/Chair", "/World/Table"])),
+            NodeData::USDSceneData(layer("override", &["/World/Chair"])),
+            NodeData::USDSceneData(layer("extra", &["/World/Lamp"])),
+        ];
+        let outputs = logic.process(7, inputs);
+        let NodeData::USDSceneData(composed) = &outputs[0] else { panic!("expected a USD scene") };
+        let chair = composed.meshes.iter().find(|mesh| mesh.prim_path == "/World/Chair").unwrap();
+        // "override" is strongest, so its chair wins
+        assert_eq!(chair.transform, Mat4::from_translation(Vec3::splat(8.0)));
+        assert_eq!(composed.meshes.len(), 2);
+        assert!(composed.meshes.iter().all(|mesh| mesh.prim_path != "/World/Lamp"));
+        assert_eq!(layer_inputs_for(7)[2].as_deref(), Some("procedural://extra"));
+    }
+}
@@ -0,0 +1,49 @@
+//! Layer Stack node module - compose USD layers in a chosen order
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Ordering, muting and composing the layers
+//! - parameters.rs: The reorderable list of layers
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::LayerStackLogic;
+pub use parameters::LayerStackNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::LayerStackNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        let inputs = (1..=logic::LAYER_COUNT)
+            .map(|index| crate::nodes::PortDefinition::optional(&format!("Layer {}", index), crate::nodes::DataType::Any)
+                .with_description("USD scene data used as one layer of the stack"))
+            .collect();
+        crate::nodes::NodeMetadata::new(
+            "3D_LayerStack",
+            "Layer Stack",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Composes USD layers into one stage, strongest first, with layers that can be reordered and muted"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("📚")
+        .with_inputs(inputs)
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Stage", crate::nodes::DataType::Any)
+                .with_description("The composed stage"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("order", "Order", "1 2 3 4")
+                .with_tooltip("Layer inputs strongest first"),
+            crate::nodes::ParameterDefinition::string("muted", "Muted", "")
+                .with_tooltip("Layer inputs left out of the composition"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "usd", "layer", "layers", "sublayer", "compose", "stack", "mute"])
+        .with_subtitle(|node| {
+            let logic = parameters::LayerStackNode::create_logic(node);
+            (!logic.muted.is_empty()).then(|| format!("{} muted", logic.muted.len()))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
@@ -0,0 +1,76 @@
+//! Layer Stack node parameters - reordering and muting the layer inputs
+
+use egui::RichText;
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{format_indices, layer_inputs_for, LayerStackLogic};
+
+/// Layer Stack node
+#[derive(Debug, Clone, Default)]
+pub struct LayerStackNode;
+
+impl LayerStackNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        let layer_inputs = layer_inputs_for(node.id);
+
+        ui.heading("Layer Stack");
+        ui.label(RichText::new("Strongest first: higher layers override prims of lower ones").weak());
+        ui.separator();
+
+        let mut order = logic.order.clone();
+        let mut muted = logic.muted.clone();
+        let mut moved = None;
+        for (position, &index) in logic.order.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let mut active = !muted.contains(&index);
+                if ui.checkbox(&mut active, "").on_hover_text("Unchecked layers are muted").changed() {
+                    if active { muted.remove(&index); } else { muted.insert(index); }
+                }
+                if ui.add_enabled(position > 0, egui::Button::new("⬆").small()).clicked() {
+                    moved = Some((position, position - 1));
+                }
+                if ui.add_enabled(position + 1 < order.len(), egui::Button::new("⬇").small()).clicked() {
+                    moved = Some((position, position + 1));
+                }
+                let source = layer_inputs.get(index).cloned().flatten();
+                let text = RichText::new(format!("Layer {}: {}", index + 1, source.as_deref().unwrap_or("(not connected)")));
+                ui.label(if source.is_some() && active { text } else { text.weak() });
+            });
+        }
+        if let Some((from, to)) = moved {
+            order.swap(from, to);
+        }
+
+        if order != logic.order {
+            changes.push(ParameterChange {
+                parameter: "order".to_string(),
+                value: NodeData::String(format_indices(&order)),
+            });
+        }
+        if muted != logic.muted {
+            let mut muted: Vec<usize> = muted.into_iter().collect();
+            muted.sort_unstable();
+            changes.push(ParameterChange {
+                parameter: "muted".to_string(),
+                value: NodeData::String(format_indices(&muted)),
+            });
+        }
+        changes
+    }
+
+    /// Build LayerStackLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> LayerStackLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        LayerStackLogic::from_text(&text("order"), &text("muted"))
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
@@ -1,5 +1,25 @@
 //! 3D Modify nodes - operations that modify existing geometry
 
 pub mod reverse;
+pub mod transform;
+pub mod prune;
+pub mod variant_select;
+pub mod layer_stack;
+pub mod scatter;
+pub mod attribute_create;
+pub mod boolean;
+pub mod normals;
+pub mod decimate;
+pub mod decimate_points;
 
-pub use reverse::ReverseNode;
\ No newline at end of file
+pub use reverse::ReverseNode;
+pub use transform::TransformNode;
+pub use prune::PruneNode;
+pub use variant_select::VariantSelectNode;
+pub use layer_stack::LayerStackNode;
+pub use scatter::ScatterNode;
+pub use attribute_create::AttributeCreateNode;
+pub use boolean::BooleanNode;
+pub use normals::NormalsNode;
+pub use decimate::DecimateNode;
+pub use decimate_points::DecimatePointsNode;
\ No newline at end of file
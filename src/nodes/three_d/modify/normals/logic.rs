@@ -0,0 +1,285 @@
+//! Normals node functional operations - recomputing mesh normals
+//!
+//! Each corner of a triangle gets the weighted average of the normals of the
+//! faces around its point, skipping faces turned further than the cusp angle
+//! from its own. Where that gives a point's corners different normals (a hard
+//! edge), the point is split, copying its UVs, colors and per-point primvars
+//! to the new points, since the mesh stores one normal per point.
+
+use glam::Vec3;
+use crate::nodes::interface::NodeData;
+use crate::nodes::three_d::modify::prune::logic::matches_prim;
+use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDMeshGeometry};
+
+/// Corners whose normals are this close share a point
+const SAME_NORMAL_DOT: f32 = 0.9999;
+
+/// Which normals are computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalsMode {
+    /// Smooth across edges sharper than the cusp angle
+    #[default]
+    Vertex,
+    /// Flat; every face keeps its own normal
+    Face,
+}
+
+impl NormalsMode {
+    pub const NAMES: [&'static str; 2] = ["Vertex", "Face"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Vertex" => Some(Self::Vertex),
+            "Face" => Some(Self::Face),
+            _ => None,
+        }
+    }
+}
+
+/// How much each face contributes to a smoothed normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalWeighting {
+    /// By face area, so small bevel faces bend the normal little
+    #[default]
+    Area,
+    /// By the angle of the face at the point, independent of tessellation
+    Angle,
+    /// Every face the same
+    Equal,
+}
+
+impl NormalWeighting {
+    pub const NAMES: [&'static str; 3] = ["Area", "Angle", "Equal"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Area" => Some(Self::Area),
+            "Angle" => Some(Self::Angle),
+            "Equal" => Some(Self::Equal),
+            _ => None,
+        }
+    }
+}
+
+/// Core Normals configuration
+#[derive(Debug, Clone)]
+pub struct NormalsLogic {
+    /// Prim patterns (as in the Prune node) of the meshes to process; empty processes every mesh
+    pub prims: Vec<String>,
+    pub mode: NormalsMode,
+    /// Faces meeting at a sharper angle than this (degrees) stay hard-edged
+    pub cusp_angle: f32,
+    pub weighting: NormalWeighting,
+}
+
+impl Default for NormalsLogic {
+    fn default() -> Self {
+        Self {
+            prims: Vec::new(),
+            mode: NormalsMode::Vertex,
+            cusp_angle: 60.0,
+            weighting: NormalWeighting::Area,
+        }
+    }
+}
+
+impl NormalsLogic {
+    /// Recompute the normals of the matching meshes of the incoming scene
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(mut scene) = input else {
+            return vec![input];
+        };
+        for mesh in scene.meshes.iter_mut()
+            .filter(|mesh| self.prims.is_empty() || self.prims.iter().any(|pattern| matches_prim(pattern, &mesh.prim_path)))
+        {
+            self.apply(mesh);
+        }
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Normal of each corner of `mesh`'s triangles, in index order
+    fn corner_normals(&self, mesh: &USDMeshGeometry) -> Vec<Vec3> {
+        let faces: Vec<[usize; 3]> = mesh.indices.chunks_exact(3)
+            .map(|face| [face[0] as usize, face[1] as usize, face[2] as usize])
+            .collect();
+        let corners = |face: &[usize; 3]| face.map(|index| mesh.vertices[index]);
+        // Length of a face normal is twice the face's area
+        let face_normals: Vec<Vec3> = faces.iter()
+            .map(|face| {
+                let [a, b, c] = corners(face);
+                (b - a).cross(c - a)
+            })
+            .collect();
+
+        let mut faces_of_point = vec![Vec::new(); mesh.vertices.len()];
+        for (face_index, face) in faces.iter().enumerate() {
+            for &point in face {
+                faces_of_point[point].push(face_index);
+            }
+        }
+
+        let cusp_cos = self.cusp_angle.clamp(0.0, 180.0).to_radians().cos();
+        let mut normals = Vec::with_capacity(faces.len() * 3);
+        for (face_index, face) in faces.iter().enumerate() {
+            let own = face_normals[face_index].normalize_or_zero();
+            for &point in face {
+                if self.mode == NormalsMode::Face {
+                    normals.push(own);
+                    continue;
+                }
+                let mut sum = Vec3::ZERO;
+                for &other in &faces_of_point[point] {
+                    let normal = face_normals[other];
+                    let direction = normal.normalize_or_zero();
+                    // Degenerate faces smooth with everything around them
+                    if own != Vec3::ZERO && other != face_index && own.dot(direction) < cusp_cos {
+                        continue;
+                    }
+                    sum += match self.weighting {
+                        NormalWeighting::Area => normal,
+                        NormalWeighting::Equal => direction,
+                        NormalWeighting::Angle => {
+                            let [a, b, c] = corners(&faces[other]);
+                            let (p, q, r) = match faces[other].iter().position(|&i| i == point) {
+                                Some(0) => (a, b, c),
+                                Some(1) => (b, c, a),
+                                _ => (c, a, b),
+                            };
+                            direction * (q - p).angle_between(r - p)
+                        }
+                    };
+                }
+                normals.push(sum.normalize_or_zero());
+            }
+        }
+        normals
+    }
+
+    /// Recompute `mesh`'s normals, splitting points along hard edges
+    pub fn apply(&self, mesh: &mut USDMeshGeometry) {
+        if mesh.indices.iter().any(|&index| index as usize >= mesh.vertices.len()) {
+            log::warn!("Normals: {} has indices past its points; left unchanged", mesh.prim_path);
+            return;
+        }
+        let corner_normals = self.corner_normals(mesh);
+        let original_count = mesh.vertices.len();
+
+        // Normals given to each point so far: the first keeps the point, others get copies
+        let mut point_normals: Vec<Vec<(Vec3, u32)>> = vec![Vec::new(); original_count];
+        let mut sources = Vec::new();
+        for (corner, normal) in corner_normals.into_iter().enumerate() {
+            let point = mesh.indices[corner] as usize;
+            let existing = point_normals[point].iter()
+                .find(|(other, _)| other.dot(normal) >= SAME_NORMAL_DOT || (*other == Vec3::ZERO && normal == Vec3::ZERO))
+                .map(|&(_, index)| index);
+            mesh.indices[corner] = match existing {
+                Some(index) => index,
+                None if point_normals[point].is_empty() => {
+                    point_normals[point].push((normal, point as u32));
+                    point as u32
+                }
+                None => {
+                    let index = (original_count + sources.len()) as u32;
+                    sources.push(point);
+                    point_normals[point].push((normal, index));
+                    index
+                }
+            };
+        }
+
+        let mut normals: Vec<Vec3> = point_normals.iter()
+            .map(|normals| normals.first().map_or(Vec3::ZERO, |&(normal, _)| normal))
+            .collect();
+        // Copies were numbered in corner order; put their normals in the same order
+        let mut copies: Vec<(u32, Vec3)> = point_normals.iter()
+            .flat_map(|normals| normals.iter().skip(1).map(|&(normal, index)| (index, normal)))
+            .collect();
+        copies.sort_by_key(|&(index, _)| index);
+        normals.extend(copies.into_iter().map(|(_, normal)| normal));
+
+        duplicate(&mut mesh.vertices, original_count, &sources);
+        duplicate(&mut mesh.uvs, original_count, &sources);
+        if let Some(colors) = &mut mesh.vertex_colors {
+            duplicate(colors, original_count, &sources);
+        }
+        mesh.primvars.retain(|primvar| primvar.name.trim_start_matches("primvars:") != "normals");
+        for primvar in &mut mesh.primvars {
+            if primvar.interpolation != "vertex" && primvar.interpolation != "varying" {
+                continue;
+            }
+            if let Some(indices) = &mut primvar.indices {
+                duplicate(indices, original_count, &sources);
+                continue;
+            }
+            match &mut primvar.values {
+                PrimvarValues::Float(values) => duplicate(values, original_count, &sources),
+                PrimvarValues::Float2(values) => duplicate(values, original_count, &sources),
+                PrimvarValues::Float3(values) => duplicate(values, original_count, &sources),
+                PrimvarValues::Int(values) => duplicate(values, original_count, &sources),
+                PrimvarValues::String(values) => duplicate(values, original_count, &sources),
+            }
+        }
+        mesh.normals = normals;
+    }
+}
+
+/// Append the values of the split points to a per-point array; other arrays are left alone
+fn duplicate<T: Clone>(values: &mut Vec<T>, point_count: usize, sources: &[usize]) {
+    if values.len() != point_count {
+        return;
+    }
+    for &source in sources {
+        values.push(values[source].clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Mat4, Vec2};
+
+    /// Two triangles folded along the x axis by `fold` degrees, sharing points 0 and 1
+    fn folded(fold: f32) -> USDMeshGeometry {
+        let (sin, cos) = fold.to_radians().sin_cos();
+        USDMeshGeometry {
+            prim_path: "/World/Fold".to_string(),
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.0, sin, cos)],
+            indices: vec![0, 1, 2, 1, 0, 3],
+            normals: vec![Vec3::NEG_Y; 4],
+            uvs: vec![Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE],
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_normals_split_only_at_sharp_edges() {
+        // A shallow fold is smoothed: no new points, shared normals between the two flat ones
+        let mut shallow = folded(30.0);
+        NormalsLogic::default().apply(&mut shallow);
+        assert_eq!(shallow.vertices.len(), 4);
+        assert_eq!(shallow.normals.len(), 4);
+        assert!((shallow.normals[2] - Vec3::Y).length() < 1e-5);
+        assert!(shallow.normals[0].dot(Vec3::Y) < 1.0 - 1e-3 && shallow.normals[0].y > 0.9);
+
+        // A sharp fold keeps a hard edge, splitting the two shared points
+        let mut sharp = folded(90.0);
+        NormalsLogic::default().apply(&mut sharp);
+        assert_eq!(sharp.vertices.len(), 6);
+        assert_eq!(sharp.uvs.len(), 6);
+        assert_eq!(sharp.indices, vec![0, 1, 2, 4, 5, 3]);
+        assert_eq!(sharp.vertices[4], sharp.vertices[1]);
+        assert!((sharp.normals[0] - Vec3::Y).length() < 1e-5);
+        assert!((sharp.normals[5] - Vec3::NEG_Z).length() < 1e-5);
+
+        // Face mode splits even shallow folds
+        let mut flat = folded(30.0);
+        NormalsLogic { mode: NormalsMode::Face, ..Default::default() }.apply(&mut flat);
+        assert_eq!(flat.vertices.len(), 6);
+    }
+}
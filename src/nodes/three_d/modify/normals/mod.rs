@@ -0,0 +1,56 @@
+//! Normals node module - recompute mesh normals
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Face normal averaging with a cusp angle and splitting hard edges
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::NormalsLogic;
+pub use parameters::NormalsNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::NormalsNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Normals",
+            "Normals",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Recomputes the normals of USD meshes, smoothing across edges below a cusp angle or keeping every face flat"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("⊥")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data whose normals are recomputed"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data with new normals"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("prims", "Prims", "")
+                .with_tooltip("Prim patterns of the meshes to process, as in the Prune node; empty processes every mesh"),
+            crate::nodes::ParameterDefinition::choice("mode", "Mode", logic::NormalsMode::NAMES.to_vec(), "Vertex")
+                .with_tooltip("Vertex smooths across edges below the cusp angle; Face gives every face its own flat normal"),
+            crate::nodes::ParameterDefinition::float("cusp_angle", "Cusp Angle", 60.0)
+                .with_range(0.0, 180.0)
+                .with_tooltip("Faces meeting at a sharper angle than this keep a hard edge; 180 smooths everything"),
+            crate::nodes::ParameterDefinition::choice("weighting", "Weighting", logic::NormalWeighting::NAMES.to_vec(), "Area")
+                .with_tooltip("How much each face counts in a smoothed normal: by area, by its angle at the point, or equally"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "normals", "smooth", "flat", "hard", "edges", "shading", "cusp"])
+        .with_subtitle(|node| {
+            let logic = parameters::NormalsNode::create_logic(node);
+            Some(match logic.mode {
+                logic::NormalsMode::Vertex => format!("smooth below {:.0}°", logic.cusp_angle),
+                logic::NormalsMode::Face => "flat".to_string(),
+            })
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
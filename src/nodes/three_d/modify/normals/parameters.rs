@@ -0,0 +1,32 @@
+//! Normals node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use crate::nodes::three_d::modify::prune::PruneLogic;
+use super::logic::{NormalWeighting, NormalsLogic, NormalsMode};
+
+/// Normals node
+#[derive(Debug, Clone, Default)]
+pub struct NormalsNode;
+
+impl NormalsNode {
+    /// Build NormalsLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> NormalsLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        NormalsLogic {
+            prims: PruneLogic::parse_patterns(&text("prims")),
+            mode: NormalsMode::from_name(&text("mode")).unwrap_or_default(),
+            cusp_angle: node.parameters.get("cusp_angle")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(NormalsLogic::default().cusp_angle),
+            weighting: NormalWeighting::from_name(&text("weighting")).unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
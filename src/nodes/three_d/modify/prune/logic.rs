@@ -0,0 +1,200 @@
+//! Prune node functional operations - filtering USD prims by path and primvar
+//!
+//! A prim is kept when its path matches an include pattern (or there are
+//! none), matches no exclude pattern and, for meshes, has the required
+//! primvar. Patterns starting with `/` match the whole prim path, others only
+//! the prim's name; `*` matches within one path segment, `**` across
+//! segments and `?` one character.
+
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{AttributeValue, USDAttribute, USDMeshGeometry};
+
+/// What happens to prims that are filtered out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PruneMode {
+    /// Drop them from the scene
+    #[default]
+    Remove,
+    /// Keep meshes in the scene with `active = false`, so viewers skip them but
+    /// downstream nodes can still reach them; lights and materials have no
+    /// attributes to deactivate and are removed
+    Deactivate,
+}
+
+impl PruneMode {
+    pub const NAMES: [&'static str; 2] = ["Remove", "Deactivate"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Remove" => Some(Self::Remove),
+            "Deactivate" => Some(Self::Deactivate),
+            _ => None,
+        }
+    }
+}
+
+/// Core Prune configuration
+#[derive(Debug, Clone, Default)]
+pub struct PruneLogic {
+    /// Patterns a prim must match one of; empty keeps every prim
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// Primvar a mesh must have; empty keeps every mesh
+    pub primvar: String,
+    pub mode: PruneMode,
+}
+
+impl PruneLogic {
+    /// Split a parameter like "/World/Props/** *_proxy" into patterns
+    pub fn parse_patterns(text: &str) -> Vec<String> {
+        text.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|pattern| !pattern.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Whether a prim at `path` passes the include and exclude patterns
+    pub fn keeps_path(&self, path: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|pattern| matches_prim(pattern, path));
+        included && !self.exclude.iter().any(|pattern| matches_prim(pattern, path))
+    }
+
+    fn keeps_mesh(&self, mesh: &USDMeshGeometry) -> bool {
+        if !self.keeps_path(&mesh.prim_path) {
+            return false;
+        }
+        self.primvar.is_empty() || mesh.primvars.iter().any(|primvar| {
+            primvar.name.strip_prefix("primvars:").unwrap_or(&primvar.name) == self.primvar.strip_prefix("primvars:").unwrap_or(&self.primvar)
+        })
+    }
+
+    /// Filter the incoming USD scene; other inputs pass through unchanged
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(mut scene) = input else {
+            return vec![input];
+        };
+        match self.mode {
+            PruneMode::Remove => scene.meshes.retain(|mesh| self.keeps_mesh(mesh)),
+            PruneMode::Deactivate => {
+                for mesh in &mut scene.meshes {
+                    let active = self.keeps_mesh(mesh);
+                    set_active(&mut mesh.attributes, active);
+                }
+            }
+        }
+        scene.lights.retain(|light| self.keeps_path(&light.prim_path));
+        scene.instancers.retain(|instancer| self.keeps_path(&instancer.prim_path));
+        scene.materials.retain(|material| self.keeps_path(&material.prim_path));
+        vec![NodeData::USDSceneData(scene)]
+    }
+}
+
+/// Author the prim's `active` metadata
+fn set_active(attributes: &mut Vec<USDAttribute>, active: bool) {
+    attributes.retain(|attribute| attribute.name != "active");
+    attributes.push(USDAttribute {
+        name: "active".to_string(),
+        value_type: "bool".to_string(),
+        value: AttributeValue::Bool(active),
+        is_custom: false,
+        metadata: Default::default(),
+    });
+}
+
+/// Whether `pattern` matches the prim at `path`
+pub fn matches_prim(pattern: &str, path: &str) -> bool {
+    if pattern.starts_with('/') {
+        glob_match(pattern.as_bytes(), path.as_bytes())
+    } else {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        glob_match(pattern.as_bytes(), name.as_bytes())
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => (0..=text.len()).any(|skip| glob_match(rest, &text[skip..])),
+        [b'*', rest @ ..] => {
+            let segment = text.iter().position(|&c| c == b'/').unwrap_or(text.len());
+            (0..=segment).any(|skip| glob_match(rest, &text[skip..]))
+        }
+        [b'?', rest @ ..] => matches!(text, [c, ..] if *c != b'/') && glob_match(rest, &text[1..]),
+        [c, rest @ ..] => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+    use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDPrimvar, USDSceneData};
+
+    fn mesh(prim_path: &str, primvars: &[&str]) -> USDMeshGeometry {
+        USDMeshGeometry {
+            prim_path: prim_path.to_string(),
+            vertices: vec![],
+            indices: vec![],
+            normals: vec![],
+            uvs: vec![],
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: primvars.iter().map(|name| USDPrimvar {
+                name: name.to_string(),
+                interpolation: "constant".to_string(),
+                data_type: "float".to_string(),
+                values: PrimvarValues::Float(vec![1.0]),
+                indices: None,
+            }).collect(),
+            attributes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_patterns_match_names_and_paths() {
+        assert!(matches_prim("*_proxy", "/World/Tree_proxy"));
+        assert!(!matches_prim("*_proxy", "/World/Tree_proxy/Leaves"));
+        assert!(matches_prim("/World/*/Leaves", "/World/Tree/Leaves"));
+        assert!(!matches_prim("/World/*", "/World/Tree/Leaves"));
+        assert!(matches_prim("/World/**", "/World/Tree/Leaves"));
+        assert!(matches_prim("Tree?", "/World/Tree2"));
+        assert_eq!(PruneLogic::parse_patterns(" /World/**, *_proxy "), vec!["/World/**", "*_proxy"]);
+    }
+
+    #[test]
+    fn test_prune_removes_or_deactivates_filtered_prims() {
+        let scene = USDSceneData {
+            stage_path: "/tmp/test.usda".to_string(),
+            meshes: vec![
+                mesh("/World/Props/Chair", &["primvars:displayColor"]),
+                mesh("/World/Props/Chair_proxy", &["displayColor"]),
+                mesh("/World/Set/Wall", &[]),
+            ],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        };
+        let logic = PruneLogic {
+            include: PruneLogic::parse_patterns("/World/Props/**"),
+            exclude: PruneLogic::parse_patterns("*_proxy"),
+            primvar: "displayColor".to_string(),
+            mode: PruneMode::Remove,
+        };
+        let NodeData::USDSceneData(removed) = &logic.process(vec![NodeData::USDSceneData(scene.clone())])[0] else {
+            panic!("expected a USD scene")
+        };
+        let paths: Vec<_> = removed.meshes.iter().map(|mesh| mesh.prim_path.as_str()).collect();
+        assert_eq!(paths, vec!["/World/Props/Chair"]);
+
+        let deactivate = PruneLogic { mode: PruneMode::Deactivate, ..logic };
+        let NodeData::USDSceneData(deactivated) = &deactivate.process(vec![NodeData::USDSceneData(scene)])[0] else {
+            panic!("expected a USD scene")
+        };
+        let active: Vec<_> = deactivated.meshes.iter().map(USDMeshGeometry::is_active).collect();
+        assert_eq!(active, vec![true, false, false]);
+    }
+}
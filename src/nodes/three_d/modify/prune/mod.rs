@@ -0,0 +1,60 @@
+//! Prune node module - isolate parts of a USD scene
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Prim path patterns and the primvar filter
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::PruneLogic;
+pub use parameters::PruneNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::PruneNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Prune",
+            "Prune",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Keeps only the USD prims matching path patterns or having a primvar, removing or deactivating the rest"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("✂")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data to filter"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data with only the matching prims active"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("include", "Include", "")
+                .with_tooltip("Prim patterns to keep, separated by spaces or commas; empty keeps everything. /World/** matches a whole branch, *_geo matches prim names")
+                .in_group("Prims"),
+            crate::nodes::ParameterDefinition::string("exclude", "Exclude", "")
+                .with_tooltip("Prim patterns to drop even when included")
+                .in_group("Prims"),
+            crate::nodes::ParameterDefinition::string("primvar", "Has Primvar", "")
+                .with_tooltip("Keep only meshes with this primvar, e.g. displayColor; empty keeps every mesh")
+                .in_group("Prims"),
+            crate::nodes::ParameterDefinition::choice("mode", "Mode", logic::PruneMode::NAMES.to_vec(), "Remove")
+                .with_tooltip("Remove filtered prims, or keep filtered meshes deactivated so viewers skip them"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "prune", "isolate", "filter", "select", "deactivate", "usd"])
+        .with_subtitle(|node| {
+            let logic = parameters::PruneNode::create_logic(node);
+            match (logic.include.is_empty(), logic.exclude.is_empty()) {
+                (true, true) => None,
+                (false, true) => Some(logic.include.join(" ")),
+                (true, false) => Some(format!("not {}", logic.exclude.join(" "))),
+                (false, false) => Some(format!("{} not {}", logic.include.join(" "), logic.exclude.join(" "))),
+            }
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
@@ -0,0 +1,29 @@
+//! Prune node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{PruneLogic, PruneMode};
+
+/// Prune node
+#[derive(Debug, Clone, Default)]
+pub struct PruneNode;
+
+impl PruneNode {
+    /// Build PruneLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> PruneLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        PruneLogic {
+            include: PruneLogic::parse_patterns(&text("include")),
+            exclude: PruneLogic::parse_patterns(&text("exclude")),
+            primvar: text("primvar").trim().to_string(),
+            mode: PruneMode::from_name(&text("mode")).unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
@@ -0,0 +1,238 @@
+//! Scatter node functional operations - instancing prototypes over a surface
+//!
+//! Points are drawn on the target's triangles with probability proportional
+//! to their area, weighted by an optional density primvar, and become the
+//! instances of a UsdGeomPointInstancer whose prototypes are the meshes of the
+//! prototype scene. Every random draw comes from the node's seeded stream, so
+//! a scatter is the same each time the file is opened.
+
+use glam::{Quat, Vec3};
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::nodes::seed::SeedContext;
+use crate::workspaces::three_d::usd::usd_engine::{PrimvarValues, USDMeshGeometry, USDPointInstancer, USDSceneData};
+
+/// Core Scatter configuration
+#[derive(Debug, Clone)]
+pub struct ScatterLogic {
+    pub count: usize,
+    /// Offset combined with the project seed
+    pub seed: i32,
+    /// Float primvar on the target weighting where points land; empty scatters evenly
+    pub density_primvar: String,
+    pub scale: f32,
+    /// Instance scale varies by up to this fraction either way
+    pub scale_jitter: f32,
+    /// Instances turn by up to this many degrees either way about their up axis
+    pub rotation_jitter: f32,
+    /// Point each instance's +Y along the surface normal
+    pub align_to_normal: bool,
+    /// Output the target along with the instancer
+    pub keep_target: bool,
+}
+
+impl Default for ScatterLogic {
+    fn default() -> Self {
+        Self {
+            count: 100,
+            seed: 0,
+            density_primvar: String::new(),
+            scale: 1.0,
+            scale_jitter: 0.0,
+            rotation_jitter: 180.0,
+            align_to_normal: true,
+            keep_target: true,
+        }
+    }
+}
+
+/// A target triangle in world space
+struct Triangle {
+    corners: [Vec3; 3],
+    normal: Vec3,
+}
+
+impl ScatterLogic {
+    /// Scatter the prototype scene (second input) over the target scene (first input)
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>, seeds: &SeedContext) -> Vec<NodeData> {
+        let mut inputs = inputs.into_iter();
+        let target = match inputs.next() {
+            Some(NodeData::USDSceneData(scene)) => scene,
+            _ => return vec![NodeData::None],
+        };
+        let prototypes = match inputs.next() {
+            Some(NodeData::USDSceneData(scene)) => scene.meshes,
+            _ => Vec::new(),
+        };
+        if prototypes.is_empty() {
+            log::warn!("Scatter: no prototype meshes connected; passing the target through");
+            return vec![NodeData::USDSceneData(target)];
+        }
+
+        let instancer = self.scatter(node_id, &target, prototypes, seeds);
+        let mut scene = if self.keep_target {
+            target
+        } else {
+            USDSceneData {
+                meshes: Vec::new(),
+                lights: Vec::new(),
+                materials: Vec::new(),
+                instancers: Vec::new(),
+                ..target
+            }
+        };
+        // The instancer only exists in memory, so the scene no longer matches any file
+        scene.stage_path = format!("procedural://scatter_node_{}", node_id);
+        scene.instancers.push(instancer);
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Point instancer of `prototypes` over the triangles of `target`
+    pub fn scatter(&self, node_id: NodeId, target: &USDSceneData, prototypes: Vec<USDMeshGeometry>, seeds: &SeedContext) -> USDPointInstancer {
+        let mut instancer = USDPointInstancer {
+            prim_path: format!("/Scatter_{}", node_id),
+            prototypes,
+            proto_indices: Vec::new(),
+            positions: Vec::new(),
+            orientations: Vec::new(),
+            scales: Vec::new(),
+            transform: glam::Mat4::IDENTITY,
+        };
+
+        let mut triangles = Vec::new();
+        let mut cumulative_weights = Vec::new();
+        let mut total_weight = 0.0;
+        for mesh in target.meshes.iter().filter(|mesh| mesh.is_active()) {
+            let density = self.vertex_density(mesh);
+            for face in mesh.indices.chunks_exact(3) {
+                let Some(corners) = face.iter()
+                    .map(|&index| mesh.vertices.get(index as usize).map(|&v| mesh.transform.transform_point3(v)))
+                    .collect::<Option<Vec<_>>>() else { continue };
+                let cross = (corners[1] - corners[0]).cross(corners[2] - corners[0]);
+                let density = face.iter().map(|&index| density(index as usize)).sum::<f32>() / 3.0;
+                let weight = 0.5 * cross.length() * density.max(0.0);
+                if weight <= 0.0 {
+                    continue;
+                }
+                total_weight += weight;
+                cumulative_weights.push(total_weight);
+                triangles.push(Triangle {
+                    corners: [corners[0], corners[1], corners[2]],
+                    normal: cross.normalize(),
+                });
+            }
+        }
+        if triangles.is_empty() {
+            return instancer;
+        }
+
+        let mut rng = seeds.rng(node_id, &format!("scatter/{}", self.seed));
+        let last_prototype = instancer.prototypes.len() as i32 - 1;
+        for _ in 0..self.count {
+            let pick = rng.next_f32() * total_weight;
+            let index = cumulative_weights.partition_point(|&weight| weight <= pick).min(triangles.len() - 1);
+            let triangle = &triangles[index];
+
+            // Uniform point in the triangle
+            let (u, v) = (rng.next_f32().sqrt(), rng.next_f32());
+            let [a, b, c] = triangle.corners;
+            instancer.positions.push(a * (1.0 - u) + b * (u * (1.0 - v)) + c * (u * v));
+
+            let base = if self.align_to_normal { Quat::from_rotation_arc(Vec3::Y, triangle.normal) } else { Quat::IDENTITY };
+            let spin = rng.range_f32(-self.rotation_jitter, self.rotation_jitter).to_radians();
+            instancer.orientations.push(base * Quat::from_rotation_y(spin));
+
+            let scale = self.scale * (1.0 + rng.range_f32(-self.scale_jitter, self.scale_jitter));
+            instancer.scales.push(Vec3::splat(scale.max(0.0)));
+            instancer.proto_indices.push(rng.range_i32(0, last_prototype) as u32);
+        }
+        instancer
+    }
+
+    /// Density of each vertex of `mesh` from the density primvar (1 everywhere without one)
+    fn vertex_density<'a>(&self, mesh: &'a USDMeshGeometry) -> impl Fn(usize) -> f32 + 'a {
+        let name = self.density_primvar.trim().trim_start_matches("primvars:").to_string();
+        let values = mesh.primvars.iter()
+            .find(|primvar| !name.is_empty() && primvar.name.trim_start_matches("primvars:") == name)
+            .and_then(|primvar| match &primvar.values {
+                PrimvarValues::Float(values) => Some((primvar.interpolation.clone(), values.as_slice())),
+                _ => None,
+            });
+        move |vertex: usize| match &values {
+            Some((interpolation, values)) if interpolation == "constant" => values.first().copied().unwrap_or(1.0),
+            Some((_, values)) => values.get(vertex).copied().unwrap_or(1.0),
+            None => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+    use crate::workspaces::three_d::usd::usd_engine::USDPrimvar;
+
+    fn mesh(prim_path: &str, vertices: Vec<Vec3>, indices: Vec<u32>) -> USDMeshGeometry {
+        USDMeshGeometry {
+            prim_path: prim_path.to_string(),
+            vertices,
+            indices,
+            normals: vec![],
+            uvs: vec![],
+            vertex_colors: None,
+            transform: Mat4::IDENTITY,
+            primvars: vec![],
+            attributes: vec![],
+        }
+    }
+
+    fn scene(meshes: Vec<USDMeshGeometry>) -> USDSceneData {
+        USDSceneData {
+            stage_path: "procedural://test".to_string(),
+            meshes,
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: vec![],
+        }
+    }
+
+    /// Two triangles covering x in [0, 2], z in [0, 1] on the ground plane
+    fn ground() -> USDMeshGeometry {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 1.0), Vec3::new(2.0, 0.0, 0.0),
+        ];
+        mesh("/World/Ground", vertices, vec![0, 1, 2, 3, 4, 5])
+    }
+
+    #[test]
+    fn test_scatter_is_seeded_and_follows_density() {
+        let seeds = SeedContext::new(7);
+        let logic = ScatterLogic { count: 50, ..Default::default() };
+        let prototypes = vec![mesh("/Proto/A", vec![Vec3::ZERO], vec![]), mesh("/Proto/B", vec![Vec3::ZERO], vec![])];
+        let target = scene(vec![ground()]);
+
+        let instancer = logic.scatter(4, &target, prototypes.clone(), &seeds);
+        assert_eq!(instancer.instance_count(), 50);
+        assert!(instancer.positions.iter().all(|p| p.y.abs() < 1e-6 && (0.0..=2.0).contains(&p.x)));
+        assert!(instancer.proto_indices.iter().all(|&index| index < 2));
+        // Aligned to the +Y normal of the ground, so instances only spin about Y
+        assert!(instancer.orientations.iter().all(|q| (*q * Vec3::Y - Vec3::Y).length() < 1e-4));
+        assert_eq!(logic.scatter(4, &target, prototypes.clone(), &seeds).positions, instancer.positions);
+        assert_eq!(instancer.to_meshes().len(), 50);
+
+        // A density of zero on the second triangle keeps every point on the first
+        let mut weighted = ground();
+        weighted.primvars.push(USDPrimvar {
+            name: "primvars:density".to_string(),
+            interpolation: "vertex".to_string(),
+            data_type: "float".to_string(),
+            values: PrimvarValues::Float(vec![1.0, 1.0, 1.0, 0.0, 0.0, 0.0]),
+            indices: None,
+        });
+        let logic = ScatterLogic { density_primvar: "density".to_string(), ..logic };
+        let instancer = logic.scatter(4, &scene(vec![weighted]), prototypes, &seeds);
+        assert!(instancer.positions.iter().all(|p| p.x <= 1.0 + 1e-6));
+    }
+}
@@ -0,0 +1,78 @@
+//! Scatter node module - instancing prototypes across a surface
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Sampling the target surface and building the point instancer
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+//!
+//! Like the Random node, placement comes from the project seed (see
+//! [`crate::nodes::seed`]).
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ScatterLogic;
+pub use parameters::ScatterNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ScatterNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Scatter",
+            "Scatter",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Scatters instances of a prototype scene across a target surface as a UsdGeomPointInstancer"
+        )
+        .with_color(egui::Color32::from_rgb(120, 180, 100))
+        .with_icon("🌱")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Target", crate::nodes::DataType::Any)
+                .with_description("USD scene whose meshes the instances are scattered over"),
+            crate::nodes::PortDefinition::required("Prototype", crate::nodes::DataType::Any)
+                .with_description("USD scene whose meshes are instanced; each instance picks one at random"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Scene", crate::nodes::DataType::Any)
+                .with_description("The target with a point instancer added"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::integer("count", "Count", 100)
+                .with_range(0.0, 1_000_000.0)
+                .with_soft_range(0.0, 10_000.0)
+                .in_group("Scatter"),
+            crate::nodes::ParameterDefinition::integer("seed", "Seed", 0)
+                .with_tooltip("Offset combined with the project seed; change it for a different placement")
+                .in_group("Scatter"),
+            crate::nodes::ParameterDefinition::string("density_primvar", "Density Primvar", "")
+                .with_tooltip("Float primvar on the target weighting where instances land, e.g. a painted mask; empty scatters evenly by area")
+                .in_group("Scatter"),
+            crate::nodes::ParameterDefinition::float("scale", "Scale", 1.0)
+                .with_min(0.0)
+                .with_step(0.01)
+                .in_group("Instances"),
+            crate::nodes::ParameterDefinition::float("scale_jitter", "Scale Jitter", 0.0)
+                .with_range(0.0, 1.0)
+                .with_step(0.01)
+                .with_tooltip("Scales vary by up to this fraction either way")
+                .in_group("Instances"),
+            crate::nodes::ParameterDefinition::float("rotation_jitter", "Rotation Jitter", 180.0)
+                .with_range(0.0, 180.0)
+                .with_tooltip("Instances turn by up to this many degrees either way about their up axis")
+                .in_group("Instances"),
+            crate::nodes::ParameterDefinition::boolean("align_to_normal", "Align to Normal", true)
+                .with_tooltip("Point each instance's +Y along the surface normal")
+                .in_group("Instances"),
+            crate::nodes::ParameterDefinition::boolean("keep_target", "Keep Target", true)
+                .with_tooltip("Output the target surface along with the instances")
+                .in_group("Output"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "scatter", "instance", "instancer", "pointinstancer", "copy", "distribute", "random"])
+        .with_subtitle(|node| {
+            let logic = parameters::ScatterNode::create_logic(node);
+            Some(format!("{} instances", logic.count))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Medium)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
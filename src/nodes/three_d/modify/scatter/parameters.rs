@@ -0,0 +1,44 @@
+//! Scatter node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::seed::SeedContext;
+use crate::nodes::Node;
+use super::logic::ScatterLogic;
+
+/// Scatter node
+#[derive(Debug, Clone, Default)]
+pub struct ScatterNode;
+
+impl ScatterNode {
+    /// Build ScatterLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ScatterLogic {
+        let defaults = ScatterLogic::default();
+        let float = |name: &str, default: f32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        let boolean = |name: &str, default: bool| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+            .unwrap_or(default);
+        ScatterLogic {
+            count: node.parameters.get("count")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some((*i).max(0) as usize) } else { None })
+                .unwrap_or(defaults.count),
+            seed: node.parameters.get("seed")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(defaults.seed),
+            density_primvar: node.parameters.get("density_primvar")
+                .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+                .unwrap_or(defaults.density_primvar),
+            scale: float("scale", defaults.scale),
+            scale_jitter: float("scale_jitter", defaults.scale_jitter),
+            rotation_jitter: float("rotation_jitter", defaults.rotation_jitter),
+            align_to_normal: boolean("align_to_normal", defaults.align_to_normal),
+            keep_target: boolean("keep_target", defaults.keep_target),
+        }
+    }
+
+    /// Execute the node under the project seed
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, seeds: &SeedContext) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs, seeds)
+    }
+}
@@ -0,0 +1,226 @@
+//! Transform node functional operations - TRS on USD prims
+//!
+//! The node's matrix is `translate * pivot * rotate * scale * pivot⁻¹`, applied
+//! on top of each prim's existing transform. Prims get matching xformOps: the
+//! node's ops first, then the prim's previous transform as one matrix op, so
+//! `xformOpOrder` always composes to the prim's `transform`.
+
+use glam::{Mat4, Vec3};
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{AttributeValue, USDAttribute};
+
+/// Order rotations are applied in; "XYZ" rotates about X first, as USD's `rotateXYZ`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationOrder {
+    #[default]
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl RotationOrder {
+    pub const NAMES: [&'static str; 6] = ["XYZ", "XZY", "YXZ", "YZX", "ZXY", "ZYX"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "XYZ" => Self::XYZ,
+            "XZY" => Self::XZY,
+            "YXZ" => Self::YXZ,
+            "YZX" => Self::YZX,
+            "ZXY" => Self::ZXY,
+            "ZYX" => Self::ZYX,
+            _ => return None,
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::XYZ => "XYZ",
+            Self::XZY => "XZY",
+            Self::YXZ => "YXZ",
+            Self::YZX => "YZX",
+            Self::ZXY => "ZXY",
+            Self::ZYX => "ZYX",
+        }
+    }
+
+    /// Rotation by `degrees` about X, Y and Z, applied in this order
+    pub fn matrix(&self, degrees: Vec3) -> Mat4 {
+        let x = Mat4::from_rotation_x(degrees.x.to_radians());
+        let y = Mat4::from_rotation_y(degrees.y.to_radians());
+        let z = Mat4::from_rotation_z(degrees.z.to_radians());
+        // The first rotation applied is the rightmost factor
+        match self {
+            Self::XYZ => z * y * x,
+            Self::XZY => y * z * x,
+            Self::YXZ => z * x * y,
+            Self::YZX => x * z * y,
+            Self::ZXY => y * x * z,
+            Self::ZYX => x * y * z,
+        }
+    }
+}
+
+/// Core Transform configuration
+#[derive(Debug, Clone)]
+pub struct TransformLogic {
+    pub translate: Vec3,
+    /// Rotation in degrees
+    pub rotate: Vec3,
+    pub scale: Vec3,
+    /// Multiplies all three scale axes
+    pub uniform_scale: f32,
+    /// Point rotation and scale happen around
+    pub pivot: Vec3,
+    pub rotation_order: RotationOrder,
+}
+
+impl Default for TransformLogic {
+    fn default() -> Self {
+        Self {
+            translate: Vec3::ZERO,
+            rotate: Vec3::ZERO,
+            scale: Vec3::ONE,
+            uniform_scale: 1.0,
+            pivot: Vec3::ZERO,
+            rotation_order: RotationOrder::XYZ,
+        }
+    }
+}
+
+impl TransformLogic {
+    /// Combined scale of the node
+    fn total_scale(&self) -> Vec3 {
+        self.scale * self.uniform_scale
+    }
+
+    /// Matrix the node applies to every prim
+    pub fn matrix(&self) -> Mat4 {
+        Mat4::from_translation(self.translate)
+            * Mat4::from_translation(self.pivot)
+            * self.rotation_order.matrix(self.rotate)
+            * Mat4::from_scale(self.total_scale())
+            * Mat4::from_translation(-self.pivot)
+    }
+
+    /// Transform the incoming USD scene; other inputs pass through unchanged
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(mut scene) = input else {
+            return vec![input];
+        };
+        let matrix = self.matrix();
+        for mesh in &mut scene.meshes {
+            self.write_xform_ops(&mut mesh.attributes, mesh.transform);
+            mesh.transform = matrix * mesh.transform;
+        }
+        for light in &mut scene.lights {
+            light.transform = matrix * light.transform;
+        }
+        for instancer in &mut scene.instancers {
+            instancer.transform = matrix * instancer.transform;
+        }
+        vec![NodeData::USDSceneData(scene)]
+    }
+
+    /// Replace the prim's xformOps with this node's ops followed by its `previous` transform
+    fn write_xform_ops(&self, attributes: &mut Vec<USDAttribute>, previous: Mat4) {
+        attributes.retain(|attribute| !attribute.name.starts_with("xformOp") && !attribute.name.starts_with("!invert!xformOp"));
+
+        let rotate_op = format!("xformOp:rotate{}", self.rotation_order.name());
+        let mut order = vec![
+            "xformOp:translate".to_string(),
+            "xformOp:translate:pivot".to_string(),
+            rotate_op.clone(),
+            "xformOp:scale".to_string(),
+            "!invert!xformOp:translate:pivot".to_string(),
+        ];
+        let mut ops = vec![
+            ("xformOp:translate".to_string(), "float3", AttributeValue::Float3(self.translate)),
+            ("xformOp:translate:pivot".to_string(), "float3", AttributeValue::Float3(self.pivot)),
+            (rotate_op, "float3", AttributeValue::Float3(self.rotate)),
+            ("xformOp:scale".to_string(), "float3", AttributeValue::Float3(self.total_scale())),
+        ];
+        if previous != Mat4::IDENTITY {
+            order.push("xformOp:transform".to_string());
+            ops.push(("xformOp:transform".to_string(), "matrix4d", AttributeValue::Matrix4d(previous)));
+        }
+        ops.push(("xformOpOrder".to_string(), "token[]", AttributeValue::TokenArray(order)));
+
+        attributes.extend(ops.into_iter().map(|(name, value_type, value)| USDAttribute {
+            name,
+            value_type: value_type.to_string(),
+            value,
+            is_custom: false,
+            metadata: Default::default(),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workspaces::three_d::usd::usd_engine::{USDMeshGeometry, USDSceneData};
+
+    fn scene_with_mesh(transform: Mat4) -> USDSceneData {
+        USDSceneData {
+            stage_path: "/tmp/test.usda".to_string(),
+            meshes: vec![USDMeshGeometry {
+                prim_path: "/World/Cube".to_string(),
+                vertices: vec![Vec3::X],
+                indices: vec![],
+                normals: vec![],
+                uvs: vec![],
+                vertex_colors: None,
+                transform,
+                primvars: vec![],
+                attributes: vec![],
+            }],
+            lights: vec![],
+            materials: vec![],
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_transform_applies_trs_around_pivot_and_writes_xform_ops() {
+        let logic = TransformLogic {
+            translate: Vec3::new(0.0, 5.0, 0.0),
+            rotate: Vec3::new(0.0, 0.0, 90.0),
+            pivot: Vec3::new(1.0, 0.0, 0.0),
+            uniform_scale: 2.0,
+            ..Default::default()
+        };
+        // The pivot stays put under rotation and scale, then moves with the translation
+        assert!(logic.matrix().transform_point3(Vec3::X).abs_diff_eq(Vec3::new(1.0, 5.0, 0.0), 1e-5));
+        assert!(logic.matrix().transform_point3(Vec3::ZERO).abs_diff_eq(Vec3::new(1.0, 3.0, 0.0), 1e-5));
+
+        let offset = Mat4::from_translation(Vec3::Z);
+        let outputs = logic.process(vec![NodeData::USDSceneData(scene_with_mesh(offset))]);
+        let NodeData::USDSceneData(scene) = &outputs[0] else { panic!("expected a USD scene") };
+        let mesh = &scene.meshes[0];
+        assert!(mesh.transform.abs_diff_eq(logic.matrix() * offset, 1e-5));
+        let order = mesh.attributes.iter().find(|attribute| attribute.name == "xformOpOrder").unwrap();
+        let AttributeValue::TokenArray(order) = &order.value else { panic!("expected tokens") };
+        assert_eq!(order.first().map(String::as_str), Some("xformOp:translate"));
+        assert_eq!(order.last().map(String::as_str), Some("xformOp:transform"));
+
+        // Other data passes through
+        assert!(matches!(logic.process(vec![NodeData::Float(1.0)])[0], NodeData::Float(_)));
+    }
+
+    #[test]
+    fn test_rotation_order_changes_the_result() {
+        let degrees = Vec3::new(90.0, 90.0, 0.0);
+        let xyz = RotationOrder::XYZ.matrix(degrees).transform_vector3(Vec3::Z);
+        let yxz = RotationOrder::YXZ.matrix(degrees).transform_vector3(Vec3::Z);
+        assert!(!xyz.abs_diff_eq(yxz, 1e-3));
+        assert!(RotationOrder::NAMES.iter().all(|name| RotationOrder::from_name(name).map(|order| order.name()) == Some(*name)));
+    }
+}
@@ -0,0 +1,65 @@
+//! Transform node module - translate, rotate and scale USD prims
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: The TRS matrix and writing it to prim xformOps
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::TransformLogic;
+pub use parameters::TransformNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::TransformNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_Transform",
+            "Transform",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Translates, rotates and scales every prim of a USD scene around a pivot by editing its xformOps"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("✥")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data to transform"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data with the transform added to each prim"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::vector3("translate", "Translate", [0.0, 0.0, 0.0])
+                .with_step(0.1)
+                .in_group("Transform"),
+            crate::nodes::ParameterDefinition::vector3("rotate", "Rotate", [0.0, 0.0, 0.0])
+                .with_tooltip("Rotation about X, Y and Z in degrees")
+                .in_group("Transform"),
+            crate::nodes::ParameterDefinition::vector3("scale", "Scale", [1.0, 1.0, 1.0])
+                .with_step(0.01)
+                .in_group("Transform"),
+            crate::nodes::ParameterDefinition::float("uniform_scale", "Uniform Scale", 1.0)
+                .with_step(0.01)
+                .with_tooltip("Multiplies the scale on all three axes")
+                .in_group("Transform"),
+            crate::nodes::ParameterDefinition::choice("rotation_order", "Rotation Order", logic::RotationOrder::NAMES.to_vec(), "XYZ")
+                .with_tooltip("Order the axis rotations are applied in; XYZ rotates about X first")
+                .in_group("Transform"),
+            crate::nodes::ParameterDefinition::vector3("pivot", "Pivot", [0.0, 0.0, 0.0])
+                .with_step(0.1)
+                .with_tooltip("Point rotation and scale happen around")
+                .in_group("Pivot"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "modify", "transform", "translate", "rotate", "scale", "move", "pivot", "xform"])
+        .with_subtitle(|node| {
+            let logic = parameters::TransformNode::create_logic(node);
+            let t = logic.translate;
+            Some(format!("t ({:.2}, {:.2}, {:.2})", t.x, t.y, t.z))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
@@ -0,0 +1,37 @@
+//! Transform node parameters, declared in the node's metadata schema
+
+use glam::Vec3;
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{RotationOrder, TransformLogic};
+
+/// Transform node
+#[derive(Debug, Clone, Default)]
+pub struct TransformNode;
+
+impl TransformNode {
+    /// Build TransformLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> TransformLogic {
+        let defaults = TransformLogic::default();
+        let vector = |name: &str, default: Vec3| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Vector3(v) = v { Some(Vec3::from(*v)) } else { None })
+            .unwrap_or(default);
+        TransformLogic {
+            translate: vector("translate", defaults.translate),
+            rotate: vector("rotate", defaults.rotate),
+            scale: vector("scale", defaults.scale),
+            uniform_scale: node.parameters.get("uniform_scale")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.uniform_scale),
+            pivot: vector("pivot", defaults.pivot),
+            rotation_order: node.parameters.get("rotation_order")
+                .and_then(|v| if let NodeData::String(s) = v { RotationOrder::from_name(s) } else { None })
+                .unwrap_or(defaults.rotation_order),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
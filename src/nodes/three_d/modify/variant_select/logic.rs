@@ -0,0 +1,139 @@
+//! Variant Select node functional operations - composing variant selections
+//!
+//! Cooking lists the variant sets of the incoming stage (kept per node for the
+//! parameter panel) and, when selections are made, recomposes the stage with
+//! them. Selections are stored one per line in USD's own path syntax,
+//! `/World/Chair{color=red}`, so they read the same as in a `.usda` file.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{USDVariantSet, GLOBAL_USD_ENGINE};
+
+/// Variant sets found on each Variant Select node's input at its last cook
+pub static VARIANT_SETS: LazyLock<RwLock<HashMap<NodeId, Vec<USDVariantSet>>>> = LazyLock::new(|| {
+    RwLock::new(HashMap::new())
+});
+
+/// Variant sets last found on a node's input
+pub fn variant_sets_for(node_id: NodeId) -> Vec<USDVariantSet> {
+    VARIANT_SETS.read().ok()
+        .and_then(|sets| sets.get(&node_id).cloned())
+        .unwrap_or_default()
+}
+
+/// One chosen variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantSelection {
+    pub prim_path: String,
+    pub set: String,
+    pub variant: String,
+}
+
+impl VariantSelection {
+    /// Parse `/World/Chair{color=red}`
+    pub fn parse(text: &str) -> Option<Self> {
+        let (prim_path, rest) = text.trim().split_once('{')?;
+        let (set, variant) = rest.strip_suffix('}')?.split_once('=')?;
+        Some(Self {
+            prim_path: prim_path.trim().to_string(),
+            set: set.trim().to_string(),
+            variant: variant.trim().to_string(),
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        format!("{}{{{}={}}}", self.prim_path, self.set, self.variant)
+    }
+}
+
+/// Parse the selections parameter, one per line; malformed lines are skipped
+pub fn parse_selections(text: &str) -> Vec<VariantSelection> {
+    text.lines().filter_map(VariantSelection::parse).collect()
+}
+
+pub fn format_selections(selections: &[VariantSelection]) -> String {
+    selections.iter().map(VariantSelection::to_text).collect::<Vec<_>>().join("\n")
+}
+
+/// Core Variant Select configuration
+#[derive(Debug, Clone, Default)]
+pub struct VariantSelectLogic {
+    pub selections: Vec<VariantSelection>,
+}
+
+impl VariantSelectLogic {
+    /// Recompose the incoming stage with the selections; other inputs pass through unchanged
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(input) = inputs.into_iter().next() else {
+            if let Ok(mut sets) = VARIANT_SETS.write() {
+                sets.remove(&node_id);
+            }
+            return vec![NodeData::None];
+        };
+        let NodeData::USDSceneData(scene) = &input else {
+            return vec![input];
+        };
+        let Ok(mut engine) = GLOBAL_USD_ENGINE.lock() else {
+            return vec![input];
+        };
+
+        match engine.list_variant_sets(&scene.stage_path) {
+            Ok(found) => {
+                if let Ok(mut sets) = VARIANT_SETS.write() {
+                    sets.insert(node_id, found);
+                }
+            }
+            Err(e) => log::warn!("Variant Select: {}", e),
+        }
+
+        // Only selections that differ from the file's own need composing
+        let available = variant_sets_for(node_id);
+        let changes: Vec<(String, String, String)> = self.selections.iter()
+            .filter(|selection| available.iter().any(|set| {
+                set.prim_path == selection.prim_path
+                    && set.name == selection.set
+                    && set.selection != selection.variant
+                    && set.variants.contains(&selection.variant)
+            }))
+            .map(|selection| (selection.prim_path.clone(), selection.set.clone(), selection.variant.clone()))
+            .collect();
+        if changes.is_empty() {
+            return vec![input];
+        }
+
+        let output_path = std::env::temp_dir()
+            .join(format!("nodle_variants_{}.usda", node_id))
+            .to_string_lossy()
+            .to_string();
+        match engine.load_stage_with_variants(&scene.stage_path, &changes, &output_path) {
+            Ok(composed) => vec![NodeData::USDSceneData(composed)],
+            Err(e) => {
+                log::warn!("Variant Select: {}", e);
+                vec![input]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selections_round_trip_usd_path_syntax() {
+        let selection = VariantSelection::parse(" /World/Chair{color = red} ").unwrap();
+        assert_eq!(selection, VariantSelection {
+            prim_path: "/World/Chair".to_string(),
+            set: "color".to_string(),
+            variant: "red".to_string(),
+        });
+        assert_eq!(selection.to_text(), "/World/Chair{color=red}");
+
+        let text = "/World/Chair{color=red}\nnot a selection\n/World/Table{lod=high}";
+        let selections = parse_selections(text);
+        assert_eq!(selections.len(), 2);
+        assert_eq!(format_selections(&selections), "/World/Chair{color=red}\n/World/Table{lod=high}");
+    }
+}
@@ -0,0 +1,46 @@
+//! Variant Select node module - pick USD variant selections
+//!
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Listing variant sets and recomposing the stage with the selections
+//! - parameters.rs: Dropdowns for the variant sets found on the input
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::VariantSelectLogic;
+pub use parameters::VariantSelectNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::VariantSelectNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_VariantSelect",
+            "Variant Select",
+            crate::nodes::NodeCategory::new(&["3D", "Modify"]),
+            "Lists the variant sets of the incoming USD stage and composes it with the chosen variants"
+        )
+        .with_color(egui::Color32::from_rgb(200, 120, 160))
+        .with_icon("🔀")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("USD scene data whose stage has variant sets"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Geometry", crate::nodes::DataType::Any)
+                .with_description("The stage composed with the selected variants"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("selections", "Selections", "")
+                .with_tooltip("One selection per line, as /World/Chair{color=red}"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "usd", "variant", "variants", "lookdev", "select", "compose"])
+        .with_subtitle(|node| {
+            let count = parameters::VariantSelectNode::create_logic(node).selections.len();
+            (count > 0).then(|| format!("{} selection{}", count, if count == 1 { "" } else { "s" }))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "USD", "MaterialX"])
+    }
+}
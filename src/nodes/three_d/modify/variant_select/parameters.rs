@@ -0,0 +1,92 @@
+//! Variant Select node parameters - one dropdown per variant set on the incoming stage
+
+use egui::RichText;
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{format_selections, parse_selections, variant_sets_for, VariantSelectLogic, VariantSelection};
+
+/// Variant Select node
+#[derive(Debug, Clone, Default)]
+pub struct VariantSelectNode;
+
+impl VariantSelectNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let mut selections = Self::create_logic(node).selections;
+        let variant_sets = variant_sets_for(node.id);
+
+        ui.heading("Variant Sets");
+        ui.separator();
+
+        if variant_sets.is_empty() {
+            ui.label(RichText::new("No variant sets on the incoming stage. Connect a USD stage and cook to list them.").weak());
+        }
+
+        let mut changed = false;
+        let mut current_prim = None;
+        for set in &variant_sets {
+            if current_prim != Some(&set.prim_path) {
+                ui.add_space(4.0);
+                ui.label(RichText::new(&set.prim_path).strong());
+                current_prim = Some(&set.prim_path);
+            }
+            let existing = selections.iter().position(|selection| selection.prim_path == set.prim_path && selection.set == set.name);
+            let mut set_changed = false;
+            let mut chosen = existing.map_or_else(|| set.selection.clone(), |index| selections[index].variant.clone());
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", set.name));
+                egui::ComboBox::from_id_salt((node.id, &set.prim_path, &set.name))
+                    .selected_text(if chosen.is_empty() { "(none)" } else { chosen.as_str() })
+                    .show_ui(ui, |ui| {
+                        for variant in &set.variants {
+                            let label = if *variant == set.selection { format!("{} (file default)", variant) } else { variant.clone() };
+                            if ui.selectable_value(&mut chosen, variant.clone(), label).changed() {
+                                set_changed = true;
+                            }
+                        }
+                    });
+            });
+            if set_changed {
+                let selection = VariantSelection {
+                    prim_path: set.prim_path.clone(),
+                    set: set.name.clone(),
+                    variant: chosen,
+                };
+                match existing {
+                    Some(index) => selections[index] = selection,
+                    None => selections.push(selection),
+                }
+                changed = true;
+            }
+        }
+
+        ui.add_space(8.0);
+        if !selections.is_empty() && ui.button("Reset to file defaults").clicked() {
+            selections.clear();
+            changed = true;
+        }
+
+        if changed {
+            changes.push(ParameterChange {
+                parameter: "selections".to_string(),
+                value: NodeData::String(format_selections(&selections)),
+            });
+        }
+        changes
+    }
+
+    /// Build VariantSelectLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> VariantSelectLogic {
+        VariantSelectLogic {
+            selections: node.parameters.get("selections")
+                .and_then(|v| if let NodeData::String(s) = v { Some(parse_selections(s)) } else { None })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
@@ -1,5 +1,7 @@
 //! 3D output nodes for rendering and exporting
 
 pub mod render;
+pub mod write_usd;
 
-pub use render::RenderNodeFactory;
\ No newline at end of file
+pub use render::RenderNodeFactory;
+pub use write_usd::WriteUSDNode;
\ No newline at end of file
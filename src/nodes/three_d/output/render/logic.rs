@@ -1,33 +1,47 @@
 //! Logic implementation for the USD Hydra Render node
 
 use crate::nodes::interface::NodeData;
-use crate::nodes::Node;
-use std::process::{Command, Stdio};
+use crate::nodes::{Node, NodeId};
+use crate::nodes::project_paths;
+use crate::nodes::three_d::input::read_vdb::logic::volume_layer;
+use crate::workspaces::three_d::usd::hydra::{self, RenderRegion};
+use super::queue;
 use std::path::Path;
 use std::fs;
-use std::env;
-use std::io::{BufRead, BufReader};
-use std::thread;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[cfg(feature = "usd")]
 use crate::workspaces::three_d::usd::usd_engine::{USDEngine, USDSceneData};
 
+/// Scenes written for renders so far, so queued jobs never share a file
+static TEMP_SCENES: AtomicU64 = AtomicU64::new(0);
 
 pub struct RenderLogic {
+    node_id: NodeId,
     renderer: String,
     output_path: String,
     temp_folder: String,
     image_width: i32,
+    /// Crop, resolution percentage and overscan
+    region: RenderRegion,
     camera_path: String,
     complexity: String,
     color_correction: String,
     trigger_render: bool,
     refresh_renderers: bool,
     open_output: bool,
+    /// Render `start_frame..=end_frame` instead of the timeline frame
+    frame_range: bool,
+    start_frame: i32,
+    end_frame: i32,
+    frame_step: i32,
+    /// Timeline frame the graph was cooked at
+    current_frame: i64,
 }
 
 impl RenderLogic {
-    pub fn from_node(node: &Node) -> Self {
+    pub fn from_node(node: &Node, current_frame: i64) -> Self {
         let get_string = |key: &str| -> String {
             node.parameters.get(key)
                 .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
@@ -47,16 +61,30 @@ impl RenderLogic {
         };
         
         Self {
+            node_id: node.id,
             renderer: get_string("renderer"),
-            output_path: get_string("output_path"),
+            // Relative output paths are written into the project folder
+            output_path: project_paths::resolve_path_string(&get_string("output_path")),
             temp_folder: get_string("temp_folder"),
             image_width: get_int("image_width"),
+            region: render_region(node, true),
             camera_path: get_string("camera_path"),
             complexity: get_string("complexity"),
             color_correction: get_string("color_correction"),
             trigger_render: get_bool("trigger_render"),
             refresh_renderers: get_bool("refresh_renderers"),
             open_output: get_bool("open_output"),
+            frame_range: get_bool("frame_range"),
+            start_frame: node.parameters.get("start_frame")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(1),
+            end_frame: node.parameters.get("end_frame")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(1),
+            frame_step: node.parameters.get("frame_step")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(1),
+            current_frame,
         }
     }
     
@@ -65,11 +93,10 @@ impl RenderLogic {
         
         // Handle renderer refresh
         if self.refresh_renderers {
-            if let Ok(renderers) = self.detect_available_renderers() {
-                println!("🎬 Available renderers: {:?}", renderers);
-                // Reset the refresh flag
-                self.refresh_renderers = false;
-            }
+            hydra::refresh_delegates();
+            println!("🎬 Available renderers: {:?}", hydra::available_delegates());
+            // Reset the refresh flag
+            self.refresh_renderers = false;
         }
         
         // Handle render trigger
@@ -80,21 +107,11 @@ impl RenderLogic {
             self.trigger_render = false;
             
             if let Some(scene_data) = inputs.first() {
-                outputs[0] = NodeData::String("Rendering...".to_string());
-                
-                // TODO: Make this async to avoid blocking the UI
-                // For now, just execute synchronously but with better error handling
-                println!("🎬 Starting render process...");
-                match self.execute_render(scene_data) {
-                    Ok(status) => {
-                        println!("✅ Render completed: {}", status);
-                        outputs[0] = NodeData::String(status);
-                        
-                        // Handle open output
-                        if self.open_output {
-                            self.open_output_file();
-                            self.open_output = false; // Reset flag
-                        }
+                // The render runs in the background queue; the Status output follows the job
+                match self.queue_render(scene_data) {
+                    Ok(job_ids) => {
+                        println!("🎬 Render queued as jobs {:?}", job_ids);
+                        outputs[0] = NodeData::String(queue::node_status(self.node_id).unwrap_or_else(|| "Queued".to_string()));
                     }
                     Err(e) => {
                         eprintln!("❌ Render failed: {}", e);
@@ -105,211 +122,76 @@ impl RenderLogic {
                 outputs[0] = NodeData::String("Error: No scene data input".to_string());
             }
         } else {
-            // Always try to refresh available renderers when not rendering
-            if let Ok(renderers) = self.detect_available_renderers() {
-                if !renderers.is_empty() {
-                    outputs[0] = NodeData::String(format!("Ready - Renderers: {}", renderers.join(", ")));
-                    // Store renderers for the UI (this is a hack, but works for now)
-                    outputs.push(NodeData::String(format!("__available_renderers__{}", renderers.join(","))));
-                }
-            }
+            // Delegates are detected once and cached; Refresh detects them again
+            let renderers = hydra::available_delegates();
+            outputs[0] = NodeData::String(format!("Ready - Renderers: {}", renderers.join(", ")));
         }
         
         outputs
     }
     
-    /// Detect available Hydra render delegates by querying our Hydra script
-    fn detect_available_renderers(&self) -> Result<Vec<String>, String> {
-        // Get Python executable and Hydra script paths
-        let python_path = self.get_python_path()?;
-        let render_script_path = self.get_hydra_render_script_path()?;
-        
-        // Run Hydra script with --list-renderers to see available renderers
-        let output = Command::new(&python_path)
-            .arg(&render_script_path)
-            .arg("--list-renderers")
-            .env("PYTHONPATH", self.get_usd_python_path())
-            .env("DYLD_LIBRARY_PATH", self.get_usd_lib_path())
-            .env("LD_LIBRARY_PATH", self.get_usd_lib_path())
-            .env("USD_INSTALL_ROOT", self.get_usd_install_root())
-            .env("PYTHONDONTWRITEBYTECODE", "1")
-            .env("PYTHONUNBUFFERED", "1")
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
-            .map_err(|e| format!("Failed to run Hydra renderer detection: {}", e))?;
-        
-        let list_text = String::from_utf8_lossy(&output.stdout);
-        let mut renderers = Vec::new();
-        
-        // Parse the output to find available renderers
-        for line in list_text.lines() {
-            let line = line.trim();
-            if line.starts_with("- ") {
-                let renderer = line.trim_start_matches("- ");
-                if !renderer.is_empty() {
-                    renderers.push(renderer.to_string());
-                    println!("🎬 Found Hydra renderer: {}", renderer);
-                }
-            }
-        }
-        
-        // Default fallback
-        if renderers.is_empty() {
-            renderers.push("Storm".to_string()); // Storm is always available in USD
+    /// Frames to render: the range when enabled, otherwise the timeline frame
+    fn frames(&self) -> Vec<i64> {
+        if self.frame_range {
+            frame_sequence(self.start_frame, self.end_frame, self.frame_step)
+        } else {
+            vec![self.current_frame]
         }
-        
-        Ok(renderers)
     }
     
-    /// Execute the render using direct Hydra Python pipeline
-    fn execute_render(&self, scene_data: &NodeData) -> Result<String, String> {
-        // Create temporary USD file from scene data
-        let temp_usd_path = self.create_temp_usd_file(scene_data)?;
-        
-        // Get Python executable path
-        let python_path = self.get_python_path()?;
-        
-        // Get Hydra render script path
-        let render_script_path = self.get_hydra_render_script_path()?;
-        
-        // Build Python command for Hydra rendering
-        let mut cmd = Command::new(&python_path);
-        cmd.arg(&render_script_path);
-        
-        // Basic arguments
-        cmd.arg(&temp_usd_path);  // input USD file
-        cmd.arg(&self.output_path);  // output image file
-        cmd.arg("--renderer").arg(&self.renderer);
-        cmd.arg("--width").arg(self.image_width.to_string());
-        
-        // Calculate height from 16:9 aspect ratio if not specified
-        let image_height = (self.image_width as f32 / 16.0 * 9.0) as i32;
-        cmd.arg("--height").arg(image_height.to_string());
-        
-        // Optional arguments based on parameters
-        if !self.camera_path.is_empty() {
-            cmd.arg("--camera").arg(&self.camera_path);
-        }
-        
-        // Complexity setting
-        if !self.complexity.is_empty() {
-            cmd.arg("--complexity").arg(&self.complexity);
-        }
-        
-        // Set environment variables for USD/Python
-        cmd.env("PYTHONPATH", self.get_usd_python_path());
-        cmd.env("DYLD_LIBRARY_PATH", self.get_usd_lib_path());
-        cmd.env("LD_LIBRARY_PATH", self.get_usd_lib_path());
-        cmd.env("USD_INSTALL_ROOT", self.get_usd_install_root());
-        
-        // Force Python environment isolation and clean shutdown
-        cmd.env("PYTHONDONTWRITEBYTECODE", "1");
-        cmd.env("PYTHONUNBUFFERED", "1");
-        
-        // Add Cycles plugin path if using Cycles, otherwise disable it entirely
-        if self.renderer == "Cycles" {
-            let cycles_plugin_path = self.get_cycles_plugin_path();
-            cmd.env("PXR_PLUGINPATH", cycles_plugin_path);
-        } else {
-            // Clear PXR_PLUGINPATH and disable Cycles plugin loading
-            cmd.env("PXR_PLUGINPATH", "");
-            // Tell USD to not load the hdCycles plugin
-            cmd.env("PXR_DISABLE_PLUGINS", "hdCycles");
-        }
-        
-        println!("🎬 Executing Hydra render command: {:?}", cmd);
-        
-        // Execute the command with live output streaming
-        let mut child = cmd
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| format!("Failed to execute Hydra render script: {}", e))?;
-        
-        // Stream stdout and stderr in real-time using separate threads
-        let stdout_handle = if let Some(stdout) = child.stdout.take() {
-            Some(thread::spawn(move || {
-                let reader = BufReader::new(stdout);
-                let mut lines = Vec::new();
-                for line in reader.lines() {
-                    if let Ok(line) = line {
-                        println!("🎬 [HYDRA] {}", line);
-                        lines.push(line);
-                    }
-                }
-                lines
-            }))
-        } else {
-            None
-        };
-        
-        let stderr_handle = if let Some(stderr) = child.stderr.take() {
-            Some(thread::spawn(move || {
-                let reader = BufReader::new(stderr);
-                let mut lines = Vec::new();
-                for line in reader.lines() {
-                    if let Ok(line) = line {
-                        println!("🎬 [HYDRA-ERROR] {}", line);
-                        lines.push(line);
-                    }
-                }
-                lines
-            }))
-        } else {
-            None
-        };
-        
-        // Wait for the process to complete
-        let status = child.wait()
-            .map_err(|e| format!("Failed to wait for Hydra render process: {}", e))?;
-        
-        println!("🎬 [CLEANUP] Hydra render process completed");
-        
-        // Collect output from threads
-        let _stdout_lines = if let Some(handle) = stdout_handle {
-            handle.join().unwrap_or_default()
-        } else {
-            Vec::new()
-        };
-        
-        let stderr_lines = if let Some(handle) = stderr_handle {
-            handle.join().unwrap_or_default()
-        } else {
-            Vec::new()
-        };
-        
-        // Clean up temp file
-        let _ = fs::remove_file(&temp_usd_path);
-        
-        if status.success() {
-            println!("🎬 Hydra render completed successfully!");
+    /// Queue one render per frame through the direct Hydra Python pipeline
+    fn queue_render(&self, scene_data: &NodeData) -> Result<Vec<queue::JobId>, String> {
+        // Write the scene the jobs render; a file path input is rendered in place
+        let (usd_path, is_temp) = self.create_temp_usd_file(scene_data)?;
+        // Shared by the frames of the sequence and removed after the last one
+        let temp_files = if is_temp { vec![Arc::new(queue::TempFile(usd_path.clone()))] } else { Vec::new() };
+        
+        let mut job_ids = Vec::new();
+        for frame in self.frames() {
+            let output_path = expand_output_tokens(&self.output_path, frame, &self.camera_path);
             
-            // Check if output file was created
-            if Path::new(&self.output_path).exists() {
-                Ok(format!("Hydra rendered to {}", self.output_path))
-            } else {
-                Err("Hydra render completed but output file not found".to_string())
+            // Build Python command for Hydra rendering
+            let mut cmd = hydra::script_command(&self.renderer)?;
+            
+            // Basic arguments
+            cmd.arg(&usd_path);  // input USD file
+            cmd.arg(&output_path);  // output image file
+            cmd.arg("--renderer").arg(&self.renderer);
+            cmd.arg("--frame").arg(frame.to_string());
+            
+            // Calculate height from 16:9 aspect ratio if not specified, then size the region
+            let image_width = self.image_width.max(1) as u32;
+            let image_height = (self.image_width as f32 / 16.0 * 9.0) as u32;
+            let (width, height) = self.region.image_size(image_width, image_height);
+            cmd.arg("--width").arg(width.to_string());
+            cmd.arg("--height").arg(height.to_string());
+            cmd.args(self.region.arguments());
+            
+            // Optional arguments based on parameters
+            if !self.camera_path.is_empty() {
+                cmd.arg("--camera").arg(&self.camera_path);
             }
-        } else {
-            let error_msg = if !stderr_lines.is_empty() {
-                stderr_lines.join("\n")
-            } else {
-                format!("Process exited with code: {:?}", status.code())
-            };
-            Err(format!("Hydra render failed: {}", error_msg))
+            
+            // Complexity setting
+            if !self.complexity.is_empty() {
+                cmd.arg("--complexity").arg(&self.complexity);
+            }
+            
+            let label = format!("{} frame {} → {}", self.renderer, frame, output_path);
+            job_ids.push(queue::submit(self.node_id, label, output_path, cmd, temp_files.clone(), self.open_output));
         }
+        Ok(job_ids)
     }
     
-    /// Create a temporary USD file from scene data
-    fn create_temp_usd_file(&self, scene_data: &NodeData) -> Result<String, String> {
+    /// USD file to render and whether it was written for this render
+    fn create_temp_usd_file(&self, scene_data: &NodeData) -> Result<(String, bool), String> {
         // Handle different types of scene data
         match scene_data {
             NodeData::String(usd_path) => {
                 // If it's a file path, use it directly
                 if Path::new(usd_path).exists() {
                     println!("🎬 Using USD file: {}", usd_path);
-                    return Ok(usd_path.clone());
+                    return Ok((usd_path.clone(), false));
                 } else {
                     return Err(format!("USD file not found: {}", usd_path));
                 }
@@ -321,8 +203,8 @@ impl RenderLogic {
                 fs::create_dir_all(&self.temp_folder)
                     .map_err(|e| format!("Failed to create temp folder '{}': {}", self.temp_folder, e))?;
                 
-                // Create temporary USD file path within the temp folder
-                let temp_usd_path = format!("{}/scene_{}.usda", self.temp_folder, std::process::id());
+                // Create temporary USD file path within the temp folder, one per queued job
+                let temp_usd_path = format!("{}/scene_{}_{}.usda", self.temp_folder, std::process::id(), TEMP_SCENES.fetch_add(1, Ordering::Relaxed));
                 println!("🎬 Creating temporary USD file: {}", temp_usd_path);
                 
                 // Use USD engine to properly save the scene data as a USD file
@@ -331,126 +213,100 @@ impl RenderLogic {
                     .map_err(|e| format!("Failed to save USD scene data to file: {}", e))?;
                 
                 println!("🎬 Successfully saved USD scene data to: {}", temp_usd_path);
-                Ok(temp_usd_path)
+                Ok((temp_usd_path, true))
             }
             #[cfg(not(feature = "usd"))]
             NodeData::USDSceneData(_) => {
                 Err("USD support not enabled. Build with --features usd to enable USD rendering.".to_string())
             }
+            NodeData::Volume(volume) => {
+                // Volumes render from a layer referencing their .vdb file
+                fs::create_dir_all(&self.temp_folder)
+                    .map_err(|e| format!("Failed to create temp folder '{}': {}", self.temp_folder, e))?;
+                let temp_usd_path = format!("{}/scene_{}_{}.usda", self.temp_folder, std::process::id(), TEMP_SCENES.fetch_add(1, Ordering::Relaxed));
+                fs::write(&temp_usd_path, volume_layer(volume))
+                    .map_err(|e| format!("Failed to write volume layer '{}': {}", temp_usd_path, e))?;
+                Ok((temp_usd_path, true))
+            }
             _ => {
                 return Err("Invalid scene data type for rendering. Expected USD file path or scene data.".to_string());
             }
         }
     }
-    
-    /// Open the output file with the system default application
-    fn open_output_file(&self) {
-        if Path::new(&self.output_path).exists() {
-            #[cfg(target_os = "macos")]
-            {
-                let _ = Command::new("open").arg(&self.output_path).spawn();
-            }
-            #[cfg(target_os = "linux")]
-            {
-                let _ = Command::new("xdg-open").arg(&self.output_path).spawn();
-            }
-            #[cfg(target_os = "windows")]
-            {
-                let _ = Command::new("cmd").args(&["/C", "start", &self.output_path]).spawn();
-            }
-        }
-    }
-    
-    /// Get USD bin path from environment or vendor directory
-    fn get_usd_bin_path(&self) -> Result<String, String> {
-        // Try environment variable first
-        if let Ok(usd_root) = env::var("USD_INSTALL_ROOT") {
-            return Ok(format!("{}/bin", usd_root));
-        }
-        
-        // Try relative to current executable (vendor installation)
-        let vendor_usd = "/Users/brian/nodle/nodle/vendor/usd/bin";
-        if Path::new(vendor_usd).exists() {
-            return Ok(vendor_usd.to_string());
-        }
-        
-        Err("USD installation not found. Please set USD_INSTALL_ROOT or ensure vendor/usd exists".to_string())
-    }
-    
-    /// Get USD Python path
-    fn get_usd_python_path(&self) -> String {
-        let vendor_python = "/Users/brian/nodle/nodle/vendor/usd/lib/python:/Users/brian/nodle/nodle/vendor/python-runtime/python/lib/python3.9/site-packages";
-        
-        if let Ok(existing) = env::var("PYTHONPATH") {
-            format!("{}:{}", vendor_python, existing)
-        } else {
-            vendor_python.to_string()
-        }
-    }
-    
-    /// Get USD library path
-    fn get_usd_lib_path(&self) -> String {
-        // Base libraries (USD and Python always needed)
-        let mut lib_paths = vec![
-            "/Users/brian/nodle/nodle/vendor/usd/lib",
-            "/Users/brian/nodle/nodle/vendor/python-runtime/python/lib",
-        ];
-        
-        // Add Cycles libraries only when using Cycles renderer
-        if self.renderer == "Cycles" {
-            lib_paths.push("/Users/brian/nodle/nodle/vendor/cycles/install/lib");
-        }
-        
-        let vendor_lib = lib_paths.join(":");
-        
-        let env_var = if cfg!(target_os = "macos") {
-            "DYLD_LIBRARY_PATH"
-        } else {
-            "LD_LIBRARY_PATH"
-        };
-        
-        // Don't inherit existing environment variables to avoid global Cycles paths
-        // We want to set exactly what we need for this specific renderer
-        vendor_lib
-    }
-    
-    /// Get USD install root
-    fn get_usd_install_root(&self) -> String {
-        env::var("USD_INSTALL_ROOT")
-            .unwrap_or_else(|_| "/Users/brian/nodle/nodle/vendor/usd".to_string())
+}
+
+/// Crop, resolution percentage and, when `with_overscan`, overscan set on a Render or Viewport node
+pub fn render_region(node: &Node, with_overscan: bool) -> RenderRegion {
+    let float = |key: &str, default: f32| node.parameters.get(key)
+        .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+        .unwrap_or(default);
+    let crop_enabled = matches!(node.parameters.get("crop_enabled"), Some(NodeData::Boolean(true)));
+    RenderRegion {
+        crop: crop_enabled.then(|| [float("crop_left", 0.0), float("crop_top", 0.0), float("crop_right", 1.0), float("crop_bottom", 1.0)]),
+        resolution_percent: node.parameters.get("resolution_percent")
+            .and_then(|v| if let NodeData::Integer(i) = v { Some((*i).max(1) as u32) } else { None })
+            .unwrap_or(100),
+        overscan: if with_overscan { float("overscan_percent", 0.0).max(0.0) / 100.0 } else { 0.0 },
     }
-    
-    /// Get Cycles plugin path
-    fn get_cycles_plugin_path(&self) -> String {
-        "/Users/brian/nodle/nodle/vendor/cycles/install/hydra:/Users/brian/nodle/nodle/vendor/cycles/install/usd".to_string()
+}
+
+/// Frames from `start` to `end` inclusive, every `step` frames; a reversed range counts down
+pub fn frame_sequence(start: i32, end: i32, step: i32) -> Vec<i64> {
+    let step = step.unsigned_abs().max(1) as usize;
+    let (start, end) = (start as i64, end as i64);
+    if start <= end {
+        (start..=end).step_by(step).collect()
+    } else {
+        (end..=start).rev().step_by(step).collect()
     }
-    
-    /// Get Python executable path
-    fn get_python_path(&self) -> Result<String, String> {
-        // Use embedded Python from vendor directory
-        let vendor_python = "/Users/brian/nodle/nodle/vendor/python-runtime/python/bin/python3";
-        if Path::new(vendor_python).exists() {
-            return Ok(vendor_python.to_string());
-        }
-        
-        // Fallback to system Python if embedded not found
-        if let Ok(output) = Command::new("which").arg("python3").output() {
-            if output.status.success() {
-                let python_path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-                return Ok(python_path);
+}
+
+/// Output path with its tokens replaced: `$F` is the frame, `$F2`..`$F9` the frame
+/// padded with zeros to that many digits and `$CAMERA` the camera prim's name
+pub fn expand_output_tokens(path: &str, frame: i64, camera_path: &str) -> String {
+    let camera = camera_path.rsplit('/').find(|name| !name.is_empty()).unwrap_or("default");
+    let mut expanded = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(index) = rest.find('$') {
+        expanded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some(after) = rest.strip_prefix("$CAMERA") {
+            expanded.push_str(camera);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("$F") {
+            match after.chars().next().and_then(|c| c.to_digit(10)).filter(|width| *width > 0) {
+                Some(width) => {
+                    let sign = if frame < 0 { "-" } else { "" };
+                    expanded.push_str(&format!("{}{:0width$}", sign, frame.unsigned_abs(), width = width as usize));
+                    rest = &after[1..];
+                }
+                None => {
+                    expanded.push_str(&frame.to_string());
+                    rest = after;
+                }
             }
-        }
-        
-        Err("Python executable not found. Ensure embedded Python is installed or Python 3 is available in PATH".to_string())
-    }
-    
-    /// Get Hydra render script path
-    fn get_hydra_render_script_path(&self) -> Result<String, String> {
-        let script_path = "/Users/brian/nodle/nodle/vendor/usd_hydra_render.py";
-        if Path::new(script_path).exists() {
-            Ok(script_path.to_string())
         } else {
-            Err(format!("Hydra render script not found: {}", script_path))
+            expanded.push('$');
+            rest = &rest[1..];
         }
     }
-}
\ No newline at end of file
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_tokens_and_frame_sequences() {
+        assert_eq!(expand_output_tokens("renders/shot.$F4.png", 7, ""), "renders/shot.0007.png");
+        assert_eq!(expand_output_tokens("$CAMERA/$F.exr", 12, "/World/Cameras/closeUp"), "closeUp/12.exr");
+        assert_eq!(expand_output_tokens("beauty_$F3_$CAMERA.png", -4, ""), "beauty_-004_default.png");
+        assert_eq!(expand_output_tokens("cost$5.png", 1, ""), "cost$5.png");
+
+        assert_eq!(frame_sequence(1, 10, 3), vec![1, 4, 7, 10]);
+        assert_eq!(frame_sequence(5, 5, 1), vec![5]);
+        assert_eq!(frame_sequence(3, 1, 0), vec![3, 2, 1]);
+    }
+}
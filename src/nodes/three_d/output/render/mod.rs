@@ -2,6 +2,7 @@
 
 pub mod parameters;
 pub mod logic;
+pub mod queue;
 
 use crate::nodes::interface::{NodeData, ParameterChange};
 use crate::nodes::{Node, NodeFactory, NodeMetadata, NodeCategory};
@@ -24,7 +25,7 @@ impl NodeFactory for RenderNodeFactory {
         .with_icon("🎬")
         .with_inputs(vec![
             PortDefinition::required("Scene", DataType::Any)
-                .with_description("USD scene data to render")
+                .with_description("USD scene data or a volume to render")
         ])
         .with_outputs(vec![
             // Output nodes typically don't have outputs, but we could add status output
@@ -68,6 +69,15 @@ impl NodeFactory for RenderNodeFactory {
         node.parameters.insert("available_renderers".to_string(), NodeData::String("Storm".to_string())); // Will be populated dynamically
         node.parameters.insert("last_render_status".to_string(), NodeData::String("Ready".to_string()));
         node.parameters.insert("trigger_render".to_string(), NodeData::Boolean(false)); // Only true when render button is clicked
+        // Frames: the timeline frame unless a range is enabled
+        node.parameters.insert("frame_range".to_string(), NodeData::Boolean(false));
+        node.parameters.insert("start_frame".to_string(), NodeData::Integer(1));
+        node.parameters.insert("end_frame".to_string(), NodeData::Integer(24));
+        node.parameters.insert("frame_step".to_string(), NodeData::Integer(1));
+        // Region: full frame at full size
+        node.parameters.insert("resolution_percent".to_string(), NodeData::Integer(100));
+        node.parameters.insert("overscan_percent".to_string(), NodeData::Float(0.0));
+        node.parameters.insert("crop_enabled".to_string(), NodeData::Boolean(false));
         
         // Update port positions
         node.update_port_positions();
@@ -85,9 +95,9 @@ impl RenderNode {
         parameters::RenderParameters::build_interface(node, ui)
     }
     
-    /// Process the Render node's logic
-    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
-        let mut logic = logic::RenderLogic::from_node(node);
+    /// Process the Render node's logic at the timeline frame `current_frame`
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>, current_frame: i64) -> Vec<NodeData> {
+        let mut logic = logic::RenderLogic::from_node(node, current_frame);
         logic.process(inputs)
     }
 }
\ No newline at end of file
@@ -2,7 +2,12 @@
 
 use crate::nodes::interface::{NodeData, ParameterChange};
 use crate::nodes::Node;
-use egui::{Ui, Button, ComboBox, DragValue, TextEdit};
+use crate::nodes::project_paths::{self, PathKind};
+use crate::workspaces::three_d::usd::hydra;
+use super::logic;
+use super::queue::{self, JobState};
+use std::path::PathBuf;
+use egui::{Ui, Button, ComboBox, DragValue, ProgressBar, TextEdit};
 
 pub struct RenderParameters;
 
@@ -18,8 +23,8 @@ impl RenderParameters {
         if let Some(NodeData::String(current_renderer)) = node.parameters.get("renderer") {
             let mut selected_renderer = current_renderer.clone();
             
-            // For now, provide all possible renderers until we fix dynamic detection
-            let available_renderers = vec!["Storm".to_string(), "Cycles".to_string()];
+            // Delegates the Hydra render script reports; Refresh Renderers detects them again
+            let available_renderers = hydra::available_delegates();
             
             ui.horizontal(|ui| {
                 ui.label("Renderer:");
@@ -71,26 +76,15 @@ impl RenderParameters {
         
         // Output path with file picker
         if let Some(NodeData::String(output_path)) = node.parameters.get("output_path") {
-            let mut path = output_path.clone();
             ui.horizontal(|ui| {
                 ui.label("Output:");
-                let response = ui.add(TextEdit::singleline(&mut path).desired_width(200.0));
-                // Update parameter on any change, not just on lost focus
-                if response.changed() {
+                // Relative paths (the default) are written into the project folder
+                if let Some(path) = project_paths::path_browser(ui, output_path, "render_output.png", PathKind::Output(&["png", "jpg", "jpeg", "exr"]), Self::open_save_file_dialog) {
                     changes.push(ParameterChange {
                         parameter: "output_path".to_string(),
-                        value: NodeData::String(path.clone()),
+                        value: NodeData::String(path),
                     });
                 }
-                if ui.button("Browse").clicked() {
-                    // Open file save dialog for output image
-                    if let Some(selected_path) = Self::open_save_file_dialog() {
-                        changes.push(ParameterChange {
-                            parameter: "output_path".to_string(),
-                            value: NodeData::String(selected_path),
-                        });
-                    }
-                }
             });
         }
         
@@ -137,6 +131,8 @@ impl RenderParameters {
             }
         });
         
+        changes.extend(Self::region_interface(node, ui, true));
+        
         ui.separator();
         ui.strong("Camera & Quality");
         ui.separator();
@@ -208,20 +204,63 @@ impl RenderParameters {
             }
         }
         
+        ui.separator();
+        ui.strong("Frames");
+        ui.separator();
+        
+        let get_int = |key: &str, default: i32| node.parameters.get(key)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(default);
+        let mut frame_range = node.parameters.get("frame_range")
+            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+            .unwrap_or(false);
+        if ui.checkbox(&mut frame_range, "Render Frame Range")
+            .on_hover_text("Queue one render per frame; otherwise the timeline frame is rendered")
+            .changed()
+        {
+            changes.push(ParameterChange {
+                parameter: "frame_range".to_string(),
+                value: NodeData::Boolean(frame_range),
+            });
+        }
+        if frame_range {
+            ui.horizontal(|ui| {
+                for (parameter, label, default) in [("start_frame", "Start:", 1), ("end_frame", "End:", 24), ("frame_step", "Step:", 1)] {
+                    ui.label(label);
+                    let mut value = get_int(parameter, default);
+                    let drag = if parameter == "frame_step" { DragValue::new(&mut value).range(1..=1000) } else { DragValue::new(&mut value) };
+                    if ui.add(drag).changed() {
+                        changes.push(ParameterChange {
+                            parameter: parameter.to_string(),
+                            value: NodeData::Integer(value),
+                        });
+                    }
+                }
+            });
+            let count = logic::frame_sequence(get_int("start_frame", 1), get_int("end_frame", 24), get_int("frame_step", 1)).len();
+            ui.label(format!("📝 {} frames; use $F4 in the output path to number them", count));
+        }
+        ui.label("📝 Output tokens: $F (frame), $F4 (zero-padded to 4 digits), $CAMERA (camera name)");
+        
         ui.separator();
         ui.strong("Render");
         ui.separator();
         
-        // Render status
-        if let Some(NodeData::String(status)) = node.parameters.get("last_render_status") {
+        // Render status of the node's latest job
+        let status = queue::node_status(node.id).or_else(|| node.parameters.get("last_render_status")
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None }));
+        if let Some(status) = status {
             ui.horizontal(|ui| {
                 ui.label("Status:");
                 ui.label(status);
             });
         }
         
-        // Render button
-        if ui.add(Button::new("🎬 Render").min_size(egui::vec2(100.0, 30.0))).clicked() {
+        // Render button; each click queues another job
+        if ui.add(Button::new("🎬 Render").min_size(egui::vec2(100.0, 30.0)))
+            .on_hover_text("Queue a render; it runs in the background")
+            .clicked()
+        {
             changes.push(ParameterChange {
                 parameter: "trigger_render".to_string(),
                 value: NodeData::Boolean(true),
@@ -229,6 +268,16 @@ impl RenderParameters {
             println!("🎬 Render button clicked - trigger_render set to true");
         }
         
+        let mut open_when_done = node.parameters.get("open_output")
+            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+            .unwrap_or(false);
+        if ui.checkbox(&mut open_when_done, "Open when done").changed() {
+            changes.push(ParameterChange {
+                parameter: "open_output".to_string(),
+                value: NodeData::Boolean(open_when_done),
+            });
+        }
+        
         // Quick actions
         ui.horizontal(|ui| {
             if ui.small_button("Refresh Renderers").clicked() {
@@ -239,16 +288,132 @@ impl RenderParameters {
             }
             
             if ui.small_button("Open Output").clicked() {
-                changes.push(ParameterChange {
-                    parameter: "open_output".to_string(),
-                    value: NodeData::Boolean(true),
-                });
+                if let Some(NodeData::String(output_path)) = node.parameters.get("output_path") {
+                    queue::open_file(&project_paths::resolve_path_string(output_path));
+                }
             }
         });
         
+        Self::render_queue(node, ui);
+        
+        changes
+    }
+    
+    /// Resolution percentage, crop region and optionally overscan, shared with the viewport's Hydra preview
+    pub fn region_interface(node: &Node, ui: &mut Ui, with_overscan: bool) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let float = |key: &str, default: f32| node.parameters.get(key)
+            .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+            .unwrap_or(default);
+        
+        let percent = node.parameters.get("resolution_percent")
+            .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+            .unwrap_or(100);
+        ui.horizontal(|ui| {
+            ui.label("Resolution:");
+            for preset in [25, 50, 100] {
+                if ui.selectable_label(percent == preset, format!("{}%", preset)).clicked() && percent != preset {
+                    changes.push(ParameterChange {
+                        parameter: "resolution_percent".to_string(),
+                        value: NodeData::Integer(preset),
+                    });
+                }
+            }
+        }).response.on_hover_text("Render at a fraction of the image size for quick tests");
+        
+        if with_overscan {
+            let mut overscan = float("overscan_percent", 0.0);
+            ui.horizontal(|ui| {
+                ui.label("Overscan:");
+                if ui.add(DragValue::new(&mut overscan).range(0.0..=50.0).suffix("%").speed(0.5))
+                    .on_hover_text("Extra image rendered around the camera's view on each side")
+                    .changed()
+                {
+                    changes.push(ParameterChange {
+                        parameter: "overscan_percent".to_string(),
+                        value: NodeData::Float(overscan),
+                    });
+                }
+            });
+        }
+        
+        let mut crop_enabled = node.parameters.get("crop_enabled")
+            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+            .unwrap_or(false);
+        if ui.checkbox(&mut crop_enabled, "Crop Region")
+            .on_hover_text("Render only part of the frame; only the cropped pixels are rendered")
+            .changed()
+        {
+            changes.push(ParameterChange {
+                parameter: "crop_enabled".to_string(),
+                value: NodeData::Boolean(crop_enabled),
+            });
+        }
+        if crop_enabled {
+            ui.horizontal(|ui| {
+                for (parameter, label, default) in [("crop_left", "L", 0.0), ("crop_top", "T", 0.0), ("crop_right", "R", 1.0), ("crop_bottom", "B", 1.0)] {
+                    ui.label(label);
+                    let mut value = float(parameter, default);
+                    if ui.add(DragValue::new(&mut value).range(0.0..=1.0).speed(0.01).max_decimals(2)).changed() {
+                        changes.push(ParameterChange {
+                            parameter: parameter.to_string(),
+                            value: NodeData::Float(value),
+                        });
+                    }
+                }
+            }).response.on_hover_text("Left, top, right and bottom as fractions of the frame");
+        }
+        
         changes
     }
     
+    /// Jobs this node queued, with their progress and a Cancel button
+    fn render_queue(node: &Node, ui: &mut Ui) {
+        let jobs = queue::node_jobs(node.id);
+        if jobs.is_empty() {
+            return;
+        }
+        
+        ui.separator();
+        ui.strong("Queue");
+        for job in &jobs {
+            ui.horizontal(|ui| {
+                match &job.state {
+                    JobState::Queued => {
+                        ui.label("⏳");
+                    }
+                    JobState::Running => {
+                        let bar = match job.progress {
+                            Some(progress) => ProgressBar::new(progress).show_percentage(),
+                            None => ProgressBar::new(0.0).animate(true),
+                        };
+                        ui.add(bar.desired_width(80.0));
+                    }
+                    JobState::Done => {
+                        ui.label("✅");
+                    }
+                    JobState::Failed(error) => {
+                        ui.label("❌").on_hover_text(error);
+                    }
+                    JobState::Cancelled => {
+                        ui.label("⏹");
+                    }
+                }
+                ui.label(&job.label);
+                if !job.state.is_finished() && ui.small_button("Cancel").clicked() {
+                    queue::cancel(job.id);
+                }
+            });
+        }
+        if jobs.iter().any(|job| job.state.is_finished()) && ui.small_button("Clear Finished").clicked() {
+            queue::clear_finished();
+        }
+        if queue::is_busy() {
+            // Keep the progress moving while the panel is open
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+        }
+    }
+    
     /// Open a folder picker dialog
     fn open_folder_dialog() -> Option<String> {
         use rfd::FileDialog;
@@ -264,7 +429,7 @@ impl RenderParameters {
     }
     
     /// Open a save file dialog for output images
-    fn open_save_file_dialog() -> Option<String> {
+    fn open_save_file_dialog() -> Option<PathBuf> {
         use rfd::FileDialog;
         
         let dialog = FileDialog::new()
@@ -274,10 +439,6 @@ impl RenderParameters {
             .add_filter("EXR Images", &["exr"])
             .add_filter("All Files", &["*"]);
             
-        if let Some(path) = dialog.save_file() {
-            path.to_string_lossy().to_string().into()
-        } else {
-            None
-        }
+        dialog.save_file()
     }
 }
\ No newline at end of file
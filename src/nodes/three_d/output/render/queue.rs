@@ -0,0 +1,328 @@
+//! Background render queue
+//!
+//! Clicking Render queues a job instead of blocking the cook. Jobs run one at
+//! a time on a worker thread, in the order they were queued, and report the
+//! progress the render script prints. Queued and running jobs can be
+//! cancelled; finished jobs are kept so the editor can announce them and the
+//! node's Status output can show how its last render went.
+
+use crate::nodes::NodeId;
+use once_cell::sync::Lazy;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+static RENDER_QUEUE: Lazy<Mutex<RenderQueue>> = Lazy::new(|| Mutex::new(RenderQueue::default()));
+
+/// Identifier of a queued render
+pub type JobId = u64;
+
+/// Where a job is in its life
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobState {
+    Queued,
+    Running,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+
+impl JobState {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, JobState::Done | JobState::Failed(_) | JobState::Cancelled)
+    }
+}
+
+/// What the UI sees of a job
+#[derive(Debug, Clone)]
+pub struct RenderJob {
+    pub id: JobId,
+    pub node_id: NodeId,
+    /// Shown in the queue, e.g. "Storm → render_output.png"
+    pub label: String,
+    pub output_path: String,
+    pub state: JobState,
+    /// Fraction done, when the render script reports it
+    pub progress: Option<f32>,
+}
+
+impl RenderJob {
+    /// One-line status, as shown on the node's Status output
+    pub fn status_text(&self) -> String {
+        match &self.state {
+            JobState::Queued => format!("Queued: {}", self.label),
+            JobState::Running => match self.progress {
+                Some(progress) => format!("Rendering {:.0}%: {}", progress * 100.0, self.label),
+                None => format!("Rendering: {}", self.label),
+            },
+            JobState::Done => format!("Hydra rendered to {}", self.output_path),
+            JobState::Failed(error) => format!("Error: {}", error),
+            JobState::Cancelled => format!("Cancelled: {}", self.label),
+        }
+    }
+}
+
+/// A job and what the worker needs to run it
+struct QueuedJob {
+    job: RenderJob,
+    command: Option<Command>,
+    /// Files removed once every job sharing them is over, e.g. the scene written for a sequence
+    temp_files: Vec<Arc<TempFile>>,
+    /// Open the image with the system viewer when it is done
+    open_when_done: bool,
+    cancel: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct RenderQueue {
+    jobs: Vec<QueuedJob>,
+    next_id: JobId,
+    worker_running: bool,
+    /// Jobs finished since the editor last asked
+    finished: Vec<RenderJob>,
+}
+
+/// Queue `command`, which renders to `output_path`, and start the worker if it is idle
+pub fn submit(node_id: NodeId, label: String, output_path: String, command: Command, temp_files: Vec<Arc<TempFile>>, open_when_done: bool) -> JobId {
+    let Ok(mut queue) = RENDER_QUEUE.lock() else {
+        return 0;
+    };
+    queue.next_id += 1;
+    let id = queue.next_id;
+    queue.jobs.push(QueuedJob {
+        job: RenderJob { id, node_id, label, output_path, state: JobState::Queued, progress: None },
+        command: Some(command),
+        temp_files,
+        open_when_done,
+        cancel: Arc::new(AtomicBool::new(false)),
+    });
+    if !queue.worker_running {
+        queue.worker_running = true;
+        thread::spawn(run_worker);
+    }
+    id
+}
+
+/// Cancel a job; queued jobs never start and running ones are killed
+pub fn cancel(id: JobId) {
+    if let Ok(mut queue) = RENDER_QUEUE.lock() {
+        let mut cancelled = None;
+        if let Some(entry) = queue.jobs.iter_mut().find(|entry| entry.job.id == id) {
+            match entry.job.state {
+                JobState::Queued => {
+                    entry.job.state = JobState::Cancelled;
+                    entry.temp_files.clear();
+                    cancelled = Some(entry.job.clone());
+                }
+                // The worker notices the flag, kills the process and reports the job
+                JobState::Running => entry.cancel.store(true, Ordering::SeqCst),
+                _ => {}
+            }
+        }
+        queue.finished.extend(cancelled);
+    }
+}
+
+/// Every job still in the queue, oldest first
+pub fn jobs() -> Vec<RenderJob> {
+    RENDER_QUEUE.lock()
+        .map(|queue| queue.jobs.iter().map(|entry| entry.job.clone()).collect())
+        .unwrap_or_default()
+}
+
+/// Jobs of one node, oldest first
+pub fn node_jobs(node_id: NodeId) -> Vec<RenderJob> {
+    jobs().into_iter().filter(|job| job.node_id == node_id).collect()
+}
+
+/// Status of a node's most recent job; None when it never queued one
+pub fn node_status(node_id: NodeId) -> Option<String> {
+    node_jobs(node_id).last().map(RenderJob::status_text)
+}
+
+/// Remove finished jobs from the queue
+pub fn clear_finished() {
+    if let Ok(mut queue) = RENDER_QUEUE.lock() {
+        queue.jobs.retain(|entry| !entry.job.state.is_finished());
+    }
+}
+
+/// Jobs that finished since the last call, for completion notifications
+pub fn take_finished() -> Vec<RenderJob> {
+    RENDER_QUEUE.lock()
+        .map(|mut queue| std::mem::take(&mut queue.finished))
+        .unwrap_or_default()
+}
+
+/// Whether any job is queued or running
+pub fn is_busy() -> bool {
+    RENDER_QUEUE.lock()
+        .map(|queue| queue.jobs.iter().any(|entry| !entry.job.state.is_finished()))
+        .unwrap_or(false)
+}
+
+/// Run queued jobs one after another until none are left
+fn run_worker() {
+    loop {
+        let next = {
+            let Ok(mut queue) = RENDER_QUEUE.lock() else { return };
+            let next = queue.jobs.iter_mut()
+                .find(|entry| entry.job.state == JobState::Queued)
+                .map(|entry| {
+                    entry.job.state = JobState::Running;
+                    (entry.job.id, entry.command.take(), entry.cancel.clone())
+                });
+            if next.is_none() {
+                queue.worker_running = false;
+            }
+            next
+        };
+        let Some((id, command, cancel)) = next else { return };
+
+        let state = match command {
+            Some(command) => run_job(id, command, &cancel),
+            None => JobState::Failed("Render command missing".to_string()),
+        };
+        finish(id, state);
+    }
+}
+
+/// Run one render to completion, killing it if it gets cancelled
+fn run_job(id: JobId, mut command: Command, cancel: &AtomicBool) -> JobState {
+    println!("🎬 Executing Hydra render command: {:?}", command);
+    let mut child = match command.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => return JobState::Failed(format!("Failed to execute Hydra render script: {}", e)),
+    };
+
+    // Stream output on separate threads; stdout carries the progress
+    let stdout_handle = child.stdout.take().map(|stdout| thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            println!("🎬 [HYDRA] {}", line);
+            if let Some(progress) = parse_progress(&line) {
+                set_progress(id, progress);
+            }
+        }
+    }));
+    let stderr_handle = child.stderr.take().map(|stderr| thread::spawn(move || {
+        BufReader::new(stderr).lines().map_while(Result::ok)
+            .inspect(|line| println!("🎬 [HYDRA-ERROR] {}", line))
+            .collect::<Vec<_>>()
+    }));
+
+    let status = loop {
+        if cancel.load(Ordering::SeqCst) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return JobState::Cancelled;
+        }
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => thread::sleep(Duration::from_millis(100)),
+            Err(e) => return JobState::Failed(format!("Failed to wait for Hydra render process: {}", e)),
+        }
+    };
+
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    let stderr_lines = stderr_handle.and_then(|handle| handle.join().ok()).unwrap_or_default();
+
+    if status.success() {
+        JobState::Done
+    } else if stderr_lines.is_empty() {
+        JobState::Failed(format!("Hydra render failed: process exited with code: {:?}", status.code()))
+    } else {
+        JobState::Failed(format!("Hydra render failed: {}", stderr_lines.join("\n")))
+    }
+}
+
+fn set_progress(id: JobId, progress: f32) {
+    if let Ok(mut queue) = RENDER_QUEUE.lock() {
+        if let Some(entry) = queue.jobs.iter_mut().find(|entry| entry.job.id == id) {
+            entry.job.progress = Some(progress);
+        }
+    }
+}
+
+/// Record how a job ended, clean up after it and announce it
+fn finish(id: JobId, mut state: JobState) {
+    let Ok(mut queue) = RENDER_QUEUE.lock() else { return };
+    let Some(entry) = queue.jobs.iter_mut().find(|entry| entry.job.id == id) else { return };
+    if state == JobState::Done && !Path::new(&entry.job.output_path).exists() {
+        state = JobState::Failed("Hydra render completed but output file not found".to_string());
+    }
+    if state == JobState::Done {
+        entry.job.progress = Some(1.0);
+        if entry.open_when_done {
+            open_file(&entry.job.output_path);
+        }
+    }
+    entry.job.state = state;
+    entry.temp_files.clear();
+    let job = entry.job.clone();
+    queue.finished.push(job);
+}
+
+/// A file written for rendering, removed when the last job using it lets go
+#[derive(Debug)]
+pub struct TempFile(pub String);
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Open a file with the system default application
+pub fn open_file(path: &str) {
+    if Path::new(path).exists() {
+        #[cfg(target_os = "macos")]
+        {
+            let _ = Command::new("open").arg(path).spawn();
+        }
+        #[cfg(target_os = "linux")]
+        {
+            let _ = Command::new("xdg-open").arg(path).spawn();
+        }
+        #[cfg(target_os = "windows")]
+        {
+            let _ = Command::new("cmd").args(["/C", "start", path]).spawn();
+        }
+    }
+}
+
+/// Fraction done in a line of render output: "42%", "Progress: 42.5 %" or "progress 0.42"
+pub fn parse_progress(line: &str) -> Option<f32> {
+    let lower = line.to_lowercase();
+    if let Some(percent) = lower.find('%') {
+        let number: String = lower[..percent].trim_end().chars().rev()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect::<Vec<_>>().into_iter().rev().collect();
+        return number.parse::<f32>().ok().map(|value| (value / 100.0).clamp(0.0, 1.0));
+    }
+    let rest = lower.trim().strip_prefix("progress")?;
+    rest.trim_start_matches([':', ' ']).split_whitespace().next()?
+        .parse::<f32>().ok()
+        .filter(|value| (0.0..=1.0).contains(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_is_read_from_render_output() {
+        assert_eq!(parse_progress("Rendering... 42%"), Some(0.42));
+        assert_eq!(parse_progress("Progress: 12.5 %"), Some(0.125));
+        assert_eq!(parse_progress("progress 0.5"), Some(0.5));
+        assert_eq!(parse_progress("Progress: 7 of 10 tiles"), None);
+        assert_eq!(parse_progress("Loading stage"), None);
+        assert_eq!(parse_progress("100%"), Some(1.0));
+    }
+}
@@ -0,0 +1,178 @@
+//! Write USD node functional operations - exporting scenes to disk
+//!
+//! Scenes loaded from a file are exported from their USD stage, which keeps
+//! everything Nodle does not extract (materials, composition, custom
+//! schemas). Procedural scenes, or any scene when "from scene data" is set,
+//! are first rebuilt from the cooked meshes; this is also the way to keep
+//! edits made by nodes that only change the cooked data, like Transform and
+//! Prune.
+
+use std::path::Path;
+use crate::nodes::NodeId;
+use crate::nodes::interface::NodeData;
+use crate::workspaces::three_d::usd::usd_engine::{USDExportOptions, USDSceneData, GLOBAL_USD_ENGINE};
+
+/// File extensions USD can write
+pub const USD_EXTENSIONS: [&str; 3] = ["usda", "usdc", "usd"];
+
+/// Root prim of scenes rebuilt from cooked data
+const REBUILT_ROOT_PRIM: &str = "Root";
+
+/// What the output file holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportMode {
+    /// The composed stage baked into one self-contained layer
+    #[default]
+    Flatten,
+    /// A layer sublayering the source file, carrying only the export's metadata
+    Layer,
+}
+
+impl ExportMode {
+    pub const NAMES: [&'static str; 2] = ["Flatten", "Layer"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Flatten" => Some(Self::Flatten),
+            "Layer" => Some(Self::Layer),
+            _ => None,
+        }
+    }
+}
+
+/// Core Write USD configuration
+#[derive(Debug, Clone, Default)]
+pub struct WriteUSDLogic {
+    pub file_path: String,
+    /// Root prim to set as default; empty keeps the source's
+    pub default_prim: String,
+    /// "Y", "Z", or "Scene" to keep the incoming scene's
+    pub up_axis: String,
+    pub mode: ExportMode,
+    /// Rebuild the stage from the cooked meshes even when the scene has a source file
+    pub from_scene_data: bool,
+}
+
+/// Check `path` names a file USD can write
+pub fn check_output_path(path: &str) -> Result<(), String> {
+    if path.trim().is_empty() {
+        return Err("No output file set".to_string());
+    }
+    let extension = Path::new(path).extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    if USD_EXTENSIONS.contains(&extension.as_str()) {
+        Ok(())
+    } else {
+        Err(format!("{} is not a .usda, .usdc or .usd file", path))
+    }
+}
+
+/// "World" for "/World"; default prims must be root prims
+pub fn default_prim_name(text: &str) -> Result<String, String> {
+    let name = text.trim().trim_start_matches('/');
+    if name.contains('/') {
+        Err(format!("Default prim {} is not a root prim", text.trim()))
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+impl WriteUSDLogic {
+    /// Export options for `scene`; `rebuilt` when it was rebuilt from cooked data
+    pub fn options(&self, scene: &USDSceneData, rebuilt: bool) -> Result<USDExportOptions, String> {
+        let mut default_prim = default_prim_name(&self.default_prim)?;
+        if default_prim.is_empty() && rebuilt {
+            default_prim = REBUILT_ROOT_PRIM.to_string();
+        }
+        let up_axis = match self.up_axis.as_str() {
+            "Y" | "Z" => self.up_axis.clone(),
+            _ => scene.up_axis.to_uppercase(),
+        };
+        Ok(USDExportOptions {
+            default_prim,
+            up_axis,
+            // A layer can't sublayer the temporary file a rebuilt scene goes through
+            flatten: rebuilt || self.mode == ExportMode::Flatten,
+        })
+    }
+
+    /// Write `scene` to the output file, returning a status line
+    pub fn write(&self, node_id: NodeId, scene: &USDSceneData) -> Result<String, String> {
+        check_output_path(&self.file_path)?;
+        let output_path = self.file_path.trim();
+        if let Some(parent) = Path::new(output_path).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+
+        let mut engine = GLOBAL_USD_ENGINE.lock().map_err(|_| "USD engine is unavailable".to_string())?;
+        let rebuilt = self.from_scene_data || !Path::new(&scene.stage_path).is_file();
+        let source_path = if rebuilt {
+            let temp_path = std::env::temp_dir()
+                .join(format!("nodle_write_usd_{}.usda", node_id))
+                .to_string_lossy()
+                .to_string();
+            // CreateNew refuses to overwrite the previous cook's file
+            let _ = std::fs::remove_file(&temp_path);
+            engine.save_usd_scene_to_file(scene, &temp_path)?;
+            temp_path
+        } else {
+            // Sublayer paths resolve against the output file, so make the source absolute
+            std::fs::canonicalize(&scene.stage_path)
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_else(|_| scene.stage_path.clone())
+        };
+        let options = self.options(scene, rebuilt)?;
+        engine.export_stage(&source_path, output_path, &options)?;
+        Ok(format!("Wrote {} ({} meshes)", output_path, scene.meshes.len()))
+    }
+
+    /// Write the incoming USD scene, passing it through along with the status
+    pub fn process(&self, node_id: NodeId, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let Some(NodeData::USDSceneData(scene)) = inputs.into_iter().next() else {
+            return vec![NodeData::None, NodeData::String("No USD scene connected".to_string())];
+        };
+        let status = match self.write(node_id, &scene) {
+            Ok(status) => status,
+            Err(e) => {
+                log::error!("Write USD: {}", e);
+                format!("Error: {}", e)
+            }
+        };
+        vec![NodeData::USDSceneData(scene), NodeData::String(status)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_and_options_are_validated() {
+        assert!(check_output_path("out/scene.usda").is_ok());
+        assert!(check_output_path("scene.USDC").is_ok());
+        assert!(check_output_path("scene.obj").is_err());
+        assert!(check_output_path("  ").is_err());
+
+        assert_eq!(default_prim_name("/World").unwrap(), "World");
+        assert!(default_prim_name("/World/Chair").is_err());
+
+        let scene = USDSceneData {
+            stage_path: "procedural://cube_node_1".to_string(),
+            meshes: Vec::new(),
+            lights: Vec::new(),
+            materials: Vec::new(),
+            up_axis: "y".to_string(),
+            instancers: Vec::new(),
+        };
+        let logic = WriteUSDLogic { up_axis: "Scene".to_string(), mode: ExportMode::Layer, ..Default::default() };
+        let rebuilt = logic.options(&scene, true).unwrap();
+        assert_eq!(rebuilt.default_prim, "Root");
+        assert_eq!(rebuilt.up_axis, "Y");
+        assert!(rebuilt.flatten);
+        let layered = WriteUSDLogic { up_axis: "Z".to_string(), ..logic }.options(&scene, false).unwrap();
+        assert_eq!(layered, USDExportOptions { default_prim: String::new(), up_axis: "Z".to_string(), flatten: false });
+    }
+}
@@ -0,0 +1,60 @@
+//! Write USD node module - exporting scenes to .usda/.usdc files
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Choosing the export source and writing the file
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::WriteUSDLogic;
+pub use parameters::WriteUSDNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::WriteUSDNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "3D_WriteUSD",
+            "Write USD",
+            crate::nodes::NodeCategory::new(&["3D", "Output"]),
+            "Writes the incoming USD scene to a .usda or .usdc file every time it cooks"
+        )
+        .with_color(egui::Color32::from_rgb(220, 80, 80))
+        .with_icon("💾")
+        .with_inputs(vec![
+            crate::nodes::PortDefinition::required("Scene", crate::nodes::DataType::Any)
+                .with_description("USD scene data to write"),
+        ])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::optional("Scene", crate::nodes::DataType::Any)
+                .with_description("The input scene, passed through"),
+            crate::nodes::PortDefinition::optional("Status", crate::nodes::DataType::String)
+                .with_description("File written, or why writing failed"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::string("file_path", "File", "output.usda")
+                .with_tooltip("Output file; the extension picks the format (.usda text, .usdc binary)"),
+            crate::nodes::ParameterDefinition::choice("mode", "Export", logic::ExportMode::NAMES.to_vec(), "Flatten")
+                .with_tooltip("Flatten bakes the composed stage into one file; Layer writes a layer sublayering the source file"),
+            crate::nodes::ParameterDefinition::string("default_prim", "Default Prim", "")
+                .with_tooltip("Root prim referencing the file brings in, e.g. /World; empty keeps the source's")
+                .in_group("Stage Metadata"),
+            crate::nodes::ParameterDefinition::choice("up_axis", "Up Axis", vec!["Scene", "Y", "Z"], "Scene")
+                .with_tooltip("Scene keeps the up axis of the incoming scene")
+                .in_group("Stage Metadata"),
+            crate::nodes::ParameterDefinition::boolean("from_scene_data", "From Scene Data", false)
+                .with_tooltip("Rebuild the file from the cooked meshes instead of the source stage. Keeps edits of nodes like Transform and Prune, but drops materials and composition")
+                .in_group("Advanced"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["3d", "output", "usd", "write", "export", "save", "usda", "usdc", "flatten"])
+        .with_subtitle(|node| {
+            let logic = parameters::WriteUSDNode::create_logic(node);
+            std::path::Path::new(&logic.file_path).file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "USD"])
+    }
+}
@@ -0,0 +1,32 @@
+//! Write USD node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::{ExportMode, WriteUSDLogic};
+
+/// Write USD node
+#[derive(Debug, Clone, Default)]
+pub struct WriteUSDNode;
+
+impl WriteUSDNode {
+    /// Build WriteUSDLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> WriteUSDLogic {
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default();
+        WriteUSDLogic {
+            file_path: text("file_path"),
+            default_prim: text("default_prim"),
+            up_axis: text("up_axis"),
+            mode: ExportMode::from_name(&text("mode")).unwrap_or_default(),
+            from_scene_data: node.parameters.get("from_scene_data")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(false),
+        }
+    }
+
+    /// Execute the node on its inputs
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, inputs)
+    }
+}
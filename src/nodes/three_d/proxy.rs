@@ -0,0 +1,195 @@
+//! Viewport proxies for heavy geometry
+//!
+//! In draft mode viewers are fed a decimated copy of scenes above a triangle
+//! budget, while render and export nodes keep cooking the full-resolution
+//! result. Decimation uses vertex clustering: vertices are snapped to a grid
+//! over the mesh bounds, each occupied cell becomes one vertex and triangles
+//! that collapse are dropped.
+
+use std::collections::{HashMap, HashSet};
+use glam::Vec3;
+use crate::workspaces::three_d::usd::usd_engine::{USDMeshGeometry, USDSceneData};
+
+/// Scenes with more triangles than this are shown as a proxy in draft mode
+pub const PROXY_TRIANGLE_BUDGET: usize = 200_000;
+
+/// Meshes are never reduced below this many triangles
+const MIN_MESH_TRIANGLES: usize = 12;
+
+/// Total triangle count of a scene
+pub fn triangle_count(scene: &USDSceneData) -> usize {
+    scene.meshes.iter().map(|mesh| mesh.indices.len() / 3).sum()
+}
+
+/// Decimated copy of `scene` with about `budget` triangles, or None if the
+/// scene is already within budget
+pub fn decimate_scene(scene: &USDSceneData, budget: usize) -> Option<USDSceneData> {
+    let total = triangle_count(scene);
+    if total <= budget {
+        return None;
+    }
+
+    // Every mesh gives up the same share of its triangles
+    let ratio = budget as f64 / total as f64;
+    let meshes = scene.meshes.iter()
+        .map(|mesh| {
+            let triangles = mesh.indices.len() / 3;
+            let target = ((triangles as f64 * ratio) as usize).max(MIN_MESH_TRIANGLES);
+            decimate_mesh(mesh, target)
+        })
+        .collect();
+
+    Some(USDSceneData {
+        stage_path: scene.stage_path.clone(),
+        meshes,
+        lights: scene.lights.clone(),
+        materials: scene.materials.clone(),
+        up_axis: scene.up_axis.clone(),
+        instancers: Vec::new(),
+    })
+}
+
+/// Reduce `mesh` to at most `target` triangles (coarser grids are tried until
+/// it fits). Primvars are dropped since they index the full-resolution topology.
+pub fn decimate_mesh(mesh: &USDMeshGeometry, target: usize) -> USDMeshGeometry {
+    if mesh.indices.len() / 3 <= target || mesh.vertices.is_empty() {
+        return mesh.clone();
+    }
+
+    // A surface clustered on an n³ grid occupies roughly n² cells, with two triangles per cell
+    let mut resolution = ((target as f64 / 2.0).sqrt().ceil() as u32).max(2);
+    loop {
+        let proxy = cluster_vertices(mesh, resolution);
+        if proxy.indices.len() / 3 <= target || resolution <= 2 {
+            return proxy;
+        }
+        resolution = (resolution * 3 / 4).max(2);
+    }
+}
+
+/// Merge the vertices of `mesh` that fall into the same cell of a `resolution`³ grid
+fn cluster_vertices(mesh: &USDMeshGeometry, resolution: u32) -> USDMeshGeometry {
+    let (min, max) = mesh.vertices.iter().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), vertex| (min.min(*vertex), max.max(*vertex)),
+    );
+    let cell_size = ((max - min).max_element() / resolution as f32).max(f32::EPSILON);
+    let cell_of = |vertex: Vec3| {
+        let cell = ((vertex - min) / cell_size).floor();
+        let clamp = |value: f32| (value.max(0.0) as u32).min(resolution - 1);
+        (clamp(cell.x), clamp(cell.y), clamp(cell.z))
+    };
+
+    // One proxy vertex per occupied cell, placed at the average of its members;
+    // other attributes come from the first vertex that landed in the cell
+    let mut cells: HashMap<(u32, u32, u32), u32> = HashMap::new();
+    let mut representatives: Vec<usize> = Vec::new();
+    let mut sums: Vec<(Vec3, u32)> = Vec::new();
+    let remap: Vec<u32> = mesh.vertices.iter().enumerate()
+        .map(|(index, vertex)| {
+            let proxy_index = *cells.entry(cell_of(*vertex)).or_insert_with(|| {
+                representatives.push(index);
+                sums.push((Vec3::ZERO, 0));
+                (representatives.len() - 1) as u32
+            });
+            let sum = &mut sums[proxy_index as usize];
+            sum.0 += *vertex;
+            sum.1 += 1;
+            proxy_index
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut indices = Vec::new();
+    for triangle in mesh.indices.chunks_exact(3) {
+        let Some(corners) = triangle.iter()
+            .map(|&index| remap.get(index as usize).copied())
+            .collect::<Option<Vec<u32>>>()
+        else {
+            continue;
+        };
+        let (a, b, c) = (corners[0], corners[1], corners[2]);
+        if a == b || b == c || a == c {
+            continue;
+        }
+        let mut key = [a, b, c];
+        key.sort_unstable();
+        if seen.insert(key) {
+            indices.extend_from_slice(&[a, b, c]);
+        }
+    }
+
+    let vertex_count = mesh.vertices.len();
+    USDMeshGeometry {
+        prim_path: mesh.prim_path.clone(),
+        vertices: sums.iter().map(|(sum, count)| *sum / *count as f32).collect(),
+        indices,
+        normals: pick_per_vertex(&mesh.normals, &representatives, vertex_count).unwrap_or_default(),
+        uvs: pick_per_vertex(&mesh.uvs, &representatives, vertex_count).unwrap_or_default(),
+        vertex_colors: mesh.vertex_colors.as_ref()
+            .and_then(|colors| pick_per_vertex(colors, &representatives, vertex_count)),
+        transform: mesh.transform,
+        primvars: Vec::new(),
+        attributes: mesh.attributes.clone(),
+    }
+}
+
+/// The values of the `representatives`, if `values` holds one per vertex
+fn pick_per_vertex<T: Copy>(values: &[T], representatives: &[usize], vertex_count: usize) -> Option<Vec<T>> {
+    (values.len() == vertex_count).then(|| representatives.iter().map(|&index| values[index]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+
+    /// A flat grid of `size`×`size` quads
+    fn grid_mesh(size: u32) -> USDMeshGeometry {
+        let row = size + 1;
+        let vertices = (0..row * row)
+            .map(|index| Vec3::new((index % row) as f32, 0.0, (index / row) as f32))
+            .collect::<Vec<_>>();
+        let mut indices = Vec::new();
+        for z in 0..size {
+            for x in 0..size {
+                let corner = z * row + x;
+                indices.extend_from_slice(&[corner, corner + 1, corner + row]);
+                indices.extend_from_slice(&[corner + 1, corner + row + 1, corner + row]);
+            }
+        }
+        USDMeshGeometry {
+            prim_path: "/Grid".to_string(),
+            normals: vec![Vec3::Y; vertices.len()],
+            uvs: Vec::new(),
+            vertex_colors: None,
+            vertices,
+            indices,
+            transform: Mat4::IDENTITY,
+            primvars: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_heavy_scenes_are_decimated_within_budget() {
+        let scene = USDSceneData {
+            stage_path: "grid".to_string(),
+            meshes: vec![grid_mesh(100)],
+            lights: Vec::new(),
+            materials: Vec::new(),
+            up_axis: "Y".to_string(),
+            instancers: Vec::new(),
+        };
+        assert_eq!(triangle_count(&scene), 20_000);
+        assert!(decimate_scene(&scene, 20_000).is_none());
+
+        let proxy = decimate_scene(&scene, 2_000).unwrap();
+        let mesh = &proxy.meshes[0];
+        let triangles = triangle_count(&proxy);
+        assert!(triangles > 0 && triangles <= 2_000, "{} triangles", triangles);
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+        assert!(mesh.indices.iter().all(|&index| (index as usize) < mesh.vertices.len()));
+        assert_eq!(mesh.prim_path, "/Grid");
+    }
+}
@@ -35,7 +35,7 @@ impl Default for Camera3D {
             position: Vec3::new(5.0, 5.0, 5.0),
             target: Vec3::ZERO,
             up: Vec3::Y,
-            fov: 45.0_f32.to_radians(),
+            fov: crate::settings::current().viewport.field_of_view.to_radians(),
             near: 0.1,
             far: 100.0,
             aspect: 1.0,
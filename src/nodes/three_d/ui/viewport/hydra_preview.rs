@@ -0,0 +1,223 @@
+//! Hydra preview of the viewport
+//!
+//! When a viewport node picks a Hydra render delegate, each cook saves its
+//! scene as a .usda file and the viewport panel renders it in the background
+//! through the Hydra render script, from the viewport camera. The latest image
+//! is drawn over the built-in renderer; moving the camera or changing a
+//! delegate setting renders again once the input has been still for a moment.
+
+use crate::nodes::NodeId;
+use crate::viewport::CameraData;
+use crate::workspaces::three_d::usd::hydra::{self, RenderRegion};
+use crate::workspaces::three_d::usd::usd_engine::{USDEngine, USDSceneData};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long the request must stay unchanged before a render starts
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Latest scene file of each viewport node and how many times it was saved
+static SCENE_FILES: Lazy<Mutex<HashMap<NodeId, (String, u64)>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Preview state of each viewport node
+static PREVIEWS: Lazy<Mutex<HashMap<NodeId, Preview>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// What the viewport wants rendered
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewRequest {
+    pub delegate: String,
+    pub settings: Vec<(String, String)>,
+    pub aov: Option<String>,
+    pub camera: CameraData,
+    /// Viewport size in pixels
+    pub width: u32,
+    pub height: u32,
+    /// Resolution and crop of the image
+    pub region: RenderRegion,
+}
+
+/// What the panel draws for a node
+#[derive(Debug, Clone)]
+pub struct PreviewState {
+    /// Latest finished image
+    pub image: Option<String>,
+    /// Part of the viewport the image covers: left, top, right, bottom fractions
+    pub image_crop: [f32; 4],
+    /// Image replaced since the last update; its texture can be forgotten
+    pub replaced: Option<String>,
+    /// A render is waiting or running
+    pub rendering: bool,
+    pub error: Option<String>,
+}
+
+impl Default for PreviewState {
+    fn default() -> Self {
+        Self { image: None, image_crop: [0.0, 0.0, 1.0, 1.0], replaced: None, rendering: false, error: None }
+    }
+}
+
+#[derive(Default)]
+struct Preview {
+    /// Request and scene version of the last render started
+    started: Option<(PreviewRequest, u64)>,
+    /// Request waiting for the input to settle
+    pending: Option<(PreviewRequest, u64, Instant)>,
+    /// Set by the render thread when it finishes
+    result: Option<Arc<Mutex<Option<Result<String, String>>>>>,
+    image: Option<String>,
+    /// Crop of the finished image and of the render running
+    image_crop: [f32; 4],
+    running_crop: [f32; 4],
+    error: Option<String>,
+    renders: u64,
+}
+
+fn preview_folder() -> PathBuf {
+    std::env::temp_dir().join("nodle_viewport")
+}
+
+/// Save the scene a viewport node cooked so the preview can render it
+pub fn store_scene(node_id: NodeId, scene: &USDSceneData) -> Result<(), String> {
+    let folder = preview_folder();
+    fs::create_dir_all(&folder)
+        .map_err(|e| format!("Failed to create preview folder '{}': {}", folder.display(), e))?;
+    let path = folder.join(format!("scene_{}.usda", node_id)).to_string_lossy().to_string();
+    USDEngine::new().save_usd_scene_to_file(scene, &path)?;
+    scene_saved(node_id, path);
+    Ok(())
+}
+
+/// Save a ready-made layer as the scene a viewport node cooked
+pub fn store_layer(node_id: NodeId, text: &str) -> Result<(), String> {
+    let folder = preview_folder();
+    fs::create_dir_all(&folder)
+        .map_err(|e| format!("Failed to create preview folder '{}': {}", folder.display(), e))?;
+    let path = folder.join(format!("scene_{}.usda", node_id));
+    fs::write(&path, text).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    scene_saved(node_id, path.to_string_lossy().to_string());
+    Ok(())
+}
+
+/// Record a new version of a node's scene file so the preview renders it again
+fn scene_saved(node_id: NodeId, path: String) {
+    if let Ok(mut files) = SCENE_FILES.lock() {
+        let version = files.get(&node_id).map_or(0, |(_, version)| version + 1);
+        files.insert(node_id, (path, version));
+    }
+}
+
+/// Drop the scene file and preview of a deleted node
+pub fn forget(node_id: NodeId) {
+    if let Some((path, _)) = SCENE_FILES.lock().ok().and_then(|mut files| files.remove(&node_id)) {
+        let _ = fs::remove_file(path);
+    }
+    if let Some(image) = PREVIEWS.lock().ok().and_then(|mut previews| previews.remove(&node_id)).and_then(|preview| preview.image) {
+        let _ = fs::remove_file(image);
+    }
+}
+
+/// Advance the preview of a node towards `request` and report what to draw
+pub fn update(node_id: NodeId, request: PreviewRequest) -> PreviewState {
+    let Some((scene_file, version)) = SCENE_FILES.lock().ok().and_then(|files| files.get(&node_id).cloned()) else {
+        return PreviewState { error: Some("Waiting for the scene to cook".to_string()), ..Default::default() };
+    };
+    let Ok(mut previews) = PREVIEWS.lock() else {
+        return PreviewState::default();
+    };
+    let preview = previews.entry(node_id).or_default();
+    let mut replaced = None;
+
+    // Collect a finished render
+    let finished = preview.result.as_ref().and_then(|result| result.lock().ok().and_then(|mut result| result.take()));
+    if let Some(result) = finished {
+        preview.result = None;
+        match result {
+            Ok(image) => {
+                replaced = preview.image.replace(image);
+                preview.image_crop = preview.running_crop;
+                if let Some(old) = &replaced {
+                    let _ = fs::remove_file(old);
+                }
+                preview.error = None;
+            }
+            Err(e) => preview.error = Some(e),
+        }
+    }
+
+    let wanted = (request, version);
+    if preview.started.as_ref() == Some(&wanted) {
+        preview.pending = None;
+    } else {
+        match &preview.pending {
+            Some((request, version, since)) if (request, *version) == (&wanted.0, wanted.1) => {
+                if preview.result.is_none() && since.elapsed() >= DEBOUNCE {
+                    preview.renders += 1;
+                    preview.running_crop = wanted.0.region.crop_rect();
+                    preview.result = Some(start_render(node_id, preview.renders, scene_file, wanted.0.clone()));
+                    preview.started = Some(wanted);
+                    preview.pending = None;
+                }
+            }
+            _ => preview.pending = Some((wanted.0, wanted.1, Instant::now())),
+        }
+    }
+
+    PreviewState {
+        image: preview.image.clone(),
+        image_crop: preview.image_crop,
+        replaced,
+        rendering: preview.result.is_some() || preview.pending.is_some(),
+        error: preview.error.clone(),
+    }
+}
+
+/// Render on a background thread; the returned slot receives the image path or the error
+fn start_render(node_id: NodeId, index: u64, scene_file: String, request: PreviewRequest) -> Arc<Mutex<Option<Result<String, String>>>> {
+    let result = Arc::new(Mutex::new(None));
+    let slot = result.clone();
+    thread::spawn(move || {
+        let rendered = render(node_id, index, &scene_file, &request);
+        if let Ok(mut slot) = slot.lock() {
+            *slot = Some(rendered);
+        }
+    });
+    result
+}
+
+fn render(node_id: NodeId, index: u64, scene_file: &str, request: &PreviewRequest) -> Result<String, String> {
+    let folder = preview_folder();
+    // The camera frames the whole viewport; the script narrows it to the crop
+    let aspect = request.width as f32 / request.height.max(1) as f32;
+    let layer = hydra::camera_layer(scene_file, &request.camera, aspect);
+    let (width, height) = request.region.image_size(request.width, request.height);
+    let layer_path = folder.join(format!("camera_{}.usda", node_id));
+    fs::write(&layer_path, layer)
+        .map_err(|e| format!("Failed to write camera layer: {}", e))?;
+    // A new name per render, so the image cache never shows a stale frame
+    let image_path = folder.join(format!("preview_{}_{}.png", node_id, index)).to_string_lossy().to_string();
+
+    let output = hydra::script_command(&request.delegate)?
+        .arg(&layer_path)
+        .arg(&image_path)
+        .arg("--renderer").arg(&request.delegate)
+        .arg("--width").arg(width.to_string())
+        .arg("--height").arg(height.to_string())
+        .arg("--camera").arg(hydra::VIEWPORT_CAMERA_PATH)
+        .args(hydra::delegate_arguments(&request.settings, request.aov.as_deref()))
+        .args(request.region.arguments())
+        .output()
+        .map_err(|e| format!("Failed to run Hydra render script: {}", e))?;
+
+    if output.status.success() && PathBuf::from(&image_path).exists() {
+        Ok(image_path)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = stderr.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or("no output");
+        Err(format!("{} render failed: {}", request.delegate, message))
+    }
+}
@@ -1,13 +1,15 @@
 //! Core viewport node with complete USD viewport functionality
 
 pub mod viewport_node;
+pub mod hydra_preview;
+pub mod volume_display;
 mod camera;
 mod logic;
 mod properties;
 mod usd_rendering;
 pub mod hooks;
 
-pub use viewport_node::{ViewportNode, GPU_VIEWPORT_CACHE, USD_RENDERER_CACHE};
+pub use viewport_node::{ViewportNode, GPU_VIEWPORT_CACHE, USD_RENDERER_CACHE, SELECTION_PARAMETER};
 pub use camera::{Camera3D, Vertex3D};
 pub use logic::USDViewportLogic;
 pub use properties::{ViewportProperties, ShadingMode, CameraMode};
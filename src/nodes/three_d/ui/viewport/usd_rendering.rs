@@ -252,8 +252,9 @@ impl USDRenderer {
     
     /// Convert USDEngine scene data to renderer format
     fn convert_usd_scene_to_renderer(&mut self, usd_scene_data: &USDSceneData) {
-        // Convert USD meshes to renderer geometry
-        for usd_mesh in &usd_scene_data.meshes {
+        // Convert USD meshes, and the instances of point instancers, to renderer geometry
+        let instanced_meshes = usd_scene_data.instanced_meshes();
+        for usd_mesh in usd_scene_data.meshes.iter().chain(&instanced_meshes).filter(|mesh| mesh.is_active()) {
             // Convert Vec3 vertices to Vertex3D
             let vertices: Vec<Vertex3D> = usd_mesh.vertices.iter().enumerate().map(|(i, &pos)| {
                 let normal = if i < usd_mesh.normals.len() {
@@ -317,7 +318,7 @@ impl USDRenderer {
                 intensity: usd_light.intensity,
                 color: usd_light.color,
                 transform: usd_light.transform,
-                exposure: 0.0,
+                exposure: usd_light.exposure,
                 cone_angle: None,
                 cone_softness: None,
             };
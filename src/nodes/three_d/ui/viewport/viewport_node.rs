@@ -10,9 +10,21 @@ use once_cell::sync::Lazy;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use crate::viewport::*;
+use crate::viewport::selection::{self, SelectMode};
+use super::hydra_preview;
+use super::volume_display::{self, VolumeDisplay, SLICE_AXES};
 use super::logic::USDViewportLogic;
 use super::usd_rendering::USDRenderer;
 use glam::{Mat4, Vec3};
+use crate::workspaces::three_d::usd::hydra::{self, SettingKind};
+use crate::nodes::three_d::output::render::logic::render_region;
+use crate::nodes::three_d::output::render::parameters::RenderParameters;
+
+/// Parameter mirroring the prim selection, so the Selection output re-cooks when it changes
+pub const SELECTION_PARAMETER: &str = "selection";
+
+/// Renderer choice drawing the viewport with the GPU renderer instead of a Hydra delegate
+const BUILT_IN_RENDERER: &str = "Built-in";
 
 /// Simple GPU cache for viewport rendering data - only cache needed after unified cache simplification
 /// This stores the final ViewportData after processing USDSceneData from unified cache
@@ -142,7 +154,7 @@ impl USDRendererCache {
             position: camera_position.into(),
             target: camera_target.into(),
             up: [0.0, 1.0, 0.0],  // Default Y-up viewport
-            fov: 45.0_f32.to_radians(),
+            fov: crate::settings::current().viewport.field_of_view.to_radians(),
             near: 0.1,
             far: far_plane,  // Calculated based on scene size
             aspect: 800.0 / 600.0,
@@ -162,6 +174,7 @@ impl USDRendererCache {
                 vertex_colors: Some(usd_geometry.vertices.iter().flat_map(|v| v.color.iter().cloned()).collect()),
                 material_id: usd_geometry.material_path.clone(),
                 transform: final_transform.to_cols_array_2d(),
+                prim_path: usd_geometry.prim_path.clone(),
             };
             scene.meshes.push(mesh_data);
         }
@@ -226,13 +239,21 @@ impl USDRendererCache {
             dimensions: (800, 600),
             scene_dirty: true,
             settings: ViewportSettings {
-                background_color: [0.2, 0.2, 0.2, 1.0],
+                background_color: crate::settings::current().viewport.background_color,
                 wireframe: false,
                 lighting: true,
-                show_grid: true,
+                show_grid: crate::settings::current().viewport.show_grid,
                 show_ground_plane: false,
                 aa_samples: 4,
                 shading_mode: ShadingMode::Smooth,
+                display_mode: DisplayMode::Shaded,
+                available_aovs: Vec::new(),
+                aov: None,
+                selected_prims: Vec::new(),
+                hydra_delegate: None,
+                delegate_settings: Vec::new(),
+                hydra_region: Default::default(),
+                point_display: Default::default(),
             },
             settings_dirty: false,
         };
@@ -404,7 +425,7 @@ impl ViewportNode {
             position: [5.0, 5.0, 5.0],
             target: [0.0, 0.0, 0.0],
             up: [0.0, 1.0, 0.0],
-            fov: 45.0_f32.to_radians(),
+            fov: crate::settings::current().viewport.field_of_view.to_radians(),
             near: 0.1,
             far: 100.0,
             aspect: 800.0 / 600.0,
@@ -474,6 +495,7 @@ impl ViewportNode {
                 [0.0, 0.0, 1.0, 0.0],
                 [0.0, 0.0, 0.0, 1.0],
             ],
+            prim_path: String::new(),
         };
         
         scene.meshes.push(cube_mesh);
@@ -518,6 +540,18 @@ impl ViewportNode {
     
     /// Handle viewport input events (mouse, keyboard, etc.) for core viewports
     pub fn handle_viewport_input(&mut self, ui: &egui::Ui, response: &egui::Response, callback: &mut crate::gpu::viewport_3d_callback::ViewportRenderCallback) {
+        // Click picking: the prim under the cursor becomes the selection, Shift toggles it
+        let modifiers = ui.ctx().input(|i| i.modifiers);
+        if response.clicked() && !modifiers.alt {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let rect = response.rect;
+                let screen = (pointer - rect.min) / rect.size();
+                let picked = callback.pick_prim(screen.x, screen.y);
+                let mode = if modifiers.shift { SelectMode::Toggle } else { SelectMode::Replace };
+                selection::pick(picked.as_deref(), mode);
+            }
+        }
+        
         // Handle mouse interactions for camera control - Maya-style navigation
         if response.dragged() {
             let delta = response.drag_delta();
@@ -612,9 +646,8 @@ impl ViewportNode {
                 
                 // Handle F key for framing - only when viewport is focused
                 if i.key_pressed(egui::Key::F) {
-                    // TODO: In the future, check if geometry is selected and frame that instead
-                    // let selected_bounds = self.get_selected_geometry_bounds(); // Future implementation
-                    let selected_bounds = None; // No selection system yet
+                    // Frame the selected prims when there are any
+                    let selected_bounds = callback.selected_bounds(selection::current().paths());
                     
                     // Frame the scene or selected geometry
                     callback.frame_scene(selected_bounds);
@@ -925,17 +958,119 @@ impl ViewportNode {
         
         if show_viewport_settings {
             ui.indent("viewport_settings", |ui| {
-                let mut wireframe = node.parameters.get("wireframe")
-                    .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
-                    .unwrap_or(false);
+                let current_renderer = Self::hydra_delegate(node).unwrap_or_else(|| BUILT_IN_RENDERER.to_string());
+                let mut selected_renderer = current_renderer.clone();
+                ui.horizontal(|ui| {
+                    ui.label("Renderer:");
+                    egui::ComboBox::from_id_salt("viewport_renderer")
+                        .selected_text(&selected_renderer)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut selected_renderer, BUILT_IN_RENDERER.to_string(), BUILT_IN_RENDERER);
+                            for delegate in hydra::available_delegates() {
+                                ui.selectable_value(&mut selected_renderer, delegate.clone(), delegate);
+                            }
+                        });
+                    if ui.small_button("⟳").on_hover_text("Detect Hydra render delegates again").clicked() {
+                        hydra::refresh_delegates();
+                    }
+                });
+                if selected_renderer != current_renderer {
+                    changes.push(ParameterChange {
+                        parameter: "renderer".to_string(),
+                        value: NodeData::String(selected_renderer),
+                    });
+                    // The new delegate may not have the AOV that was shown
+                    changes.push(ParameterChange {
+                        parameter: "aov".to_string(),
+                        value: NodeData::String(String::new()),
+                    });
+                }
+                
+                // Settings of the active delegate, stored per delegate so switching back keeps them
+                if let Some(info) = Self::hydra_delegate(node).and_then(|delegate| hydra::delegate_info(&delegate)) {
+                    for setting in info.settings {
+                        let parameter = Self::delegate_setting_parameter(info.name, setting.key);
+                        match setting.kind {
+                            SettingKind::Boolean(default) => {
+                                let mut value = node.parameters.get(&parameter)
+                                    .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                                    .unwrap_or(default);
+                                if ui.checkbox(&mut value, setting.label).changed() {
+                                    changes.push(ParameterChange { parameter, value: NodeData::Boolean(value) });
+                                }
+                            }
+                            SettingKind::Integer { default, min, max } => {
+                                let mut value = node.parameters.get(&parameter)
+                                    .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                                    .unwrap_or(default);
+                                if ui.add(egui::Slider::new(&mut value, min..=max).logarithmic(max > 1000).text(setting.label)).changed() {
+                                    changes.push(ParameterChange { parameter, value: NodeData::Integer(value) });
+                                }
+                            }
+                            SettingKind::Float { default, min, max } => {
+                                let mut value = node.parameters.get(&parameter)
+                                    .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                                    .unwrap_or(default);
+                                if ui.add(egui::Slider::new(&mut value, min..=max).text(setting.label)).changed() {
+                                    changes.push(ParameterChange { parameter, value: NodeData::Float(value) });
+                                }
+                            }
+                        }
+                    }
+                }
+                
+                // Quick partial renders of heavy scenes, as on the Render node
+                if Self::hydra_delegate(node).is_some() {
+                    changes.extend(RenderParameters::region_interface(node, ui, false));
+                }
                 
-                if ui.checkbox(&mut wireframe, "Wireframe").changed() {
+                let display_mode = Self::display_mode(node);
+                let mut selected_mode = display_mode;
+                ui.horizontal(|ui| {
+                    ui.label("Display:");
+                    egui::ComboBox::from_id_salt("viewport_display_mode")
+                        .selected_text(display_mode.name())
+                        .show_ui(ui, |ui| {
+                            for name in DisplayMode::NAMES {
+                                if let Some(mode) = DisplayMode::from_name(name) {
+                                    ui.selectable_value(&mut selected_mode, mode, name);
+                                }
+                            }
+                        });
+                });
+                if selected_mode != display_mode {
                     changes.push(ParameterChange {
-                        parameter: "wireframe".to_string(),
-                        value: NodeData::Boolean(wireframe),
+                        parameter: "display_mode".to_string(),
+                        value: NodeData::String(selected_mode.name().to_string()),
                     });
                 }
                 
+                // AOVs are only offered when the render delegate reports some
+                let available_aovs = Self::available_aovs(node);
+                if !available_aovs.is_empty() {
+                    let current_aov = node.parameters.get("aov")
+                        .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+                        .unwrap_or_default();
+                    let mut selected_aov = current_aov.clone();
+                    ui.horizontal(|ui| {
+                        ui.label("AOV:");
+                        egui::ComboBox::from_id_salt("viewport_aov")
+                            .selected_text(if selected_aov.is_empty() { "Display Mode" } else { selected_aov.as_str() })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut selected_aov, String::new(), "Display Mode");
+                                for aov in &available_aovs {
+                                    ui.selectable_value(&mut selected_aov, aov.clone(), aov);
+                                }
+                            });
+                    }).response.on_hover_text("Render output of the active render delegate shown instead of the display mode");
+                    if selected_aov != current_aov {
+                        changes.push(ParameterChange {
+                            parameter: "aov".to_string(),
+                            value: NodeData::String(selected_aov),
+                        });
+                    }
+                }
+                
                 let mut lighting = node.parameters.get("lighting")
                     .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
                     .unwrap_or(true);
@@ -968,6 +1103,101 @@ impl ViewportNode {
                         value: NodeData::Boolean(show_ground_plane),
                     });
                 }
+                
+                // Point clouds
+                let point_display = Self::point_display(node);
+                ui.horizontal(|ui| {
+                    ui.label("Point Size:");
+                    let mut size = point_display.size;
+                    if ui.add(egui::DragValue::new(&mut size).range(1.0..=32.0).speed(0.1).suffix(" px")).changed() {
+                        changes.push(ParameterChange {
+                            parameter: "point_size".to_string(),
+                            value: NodeData::Float(size),
+                        });
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Point Color:");
+                    let mut color_mode = point_display.color_mode;
+                    egui::ComboBox::from_id_salt("viewport_point_color_mode")
+                        .selected_text(color_mode.name())
+                        .show_ui(ui, |ui| {
+                            for name in PointColorMode::NAMES {
+                                if let Some(mode) = PointColorMode::from_name(name) {
+                                    ui.selectable_value(&mut color_mode, mode, name);
+                                }
+                            }
+                        });
+                    if color_mode != point_display.color_mode {
+                        changes.push(ParameterChange {
+                            parameter: "point_color_mode".to_string(),
+                            value: NodeData::String(color_mode.name().to_string()),
+                        });
+                    }
+                    if color_mode == PointColorMode::Solid {
+                        let mut color = point_display.solid_color;
+                        if ui.color_edit_button_rgb(&mut color).changed() {
+                            changes.push(ParameterChange {
+                                parameter: "point_color".to_string(),
+                                value: NodeData::Color([color[0], color[1], color[2], 1.0]),
+                            });
+                        }
+                    }
+                });
+                
+                // Volumes
+                let volume_display = VolumeDisplay::from_node(node);
+                let mut show_slice = volume_display.show_slice;
+                if ui.checkbox(&mut show_slice, "Volume Slice").changed() {
+                    changes.push(ParameterChange {
+                        parameter: "volume_show_slice".to_string(),
+                        value: NodeData::Boolean(show_slice),
+                    });
+                }
+                if show_slice {
+                    ui.horizontal(|ui| {
+                        ui.label("Grid:");
+                        let grids = volume_display::grid_names(node.id);
+                        let shown = grids.iter().find(|grid| **grid == volume_display.grid).or(grids.first()).cloned().unwrap_or_default();
+                        let mut grid = shown.clone();
+                        egui::ComboBox::from_id_salt("viewport_volume_grid")
+                            .selected_text(&shown)
+                            .show_ui(ui, |ui| {
+                                for name in &grids {
+                                    ui.selectable_value(&mut grid, name.clone(), name);
+                                }
+                            });
+                        if grid != shown {
+                            changes.push(ParameterChange {
+                                parameter: "volume_grid".to_string(),
+                                value: NodeData::String(grid),
+                            });
+                        }
+                        
+                        let mut axis = volume_display.axis;
+                        egui::ComboBox::from_id_salt("viewport_volume_slice_axis")
+                            .selected_text(SLICE_AXES[axis.min(2)])
+                            .width(40.0)
+                            .show_ui(ui, |ui| {
+                                for (index, name) in SLICE_AXES.iter().enumerate() {
+                                    ui.selectable_value(&mut axis, index, *name);
+                                }
+                            });
+                        if axis != volume_display.axis {
+                            changes.push(ParameterChange {
+                                parameter: "volume_slice_axis".to_string(),
+                                value: NodeData::String(SLICE_AXES[axis].to_string()),
+                            });
+                        }
+                    });
+                    let mut position = volume_display.position;
+                    if ui.add(egui::Slider::new(&mut position, 0.0..=1.0).text("Slice")).changed() {
+                        changes.push(ParameterChange {
+                            parameter: "volume_slice".to_string(),
+                            value: NodeData::Float(position),
+                        });
+                    }
+                }
             });
         }
         
@@ -993,10 +1223,21 @@ impl ViewportNode {
         params.insert("camera_reset".to_string(), NodeData::Boolean(false));
         
         // Viewport settings
-        params.insert("wireframe".to_string(), NodeData::Boolean(false));
+        params.insert("renderer".to_string(), NodeData::String(BUILT_IN_RENDERER.to_string()));
+        params.insert("display_mode".to_string(), NodeData::String(DisplayMode::Shaded.name().to_string()));
+        params.insert("aov".to_string(), NodeData::String(String::new()));
+        params.insert(SELECTION_PARAMETER.to_string(), NodeData::String(String::new()));
         params.insert("lighting".to_string(), NodeData::Boolean(true));
-        params.insert("show_grid".to_string(), NodeData::Boolean(true));
+        params.insert("show_grid".to_string(), NodeData::Boolean(crate::settings::current().viewport.show_grid));
         params.insert("show_ground_plane".to_string(), NodeData::Boolean(false));
+        params.insert("point_size".to_string(), NodeData::Float(PointDisplay::default().size));
+        params.insert("point_color_mode".to_string(), NodeData::String(PointColorMode::default().name().to_string()));
+        params.insert("point_color".to_string(), NodeData::Color([1.0, 1.0, 1.0, 1.0]));
+        let volume_display = VolumeDisplay::default();
+        params.insert("volume_grid".to_string(), NodeData::String(volume_display.grid));
+        params.insert("volume_show_slice".to_string(), NodeData::Boolean(volume_display.show_slice));
+        params.insert("volume_slice_axis".to_string(), NodeData::String(SLICE_AXES[volume_display.axis].to_string()));
+        params.insert("volume_slice".to_string(), NodeData::Float(volume_display.position));
         
         // UI state
         params.insert("show_camera_settings".to_string(), NodeData::Boolean(false));
@@ -1024,9 +1265,45 @@ impl ViewportNode {
                 println!("🎬 Viewport: Fresh viewport data stored in GPU cache for node {}", node.id);
             }
             
+            // Hydra delegates render from a file, so save the scene for the preview
+            if Self::hydra_delegate(node).is_some() {
+                if let Err(e) = hydra_preview::store_scene(node.id, usd_scene_data) {
+                    eprintln!("❌ Viewport: Failed to save scene for Hydra preview: {}", e);
+                }
+            }
+            
             return vec![
                 NodeData::String(format!("Viewport: {} meshes rendered", usd_scene_data.meshes.len())),
-                NodeData::Boolean(true) // Scene loaded indicator
+                NodeData::String(Self::selection_text(node)),
+            ];
+        }
+        
+        if let Some(NodeData::PointCloud(points)) = inputs.get(0) {
+            let viewport_data = Self::convert_point_cloud_to_viewport_data(points, node);
+            if let Ok(mut gpu_cache) = GPU_VIEWPORT_CACHE.lock() {
+                gpu_cache.insert(node.id, viewport_data);
+            }
+            return vec![
+                NodeData::String(format!("Viewport: {} points rendered", points.point_count())),
+                NodeData::String(Self::selection_text(node)),
+            ];
+        }
+        
+        if let Some(NodeData::Volume(volume)) = inputs.get(0) {
+            let viewport_data = Self::convert_volume_to_viewport_data(volume, node);
+            if let Ok(mut gpu_cache) = GPU_VIEWPORT_CACHE.lock() {
+                gpu_cache.insert(node.id, viewport_data);
+            }
+            volume_display::store_grid_names(node.id, volume);
+            if Self::hydra_delegate(node).is_some() {
+                let layer = crate::nodes::three_d::input::read_vdb::logic::volume_layer(volume);
+                if let Err(e) = hydra_preview::store_layer(node.id, &layer) {
+                    eprintln!("❌ Viewport: Failed to save volume for Hydra preview: {}", e);
+                }
+            }
+            return vec![
+                NodeData::String(format!("Viewport: {} volume grids shown", volume.grids.len())),
+                NodeData::String(Self::selection_text(node)),
             ];
         }
         
@@ -1041,7 +1318,7 @@ impl ViewportNode {
         
         vec![
             NodeData::String("Empty Viewport - Connect USD File Reader".to_string()),
-            NodeData::Boolean(false) // No scene loaded
+            NodeData::String(Self::selection_text(node)),
         ]
     }
     
@@ -1065,13 +1342,25 @@ impl ViewportNode {
         Some(Self::create_empty_viewport_data(node))
     }
     
+    /// Viewport data for a node output pinned to the viewer `node`, using the
+    /// viewer's display settings. None if the output is not a scene.
+    pub fn viewport_data_for_output(node: &Node, output: &NodeData) -> Option<ViewportData> {
+        match output {
+            NodeData::USDSceneData(usd_scene_data) => Some(Self::convert_usd_scene_to_viewport_data(usd_scene_data, node)),
+            NodeData::PointCloud(points) => Some(Self::convert_point_cloud_to_viewport_data(points, node)),
+            NodeData::Volume(volume) => Some(Self::convert_volume_to_viewport_data(volume, node)),
+            _ => None,
+        }
+    }
+    
     /// Convert USDSceneData to ViewportData for rendering
     fn convert_usd_scene_to_viewport_data(usd_scene_data: &crate::workspaces::three_d::usd::usd_engine::USDSceneData, node: &Node) -> ViewportData {
         let mut scene = SceneData::default();
         scene.name = format!("USD Scene: {}", usd_scene_data.stage_path);
         
-        // Convert USD meshes to viewport meshes
-        for (mesh_idx, usd_mesh) in usd_scene_data.meshes.iter().enumerate() {
+        // Convert USD meshes, and the instances of point instancers, to viewport meshes
+        let instanced_meshes = usd_scene_data.instanced_meshes();
+        for (mesh_idx, usd_mesh) in usd_scene_data.meshes.iter().chain(&instanced_meshes).enumerate().filter(|(_, mesh)| mesh.is_active()) {
             // Convert Vec<Vec3> to Vec<f32> (flatten)
             let vertices: Vec<f32> = usd_mesh.vertices.iter()
                 .flat_map(|v| [v.x, v.y, v.z])
@@ -1131,6 +1420,7 @@ impl ViewportNode {
                     [usd_mesh.transform.z_axis.x, usd_mesh.transform.z_axis.y, usd_mesh.transform.z_axis.z, usd_mesh.transform.z_axis.w],
                     [usd_mesh.transform.w_axis.x, usd_mesh.transform.w_axis.y, usd_mesh.transform.w_axis.z, usd_mesh.transform.w_axis.w],
                 ],
+                prim_path: usd_mesh.prim_path.clone(),
             };
             scene.meshes.push(mesh);
         }
@@ -1197,13 +1487,21 @@ impl ViewportNode {
             dimensions: (800, 600),
             scene_dirty: true,
             settings: ViewportSettings {
-                background_color: [0.2, 0.2, 0.2, 1.0],
+                background_color: crate::settings::current().viewport.background_color,
                 wireframe: false,
                 lighting: true,
-                show_grid: true,
+                show_grid: crate::settings::current().viewport.show_grid,
                 show_ground_plane: false,
                 aa_samples: 4,
                 shading_mode: ShadingMode::Smooth,
+                display_mode: DisplayMode::Shaded,
+                available_aovs: Vec::new(),
+                aov: None,
+                selected_prims: Vec::new(),
+                hydra_delegate: None,
+                delegate_settings: Vec::new(),
+                hydra_region: Default::default(),
+                point_display: Default::default(),
             },
             settings_dirty: false,
         };
@@ -1213,20 +1511,173 @@ impl ViewportNode {
         viewport_data
     }
     
+    /// Convert a point cloud to ViewportData drawing it as dots
+    fn convert_point_cloud_to_viewport_data(points: &crate::nodes::interface::PointCloudData, node: &Node) -> ViewportData {
+        let mut scene = SceneData::default();
+        scene.name = format!("Point cloud: {}", points.source);
+        let positions: Vec<f32> = points.positions.iter().flat_map(|p| [p.x, p.y, p.z]).collect();
+        let colors: Vec<f32> = if points.has_colors() {
+            points.colors.iter().flat_map(|c| [c.x, c.y, c.z]).collect()
+        } else {
+            Vec::new()
+        };
+        // The renderer caches uploads by id, so the id follows the content
+        let mut hasher = DefaultHasher::new();
+        for value in positions.iter().chain(&colors) {
+            value.to_bits().hash(&mut hasher);
+        }
+        scene.points.push(PointsData {
+            id: format!("points_{:016x}", hasher.finish()),
+            positions,
+            colors,
+        });
+        scene.bounding_box = Some(points.bounds()
+            .map(|(min, max)| (min.to_array(), max.to_array()))
+            .unwrap_or(([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0])));
+        
+        let settings = crate::settings::current();
+        let mut viewport_data = ViewportData {
+            scene,
+            settings: ViewportSettings {
+                background_color: settings.viewport.background_color,
+                show_grid: settings.viewport.show_grid,
+                show_ground_plane: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Self::apply_viewport_settings(&mut viewport_data, node);
+        viewport_data
+    }
+    
+    /// Convert a volume to ViewportData drawing its grids' bounds and a slice of one grid
+    fn convert_volume_to_viewport_data(volume: &crate::nodes::interface::VolumeData, node: &Node) -> ViewportData {
+        let mut scene = SceneData::default();
+        scene.name = format!("Volume: {}", volume.source);
+        scene.lines.push(volume_display::bounds_lines(volume));
+        let display = VolumeDisplay::from_node(node);
+        if display.show_slice {
+            if let Some(slice) = display.pick_grid(volume).and_then(|grid| volume_display::slice_points(grid, &display)) {
+                scene.points.push(slice);
+            }
+        }
+        scene.bounding_box = Some(volume.bounds()
+            .map(|(min, max)| (min.to_array(), max.to_array()))
+            .unwrap_or(([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0])));
+        
+        let settings = crate::settings::current();
+        let mut viewport_data = ViewportData {
+            scene,
+            settings: ViewportSettings {
+                background_color: settings.viewport.background_color,
+                show_grid: settings.viewport.show_grid,
+                show_ground_plane: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Self::apply_viewport_settings(&mut viewport_data, node);
+        viewport_data
+    }
+    
+    /// Point size and coloring from the node's parameters
+    fn point_display(node: &Node) -> PointDisplay {
+        let defaults = PointDisplay::default();
+        PointDisplay {
+            size: node.parameters.get("point_size")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.size),
+            color_mode: node.parameters.get("point_color_mode")
+                .and_then(|v| if let NodeData::String(s) = v { PointColorMode::from_name(s) } else { None })
+                .unwrap_or_default(),
+            solid_color: node.parameters.get("point_color")
+                .and_then(|v| if let NodeData::Color(c) = v { Some([c[0], c[1], c[2]]) } else { None })
+                .unwrap_or(defaults.solid_color),
+        }
+    }
+    
     /// Apply viewport settings from node parameters to viewport data
-    fn apply_viewport_settings(viewport_data: &mut ViewportData, node: &Node) {
-        viewport_data.settings.wireframe = node.parameters.get("wireframe")
-            .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
-            .unwrap_or(false);
+    pub fn apply_viewport_settings(viewport_data: &mut ViewportData, node: &Node) {
+        let display_mode = Self::display_mode(node);
+        viewport_data.settings.display_mode = display_mode;
+        viewport_data.settings.wireframe = display_mode == DisplayMode::Wireframe;
+        viewport_data.settings.available_aovs = Self::available_aovs(node);
+        viewport_data.settings.aov = node.parameters.get("aov")
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .filter(|aov| !aov.is_empty());
+        viewport_data.settings.selected_prims = selection::current().paths().to_vec();
+        viewport_data.settings.hydra_delegate = Self::hydra_delegate(node);
+        viewport_data.settings.delegate_settings = viewport_data.settings.hydra_delegate.as_deref()
+            .map(|delegate| Self::delegate_settings(node, delegate))
+            .unwrap_or_default();
+        // Overscan would draw past the viewport's edges, so only the Render node has it
+        viewport_data.settings.hydra_region = render_region(node, false);
         viewport_data.settings.lighting = node.parameters.get("lighting")
             .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
             .unwrap_or(true);
         viewport_data.settings.show_grid = node.parameters.get("show_grid")
             .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
-            .unwrap_or(true);
+            .unwrap_or_else(|| crate::settings::current().viewport.show_grid);
         viewport_data.settings.show_ground_plane = node.parameters.get("show_ground_plane")
             .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
             .unwrap_or(false);
+        viewport_data.settings.point_display = Self::point_display(node);
+    }
+    
+    /// Selected prim paths the node last published, separated by spaces
+    pub fn selection_text(node: &Node) -> String {
+        node.parameters.get(SELECTION_PARAMETER)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or_default()
+    }
+    
+    /// Display mode of a viewport node; nodes saved before display modes fall back to their wireframe toggle
+    fn display_mode(node: &Node) -> DisplayMode {
+        match node.parameters.get("display_mode") {
+            Some(NodeData::String(name)) => DisplayMode::from_name(name).unwrap_or_default(),
+            _ => match node.parameters.get("wireframe") {
+                Some(NodeData::Boolean(true)) => DisplayMode::Wireframe,
+                _ => DisplayMode::Shaded,
+            },
+        }
+    }
+    
+    /// Hydra delegate chosen for the node; None when the built-in renderer draws it
+    fn hydra_delegate(node: &Node) -> Option<String> {
+        node.parameters.get("renderer")
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .filter(|renderer| !renderer.is_empty() && renderer != BUILT_IN_RENDERER)
+    }
+    
+    /// Parameter holding one setting of one delegate, e.g. `hydra:Embree:samplesToConvergence`
+    fn delegate_setting_parameter(delegate: &str, key: &str) -> String {
+        format!("hydra:{}:{}", delegate, key)
+    }
+    
+    /// Render settings of `delegate` as key and value, defaults for the ones never changed
+    fn delegate_settings(node: &Node, delegate: &str) -> Vec<(String, String)> {
+        let Some(info) = hydra::delegate_info(delegate) else {
+            return Vec::new();
+        };
+        info.settings.iter().map(|setting| {
+            let value = match node.parameters.get(&Self::delegate_setting_parameter(delegate, setting.key)) {
+                Some(NodeData::Boolean(b)) => b.to_string(),
+                Some(NodeData::Integer(i)) => i.to_string(),
+                Some(NodeData::Float(f)) => f.to_string(),
+                _ => setting.default_text(),
+            };
+            (setting.key.to_string(), value)
+        }).collect()
+    }
+    
+    /// AOVs besides the beauty pass of the node's Hydra delegate; none for the built-in renderer
+    fn available_aovs(node: &Node) -> Vec<String> {
+        let Some(delegate) = Self::hydra_delegate(node) else {
+            return Vec::new();
+        };
+        // Third-party delegates we know nothing about are only asked for depth
+        let aovs: &[&str] = hydra::delegate_info(&delegate).map_or(&["depth"], |info| info.aovs);
+        aovs.iter().filter(|aov| **aov != "color").map(|aov| aov.to_string()).collect()
     }
     
     /// Create empty viewport data when no input is available
@@ -1239,7 +1690,7 @@ impl ViewportNode {
             position: [5.0, 5.0, 5.0],
             target: [0.0, 0.0, 0.0],
             up: [0.0, 1.0, 0.0],
-            fov: 45.0_f32.to_radians(),
+            fov: crate::settings::current().viewport.field_of_view.to_radians(),
             near: 0.1,
             far: 100.0,
             aspect: 800.0 / 600.0,
@@ -1256,10 +1707,18 @@ impl ViewportNode {
                 background_color: [0.1, 0.1, 0.1, 1.0], // Darker background for empty state
                 wireframe: false,
                 lighting: true,
-                show_grid: true,
+                show_grid: crate::settings::current().viewport.show_grid,
                 show_ground_plane: false,
                 aa_samples: 4,
                 shading_mode: ShadingMode::Smooth,
+                display_mode: DisplayMode::Shaded,
+                available_aovs: Vec::new(),
+                aov: None,
+                selected_prims: Vec::new(),
+                hydra_delegate: None,
+                delegate_settings: Vec::new(),
+                hydra_region: Default::default(),
+                point_display: Default::default(),
             },
             settings_dirty: false,
         };
@@ -1282,11 +1741,13 @@ impl NodeFactory for ViewportNode {
         .with_icon("🎥")
         .with_inputs(vec![
             crate::nodes::PortDefinition::optional("USD Scene", crate::nodes::DataType::Any)
-                .with_description("USD scene data from USD File Reader"),
+                .with_description("USD scene data from USD File Reader, a point cloud or a volume"),
         ])
         .with_outputs(vec![
             crate::nodes::PortDefinition::optional("Rendered Image", crate::nodes::DataType::String)
                 .with_description("Viewport render output"),
+            crate::nodes::PortDefinition::optional("Selection", crate::nodes::DataType::String)
+                .with_description("Paths of the prims selected in the viewport or Scenegraph, separated by spaces"),
         ])
         .with_size_hint(egui::Vec2::new(400.0, 300.0))
         .with_workspace_compatibility(vec!["3D"])
@@ -0,0 +1,191 @@
+//! Viewport display of volumes
+//!
+//! Volumes are drawn as the outlines of their grids' bounds plus, for the
+//! grid picked in the viewport panel, one slice of voxels as points shaded
+//! from dark to bright by value. Only voxels differing from the background
+//! are shown, so empty space stays clear.
+
+use crate::nodes::interface::{NodeData, VolumeData, VolumeGrid};
+use crate::nodes::{Node, NodeId};
+use crate::viewport::{LinesData, PointsData};
+use glam::{IVec3, Vec3};
+use once_cell::sync::Lazy;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+/// Most slice samples along each side; larger grids are sampled every few voxels
+const MAX_SLICE_SAMPLES: u32 = 256;
+
+/// Grid names of the volume each viewport node shows, for the panel's grid menu
+static VOLUME_GRIDS: Lazy<Mutex<HashMap<NodeId, Vec<String>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Axis names of the slice plane's normal
+pub const SLICE_AXES: [&str; 3] = ["X", "Y", "Z"];
+
+/// Which slice of which grid a viewport shows
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeDisplay {
+    /// Grid to slice; empty picks the first grid
+    pub grid: String,
+    pub show_slice: bool,
+    /// Index of the axis the slice is perpendicular to
+    pub axis: usize,
+    /// Slice position across the grid's bounds, 0..1
+    pub position: f32,
+}
+
+impl Default for VolumeDisplay {
+    fn default() -> Self {
+        Self {
+            grid: String::new(),
+            show_slice: true,
+            axis: 1,
+            position: 0.5,
+        }
+    }
+}
+
+impl VolumeDisplay {
+    /// Read the display from a viewport node's parameters
+    pub fn from_node(node: &Node) -> Self {
+        let defaults = Self::default();
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None });
+        Self {
+            grid: text("volume_grid").unwrap_or(defaults.grid),
+            show_slice: node.parameters.get("volume_show_slice")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.show_slice),
+            axis: text("volume_slice_axis")
+                .and_then(|name| SLICE_AXES.iter().position(|axis| *axis == name))
+                .unwrap_or(defaults.axis),
+            position: node.parameters.get("volume_slice")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.position),
+        }
+    }
+
+    /// The grid to slice
+    pub fn pick_grid<'a>(&self, volume: &'a VolumeData) -> Option<&'a VolumeGrid> {
+        volume.grids.iter().find(|grid| grid.name == self.grid).or(volume.grids.first())
+    }
+}
+
+/// Remember the grid names a viewport node shows
+pub fn store_grid_names(node_id: NodeId, volume: &VolumeData) {
+    if let Ok(mut grids) = VOLUME_GRIDS.lock() {
+        grids.insert(node_id, volume.grids.iter().map(|grid| grid.name.clone()).collect());
+    }
+}
+
+/// Grid names of the volume a viewport node last showed
+pub fn grid_names(node_id: NodeId) -> Vec<String> {
+    VOLUME_GRIDS.lock().ok().and_then(|grids| grids.get(&node_id).cloned()).unwrap_or_default()
+}
+
+/// The twelve edges of every grid's bounds
+pub fn bounds_lines(volume: &VolumeData) -> LinesData {
+    // Corner indices use bit 0 for x, bit 1 for y and bit 2 for z; edges join corners one bit apart
+    let edges = (0..8usize).flat_map(|corner| [1, 2, 4].into_iter()
+        .filter(move |bit| corner & bit == 0)
+        .map(move |bit| (corner, corner | bit)));
+    let positions = volume.grids.iter().flat_map(|grid| {
+        let corners = grid.world_corners();
+        edges.clone().flat_map(move |(a, b)| [corners[a], corners[b]])
+    }).flat_map(|p| p.to_array()).collect::<Vec<f32>>();
+    LinesData { id: format!("volume_bounds_{:016x}", content_hash(&positions, &[])), positions }
+}
+
+/// Voxels of one slice through `grid`, colored by value; None when the grid's values weren't loaded
+pub fn slice_points(grid: &VolumeGrid, display: &VolumeDisplay) -> Option<PointsData> {
+    let values = grid.values.as_ref()?;
+    let sampler = values.sampler();
+    let (min, max) = (IVec3::from(grid.index_min), IVec3::from(grid.index_max));
+    let axis = display.axis.min(2);
+    let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+    let layer = min[axis] + ((max[axis] - min[axis]) as f32 * display.position.clamp(0.0, 1.0)).round() as i32;
+    let stride = |axis: usize| ((max[axis] - min[axis] + 1).max(1) as u32).div_ceil(MAX_SLICE_SAMPLES) as usize;
+
+    let mut samples = Vec::new();
+    for a in (min[u]..=max[u]).step_by(stride(u)) {
+        for b in (min[v]..=max[v]).step_by(stride(v)) {
+            let mut ijk = IVec3::ZERO;
+            ijk[axis] = layer;
+            ijk[u] = a;
+            ijk[v] = b;
+            let value = sampler.value(ijk.to_array());
+            if value != values.background {
+                samples.push((ijk, value));
+            }
+        }
+    }
+
+    let (low, high) = samples.iter().fold((f32::MAX, f32::MIN), |(low, high), (_, value)| (low.min(*value), high.max(*value)));
+    let range = if high > low { high - low } else { 1.0 };
+    let positions: Vec<f32> = samples.iter()
+        .flat_map(|(ijk, _)| grid.transform.transform_point3(ijk.as_vec3()).to_array())
+        .collect();
+    let colors: Vec<f32> = samples.iter()
+        .flat_map(|(_, value)| Vec3::splat(0.15 + 0.85 * ((value - low) / range).clamp(0.0, 1.0)).to_array())
+        .collect();
+    Some(PointsData {
+        id: format!("volume_slice_{:016x}", content_hash(&positions, &colors)),
+        positions,
+        colors,
+    })
+}
+
+/// The renderer caches uploads by id, so ids follow the content
+fn content_hash(positions: &[f32], colors: &[f32]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for value in positions.iter().chain(colors) {
+        value.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nodes::interface::{VolumeLeaf, VolumeValues};
+    use glam::Mat4;
+
+    fn grid() -> VolumeGrid {
+        // Voxel (x, y, z) of the leaf holds x + y + z, zero on the y = 0 layer
+        let values = (0..512).map(|index| {
+            let (x, y, z) = (index >> 6, (index >> 3) & 7, index & 7);
+            if y == 0 { 0.0 } else { (x + y + z) as f32 }
+        }).collect();
+        VolumeGrid {
+            name: "density".to_string(),
+            index_min: [0, 0, 0],
+            index_max: [7, 7, 7],
+            transform: Mat4::from_scale(Vec3::splat(0.1)),
+            values: Some(VolumeValues { background: 0.0, leaves: vec![VolumeLeaf { origin: [0, 0, 0], values }], tiles: Vec::new() }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_bounds_have_twelve_edges_per_grid() {
+        let volume = VolumeData { source: "smoke.vdb".to_string(), grids: vec![grid(), grid()] };
+        assert_eq!(bounds_lines(&volume).positions.len(), 2 * 12 * 2 * 3);
+    }
+
+    #[test]
+    fn test_slices_skip_background_voxels() {
+        let mut display = VolumeDisplay { axis: 1, position: 0.0, ..Default::default() };
+        // The bottom layer is all background
+        assert_eq!(slice_points(&grid(), &display).unwrap().positions.len(), 0);
+
+        display.position = 1.0;
+        let slice = slice_points(&grid(), &display).unwrap();
+        assert_eq!(slice.positions.len(), 64 * 3);
+        // Every point lies on the top layer, in world units
+        assert!(slice.positions.chunks(3).all(|p| (p[1] - 0.7).abs() < 1e-5));
+        // Values 7..21 span the whole shade range
+        assert!((slice.colors[0] - 0.15).abs() < 1e-5);
+    }
+}
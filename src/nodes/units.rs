@@ -0,0 +1,186 @@
+//! Physical units for float ports and parameters
+//!
+//! Ports and parameters may declare a unit (meters, centimeters, degrees, ...).
+//! When two connected ports declare compatible units the execution engine
+//! converts values at the connection boundary, so a centimeter output feeding a
+//! meter input arrives scaled by 0.01 instead of silently changing scale.
+
+use serde::{Deserialize, Serialize};
+use crate::nodes::interface::NodeData;
+
+/// Frame rate used when converting between seconds and frames
+pub const DEFAULT_FRAME_RATE: f32 = 24.0;
+
+/// Physical quantity a unit measures; only units of the same kind convert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Length,
+    Angle,
+    Time,
+}
+
+/// Unit annotation for a float port or parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Unit {
+    /// Unitless value (never converted)
+    #[default]
+    None,
+    Meters,
+    Centimeters,
+    Millimeters,
+    Degrees,
+    Radians,
+    Seconds,
+    Frames,
+}
+
+impl Unit {
+    pub const ALL: [Unit; 8] = [
+        Unit::None,
+        Unit::Meters,
+        Unit::Centimeters,
+        Unit::Millimeters,
+        Unit::Degrees,
+        Unit::Radians,
+        Unit::Seconds,
+        Unit::Frames,
+    ];
+
+    /// Display name for combo boxes
+    pub fn name(&self) -> &'static str {
+        match self {
+            Unit::None => "None",
+            Unit::Meters => "Meters",
+            Unit::Centimeters => "Centimeters",
+            Unit::Millimeters => "Millimeters",
+            Unit::Degrees => "Degrees",
+            Unit::Radians => "Radians",
+            Unit::Seconds => "Seconds",
+            Unit::Frames => "Frames",
+        }
+    }
+
+    /// Look up a unit by display name
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL.iter().copied().find(|unit| unit.name() == name).unwrap_or(Unit::None)
+    }
+
+    /// Short suffix shown after values in widgets (includes leading space where needed)
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Unit::None => "",
+            Unit::Meters => " m",
+            Unit::Centimeters => " cm",
+            Unit::Millimeters => " mm",
+            Unit::Degrees => "°",
+            Unit::Radians => " rad",
+            Unit::Seconds => " s",
+            Unit::Frames => " f",
+        }
+    }
+
+    /// Quantity this unit measures
+    pub fn kind(&self) -> Option<UnitKind> {
+        match self {
+            Unit::None => None,
+            Unit::Meters | Unit::Centimeters | Unit::Millimeters => Some(UnitKind::Length),
+            Unit::Degrees | Unit::Radians => Some(UnitKind::Angle),
+            Unit::Seconds | Unit::Frames => Some(UnitKind::Time),
+        }
+    }
+
+    /// Scale from this unit to the base unit of its kind (meters, radians, seconds)
+    fn to_base(self) -> f32 {
+        match self {
+            Unit::None | Unit::Meters | Unit::Radians | Unit::Seconds => 1.0,
+            Unit::Centimeters => 0.01,
+            Unit::Millimeters => 0.001,
+            Unit::Degrees => std::f32::consts::PI / 180.0,
+            Unit::Frames => 1.0 / DEFAULT_FRAME_RATE,
+        }
+    }
+
+    /// Whether values need converting from `self` to `to`
+    pub fn converts_to(&self, to: Unit) -> bool {
+        *self != to && self.kind().is_some() && self.kind() == to.kind()
+    }
+
+    /// Convert a value from this unit to another. Unitless or mismatched kinds pass through.
+    pub fn convert(&self, value: f32, to: Unit) -> f32 {
+        if self.converts_to(to) {
+            value * self.to_base() / to.to_base()
+        } else {
+            value
+        }
+    }
+
+    /// Convert every float in a value (scalars, vectors, buffers); other data passes through
+    pub fn convert_data(&self, data: NodeData, to: Unit) -> NodeData {
+        if !self.converts_to(to) {
+            return data;
+        }
+        match data {
+            NodeData::Float(value) => NodeData::Float(self.convert(value, to)),
+            NodeData::Vector3(v) => NodeData::Vector3([
+                self.convert(v[0], to),
+                self.convert(v[1], to),
+                self.convert(v[2], to),
+            ]),
+            NodeData::Buffer(values) => NodeData::Buffer(
+                values.into_iter().map(|value| self.convert(value, to)).collect()
+            ),
+            other => other,
+        }
+    }
+}
+
+/// Drag value for a float parameter showing its unit suffix
+pub fn unit_drag_value(ui: &mut egui::Ui, value: &mut f32, unit: Unit, speed: f64) -> egui::Response {
+    ui.add(egui::DragValue::new(value).speed(speed).suffix(unit.suffix()))
+}
+
+/// Combo box to pick a unit of a given kind (or any unit when `kind` is None)
+pub fn unit_combo_box(ui: &mut egui::Ui, id_salt: impl std::hash::Hash, unit: &mut Unit, kind: Option<UnitKind>) -> bool {
+    let mut changed = false;
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(unit.name())
+        .show_ui(ui, |ui| {
+            for candidate in Unit::ALL.iter().filter(|u| kind.is_none() || u.kind() == kind || **u == Unit::None) {
+                if ui.selectable_value(unit, *candidate, candidate.name()).changed() {
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_and_angle_conversion() {
+        assert!((Unit::Centimeters.convert(150.0, Unit::Meters) - 1.5).abs() < 1e-6);
+        assert!((Unit::Degrees.convert(180.0, Unit::Radians) - std::f32::consts::PI).abs() < 1e-5);
+        assert!((Unit::Seconds.convert(2.0, Unit::Frames) - 48.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_incompatible_units_pass_through() {
+        assert_eq!(Unit::Meters.convert(3.0, Unit::Degrees), 3.0);
+        assert_eq!(Unit::None.convert(3.0, Unit::Meters), 3.0);
+        assert!(matches!(Unit::Millimeters.convert_data(NodeData::String("x".into()), Unit::Meters), NodeData::String(_)));
+    }
+
+    #[test]
+    fn test_vector_conversion() {
+        match Unit::Meters.convert_data(NodeData::Vector3([1.0, 2.0, 0.5]), Unit::Centimeters) {
+            NodeData::Vector3(v) => {
+                for (actual, expected) in v.iter().zip([100.0, 200.0, 50.0]) {
+                    assert!((actual - expected).abs() < 1e-3);
+                }
+            }
+            _ => panic!("expected vector"),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Compute Shader node logic - converts inputs to f32 buffers and dispatches on the GPU
+
+use crate::nodes::interface::NodeData;
+use crate::gpu::compute::{self, ComputeRequest};
+
+/// Number of input ports, bound at `@binding(0)` .. `@binding(INPUT_BINDINGS - 1)`
+pub const INPUT_BINDINGS: usize = 4;
+
+/// Workgroup size assumed when the workgroup count is derived automatically
+pub const DEFAULT_WORKGROUP_SIZE: u32 = 64;
+
+/// Starter shader shown for new nodes
+pub const DEFAULT_SHADER: &str = r#"@group(0) @binding(0) var<storage, read> input0: array<f32>;
+@group(0) @binding(4) var<storage, read_write> output: array<f32>;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if (i >= arrayLength(&output)) {
+        return;
+    }
+    output[i] = input0[i % arrayLength(&input0)] * 2.0;
+}
+"#;
+
+/// Core Compute Shader data and functionality
+#[derive(Debug, Clone)]
+pub struct ComputeShaderLogic {
+    /// WGSL source
+    pub source: String,
+    /// Compute entry point
+    pub entry_point: String,
+    /// Output length in f32 elements (0 = longest input)
+    pub output_length: usize,
+    /// Workgroup counts (x of 0 = derived from output length)
+    pub workgroups: [u32; 3],
+}
+
+impl Default for ComputeShaderLogic {
+    fn default() -> Self {
+        Self {
+            source: DEFAULT_SHADER.to_string(),
+            entry_point: "main".to_string(),
+            output_length: 0,
+            workgroups: [0, 1, 1],
+        }
+    }
+}
+
+impl ComputeShaderLogic {
+    /// Process inputs: returns [Result buffer, Status string]
+    pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        let buffers: Vec<Vec<f32>> = (0..INPUT_BINDINGS)
+            .map(|i| inputs.get(i).map(to_f32_buffer).unwrap_or_default())
+            .collect();
+
+        let output_len = self.resolve_output_length(&buffers);
+        let request = ComputeRequest {
+            source: &self.source,
+            entry_point: &self.entry_point,
+            inputs: &buffers,
+            output_len,
+            workgroups: self.resolve_workgroups(output_len),
+        };
+
+        match compute::dispatch(&request) {
+            Ok(result) => vec![
+                NodeData::Buffer(result),
+                NodeData::String(format!("OK: {} elements", output_len)),
+            ],
+            Err(e) => {
+                log::error!("Compute Shader node failed: {}", e);
+                vec![NodeData::None, NodeData::String(format!("Error: {}", e))]
+            }
+        }
+    }
+
+    /// Output length, falling back to the longest input (minimum 1)
+    pub fn resolve_output_length(&self, buffers: &[Vec<f32>]) -> usize {
+        if self.output_length > 0 {
+            self.output_length
+        } else {
+            buffers.iter().map(|b| b.len()).max().unwrap_or(0).max(1)
+        }
+    }
+
+    /// Workgroup counts, deriving x from the output length when unset
+    pub fn resolve_workgroups(&self, output_len: usize) -> [u32; 3] {
+        let [x, y, z] = self.workgroups;
+        let x = if x == 0 {
+            (output_len as u32).div_ceil(DEFAULT_WORKGROUP_SIZE).max(1)
+        } else {
+            x
+        };
+        [x, y.max(1), z.max(1)]
+    }
+}
+
+/// Flatten node data into an f32 buffer for binding
+pub fn to_f32_buffer(data: &NodeData) -> Vec<f32> {
+    match data {
+        NodeData::Buffer(values) => values.clone(),
+        NodeData::Float(f) => vec![*f],
+        NodeData::Integer(i) => vec![*i as f32],
+        NodeData::Boolean(b) => vec![if *b { 1.0 } else { 0.0 }],
+        NodeData::Vector3(v) => v.to_vec(),
+        NodeData::Color(c) => c.to_vec(),
+        _ => Vec::new(),
+    }
+}
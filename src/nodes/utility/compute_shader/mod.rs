@@ -0,0 +1,41 @@
+//! Compute Shader node module - runs user WGSL on the editor's wgpu device
+//!
+//! Uses Pattern A: build_interface method
+//! - mod.rs: Base node metadata and factory implementation
+//! - logic.rs: Input buffer conversion and GPU dispatch
+//! - parameters.rs: Pattern A interface with build_interface method
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::ComputeShaderLogic;
+pub use parameters::ComputeShaderNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::ComputeShaderNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Utility_ComputeShader",
+            "Compute Shader",
+            crate::nodes::NodeCategory::new(&["Utility", "GPU"]),
+            "Runs a WGSL compute shader on the GPU; inputs bind as read-only f32 storage buffers"
+        )
+        .with_color(egui::Color32::from_rgb(60, 70, 90))
+        .with_icon("⚡")
+        .with_inputs((0..logic::INPUT_BINDINGS).map(|i| {
+            crate::nodes::PortDefinition::optional(&format!("Buffer {}", i), crate::nodes::DataType::Any)
+                .with_description(&format!("Bound at @binding({}) as array<f32>", i))
+        }).collect())
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Result", crate::nodes::DataType::Buffer)
+                .with_description("Contents of the read_write output buffer"),
+            crate::nodes::PortDefinition::optional("Status", crate::nodes::DataType::String)
+                .with_description("Dispatch status or shader error"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["utility", "gpu", "compute", "shader", "wgsl", "wgpu"])
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::High)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
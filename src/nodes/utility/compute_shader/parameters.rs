@@ -0,0 +1,132 @@
+//! Compute Shader node parameters using Pattern A: build_interface method
+
+use crate::nodes::interface::{NodeData, ParameterChange};
+use crate::nodes::Node;
+use super::logic::{ComputeShaderLogic, INPUT_BINDINGS, DEFAULT_SHADER};
+
+/// Compute Shader node with Pattern A interface
+#[derive(Debug, Clone, Default)]
+pub struct ComputeShaderNode;
+
+impl ComputeShaderNode {
+    /// Pattern A: build_interface method that renders UI and returns parameter changes
+    pub fn build_interface(node: &mut Node, ui: &mut egui::Ui) -> Vec<ParameterChange> {
+        let mut changes = Vec::new();
+        let logic = Self::create_logic(node);
+        
+        ui.heading("Compute Shader Parameters");
+        ui.separator();
+        
+        if !crate::gpu::compute::is_available() {
+            ui.colored_label(egui::Color32::from_rgb(255, 180, 80), "GPU device not initialized yet");
+        }
+        
+        // Entry point
+        ui.horizontal(|ui| {
+            ui.label("Entry Point:");
+            let mut entry_point = logic.entry_point.clone();
+            if ui.text_edit_singleline(&mut entry_point).changed() {
+                changes.push(ParameterChange {
+                    parameter: "entry_point".to_string(),
+                    value: NodeData::String(entry_point),
+                });
+            }
+        });
+        
+        // Output length (0 = longest input)
+        ui.horizontal(|ui| {
+            ui.label("Output Length:");
+            let mut output_length = logic.output_length as i32;
+            if ui.add(egui::DragValue::new(&mut output_length).range(0..=16_777_216)).changed() {
+                changes.push(ParameterChange {
+                    parameter: "output_length".to_string(),
+                    value: NodeData::Integer(output_length),
+                });
+            }
+            ui.label("(0 = longest input)");
+        });
+        
+        // Workgroup counts (x of 0 = auto)
+        ui.horizontal(|ui| {
+            ui.label("Workgroups:");
+            for (axis, current) in ["x", "y", "z"].iter().zip(logic.workgroups) {
+                let mut count = current as i32;
+                if ui.add(egui::DragValue::new(&mut count).range(0..=65_535).prefix(format!("{}: ", axis))).changed() {
+                    changes.push(ParameterChange {
+                        parameter: format!("workgroups_{}", axis),
+                        value: NodeData::Integer(count),
+                    });
+                }
+            }
+        });
+        
+        ui.separator();
+        
+        // Binding reference derived from the input ports
+        ui.collapsing("Bindings", |ui| {
+            for (i, port) in node.inputs.iter().enumerate().take(INPUT_BINDINGS) {
+                ui.monospace(format!("@binding({}) var<storage, read>       {} : array<f32>", i, port.name));
+            }
+            ui.monospace(format!("@binding({}) var<storage, read_write> output : array<f32>", INPUT_BINDINGS));
+        });
+        
+        // WGSL source editor
+        ui.horizontal(|ui| {
+            ui.label("WGSL Source:");
+            if ui.small_button("Reset").clicked() {
+                changes.push(ParameterChange {
+                    parameter: "shader_source".to_string(),
+                    value: NodeData::String(DEFAULT_SHADER.to_string()),
+                });
+            }
+        });
+        let mut source = logic.source.clone();
+        let response = egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut source)
+                        .code_editor()
+                        .desired_rows(16)
+                        .desired_width(f32::INFINITY)
+                )
+            })
+            .inner;
+        // Only recompile once editing finishes
+        if response.lost_focus() && source != logic.source {
+            changes.push(ParameterChange {
+                parameter: "shader_source".to_string(),
+                value: NodeData::String(source),
+            });
+        }
+        
+        changes
+    }
+    
+    /// Build ComputeShaderLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> ComputeShaderLogic {
+        let defaults = ComputeShaderLogic::default();
+        let get_string = |name: &str, default: String| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None })
+            .unwrap_or(default);
+        let get_int = |name: &str, default: u32| node.parameters.get(name)
+            .and_then(|v| if let NodeData::Integer(i) = v { Some((*i).max(0) as u32) } else { None })
+            .unwrap_or(default);
+        
+        ComputeShaderLogic {
+            source: get_string("shader_source", defaults.source),
+            entry_point: get_string("entry_point", defaults.entry_point),
+            output_length: get_int("output_length", defaults.output_length as u32) as usize,
+            workgroups: [
+                get_int("workgroups_x", defaults.workgroups[0]),
+                get_int("workgroups_y", defaults.workgroups[1]),
+                get_int("workgroups_z", defaults.workgroups[2]),
+            ],
+        }
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+}
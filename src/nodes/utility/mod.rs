@@ -5,7 +5,13 @@
 
 pub mod null;
 pub mod test;
+pub mod compute_shader;
+pub mod time;
+pub mod random;
 
 // Re-export for convenience
 pub use null::{NullLogic, NullNode};
-pub use test::{TestLogic, TestNode};
\ No newline at end of file
+pub use test::{TestLogic, TestNode};
+pub use compute_shader::{ComputeShaderLogic, ComputeShaderNode};
+pub use time::{TimeLogic, TimeNode};
+pub use random::{RandomLogic, RandomNode};
\ No newline at end of file
@@ -24,16 +24,11 @@ impl Default for NullLogic {
 }
 
 impl NullLogic {
-    /// Process input data - simple passthrough
+    /// Process input data - identity passthrough of the first input
     pub fn process(&self, inputs: Vec<NodeData>) -> Vec<NodeData> {
         if self.enabled {
-            // Pass through the first input if available
-            if !inputs.is_empty() {
-                vec![inputs[0].clone()]
-            } else {
-                // If no input, output None
-                vec![NodeData::None]
-            }
+            // Move the input out rather than cloning it; the null only names a point in the graph
+            vec![inputs.into_iter().next().unwrap_or(NodeData::None)]
         } else {
             // If disabled, output None regardless of input
             vec![NodeData::None]
@@ -69,4 +64,20 @@ impl NullLogic {
     pub fn set_description(&mut self, description: String) {
         self.description = description;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_null_is_identity_unless_disabled() {
+        let logic = NullLogic::default();
+        let result = logic.process(vec![NodeData::String("geometry".to_string())]);
+        assert!(matches!(&result[0], NodeData::String(s) if s == "geometry"));
+        assert!(matches!(logic.process(Vec::new())[0], NodeData::None));
+
+        let disabled = NullLogic { enabled: false, ..Default::default() };
+        assert!(matches!(disabled.process(vec![NodeData::Float(1.0)])[0], NodeData::None));
+    }
+}
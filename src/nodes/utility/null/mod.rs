@@ -14,7 +14,7 @@ impl NodeFactory for parameters::NullNode {
             "Null",
             "Null",
             crate::nodes::NodeCategory::new(&["Utility"]),
-            "A simple passthrough node for organization and hierarchy"
+            "Identity passthrough with a large label on the canvas, used as a named anchor point in big graphs"
         )
         .with_color(egui::Color32::from_rgb(100, 100, 100))
         .with_icon("⬜")
@@ -27,7 +27,7 @@ impl NodeFactory for parameters::NullNode {
                 .with_description("Passthrough of input data"),
         ])
         .with_panel_type(crate::nodes::interface::PanelType::Parameter)
-        .with_tags(vec!["utility", "null", "passthrough", "organization", "placeholder"])
+        .with_tags(vec!["utility", "null", "passthrough", "organization", "placeholder", "anchor", "label", "out"])
         .with_processing_cost(crate::nodes::factory::ProcessingCost::Low)
         .with_workspace_compatibility(vec!["3D", "General", "USD", "MaterialX"])
     }
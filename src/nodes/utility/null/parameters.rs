@@ -188,6 +188,32 @@ impl NullNode {
         changes
     }
     
+    /// Build NullLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> NullLogic {
+        let defaults = NullLogic::default();
+        let text = |name: &str| node.parameters.get(name)
+            .and_then(|v| if let NodeData::String(s) = v { Some(s.clone()) } else { None });
+        NullLogic {
+            label: text("label").unwrap_or(defaults.label),
+            enabled: node.parameters.get("enabled")
+                .and_then(|v| if let NodeData::Boolean(b) = v { Some(*b) } else { None })
+                .unwrap_or(defaults.enabled),
+            description: text("description").unwrap_or(defaults.description),
+        }
+    }
+    
+    /// Label drawn above the node on the canvas, unless it just repeats the title
+    pub fn anchor_label(node: &Node) -> Option<String> {
+        let label = Self::create_logic(node).label;
+        let label = label.trim();
+        (!label.is_empty() && label != node.title).then(|| label.to_string())
+    }
+    
+    /// Execute the node using its stored parameters
+    pub fn process_node(node: &Node, inputs: Vec<NodeData>) -> Vec<NodeData> {
+        Self::create_logic(node).process(inputs)
+    }
+    
     /// Convert current parameters to NullLogic for processing
     pub fn to_null_logic(&self) -> NullLogic {
         NullLogic {
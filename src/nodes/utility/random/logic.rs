@@ -0,0 +1,58 @@
+//! Random node functional operations - drawing from a seeded stream
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::seed::SeedContext;
+use crate::nodes::NodeId;
+
+/// Core Random configuration
+#[derive(Debug, Clone)]
+pub struct RandomLogic {
+    pub min: f32,
+    pub max: f32,
+    /// Number of values in the buffer output
+    pub count: usize,
+    /// Offset combined with the project seed
+    pub seed: i32,
+}
+
+impl Default for RandomLogic {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            count: 16,
+            seed: 0,
+        }
+    }
+}
+
+impl RandomLogic {
+    /// Outputs of node `node_id` under the project seed in `seeds`
+    pub fn process(&self, node_id: NodeId, seeds: &SeedContext) -> Vec<NodeData> {
+        let stream = format!("random/{}", self.seed);
+        let mut rng = seeds.rng(node_id, &stream);
+        let value = rng.range_f32(self.min, self.max);
+        let values = (0..self.count).map(|_| rng.range_f32(self.min, self.max)).collect();
+        vec![NodeData::Float(value), NodeData::Buffer(values)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_values_follow_the_project_seed() {
+        let logic = RandomLogic { min: -1.0, max: 1.0, count: 4, seed: 0 };
+        let outputs = logic.process(3, &SeedContext::new(10));
+        assert_eq!(outputs.len(), 2);
+        let NodeData::Buffer(values) = &outputs[1] else { panic!("expected a buffer") };
+        assert_eq!(values.len(), 4);
+        assert!(values.iter().all(|value| (-1.0..1.0).contains(value)));
+
+        let again = logic.process(3, &SeedContext::new(10));
+        assert!(matches!((&outputs[0], &again[0]), (NodeData::Float(a), NodeData::Float(b)) if a == b));
+        let reseeded = logic.process(3, &SeedContext::new(11));
+        assert!(matches!((&outputs[0], &reseeded[0]), (NodeData::Float(a), NodeData::Float(b)) if a != b));
+    }
+}
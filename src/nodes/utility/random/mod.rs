@@ -0,0 +1,56 @@
+//! Random node module - seeded random values
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Drawing values from the node's random stream
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+//!
+//! Values come from the project seed (see [`crate::nodes::seed`]), so they
+//! stay the same every time the file is opened until the seed changes.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::RandomLogic;
+pub use parameters::RandomNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::RandomNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Utility_Random",
+            "Random",
+            crate::nodes::NodeCategory::new(&["Utility"]),
+            "Outputs reproducible random values drawn from the project seed"
+        )
+        .with_color(egui::Color32::from_rgb(90, 70, 60))
+        .with_icon("🎲")
+        .with_inputs(vec![])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Value", crate::nodes::DataType::Float)
+                .with_description("Random float between Min and Max"),
+            crate::nodes::PortDefinition::required("Values", crate::nodes::DataType::Buffer)
+                .with_description("Count random floats between Min and Max"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::float("min", "Min", 0.0)
+                .with_tooltip("Smallest value drawn"),
+            crate::nodes::ParameterDefinition::float("max", "Max", 1.0)
+                .with_tooltip("Largest value drawn"),
+            crate::nodes::ParameterDefinition::integer("count", "Count", 16)
+                .with_range(0.0, 1_000_000.0)
+                .with_soft_range(0.0, 1_000.0)
+                .with_tooltip("Number of values in the Values buffer"),
+            crate::nodes::ParameterDefinition::integer("seed", "Seed", 0)
+                .with_tooltip("Offset combined with the project seed; change it to get different values from this node"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["utility", "random", "seed", "noise", "jitter"])
+        .with_subtitle(|node| {
+            let logic = parameters::RandomNode::create_logic(node);
+            Some(format!("{} – {}, seed {}", logic.min, logic.max, logic.seed))
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
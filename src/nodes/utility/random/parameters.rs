@@ -0,0 +1,36 @@
+//! Random node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::seed::SeedContext;
+use crate::nodes::Node;
+use super::logic::RandomLogic;
+
+/// Random node
+#[derive(Debug, Clone, Default)]
+pub struct RandomNode;
+
+impl RandomNode {
+    /// Build RandomLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> RandomLogic {
+        let defaults = RandomLogic::default();
+        RandomLogic {
+            min: node.parameters.get("min")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.min),
+            max: node.parameters.get("max")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.max),
+            count: node.parameters.get("count")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some((*i).max(0) as usize) } else { None })
+                .unwrap_or(defaults.count),
+            seed: node.parameters.get("seed")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(defaults.seed),
+        }
+    }
+
+    /// Execute the node under the project seed
+    pub fn process_node(node: &Node, seeds: &SeedContext) -> Vec<NodeData> {
+        Self::create_logic(node).process(node.id, seeds)
+    }
+}
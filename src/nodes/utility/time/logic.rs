@@ -0,0 +1,33 @@
+//! Time node functional operations - frame to time conversion
+
+use crate::nodes::interface::NodeData;
+
+/// Core Time configuration
+#[derive(Debug, Clone)]
+pub struct TimeLogic {
+    /// Frames per second used for the Seconds output
+    pub fps: f32,
+    /// Frames added to the timeline frame
+    pub offset: i32,
+}
+
+impl Default for TimeLogic {
+    fn default() -> Self {
+        Self {
+            fps: 24.0,
+            offset: 0,
+        }
+    }
+}
+
+impl TimeLogic {
+    /// Outputs for the timeline frame `frame`
+    pub fn process(&self, frame: i64) -> Vec<NodeData> {
+        let frame = frame.saturating_add(self.offset as i64);
+        let seconds = if self.fps > 0.0 { frame as f32 / self.fps } else { 0.0 };
+        vec![
+            NodeData::Integer(frame.clamp(i32::MIN as i64, i32::MAX as i64) as i32),
+            NodeData::Float(seconds),
+        ]
+    }
+}
@@ -0,0 +1,56 @@
+//! Time node module - the engine's current frame as graph data
+//!
+//! - mod.rs: Base node metadata, parameter schema and factory implementation
+//! - logic.rs: Frame to time conversion
+//! - parameters.rs: Reading the parameters; the panel is generated from the schema
+//!
+//! Time nodes are the sources of time dependence: they and everything
+//! downstream of them are cached per frame, the rest of the graph once.
+
+pub mod logic;
+pub mod parameters;
+
+pub use logic::TimeLogic;
+pub use parameters::TimeNode;
+
+use crate::nodes::NodeFactory;
+
+impl NodeFactory for parameters::TimeNode {
+    fn metadata() -> crate::nodes::NodeMetadata {
+        crate::nodes::NodeMetadata::new(
+            "Utility_Time",
+            "Time",
+            crate::nodes::NodeCategory::new(&["Utility"]),
+            "Outputs the current timeline frame and the matching time in seconds"
+        )
+        .with_color(egui::Color32::from_rgb(70, 60, 90))
+        .with_icon("⏱")
+        .with_inputs(vec![])
+        .with_outputs(vec![
+            crate::nodes::PortDefinition::required("Frame", crate::nodes::DataType::Integer)
+                .with_description("Current frame plus the node's offset"),
+            crate::nodes::PortDefinition::required("Seconds", crate::nodes::DataType::Float)
+                .with_description("Frame divided by the frame rate"),
+        ])
+        .with_parameters(vec![
+            crate::nodes::ParameterDefinition::float("fps", "Frame Rate", 24.0)
+                .with_range(1.0, 240.0)
+                .with_soft_range(12.0, 60.0)
+                .with_step(0.1)
+                .with_tooltip("Frames per second used to convert frames to seconds"),
+            crate::nodes::ParameterDefinition::integer("offset", "Offset", 0)
+                .with_tooltip("Frames added to the timeline frame"),
+        ])
+        .with_panel_type(crate::nodes::interface::PanelType::Parameter)
+        .with_tags(vec!["utility", "time", "frame", "animation", "timeline"])
+        .with_subtitle(|node| {
+            let logic = parameters::TimeNode::create_logic(node);
+            Some(match logic.offset {
+                0 => format!("{} fps", logic.fps),
+                offset => format!("{} fps, {:+} frames", logic.fps, offset),
+            })
+        })
+        .with_processing_cost(crate::nodes::factory::ProcessingCost::Minimal)
+        .with_workspace_compatibility(vec!["3D", "General", "Programming"])
+    }
+}
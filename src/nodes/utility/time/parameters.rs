@@ -0,0 +1,29 @@
+//! Time node parameters, declared in the node's metadata schema
+
+use crate::nodes::interface::NodeData;
+use crate::nodes::Node;
+use super::logic::TimeLogic;
+
+/// Time node
+#[derive(Debug, Clone, Default)]
+pub struct TimeNode;
+
+impl TimeNode {
+    /// Build TimeLogic from the node's stored parameters
+    pub fn create_logic(node: &Node) -> TimeLogic {
+        let defaults = TimeLogic::default();
+        TimeLogic {
+            fps: node.parameters.get("fps")
+                .and_then(|v| if let NodeData::Float(f) = v { Some(*f) } else { None })
+                .unwrap_or(defaults.fps),
+            offset: node.parameters.get("offset")
+                .and_then(|v| if let NodeData::Integer(i) = v { Some(*i) } else { None })
+                .unwrap_or(defaults.offset),
+        }
+    }
+    
+    /// Execute the node for the engine's current frame
+    pub fn process_node(node: &Node, frame: i64) -> Vec<NodeData> {
+        Self::create_logic(node).process(frame)
+    }
+}
@@ -0,0 +1,188 @@
+//! Short text forms of node output values
+//!
+//! Used wherever a value is shown in passing on the canvas: the tooltip of a
+//! connection shows scalars only, while probes and the inspect hover show a
+//! few lines for any value (array heads, table and scene summaries, image
+//! thumbnails).
+
+use crate::nodes::interface::NodeData;
+
+/// Longest string shown before it is cut off with an ellipsis
+const MAX_STRING_CHARS: usize = 60;
+
+/// One-line text for scalar and string values; None for everything else
+pub fn scalar_text(value: &NodeData) -> Option<String> {
+    Some(match value {
+        NodeData::Float(v) => format!("{:.4}", v),
+        NodeData::Integer(v) => v.to_string(),
+        NodeData::Boolean(v) => v.to_string(),
+        NodeData::Vector3([x, y, z]) => format!("({:.3}, {:.3}, {:.3})", x, y, z),
+        NodeData::Color([r, g, b, a]) => format!("rgba({:.3}, {:.3}, {:.3}, {:.3})", r, g, b, a),
+        NodeData::String(text) | NodeData::Any(text) => format!("\"{}\"", truncate(text, MAX_STRING_CHARS)),
+        _ => return None,
+    })
+}
+
+/// Entries of an array listed before the rest is summarized
+const MAX_ARRAY_ENTRIES: usize = 4;
+
+/// A few lines describing a value, plus an image to show as a thumbnail
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValuePreview {
+    pub lines: Vec<String>,
+    /// File of an image value
+    pub image_path: Option<String>,
+}
+
+impl ValuePreview {
+    /// A preview that is just a note, such as for a value not cooked yet
+    pub fn message(text: &str) -> Self {
+        Self { lines: vec![text.to_string()], image_path: None }
+    }
+}
+
+/// Describe `value` in at most `max_lines` lines
+pub fn preview(value: &NodeData, max_lines: usize) -> ValuePreview {
+    let mut image_path = None;
+    let mut lines = match value {
+        NodeData::String(text) | NodeData::Any(text) => text.lines()
+            .map(|line| truncate(line, MAX_STRING_CHARS))
+            .collect(),
+        NodeData::Buffer(values) => vec![
+            format!("Buffer [{}]", values.len()),
+            array_head(values.iter().map(|v| format!("{:.3}", v)), values.len()),
+        ],
+        NodeData::Table(table) => {
+            let mut lines = vec![format!("Table {} × {}", table.row_count(), table.column_count())];
+            lines.push(truncate(&table.columns.join(", "), MAX_STRING_CHARS));
+            lines.extend(table.rows.iter().map(|row| {
+                truncate(&row.iter().map(|cell| cell.to_string()).collect::<Vec<_>>().join(", "), MAX_STRING_CHARS)
+            }));
+            lines
+        }
+        NodeData::Tree(tree) => {
+            let mut lines = vec![format!("Tree, {} items", tree.item_count())];
+            lines.extend(tree.roots.iter().map(|item| item.label.clone()));
+            lines
+        }
+        NodeData::PointCloud(points) => vec![
+            format!("Point cloud {}", file_name(&points.source)),
+            format!("{} points{}", points.point_count(), if points.has_colors() { ", colored" } else { "" }),
+        ],
+        NodeData::Volume(volume) => {
+            let mut lines = vec![format!("Volume {}", file_name(&volume.source))];
+            lines.extend(volume.grids.iter().map(|grid| format!("{} ({})", grid.name, grid.value_type)));
+            lines
+        }
+        NodeData::List(items) => vec![
+            format!("List [{}]", items.len()),
+            array_head(items.iter().map(|item| scalar_text(item).unwrap_or_else(|| preview(item, 1).lines.concat())), items.len()),
+        ],
+        NodeData::Map(entries) => {
+            let mut lines = vec![format!("Map, {} keys", entries.len())];
+            lines.extend(entries.iter().map(|(key, value)| truncate(
+                &format!("{}: {}", key, scalar_text(value).unwrap_or_else(|| preview(value, 1).lines.concat())),
+                MAX_STRING_CHARS,
+            )));
+            lines
+        }
+        NodeData::Audio(audio) => vec![
+            format!("Audio {}", file_name(&audio.source)),
+            format!("{:.2}s, {} Hz, {} channels", audio.duration(), audio.sample_rate, audio.channels),
+        ],
+        NodeData::Image(image) => {
+            image_path = image.file_path.clone();
+            vec![format!("Image {} × {}", image.width, image.height)]
+        }
+        NodeData::Geometry(geometry) => vec![
+            format!("Geometry {}", geometry.id),
+            format!("{} points, {} triangles", geometry.vertices.len(), geometry.indices.len() / 3),
+        ],
+        NodeData::Scene(scene) => vec![
+            "Scene".to_string(),
+            format!("{} meshes, {} materials, {} lights", scene.geometry.len(), scene.materials.len(), scene.lights.len()),
+        ],
+        NodeData::USDSceneData(scene) => vec![
+            format!("USD {}", file_name(&scene.stage_path)),
+            format!("{} meshes, {} materials, {} lights", scene.meshes.len(), scene.materials.len(), scene.lights.len()),
+        ],
+        NodeData::USDScenegraphMetadata(metadata) => vec![
+            format!("USD {}", file_name(&metadata.stage_path)),
+            format!("{} meshes, {} triangles", metadata.meshes.len(), metadata.total_triangles),
+        ],
+        NodeData::Stage(stage) => vec![
+            format!("Stage {}", stage.identifier),
+            format!("{} prims", stage.prims.len()),
+        ],
+        NodeData::Material(material) => vec![format!("Material {}", material.id)],
+        NodeData::Light(light) => vec![format!("Light {} ({:.2})", light.id, light.intensity)],
+        NodeData::Custom(data) => vec![
+            crate::plugins::data_types::type_name(&data.type_id),
+            truncate(&data.value.to_string(), MAX_STRING_CHARS),
+        ],
+        NodeData::None => vec!["(none)".to_string()],
+        other => scalar_text(other).into_iter().collect(),
+    };
+    if lines.len() > max_lines {
+        let hidden = lines.len() - max_lines + 1;
+        lines.truncate(max_lines - 1);
+        lines.push(format!("… {} more", hidden));
+    }
+    ValuePreview { lines, image_path }
+}
+
+/// The first few entries of an array of `len` entries
+fn array_head(entries: impl Iterator<Item = String>, len: usize) -> String {
+    let head: Vec<String> = entries.take(MAX_ARRAY_ENTRIES).collect();
+    if len > MAX_ARRAY_ENTRIES {
+        format!("[{}, …]", head.join(", "))
+    } else {
+        format!("[{}]", head.join(", "))
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+/// `text` cut to at most `max_chars` characters, marked with an ellipsis when cut
+pub fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_text_covers_small_values_only() {
+        assert_eq!(scalar_text(&NodeData::Float(1.5)).as_deref(), Some("1.5000"));
+        assert_eq!(scalar_text(&NodeData::Integer(-3)).as_deref(), Some("-3"));
+        assert_eq!(scalar_text(&NodeData::String("hi".into())).as_deref(), Some("\"hi\""));
+        assert_eq!(scalar_text(&NodeData::String("é".repeat(80))).unwrap().chars().count(), MAX_STRING_CHARS + 3);
+        assert!(scalar_text(&NodeData::Buffer(vec![1.0])).is_none());
+        assert!(scalar_text(&NodeData::None).is_none());
+    }
+
+    #[test]
+    fn test_preview_summarizes_and_limits_lines() {
+        let buffer = preview(&NodeData::Buffer(vec![1.0, 2.0, 3.0, 4.0, 5.0]), 4);
+        assert_eq!(buffer.lines, vec!["Buffer [5]".to_string(), "[1.000, 2.000, 3.000, 4.000, …]".to_string()]);
+
+        let text = preview(&NodeData::String("a\nb\nc\nd\ne".into()), 3);
+        assert_eq!(text.lines, vec!["a".to_string(), "b".to_string(), "… 3 more".to_string()]);
+
+        let image = preview(&NodeData::Image(crate::nodes::interface::ImageData {
+            id: "render".into(),
+            file_path: Some("/tmp/render.png".into()),
+            width: 64,
+            height: 32,
+            format: crate::nodes::interface::ImageFormat::RGBA8,
+        }), 4);
+        assert_eq!(image.lines, vec!["Image 64 × 32".to_string()]);
+        assert_eq!(image.image_path.as_deref(), Some("/tmp/render.png"));
+    }
+}
@@ -0,0 +1,189 @@
+//! Wire routing styles
+//!
+//! Wires run from an output at the bottom of one node to an input at the top
+//! of another. They are drawn as curves by default, or as straight lines, or
+//! stepped: down out of the output, across, and down into the input, with
+//! rounded corners. The style is chosen per file (see `NodeGraph::wire_style`)
+//! and every place that draws or hit-tests a wire builds its path here, so
+//! clicks, cuts and box selections follow what is on screen.
+
+use egui::{Pos2, Shape, Stroke, Vec2};
+use serde::{Deserialize, Serialize};
+use super::math_utils::{cubic_bezier_point, distance_to_bezier_curve, distance_to_line_segment};
+
+/// Radius of a stepped wire's corners at zoom 1
+const CORNER_RADIUS: f32 = 12.0;
+
+/// Distance a stepped wire runs straight out of and into its ports when it has to go around, at zoom 1
+const LEAD: f32 = 20.0;
+
+/// Segments drawn per rounded corner
+const CORNER_SEGMENTS: usize = 6;
+
+/// How wires are routed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WireStyle {
+    #[default]
+    Bezier,
+    Straight,
+    /// Vertical and horizontal runs with rounded corners
+    Stepped,
+}
+
+impl WireStyle {
+    pub const ALL: [WireStyle; 3] = [WireStyle::Bezier, WireStyle::Straight, WireStyle::Stepped];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WireStyle::Bezier => "Curved",
+            WireStyle::Straight => "Straight",
+            WireStyle::Stepped => "Stepped",
+        }
+    }
+}
+
+/// The shape of one wire
+#[derive(Debug, Clone, PartialEq)]
+pub enum WirePath {
+    /// Control points of a cubic bezier
+    Bezier([Pos2; 4]),
+    Polyline(Vec<Pos2>),
+}
+
+impl WirePath {
+    /// Path from an output at `from` to an input at `to`. `scale` sizes the
+    /// stepped corners: the zoom for screen positions, 1 for world positions.
+    pub fn new(style: WireStyle, from: Pos2, to: Pos2, scale: f32) -> Self {
+        match style {
+            WireStyle::Bezier => {
+                // Handle length proportional to the distance, as wires have always been drawn
+                let control_offset = (to - from).length().sqrt() * 4.0;
+                WirePath::Bezier([from, from + Vec2::new(0.0, control_offset), to - Vec2::new(0.0, control_offset), to])
+            }
+            WireStyle::Straight => WirePath::Polyline(vec![from, to]),
+            WireStyle::Stepped => WirePath::Polyline(round_corners(&stepped_corners(from, to, LEAD * scale), CORNER_RADIUS * scale)),
+        }
+    }
+
+    /// Point `t` of the way along the path, 0 at the output and 1 at the input
+    pub fn point_at(&self, t: f32) -> Pos2 {
+        match self {
+            WirePath::Bezier([p0, p1, p2, p3]) => cubic_bezier_point(t, *p0, *p1, *p2, *p3),
+            WirePath::Polyline(points) => {
+                let length: f32 = points.windows(2).map(|segment| (segment[1] - segment[0]).length()).sum();
+                let mut remaining = length * t.clamp(0.0, 1.0);
+                for segment in points.windows(2) {
+                    let segment_length = (segment[1] - segment[0]).length();
+                    if remaining <= segment_length && segment_length > 0.0 {
+                        return segment[0].lerp(segment[1], remaining / segment_length);
+                    }
+                    remaining -= segment_length;
+                }
+                points.last().copied().unwrap_or_default()
+            }
+        }
+    }
+
+    /// Distance from `point` to the nearest part of the path
+    pub fn distance_to(&self, point: Pos2) -> f32 {
+        match self {
+            WirePath::Bezier([p0, p1, p2, p3]) => distance_to_bezier_curve(point, *p0, *p1, *p2, *p3),
+            WirePath::Polyline(points) => points.windows(2)
+                .map(|segment| distance_to_line_segment(point, segment[0], segment[1]))
+                .fold(f32::INFINITY, f32::min),
+        }
+    }
+
+    /// `count + 1` points spread evenly along the path, ends included
+    pub fn sample(&self, count: usize) -> Vec<Pos2> {
+        (0..=count).map(|i| self.point_at(i as f32 / count.max(1) as f32)).collect()
+    }
+
+    pub fn shape(&self, stroke: Stroke) -> Shape {
+        match self {
+            WirePath::Bezier(points) => Shape::CubicBezier(egui::epaint::CubicBezierShape {
+                points: *points,
+                closed: false,
+                fill: egui::Color32::TRANSPARENT,
+                stroke: stroke.into(),
+            }),
+            WirePath::Polyline(points) => Shape::line(points.clone(), stroke),
+        }
+    }
+}
+
+/// Corners of a stepped wire before rounding
+fn stepped_corners(from: Pos2, to: Pos2, lead: f32) -> Vec<Pos2> {
+    if (to.x - from.x).abs() < 1.0 && to.y > from.y {
+        return vec![from, to];
+    }
+    if to.y - from.y >= 2.0 * lead {
+        let middle = (from.y + to.y) / 2.0;
+        return vec![from, Pos2::new(from.x, middle), Pos2::new(to.x, middle), to];
+    }
+    // The input is level with or above the output: leave downwards, go up
+    // halfway between the two ports and come into the input from above
+    let middle = (from.x + to.x) / 2.0;
+    vec![
+        from,
+        Pos2::new(from.x, from.y + lead),
+        Pos2::new(middle, from.y + lead),
+        Pos2::new(middle, to.y - lead),
+        Pos2::new(to.x, to.y - lead),
+        to,
+    ]
+}
+
+/// Replace every inner corner with a curve of up to `radius`
+fn round_corners(corners: &[Pos2], radius: f32) -> Vec<Pos2> {
+    let mut points = corners.first().copied().into_iter().collect::<Vec<_>>();
+    for window in corners.windows(3) {
+        let (before, corner, after) = (window[0], window[1], window[2]);
+        let radius = radius.min((corner - before).length() / 2.0).min((after - corner).length() / 2.0);
+        let start = corner + (before - corner).normalized() * radius;
+        let end = corner + (after - corner).normalized() * radius;
+        // Quadratic bezier with the corner as its control point
+        points.extend((0..=CORNER_SEGMENTS).map(|i| {
+            let t = i as f32 / CORNER_SEGMENTS as f32;
+            start.lerp(corner, t).lerp(corner.lerp(end, t), t)
+        }));
+    }
+    points.extend(corners.last().filter(|_| corners.len() > 1));
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_join_their_ports_in_every_style() {
+        let from = Pos2::new(0.0, 0.0);
+        let to = Pos2::new(200.0, 300.0);
+        for style in WireStyle::ALL {
+            let path = WirePath::new(style, from, to, 1.0);
+            assert!(path.point_at(0.0).distance(from) < 1e-3, "{:?}", style);
+            assert!(path.point_at(1.0).distance(to) < 1e-3, "{:?}", style);
+            assert!(path.distance_to(path.point_at(0.3)) < 0.5, "{:?}", style);
+        }
+
+        let straight = WirePath::new(WireStyle::Straight, from, to, 1.0);
+        assert!(straight.point_at(0.5).distance(Pos2::new(100.0, 150.0)) < 1e-3);
+
+        // Stepped wires cross over halfway down, clear of the straight line's middle
+        let stepped = WirePath::new(WireStyle::Stepped, from, to, 1.0);
+        assert!(stepped.distance_to(Pos2::new(100.0, 150.0)) < 1e-3);
+        assert!(stepped.distance_to(Pos2::new(0.0, 100.0)) < 1e-3);
+        assert!(stepped.distance_to(Pos2::new(100.0, 100.0)) > 40.0);
+    }
+
+    #[test]
+    fn test_stepped_wires_go_around_to_inputs_above_their_output() {
+        let path = WirePath::new(WireStyle::Stepped, Pos2::new(0.0, 100.0), Pos2::new(200.0, 0.0), 1.0);
+        // Leaves downwards and comes into the input from above
+        assert!(path.distance_to(Pos2::new(0.0, 110.0)) < 1e-3);
+        assert!(path.distance_to(Pos2::new(200.0, -10.0)) < 1e-3);
+        assert!(path.distance_to(Pos2::new(100.0, 50.0)) < 1e-3);
+    }
+}
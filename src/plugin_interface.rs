@@ -107,6 +107,8 @@ impl From<nodle_plugin_sdk::viewport::SceneData> for crate::viewport::SceneData
             lights: sdk_scene.lights.into_iter().map(|l| l.into()).collect(),
             camera: sdk_scene.camera.into(),
             bounding_box: sdk_scene.bounding_box,
+            points: Vec::new(),
+            lines: Vec::new(),
         }
     }
 }
@@ -167,6 +169,7 @@ impl From<nodle_plugin_sdk::viewport::MeshData> for crate::viewport::MeshData {
             vertex_colors: sdk_mesh.vertex_colors,
             material_id: sdk_mesh.material_id,
             transform: sdk_mesh.transform,
+            prim_path: String::new(),
         }
     }
 }
@@ -290,6 +293,14 @@ impl From<nodle_plugin_sdk::viewport::ViewportSettings> for crate::viewport::Vie
             show_ground_plane: sdk_settings.show_ground_plane,
             aa_samples: sdk_settings.aa_samples,
             shading_mode: sdk_settings.shading_mode.into(),
+            display_mode: if sdk_settings.wireframe { crate::viewport::DisplayMode::Wireframe } else { crate::viewport::DisplayMode::Shaded },
+            available_aovs: Vec::new(),
+            aov: None,
+            selected_prims: Vec::new(),
+            hydra_delegate: None,
+            delegate_settings: Vec::new(),
+            hydra_region: Default::default(),
+            point_display: Default::default(),
         }
     }
 }
@@ -0,0 +1,423 @@
+//! Data types provided by plugins
+//!
+//! A plugin can pass values of its own types between its nodes (a scan format
+//! of its own, a simulation state) by exporting the types it provides:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub extern "C" fn nodle_plugin_data_types() -> *const std::os::raw::c_char {
+//!     concat!(r#"[{"id": "lidar.scan", "name": "Lidar Scan", "converts_to": ["PointCloud", "Integer"]}]"#, "\0").as_ptr().cast()
+//! }
+//! ```
+//!
+//! Values of these types flow through the graph as [`NodeData::Custom`]: the
+//! type ID and the value as JSON. They pass through Any ports unchanged and
+//! reach plugin nodes as `Any` handles (see [`to_handle`]). A plugin may also
+//! export hooks, each taking the type ID and NUL-terminated JSON and returning
+//! NUL-terminated JSON that the host copies before calling the plugin again:
+//!
+//! - `nodle_plugin_data_validate(type_id, value)`: null for a valid value, otherwise what is wrong with it
+//! - `nodle_plugin_data_convert(type_id, target, value)`: the value as the built-in type `target`, one of its
+//!   `converts_to` names, or null; used when a value reaches an input of that type
+//! - `nodle_plugin_data_serialize(type_id, value)`: the form written to project files
+//! - `nodle_plugin_data_deserialize(type_id, saved)`: the value read back from that form, or null
+//!
+//! Hooks are called without holding the registry lock and guarded like plugin
+//! nodes: a plugin whose hook panics has its hooks dropped, after which its
+//! values are treated as having none.
+//!
+//! Without hooks every value is valid, nothing converts and values are saved
+//! as they are. Values of types whose plugin is not loaded are kept, and saved
+//! again, unchanged.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::nodes::DataType;
+use crate::nodes::interface::{CustomData, NodeData, PointCloudData};
+use super::sandbox;
+
+/// Name of the optional symbol returning the data types a plugin provides
+pub const DATA_TYPES_SYMBOL: &[u8] = b"nodle_plugin_data_types";
+
+pub const VALIDATE_SYMBOL: &[u8] = b"nodle_plugin_data_validate";
+pub const CONVERT_SYMBOL: &[u8] = b"nodle_plugin_data_convert";
+pub const SERIALIZE_SYMBOL: &[u8] = b"nodle_plugin_data_serialize";
+pub const DESERIALIZE_SYMBOL: &[u8] = b"nodle_plugin_data_deserialize";
+
+/// Key of the type ID in the JSON handles values travel in to plugin nodes
+const HANDLE_TYPE_KEY: &str = "$type";
+
+/// Signature of the validate, serialize and deserialize hooks: type ID and value in, JSON or null out
+pub type ValueHook = unsafe extern "C" fn(*const c_char, *const c_char) -> *const c_char;
+
+/// Signature of the convert hook: type ID, target type name and value in, JSON or null out
+pub type ConvertHook = unsafe extern "C" fn(*const c_char, *const c_char, *const c_char) -> *const c_char;
+
+/// A data type as declared by its plugin
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataTypeDescriptor {
+    /// Unique ID, saved with every value; prefix it with the plugin's name
+    pub id: String,
+    /// Name shown in the inspector and previews
+    pub name: String,
+    /// Built-in types (as in `DataType::name`) the convert hook turns values into
+    #[serde(default)]
+    pub converts_to: Vec<String>,
+}
+
+/// The hooks a plugin exports for its data types
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DataTypeHooks {
+    pub validate: Option<ValueHook>,
+    pub convert: Option<ConvertHook>,
+    pub serialize: Option<ValueHook>,
+    pub deserialize: Option<ValueHook>,
+}
+
+#[derive(Clone)]
+struct RegisteredType {
+    plugin: String,
+    descriptor: DataTypeDescriptor,
+    hooks: DataTypeHooks,
+}
+
+static REGISTRY: Lazy<RwLock<HashMap<String, RegisteredType>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Parse the JSON array returned by a plugin's data types symbol
+pub fn parse_data_type_descriptors(json: &str) -> Result<Vec<DataTypeDescriptor>, String> {
+    let descriptors: Vec<DataTypeDescriptor> = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid data types: {}", e))?;
+    for descriptor in &descriptors {
+        if descriptor.id.trim().is_empty() || descriptor.id.starts_with('$') {
+            return Err(format!("Invalid data type id \"{}\"", descriptor.id));
+        }
+        if builtin_type(&descriptor.id).is_some() {
+            return Err(format!("Data type id \"{}\" is a built-in type", descriptor.id));
+        }
+        // Any is no conversion at all
+        if let Some(target) = descriptor.converts_to.iter().find(|target| !matches!(builtin_type(target), Some(data_type) if data_type != DataType::Any)) {
+            return Err(format!("Data type \"{}\" converts to unknown type \"{}\"", descriptor.id, target));
+        }
+    }
+    Ok(descriptors)
+}
+
+/// The built-in type named `name`, ignoring case
+fn builtin_type(name: &str) -> Option<DataType> {
+    DataType::ALL.into_iter().find(|data_type| data_type.name().eq_ignore_ascii_case(name))
+}
+
+/// Make the types of `plugin` available. Fails, registering none of them, if
+/// another plugin already provides one of the IDs.
+///
+/// # Safety
+/// The hooks must stay callable until [`unregister_plugin`] is called for
+/// `plugin` and no hook call started before that is still running.
+pub unsafe fn register(plugin: &str, descriptors: Vec<DataTypeDescriptor>, hooks: DataTypeHooks) -> Result<(), String> {
+    let mut registry = REGISTRY.write().map_err(|_| "Data type registry is poisoned".to_string())?;
+    if let Some(taken) = descriptors.iter()
+        .find_map(|descriptor| registry.get(&descriptor.id).filter(|registered| registered.plugin != plugin))
+    {
+        return Err(format!("Data type \"{}\" is already provided by {}", taken.descriptor.id, taken.plugin));
+    }
+    for descriptor in descriptors {
+        registry.insert(descriptor.id.clone(), RegisteredType { plugin: plugin.to_string(), descriptor, hooks });
+    }
+    Ok(())
+}
+
+/// Forget the types of `plugin`, before its library is unloaded
+pub fn unregister_plugin(plugin: &str) {
+    if let Ok(mut registry) = REGISTRY.write() {
+        registry.retain(|_, registered| registered.plugin != plugin);
+    }
+}
+
+/// Display name of the type `type_id`, the ID itself while its plugin is not loaded
+pub fn type_name(type_id: &str) -> String {
+    with_type(type_id, |registered| registered.descriptor.name.clone()).unwrap_or_else(|| type_id.to_string())
+}
+
+fn with_type<T>(type_id: &str, f: impl FnOnce(&RegisteredType) -> T) -> Option<T> {
+    REGISTRY.read().ok()?.get(type_id).map(f)
+}
+
+/// Copy of the registration of `type_id`, so its hooks run without the registry locked
+fn registered(type_id: &str) -> Option<RegisteredType> {
+    with_type(type_id, RegisteredType::clone)
+}
+
+/// Call `hook` of `registered`, catching panics. A plugin whose hook panicked
+/// has all its hooks dropped, so they are not called again.
+fn call_hook<T>(registered: &RegisteredType, hook: &str, f: impl FnOnce() -> T) -> Option<T> {
+    let operation = format!("the {} hook of {}", hook, registered.descriptor.id);
+    let result = sandbox::guard_call(&registered.plugin, &operation, f);
+    if result.is_none() {
+        if let Ok(mut registry) = REGISTRY.write() {
+            for other in registry.values_mut().filter(|other| other.plugin == registered.plugin) {
+                other.hooks = DataTypeHooks::default();
+            }
+        }
+    }
+    result
+}
+
+/// NUL-terminated copy of `text`
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "")).unwrap_or_default()
+}
+
+/// Copy of the string a hook returned, None for null
+///
+/// # Safety
+/// `returned` must be null or point to a NUL-terminated string.
+unsafe fn copy_returned(returned: *const c_char) -> Option<String> {
+    (!returned.is_null()).then(|| CStr::from_ptr(returned).to_string_lossy().into_owned())
+}
+
+/// Check `data` with its type's validate hook
+pub fn validate(data: &CustomData) -> Result<(), String> {
+    let registered = registered(&data.type_id)
+        .ok_or_else(|| format!("Unknown data type \"{}\"; is its plugin loaded?", data.type_id))?;
+    let Some(hook) = registered.hooks.validate else {
+        return Ok(());
+    };
+    let (type_id, value) = (c_string(&data.type_id), c_string(&data.value.to_string()));
+    // Registered hooks stay callable until their plugin unregisters them
+    match call_hook(&registered, "validate", || unsafe { copy_returned(hook(type_id.as_ptr(), value.as_ptr())) }) {
+        Some(None) => Ok(()),
+        Some(Some(problem)) => Err(format!("Invalid {}: {}", registered.descriptor.name, problem)),
+        None => Err(format!("{} could not validate a {}", registered.plugin, registered.descriptor.name)),
+    }
+}
+
+/// `data` converted to the built-in type `to`, if its type converts to it
+pub fn convert(data: &CustomData, to: &DataType) -> Option<NodeData> {
+    let registered = registered(&data.type_id)?;
+    let hook = registered.hooks.convert?;
+    let target = registered.descriptor.converts_to.iter()
+        .find(|target| builtin_type(target).as_ref() == Some(to))?;
+    let (type_id, target, value) = (c_string(&data.type_id), c_string(target), c_string(&data.value.to_string()));
+    let json = call_hook(&registered, "convert", || unsafe {
+        copy_returned(hook(type_id.as_ptr(), target.as_ptr(), value.as_ptr()))
+    })??;
+    let converted = serde_json::from_str(&json).ok().and_then(|json| builtin_value(&json, to));
+    if converted.is_none() {
+        log::warn!("{} could not be converted to {}", type_name(&data.type_id), to.name());
+    }
+    converted
+}
+
+/// Form of `data` written to project files
+pub fn save_value(data: &CustomData) -> Value {
+    let saved = registered(&data.type_id).and_then(|registered| {
+        let hook = registered.hooks.serialize?;
+        let (type_id, value) = (c_string(&data.type_id), c_string(&data.value.to_string()));
+        call_hook(&registered, "serialize", || unsafe { copy_returned(hook(type_id.as_ptr(), value.as_ptr())) })?
+    });
+    match saved.map(|saved| serde_json::from_str(&saved)) {
+        Some(Ok(saved)) => saved,
+        Some(Err(e)) => {
+            log::warn!("{} saved as it is; its serialize hook returned invalid JSON: {}", type_name(&data.type_id), e);
+            data.value.clone()
+        }
+        None => data.value.clone(),
+    }
+}
+
+/// Value of the type `type_id` read back from its saved form, which is kept
+/// when the type's plugin is not loaded or cannot read it
+pub fn load_value(type_id: &str, saved: Value) -> Value {
+    let loaded = registered(type_id).and_then(|registered| {
+        let hook = registered.hooks.deserialize?;
+        let (id, text) = (c_string(type_id), c_string(&saved.to_string()));
+        call_hook(&registered, "deserialize", || unsafe { copy_returned(hook(id.as_ptr(), text.as_ptr())) })
+    });
+    match loaded {
+        Some(Some(loaded)) => serde_json::from_str(&loaded).unwrap_or_else(|e| {
+            log::warn!("{} kept as saved; its deserialize hook returned invalid JSON: {}", type_name(type_id), e);
+            saved
+        }),
+        Some(None) => {
+            log::warn!("{} kept as saved; its plugin could not read it", type_name(type_id));
+            saved
+        }
+        None => saved,
+    }
+}
+
+/// `data` as the `Any` handle plugin nodes receive: `{"$type": id, "value": ...}`
+pub fn to_handle(data: &CustomData) -> String {
+    let mut handle = serde_json::Map::new();
+    handle.insert(HANDLE_TYPE_KEY.to_string(), Value::from(data.type_id.as_str()));
+    handle.insert("value".to_string(), data.value.clone());
+    Value::Object(handle).to_string()
+}
+
+/// The value a plugin node handed back in a handle, if it is a valid value of a registered type
+pub fn from_handle(handle: &str) -> Option<CustomData> {
+    let Ok(Value::Object(mut handle)) = serde_json::from_str::<Value>(handle) else {
+        return None;
+    };
+    let Some(Value::String(type_id)) = handle.remove(HANDLE_TYPE_KEY) else {
+        return None;
+    };
+    with_type(&type_id, |_| ())?;
+    let data = CustomData { type_id, value: handle.remove("value").unwrap_or(Value::Null) };
+    match validate(&data) {
+        Ok(()) => Some(data),
+        Err(e) => {
+            log::warn!("{}", e);
+            None
+        }
+    }
+}
+
+/// Read a convert hook's JSON as a value of the built-in type `to`
+fn builtin_value(json: &Value, to: &DataType) -> Option<NodeData> {
+    let numbers = |json: &Value| json.as_array()?.iter()
+        .map(|number| number.as_f64().map(|number| number as f32))
+        .collect::<Option<Vec<f32>>>();
+    let vector = |json: &Value| numbers(json).and_then(|numbers| <[f32; 3]>::try_from(numbers).ok());
+    Some(match to {
+        DataType::Float => NodeData::Float(json.as_f64()? as f32),
+        DataType::Integer => NodeData::Integer(i32::try_from(json.as_i64()?).ok()?),
+        DataType::Boolean => NodeData::Boolean(json.as_bool()?),
+        DataType::String => NodeData::String(json.as_str()?.to_string()),
+        DataType::Vector3 => NodeData::Vector3(vector(json)?),
+        DataType::Color => match numbers(json)?.as_slice() {
+            [r, g, b] => NodeData::Color([*r, *g, *b, 1.0]),
+            [r, g, b, a] => NodeData::Color([*r, *g, *b, *a]),
+            _ => return None,
+        },
+        DataType::Buffer => NodeData::Buffer(numbers(json)?),
+        DataType::List => NodeData::List(json.as_array()?.iter().map(plain_value).collect()),
+        DataType::Map => NodeData::Map(json.as_object()?.iter().map(|(key, value)| (key.clone(), plain_value(value))).collect()),
+        // {"positions": [[x, y, z], ...], "colors": [[r, g, b], ...]}
+        DataType::PointCloud => {
+            let points = |key: &str| match json.get(key) {
+                Some(points) => points.as_array()?.iter().map(|point| vector(point).map(glam::Vec3::from)).collect(),
+                None => Some(Vec::new()),
+            };
+            NodeData::PointCloud(PointCloudData {
+                source: json.get("source").and_then(Value::as_str).unwrap_or_default().to_string(),
+                positions: points("positions")?,
+                colors: points("colors")?,
+            })
+        }
+        _ => return None,
+    })
+}
+
+/// The closest built-in value to a JSON value
+fn plain_value(json: &Value) -> NodeData {
+    match json {
+        Value::Null => NodeData::None,
+        Value::Bool(b) => NodeData::Boolean(*b),
+        Value::Number(number) => match number.as_i64().and_then(|i| i32::try_from(i).ok()) {
+            Some(i) => NodeData::Integer(i),
+            None => NodeData::Float(number.as_f64().unwrap_or_default() as f32),
+        },
+        Value::String(s) => NodeData::String(s.clone()),
+        Value::Array(items) => NodeData::List(items.iter().map(plain_value).collect()),
+        Value::Object(entries) => NodeData::Map(entries.iter().map(|(key, value)| (key.clone(), plain_value(value))).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(value: Value) -> CustomData {
+        CustomData { type_id: "test.scan".to_string(), value }
+    }
+
+    unsafe extern "C" fn validate_scan(_type_id: *const c_char, value: *const c_char) -> *const c_char {
+        let value = CStr::from_ptr(value).to_string_lossy();
+        if value.contains("positions") {
+            std::ptr::null()
+        } else {
+            c"a scan needs positions".as_ptr()
+        }
+    }
+
+    unsafe extern "C" fn convert_scan(_type_id: *const c_char, target: *const c_char, _value: *const c_char) -> *const c_char {
+        match CStr::from_ptr(target).to_str() {
+            Ok("Integer") => c"2".as_ptr(),
+            Ok("PointCloud") => cr#"{"positions": [[0, 0, 0], [1, 2, 3]]}"#.as_ptr(),
+            _ => std::ptr::null(),
+        }
+    }
+
+    unsafe extern "C" fn serialize_scan(_type_id: *const c_char, _value: *const c_char) -> *const c_char {
+        cr#"{"file": "scan.e57"}"#.as_ptr()
+    }
+
+    unsafe extern "C" fn deserialize_scan(_type_id: *const c_char, _saved: *const c_char) -> *const c_char {
+        cr#"{"positions": [[0, 0, 0], [1, 2, 3]]}"#.as_ptr()
+    }
+
+    #[test]
+    fn test_descriptors_must_not_shadow_or_convert_to_unknown_types() {
+        let types = parse_data_type_descriptors(r#"[{"id": "lidar.scan", "name": "Lidar Scan", "converts_to": ["PointCloud"]}]"#).unwrap();
+        assert_eq!(types[0].converts_to, vec!["PointCloud".to_string()]);
+        assert!(parse_data_type_descriptors(r#"[{"id": "float", "name": "Float"}]"#).is_err());
+        assert!(parse_data_type_descriptors(r#"[{"id": "lidar.scan", "name": "Scan", "converts_to": ["Any"]}]"#).is_err());
+        assert!(parse_data_type_descriptors(r#"[{"id": "lidar.scan", "name": "Scan", "converts_to": ["Mesh"]}]"#).is_err());
+    }
+
+    #[test]
+    fn test_registered_types_validate_convert_and_save_through_their_hooks() {
+        let descriptors = parse_data_type_descriptors(r#"[{"id": "test.scan", "name": "Scan", "converts_to": ["Integer", "PointCloud"]}]"#).unwrap();
+        let hooks = DataTypeHooks {
+            validate: Some(validate_scan),
+            convert: Some(convert_scan),
+            serialize: Some(serialize_scan),
+            deserialize: Some(deserialize_scan),
+        };
+        unsafe { register("Scanner", descriptors.clone(), hooks).unwrap() };
+        assert!(unsafe { register("Other", descriptors, DataTypeHooks::default()) }.unwrap_err().contains("Scanner"));
+        assert_eq!(type_name("test.scan"), "Scan");
+
+        let value = scan(serde_json::json!({"positions": [[0, 0, 0], [1, 2, 3]]}));
+        assert!(validate(&value).is_ok());
+        assert!(validate(&scan(Value::Null)).unwrap_err().contains("needs positions"));
+        assert!(matches!(convert(&value, &DataType::Integer), Some(NodeData::Integer(2))));
+        assert!(matches!(convert(&value, &DataType::PointCloud), Some(NodeData::PointCloud(points)) if points.point_count() == 2));
+        assert!(convert(&value, &DataType::Float).is_none());
+
+        // Project files hold the saved form, read back into the value
+        let saved = serde_json::to_value(NodeData::Custom(value.clone())).unwrap();
+        assert_eq!(saved["Custom"]["value"]["file"], "scan.e57");
+        assert!(matches!(serde_json::from_value::<NodeData>(saved.clone()).unwrap(), NodeData::Custom(loaded) if loaded == value));
+
+        // Plugin nodes get the value as a handle and hand it back the same way
+        assert_eq!(from_handle(&to_handle(&value)), Some(value.clone()));
+        assert_eq!(from_handle(&to_handle(&scan(Value::Null))), None);
+        assert_eq!(from_handle("not a handle"), None);
+
+        // Once the plugin is gone values are kept as they were saved
+        unregister_plugin("Scanner");
+        assert!(validate(&value).unwrap_err().contains("Unknown data type"));
+        assert!(matches!(serde_json::from_value::<NodeData>(saved).unwrap(), NodeData::Custom(kept) if kept.value["file"] == "scan.e57"));
+    }
+
+    #[test]
+    fn test_a_panicking_hook_is_contained_and_not_called_again() {
+        let descriptors = parse_data_type_descriptors(r#"[{"id": "test.crashing", "name": "Crashing"}]"#).unwrap();
+        let hooks = DataTypeHooks { validate: Some(validate_scan), ..DataTypeHooks::default() };
+        unsafe { register("Crasher", descriptors, hooks).unwrap() };
+
+        let crashing = registered("test.crashing").unwrap();
+        assert_eq!(call_hook(&crashing, "validate", || -> i32 { panic!("hook failed") }), None);
+        assert!(registered("test.crashing").unwrap().hooks.validate.is_none());
+        // Without its hooks every value of the type is valid again
+        assert!(validate(&CustomData { type_id: "test.crashing".to_string(), value: Value::Null }).is_ok());
+        unregister_plugin("Crasher");
+    }
+}
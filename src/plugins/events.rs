@@ -0,0 +1,242 @@
+//! Graph lifecycle events for plugins
+//!
+//! Integration plugins (a pipeline tracker, a live-sync bridge) can react to
+//! what happens in the editor instead of polling it, by exporting an event
+//! handler and, optionally, the list of events they want:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub extern "C" fn nodle_plugin_event_subscriptions() -> *const std::os::raw::c_char {
+//!     concat!(r#"["node_added", "node_removed", "cook_finished"]"#, "\0").as_ptr().cast()
+//! }
+//!
+//! #[no_mangle]
+//! pub extern "C" fn nodle_plugin_on_event(event: *const std::os::raw::c_char) {
+//!     // {"event": "cook_finished", "node_id": 4, "node_type": "3D_Cube", ...}
+//! }
+//! ```
+//!
+//! Without the subscription list a handler receives every event. Events are
+//! queued as they happen and delivered in order on the UI thread at the end of
+//! the frame, one NUL-terminated JSON object per call; the string is only valid
+//! during the call. Nothing is queued while no loaded plugin has a handler.
+
+use std::collections::{HashMap, HashSet};
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use crate::nodes::{NodeGraph, NodeId};
+
+/// Name of the optional symbol returning the events a plugin subscribes to
+pub const SUBSCRIPTIONS_SYMBOL: &[u8] = b"nodle_plugin_event_subscriptions";
+
+/// Name of the optional symbol receiving events
+pub const HANDLER_SYMBOL: &[u8] = b"nodle_plugin_on_event";
+
+/// Events kept for delivery before the oldest are dropped
+const MAX_PENDING_EVENTS: usize = 10_000;
+
+/// Signature of a plugin's event handler
+pub type EventHandler = unsafe extern "C" fn(*const c_char);
+
+/// Kinds of event a plugin can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphEventKind {
+    NodeAdded,
+    NodeRemoved,
+    /// Connections added or removed
+    ConnectionChanged,
+    CookStarted,
+    CookFinished,
+    SelectionChanged,
+}
+
+/// Something that happened to the graph
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum GraphEvent {
+    NodeAdded { node_id: NodeId, node_type: String },
+    NodeRemoved { node_id: NodeId, node_type: String },
+    ConnectionAdded { from_node: NodeId, from_port: usize, to_node: NodeId, to_port: usize },
+    ConnectionRemoved { from_node: NodeId, from_port: usize, to_node: NodeId, to_port: usize },
+    CookStarted { node_id: NodeId, node_type: String },
+    CookFinished { node_id: NodeId, node_type: String, success: bool, duration_ms: f64 },
+    SelectionChanged { selected: Vec<NodeId> },
+}
+
+impl GraphEvent {
+    pub fn kind(&self) -> GraphEventKind {
+        match self {
+            GraphEvent::NodeAdded { .. } => GraphEventKind::NodeAdded,
+            GraphEvent::NodeRemoved { .. } => GraphEventKind::NodeRemoved,
+            GraphEvent::ConnectionAdded { .. } | GraphEvent::ConnectionRemoved { .. } => GraphEventKind::ConnectionChanged,
+            GraphEvent::CookStarted { .. } => GraphEventKind::CookStarted,
+            GraphEvent::CookFinished { .. } => GraphEventKind::CookFinished,
+            GraphEvent::SelectionChanged { .. } => GraphEventKind::SelectionChanged,
+        }
+    }
+}
+
+/// A plugin's event handler and the events it wants
+#[derive(Debug, Clone)]
+pub struct EventSubscription {
+    /// None subscribes to every event
+    kinds: Option<Vec<GraphEventKind>>,
+    handler: EventHandler,
+}
+
+impl EventSubscription {
+    pub fn new(kinds: Option<Vec<GraphEventKind>>, handler: EventHandler) -> Self {
+        Self { kinds, handler }
+    }
+
+    pub fn wants(&self, kind: GraphEventKind) -> bool {
+        self.kinds.as_ref().map_or(true, |kinds| kinds.contains(&kind))
+    }
+
+    /// Call the handler with `event` if subscribed to it
+    ///
+    /// # Safety
+    /// The library providing the handler must still be loaded.
+    pub unsafe fn deliver(&self, event: &GraphEvent) {
+        if !self.wants(event.kind()) {
+            return;
+        }
+        let Ok(json) = serde_json::to_string(event) else {
+            return;
+        };
+        if let Ok(json) = CString::new(json) {
+            (self.handler)(json.as_ptr());
+        }
+    }
+}
+
+/// Parse the JSON array returned by a plugin's subscription symbol
+pub fn parse_subscriptions(json: &str) -> Result<Vec<GraphEventKind>, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid event subscriptions: {}", e))
+}
+
+static LISTENING: AtomicBool = AtomicBool::new(false);
+static PENDING_EVENTS: Lazy<Mutex<Vec<GraphEvent>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Whether any loaded plugin handles events
+pub fn is_listening() -> bool {
+    LISTENING.load(Ordering::Relaxed)
+}
+
+/// Start or stop queueing events; stopping drops the ones not yet delivered
+pub fn set_listening(listening: bool) {
+    LISTENING.store(listening, Ordering::Relaxed);
+    if !listening {
+        take_events();
+    }
+}
+
+/// Queue `event` for delivery at the end of the frame
+pub fn emit(event: GraphEvent) {
+    if !is_listening() {
+        return;
+    }
+    if let Ok(mut pending) = PENDING_EVENTS.lock() {
+        if pending.len() >= MAX_PENDING_EVENTS {
+            pending.remove(0);
+        }
+        pending.push(event);
+    }
+}
+
+/// Take the queued events, oldest first
+pub fn take_events() -> Vec<GraphEvent> {
+    PENDING_EVENTS.lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default()
+}
+
+/// Remembers the viewed graph and selection between frames and emits what changed
+#[derive(Default)]
+pub struct GraphEventTracker {
+    /// Graph the state below belongs to (None for the root graph)
+    scope: Option<Option<NodeId>>,
+    nodes: HashMap<NodeId, String>,
+    connections: HashSet<(NodeId, usize, NodeId, usize)>,
+    selection: Vec<NodeId>,
+}
+
+impl GraphEventTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the remembered state, so the next update starts over without emitting anything
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Compare `graph` and `selection` with the last frame and emit the differences.
+    /// Switching to another graph (`scope`) starts over without emitting anything.
+    pub fn update(&mut self, scope: Option<NodeId>, graph: &NodeGraph, selection: &HashSet<NodeId>) {
+        let nodes: HashMap<NodeId, String> = graph.nodes.iter()
+            .map(|(id, node)| (*id, node.type_id.clone()))
+            .collect();
+        let connections: HashSet<(NodeId, usize, NodeId, usize)> = graph.connections.iter()
+            .map(|c| (c.from_node, c.from_port, c.to_node, c.to_port))
+            .collect();
+        let mut selected: Vec<NodeId> = selection.iter().copied().collect();
+        selected.sort_unstable();
+
+        if self.scope == Some(scope) {
+            let mut added: Vec<_> = nodes.iter().filter(|(id, _)| !self.nodes.contains_key(id)).collect();
+            added.sort_by_key(|(id, _)| **id);
+            for (node_id, node_type) in added {
+                emit(GraphEvent::NodeAdded { node_id: *node_id, node_type: node_type.clone() });
+            }
+            let mut removed: Vec<_> = self.nodes.iter().filter(|(id, _)| !nodes.contains_key(id)).collect();
+            removed.sort_by_key(|(id, _)| **id);
+            for (node_id, node_type) in removed {
+                emit(GraphEvent::NodeRemoved { node_id: *node_id, node_type: node_type.clone() });
+            }
+            for &(from_node, from_port, to_node, to_port) in connections.difference(&self.connections) {
+                emit(GraphEvent::ConnectionAdded { from_node, from_port, to_node, to_port });
+            }
+            for &(from_node, from_port, to_node, to_port) in self.connections.difference(&connections) {
+                emit(GraphEvent::ConnectionRemoved { from_node, from_port, to_node, to_port });
+            }
+            if selected != self.selection {
+                emit(GraphEvent::SelectionChanged { selected: selected.clone() });
+            }
+        }
+
+        self.scope = Some(scope);
+        self.nodes = nodes;
+        self.connections = connections;
+        self.selection = selected;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_serialize_with_their_kind_and_subscriptions_parse() {
+        let event = GraphEvent::CookFinished { node_id: 4, node_type: "3D_Cube".to_string(), success: true, duration_ms: 1.5 };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], "cook_finished");
+        assert_eq!(json["node_type"], "3D_Cube");
+        assert_eq!(event.kind(), GraphEventKind::CookFinished);
+
+        let kinds = parse_subscriptions(r#"["node_added", "connection_changed"]"#).unwrap();
+        assert_eq!(kinds, vec![GraphEventKind::NodeAdded, GraphEventKind::ConnectionChanged]);
+        assert!(parse_subscriptions(r#"["node_renamed"]"#).is_err());
+
+        extern "C" fn ignore(_event: *const c_char) {}
+        let subscription = EventSubscription::new(Some(kinds), ignore);
+        assert!(subscription.wants(GraphEventKind::ConnectionChanged));
+        assert!(!subscription.wants(GraphEventKind::CookStarted));
+        assert!(EventSubscription::new(None, ignore).wants(GraphEventKind::CookStarted));
+    }
+}
@@ -0,0 +1,265 @@
+//! Shared access to the plugin system
+//!
+//! The plugin manager is only touched by the [`PluginHost`] itself. Everything
+//! else reads an immutable [`PluginSnapshot`], which is swapped for a new one
+//! whenever the set of loaded plugins changes, and asks for changes through
+//! [`PluginCommand`]s. Commands can be queued from anywhere and are applied by
+//! the editor on the UI thread at a point where no plugin code is running.
+//!
+//! Plugin node instances that live outside the graph (viewport nodes) are kept
+//! behind one lock each, so drawing one viewport never waits on another node or
+//! on the manager. The manager lock never escapes this module, so holding a
+//! snapshot or an instance can not deadlock against it.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock, TryLockError};
+use nodle_plugin_sdk::{NodeRegistryTrait, PluginError, PluginInfo, PluginNode};
+use crate::nodes::NodeId;
+use crate::workspace::WorkspaceMenuItem;
+use super::{PluginManager, workspace::WorkspaceDescriptor};
+
+/// A loaded plugin as seen in a snapshot
+#[derive(Clone)]
+pub struct PluginSummary {
+    pub info: PluginInfo,
+    /// Library file the plugin was loaded from
+    pub source_path: PathBuf,
+    /// Node types the plugin provides
+    pub node_types: Vec<String>,
+}
+
+/// Read-only view of the loaded plugins at one point in time
+#[derive(Clone, Default)]
+pub struct PluginSnapshot {
+    /// Incremented for every change to the loaded plugins
+    pub generation: u64,
+    pub plugins: Vec<PluginSummary>,
+    /// Menu entries of all plugin nodes
+    pub menu_items: Vec<WorkspaceMenuItem>,
+    /// Workspaces provided by plugins
+    pub workspaces: Vec<WorkspaceDescriptor>,
+}
+
+impl PluginSnapshot {
+    fn capture(manager: &PluginManager, generation: u64) -> Self {
+        let mut plugins: Vec<PluginSummary> = manager.loaded_plugins.iter()
+            .map(|(name, loaded)| PluginSummary {
+                info: loaded.info.clone(),
+                source_path: loaded.source_path.clone(),
+                node_types: manager.plugin_node_types(name),
+            })
+            .collect();
+        plugins.sort_by(|a, b| a.info.name.cmp(&b.info.name));
+        Self {
+            generation,
+            plugins,
+            menu_items: manager.get_workspace_menu_items(),
+            workspaces: manager.get_workspace_descriptors(),
+        }
+    }
+
+    /// The loaded plugin named `name`
+    pub fn plugin(&self, name: &str) -> Option<&PluginSummary> {
+        self.plugins.iter().find(|plugin| plugin.info.name == name)
+    }
+
+    /// The loaded plugin whose library is `path`
+    pub fn plugin_for_path(&self, path: &Path) -> Option<&PluginSummary> {
+        self.plugins.iter().find(|plugin| plugin.source_path == path)
+    }
+
+    /// The loaded plugin providing nodes of `node_type`
+    pub fn plugin_for_node_type(&self, node_type: &str) -> Option<&PluginSummary> {
+        self.plugins.iter().find(|plugin| plugin.node_types.iter().any(|provided| provided == node_type))
+    }
+}
+
+/// A change to the loaded plugins
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginCommand {
+    /// Load a new plugin library
+    Load(PathBuf),
+    /// Replace the plugin loaded from this library with the build on disk
+    Reload(PathBuf),
+    /// Unload the named plugin and keep it from loading again
+    Disable(String),
+}
+
+/// A plugin node instance, locked separately from every other instance
+pub type SharedPluginNode = Arc<Mutex<Box<dyn PluginNode>>>;
+
+/// Owner of the plugin manager and of plugin node instances kept outside the graph
+pub struct PluginHost {
+    manager: Mutex<PluginManager>,
+    snapshot: RwLock<Arc<PluginSnapshot>>,
+    commands: Mutex<Vec<PluginCommand>>,
+    instances: RwLock<HashMap<NodeId, SharedPluginNode>>,
+}
+
+impl PluginHost {
+    pub fn new(manager: PluginManager) -> Self {
+        let snapshot = PluginSnapshot::capture(&manager, 0);
+        super::events::set_listening(manager.has_event_handlers());
+        Self {
+            manager: Mutex::new(manager),
+            snapshot: RwLock::new(Arc::new(snapshot)),
+            commands: Mutex::new(Vec::new()),
+            instances: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Current view of the loaded plugins. Cheap; the snapshot stays valid (but
+    /// may become stale) while held.
+    pub fn snapshot(&self) -> Arc<PluginSnapshot> {
+        match self.snapshot.read() {
+            Ok(snapshot) => snapshot.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Queue a change, to be applied by the editor at the end of the current frame
+    pub fn submit(&self, command: PluginCommand) {
+        if let Ok(mut commands) = self.commands.lock() {
+            if !commands.contains(&command) {
+                commands.push(command);
+            }
+        }
+    }
+
+    /// Take the queued changes, oldest first
+    pub fn take_commands(&self) -> Vec<PluginCommand> {
+        self.commands.lock()
+            .map(|mut commands| std::mem::take(&mut *commands))
+            .unwrap_or_default()
+    }
+
+    /// Apply a change now and publish the new snapshot. Returns the plugin that was
+    /// loaded, if any.
+    ///
+    /// Reloading or disabling unloads library code, so every node instance and
+    /// factory created by that plugin must be dropped first.
+    pub fn apply(&self, command: &PluginCommand) -> Result<Option<PluginInfo>, PluginError> {
+        let mut manager = self.lock_manager();
+        let result = match command {
+            PluginCommand::Load(path) => manager.load_plugin(path).map(Some),
+            PluginCommand::Reload(path) => manager.reload_plugin(path).map(Some),
+            PluginCommand::Disable(name) => manager.disable_plugin(name).map(|_| None),
+        };
+        let generation = self.snapshot().generation + 1;
+        let snapshot = Arc::new(PluginSnapshot::capture(&manager, generation));
+        super::events::set_listening(manager.has_event_handlers());
+        drop(manager);
+        match self.snapshot.write() {
+            Ok(mut current) => *current = snapshot,
+            Err(poisoned) => *poisoned.into_inner() = snapshot,
+        }
+        result
+    }
+
+    /// Hand the graph events queued since the last call to the plugins subscribed
+    /// to them. Must not be called from plugin code.
+    pub fn deliver_events(&self) {
+        let events = super::events::take_events();
+        if events.is_empty() {
+            return;
+        }
+        let manager = self.lock_manager();
+        for event in &events {
+            manager.deliver_event(event);
+        }
+    }
+    
+    /// Run a read-only query against the manager, or return None instead of
+    /// waiting if a change is being applied
+    pub fn query<T>(&self, f: impl FnOnce(&PluginManager) -> T) -> Option<T> {
+        match self.manager.try_lock() {
+            Ok(manager) => Some(f(&manager)),
+            Err(TryLockError::Poisoned(poisoned)) => Some(f(&poisoned.into_inner())),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// Register the node factories of every loaded plugin with `registry`
+    pub fn register_plugin_nodes(&self, registry: &mut dyn NodeRegistryTrait) -> Result<(), PluginError> {
+        self.lock_manager().register_plugin_nodes(registry)
+    }
+
+    fn lock_manager(&self) -> std::sync::MutexGuard<'_, PluginManager> {
+        // A panic while the manager was locked leaves it usable; plugin calls are sandboxed
+        self.manager.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Keep a plugin node instance for the node `node_id`
+    pub fn store_instance(&self, node_id: NodeId, instance: Box<dyn PluginNode>) {
+        if let Ok(mut instances) = self.instances.write() {
+            instances.insert(node_id, Arc::new(Mutex::new(instance)));
+        }
+    }
+
+    /// Move the instance kept for `from` to `to`, returning whether there was one
+    pub fn move_instance(&self, from: NodeId, to: NodeId) -> bool {
+        let Ok(mut instances) = self.instances.write() else {
+            return false;
+        };
+        match instances.remove(&from) {
+            Some(instance) => {
+                instances.insert(to, instance);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop the instance kept for `node_id`
+    pub fn remove_instance(&self, node_id: NodeId) {
+        if let Ok(mut instances) = self.instances.write() {
+            instances.remove(&node_id);
+        }
+    }
+
+    /// Whether an instance is kept for `node_id`
+    pub fn has_instance(&self, node_id: NodeId) -> bool {
+        self.instances.read().is_ok_and(|instances| instances.contains_key(&node_id))
+    }
+
+    /// Call `f` with the instance kept for `node_id`. Returns None if there is none,
+    /// or if it is already in use further up the stack (rather than deadlocking).
+    pub fn with_instance<T>(&self, node_id: NodeId, f: impl FnOnce(&mut dyn PluginNode) -> T) -> Option<T> {
+        let instance = self.instances.read().ok()?.get(&node_id)?.clone();
+        let mut guard = match instance.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                log::debug!("Plugin node instance {} is busy", node_id);
+                return None;
+            }
+        };
+        Some(f(guard.as_mut()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_are_queued_once_and_publish_a_new_snapshot() {
+        let host = PluginHost::new(PluginManager::new());
+        assert_eq!(host.snapshot().generation, 0);
+
+        let reload = PluginCommand::Reload(PathBuf::from("plugins/libexample.so"));
+        host.submit(reload.clone());
+        host.submit(PluginCommand::Disable("Example".to_string()));
+        host.submit(reload.clone());
+        assert_eq!(host.take_commands(), vec![reload, PluginCommand::Disable("Example".to_string())]);
+        assert!(host.take_commands().is_empty());
+
+        // Failed changes still publish, so readers never see a stale generation after apply
+        let held = host.snapshot();
+        assert!(host.apply(&PluginCommand::Disable("Missing".to_string())).is_err());
+        assert_eq!(host.snapshot().generation, 1);
+        assert_eq!(held.generation, 0);
+        assert!(host.query(|manager| manager.get_loaded_plugins().len()).is_some());
+    }
+}
@@ -1,7 +1,16 @@
 //! Plugin system for dynamic node loading
 
+pub mod data_types;
+pub mod events;
+pub mod host;
+pub mod repository;
+pub mod sandbox;
+pub mod workspace;
+
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
 use libloading::{Library, Symbol};
 use nodle_plugin_sdk::{NodePlugin, PluginInfo, PluginError, NodeRegistryTrait, MenuStructure, PluginHandle, PluginNodeHandle};
 
@@ -11,17 +20,134 @@ use crate::workspace::WorkspaceMenuItem;
 
 /// Loaded plugin wrapper
 struct LoadedPlugin {
-    library: Library,
+    // Declared before `library` so the plugin is dropped while its code is still loaded
     plugin: Box<dyn NodePlugin>,
+    library: Library,
     info: PluginInfo,
+    /// Library file the plugin was loaded from
+    source_path: PathBuf,
+    /// Modification time of `source_path` when it was loaded
+    modified: Option<SystemTime>,
+    /// Private copy that is actually loaded, so the source can be rebuilt while in use
+    shadow_path: PathBuf,
+    /// Workspaces the plugin provides
+    workspaces: Vec<workspace::WorkspaceDescriptor>,
+    /// Graph event handler, if the plugin exports one
+    events: Option<events::EventSubscription>,
+    /// Data types the plugin provides, and its hooks for them
+    data_types: Vec<data_types::DataTypeDescriptor>,
+    data_type_hooks: data_types::DataTypeHooks,
+}
+
+impl LoadedPlugin {
+    /// Make the plugin's data types available; its library stays loaded until `unload`
+    fn register_data_types(&self) {
+        if self.data_types.is_empty() {
+            return;
+        }
+        if let Err(e) = unsafe { data_types::register(&self.info.name, self.data_types.clone(), self.data_type_hooks) } {
+            println!("⚠️  Plugin {} data types ignored: {}", self.info.name, e);
+        }
+    }
+
+    /// Run the plugin's cleanup, unload the library and delete its shadow copy
+    fn unload(self) -> Result<(), PluginError> {
+        // Values of its types are kept, but its hooks go with the library
+        data_types::unregister_plugin(&self.info.name);
+        let result = self.plugin.on_unload()
+            .map_err(|e| PluginError::Other(format!("Plugin cleanup failed: {}", e)));
+        let shadow_path = self.shadow_path.clone();
+        drop(self);
+        let _ = std::fs::remove_file(shadow_path);
+        result
+    }
+}
+
+/// Modification time of a file, None if it cannot be read
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Copy a plugin library to a unique file in the temp directory and return its path.
+/// Loading the copy keeps the original free to be overwritten by a rebuild, and
+/// gives the dynamic loader a new path so it does not hand back the old library.
+fn shadow_copy(path: &Path) -> Result<PathBuf, PluginError> {
+    static SHADOW_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let shadow_dir = std::env::temp_dir().join("nodle-plugins");
+    std::fs::create_dir_all(&shadow_dir)
+        .map_err(|e| PluginError::LoadError(format!("Failed to create {}: {}", shadow_dir.display(), e)))?;
+
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    let extension = path.extension().map(|ext| ext.to_string_lossy().into_owned()).unwrap_or_default();
+    let counter = SHADOW_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let shadow_path = shadow_dir.join(format!("{}-{}-{}.{}", stem, std::process::id(), counter, extension));
+    std::fs::copy(path, &shadow_path)
+        .map_err(|e| PluginError::LoadError(format!("Failed to copy {}: {}", path.display(), e)))?;
+    Ok(shadow_path)
 }
 
-/// Plugin manager for loading and managing external node plugins
+/// Convert a core value to plugin data, for restoring parameters on recreated
+/// plugin node instances. Values of plugin data types become `Any` handles
+/// (see [`data_types::to_handle`]). None for values plugins cannot take.
+pub fn to_plugin_data(value: &crate::nodes::interface::NodeData) -> Option<NodeData> {
+    use crate::nodes::interface::NodeData as CoreData;
+    match value {
+        CoreData::Custom(data) => Some(NodeData::Any(data_types::to_handle(data))),
+        CoreData::Float(v) => Some(NodeData::Float(*v)),
+        CoreData::Integer(v) => Some(NodeData::Integer(*v)),
+        CoreData::Boolean(v) => Some(NodeData::Boolean(*v)),
+        CoreData::String(s) => Some(NodeData::String(s.clone())),
+        CoreData::Any(s) => Some(NodeData::Any(s.clone())),
+        CoreData::Vector3(v) => Some(NodeData::Vector3(*v)),
+        CoreData::Color(c) => Some(NodeData::Color(*c)),
+        _ => None,
+    }
+}
+
+/// Convert plugin data to a core value. `Any` handles of plugin data types
+/// become their values; rich data is only described, as text.
+pub fn from_plugin_data(value: NodeData) -> crate::nodes::interface::NodeData {
+    use crate::nodes::interface::NodeData as CoreData;
+    match value {
+        NodeData::Float(f) => CoreData::Float(f),
+        NodeData::Vector3(v) => CoreData::Vector3(v),
+        NodeData::Color(c) => CoreData::Color([c[0], c[1], c[2], 1.0]),
+        NodeData::String(s) => CoreData::String(s),
+        NodeData::Boolean(b) => CoreData::Boolean(b),
+        NodeData::USDScene(s) => CoreData::String(s), // Convert USD to string
+        // Rich data types - convert to JSON strings for now
+        NodeData::Scene(_) => CoreData::String("[Scene Data]".to_string()),
+        NodeData::Geometry(_) => CoreData::String("[Geometry Data]".to_string()),
+        NodeData::Material(_) => CoreData::String("[Material Data]".to_string()),
+        NodeData::Stage(_) => CoreData::String("[Stage Data]".to_string()),
+        NodeData::USDSceneData(_) => CoreData::String("[USD Scene Data]".to_string()),
+        NodeData::USDScenegraphMetadata(_) => CoreData::String("[USD Metadata]".to_string()),
+        NodeData::Light(_) => CoreData::String("[Light Data]".to_string()),
+        NodeData::Image(_) => CoreData::String("[Image Data]".to_string()),
+        NodeData::Integer(i) => CoreData::Integer(i),
+        NodeData::Any(s) => match data_types::from_handle(&s) {
+            Some(data) => CoreData::Custom(data),
+            None => CoreData::String(s),
+        },
+        NodeData::None => CoreData::String("None".to_string()),
+    }
+}
+
+/// Plugin manager for loading and managing external node plugins. The running
+/// editor only reaches it through [`host::PluginHost`].
 pub struct PluginManager {
     loaded_plugins: HashMap<String, LoadedPlugin>,
     plugin_directories: Vec<PathBuf>,
-    /// Store active plugin node instances for viewport rendering
-    pub plugin_node_instances: HashMap<crate::nodes::NodeId, Box<dyn nodle_plugin_sdk::PluginNode>>,
+    /// Library files that failed to load, with their modification time at the
+    /// time, so they are only retried once they change again
+    failed_loads: HashMap<PathBuf, Option<SystemTime>>,
+    /// Library files disabled after crashing, never loaded again until re-enabled
+    disabled_plugins: Vec<PathBuf>,
+}
+
+/// Where the disabled plugin list is kept (`~/.nodle/disabled_plugins.json`)
+fn disabled_plugins_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".nodle").join("disabled_plugins.json"))
 }
 
 impl PluginManager {
@@ -38,7 +164,11 @@ impl PluginManager {
         Self {
             loaded_plugins: HashMap::new(),
             plugin_directories,
-            plugin_node_instances: HashMap::new(),
+            failed_loads: HashMap::new(),
+            disabled_plugins: disabled_plugins_path()
+                .and_then(|path| std::fs::read_to_string(path).ok())
+                .and_then(|text| serde_json::from_str(&text).ok())
+                .unwrap_or_default(),
         }
     }
     
@@ -59,7 +189,7 @@ impl PluginManager {
                 if let Ok(entries) = std::fs::read_dir(dir) {
                     for entry in entries.flatten() {
                         let path = entry.path();
-                        if self.is_plugin_file(&path) {
+                        if self.is_plugin_file(&path) && !self.is_disabled(&path) {
                             match self.load_plugin(&path) {
                                 Ok(info) => {
                                     println!("✅ Successfully loaded plugin: {}", info.name);
@@ -80,11 +210,39 @@ impl PluginManager {
     
     /// Load a specific plugin from path
     pub fn load_plugin<P: AsRef<Path>>(&mut self, path: P) -> Result<PluginInfo, PluginError> {
-        let path = path.as_ref();
+        let loaded_plugin = self.open_plugin(path.as_ref())?;
+        let info = loaded_plugin.info.clone();
+        loaded_plugin.register_data_types();
+        self.loaded_plugins.insert(info.name.clone(), loaded_plugin);
+        
+        println!("Successfully loaded plugin: {} v{}", info.name, info.version);
         
+        Ok(info)
+    }
+    
+    /// Load and initialize the plugin library at `path` without registering it
+    fn open_plugin(&mut self, path: &Path) -> Result<LoadedPlugin, PluginError> {
+        let modified = modified_time(path);
+        let result = shadow_copy(path).and_then(|shadow_path| {
+            let result = self.open_shadow_copy(path, &shadow_path, modified);
+            if result.is_err() {
+                let _ = std::fs::remove_file(&shadow_path);
+            }
+            result
+        });
+        if result.is_err() {
+            self.failed_loads.insert(path.to_path_buf(), modified);
+        } else {
+            self.failed_loads.remove(path);
+        }
+        result
+    }
+    
+    /// Load the plugin from its shadow copy `shadow_path` of `path`
+    fn open_shadow_copy(&self, path: &Path, shadow_path: &Path, modified: Option<SystemTime>) -> Result<LoadedPlugin, PluginError> {
         // Load the dynamic library
         let library = unsafe {
-            Library::new(path)
+            Library::new(shadow_path)
                 .map_err(|e| PluginError::LoadError(format!("Failed to load library: {}", e)))?
         };
         
@@ -112,28 +270,193 @@ impl PluginManager {
         // Call plugin initialization
         plugin.on_load().map_err(|e| PluginError::InitError(format!("Plugin initialization failed: {}", e)))?;
         
-        // Store the loaded plugin
-        let loaded_plugin = LoadedPlugin {
-            library,
-            plugin,
-            info: info.clone(),
+        // Workspaces are optional; a plugin without the symbol only provides nodes
+        let workspaces = match unsafe { library.get::<unsafe extern "C" fn() -> *const std::os::raw::c_char>(workspace::WORKSPACES_SYMBOL) } {
+            Ok(get_workspaces) => {
+                let json_ptr = unsafe { get_workspaces() };
+                if json_ptr.is_null() {
+                    Vec::new()
+                } else {
+                    let json = unsafe { std::ffi::CStr::from_ptr(json_ptr) }.to_string_lossy();
+                    workspace::parse_workspace_descriptors(&json).unwrap_or_else(|e| {
+                        println!("⚠️  Plugin {} workspaces ignored: {}", info.name, e);
+                        Vec::new()
+                    })
+                }
+            }
+            Err(_) => Vec::new(),
         };
         
-        self.loaded_plugins.insert(info.name.clone(), loaded_plugin);
+        // So is the event handler; without a subscription list it receives every event
+        let events = match unsafe { library.get::<events::EventHandler>(events::HANDLER_SYMBOL) } {
+            Ok(handler) => {
+                let kinds = match unsafe { library.get::<unsafe extern "C" fn() -> *const std::os::raw::c_char>(events::SUBSCRIPTIONS_SYMBOL) } {
+                    Ok(get_subscriptions) => {
+                        let json_ptr = unsafe { get_subscriptions() };
+                        if json_ptr.is_null() {
+                            None
+                        } else {
+                            let json = unsafe { std::ffi::CStr::from_ptr(json_ptr) }.to_string_lossy();
+                            match events::parse_subscriptions(&json) {
+                                Ok(kinds) => Some(kinds),
+                                Err(e) => {
+                                    println!("⚠️  Plugin {} event subscriptions ignored: {}", info.name, e);
+                                    Some(Vec::new())
+                                }
+                            }
+                        }
+                    }
+                    Err(_) => None,
+                };
+                Some(events::EventSubscription::new(kinds, *handler))
+            }
+            Err(_) => None,
+        };
         
-        println!("Successfully loaded plugin: {} v{}", info.name, info.version);
+        // As are data types, each hook of which may be left out
+        let data_types = match unsafe { library.get::<unsafe extern "C" fn() -> *const std::os::raw::c_char>(data_types::DATA_TYPES_SYMBOL) } {
+            Ok(get_data_types) => {
+                let json_ptr = unsafe { get_data_types() };
+                if json_ptr.is_null() {
+                    Vec::new()
+                } else {
+                    let json = unsafe { std::ffi::CStr::from_ptr(json_ptr) }.to_string_lossy();
+                    data_types::parse_data_type_descriptors(&json).unwrap_or_else(|e| {
+                        println!("⚠️  Plugin {} data types ignored: {}", info.name, e);
+                        Vec::new()
+                    })
+                }
+            }
+            Err(_) => Vec::new(),
+        };
+        let data_type_hooks = unsafe {
+            data_types::DataTypeHooks {
+                validate: library.get::<data_types::ValueHook>(data_types::VALIDATE_SYMBOL).ok().map(|hook| *hook),
+                convert: library.get::<data_types::ConvertHook>(data_types::CONVERT_SYMBOL).ok().map(|hook| *hook),
+                serialize: library.get::<data_types::ValueHook>(data_types::SERIALIZE_SYMBOL).ok().map(|hook| *hook),
+                deserialize: library.get::<data_types::ValueHook>(data_types::DESERIALIZE_SYMBOL).ok().map(|hook| *hook),
+            }
+        };
+        
+        Ok(LoadedPlugin {
+            plugin,
+            library,
+            info,
+            source_path: path.to_path_buf(),
+            modified,
+            shadow_path: shadow_path.to_path_buf(),
+            workspaces,
+            events,
+            data_types,
+            data_type_hooks,
+        })
+    }
+    
+    /// Plugin library files that changed on disk since they were loaded, plus new
+    /// ones in the plugin directories. Files that failed to load are skipped until
+    /// they change again.
+    pub fn changed_plugin_files(&self) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self.loaded_plugins.values()
+            .filter(|loaded| loaded.source_path.exists() && modified_time(&loaded.source_path) != loaded.modified)
+            .map(|loaded| loaded.source_path.clone())
+            .collect();
         
+        for dir in &self.plugin_directories {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for path in entries.flatten().map(|entry| entry.path()) {
+                let is_loaded = self.loaded_plugins.values().any(|loaded| loaded.source_path == path);
+                if self.is_plugin_file(&path) && !is_loaded && !self.is_disabled(&path) && !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        }
+        
+        changed.retain(|path| self.failed_loads.get(path).map_or(true, |failed| *failed != modified_time(path)));
+        changed
+    }
+    
+    /// Name of the loaded plugin whose library is `path`
+    pub fn plugin_name_for_path(&self, path: &Path) -> Option<String> {
+        self.loaded_plugins.values()
+            .find(|loaded| loaded.source_path == path)
+            .map(|loaded| loaded.info.name.clone())
+    }
+    
+    /// Whether the library at `path` was disabled
+    pub fn is_disabled(&self, path: &Path) -> bool {
+        self.disabled_plugins.iter().any(|disabled| disabled == path)
+    }
+    
+    /// Unload the plugin `name` and keep it from being loaded again, also in later
+    /// sessions. Like reloading, this requires its node instances to be dropped first.
+    pub fn disable_plugin(&mut self, name: &str) -> Result<(), PluginError> {
+        let path = self.loaded_plugins.get(name)
+            .map(|loaded| loaded.source_path.clone())
+            .ok_or_else(|| PluginError::Other(format!("Plugin '{}' not found", name)))?;
+        if !self.is_disabled(&path) {
+            self.disabled_plugins.push(path);
+        }
+        if let Some(disabled_path) = disabled_plugins_path() {
+            let json = serde_json::to_string_pretty(&self.disabled_plugins).map_err(|e| PluginError::Other(e.to_string()))?;
+            if let Some(parent) = disabled_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Err(e) = std::fs::write(&disabled_path, json) {
+                println!("⚠️  Failed to save disabled plugins to {}: {}", disabled_path.display(), e);
+            }
+        }
+        self.unload_plugin(name)
+    }
+    
+    /// Node types provided by the loaded plugin `name`
+    pub fn plugin_node_types(&self, name: &str) -> Vec<String> {
+        fn collect(menu: &MenuStructure, types: &mut Vec<String>) {
+            match menu {
+                MenuStructure::Category { items, .. } => items.iter().for_each(|item| collect(item, types)),
+                MenuStructure::Node { node_type, .. } => types.push(node_type.clone()),
+            }
+        }
+        let mut types = Vec::new();
+        if let Some(loaded) = self.loaded_plugins.get(name) {
+            for menu in loaded.plugin.get_menu_structure() {
+                collect(&menu, &mut types);
+            }
+        }
+        types
+    }
+    
+    /// Replace the plugin loaded from `path` with the library currently on disk, or
+    /// load it if it is new. The old library is kept if the new one fails to load.
+    ///
+    /// Every node instance and factory created by the old library must be dropped
+    /// before calling this, since its code is unloaded.
+    pub fn reload_plugin(&mut self, path: &Path) -> Result<PluginInfo, PluginError> {
+        let loaded_plugin = self.open_plugin(path)?;
+        let info = loaded_plugin.info.clone();
+        
+        if let Some(old_name) = self.plugin_name_for_path(path) {
+            if let Some(old) = self.loaded_plugins.remove(&old_name) {
+                if let Err(e) = old.unload() {
+                    println!("⚠️  Plugin {} cleanup failed during reload: {}", old_name, e);
+                }
+            }
+        }
+        // Registered after the old library's types are gone, as they usually share IDs
+        loaded_plugin.register_data_types();
+        self.loaded_plugins.insert(info.name.clone(), loaded_plugin);
+        
+        println!("🔄 Reloaded plugin: {} v{}", info.name, info.version);
         Ok(info)
     }
     
     /// Unload a plugin by name
     pub fn unload_plugin(&mut self, name: &str) -> Result<(), PluginError> {
         if let Some(loaded_plugin) = self.loaded_plugins.remove(name) {
-            // Call plugin cleanup
-            loaded_plugin.plugin.on_unload()
-                .map_err(|e| PluginError::Other(format!("Plugin cleanup failed: {}", e)))?;
+            // Call plugin cleanup; the library is unloaded either way
+            loaded_plugin.unload()?;
             
-            // Library will be dropped automatically
             println!("Unloaded plugin: {}", name);
             Ok(())
         } else {
@@ -141,6 +464,21 @@ impl PluginManager {
         }
     }
     
+    /// Whether any loaded plugin handles graph events
+    pub fn has_event_handlers(&self) -> bool {
+        self.loaded_plugins.values().any(|loaded| loaded.events.is_some())
+    }
+    
+    /// Hand `event` to every loaded plugin subscribed to it
+    pub fn deliver_event(&self, event: &events::GraphEvent) {
+        for loaded_plugin in self.loaded_plugins.values() {
+            if let Some(subscription) = &loaded_plugin.events {
+                // The handler lives in the plugin's library, which stays loaded while it is in the map
+                unsafe { subscription.deliver(event) };
+            }
+        }
+    }
+    
     /// Register all plugin nodes with a registry
     pub fn register_plugin_nodes(&self, registry: &mut dyn NodeRegistryTrait) -> Result<(), PluginError> {
         for loaded_plugin in self.loaded_plugins.values() {
@@ -154,6 +492,13 @@ impl PluginManager {
         self.loaded_plugins.values().map(|p| &p.info).collect()
     }
     
+    /// Workspaces provided by all loaded plugins
+    pub fn get_workspace_descriptors(&self) -> Vec<workspace::WorkspaceDescriptor> {
+        self.loaded_plugins.values()
+            .flat_map(|loaded| loaded.workspaces.iter().cloned())
+            .collect()
+    }
+    
     /// Get menu structures from all loaded plugins
     pub fn get_plugin_menu_structures(&self) -> Vec<MenuStructure> {
         let mut menu_structures = Vec::new();
@@ -211,26 +556,6 @@ impl PluginManager {
         plugin_version.starts_with("0.1")
     }
     
-    /// Store a plugin node instance for viewport rendering
-    pub fn store_plugin_node_instance(&mut self, node_id: crate::nodes::NodeId, plugin_node: Box<dyn nodle_plugin_sdk::PluginNode>) {
-        self.plugin_node_instances.insert(node_id, plugin_node);
-    }
-    
-    /// Get a plugin node instance for viewport rendering
-    pub fn get_plugin_node_for_rendering(&mut self, node_id: crate::nodes::NodeId, _node_title: &str) -> Option<&mut Box<dyn nodle_plugin_sdk::PluginNode>> {
-        // Check if we have a stored instance for this node
-        if let Some(plugin_node) = self.plugin_node_instances.get_mut(&node_id) {
-            // Verify it supports viewport rendering
-            if plugin_node.supports_viewport() {
-                return Some(plugin_node);
-            }
-        }
-        
-        // For now, we only support nodes that were created and stored during node creation
-        // Future enhancement: create instances on-demand
-        None
-    }
-    
     /// Helper to find viewport node type in menu structure
     fn find_viewport_node_type_in_menu(&self, menu_item: &MenuStructure) -> Option<String> {
         match menu_item {
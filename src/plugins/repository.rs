@@ -0,0 +1,232 @@
+//! Remote plugin repository
+//!
+//! A plugin repository is a JSON index served over HTTPS listing downloadable
+//! plugin libraries with their SHA-256 checksums. Downloads are verified before
+//! they are written to `~/.nodle/plugins`, after which the plugin manager can
+//! load them without restarting the editor.
+//!
+//! The checksum comes from the same index as the download URL, so it only
+//! catches corrupted or truncated downloads. It does not protect against a
+//! tampered repository: whoever can change a library can change its checksum
+//! too, so only point the editor at repositories you trust.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Largest plugin download accepted (bytes)
+const MAX_PLUGIN_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Plugin index document served by a repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginIndex {
+    #[serde(default)]
+    pub plugins: Vec<PluginIndexEntry>,
+}
+
+/// One downloadable plugin build
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginIndexEntry {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    /// HTTPS download URL of the plugin library
+    pub url: String,
+    /// Hex-encoded SHA-256 of the library
+    pub sha256: String,
+    /// Target OS as in `std::env::consts::OS` ("linux", "macos", "windows"); None = any
+    #[serde(default)]
+    pub platform: Option<String>,
+}
+
+impl PluginIndexEntry {
+    /// Whether this build runs on the current OS
+    pub fn is_compatible(&self) -> bool {
+        match &self.platform {
+            Some(platform) => platform.eq_ignore_ascii_case(std::env::consts::OS),
+            None => true,
+        }
+    }
+
+    /// File name to install the library under (last URL path segment). Only a
+    /// plain file name is accepted, so the download can not land outside the
+    /// plugin directory on any OS.
+    pub fn file_name(&self) -> Result<String, String> {
+        let name = self.url
+            .split(['?', '#'])
+            .next()
+            .and_then(|url| url.rsplit('/').next())
+            .filter(|name| !name.contains(['/', '\\', ':', '\0']))
+            .filter(|name| Path::new(name).file_name() == Some(std::ffi::OsStr::new(name)))
+            .ok_or_else(|| format!("Cannot determine a file name from {}", self.url))?;
+        Ok(name.to_string())
+    }
+}
+
+/// Repository settings, persisted in `~/.nodle/plugin_repository.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepositoryConfig {
+    /// HTTPS URL of the plugin index
+    #[serde(default)]
+    pub index_url: String,
+}
+
+impl RepositoryConfig {
+    fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".nodle").join("plugin_repository.json"))
+    }
+
+    /// Load the saved settings (defaults if missing or unreadable)
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the settings
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path().ok_or_else(|| "No home directory".to_string())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+/// Directory downloaded plugins are installed into (`~/.nodle/plugins`)
+pub fn default_install_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".nodle").join("plugins"))
+}
+
+/// Only HTTPS is accepted for remote indexes and downloads
+fn require_https(url: &str) -> Result<(), String> {
+    if url.starts_with("https://") {
+        Ok(())
+    } else {
+        Err(format!("Refusing non-HTTPS URL: {}", url))
+    }
+}
+
+/// Download and parse a plugin index
+pub fn fetch_index(url: &str) -> Result<PluginIndex, String> {
+    require_https(url)?;
+    let body = ureq::get(url)
+        .call()
+        .map_err(|e| format!("Failed to fetch plugin index: {}", e))?
+        .into_string()
+        .map_err(|e| format!("Failed to read plugin index: {}", e))?;
+    parse_index(&body)
+}
+
+/// Parse a plugin index document
+pub fn parse_index(json: &str) -> Result<PluginIndex, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid plugin index: {}", e))
+}
+
+/// Check data against a hex-encoded SHA-256 checksum
+pub fn verify_checksum(data: &[u8], expected_hex: &str) -> Result<(), String> {
+    let actual: String = Sha256::digest(data).iter().map(|byte| format!("{:02x}", byte)).collect();
+    if actual.eq_ignore_ascii_case(expected_hex.trim()) {
+        Ok(())
+    } else {
+        Err(format!("Checksum mismatch: expected {}, got {}", expected_hex.trim(), actual))
+    }
+}
+
+/// Read all of `reader`, failing rather than truncating once it exceeds `limit` bytes
+fn read_limited(reader: impl Read, limit: u64) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    reader.take(limit + 1)
+        .read_to_end(&mut data)
+        .map_err(|e| e.to_string())?;
+    if data.len() as u64 > limit {
+        return Err(format!("larger than the {} byte limit", limit));
+    }
+    Ok(data)
+}
+
+/// Download a plugin, verify its checksum and install it into `install_dir`.
+/// Returns the installed library path, ready for `PluginManager::load_plugin`.
+pub fn download_plugin(entry: &PluginIndexEntry, install_dir: &Path) -> Result<PathBuf, String> {
+    require_https(&entry.url)?;
+    if !entry.is_compatible() {
+        return Err(format!("{} is not built for {}", entry.name, std::env::consts::OS));
+    }
+    let file_name = entry.file_name()?;
+
+    let reader = ureq::get(&entry.url)
+        .call()
+        .map_err(|e| format!("Failed to download {}: {}", entry.name, e))?
+        .into_reader();
+    let data = read_limited(reader, MAX_PLUGIN_SIZE)
+        .map_err(|e| format!("Failed to download {}: {}", entry.name, e))?;
+    verify_checksum(&data, &entry.sha256)?;
+
+    std::fs::create_dir_all(install_dir)
+        .map_err(|e| format!("Failed to create {}: {}", install_dir.display(), e))?;
+    // Write next to the target then rename, so a failed write never leaves a half library behind
+    let path = install_dir.join(&file_name);
+    let partial = install_dir.join(format!("{}.part", file_name));
+    std::fs::write(&partial, &data)
+        .map_err(|e| format!("Failed to write {}: {}", partial.display(), e))?;
+    std::fs::rename(&partial, &path)
+        .map_err(|e| format!("Failed to install {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_index_and_file_name() {
+        let index = parse_index(r#"{"plugins": [{
+            "name": "USD Tools", "version": "1.2.0",
+            "url": "https://example.com/builds/libusd_tools.so?token=1",
+            "sha256": "00", "platform": "linux"
+        }]}"#).unwrap();
+        assert_eq!(index.plugins.len(), 1);
+        assert_eq!(index.plugins[0].file_name().unwrap(), "libusd_tools.so");
+        assert_eq!(index.plugins[0].description, "");
+    }
+
+    #[test]
+    fn test_file_names_must_be_plain() {
+        let entry = |url: &str| PluginIndexEntry {
+            name: "Tools".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            url: url.to_string(),
+            sha256: "00".to_string(),
+            platform: None,
+        };
+        assert!(entry(r"https://example.com/builds/..\..\evil.dll").file_name().is_err());
+        assert!(entry("https://example.com/builds/C:evil.dll").file_name().is_err());
+        assert!(entry("https://example.com/builds/..").file_name().is_err());
+        assert!(entry("https://example.com/builds/").file_name().is_err());
+        assert!(entry("https://example.com/builds/lib\0tools.so").file_name().is_err());
+    }
+
+    #[test]
+    fn test_read_limited_rejects_oversized_data() {
+        assert_eq!(read_limited(&[1u8, 2, 3][..], 3).unwrap(), vec![1, 2, 3]);
+        assert!(read_limited(&[1u8, 2, 3, 4][..], 3).is_err());
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        // SHA-256 of "abc"
+        let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert!(verify_checksum(b"abc", expected).is_ok());
+        assert!(verify_checksum(b"abd", expected).is_err());
+    }
+
+    #[test]
+    fn test_non_https_urls_are_rejected() {
+        assert!(fetch_index("http://example.com/index.json").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+//! Crash isolation for plugin code
+//!
+//! Calls into plugin nodes go through [`guard`], which catches panics so a
+//! misbehaving plugin cannot take down the editor. A node that panicked is
+//! marked as crashed and not called again until it is reset, and the crash is
+//! queued for the editor to report (offering to disable the plugin).
+//!
+//! Panics are only caught when they unwind back into the editor; a plugin that
+//! aborts the process or corrupts memory still cannot be contained in-process.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Mutex;
+use crate::nodes::NodeId;
+
+/// A panic caught while calling into a plugin node
+#[derive(Debug, Clone)]
+pub struct PluginCrash {
+    pub node_id: NodeId,
+    pub node_type: String,
+    pub node_title: String,
+    /// What the editor was asking the node to do ("draw parameters", "render viewport")
+    pub operation: String,
+    pub message: String,
+}
+
+/// Crashes not yet reported by the editor
+static PENDING_CRASHES: Mutex<Vec<PluginCrash>> = Mutex::new(Vec::new());
+
+/// Nodes that crashed and are no longer called
+static CRASHED_NODES: Mutex<Option<HashSet<NodeId>>> = Mutex::new(None);
+
+/// Text of a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Run `f`, which calls into the plugin node `node_id`, catching any panic.
+/// Returns None if the node panicked now or crashed earlier.
+pub fn guard<T>(node_id: NodeId, node_type: &str, node_title: &str, operation: &str, f: impl FnOnce() -> T) -> Option<T> {
+    if is_crashed(node_id) {
+        return None;
+    }
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Some(value),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            log::error!("Plugin node {} ({}) panicked during {}: {}", node_title, node_type, operation, message);
+            if let Ok(mut crashed) = CRASHED_NODES.lock() {
+                crashed.get_or_insert_with(HashSet::new).insert(node_id);
+            }
+            if let Ok(mut pending) = PENDING_CRASHES.lock() {
+                pending.push(PluginCrash {
+                    node_id,
+                    node_type: node_type.to_string(),
+                    node_title: node_title.to_string(),
+                    operation: operation.to_string(),
+                    message,
+                });
+            }
+            None
+        }
+    }
+}
+
+/// Run `f`, which calls plugin code outside of any node, catching any panic.
+/// Returns None if it panicked.
+pub fn guard_call<T>(plugin: &str, operation: &str, f: impl FnOnce() -> T) -> Option<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Some(value),
+        Err(payload) => {
+            log::error!("Plugin {} panicked during {}: {}", plugin, operation, panic_message(payload.as_ref()));
+            None
+        }
+    }
+}
+
+/// Whether `node_id` crashed and is no longer called
+pub fn is_crashed(node_id: NodeId) -> bool {
+    CRASHED_NODES.lock()
+        .map(|crashed| crashed.as_ref().is_some_and(|crashed| crashed.contains(&node_id)))
+        .unwrap_or(false)
+}
+
+/// All nodes currently marked as crashed
+pub fn crashed_nodes() -> HashSet<NodeId> {
+    CRASHED_NODES.lock()
+        .map(|crashed| crashed.clone().unwrap_or_default())
+        .unwrap_or_default()
+}
+
+/// Let a crashed node be called again
+pub fn reset_node(node_id: NodeId) {
+    if let Ok(mut crashed) = CRASHED_NODES.lock() {
+        if let Some(crashed) = crashed.as_mut() {
+            crashed.remove(&node_id);
+        }
+    }
+}
+
+/// Crashes since the last call, for the editor to report
+pub fn take_crashes() -> Vec<PluginCrash> {
+    PENDING_CRASHES.lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_contains_panics_and_stops_calling_the_node() {
+        let node_id = 4_000_000_001;
+        assert_eq!(guard(node_id, "Test_Node", "Test", "cook", || 7), Some(7));
+
+        assert_eq!(guard(node_id, "Test_Node", "Test", "cook", || -> i32 { panic!("bad input") }), None);
+        assert!(is_crashed(node_id));
+        let crash = take_crashes().into_iter().find(|crash| crash.node_id == node_id).unwrap();
+        assert_eq!(crash.message, "bad input");
+
+        // Crashed nodes are skipped until reset
+        assert_eq!(guard(node_id, "Test_Node", "Test", "cook", || 7), None);
+        reset_node(node_id);
+        assert_eq!(guard(node_id, "Test_Node", "Test", "cook", || 7), Some(7));
+    }
+}
@@ -0,0 +1,116 @@
+//! Workspaces provided by plugins
+//!
+//! Besides nodes, a plugin can provide whole workspaces (for example a
+//! compositing or audio workspace) by exporting an optional function:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub extern "C" fn nodle_plugin_workspaces() -> *const std::os::raw::c_char {
+//!     concat!(r#"[{"id": "audio", "display_name": "Audio", ... }]"#, "\0").as_ptr().cast()
+//! }
+//! ```
+//!
+//! The function returns a NUL-terminated JSON array of workspace descriptors
+//! that stays valid while the library is loaded. Each descriptor defines the
+//! workspace's menu, which other nodes it accepts and the panels its nodes open
+//! in. A workspace with a `parent` is entered from inside that workspace instead
+//! of being created at the root.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::nodes::interface::PanelType;
+use crate::workspace::WorkspaceMenuItem;
+
+/// Name of the optional symbol returning a plugin's workspace descriptors
+pub const WORKSPACES_SYMBOL: &[u8] = b"nodle_plugin_workspaces";
+
+/// Menu entry of a plugin workspace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WorkspaceMenuEntry {
+    Category { name: String, items: Vec<WorkspaceMenuEntry> },
+    Node { name: String, node_type: String },
+}
+
+impl WorkspaceMenuEntry {
+    pub fn to_menu_item(&self) -> WorkspaceMenuItem {
+        match self {
+            WorkspaceMenuEntry::Category { name, items } => WorkspaceMenuItem::Category {
+                name: name.clone(),
+                items: items.iter().map(WorkspaceMenuEntry::to_menu_item).collect(),
+            },
+            WorkspaceMenuEntry::Node { name, node_type } => WorkspaceMenuItem::Node {
+                name: name.clone(),
+                node_type: node_type.clone(),
+            },
+        }
+    }
+
+    /// Node types listed under this entry
+    pub fn node_types(&self) -> Vec<&str> {
+        match self {
+            WorkspaceMenuEntry::Category { items, .. } => items.iter().flat_map(WorkspaceMenuEntry::node_types).collect(),
+            WorkspaceMenuEntry::Node { node_type, .. } => vec![node_type.as_str()],
+        }
+    }
+}
+
+/// A workspace as described by a plugin
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceDescriptor {
+    /// Unique workspace id, also used in navigation paths
+    pub id: String,
+    pub display_name: String,
+    /// Workspace this one is nested in, None for a top-level workspace
+    #[serde(default)]
+    pub parent: Option<String>,
+    pub menu: Vec<WorkspaceMenuEntry>,
+    /// Nodes accepted besides those in the menu (core nodes such as "Add" or "Print")
+    #[serde(default)]
+    pub compatible_nodes: Vec<String>,
+    /// Panel each node type opens in, overriding the node's own default
+    #[serde(default)]
+    pub panel_types: HashMap<String, PanelType>,
+}
+
+/// Parse the JSON returned by a plugin's workspace function
+pub fn parse_workspace_descriptors(json: &str) -> Result<Vec<WorkspaceDescriptor>, String> {
+    let descriptors: Vec<WorkspaceDescriptor> = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid workspace descriptors: {}", e))?;
+    for descriptor in &descriptors {
+        if descriptor.id.is_empty() || descriptor.id.contains(['/', ':']) {
+            return Err(format!("Invalid workspace id \"{}\"", descriptor.id));
+        }
+    }
+    Ok(descriptors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_workspace_descriptors() {
+        let json = r#"[{
+            "id": "audio",
+            "display_name": "Audio",
+            "menu": [
+                {"kind": "category", "name": "Sources", "items": [
+                    {"kind": "node", "name": "Oscillator", "node_type": "Audio_Oscillator"}
+                ]},
+                {"kind": "node", "name": "Mixer", "node_type": "Audio_Mixer"}
+            ],
+            "compatible_nodes": ["Add", "Print"],
+            "panel_types": {"Audio_Mixer": "Viewer"}
+        }]"#;
+        let descriptors = parse_workspace_descriptors(json).unwrap();
+        assert_eq!(descriptors.len(), 1);
+        let audio = &descriptors[0];
+        assert_eq!(audio.parent, None);
+        let node_types: Vec<&str> = audio.menu.iter().flat_map(WorkspaceMenuEntry::node_types).collect();
+        assert_eq!(node_types, vec!["Audio_Oscillator", "Audio_Mixer"]);
+        assert_eq!(audio.panel_types["Audio_Mixer"], PanelType::Viewer);
+
+        assert!(parse_workspace_descriptors(r#"[{"id": "a/b", "display_name": "X", "menu": []}]"#).is_err());
+    }
+}
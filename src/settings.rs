@@ -0,0 +1,150 @@
+//! Application settings
+//!
+//! Preferences that outlive a session live in `~/.nodle/config.toml` and are
+//! edited in the Preferences window. Missing keys take their defaults, so the
+//! file only needs the values a user actually changed. Code reads the current
+//! values through [`current`]; [`update`] replaces and saves them.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{LazyLock, RwLock};
+
+/// Execution mode new sessions start in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StartupExecutionMode {
+    Auto,
+    Manual,
+}
+
+/// Settings of newly opened 3D viewports
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewportDefaults {
+    /// RGBA
+    pub background_color: [f32; 4],
+    pub show_grid: bool,
+    /// Vertical field of view in degrees
+    pub field_of_view: f32,
+}
+
+impl Default for ViewportDefaults {
+    fn default() -> Self {
+        Self {
+            background_color: [0.2, 0.2, 0.2, 1.0],
+            show_grid: true,
+            field_of_view: 45.0,
+        }
+    }
+}
+
+/// Everything stored in the config file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Name of the editor theme
+    pub theme: String,
+    /// Minutes between automatic saves of a project that has a file; 0 turns autosave off
+    pub autosave_minutes: u32,
+    pub execution_mode: StartupExecutionMode,
+    /// Connect the selected node to nodes created from the context menu
+    pub auto_wire_new_nodes: bool,
+    /// Draw the node graph on the GPU
+    pub gpu_rendering: bool,
+    /// Searched for plugins in addition to `~/.nodle/plugins` and `./plugins`
+    pub plugin_directories: Vec<PathBuf>,
+    pub viewport: ViewportDefaults,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "dark".to_string(),
+            autosave_minutes: 0,
+            execution_mode: StartupExecutionMode::Auto,
+            auto_wire_new_nodes: true,
+            gpu_rendering: true,
+            plugin_directories: Vec::new(),
+            viewport: ViewportDefaults::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Parse a config file, taking defaults for missing keys
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| format!("Invalid config: {}", e))
+    }
+
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// Load `path`, falling back to the defaults if it is missing or invalid
+    pub fn load(path: Option<&PathBuf>) -> Self {
+        let Some(text) = path.and_then(|path| std::fs::read_to_string(path).ok()) else {
+            return Self::default();
+        };
+        Self::from_toml(&text).unwrap_or_else(|e| {
+            log::warn!("{}; using default settings", e);
+            Self::default()
+        })
+    }
+
+    /// Write the settings to `path`
+    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(path, self.to_toml()?).map_err(|e| e.to_string())
+    }
+}
+
+/// Location of the config file (`~/.nodle/config.toml`)
+pub fn config_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".nodle").join("config.toml"))
+}
+
+static SETTINGS: LazyLock<RwLock<Settings>> = LazyLock::new(|| RwLock::new(Settings::load(config_path().as_ref())));
+
+/// The current settings
+pub fn current() -> Settings {
+    match SETTINGS.read() {
+        Ok(settings) => settings.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Replace the current settings and save them to the config file
+pub fn update(settings: Settings) -> Result<(), String> {
+    let result = config_path().map_or(Ok(()), |path| settings.save(&path));
+    match SETTINGS.write() {
+        Ok(mut current) => *current = settings,
+        Err(poisoned) => *poisoned.into_inner() = settings,
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip_and_fill_in_missing_keys() {
+        let mut settings = Settings::default();
+        settings.autosave_minutes = 5;
+        settings.execution_mode = StartupExecutionMode::Manual;
+        settings.plugin_directories.push(PathBuf::from("/studio/nodle/plugins"));
+        settings.viewport.field_of_view = 60.0;
+        let text = settings.to_toml().unwrap();
+        assert_eq!(Settings::from_toml(&text).unwrap(), settings);
+
+        let partial = Settings::from_toml("autosave_minutes = 10\n[viewport]\nshow_grid = false\n").unwrap();
+        assert_eq!(partial.autosave_minutes, 10);
+        assert!(!partial.viewport.show_grid);
+        assert_eq!(partial.viewport.field_of_view, 45.0);
+        assert_eq!(partial.theme, "dark");
+        assert!(partial.auto_wire_new_nodes);
+        assert!(Settings::from_toml("autosave_minutes = \"often\"").is_err());
+    }
+}
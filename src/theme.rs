@@ -2,10 +2,35 @@
 //!
 //! This module provides a single source of truth for all colors, dimensions,
 //! and styling values used throughout the application.
+//!
+//! Two themes are built in, "dark" and "light". Studios can add their own as
+//! TOML files in `~/.nodle/themes/`; a custom theme starts from one of the
+//! built-in palettes and overrides individual colors, the colors of node
+//! categories and the connection color scheme (`data_type`, the default,
+//! `source_node` or `uniform`):
+//!
+//! ```toml
+//! name = "Studio"
+//! base = "light"
+//! connection_scheme = "source_node"
+//!
+//! [colors]
+//! selection_blue = "#ff8800"
+//!
+//! [categories]
+//! "3D" = "#3a6ea5"
+//! "MaterialX/Shading" = "#7a4fa0"
+//! ```
 
 use egui::{Color32, Vec2};
+use crate::nodes::factory::DataType;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
 
 /// Color palette for the Nōdle editor
+#[derive(Clone)]
 pub struct Colors {
     // Selection and highlighting
     pub selection_blue: Color32,
@@ -58,14 +83,67 @@ impl Colors {
             panel_background: Color32::from_rgb(22, 27, 34),
             
             // Connection colors
-            connection_default: Color32::from_rgb(200, 200, 200),
+            connection_default: Color32::from_rgb(100, 110, 120),
             connection_hover: Color32::from_rgb(255, 255, 255),
-            connection_selected: Color32::from_rgb(100, 150, 255),
+            connection_selected: Color32::from_rgb(88, 166, 255),
         }
     }
+
+    /// Get the light color palette
+    pub fn light() -> Self {
+        Self {
+            // Selection and highlighting
+            selection_blue: Color32::from_rgb(30, 110, 230),
+            hover_highlight: Color32::from_rgb(70, 140, 240),
+
+            // Node colors
+            node_bg_top: Color32::from_rgb(245, 245, 245),
+            node_bg_bottom: Color32::from_rgb(205, 205, 205),
+            node_border: Color32::from_rgb(150, 150, 150),
+            node_bevel_light: Color32::from_rgb(255, 255, 255),
+            node_bevel_dark: Color32::from_rgb(170, 170, 170),
+
+            // Port colors
+            port_input: Color32::from_rgb(60, 150, 100),
+            port_output: Color32::from_rgb(170, 70, 70),
+            port_border: Color32::from_rgb(90, 90, 90),
+
+            // Background colors
+            main_background: Color32::from_rgb(232, 232, 232),
+            panel_background: Color32::from_rgb(218, 222, 228),
+
+            // Connection colors
+            connection_default: Color32::from_rgb(90, 90, 90),
+            connection_hover: Color32::from_rgb(20, 20, 20),
+            connection_selected: Color32::from_rgb(30, 110, 230),
+        }
+    }
+
+    /// The color named `name`, as written in theme files
+    fn color_mut(&mut self, name: &str) -> Option<&mut Color32> {
+        Some(match name {
+            "selection_blue" => &mut self.selection_blue,
+            "hover_highlight" => &mut self.hover_highlight,
+            "node_bg_top" => &mut self.node_bg_top,
+            "node_bg_bottom" => &mut self.node_bg_bottom,
+            "node_border" => &mut self.node_border,
+            "node_bevel_light" => &mut self.node_bevel_light,
+            "node_bevel_dark" => &mut self.node_bevel_dark,
+            "port_input" => &mut self.port_input,
+            "port_output" => &mut self.port_output,
+            "port_border" => &mut self.port_border,
+            "main_background" => &mut self.main_background,
+            "panel_background" => &mut self.panel_background,
+            "connection_default" => &mut self.connection_default,
+            "connection_hover" => &mut self.connection_hover,
+            "connection_selected" => &mut self.connection_selected,
+            _ => return None,
+        })
+    }
 }
 
 /// Dimension constants for the Nōdle editor
+#[derive(Clone)]
 pub struct Dimensions {
     // Node sizes
     pub default_node_size: Vec2,
@@ -114,6 +192,7 @@ impl Dimensions {
 }
 
 /// Animation and timing constants
+#[derive(Clone)]
 pub struct Animation {
     // Zoom factors
     pub zoom_normal: f32,
@@ -144,41 +223,286 @@ impl Animation {
     }
 }
 
+/// How ports and connection wires are colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionScheme {
+    /// Ports and wires take the color of the port's data type
+    DataType,
+    /// Every wire uses `connection_default`
+    Uniform,
+    /// Wires take the color of the node they come from
+    SourceNode,
+}
+
 /// Complete theme containing all styling constants
+#[derive(Clone)]
 pub struct Theme {
+    /// Name shown in the Preferences window
+    pub name: String,
+    /// Whether the editor chrome uses egui's dark or light visuals
+    pub dark: bool,
     pub colors: Colors,
     pub dimensions: Dimensions,
     pub animation: Animation,
+    /// Node body colors by category path ("3D", "MaterialX/Shading"); the most specific match wins
+    pub category_colors: BTreeMap<String, Color32>,
+    pub connection_scheme: ConnectionScheme,
 }
 
 impl Theme {
     /// Get the default theme
     pub fn default() -> Self {
         Self {
+            name: "dark".to_string(),
+            dark: true,
             colors: Colors::default(),
             dimensions: Dimensions::default(),
             animation: Animation::default(),
+            category_colors: BTreeMap::new(),
+            connection_scheme: ConnectionScheme::DataType,
+        }
+    }
+
+    /// Get the light theme
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            dark: false,
+            colors: Colors::light(),
+            ..Self::default()
+        }
+    }
+
+    /// Parse a custom theme file
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let file: ThemeFile = toml::from_str(text).map_err(|e| format!("Invalid theme: {}", e))?;
+        let mut theme = match file.base.as_deref() {
+            None | Some("dark") => Self::default(),
+            Some("light") => Self::light(),
+            Some(other) => return Err(format!("Unknown base theme '{}'", other)),
+        };
+        if let Some(name) = file.name {
+            theme.name = name;
+        }
+        if let Some(scheme) = file.connection_scheme {
+            theme.connection_scheme = scheme;
+        }
+        for (name, value) in &file.colors {
+            let color = parse_color(value)?;
+            *theme.colors.color_mut(name).ok_or_else(|| format!("Unknown theme color '{}'", name))? = color;
+        }
+        for (category, value) in &file.categories {
+            theme.category_colors.insert(category.clone(), parse_color(value)?);
+        }
+        Ok(theme)
+    }
+
+    /// Body color of nodes of `type_id`: the color of its category, if the theme sets one
+    pub fn node_color(&self, type_id: &str) -> Option<Color32> {
+        if self.category_colors.is_empty() {
+            return None;
+        }
+        let catalog = crate::nodes::factory::NodeCatalog::current();
+        let path = &catalog.node(type_id)?.category;
+        (1..=path.len()).rev()
+            .find_map(|depth| self.category_colors.get(&path[..depth].join("/")))
+            .copied()
+    }
+
+    /// Top and bottom of the node body gradient for nodes of `type_id`
+    pub fn node_gradient(&self, type_id: &str) -> (Color32, Color32) {
+        match self.node_color(type_id) {
+            Some(color) => (color, color.linear_multiply(0.5).to_opaque()),
+            None => (self.colors.node_bg_top, self.colors.node_bg_bottom),
+        }
+    }
+
+    /// Fill of port `index` on a node of `type_id`
+    pub fn port_color(&self, type_id: &str, is_input: bool, index: usize) -> Color32 {
+        let fallback = if is_input { self.colors.port_input } else { self.colors.port_output };
+        if self.connection_scheme != ConnectionScheme::DataType {
+            return fallback;
+        }
+        port_data_type(type_id, is_input, index).map_or(fallback, |data_type| data_type.color())
+    }
+
+    /// Color of a wire leaving output `port` of a node of `source_type_id`
+    pub fn connection_color(&self, source_type_id: &str, port: usize) -> Color32 {
+        match self.connection_scheme {
+            ConnectionScheme::DataType => port_data_type(source_type_id, false, port)
+                .map_or(self.colors.connection_default, |data_type| data_type.color()),
+            ConnectionScheme::Uniform => self.colors.connection_default,
+            ConnectionScheme::SourceNode => self.node_color(source_type_id).unwrap_or(self.colors.connection_default),
         }
     }
 }
 
-/// Global theme instance
-static GLOBAL_THEME: std::sync::LazyLock<Theme> = std::sync::LazyLock::new(|| Theme::default());
+/// On-disk form of a custom theme; everything is optional
+#[derive(Deserialize)]
+struct ThemeFile {
+    name: Option<String>,
+    base: Option<String>,
+    connection_scheme: Option<ConnectionScheme>,
+    #[serde(default)]
+    colors: HashMap<String, String>,
+    #[serde(default)]
+    categories: BTreeMap<String, String>,
+}
+
+/// Parse "#rrggbb" or "#rrggbbaa"
+fn parse_color(value: &str) -> Result<Color32, String> {
+    Color32::from_hex(value).map_err(|_| format!("Invalid color '{}', expected #rrggbb", value))
+}
+
+/// Declared data type of port `index` on a node of `type_id`; copies of a
+/// variadic input share its type. None for unregistered types and other
+/// ports added at runtime
+pub fn port_data_type(type_id: &str, is_input: bool, index: usize) -> Option<DataType> {
+    let catalog = crate::nodes::factory::NodeCatalog::current();
+    let info = catalog.node(type_id)?;
+    if is_input && info.variadic && index >= info.inputs.len() {
+        return info.inputs.last().cloned();
+    }
+    let ports = if is_input { &info.inputs } else { &info.outputs };
+    ports.get(index).cloned()
+}
+
+/// Directory of custom theme files (`~/.nodle/themes`)
+pub fn themes_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".nodle").join("themes"))
+}
+
+/// Custom themes in `dir`, sorted by name; files that fail to parse are logged and skipped
+pub fn load_custom_themes(dir: &Path) -> Vec<Theme> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut themes: Vec<Theme> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| {
+            let text = std::fs::read_to_string(&path).ok()?;
+            match Theme::from_toml(&text) {
+                Ok(mut theme) => {
+                    if theme.name == "dark" || theme.name == "light" {
+                        theme.name = path.file_stem()?.to_string_lossy().into_owned();
+                    }
+                    Some(theme)
+                }
+                Err(e) => {
+                    log::warn!("Skipping theme {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+    themes.sort_by(|a, b| a.name.cmp(&b.name));
+    themes
+}
+
+/// Names of the built-in and custom themes
+pub fn available_themes() -> Vec<String> {
+    let mut names = vec!["dark".to_string(), "light".to_string()];
+    if let Some(dir) = themes_dir() {
+        names.extend(load_custom_themes(&dir).into_iter().map(|theme| theme.name));
+    }
+    names
+}
+
+/// Look up a theme by name, falling back to the dark theme
+pub fn find_theme(name: &str) -> Theme {
+    match name {
+        "dark" => Theme::default(),
+        "light" => Theme::light(),
+        _ => themes_dir()
+            .and_then(|dir| load_custom_themes(&dir).into_iter().find(|theme| theme.name == name))
+            .unwrap_or_else(|| {
+                log::warn!("Theme '{}' not found; using the dark theme", name);
+                Theme::default()
+            }),
+    }
+}
+
+/// Make the theme named `name` current and restyle the egui chrome and the
+/// window decorations to match
+pub fn apply(ctx: &egui::Context, name: &str) {
+    let theme = find_theme(name);
+    if theme.dark {
+        ctx.set_visuals(egui::Visuals::dark());
+        ctx.set_theme(egui::Theme::Dark);
+        ctx.send_viewport_cmd(egui::ViewportCommand::SetTheme(egui::SystemTheme::Dark));
+    } else {
+        ctx.set_visuals(egui::Visuals::light());
+        ctx.set_theme(egui::Theme::Light);
+        ctx.send_viewport_cmd(egui::ViewportCommand::SetTheme(egui::SystemTheme::Light));
+    }
+    set_theme(theme);
+}
+
+/// Global theme instance. Readers get a shared handle, so replacing the
+/// theme never invalidates one that is still in use.
+static GLOBAL_THEME: OnceLock<RwLock<Arc<Theme>>> = OnceLock::new();
+
+fn global_theme() -> &'static RwLock<Arc<Theme>> {
+    GLOBAL_THEME.get_or_init(|| RwLock::new(Arc::new(Theme::default())))
+}
+
+/// Replace the global theme
+pub fn set_theme(theme: Theme) {
+    let theme = Arc::new(theme);
+    match global_theme().write() {
+        Ok(mut current) => *current = theme,
+        Err(poisoned) => *poisoned.into_inner() = theme,
+    }
+}
 
 /// Get the global theme
-pub fn theme() -> &'static Theme {
-    &GLOBAL_THEME
+pub fn theme() -> Arc<Theme> {
+    match global_theme().read() {
+        Ok(current) => Arc::clone(&current),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+    }
 }
 
 /// Convenience functions for commonly used values
-pub fn colors() -> &'static Colors {
-    &theme().colors
+pub fn colors() -> Colors {
+    theme().colors.clone()
+}
+
+pub fn dimensions() -> Dimensions {
+    theme().dimensions.clone()
 }
 
-pub fn dimensions() -> &'static Dimensions {
-    &theme().dimensions
+pub fn animation() -> Animation {
+    theme().animation.clone()
 }
 
-pub fn animation() -> &'static Animation {
-    &theme().animation
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_theme_overrides_base_palette() {
+        let theme = Theme::from_toml(r##"
+            name = "Studio"
+            base = "light"
+            connection_scheme = "source_node"
+            [colors]
+            selection_blue = "#ff8800"
+            [categories]
+            "MaterialX/Shading" = "#7a4fa0"
+        "##).unwrap();
+        assert_eq!(theme.name, "Studio");
+        assert!(!theme.dark);
+        assert_eq!(theme.colors.selection_blue, Color32::from_rgb(255, 136, 0));
+        assert_eq!(theme.colors.main_background, Colors::light().main_background);
+        assert_eq!(theme.connection_scheme, ConnectionScheme::SourceNode);
+        assert_eq!(theme.category_colors["MaterialX/Shading"], Color32::from_rgb(122, 79, 160));
+
+        assert!(Theme::from_toml("[colors]\nnot_a_color = \"#000000\"").is_err());
+        assert!(Theme::from_toml("[colors]\nselection_blue = \"blue\"").is_err());
+        assert!(Theme::from_toml("base = \"sepia\"").is_err());
+    }
+}
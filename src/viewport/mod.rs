@@ -4,10 +4,12 @@
 //! independent of the plugin SDK.
 
 pub mod types;
+pub mod selection;
 
 // Re-export commonly used types
 pub use types::{
     CameraData, MeshData, MaterialData, LightData, LightType,
-    SceneData, ViewportSettings, ShadingMode, ViewportData,
+    SceneData, ViewportSettings, ShadingMode, DisplayMode, ViewportData,
+    PointsData, PointDisplay, PointColorMode, LinesData,
     CameraManipulation,
 };
\ No newline at end of file
@@ -0,0 +1,92 @@
+//! Prim selection shared by the viewports and the Scenegraph panel
+//!
+//! Clicking a prim in a viewport or a mesh in the Scenegraph tree selects it
+//! here, so both show the same selection. Viewports highlight the selected
+//! prims and publish them on their Selection output as prim paths separated
+//! by spaces, the format prim pattern parameters accept.
+
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+static PRIM_SELECTION: Lazy<RwLock<PrimSelection>> = Lazy::new(|| RwLock::new(PrimSelection::default()));
+
+/// How a pick changes the selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectMode {
+    /// The picked prim becomes the whole selection; picking nothing clears it
+    Replace,
+    /// The picked prim is added, or removed when already selected
+    Toggle,
+}
+
+/// Selected prim paths in the order they were picked
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrimSelection {
+    paths: Vec<String>,
+}
+
+impl PrimSelection {
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.paths.iter().any(|selected| selected == path)
+    }
+
+    /// Apply a pick; `path` is None when nothing was under the cursor
+    pub fn pick(&mut self, path: Option<&str>, mode: SelectMode) {
+        match (mode, path) {
+            (SelectMode::Replace, None) => self.paths.clear(),
+            (SelectMode::Replace, Some(path)) => self.paths = vec![path.to_string()],
+            (SelectMode::Toggle, None) => {}
+            (SelectMode::Toggle, Some(path)) => {
+                if self.contains(path) {
+                    self.paths.retain(|selected| selected != path);
+                } else {
+                    self.paths.push(path.to_string());
+                }
+            }
+        }
+    }
+
+    /// "/World/Cube /World/Sphere", as read by prim pattern parameters
+    pub fn to_text(&self) -> String {
+        self.paths.join(" ")
+    }
+}
+
+/// Current selection
+pub fn current() -> PrimSelection {
+    PRIM_SELECTION.read().map(|selection| selection.clone()).unwrap_or_default()
+}
+
+/// Apply a pick to the current selection
+pub fn pick(path: Option<&str>, mode: SelectMode) {
+    if let Ok(mut selection) = PRIM_SELECTION.write() {
+        selection.pick(path, mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picks_replace_and_toggle_the_selection() {
+        let mut selection = PrimSelection::default();
+        selection.pick(Some("/World/Cube"), SelectMode::Replace);
+        selection.pick(Some("/World/Sphere"), SelectMode::Toggle);
+        assert_eq!(selection.to_text(), "/World/Cube /World/Sphere");
+
+        selection.pick(Some("/World/Cube"), SelectMode::Toggle);
+        assert_eq!(selection.paths(), ["/World/Sphere".to_string()]);
+        selection.pick(None, SelectMode::Toggle);
+        assert!(selection.contains("/World/Sphere"));
+
+        selection.pick(Some("/World/Cone"), SelectMode::Replace);
+        assert_eq!(selection.to_text(), "/World/Cone");
+        selection.pick(None, SelectMode::Replace);
+        assert!(selection.paths().is_empty());
+    }
+}
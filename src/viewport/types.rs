@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 /// 3D camera state data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CameraData {
     /// Camera position in world space
     pub position: [f32; 3],
@@ -57,6 +57,29 @@ pub struct MeshData {
     pub material_id: Option<String>,
     /// Transformation matrix for this mesh
     pub transform: [[f32; 4]; 4],
+    /// USD prim the mesh was converted from; empty for meshes that do not come from a stage
+    #[serde(default)]
+    pub prim_path: String,
+}
+
+/// Point cloud drawn as screen-space dots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointsData {
+    /// Unique identifier for these points
+    pub id: String,
+    /// Point positions (x,y,z triplets)
+    pub positions: Vec<f32>,
+    /// Point colors (r,g,b triplets); empty when the points have none
+    pub colors: Vec<f32>,
+}
+
+/// Line segments drawn as overlays, such as volume bounds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinesData {
+    /// Unique identifier for these lines
+    pub id: String,
+    /// Segment end points (x,y,z triplets, two per segment)
+    pub positions: Vec<f32>,
 }
 
 /// Material data for 3D rendering
@@ -126,6 +149,12 @@ pub struct SceneData {
     pub camera: CameraData,
     /// Scene bounding box (min, max)
     pub bounding_box: Option<([f32; 3], [f32; 3])>,
+    /// Point clouds in the scene
+    #[serde(default)]
+    pub points: Vec<PointsData>,
+    /// Overlay lines in the scene
+    #[serde(default)]
+    pub lines: Vec<LinesData>,
 }
 
 impl Default for SceneData {
@@ -137,6 +166,8 @@ impl Default for SceneData {
             lights: Vec::new(),
             camera: CameraData::default(),
             bounding_box: None,
+            points: Vec::new(),
+            lines: Vec::new(),
         }
     }
 }
@@ -158,6 +189,131 @@ pub struct ViewportSettings {
     pub aa_samples: u32,
     /// Shading mode
     pub shading_mode: ShadingMode,
+    /// What the viewport draws for each mesh
+    #[serde(default)]
+    pub display_mode: DisplayMode,
+    /// AOVs the active render delegate can display; empty for the built-in renderer
+    #[serde(default)]
+    pub available_aovs: Vec<String>,
+    /// AOV shown instead of the display mode; only honoured when it is one of `available_aovs`
+    #[serde(default)]
+    pub aov: Option<String>,
+    /// Prim paths drawn highlighted
+    #[serde(default)]
+    pub selected_prims: Vec<String>,
+    /// Hydra render delegate drawing the viewport; None for the built-in renderer
+    #[serde(default)]
+    pub hydra_delegate: Option<String>,
+    /// Render settings passed to the Hydra delegate, as key and value
+    #[serde(default)]
+    pub delegate_settings: Vec<(String, String)>,
+    /// Resolution and crop of the Hydra delegate's image
+    #[serde(default)]
+    pub hydra_region: crate::workspaces::three_d::usd::hydra::RenderRegion,
+    /// Size and coloring of point clouds
+    #[serde(default)]
+    pub point_display: PointDisplay,
+}
+
+impl ViewportSettings {
+    /// AOV to display, if one is selected and the render delegate provides it
+    pub fn active_aov(&self) -> Option<&str> {
+        self.aov.as_deref().filter(|aov| self.available_aovs.iter().any(|available| available == aov))
+    }
+}
+
+/// Display modes of the viewport: shaded output or a debug visualization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DisplayMode {
+    #[default]
+    Shaded,
+    WireframeOnShaded,
+    Wireframe,
+    /// World-space normals mapped to RGB
+    Normals,
+    /// A distinct flat color per prim
+    PrimId,
+    /// Checkerboard over the UV coordinates
+    UvChecker,
+}
+
+impl DisplayMode {
+    pub const NAMES: [&'static str; 6] = ["Shaded", "Wireframe on Shaded", "Wireframe", "Normals", "Prim ID", "UV Checker"];
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[*self as usize]
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Shaded" => Some(DisplayMode::Shaded),
+            "Wireframe on Shaded" => Some(DisplayMode::WireframeOnShaded),
+            "Wireframe" => Some(DisplayMode::Wireframe),
+            "Normals" => Some(DisplayMode::Normals),
+            "Prim ID" => Some(DisplayMode::PrimId),
+            "UV Checker" => Some(DisplayMode::UvChecker),
+            _ => None,
+        }
+    }
+
+    /// Whether surfaces are filled (everything but the plain wireframe)
+    pub fn draws_surfaces(&self) -> bool {
+        *self != DisplayMode::Wireframe
+    }
+
+    /// Whether mesh edges are drawn as lines
+    pub fn draws_edges(&self) -> bool {
+        matches!(self, DisplayMode::Wireframe | DisplayMode::WireframeOnShaded)
+    }
+}
+
+/// How point clouds are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PointDisplay {
+    /// Diameter of each point in pixels
+    pub size: f32,
+    pub color_mode: PointColorMode,
+    /// Color of every point in Solid mode
+    pub solid_color: [f32; 3],
+}
+
+impl Default for PointDisplay {
+    fn default() -> Self {
+        Self {
+            size: 2.0,
+            color_mode: PointColorMode::Source,
+            solid_color: [1.0, 1.0, 1.0],
+        }
+    }
+}
+
+/// Where point colors come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PointColorMode {
+    /// The colors read with the points, white where there are none
+    #[default]
+    Source,
+    /// One color for every point
+    Solid,
+    /// A ramp over the height of the points within the scene bounds
+    Height,
+}
+
+impl PointColorMode {
+    pub const NAMES: [&'static str; 3] = ["Source", "Solid", "Height"];
+
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[*self as usize]
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Source" => Some(PointColorMode::Source),
+            "Solid" => Some(PointColorMode::Solid),
+            "Height" => Some(PointColorMode::Height),
+            _ => None,
+        }
+    }
 }
 
 /// Shading modes for viewport rendering
@@ -179,6 +335,14 @@ impl Default for ViewportSettings {
             show_ground_plane: true,
             aa_samples: 4,
             shading_mode: ShadingMode::Smooth,
+            display_mode: DisplayMode::Shaded,
+            available_aovs: Vec::new(),
+            aov: None,
+            selected_prims: Vec::new(),
+            hydra_delegate: None,
+            delegate_settings: Vec::new(),
+            hydra_region: Default::default(),
+            point_display: Default::default(),
         }
     }
 }
@@ -223,4 +387,22 @@ pub enum CameraManipulation {
     Reset,
     /// Set camera to specific position and target
     SetPosition { position: [f32; 3], target: [f32; 3] },
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_mode_names_round_trip() {
+        for name in DisplayMode::NAMES {
+            assert_eq!(DisplayMode::from_name(name).map(|mode| mode.name()), Some(name));
+        }
+        assert!(DisplayMode::WireframeOnShaded.draws_surfaces() && DisplayMode::WireframeOnShaded.draws_edges());
+        assert!(!DisplayMode::Wireframe.draws_surfaces());
+
+        let mut settings = ViewportSettings { aov: Some("depth".to_string()), ..Default::default() };
+        assert_eq!(settings.active_aov(), None);
+        settings.available_aovs = vec!["color".to_string(), "depth".to_string()];
+        assert_eq!(settings.active_aov(), Some("depth"));
+    }
+}
@@ -2,9 +2,10 @@
 
 use egui::Color32;
 use crate::nodes::NodeId;
+use crate::nodes::factory::RegisteredNode;
 use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
 use std::sync::OnceLock;
+use crate::plugins::host::PluginHost;
 
 /// Represents a workspace for node editing (e.g., MaterialX, 3D, etc.)
 pub trait Workspace {
@@ -27,6 +28,23 @@ pub trait Workspace {
     
     /// Create a workspace-specific node at the given position
     fn create_workspace_node(&self, node_type: &str, position: egui::Pos2) -> Option<crate::nodes::Node>;
+    
+    /// Nodes of this workspace matching a search query, best matches first
+    fn search_nodes(&self, _query: &str) -> Vec<RegisteredNode> {
+        Vec::new()
+    }
+    
+    /// Workspace this one is nested in, None for top-level workspaces
+    fn parent_workspace(&self) -> Option<&str> {
+        None
+    }
+    
+    /// Whether menus list this workspace automatically: the root menu when it is
+    /// top-level, its parent's menu when nested. Built-in workspaces are placed
+    /// in the menu hierarchy by hand instead.
+    fn listed_in_menus(&self) -> bool {
+        false
+    }
 }
 
 /// Menu item structure for workspace menus
@@ -71,11 +89,12 @@ impl WorkspaceManager {
         self.workspaces.push(workspace);
         self.workspace_lookup.insert(workspace_id.clone(), index);
         
-        // Set hierarchy - for now, all workspaces are top-level except MaterialX
+        // Set hierarchy - built-in workspaces are top-level except MaterialX
         if workspace_id == "materialx" {
             self.workspace_hierarchy.insert(workspace_id, Some("3d".to_string()));
         } else {
-            self.workspace_hierarchy.insert(workspace_id, None);
+            let parent = self.workspaces[index].parent_workspace().map(str::to_string);
+            self.workspace_hierarchy.insert(workspace_id, parent);
         }
     }
     
@@ -96,9 +115,14 @@ impl WorkspaceManager {
     
     /// Set active workspace by ID
     pub fn set_active_workspace_by_id(&mut self, workspace_id: Option<&str>) {
-        self.active_workspace = workspace_id
-            .and_then(|id| self.workspace_lookup.get(id))
-            .copied();
+        self.active_workspace = workspace_id.and_then(|id| self.workspace_index(id));
+    }
+    
+    /// Index of a workspace by ID, or by display name as used in navigation paths
+    fn workspace_index(&self, id_or_name: &str) -> Option<usize> {
+        self.workspace_lookup.get(id_or_name).copied().or_else(|| {
+            self.workspaces.iter().position(|workspace| workspace.display_name() == id_or_name)
+        })
     }
     
     /// Get workspace by ID
@@ -118,12 +142,21 @@ impl WorkspaceManager {
                     "MaterialX" => "materialx",
                     _ => workspace_name,
                 };
-                self.get_workspace_by_id(workspace_id)
+                self.workspace_index(workspace_id)
+                    .and_then(|index| self.workspaces.get(index))
+                    .map(|workspace| workspace.as_ref())
             }
             None => None, // Root level - no specific workspace
         }
     }
     
+    /// Nodes matching `query` in the workspace of a navigation path (none at root level)
+    pub fn search_nodes_for_path(&self, path: &crate::editor::navigation::WorkspacePath, query: &str) -> Vec<RegisteredNode> {
+        self.get_workspace_for_path(path)
+            .map(|workspace| workspace.search_nodes(query))
+            .unwrap_or_default()
+    }
+    
     /// Get menu structure based on current navigation path
     pub fn get_menu_for_path(&self, path: &crate::editor::navigation::WorkspacePath) -> Vec<WorkspaceMenuItem> {
         // Get the workspace for this path
@@ -137,19 +170,37 @@ impl WorkspaceManager {
             // Plugin nodes are already included in workspace.get_menu_structure() 
             // via the global plugin manager integration in workspace_3d.rs
             
+            // Workspaces nested in this one (registered by plugins)
+            for child in &self.workspaces {
+                let is_child = child.parent_workspace() == Some(workspace.id());
+                if is_child && child.listed_in_menus() {
+                    menu_items.push(WorkspaceMenuItem::Workspace {
+                        name: child.display_name().to_string(),
+                        workspace_id: child.id().to_string(),
+                    });
+                }
+            }
+            
             menu_items
         } else {
             // At root level, use the hardcoded root menu for workspace selection
-            crate::menu_hierarchy::GlobalMenuHierarchy::get_menu_for_workspace(None)
+            let mut menu_items = crate::menu_hierarchy::GlobalMenuHierarchy::get_menu_for_workspace(None);
+            
+            // Top-level workspaces registered by plugins
+            for workspace in self.workspaces.iter().filter(|workspace| workspace.listed_in_menus() && workspace.parent_workspace().is_none()) {
+                menu_items.push(WorkspaceMenuItem::Node {
+                    name: format!("{} Workspace", workspace.display_name()),
+                    node_type: format!("WORKSPACE:{}", workspace.id()),
+                });
+            }
+            menu_items
         }
     }
     
-    /// Get plugin menu items using global plugin manager
+    /// Get plugin menu items from the current plugin snapshot
     fn get_plugin_menu_items(&self) -> Result<Vec<WorkspaceMenuItem>, String> {
-        // Use the global plugin manager instance
-        if let Some(plugin_manager) = get_global_plugin_manager() {
-            let manager = plugin_manager.lock().map_err(|e| format!("Plugin manager lock error: {}", e))?;
-            Ok(manager.get_workspace_menu_items())
+        if let Some(plugin_host) = get_plugin_host() {
+            Ok(plugin_host.snapshot().menu_items.clone())
         } else {
             Ok(Vec::new()) // No plugins loaded
         }
@@ -189,12 +240,15 @@ impl WorkspaceManager {
     }
 }
 
-// Global plugin manager singleton
-static GLOBAL_PLUGIN_MANAGER: OnceLock<Arc<Mutex<crate::plugins::PluginManager>>> = OnceLock::new();
+// Global plugin host singleton, owning the plugin manager
+static GLOBAL_PLUGIN_HOST: OnceLock<PluginHost> = OnceLock::new();
 
 /// Initialize the global plugin manager
 pub fn initialize_global_plugin_manager() -> Result<(), String> {
     let mut plugin_manager = crate::plugins::PluginManager::new();
+    for directory in crate::settings::current().plugin_directories {
+        plugin_manager.add_plugin_directory(directory);
+    }
     
     // Load plugins once at startup
     if let Err(e) = plugin_manager.discover_and_load_plugins() {
@@ -202,13 +256,13 @@ pub fn initialize_global_plugin_manager() -> Result<(), String> {
     }
     
     // Store in global singleton
-    GLOBAL_PLUGIN_MANAGER.set(Arc::new(Mutex::new(plugin_manager)))
+    GLOBAL_PLUGIN_HOST.set(PluginHost::new(plugin_manager))
         .map_err(|_| "Failed to initialize global plugin manager".to_string())?;
     
     Ok(())
 }
 
-/// Get the global plugin manager instance
-pub fn get_global_plugin_manager() -> Option<Arc<Mutex<crate::plugins::PluginManager>>> {
-    GLOBAL_PLUGIN_MANAGER.get().cloned()
+/// Get the global plugin host, through which the plugin manager is accessed
+pub fn get_plugin_host() -> Option<&'static PluginHost> {
+    GLOBAL_PLUGIN_HOST.get()
 }
\ No newline at end of file
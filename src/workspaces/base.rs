@@ -1,7 +1,7 @@
 //! Base workspace implementation for generic node editing
 
 use crate::workspace::{Workspace, WorkspaceMenuItem};
-use crate::nodes::factory::NodeRegistry;
+use crate::nodes::factory::{NodeRegistry, RegisteredNode};
 
 /// Base workspace for generic node editing (no specialized workspace)
 pub struct BaseWorkspace {
@@ -26,49 +26,8 @@ impl Workspace for BaseWorkspace {
     }
     
     fn get_menu_structure(&self) -> Vec<WorkspaceMenuItem> {
-        // Build menu from enhanced node registry categories
-        let mut menu_items = Vec::new();
-        
-        // Math category
-        menu_items.push(WorkspaceMenuItem::Category {
-            name: "Math".to_string(),
-            items: vec![
-                WorkspaceMenuItem::Node { name: "Add".to_string(), node_type: "Add".to_string() },
-                WorkspaceMenuItem::Node { name: "Subtract".to_string(), node_type: "Subtract".to_string() },
-                WorkspaceMenuItem::Node { name: "Multiply".to_string(), node_type: "Multiply".to_string() },
-                WorkspaceMenuItem::Node { name: "Divide".to_string(), node_type: "Divide".to_string() },
-            ],
-        });
-        
-        // Logic category
-        menu_items.push(WorkspaceMenuItem::Category {
-            name: "Logic".to_string(),
-            items: vec![
-                WorkspaceMenuItem::Node { name: "AND".to_string(), node_type: "AND".to_string() },
-                WorkspaceMenuItem::Node { name: "OR".to_string(), node_type: "OR".to_string() },
-                WorkspaceMenuItem::Node { name: "NOT".to_string(), node_type: "NOT".to_string() },
-            ],
-        });
-        
-        // Data category
-        menu_items.push(WorkspaceMenuItem::Category {
-            name: "Data".to_string(),
-            items: vec![
-                WorkspaceMenuItem::Node { name: "Constant".to_string(), node_type: "Constant".to_string() },
-                WorkspaceMenuItem::Node { name: "Variable".to_string(), node_type: "Variable".to_string() },
-            ],
-        });
-        
-        // Output category
-        menu_items.push(WorkspaceMenuItem::Category {
-            name: "Output".to_string(),
-            items: vec![
-                WorkspaceMenuItem::Node { name: "Print".to_string(), node_type: "Print".to_string() },
-                WorkspaceMenuItem::Node { name: "Debug".to_string(), node_type: "Debug".to_string() },
-            ],
-        });
-        
-        menu_items
+        // Build menu from the metadata of the generic nodes
+        self.node_registry.generate_menu_structure(&["General"])
     }
     
     fn is_generic_node_compatible(&self, _node_type: &str) -> bool {
@@ -79,6 +38,10 @@ impl Workspace for BaseWorkspace {
         // Delegate to enhanced node registry
         self.node_registry.create_node(node_type, position)
     }
+    
+    fn search_nodes(&self, query: &str) -> Vec<RegisteredNode> {
+        self.node_registry.search(query, &["General"])
+    }
 }
 
 impl Default for BaseWorkspace {
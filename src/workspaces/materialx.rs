@@ -44,7 +44,7 @@ impl Workspace for MaterialXWorkspace {
     
     fn create_workspace_node(&self, node_type: &str, position: Pos2) -> Option<Node> {
         // Create MaterialX-specific nodes using the organized node functions
-        match node_type {
+        let node = match node_type {
             // Shading nodes
             "MaterialX_StandardSurface" => Some(materialx::shading::create_standard_surface_node(position)),
             "MaterialX_SurfaceShader" => Some(materialx::shading::create_surface_shader_node(position)),
@@ -66,6 +66,13 @@ impl Workspace for MaterialXWorkspace {
             "MaterialX_Constant" => Some(materialx::utilities::create_constant_node(position)),
             
             _ => None,
-        }
+        };
+        // Keep the type on the node so MaterialX export can identify it
+        node.map(|mut node| {
+            if node.type_id == "Unknown" {
+                node.type_id = node_type.to_string();
+            }
+            node
+        })
     }
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ pub mod base;
 pub mod workspace_2d;
 pub mod workspace_3d;
 pub mod materialx;
+pub mod plugin_workspace;
 pub mod registry;
 pub mod three_d;
 
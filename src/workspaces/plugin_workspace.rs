@@ -0,0 +1,89 @@
+//! Workspaces defined by plugins
+
+use std::collections::HashSet;
+use crate::workspace::{Workspace, WorkspaceMenuItem};
+use crate::nodes::{Node, factory::{NodeRegistry, RegisteredNode}};
+use crate::plugins::workspace::WorkspaceDescriptor;
+use egui::Pos2;
+use log::warn;
+
+/// Workspace built from a plugin's workspace descriptor
+pub struct PluginWorkspace {
+    // Workspace ids and names are 'static in the Workspace trait; plugin workspaces are
+    // built once per workspace manager, so leaking them is bounded
+    id: &'static str,
+    display_name: &'static str,
+    descriptor: WorkspaceDescriptor,
+    /// Node types from the menu plus the extra compatible nodes
+    allowed_nodes: HashSet<String>,
+    node_registry: NodeRegistry,
+}
+
+impl PluginWorkspace {
+    pub fn new(descriptor: WorkspaceDescriptor) -> Self {
+        // Core nodes the workspace accepts plus the nodes of all loaded plugins
+        let mut node_registry = NodeRegistry::default();
+        if let Some(plugin_host) = crate::workspace::get_plugin_host() {
+            if let Err(e) = plugin_host.register_plugin_nodes(&mut node_registry) {
+                warn!("Failed to register plugin nodes in workspace {}: {}", descriptor.id, e);
+            }
+        }
+
+        let mut allowed_nodes: HashSet<String> = descriptor.menu.iter()
+            .flat_map(|entry| entry.node_types())
+            .map(str::to_string)
+            .collect();
+        allowed_nodes.extend(descriptor.compatible_nodes.iter().cloned());
+
+        Self {
+            id: Box::leak(descriptor.id.clone().into_boxed_str()),
+            display_name: Box::leak(descriptor.display_name.clone().into_boxed_str()),
+            descriptor,
+            allowed_nodes,
+            node_registry,
+        }
+    }
+}
+
+impl Workspace for PluginWorkspace {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn display_name(&self) -> &'static str {
+        self.display_name
+    }
+
+    fn get_menu_structure(&self) -> Vec<WorkspaceMenuItem> {
+        self.descriptor.menu.iter().map(|entry| entry.to_menu_item()).collect()
+    }
+
+    fn is_generic_node_compatible(&self, node_type: &str) -> bool {
+        self.allowed_nodes.contains(node_type)
+    }
+
+    fn create_workspace_node(&self, node_type: &str, position: Pos2) -> Option<Node> {
+        if !self.allowed_nodes.contains(node_type) {
+            return None;
+        }
+        let mut node = self.node_registry.create_node(node_type, position)?;
+        if let Some(panel_type) = self.descriptor.panel_types.get(node_type) {
+            node.set_panel_type(*panel_type);
+        }
+        Some(node)
+    }
+
+    fn search_nodes(&self, query: &str) -> Vec<RegisteredNode> {
+        self.node_registry.search(query, &[]).into_iter()
+            .filter(|node| self.allowed_nodes.contains(&node.node_type))
+            .collect()
+    }
+
+    fn parent_workspace(&self) -> Option<&str> {
+        self.descriptor.parent.as_deref()
+    }
+
+    fn listed_in_menus(&self) -> bool {
+        true
+    }
+}
@@ -6,6 +6,7 @@ use crate::workspaces::{
     workspace_2d::Workspace2D,
     workspace_3d::Workspace3D,
     materialx::MaterialXWorkspace,
+    plugin_workspace::PluginWorkspace,
 };
 
 /// Registry for managing available workspaces
@@ -22,6 +23,18 @@ impl WorkspaceRegistry {
         manager.register_workspace(Box::new(Workspace3D::new()));
         manager.register_workspace(Box::new(MaterialXWorkspace::new()));
         
+        // Workspaces provided by plugins, after the built-in ones so those keep their ids
+        let descriptors = crate::workspace::get_plugin_host()
+            .map(|plugin_host| plugin_host.snapshot().workspaces.clone())
+            .unwrap_or_default();
+        for descriptor in descriptors {
+            if manager.get_workspace_by_id(&descriptor.id).is_some() {
+                log::warn!("Plugin workspace \"{}\" ignored: a workspace with that id already exists", descriptor.id);
+                continue;
+            }
+            manager.register_workspace(Box::new(PluginWorkspace::new(descriptor)));
+        }
+        
         manager
     }
     
@@ -0,0 +1,379 @@
+//! Hydra render delegates
+//!
+//! Rendering through Hydra goes through the vendored `usd_hydra_render.py`
+//! script, run with the USD Python runtime. This module finds that runtime,
+//! lists the delegates the script reports (Storm, Embree, Cycles and any
+//! third-party plugin) and describes the settings and AOVs of the ones we
+//! know, so the Render node and the viewport offer the same delegates.
+
+use crate::viewport::CameraData;
+use glam::{Mat4, Vec3};
+use serde::{Deserialize, Serialize};
+use once_cell::sync::Lazy;
+use std::env;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// Delegate every USD build ships with
+pub const DEFAULT_DELEGATE: &str = "Storm";
+
+/// Camera prim the viewport renders through
+pub const VIEWPORT_CAMERA_PATH: &str = "/NodleViewportCamera";
+
+/// Delegates found by the last detection; None until detection has run
+static DETECTED_DELEGATES: Lazy<Mutex<Option<Vec<String>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Kind, default and range of a render setting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingKind {
+    Boolean(bool),
+    Integer { default: i32, min: i32, max: i32 },
+    Float { default: f32, min: f32, max: f32 },
+}
+
+/// A render setting a delegate reads from Hydra's render settings map
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DelegateSetting {
+    /// Key in the render settings map, e.g. `samplesToConvergence`
+    pub key: &'static str,
+    pub label: &'static str,
+    pub kind: SettingKind,
+}
+
+impl DelegateSetting {
+    /// Default value as passed on the script's command line
+    pub fn default_text(&self) -> String {
+        match self.kind {
+            SettingKind::Boolean(default) => default.to_string(),
+            SettingKind::Integer { default, .. } => default.to_string(),
+            SettingKind::Float { default, .. } => default.to_string(),
+        }
+    }
+}
+
+/// Settings and AOVs of a delegate we know
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DelegateInfo {
+    /// Name the render script reports
+    pub name: &'static str,
+    pub settings: &'static [DelegateSetting],
+    /// Render outputs besides the beauty pass
+    pub aovs: &'static [&'static str],
+}
+
+const KNOWN_DELEGATES: &[DelegateInfo] = &[
+    DelegateInfo {
+        name: "Storm",
+        settings: &[
+            DelegateSetting { key: "enableTinyPrimCulling", label: "Tiny Prim Culling", kind: SettingKind::Boolean(false) },
+            DelegateSetting { key: "volumeRaymarchingStepSize", label: "Volume Step Size", kind: SettingKind::Float { default: 1.0, min: 0.01, max: 10.0 } },
+        ],
+        aovs: &["color", "depth", "primId", "Neye"],
+    },
+    DelegateInfo {
+        name: "Embree",
+        settings: &[
+            DelegateSetting { key: "samplesToConvergence", label: "Samples", kind: SettingKind::Integer { default: 100, min: 1, max: 4096 } },
+            DelegateSetting { key: "ambientOcclusionSamples", label: "AO Samples", kind: SettingKind::Integer { default: 16, min: 0, max: 256 } },
+            DelegateSetting { key: "enableSceneColors", label: "Scene Colors", kind: SettingKind::Boolean(true) },
+        ],
+        aovs: &["color", "depth", "primId", "instanceId", "Neye", "normal"],
+    },
+    DelegateInfo {
+        name: "Cycles",
+        settings: &[
+            DelegateSetting { key: "cycles:samples", label: "Samples", kind: SettingKind::Integer { default: 64, min: 1, max: 4096 } },
+            DelegateSetting { key: "cycles:use_denoising", label: "Denoise", kind: SettingKind::Boolean(true) },
+        ],
+        aovs: &["color", "depth", "primId", "Neye"],
+    },
+];
+
+/// Settings and AOVs of a delegate; None for third-party delegates we know nothing about
+pub fn delegate_info(name: &str) -> Option<&'static DelegateInfo> {
+    KNOWN_DELEGATES.iter().find(|info| info.name == name)
+}
+
+/// Delegates the render script reports, detected once and cached
+pub fn available_delegates() -> Vec<String> {
+    let mut detected = match DETECTED_DELEGATES.lock() {
+        Ok(detected) => detected,
+        Err(_) => return vec![DEFAULT_DELEGATE.to_string()],
+    };
+    detected.get_or_insert_with(|| detect_delegates().unwrap_or_else(|_| vec![DEFAULT_DELEGATE.to_string()])).clone()
+}
+
+/// Forget the detected delegates so the next lookup runs the script again
+pub fn refresh_delegates() {
+    if let Ok(mut detected) = DETECTED_DELEGATES.lock() {
+        *detected = None;
+    }
+}
+
+/// Ask the render script which delegates this USD build has
+pub fn detect_delegates() -> Result<Vec<String>, String> {
+    let output = script_command(DEFAULT_DELEGATE)?
+        .arg("--list-renderers")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| format!("Failed to run Hydra renderer detection: {}", e))?;
+    Ok(parse_delegate_list(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Delegates in the script's `--list-renderers` output, one `- Name` line each; Storm when none are listed
+pub fn parse_delegate_list(text: &str) -> Vec<String> {
+    let mut delegates: Vec<String> = text.lines()
+        .filter_map(|line| line.trim().strip_prefix("- "))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    if delegates.is_empty() {
+        delegates.push(DEFAULT_DELEGATE.to_string()); // Storm is always available in USD
+    }
+    delegates
+}
+
+/// Command running the render script for `renderer`, with the USD Python environment set
+pub fn script_command(renderer: &str) -> Result<Command, String> {
+    let mut cmd = Command::new(python_path()?);
+    cmd.arg(render_script_path()?);
+
+    // Set environment variables for USD/Python
+    cmd.env("PYTHONPATH", usd_python_path());
+    cmd.env("DYLD_LIBRARY_PATH", usd_lib_path(renderer));
+    cmd.env("LD_LIBRARY_PATH", usd_lib_path(renderer));
+    cmd.env("USD_INSTALL_ROOT", usd_install_root());
+
+    // Force Python environment isolation and clean shutdown
+    cmd.env("PYTHONDONTWRITEBYTECODE", "1");
+    cmd.env("PYTHONUNBUFFERED", "1");
+
+    // Add Cycles plugin path if using Cycles, otherwise disable it entirely
+    if renderer == "Cycles" {
+        cmd.env("PXR_PLUGINPATH", cycles_plugin_path());
+    } else {
+        // Clear PXR_PLUGINPATH and disable Cycles plugin loading
+        cmd.env("PXR_PLUGINPATH", "");
+        // Tell USD to not load the hdCycles plugin
+        cmd.env("PXR_DISABLE_PLUGINS", "hdCycles");
+    }
+    Ok(cmd)
+}
+
+/// `--setting key=value` for each setting and `--aov name` for a non-beauty AOV
+pub fn delegate_arguments(settings: &[(String, String)], aov: Option<&str>) -> Vec<String> {
+    let mut args = Vec::new();
+    for (key, value) in settings {
+        args.push("--setting".to_string());
+        args.push(format!("{}={}", key, value));
+    }
+    if let Some(aov) = aov.filter(|aov| *aov != "color") {
+        args.push("--aov".to_string());
+        args.push(aov.to_string());
+    }
+    args
+}
+
+/// Part of the frame a render covers and at what size, for fast partial test renders
+///
+/// The render script narrows the camera's aperture to the crop instead of
+/// cutting the finished image, so only the cropped pixels are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RenderRegion {
+    /// Left, top, right and bottom as fractions of the frame; None renders all of it
+    pub crop: Option<[f32; 4]>,
+    /// Resolution as a percentage of the full image size
+    pub resolution_percent: u32,
+    /// Extra frame rendered around the camera's view on each side, as a fraction of its size
+    pub overscan: f32,
+}
+
+impl Default for RenderRegion {
+    fn default() -> Self {
+        Self { crop: None, resolution_percent: 100, overscan: 0.0 }
+    }
+}
+
+impl RenderRegion {
+    /// Crop ordered and clamped to the frame; the whole frame without one
+    pub fn crop_rect(&self) -> [f32; 4] {
+        let [left, top, right, bottom] = self.crop.unwrap_or([0.0, 0.0, 1.0, 1.0]);
+        let (left, right) = (left.min(right).clamp(0.0, 1.0), left.max(right).clamp(0.0, 1.0));
+        let (top, bottom) = (top.min(bottom).clamp(0.0, 1.0), top.max(bottom).clamp(0.0, 1.0));
+        [left, top, right.max(left + 0.01).min(1.0), bottom.max(top + 0.01).min(1.0)]
+    }
+
+    /// Size in pixels of the rendered image for a full frame of `width` by `height`
+    pub fn image_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let scale = self.resolution_percent.clamp(1, 400) as f32 / 100.0 * (1.0 + 2.0 * self.overscan.max(0.0));
+        let [left, top, right, bottom] = self.crop_rect();
+        (
+            ((width as f32 * scale * (right - left)).round() as u32).max(1),
+            ((height as f32 * scale * (bottom - top)).round() as u32).max(1),
+        )
+    }
+
+    /// `--crop left,top,right,bottom` and `--overscan fraction` for the render script
+    pub fn arguments(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.crop.is_some() {
+            let [left, top, right, bottom] = self.crop_rect();
+            args.push("--crop".to_string());
+            args.push(format!("{},{},{},{}", left, top, right, bottom));
+        }
+        if self.overscan > 0.0 {
+            args.push("--overscan".to_string());
+            args.push(self.overscan.to_string());
+        }
+        args
+    }
+}
+
+/// A layer sublayering `scene_file` and adding a camera at the viewport's view
+///
+/// The aperture is fixed and the focal length chosen to match the camera's
+/// vertical field of view at `aspect`, as usdview does.
+pub fn camera_layer(scene_file: &str, camera: &CameraData, aspect: f32) -> String {
+    const HORIZONTAL_APERTURE: f32 = 20.955;
+    let vertical_aperture = HORIZONTAL_APERTURE / aspect.max(1e-3);
+    let focal_length = vertical_aperture / 2.0 / (camera.fov / 2.0).tan();
+    // USD matrices are row-major with row vectors: each glam column is a USD row
+    let transform = Mat4::look_at_rh(Vec3::from(camera.position), Vec3::from(camera.target), Vec3::from(camera.up)).inverse();
+    let rows: Vec<String> = transform.to_cols_array_2d().iter()
+        .map(|row| format!("({}, {}, {}, {})", row[0], row[1], row[2], row[3]))
+        .collect();
+    format!(
+        "#usda 1.0\n(\n    subLayers = [@{}@]\n)\n\ndef Camera \"{}\"\n{{\n    float focalLength = {}\n    float horizontalAperture = {}\n    float verticalAperture = {}\n    float2 clippingRange = ({}, {})\n    matrix4d xformOp:transform = ( {} )\n    uniform token[] xformOpOrder = [\"xformOp:transform\"]\n}}\n",
+        scene_file,
+        VIEWPORT_CAMERA_PATH.trim_start_matches('/'),
+        focal_length,
+        HORIZONTAL_APERTURE,
+        vertical_aperture,
+        camera.near,
+        camera.far,
+        rows.join(", "),
+    )
+}
+
+/// Get USD Python path
+fn usd_python_path() -> String {
+    let vendor_python = "/Users/brian/nodle/nodle/vendor/usd/lib/python:/Users/brian/nodle/nodle/vendor/python-runtime/python/lib/python3.9/site-packages";
+
+    if let Ok(existing) = env::var("PYTHONPATH") {
+        format!("{}:{}", vendor_python, existing)
+    } else {
+        vendor_python.to_string()
+    }
+}
+
+/// Get USD library path
+fn usd_lib_path(renderer: &str) -> String {
+    // Base libraries (USD and Python always needed)
+    let mut lib_paths = vec![
+        "/Users/brian/nodle/nodle/vendor/usd/lib",
+        "/Users/brian/nodle/nodle/vendor/python-runtime/python/lib",
+    ];
+
+    // Add Cycles libraries only when using Cycles renderer
+    if renderer == "Cycles" {
+        lib_paths.push("/Users/brian/nodle/nodle/vendor/cycles/install/lib");
+    }
+
+    // Don't inherit existing environment variables to avoid global Cycles paths
+    // We want to set exactly what we need for this specific renderer
+    lib_paths.join(":")
+}
+
+/// Get USD install root
+fn usd_install_root() -> String {
+    env::var("USD_INSTALL_ROOT")
+        .unwrap_or_else(|_| "/Users/brian/nodle/nodle/vendor/usd".to_string())
+}
+
+/// Get Cycles plugin path
+fn cycles_plugin_path() -> String {
+    "/Users/brian/nodle/nodle/vendor/cycles/install/hydra:/Users/brian/nodle/nodle/vendor/cycles/install/usd".to_string()
+}
+
+/// Get Python executable path
+fn python_path() -> Result<String, String> {
+    // Use embedded Python from vendor directory
+    let vendor_python = "/Users/brian/nodle/nodle/vendor/python-runtime/python/bin/python3";
+    if Path::new(vendor_python).exists() {
+        return Ok(vendor_python.to_string());
+    }
+
+    // Fallback to system Python if embedded not found
+    if let Ok(output) = Command::new("which").arg("python3").output() {
+        if output.status.success() {
+            let python_path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            return Ok(python_path);
+        }
+    }
+
+    Err("Python executable not found. Ensure embedded Python is installed or Python 3 is available in PATH".to_string())
+}
+
+/// Get Hydra render script path
+fn render_script_path() -> Result<String, String> {
+    let script_path = "/Users/brian/nodle/nodle/vendor/usd_hydra_render.py";
+    if Path::new(script_path).exists() {
+        Ok(script_path.to_string())
+    } else {
+        Err(format!("Hydra render script not found: {}", script_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delegate_list_arguments_and_camera_layer() {
+        let listed = parse_delegate_list("Available renderers:\n- Storm\n  - Embree\n- \nnoise\n");
+        assert_eq!(listed, vec!["Storm".to_string(), "Embree".to_string()]);
+        assert_eq!(parse_delegate_list("nothing here"), vec![DEFAULT_DELEGATE.to_string()]);
+        assert!(delegate_info("Embree").is_some_and(|info| info.aovs.contains(&"primId")));
+        assert!(delegate_info("SomeStudioDelegate").is_none());
+
+        let args = delegate_arguments(&[("samplesToConvergence".to_string(), "8".to_string())], Some("depth"));
+        assert_eq!(args, vec!["--setting", "samplesToConvergence=8", "--aov", "depth"]);
+        assert!(delegate_arguments(&[], Some("color")).is_empty());
+
+        let camera = CameraData {
+            position: [0.0, 0.0, 5.0],
+            target: [0.0, 0.0, 0.0],
+            up: [0.0, 1.0, 0.0],
+            fov: 2.0 * 0.5f32.atan(),
+            near: 0.1,
+            far: 100.0,
+            aspect: 1.0,
+        };
+        let layer = camera_layer("/tmp/scene.usda", &camera, 1.0);
+        assert!(layer.contains("subLayers = [@/tmp/scene.usda@]"));
+        assert!(layer.contains("def Camera \"NodleViewportCamera\""));
+        // A camera at +5 Z looking at the origin keeps the identity rotation
+        assert!(layer.contains("(0, 0, 5, 1)"));
+        // tan(fov / 2) = 0.5, so the focal length is the aperture
+        assert!(layer.contains("float focalLength = 20.955"));
+    }
+
+    #[test]
+    fn test_render_region_sizes_and_arguments() {
+        let full = RenderRegion::default();
+        assert_eq!(full.image_size(1920, 1080), (1920, 1080));
+        assert!(full.arguments().is_empty());
+
+        let half = RenderRegion { resolution_percent: 50, ..Default::default() };
+        assert_eq!(half.image_size(1920, 1080), (960, 540));
+
+        // A reversed crop is reordered; overscan grows the image on both sides
+        let region = RenderRegion { crop: Some([0.5, 1.0, 0.0, 0.5]), resolution_percent: 100, overscan: 0.1 };
+        assert_eq!(region.crop_rect(), [0.0, 0.5, 0.5, 1.0]);
+        assert_eq!(region.image_size(1000, 500), (600, 300));
+        assert_eq!(region.arguments(), vec!["--crop", "0,0.5,0.5,1", "--overscan", "0.1"]);
+    }
+}
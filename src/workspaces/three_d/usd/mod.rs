@@ -1 +1,2 @@
-pub mod usd_engine;
\ No newline at end of file
+pub mod usd_engine;
+pub mod hydra;
@@ -8,7 +8,7 @@ use pyo3::types::{PyDict, PyList, PyString};
 use numpy::{PyArray1, PyArray2, PyArrayMethods};
 use std::collections::HashMap;
 use std::sync::{Mutex, LazyLock};
-use glam::{Mat4, Vec3, Vec2};
+use glam::{Mat4, Quat, Vec3, Vec2};
 use serde::{Serialize, Deserialize};
 
 /// USD Stage handle - holds a reference to a USD stage
@@ -111,6 +111,34 @@ pub struct USDMeshGeometry {
     pub attributes: Vec<USDAttribute>,     // ALL USD prim attributes (built-in + custom)
 }
 
+impl USDMeshGeometry {
+    /// False once the prim is deactivated (its `active` attribute is false); viewers skip inactive prims
+    pub fn is_active(&self) -> bool {
+        !self.attributes.iter().any(|attribute| attribute.name == "active" && matches!(attribute.value, AttributeValue::Bool(false)))
+    }
+}
+
+/// A variant set authored on a prim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct USDVariantSet {
+    pub prim_path: String,
+    pub name: String,
+    pub variants: Vec<String>,
+    /// Variant selected in the file (empty when none is)
+    pub selection: String,
+}
+
+/// How a stage is written back to disk
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct USDExportOptions {
+    /// Root prim name set as the layer's defaultPrim (empty keeps the source's)
+    pub default_prim: String,
+    /// "Y" or "Z"; empty keeps the source's up axis
+    pub up_axis: String,
+    /// Flatten the composed stage into one layer instead of writing a layer that sublayers the source
+    pub flatten: bool,
+}
+
 /// USD Light extracted from UsdLux prims
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct USDLightData {
@@ -119,6 +147,28 @@ pub struct USDLightData {
     pub intensity: f32,
     pub color: Vec3,
     pub transform: Mat4,
+    /// Brightness in stops, scaling intensity by 2^exposure
+    #[serde(default)]
+    pub exposure: f32,
+    /// Light-specific inputs such as `inputs:angle` or `inputs:texture:file`
+    #[serde(default)]
+    pub attributes: Vec<USDAttribute>,
+}
+
+impl USDLightData {
+    /// UsdLux schema of the light, e.g. "DistantLight" for "distant" or "DistantLight"
+    pub fn lux_type(&self) -> Option<&'static str> {
+        let kind = self.light_type.to_lowercase();
+        match kind.strip_suffix("light").unwrap_or(&kind) {
+            "distant" | "directional" => Some("DistantLight"),
+            "dome" => Some("DomeLight"),
+            "rect" => Some("RectLight"),
+            "sphere" | "point" => Some("SphereLight"),
+            "disk" => Some("DiskLight"),
+            "cylinder" => Some("CylinderLight"),
+            _ => None,
+        }
+    }
 }
 
 /// USD Material extracted from UsdShade prims
@@ -179,6 +229,57 @@ pub struct USDSceneData {
     pub lights: Vec<USDLightData>,
     pub materials: Vec<USDMaterialData>,
     pub up_axis: String, // USD up axis: "Y", "Z", etc.
+    #[serde(default)]
+    pub instancers: Vec<USDPointInstancer>,
+}
+
+impl USDSceneData {
+    /// Every instance of every point instancer as a mesh of its own, for renderers without instancing
+    pub fn instanced_meshes(&self) -> Vec<USDMeshGeometry> {
+        self.instancers.iter().flat_map(USDPointInstancer::to_meshes).collect()
+    }
+}
+
+/// UsdGeomPointInstancer: copies of prototype meshes placed by per-instance position, orientation and scale
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct USDPointInstancer {
+    pub prim_path: String,
+    /// Prototype meshes in their own space
+    pub prototypes: Vec<USDMeshGeometry>,
+    /// Prototype of each instance
+    pub proto_indices: Vec<u32>,
+    pub positions: Vec<Vec3>,
+    pub orientations: Vec<Quat>,
+    pub scales: Vec<Vec3>,
+    /// Transform of the instancer prim itself
+    pub transform: Mat4,
+}
+
+impl USDPointInstancer {
+    pub fn instance_count(&self) -> usize {
+        self.proto_indices.len()
+    }
+
+    /// Transform of instance `index`, including the instancer's own
+    pub fn instance_transform(&self, index: usize) -> Mat4 {
+        let scale = self.scales.get(index).copied().unwrap_or(Vec3::ONE);
+        let orientation = self.orientations.get(index).copied().unwrap_or(Quat::IDENTITY);
+        let position = self.positions.get(index).copied().unwrap_or(Vec3::ZERO);
+        self.transform * Mat4::from_scale_rotation_translation(scale, orientation, position)
+    }
+
+    /// Every instance as a copy of its prototype, named after the instance
+    pub fn to_meshes(&self) -> Vec<USDMeshGeometry> {
+        self.proto_indices.iter().enumerate()
+            .filter_map(|(index, &proto_index)| {
+                let prototype = self.prototypes.get(proto_index as usize)?;
+                let mut mesh = prototype.clone();
+                mesh.prim_path = format!("{}/instance_{}", self.prim_path, index);
+                mesh.transform = self.instance_transform(index) * prototype.transform;
+                Some(mesh)
+            })
+            .collect()
+    }
 }
 
 /// USD Engine for 3D workspace - manages USD operations through Python API
@@ -229,7 +330,7 @@ impl USDEngine {
         println!("🌍 USD Engine: CLEARED {} persistent USD file data entries", count);
     }
 
-    /// Save USD scene data to a file using USD core
+    /// Save USD scene data (meshes and lights) to a file using USD core
     pub fn save_usd_scene_to_file(&mut self, scene_data: &USDSceneData, output_path: &str) -> Result<(), String> {
         #[cfg(feature = "usd")]
         {
@@ -285,62 +386,100 @@ impl USDEngine {
                              mesh_idx, mesh.vertices.len(), mesh.indices.len());
                     
                     let mesh_path = format!("{}/Mesh_{}", root_path, mesh_idx);
-                    
-                    // Create mesh using Mesh.Define
-                    let usd_mesh = mesh_class.call_method1("Define", (&stage, &mesh_path))
-                        .map_err(|e| format!("Failed to create mesh at {}: {}", mesh_path, e))?;
-                    
+                    define_mesh(&stage, &mesh_class, &mesh_path, mesh)?;
                     println!("🎬 USDEngine: Created mesh at path: {}", mesh_path);
-                    
-                    // Only set attributes if we have valid data
-                    if !mesh.vertices.is_empty() {
-                        // Set vertices - convert to tuples as Python expects
-                        let vertices: Vec<(f32, f32, f32)> = mesh.vertices.iter()
-                            .map(|v| (v.x, v.y, v.z))
-                            .collect();
-                        
-                        let points_attr = usd_mesh.call_method0("CreatePointsAttr")
-                            .map_err(|e| format!("Failed to create points attr: {}", e))?;
-                        points_attr.call_method1("Set", (vertices,))
-                            .map_err(|e| format!("Failed to set vertices: {}", e))?;
-                        
-                        println!("🎬 USDEngine: Set {} vertices for mesh {}", mesh.vertices.len(), mesh_idx);
-                    }
-                    
-                    if !mesh.indices.is_empty() && mesh.indices.len() % 3 == 0 {
-                        // Set face vertex counts (triangles = all 3s)
-                        let face_counts: Vec<i32> = vec![3; mesh.indices.len() / 3];
-                        let face_counts_attr = usd_mesh.call_method0("CreateFaceVertexCountsAttr")
-                            .map_err(|e| format!("Failed to create face counts attr: {}", e))?;
-                        face_counts_attr.call_method1("Set", (face_counts,))
-                            .map_err(|e| format!("Failed to set face counts: {}", e))?;
-                        
-                        println!("🎬 USDEngine: Set face counts for mesh {}", mesh_idx);
-                        
-                        // Set face vertex indices
-                        let indices: Vec<i32> = mesh.indices.iter().map(|&i| i as i32).collect();
-                        let indices_attr = usd_mesh.call_method0("CreateFaceVertexIndicesAttr")
-                            .map_err(|e| format!("Failed to create indices attr: {}", e))?;
-                        indices_attr.call_method1("Set", (indices,))
-                            .map_err(|e| format!("Failed to set indices: {}", e))?;
-                        
-                        println!("🎬 USDEngine: Set {} indices for mesh {}", mesh.indices.len(), mesh_idx);
-                    }
-                    
-                    // Set normals if available
-                    if !mesh.normals.is_empty() {
-                        let normals: Vec<(f32, f32, f32)> = mesh.normals.iter()
-                            .map(|n| (n.x, n.y, n.z))
-                            .collect();
-                        let normals_attr = usd_mesh.call_method0("CreateNormalsAttr")
-                            .map_err(|e| format!("Failed to create normals attr: {}", e))?;
-                        normals_attr.call_method1("Set", (normals,))
-                            .map_err(|e| format!("Failed to set normals: {}", e))?;
-                        
-                        println!("🎬 USDEngine: Set {} normals for mesh {}", mesh.normals.len(), mesh_idx);
-                    }
                 }
                 
+                // Add lights to the stage
+                for (light_idx, light) in scene_data.lights.iter().enumerate() {
+                    let Some(lux_type) = light.lux_type() else {
+                        println!("🎬 USDEngine: Skipping light {} of unknown type {}", light.prim_path, light.light_type);
+                        continue;
+                    };
+                    let light_path = format!("{}/Light_{}", root_path, light_idx);
+                    let result: PyResult<()> = (|| {
+                        let usd_lux = py.import("pxr.UsdLux")?;
+                        let gf = py.import("pxr.Gf")?;
+                        let sdf = py.import("pxr.Sdf")?;
+                        let usd_light = usd_lux.getattr(lux_type)?.call_method1("Define", (&stage, &light_path))?;
+                        usd_light.call_method0("CreateIntensityAttr")?.call_method1("Set", (light.intensity,))?;
+                        usd_light.call_method0("CreateExposureAttr")?.call_method1("Set", (light.exposure,))?;
+                        let color = gf.getattr("Vec3f")?.call1((light.color.x, light.color.y, light.color.z))?;
+                        usd_light.call_method0("CreateColorAttr")?.call_method1("Set", (color,))?;
+
+                        add_transform_op(&usd_light, &light.transform)?;
+
+                        let prim = usd_light.call_method0("GetPrim")?;
+                        let value_types = sdf.getattr("ValueTypeNames")?;
+                        for attribute in &light.attributes {
+                            match &attribute.value {
+                                AttributeValue::Float(value) => {
+                                    prim.call_method1("CreateAttribute", (&attribute.name, value_types.getattr("Float")?))?
+                                        .call_method1("Set", (*value,))?;
+                                }
+                                AttributeValue::Bool(value) => {
+                                    prim.call_method1("CreateAttribute", (&attribute.name, value_types.getattr("Bool")?))?
+                                        .call_method1("Set", (*value,))?;
+                                }
+                                AttributeValue::Asset(path) => {
+                                    let asset = sdf.getattr("AssetPath")?.call1((path,))?;
+                                    prim.call_method1("CreateAttribute", (&attribute.name, value_types.getattr("Asset")?))?
+                                        .call_method1("Set", (asset,))?;
+                                }
+                                _ => {}
+                            }
+                        }
+                        // Shaping and shadow inputs only take effect with their API schema applied
+                        for (prefix, api) in [("inputs:shaping:", "ShapingAPI"), ("inputs:shadow:", "ShadowAPI")] {
+                            if light.attributes.iter().any(|attribute| attribute.name.starts_with(prefix)) {
+                                usd_lux.getattr(api)?.call_method1("Apply", (&prim,))?;
+                            }
+                        }
+                        Ok(())
+                    })();
+                    result.map_err(|e| format!("Failed to create light at {}: {}", light_path, e))?;
+                    println!("🎬 USDEngine: Created {} at path: {}", lux_type, light_path);
+                }
+
+                // Add point instancers, with their prototypes as child meshes
+                for (instancer_idx, instancer) in scene_data.instancers.iter().enumerate() {
+                    let instancer_path = format!("{}/Instancer_{}", root_path, instancer_idx);
+                    let usd_instancer = usd_geom.getattr("PointInstancer")
+                        .and_then(|class| class.call_method1("Define", (&stage, &instancer_path)))
+                        .map_err(|e| format!("Failed to create point instancer at {}: {}", instancer_path, e))?;
+                    let prototype_paths: Vec<String> = (0..instancer.prototypes.len())
+                        .map(|proto_idx| format!("{}/Prototypes/Proto_{}", instancer_path, proto_idx))
+                        .collect();
+                    for (prototype, prototype_path) in instancer.prototypes.iter().zip(&prototype_paths) {
+                        define_mesh(&stage, &mesh_class, prototype_path, prototype)?;
+                    }
+                    let result: PyResult<()> = (|| {
+                        let gf = py.import("pxr.Gf")?;
+                        let sdf = py.import("pxr.Sdf")?;
+                        let prototypes_rel = usd_instancer.call_method0("CreatePrototypesRel")?;
+                        for prototype_path in &prototype_paths {
+                            prototypes_rel.call_method1("AddTarget", (sdf.getattr("Path")?.call1((prototype_path,))?,))?;
+                        }
+                        let proto_indices: Vec<i32> = instancer.proto_indices.iter().map(|&index| index as i32).collect();
+                        usd_instancer.call_method0("CreateProtoIndicesAttr")?.call_method1("Set", (proto_indices,))?;
+                        let positions: Vec<(f32, f32, f32)> = instancer.positions.iter().map(|p| (p.x, p.y, p.z)).collect();
+                        usd_instancer.call_method0("CreatePositionsAttr")?.call_method1("Set", (positions,))?;
+                        let quath = gf.getattr("Quath")?;
+                        let orientations = instancer.orientations.iter()
+                            .map(|q| quath.call1((q.w, q.x, q.y, q.z)))
+                            .collect::<PyResult<Vec<_>>>()?;
+                        usd_instancer.call_method0("CreateOrientationsAttr")?.call_method1("Set", (orientations,))?;
+                        let scales: Vec<(f32, f32, f32)> = instancer.scales.iter().map(|s| (s.x, s.y, s.z)).collect();
+                        usd_instancer.call_method0("CreateScalesAttr")?.call_method1("Set", (scales,))?;
+                        if instancer.transform != Mat4::IDENTITY {
+                            add_transform_op(&usd_instancer, &instancer.transform)?;
+                        }
+                        Ok(())
+                    })();
+                    result.map_err(|e| format!("Failed to author point instancer at {}: {}", instancer_path, e))?;
+                    println!("🎬 USDEngine: Created point instancer with {} instances at path: {}", instancer.instance_count(), instancer_path);
+                }
+
                 // Save the stage
                 println!("🎬 USDEngine: Saving USD stage to disk...");
                 stage.call_method0("Save")
@@ -358,6 +497,113 @@ impl USDEngine {
     }
 }
 
+/// Define a mesh prim at `mesh_path` holding `mesh`'s points, triangles and normals
+#[cfg(feature = "usd")]
+fn define_mesh<'py>(stage: &Bound<'py, PyAny>, mesh_class: &Bound<'py, PyAny>, mesh_path: &str, mesh: &USDMeshGeometry) -> Result<Bound<'py, PyAny>, String> {
+    // Create mesh using Mesh.Define
+    let usd_mesh = mesh_class.call_method1("Define", (stage, mesh_path))
+        .map_err(|e| format!("Failed to create mesh at {}: {}", mesh_path, e))?;
+    
+    // Only set attributes if we have valid data
+    if !mesh.vertices.is_empty() {
+        // Set vertices - convert to tuples as Python expects
+        let vertices: Vec<(f32, f32, f32)> = mesh.vertices.iter()
+            .map(|v| (v.x, v.y, v.z))
+            .collect();
+        
+        let points_attr = usd_mesh.call_method0("CreatePointsAttr")
+            .map_err(|e| format!("Failed to create points attr: {}", e))?;
+        points_attr.call_method1("Set", (vertices,))
+            .map_err(|e| format!("Failed to set vertices: {}", e))?;
+        
+        println!("🎬 USDEngine: Set {} vertices for mesh {}", mesh.vertices.len(), mesh_path);
+    }
+    
+    if !mesh.indices.is_empty() && mesh.indices.len() % 3 == 0 {
+        // Set face vertex counts (triangles = all 3s)
+        let face_counts: Vec<i32> = vec![3; mesh.indices.len() / 3];
+        let face_counts_attr = usd_mesh.call_method0("CreateFaceVertexCountsAttr")
+            .map_err(|e| format!("Failed to create face counts attr: {}", e))?;
+        face_counts_attr.call_method1("Set", (face_counts,))
+            .map_err(|e| format!("Failed to set face counts: {}", e))?;
+        
+        println!("🎬 USDEngine: Set face counts for mesh {}", mesh_path);
+        
+        // Set face vertex indices
+        let indices: Vec<i32> = mesh.indices.iter().map(|&i| i as i32).collect();
+        let indices_attr = usd_mesh.call_method0("CreateFaceVertexIndicesAttr")
+            .map_err(|e| format!("Failed to create indices attr: {}", e))?;
+        indices_attr.call_method1("Set", (indices,))
+            .map_err(|e| format!("Failed to set indices: {}", e))?;
+        
+        println!("🎬 USDEngine: Set {} indices for mesh {}", mesh.indices.len(), mesh_path);
+    }
+    
+    // Set normals if available
+    if !mesh.normals.is_empty() {
+        let normals: Vec<(f32, f32, f32)> = mesh.normals.iter()
+            .map(|n| (n.x, n.y, n.z))
+            .collect();
+        let normals_attr = usd_mesh.call_method0("CreateNormalsAttr")
+            .map_err(|e| format!("Failed to create normals attr: {}", e))?;
+        normals_attr.call_method1("Set", (normals,))
+            .map_err(|e| format!("Failed to set normals: {}", e))?;
+        
+        println!("🎬 USDEngine: Set {} normals for mesh {}", mesh.normals.len(), mesh_path);
+    }
+    
+    if mesh.transform != Mat4::IDENTITY {
+        add_transform_op(&usd_mesh, &mesh.transform)
+            .map_err(|e| format!("Failed to set transform of {}: {}", mesh_path, e))?;
+    }
+    
+    for primvar in &mesh.primvars {
+        add_primvar(&usd_mesh, primvar)
+            .map_err(|e| format!("Failed to set primvar {} of {}: {}", primvar.name, mesh_path, e))?;
+    }
+    
+    Ok(usd_mesh)
+}
+
+/// Author a float primvar (e.g. one set by the Attribute Create node); other value types are skipped
+#[cfg(feature = "usd")]
+fn add_primvar(mesh: &Bound<'_, PyAny>, primvar: &USDPrimvar) -> PyResult<()> {
+    let py = mesh.py();
+    let name = primvar.name.strip_prefix("primvars:").unwrap_or(&primvar.name);
+    let type_names = py.import("pxr.Sdf")?.getattr("ValueTypeNames")?;
+    let (value_type, values) = match &primvar.values {
+        PrimvarValues::Float(values) => ("FloatArray", values.clone().into_pyobject(py)?.into_any()),
+        PrimvarValues::Float2(values) => ("Float2Array", values.iter().map(|v| (v.x, v.y)).collect::<Vec<_>>().into_pyobject(py)?.into_any()),
+        PrimvarValues::Float3(values) => (
+            if name == "displayColor" { "Color3fArray" } else { "Float3Array" },
+            values.iter().map(|v| (v.x, v.y, v.z)).collect::<Vec<_>>().into_pyobject(py)?.into_any(),
+        ),
+        _ => return Ok(()),
+    };
+    let created = py.import("pxr.UsdGeom")?.getattr("PrimvarsAPI")?.call1((mesh,))?
+        .call_method1("CreatePrimvar", (name, type_names.getattr(value_type)?, primvar.interpolation.as_str()))?;
+    created.call_method1("Set", (values,))?;
+    if let Some(indices) = &primvar.indices {
+        created.call_method1("SetIndices", (indices.iter().map(|&i| i as i32).collect::<Vec<_>>(),))?;
+    }
+    Ok(())
+}
+
+/// Author `transform` as a single matrix xformOp on an xformable prim
+#[cfg(feature = "usd")]
+fn add_transform_op(prim: &Bound<'_, PyAny>, transform: &Mat4) -> PyResult<()> {
+    let py = prim.py();
+    // glam columns are USD's rows (USD uses row vectors)
+    let rows: Vec<Vec<f64>> = transform.to_cols_array_2d().iter()
+        .map(|column| column.iter().map(|&v| v as f64).collect())
+        .collect();
+    let matrix = py.import("pxr.Gf")?.getattr("Matrix4d")?.call1((rows,))?;
+    py.import("pxr.UsdGeom")?.getattr("Xformable")?.call1((prim,))?
+        .call_method0("AddTransformOp")?
+        .call_method1("Set", (matrix,))?;
+    Ok(())
+}
+
 /// Helper function to extract AttributeValue from Python object
 #[cfg(feature = "usd")]
 fn extract_attribute_value(value_obj: &pyo3::PyObject, py: Python) -> AttributeValue {
@@ -1812,6 +2058,7 @@ impl USDEngine {
                     lights: Vec::new(),
                     materials: Vec::new(),
                     up_axis: "Z".to_string(), // Default to Z-up
+                    instancers: Vec::new(),
                 };
                 
                 // Extract the dictionary with meshes and up_axis
@@ -2018,6 +2265,8 @@ impl USDEngine {
                     intensity: 1.0,
                     color: Vec3::new(1.0, 1.0, 0.9),
                     transform: Mat4::from_rotation_x(-30_f32.to_radians()),
+                    exposure: 0.0,
+                    attributes: Vec::new(),
                 };
                 scene_data.lights.push(default_light);
                 
@@ -2041,6 +2290,7 @@ impl USDEngine {
                 lights: Vec::new(),
                 materials: Vec::new(),
                 up_axis: "Z".to_string(), // Mock data uses Z-up
+                instancers: Vec::new(),
             };
             
             // Add mock geometry
@@ -2080,6 +2330,162 @@ impl USDEngine {
         }
     }
     
+    /// Variant sets authored on the prims of a stage
+    pub fn list_variant_sets(&mut self, stage_path: &str) -> Result<Vec<USDVariantSet>, String> {
+        #[cfg(feature = "usd")]
+        {
+            Python::with_gil(|py| -> Result<Vec<USDVariantSet>, String> {
+                let usd = py.import("pxr.Usd")
+                    .map_err(|e| format!("Failed to import pxr.Usd: {}", e))?;
+                let stage = usd.getattr("Stage")
+                    .and_then(|stage_class| stage_class.call_method1("Open", (stage_path,)))
+                    .map_err(|e| format!("Failed to open {}: {}", stage_path, e))?;
+                let prims = stage.call_method0("Traverse")
+                    .and_then(|prims| prims.try_iter())
+                    .map_err(|e| format!("Failed to traverse {}: {}", stage_path, e))?;
+
+                let mut variant_sets = Vec::new();
+                for prim in prims {
+                    let result: PyResult<()> = (|| {
+                        let prim = prim?;
+                        let sets = prim.call_method0("GetVariantSets")?;
+                        let names: Vec<String> = sets.call_method0("GetNames")?.extract()?;
+                        if names.is_empty() {
+                            return Ok(());
+                        }
+                        let prim_path = prim.call_method0("GetPath")?.str()?.to_string();
+                        for name in names {
+                            let set = sets.call_method1("GetVariantSet", (name.as_str(),))?;
+                            variant_sets.push(USDVariantSet {
+                                prim_path: prim_path.clone(),
+                                variants: set.call_method0("GetVariantNames")?.extract()?,
+                                selection: set.call_method0("GetVariantSelection")?.extract()?,
+                                name,
+                            });
+                        }
+                        Ok(())
+                    })();
+                    result.map_err(|e| format!("Failed to read variant sets of {}: {}", stage_path, e))?;
+                }
+                Ok(variant_sets)
+            })
+        }
+
+        #[cfg(not(feature = "usd"))]
+        {
+            let _ = stage_path;
+            Ok(Vec::new())
+        }
+    }
+
+    /// Load a stage with variant selections applied: (prim path, variant set, variant).
+    /// The composed stage is flattened into `output_path`, which the returned scene points at
+    pub fn load_stage_with_variants(&mut self, stage_path: &str, selections: &[(String, String, String)], output_path: &str) -> Result<USDSceneData, String> {
+        #[cfg(feature = "usd")]
+        {
+            Python::with_gil(|py| -> Result<(), String> {
+                let usd = py.import("pxr.Usd")
+                    .map_err(|e| format!("Failed to import pxr.Usd: {}", e))?;
+                let stage = usd.getattr("Stage")
+                    .and_then(|stage_class| stage_class.call_method1("Open", (stage_path,)))
+                    .map_err(|e| format!("Failed to open {}: {}", stage_path, e))?;
+                for (prim_path, set_name, variant) in selections {
+                    let result: PyResult<()> = (|| {
+                        let prim = stage.call_method1("GetPrimAtPath", (prim_path.as_str(),))?;
+                        if !prim.call_method0("IsValid")?.extract::<bool>()? {
+                            return Ok(());
+                        }
+                        let sets = prim.call_method0("GetVariantSets")?;
+                        if sets.call_method1("HasVariantSet", (set_name.as_str(),))?.extract::<bool>()? {
+                            sets.call_method1("GetVariantSet", (set_name.as_str(),))?
+                                .call_method1("SetVariantSelection", (variant.as_str(),))?;
+                        }
+                        Ok(())
+                    })();
+                    result.map_err(|e| format!("Failed to select {}{{{}={}}}: {}", prim_path, set_name, variant, e))?;
+                }
+                stage.call_method1("Export", (output_path,))
+                    .map_err(|e| format!("Failed to write composed stage {}: {}", output_path, e))?;
+                Ok(())
+            })?;
+            self.load_stage(output_path)
+        }
+
+        #[cfg(not(feature = "usd"))]
+        {
+            // Without USD there are no variants to compose
+            let _ = (selections, output_path);
+            self.load_stage(stage_path)
+        }
+    }
+
+    /// Write the stage at `source_path` to `output_path` (.usda or .usdc by extension).
+    /// Flattening bakes the composed stage into one layer; otherwise the output is a
+    /// thin layer that sublayers the source and only carries the export's metadata
+    pub fn export_stage(&mut self, source_path: &str, output_path: &str, options: &USDExportOptions) -> Result<(), String> {
+        #[cfg(feature = "usd")]
+        {
+            Python::with_gil(|py| -> Result<(), String> {
+                let result: PyResult<()> = (|| {
+                    let usd = py.import("pxr.Usd")?;
+                    let usd_geom = py.import("pxr.UsdGeom")?;
+                    let sdf = py.import("pxr.Sdf")?;
+                    let layer = if options.flatten {
+                        usd.getattr("Stage")?.call_method1("Open", (source_path,))?.call_method0("Flatten")?
+                    } else {
+                        let layer = sdf.getattr("Layer")?.call_method0("CreateAnonymous")?;
+                        layer.setattr("subLayerPaths", vec![source_path.to_string()])?;
+                        layer
+                    };
+                    let stage = usd.getattr("Stage")?.call_method1("Open", (&layer,))?;
+                    if !options.default_prim.is_empty() {
+                        layer.setattr("defaultPrim", options.default_prim.as_str())?;
+                    }
+                    if !options.up_axis.is_empty() {
+                        let token = usd_geom.getattr("Tokens")?.getattr(options.up_axis.to_lowercase().as_str())?;
+                        usd_geom.call_method1("SetStageUpAxis", (&stage, token))?;
+                    }
+                    layer.call_method1("Export", (output_path,))?;
+                    Ok(())
+                })();
+                result.map_err(|e| format!("Failed to export {} to {}: {}", source_path, output_path, e))
+            })
+        }
+
+        #[cfg(not(feature = "usd"))]
+        {
+            let _ = (source_path, output_path, options);
+            Err("USD support is not enabled".to_string())
+        }
+    }
+
+    /// Compose layer files into one stage, strongest first as in USD's `subLayerPaths`.
+    /// The composed stage is flattened into `output_path`, which the returned scene points at
+    pub fn compose_layers(&mut self, layer_paths: &[String], output_path: &str) -> Result<USDSceneData, String> {
+        #[cfg(feature = "usd")]
+        {
+            Python::with_gil(|py| -> Result<(), String> {
+                let result: PyResult<()> = (|| {
+                    let usd = py.import("pxr.Usd")?;
+                    let sdf = py.import("pxr.Sdf")?;
+                    let root = sdf.getattr("Layer")?.call_method0("CreateAnonymous")?;
+                    root.setattr("subLayerPaths", layer_paths.to_vec())?;
+                    let stage = usd.getattr("Stage")?.call_method1("Open", (root,))?;
+                    stage.call_method1("Export", (output_path,))?;
+                    Ok(())
+                })();
+                result.map_err(|e| format!("Failed to compose layers into {}: {}", output_path, e))
+            })?;
+            self.load_stage(output_path)
+        }
+
+        #[cfg(not(feature = "usd"))]
+        {
+            let _ = (layer_paths, output_path);
+            Err("USD support is not enabled".to_string())
+        }
+    }
+
     #[cfg(feature = "usd")]
     fn extract_meshes_recursive(
         &self,
@@ -2142,6 +2548,8 @@ impl USDEngine {
             intensity: 1.0,
             color: Vec3::new(1.0, 1.0, 0.9),
             transform: Mat4::from_rotation_x(-45_f32.to_radians()),
+            exposure: 0.0,
+            attributes: Vec::new(),
         };
         
         scene_data.lights.push(mock_light);
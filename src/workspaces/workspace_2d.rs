@@ -1,7 +1,7 @@
 //! 2D workspace for 2D graphics and drawing workflows
 
 use crate::workspace::{Workspace, WorkspaceMenuItem};
-use crate::nodes::{Node, factory::NodeRegistry};
+use crate::nodes::{Node, factory::{NodeRegistry, RegisteredNode}};
 use egui::Pos2;
 
 /// 2D workspace for 2D graphics and drawing workflows
@@ -11,12 +11,8 @@ pub struct Workspace2D {
 
 impl Workspace2D {
     pub fn new() -> Self {
-        let mut node_registry = NodeRegistry::new();
-        
-        // Register 2D-specific nodes here when they are implemented
-        // node_registry.register::<Rectangle2DNode>();
-        // node_registry.register::<Circle2DNode>();
-        // etc.
+        // 2D nodes are picked up from the core registry by declaring "2D" compatibility
+        let node_registry = NodeRegistry::for_workspace("2D");
         
         Self {
             node_registry,
@@ -40,8 +36,7 @@ impl Workspace for Workspace2D {
     }
     
     fn get_menu_structure(&self) -> Vec<WorkspaceMenuItem> {
-        // Use centralized menu hierarchy
-        crate::menu_hierarchy::GlobalMenuHierarchy::get_2d_workspace_menu()
+        self.node_registry.generate_menu_structure(&["2D"])
     }
     
     fn is_generic_node_compatible(&self, node_type: &str) -> bool {
@@ -57,4 +52,8 @@ impl Workspace for Workspace2D {
         // This will be expanded when 2D-specific nodes are implemented
         self.node_registry.create_node(node_type, position)
     }
+    
+    fn search_nodes(&self, query: &str) -> Vec<RegisteredNode> {
+        self.node_registry.search(query, &["2D"])
+    }
 }
\ No newline at end of file